@@ -53,10 +53,22 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+fn variable_base_precomputed_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(100_000);
+
+    for size in [10_000, 100_000] {
+        let precomputed = PrecomputedBases::new(&bases[..size]);
+        c.bench_function(&format!("Precomputed VariableBase MSM on BLS12-377 ({})", size), |b| {
+            b.iter(|| precomputed.msm(&scalars[..size]))
+        });
+    }
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_edwards_bls12, variable_base_precomputed_bls12_377
 }
 
 criterion_main!(variable_base_group);
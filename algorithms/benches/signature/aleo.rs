@@ -72,6 +72,29 @@ fn aleo_signature_verify(c: &mut Criterion) {
     });
 }
 
+fn aleo_signature_verify_batch(c: &mut Criterion) {
+    const NUM_SIGNATURES: usize = 128;
+
+    let rng = &mut thread_rng();
+    let parameters = SignatureScheme::setup("aleo_signature_verify_batch");
+
+    let mut public_keys = Vec::with_capacity(NUM_SIGNATURES);
+    let mut messages = Vec::with_capacity(NUM_SIGNATURES);
+    let mut signatures = Vec::with_capacity(NUM_SIGNATURES);
+    for _ in 0..NUM_SIGNATURES {
+        let private_key = SignatureScheme::generate_private_key(&parameters, rng);
+        let message = (0..128).map(|_| rand::random::<bool>()).collect::<Vec<bool>>();
+        signatures.push(SignatureScheme::sign(&parameters, &private_key, &message, rng).unwrap());
+        public_keys.push(SignatureScheme::generate_public_key(&parameters, &private_key));
+        messages.push(message);
+    }
+
+    c.bench_function("Aleo Signature Verify Batch (128)", move |b| {
+        let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        b.iter(|| SignatureScheme::verify_batch(&parameters, &public_keys, &messages, &signatures).unwrap())
+    });
+}
+
 criterion_group! {
     name = aleo_signature;
     config = Criterion::default().sample_size(20);
@@ -80,5 +103,6 @@ criterion_group! {
                 aleo_signature_generate_public_key,
                 aleo_signature_sign,
                 aleo_signature_verify,
+                aleo_signature_verify_batch,
 }
 criterion_main!(aleo_signature);
@@ -17,7 +17,7 @@
 use crate::{crh::PedersenCRH, crypto_hash::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BitIteratorLE;
+use snarkvm_utilities::IterBits;
 
 use itertools::Itertools;
 
@@ -57,8 +57,7 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Com
         let mut output = self.crh.hash(input)?.to_projective();
 
         // Compute h^r.
-        let scalar_bits = BitIteratorLE::new(randomness.to_repr()).take(G::ScalarField::size_in_bits());
-        for (bit, power) in scalar_bits.zip_eq(&self.random_base) {
+        for (bit, power) in randomness.iter_bits_le().zip_eq(&self.random_base) {
             if bit {
                 output += power
             }
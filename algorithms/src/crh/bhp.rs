@@ -14,10 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
+use crate::{
+    crypto_hash::{hash_to_curve, tree_hash},
+    CRHError,
+    CRH,
+};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BigInteger;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{BigInteger, ToBits};
 
 use std::{fmt::Debug, sync::Arc};
 
@@ -109,6 +113,46 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
 }
 
 impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Hashes a byte stream of any length, as a tree of chunks that each fit within the parameter size.
+    /// Byte streams above `TREE_HASH_PARALLEL_THRESHOLD` bytes are hashed in parallel.
+    pub fn hash_bytes_as_tree(&self, input: &[u8]) -> Result<<Self as CRH>::Output, CRHError> {
+        // Ensure the parameter size fits the domain bit and a pair of nodes.
+        let num_node_bits = 1 + 2 * <Self as CRH>::Output::zero().to_bits_le().len();
+        if num_node_bits > NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
+            return Err(CRHError::Message(format!(
+                "BHP with {NUM_WINDOWS} windows of size {WINDOW_SIZE} cannot hash a tree with {num_node_bits}-bit nodes"
+            )));
+        }
+
+        tree_hash(
+            input,
+            self.tree_hash_chunk_size(),
+            |chunk| self.hash_leaf(chunk),
+            |left, right| self.hash_node(left, right),
+        )
+    }
+
+    /// Returns the number of bytes in each chunk of a tree hash, which is the parameter size less the domain bit.
+    pub(crate) fn tree_hash_chunk_size(&self) -> usize {
+        (NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE - 1) / 8
+    }
+
+    /// Returns the leaf of the given chunk, as the hash of the leaf domain bit and the chunk.
+    pub(crate) fn hash_leaf(&self, chunk: &[u8]) -> Result<<Self as CRH>::Output, CRHError> {
+        let input = [&[false][..], &chunk.to_bits_le()].concat();
+        self.hash(&input)
+    }
+
+    /// Returns the parent of the given nodes, as the hash of the node domain bit and the two nodes.
+    pub(crate) fn hash_node(
+        &self,
+        left: &<Self as CRH>::Output,
+        right: &<Self as CRH>::Output,
+    ) -> Result<<Self as CRH>::Output, CRHError> {
+        let input = [&[true][..], &left.to_bits_le(), &right.to_bits_le()].concat();
+        self.hash(&input)
+    }
+
     pub(crate) fn hash_bits_inner(&self, input: &[bool]) -> Result<G, CRHError> {
         // Ensure the input size is within the parameter size,
         if input.len() > NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
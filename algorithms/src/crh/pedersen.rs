@@ -16,9 +16,9 @@
 
 use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_utilities::IterBits;
 
-use itertools::Itertools;
-use std::{borrow::Cow, fmt::Debug};
+use std::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCRH<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
@@ -50,29 +50,37 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
     }
 
     fn hash(&self, input: &[bool]) -> Result<Self::Output, CRHError> {
-        let mut input = Cow::Borrowed(input);
-        match input.len() <= WINDOW_SIZE * NUM_WINDOWS {
-            // Pad the input if it is under the required parameter size.
-            true => input.to_mut().resize(WINDOW_SIZE * NUM_WINDOWS, false),
-            // Ensure the input size is within the parameter size,
-            false => return Err(CRHError::IncorrectInputLength(input.len(), WINDOW_SIZE, NUM_WINDOWS)),
+        self.hash_bits_inner(input.len(), input.iter().copied())
+    }
+
+    fn hash_bytes(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        self.hash_bits_inner(input.len() * 8, input.iter_bits_le())
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.bases
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> PedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Hashes the given stream of `num_bits` bits, without allocating.
+    fn hash_bits_inner(&self, num_bits: usize, input: impl Iterator<Item = bool>) -> Result<G::Affine, CRHError> {
+        // Ensure the input size is within the parameter size,
+        if num_bits > WINDOW_SIZE * NUM_WINDOWS {
+            return Err(CRHError::IncorrectInputLength(num_bits, WINDOW_SIZE, NUM_WINDOWS));
         }
 
         // Compute sum of h_i^{m_i} for all i.
+        //
+        // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
+        // `NUM_WINDOWS * WINDOW_SIZE` in length, in which case it is implicitly padded with zeros.
         Ok(input
-            .chunks(WINDOW_SIZE)
-            .zip_eq(&self.bases)
-            .flat_map(|(bits, powers)| {
-                bits.iter().zip_eq(powers).flat_map(|(bit, base)| match bit {
-                    true => Some(*base),
-                    false => None,
-                })
+            .zip(self.bases.iter().flatten())
+            .filter_map(|(bit, base)| match bit {
+                true => Some(*base),
+                false => None,
             })
             .sum::<G>()
             .to_affine())
     }
-
-    fn parameters(&self) -> &Self::Parameters {
-        &self.bases
-    }
 }
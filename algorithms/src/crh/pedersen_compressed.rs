@@ -39,6 +39,11 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
         Ok(self.crh.hash(input)?.to_x_coordinate())
     }
 
+    /// Returns the affine x-coordinate as the collision-resistant hash output.
+    fn hash_bytes(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        Ok(self.crh.hash_bytes(input)?.to_x_coordinate())
+    }
+
     fn parameters(&self) -> &Self::Parameters {
         &self.crh
     }
@@ -26,5 +26,8 @@ pub use poseidon::*;
 pub mod sha256;
 pub use sha256::*;
 
+pub mod tree_hash;
+pub use tree_hash::*;
+
 #[cfg(test)]
 pub mod tests;
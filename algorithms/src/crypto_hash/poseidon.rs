@@ -14,11 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AlgebraicSponge, DefaultCapacityAlgebraicSponge, DuplexSpongeMode};
+use crate::{
+    crypto_hash::{tree_hash, TREE_HASH_CHUNK_SIZE},
+    AlgebraicSponge,
+    DefaultCapacityAlgebraicSponge,
+    DuplexSpongeMode,
+};
 use snarkvm_fields::{PoseidonParameters, PrimeField};
+use snarkvm_utilities::FromBits;
 
 use smallvec::SmallVec;
 use std::{
+    convert::Infallible,
     ops::{Index, IndexMut},
     sync::Arc,
 };
@@ -74,6 +81,12 @@ impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> Poseid
         Self { parameters: Arc::new(F::default_poseidon_parameters::<RATE>(OPTIMIZED_FOR_WEIGHTS).unwrap()) }
     }
 
+    /// Initializes a new instance of the cryptographic hash function with the given parameters,
+    /// such as those produced by `PoseidonParameters::generate`.
+    pub fn from_parameters(parameters: PoseidonParameters<F, RATE, 1>) -> Self {
+        Self { parameters: Arc::new(parameters) }
+    }
+
     /// Evaluate the cryptographic hash function over a list of field elements as input.
     pub fn evaluate(&self, input: &[F]) -> F {
         self.evaluate_many(input, 1)[0]
@@ -87,12 +100,56 @@ impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> Poseid
         sponge.squeeze(num_outputs).to_vec()
     }
 
+    /// Evaluate the cryptographic hash function over a list of field elements as input,
+    /// and returns the output projected onto the given scalar field.
+    ///
+    /// The output is truncated to the size in data bits of the scalar field (1 bit less than its modulus),
+    /// matching `HashToScalar` in `circuits`. This requires the scalar field to be smaller than this field.
+    pub fn hash_to_scalar<S: PrimeField>(&self, input: &[F]) -> S {
+        // Hash the input to this field.
+        let output = self.evaluate(input);
+
+        // Truncate the output to the size in data bits of the scalar field.
+        let bits = output.to_bits_le();
+        let biginteger = S::BigInteger::from_bits_le(&bits[..S::size_in_data_bits()]);
+        match biginteger.ok().and_then(S::from_repr) {
+            // This case always succeeds, as the output is truncated to fewer bits than the scalar modulus.
+            Some(scalar) => scalar,
+            None => panic!("Failed to hash input into the scalar field"),
+        }
+    }
+
     /// Evaluate the cryptographic hash function over a non-fixed-length vector,
     /// in which the length also needs to be hashed.
     pub fn evaluate_with_len(&self, input: &[F]) -> F {
         self.evaluate(&[vec![F::from(input.len() as u128)], input.to_vec()].concat())
     }
 
+    /// Evaluate the cryptographic hash function over a byte stream of any length, as a tree of chunks.
+    /// Byte streams above `TREE_HASH_PARALLEL_THRESHOLD` bytes are hashed in parallel.
+    pub fn hash_bytes_as_tree(&self, input: &[u8]) -> F {
+        tree_hash::<F, Infallible, _, _>(
+            input,
+            TREE_HASH_CHUNK_SIZE,
+            |chunk| Ok(self.hash_leaf(chunk)),
+            |left, right| Ok(self.hash_node(left, right)),
+        )
+        .unwrap_or_else(|error| match error {})
+    }
+
+    /// Returns the leaf of the given chunk, as the hash of the leaf domain, the length of the chunk,
+    /// and the chunk packed into field elements.
+    pub(crate) fn hash_leaf(&self, chunk: &[u8]) -> F {
+        let mut input = vec![F::zero(), F::from(chunk.len() as u128)];
+        input.extend(chunk.chunks(F::size_in_data_bits() / 8).map(F::from_bytes_le_mod_order));
+        self.evaluate(&input)
+    }
+
+    /// Returns the parent of the given nodes, as the hash of the node domain and the two nodes.
+    pub(crate) fn hash_node(&self, left: &F, right: &F) -> F {
+        self.evaluate(&[F::one(), *left, *right])
+    }
+
     pub fn parameters(&self) -> &Arc<PoseidonParameters<F, RATE, 1>> {
         &self.parameters
     }
@@ -181,6 +238,32 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F,
 }
 
 impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F, RATE, CAPACITY> {
+    ///
+    /// Ratchets the sponge, by permuting the state and zeroing out the rate.
+    ///
+    /// This prevents recovering any previous state from the current state, and
+    /// ensures the next `absorb` or `squeeze` starts on a fresh block.
+    ///
+    pub fn ratchet(&mut self) {
+        self.permute();
+        self.state.rate_state = [F::zero(); RATE];
+        self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+    }
+
+    ///
+    /// Finishes the sponge under the given domain separator, and returns `num_elements` field elements.
+    ///
+    /// The domain separator is added to the capacity before the final permutation,
+    /// so that sponges with the same transcript and different domains produce independent outputs.
+    ///
+    pub fn finish(mut self, domain: F, num_elements: usize) -> SmallVec<[F; 10]> {
+        // Add the domain separator to the capacity, or to the rate if there is no capacity.
+        self.state[0] += domain;
+        // Ensure the squeeze starts with a permutation, even if the sponge was squeezing.
+        self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: RATE };
+        self.squeeze(num_elements)
+    }
+
     #[inline]
     fn apply_ark(&mut self, round_number: usize) {
         for (state_elem, ark_elem) in self.state.iter_mut().zip(&self.parameters.ark[round_number]) {
@@ -69,6 +69,58 @@ fn test_poseidon_sponge_consistency() {
     }
 }
 
+#[test]
+fn test_poseidon_sponge_ratchet() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>(false).unwrap());
+    let input = vec![Fr::from(1237812u64); 3];
+
+    let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    sponge.absorb(&input);
+    let mut ratcheted = sponge.clone();
+    ratcheted.ratchet();
+    assert_eq!(ratcheted.mode, DuplexSpongeMode::Absorbing { next_absorb_index: 0 });
+
+    // Ensure ratcheting changes the subsequent output, and is deterministic.
+    let mut other = sponge.clone();
+    other.ratchet();
+    let expected = ratcheted.squeeze(3);
+    assert_ne!(sponge.squeeze(3), expected);
+    assert_eq!(other.squeeze(3), expected);
+
+    // Ensure the absorbed input is retained in the capacity across a ratchet.
+    let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    sponge.absorb(&input);
+    let mut other = sponge.clone();
+    other.absorb(&[Fr::from(1u64)]);
+    sponge.ratchet();
+    other.ratchet();
+    assert_ne!(sponge.squeeze(1), other.squeeze(1));
+}
+
+#[test]
+fn test_poseidon_sponge_finish() {
+    const RATE: usize = 4;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>(false).unwrap());
+
+    for absorb in 0..10 {
+        let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+        sponge.absorb(&vec![Fr::from(1237812u64); absorb]);
+
+        // Ensure the output is deterministic in the domain, and separated across domains.
+        let expected = sponge.clone().finish(Fr::from(1u64), 5);
+        assert_eq!(expected, sponge.clone().finish(Fr::from(1u64), 5));
+        assert_ne!(expected, sponge.clone().finish(Fr::from(2u64), 5));
+        assert_eq!(expected[..2], sponge.clone().finish(Fr::from(1u64), 2)[..]);
+
+        // Ensure finishing after a partial squeeze still permutes the state.
+        let mut squeezed = sponge.clone();
+        let output = squeezed.squeeze(1);
+        let finished = squeezed.finish(Fr::from(0u64), 1);
+        assert_ne!(output, finished);
+    }
+}
+
 #[test]
 fn bls12_377_fr_poseidon_default_parameters_test() {
     fn single_rate_test<const RATE: usize>(optimize_for_weights: bool) {
@@ -94,3 +146,65 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>(true);
     single_rate_test::<8>(true);
 }
+
+#[test]
+fn test_poseidon_hash_to_scalar() {
+    use crate::crypto_hash::Poseidon;
+    use snarkvm_curves::edwards_bls12::Fr as ScalarField;
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::{test_rng, ToBits, UniformRand};
+
+    let rng = &mut test_rng();
+    let poseidon = Poseidon::<Fr, 4, false>::setup();
+
+    for num_inputs in 0..10 {
+        let input = (0..num_inputs).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let output = poseidon.evaluate(&input);
+        let candidate: ScalarField = poseidon.hash_to_scalar(&input);
+
+        // Ensure the scalar is the output truncated to the size in data bits of the scalar field.
+        let num_bits = ScalarField::size_in_data_bits();
+        assert_eq!(output.to_bits_le()[..num_bits], candidate.to_bits_le()[..num_bits]);
+        assert!(candidate.to_bits_le()[num_bits..].iter().all(|bit| !bit));
+    }
+}
+
+#[test]
+fn test_poseidon_generate_matches_default_parameters() {
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_fields::{PoseidonParameters, PrimeField};
+
+    fn check_generate<F: PrimeField + PoseidonDefaultField, const RATE: usize>() {
+        for optimize_for_weights in [false, true] {
+            let expected = F::default_poseidon_parameters::<RATE>(optimize_for_weights).unwrap();
+            let candidate = PoseidonParameters::<F, RATE, 1>::generate(expected.alpha).unwrap();
+            assert_eq!(expected, candidate, "rate {} and optimize_for_weights {}", RATE, optimize_for_weights);
+        }
+    }
+
+    fn check_generate_for_field<F: PrimeField + PoseidonDefaultField>() {
+        check_generate::<F, 2>();
+        check_generate::<F, 3>();
+        check_generate::<F, 4>();
+        check_generate::<F, 5>();
+        check_generate::<F, 6>();
+        check_generate::<F, 7>();
+        check_generate::<F, 8>();
+    }
+
+    check_generate_for_field::<Fr>();
+    check_generate_for_field::<Fq>();
+}
+
+#[test]
+fn test_poseidon_generate_invalid_alpha() {
+    use snarkvm_fields::{is_valid_poseidon_alpha, PoseidonParameters};
+
+    // `x^alpha` is not a permutation for even `alpha`, as `p - 1` is even.
+    for alpha in [0, 1, 2, 4, 16] {
+        assert!(!is_valid_poseidon_alpha::<Fr>(alpha));
+        assert!(PoseidonParameters::<Fr, 2, 1>::generate(alpha).is_none());
+    }
+    assert!(is_valid_poseidon_alpha::<Fr>(17));
+    assert!(PoseidonParameters::<Fr, 0, 1>::generate(17).is_none());
+}
@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of bytes in each chunk of a byte stream that is hashed as a tree, unless the hash function requires fewer.
+pub const TREE_HASH_CHUNK_SIZE: usize = 4096;
+
+/// The number of bytes above which a byte stream is hashed as a tree in parallel, when the `parallel` feature is enabled.
+pub const TREE_HASH_PARALLEL_THRESHOLD: usize = 1 << 16;
+
+///
+/// Hashes the given bytes as a binary tree, by hashing each chunk of `chunk_size` bytes into a leaf,
+/// and hashing each pair of nodes into their parent, until a single root remains.
+/// An empty byte stream is hashed as a single empty leaf, and an odd node at the end of a level
+/// is promoted to the next level unchanged.
+///
+/// The root depends only on the bytes and the chunk size, and not on whether it was computed in parallel.
+/// Byte streams above `TREE_HASH_PARALLEL_THRESHOLD` bytes hash their chunks and each level in parallel.
+///
+pub fn tree_hash<D, E, L, N>(bytes: &[u8], chunk_size: usize, hash_leaf: L, hash_node: N) -> Result<D, E>
+where
+    D: Clone + Send + Sync,
+    E: Send,
+    L: Fn(&[u8]) -> Result<D, E> + Sync,
+    N: Fn(&D, &D) -> Result<D, E> + Sync,
+{
+    tree_hash_inner(bytes, chunk_size, bytes.len() > TREE_HASH_PARALLEL_THRESHOLD, hash_leaf, hash_node)
+}
+
+/// Hashes the given bytes as a binary tree, in parallel if `is_parallel` is `true`.
+fn tree_hash_inner<D, E, L, N>(
+    bytes: &[u8],
+    chunk_size: usize,
+    is_parallel: bool,
+    hash_leaf: L,
+    hash_node: N,
+) -> Result<D, E>
+where
+    D: Clone + Send + Sync,
+    E: Send,
+    L: Fn(&[u8]) -> Result<D, E> + Sync,
+    N: Fn(&D, &D) -> Result<D, E> + Sync,
+{
+    assert!(chunk_size > 0, "The chunk size of a tree hash must be nonzero");
+
+    // Hash each chunk into a leaf.
+    let mut nodes = match bytes.is_empty() {
+        true => vec![hash_leaf(&[])?],
+        false => map_chunks(bytes, chunk_size, is_parallel, &hash_leaf)?,
+    };
+
+    // Hash each pair of nodes into their parent, until the root remains.
+    while nodes.len() > 1 {
+        nodes = map_chunks(&nodes, 2, is_parallel, |pair| match pair {
+            [left, right] => hash_node(left, right),
+            [node] => Ok(node.clone()),
+            _ => unreachable!("Chunks of two nodes contain one or two nodes"),
+        })?;
+    }
+
+    // Note: `nodes` contains exactly one node, as it is never empty.
+    Ok(nodes.swap_remove(0))
+}
+
+/// Maps each chunk of `size` items with the given function, in parallel if `is_parallel` is `true`.
+fn map_chunks<T, D, E, M>(items: &[T], size: usize, is_parallel: bool, map: M) -> Result<Vec<D>, E>
+where
+    T: Sync,
+    D: Send,
+    E: Send,
+    M: Fn(&[T]) -> Result<D, E> + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if is_parallel {
+            return items.par_chunks(size).map(&map).collect();
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = is_parallel;
+
+    items.chunks(size).map(map).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crh::BHPCRH, crypto_hash::Poseidon, CRH};
+    use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fq};
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    /// Returns the root of the given bytes, printing each leaf and node.
+    fn print_tree(bytes: &[u8], chunk_size: usize, is_parallel: bool) -> String {
+        tree_hash_inner::<_, (), _, _>(
+            bytes,
+            chunk_size,
+            is_parallel,
+            |chunk| Ok(format!("{:?}", chunk)),
+            |left, right| Ok(format!("({left} {right})")),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tree_hash_structure() {
+        assert_eq!("[]", print_tree(&[], 2, false));
+        assert_eq!("[1]", print_tree(&[1], 2, false));
+        assert_eq!("[1, 2]", print_tree(&[1, 2], 2, false));
+        assert_eq!("([1, 2] [3])", print_tree(&[1, 2, 3], 2, false));
+        assert_eq!("(([1] [2]) [3])", print_tree(&[1, 2, 3], 1, false));
+        assert_eq!("(([1] [2]) ([3] [4]))", print_tree(&[1, 2, 3, 4], 1, false));
+        assert_eq!("((([1] [2]) ([3] [4])) [5])", print_tree(&[1, 2, 3, 4, 5], 1, false));
+    }
+
+    #[test]
+    fn test_tree_hash_is_parallel_invariant() {
+        let rng = &mut test_rng();
+        for num_bytes in [0, 1, 31, 32, 33, 1000, 4097] {
+            let bytes = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+            assert_eq!(print_tree(&bytes, 32, false), print_tree(&bytes, 32, true));
+        }
+    }
+
+    #[test]
+    fn test_poseidon_tree_hash() {
+        let poseidon = Poseidon::<Fq, 4, false>::setup();
+        let rng = &mut test_rng();
+
+        let bytes = (0..3 * TREE_HASH_PARALLEL_THRESHOLD).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let expected = poseidon.hash_bytes_as_tree(&bytes);
+        assert_eq!(expected, poseidon.hash_bytes_as_tree(&bytes));
+
+        // Ensure the parallel and sequential modes produce the same root.
+        let candidate = tree_hash_inner::<_, (), _, _>(
+            &bytes,
+            TREE_HASH_CHUNK_SIZE,
+            false,
+            |chunk| Ok(poseidon.hash_leaf(chunk)),
+            |left, right| Ok(poseidon.hash_node(left, right)),
+        );
+        assert_eq!(Ok(expected), candidate);
+
+        // Ensure the root depends on every byte, including trailing zeros.
+        let mut other = bytes.clone();
+        other[TREE_HASH_PARALLEL_THRESHOLD] ^= 1;
+        assert_ne!(expected, poseidon.hash_bytes_as_tree(&other));
+        assert_ne!(poseidon.hash_bytes_as_tree(&[]), poseidon.hash_bytes_as_tree(&[0]));
+        assert_ne!(poseidon.hash_bytes_as_tree(&[0]), poseidon.hash_bytes_as_tree(&[0, 0]));
+    }
+
+    #[test]
+    fn test_bhp_tree_hash() {
+        let bhp = <BHPCRH<EdwardsProjective, 8, 32> as CRH>::setup("test_bhp_tree_hash");
+        let rng = &mut test_rng();
+
+        let bytes = (0..TREE_HASH_PARALLEL_THRESHOLD + 1).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let expected = bhp.hash_bytes_as_tree(&bytes).unwrap();
+
+        // Ensure the parallel and sequential modes produce the same root.
+        let chunk_size = bhp.tree_hash_chunk_size();
+        let candidate = tree_hash_inner(
+            &bytes,
+            chunk_size,
+            false,
+            |chunk| bhp.hash_leaf(chunk),
+            |left, right| bhp.hash_node(left, right),
+        )
+        .unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure the root depends on every byte, including trailing zeros.
+        let mut other = bytes.clone();
+        other[chunk_size] ^= 1;
+        assert_ne!(expected, bhp.hash_bytes_as_tree(&other).unwrap());
+        assert_ne!(bhp.hash_bytes_as_tree(&[]).unwrap(), bhp.hash_bytes_as_tree(&[0]).unwrap());
+        assert_ne!(bhp.hash_bytes_as_tree(&[0]).unwrap(), bhp.hash_bytes_as_tree(&[0, 0]).unwrap());
+
+        // Ensure parameters that cannot hash a pair of nodes are rejected.
+        let bhp = <BHPCRH<EdwardsProjective, 2, 32> as CRH>::setup("test_bhp_tree_hash");
+        assert!(bhp.hash_bytes_as_tree(&[0]).is_err());
+    }
+}
@@ -16,6 +16,7 @@
 
 use crate::{
     crypto_hash::{hash_to_curve, Poseidon},
+    encryption::PoseidonDuplexEncryption,
     EncryptionError,
     EncryptionScheme,
 };
@@ -26,11 +27,56 @@ use snarkvm_curves::{
     TwistedEdwardsParameters,
 };
 use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{ops::Mul, serialize::*, BitIteratorBE, FromBits, ToBits, UniformRand};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    serialize::*,
+    FromBits,
+    FromBytes,
+    ToBits,
+    ToBytes,
+    UniformRand,
+};
 
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 
+///
+/// A ciphertext of the hybrid encryption scheme, which is encrypted to a public key with an
+/// ephemeral ECDH key exchange, and authenticated with Poseidon duplex encryption.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECIESCiphertext<TE: TwistedEdwardsParameters> {
+    /// The x-coordinate of the ciphertext randomizer G^r.
+    pub randomizer: TE::BaseField,
+    /// The encrypted message elements.
+    pub ciphertext: Vec<TE::BaseField>,
+    /// The authentication tag.
+    pub tag: TE::BaseField,
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ECIESCiphertext<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let randomizer = TE::BaseField::read_le(&mut reader)?;
+        let num_elements = u32::read_le(&mut reader)?;
+        // Note: The elements are read one by one, as the given length is not trusted.
+        let ciphertext = (0..num_elements).map(|_| TE::BaseField::read_le(&mut reader)).collect::<IoResult<_>>()?;
+        let tag = TE::BaseField::read_le(&mut reader)?;
+
+        Ok(Self { randomizer, ciphertext, tag })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ECIESCiphertext<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.randomizer.write_le(&mut writer)?;
+        (self.ciphertext.len() as u32).write_le(&mut writer)?;
+        self.ciphertext.write_le(&mut writer)?;
+        self.tag.write_le(&mut writer)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ECIESPoseidonEncryption<TE: TwistedEdwardsParameters>
 where
@@ -38,14 +84,18 @@ where
 {
     generator: TEAffine<TE>,
     poseidon: Poseidon<TE::BaseField, 4, false>,
+    duplex: PoseidonDuplexEncryption<TE::BaseField, 4, false>,
     symmetric_key_commitment_domain: TE::BaseField,
     symmetric_encryption_domain: TE::BaseField,
+    hybrid_key_derivation_domain: TE::BaseField,
+    hybrid_encryption_domain: TE::BaseField,
 }
 
 impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
 where
     TE::BaseField: PrimeField,
 {
+    type Ciphertext = ECIESCiphertext<TE>;
     type CiphertextRandomizer = TE::BaseField;
     type MessageType = TE::BaseField;
     type Parameters = TEAffine<TE>;
@@ -60,8 +110,18 @@ where
         let poseidon = Poseidon::<TE::BaseField, 4, false>::setup();
         let symmetric_key_commitment_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricKeyCommitment0");
         let symmetric_encryption_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricEncryption0");
-
-        Self { generator, poseidon, symmetric_key_commitment_domain, symmetric_encryption_domain }
+        let hybrid_key_derivation_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoHybridKeyDerivation0");
+        let hybrid_encryption_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoHybridEncryption0");
+
+        Self {
+            generator,
+            poseidon,
+            duplex: PoseidonDuplexEncryption::setup(),
+            symmetric_key_commitment_domain,
+            symmetric_encryption_domain,
+            hybrid_key_derivation_domain,
+            hybrid_encryption_domain,
+        }
     }
 
     fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
@@ -69,7 +129,7 @@ where
     }
 
     fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
-        self.generator.to_projective().mul(*private_key).to_affine()
+        self.generator.to_projective().ct_mul(private_key).to_affine()
     }
 
     ///
@@ -90,12 +150,12 @@ where
         let randomness: Self::ScalarRandomness = UniformRand::rand(rng);
 
         // Compute the randomizer := G^r
-        let ciphertext_randomizer =
-            self.generator.mul_bits(BitIteratorBE::new_without_leading_zeros(randomness.to_repr()));
+        // Note: The randomness is secret, so it is multiplied in constant time.
+        let ciphertext_randomizer = self.generator.to_projective().ct_mul(&randomness);
 
         // Compute the ECDH value := public_key^r.
         // Note for twisted Edwards curves, only one of (x, y) or (x, -y) is in the prime-order subgroup.
-        let symmetric_key = public_key.mul_bits(BitIteratorBE::new_without_leading_zeros(randomness.to_repr()));
+        let symmetric_key = public_key.to_projective().ct_mul(&randomness);
 
         let mut batch = [ciphertext_randomizer, symmetric_key];
         Projective::<TE>::batch_normalization(&mut batch);
@@ -133,12 +193,8 @@ where
             }
         }
 
-        randomizer.map(|randomizer| {
-            randomizer
-                .mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr()))
-                .to_affine()
-                .to_x_coordinate()
-        })
+        // Note: The private key is secret, so it is multiplied in constant time.
+        randomizer.map(|randomizer| randomizer.to_projective().ct_mul(private_key).to_affine().to_x_coordinate())
     }
 
     ///
@@ -175,12 +231,13 @@ where
         let capacity = <<TE::BaseField as PrimeField>::Parameters as FieldParameters>::CAPACITY as usize;
 
         // Pack the bits into field elements.
-        Ok(plaintext_bits
+        plaintext_bits
             .chunks(capacity)
             .map(|chunk| {
-                TE::BaseField::from_repr(<TE::BaseField as PrimeField>::BigInteger::from_bits_le(chunk)).unwrap()
+                let repr = <TE::BaseField as PrimeField>::BigInteger::from_bits_le(chunk)?;
+                Ok(TE::BaseField::from_repr(repr).unwrap())
             })
-            .collect())
+            .collect()
     }
 
     ///
@@ -251,6 +308,64 @@ where
         ciphertext.iter().zip_eq(randomizers).map(|(ciphertext, randomizer)| *ciphertext - randomizer).collect()
     }
 
+    ///
+    /// Encrypts the given message bytes to the given public key, and returns the following:
+    ///
+    /// ```ignore
+    ///     randomizer := G^r
+    ///            key := H(G^ar, G^r)
+    ///     (ciphertext, tag) := DuplexEncrypt(key, nonce := G^r, encode(message))
+    /// ```
+    ///
+    /// As the randomizer is sampled freshly for each message, each key is only used once.
+    ///
+    fn encrypt_to_public_key<R: Rng + CryptoRng>(
+        &self,
+        public_key: &Self::PublicKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Ciphertext, EncryptionError> {
+        // Compute the ephemeral ECDH value, and derive the encryption key.
+        let (_, randomizer, ecdh) = self.generate_asymmetric_key(public_key, rng);
+        let key = self.poseidon.evaluate(&[self.hybrid_key_derivation_domain, ecdh, randomizer]);
+
+        // Encrypt and authenticate the encoded message.
+        let message = Self::encode_message(message)?;
+        let (ciphertext, tag) = self.duplex.encrypt(&self.hybrid_encryption_domain, &key, &randomizer, &message);
+
+        Ok(ECIESCiphertext { randomizer, ciphertext, tag })
+    }
+
+    ///
+    /// Decrypts the given ciphertext with the given private key (i.e. the account view key),
+    /// and returns the message bytes if the ciphertext is authentic for the private key.
+    ///
+    fn decrypt_with_private_key(
+        &self,
+        private_key: &Self::PrivateKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let ECIESCiphertext { randomizer, ciphertext, tag } = ciphertext;
+
+        // Recompute the ECDH value, and derive the encryption key.
+        let ecdh = self
+            .generate_symmetric_key(private_key, *randomizer)
+            .ok_or_else(|| EncryptionError::Message("Invalid ciphertext randomizer".to_string()))?;
+        let key = self.poseidon.evaluate(&[self.hybrid_key_derivation_domain, ecdh, *randomizer]);
+
+        // Authenticate and decrypt the encoded message.
+        let message = self
+            .duplex
+            .decrypt(&self.hybrid_encryption_domain, &key, randomizer, ciphertext, tag)
+            .ok_or(EncryptionError::MismatchingAddress)?;
+
+        // Note: An authentic ciphertext always encodes a non-empty message.
+        if message.is_empty() {
+            return Err(EncryptionError::Message("The ciphertext is empty".to_string()));
+        }
+        Self::decode_message(&message)
+    }
+
     fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters {
         &self.generator
     }
@@ -17,5 +17,8 @@
 pub mod ecies_poseidon;
 pub use ecies_poseidon::*;
 
+pub mod poseidon_duplex;
+pub use poseidon_duplex::*;
+
 #[cfg(test)]
 pub mod tests;
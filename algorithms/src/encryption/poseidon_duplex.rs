@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{Poseidon, PoseidonSponge},
+    AlgebraicSponge,
+};
+use snarkvm_fields::PrimeField;
+
+///
+/// An authenticated symmetric encryption scheme over a duplex sponge with the Poseidon permutation.
+///
+/// The sponge absorbs `(domain, key, nonce, length)`, and then for each message element `m_i`,
+/// squeezes a keystream element `k_i`, outputs the ciphertext element `c_i := m_i + k_i`, and absorbs `c_i`.
+/// The tag is squeezed after the last ciphertext element is absorbed.
+///
+/// The domain separates the uses of the scheme, and each `(key, nonce)` pair *must* only be used once per domain.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonDuplexEncryption<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> {
+    poseidon: Poseidon<F, RATE, OPTIMIZED_FOR_WEIGHTS>,
+}
+
+impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>
+    PoseidonDuplexEncryption<F, RATE, OPTIMIZED_FOR_WEIGHTS>
+{
+    /// Initializes a new instance of the encryption scheme.
+    pub fn setup() -> Self {
+        Self { poseidon: Poseidon::setup() }
+    }
+
+    /// Returns the domain separator for the given tag.
+    pub fn domain(tag: &str) -> F {
+        F::from_bytes_le_mod_order(tag.as_bytes())
+    }
+
+    /// Returns the ciphertext and tag of the given message, for the given domain, key, and nonce.
+    pub fn encrypt(&self, domain: &F, key: &F, nonce: &F, message: &[F]) -> (Vec<F>, F) {
+        let mut sponge = self.initialize(domain, key, nonce, message.len());
+
+        let ciphertext = message
+            .iter()
+            .map(|plaintext| {
+                let ciphertext = *plaintext + sponge.squeeze(1)[0];
+                sponge.absorb(&[ciphertext]);
+                ciphertext
+            })
+            .collect();
+
+        (ciphertext, sponge.squeeze(1)[0])
+    }
+
+    /// Returns the message of the given ciphertext, if the tag is valid for the given domain, key, and nonce.
+    pub fn decrypt(&self, domain: &F, key: &F, nonce: &F, ciphertext: &[F], tag: &F) -> Option<Vec<F>> {
+        let mut sponge = self.initialize(domain, key, nonce, ciphertext.len());
+
+        let message = ciphertext
+            .iter()
+            .map(|ciphertext| {
+                let plaintext = *ciphertext - sponge.squeeze(1)[0];
+                sponge.absorb(&[*ciphertext]);
+                plaintext
+            })
+            .collect();
+
+        // Compare the tags in constant time, to avoid leaking how much of the expected tag was matched.
+        match bool::from(sponge.squeeze(1)[0].ct_eq(tag)) {
+            true => Some(message),
+            false => None,
+        }
+    }
+
+    ///
+    /// Returns a streaming encryptor for the given domain, key, nonce, and associated data.
+    ///
+    /// The sponge absorbs `(domain, key, nonce, length(associated_data), associated_data)`,
+    /// and the message length is absorbed when the tag is squeezed, so the message does not
+    /// need to be known in advance. The `(key, nonce)` pair *must* only be used once per domain,
+    /// across both the one-shot and streaming interfaces.
+    ///
+    pub fn encryptor(&self, domain: &F, key: &F, nonce: &F, associated_data: &[F]) -> PoseidonDuplexEncryptor<F, RATE> {
+        PoseidonDuplexEncryptor { sponge: self.initialize_stream(domain, key, nonce, associated_data), length: 0 }
+    }
+
+    ///
+    /// Returns a streaming decryptor for the given domain, key, nonce, and associated data.
+    ///
+    /// The decrypted elements are *unauthenticated* until `finalize` accepts the tag,
+    /// and must be discarded by the caller if it does not.
+    ///
+    pub fn decryptor(&self, domain: &F, key: &F, nonce: &F, associated_data: &[F]) -> PoseidonDuplexDecryptor<F, RATE> {
+        PoseidonDuplexDecryptor { sponge: self.initialize_stream(domain, key, nonce, associated_data), length: 0 }
+    }
+
+    /// Returns a new sponge, after absorbing the domain, key, nonce, and message length.
+    fn initialize(&self, domain: &F, key: &F, nonce: &F, length: usize) -> PoseidonSponge<F, RATE, 1> {
+        let mut sponge = PoseidonSponge::new(self.poseidon.parameters());
+        sponge.absorb(&[*domain, *key, *nonce, F::from(length as u128)]);
+        sponge
+    }
+
+    /// Returns a new sponge, after absorbing the domain, key, nonce, and associated data.
+    fn initialize_stream(&self, domain: &F, key: &F, nonce: &F, associated_data: &[F]) -> PoseidonSponge<F, RATE, 1> {
+        let mut sponge = PoseidonSponge::new(self.poseidon.parameters());
+        sponge.absorb(&[*domain, *key, *nonce, F::from(associated_data.len() as u128)]);
+        sponge.absorb(associated_data);
+        sponge
+    }
+}
+
+/// The encrypting half of the streaming interface of `PoseidonDuplexEncryption`.
+pub struct PoseidonDuplexEncryptor<F: PrimeField, const RATE: usize> {
+    sponge: PoseidonSponge<F, RATE, 1>,
+    length: u64,
+}
+
+impl<F: PrimeField, const RATE: usize> PoseidonDuplexEncryptor<F, RATE> {
+    /// Returns the ciphertext of the next chunk of the message.
+    pub fn update(&mut self, message: &[F]) -> Vec<F> {
+        self.length += message.len() as u64;
+        message
+            .iter()
+            .map(|plaintext| {
+                let ciphertext = *plaintext + self.sponge.squeeze(1)[0];
+                self.sponge.absorb(&[ciphertext]);
+                ciphertext
+            })
+            .collect()
+    }
+
+    /// Returns the tag of the ciphertext, after absorbing the total message length.
+    pub fn finalize(mut self) -> F {
+        self.sponge.absorb(&[F::from(self.length)]);
+        self.sponge.squeeze(1)[0]
+    }
+}
+
+/// The decrypting half of the streaming interface of `PoseidonDuplexEncryption`.
+pub struct PoseidonDuplexDecryptor<F: PrimeField, const RATE: usize> {
+    sponge: PoseidonSponge<F, RATE, 1>,
+    length: u64,
+}
+
+impl<F: PrimeField, const RATE: usize> PoseidonDuplexDecryptor<F, RATE> {
+    /// Returns the unauthenticated plaintext of the next chunk of the ciphertext.
+    pub fn update(&mut self, ciphertext: &[F]) -> Vec<F> {
+        self.length += ciphertext.len() as u64;
+        ciphertext
+            .iter()
+            .map(|ciphertext| {
+                let plaintext = *ciphertext - self.sponge.squeeze(1)[0];
+                self.sponge.absorb(&[*ciphertext]);
+                plaintext
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the tag is valid for the ciphertext, after absorbing the total ciphertext length.
+    pub fn finalize(mut self, tag: &F) -> bool {
+        self.sponge.absorb(&[F::from(self.length)]);
+        // Compare the tags in constant time, to avoid leaking how much of the expected tag was matched.
+        bool::from(self.sponge.squeeze(1)[0].ct_eq(tag))
+    }
+}
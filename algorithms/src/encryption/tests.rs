@@ -142,4 +142,226 @@ mod ecies {
             assert_ne!(message, decoded_message);
         }
     }
+
+    #[test]
+    fn test_encrypt_to_public_key() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("hybrid_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        for number_of_bytes in [0, 1, 31, 32, 320] {
+            let message = (0..number_of_bytes).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>();
+            let ciphertext = encryption.encrypt_to_public_key(&public_key, &message, rng).unwrap();
+
+            // Ensure the ciphertext round-trips through bytes.
+            let ciphertext_bytes = ciphertext.to_bytes_le().unwrap();
+            let recovered_ciphertext =
+                <TestEncryptionScheme as EncryptionScheme>::Ciphertext::read_le(&ciphertext_bytes[..]).unwrap();
+            assert_eq!(ciphertext, recovered_ciphertext);
+
+            let candidate_message = encryption.decrypt_with_private_key(&private_key, &recovered_ciphertext).unwrap();
+            assert_eq!(message, candidate_message);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_private_key() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("hybrid_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        let message = b"Hi, I am an Aleo hybrid ciphertext!";
+        let ciphertext = encryption.encrypt_to_public_key(&public_key, message, rng).unwrap();
+
+        let alternate_private_key = encryption.generate_private_key(rng);
+        assert!(encryption.decrypt_with_private_key(&alternate_private_key, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_hybrid_ciphertext_manipulation() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("hybrid_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        let message = (0..320).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>();
+        let ciphertext = encryption.encrypt_to_public_key(&public_key, &message, rng).unwrap();
+
+        // Ensure any mutation of a ciphertext element is rejected.
+        for _ in 0..ITERATIONS / 10 {
+            let mut ciphertext = ciphertext.clone();
+            let x = rng.gen_range(0..ciphertext.ciphertext.len());
+            ciphertext.ciphertext[x].add_assign(Fq::one());
+            assert!(encryption.decrypt_with_private_key(&private_key, &ciphertext).is_err());
+        }
+
+        // Ensure a truncated ciphertext is rejected.
+        let mut truncated_ciphertext = ciphertext.clone();
+        truncated_ciphertext.ciphertext.pop();
+        assert!(encryption.decrypt_with_private_key(&private_key, &truncated_ciphertext).is_err());
+
+        // Ensure a mutated tag is rejected.
+        let mut mutated_ciphertext = ciphertext;
+        mutated_ciphertext.tag.add_assign(Fq::one());
+        assert!(encryption.decrypt_with_private_key(&private_key, &mutated_ciphertext).is_err());
+    }
+}
+
+mod poseidon_duplex {
+    use crate::encryption::PoseidonDuplexEncryption;
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use rand::Rng;
+
+    pub const ITERATIONS: usize = 100;
+
+    type TestEncryptionScheme = PoseidonDuplexEncryption<Fq, 4, false>;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let rng = &mut test_rng();
+        let encryption = TestEncryptionScheme::setup();
+        let domain = TestEncryptionScheme::domain("AleoSymmetricEncryption0");
+
+        for num_elements in 0..10 {
+            let key = Fq::rand(rng);
+            let nonce = Fq::rand(rng);
+            let message = (0..num_elements).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+            let (ciphertext, tag) = encryption.encrypt(&domain, &key, &nonce, &message);
+            assert_eq!(message.len(), ciphertext.len());
+
+            let candidate = encryption.decrypt(&domain, &key, &nonce, &ciphertext, &tag);
+            assert_eq!(Some(message), candidate);
+        }
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let rng = &mut test_rng();
+        let encryption = TestEncryptionScheme::setup();
+        let domain = TestEncryptionScheme::domain("AleoSymmetricEncryption0");
+        let other_domain = TestEncryptionScheme::domain("AleoSymmetricEncryption1");
+        assert_ne!(domain, other_domain);
+
+        let (key, nonce) = (Fq::rand(rng), Fq::rand(rng));
+        let message = (0..5).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+        // Ensure the same message, key, and nonce encrypt differently under different domains.
+        let (ciphertext, tag) = encryption.encrypt(&domain, &key, &nonce, &message);
+        let (other_ciphertext, other_tag) = encryption.encrypt(&other_domain, &key, &nonce, &message);
+        assert_ne!(ciphertext, other_ciphertext);
+        assert_ne!(tag, other_tag);
+
+        // Ensure the ciphertext fails to decrypt under a different domain.
+        assert!(encryption.decrypt(&other_domain, &key, &nonce, &ciphertext, &tag).is_none());
+    }
+
+    #[test]
+    fn test_ciphertext_manipulation() {
+        let rng = &mut test_rng();
+        let encryption = TestEncryptionScheme::setup();
+        let domain = TestEncryptionScheme::domain("AleoSymmetricEncryption0");
+
+        for _ in 0..ITERATIONS {
+            let (key, nonce) = (Fq::rand(rng), Fq::rand(rng));
+            let message = (0..5).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+            let (ciphertext, tag) = encryption.encrypt(&domain, &key, &nonce, &message);
+
+            // Ensure a mutated ciphertext element fails to decrypt.
+            let mut mutated = ciphertext.clone();
+            mutated[rng.gen_range(0..5)] += Fq::one();
+            assert!(encryption.decrypt(&domain, &key, &nonce, &mutated, &tag).is_none());
+
+            // Ensure a truncated ciphertext fails to decrypt.
+            assert!(encryption.decrypt(&domain, &key, &nonce, &ciphertext[..4], &tag).is_none());
+
+            // Ensure a mutated tag, key, or nonce fails to decrypt.
+            assert!(encryption.decrypt(&domain, &key, &nonce, &ciphertext, &(tag + Fq::one())).is_none());
+            assert!(encryption.decrypt(&domain, &(key + Fq::one()), &nonce, &ciphertext, &tag).is_none());
+            assert!(encryption.decrypt(&domain, &key, &(nonce + Fq::one()), &ciphertext, &tag).is_none());
+        }
+    }
+
+    #[test]
+    fn test_streaming_encrypt_and_decrypt() {
+        let rng = &mut test_rng();
+        let encryption = TestEncryptionScheme::setup();
+        let domain = TestEncryptionScheme::domain("AleoStreamingEncryption0");
+
+        for num_elements in 0..10 {
+            let (key, nonce) = (Fq::rand(rng), Fq::rand(rng));
+            let associated_data = (0..rng.gen_range(0..4)).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+            let message = (0..num_elements).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+            // Encrypt the message in one chunk.
+            let mut encryptor = encryption.encryptor(&domain, &key, &nonce, &associated_data);
+            let ciphertext = encryptor.update(&message);
+            let tag = encryptor.finalize();
+            assert_eq!(message.len(), ciphertext.len());
+
+            // Ensure encrypting the message in chunks of any size produces the same ciphertext and tag.
+            for chunk_size in 1..4 {
+                let mut encryptor = encryption.encryptor(&domain, &key, &nonce, &associated_data);
+                let candidate =
+                    message.chunks(chunk_size).flat_map(|chunk| encryptor.update(chunk)).collect::<Vec<_>>();
+                assert_eq!(ciphertext, candidate);
+                assert_eq!(tag, encryptor.finalize());
+            }
+
+            // Decrypt the ciphertext in chunks.
+            let mut decryptor = encryption.decryptor(&domain, &key, &nonce, &associated_data);
+            let candidate = ciphertext.chunks(3).flat_map(|chunk| decryptor.update(chunk)).collect::<Vec<_>>();
+            assert!(decryptor.finalize(&tag));
+            assert_eq!(message, candidate);
+        }
+    }
+
+    #[test]
+    fn test_streaming_ciphertext_manipulation() {
+        let rng = &mut test_rng();
+        let encryption = TestEncryptionScheme::setup();
+        let domain = TestEncryptionScheme::domain("AleoStreamingEncryption0");
+
+        let decrypts = |key: &Fq, nonce: &Fq, associated_data: &[Fq], ciphertext: &[Fq], tag: &Fq| {
+            let mut decryptor = encryption.decryptor(&domain, key, nonce, associated_data);
+            decryptor.update(ciphertext);
+            decryptor.finalize(tag)
+        };
+
+        for _ in 0..ITERATIONS {
+            let (key, nonce) = (Fq::rand(rng), Fq::rand(rng));
+            let associated_data = (0..2).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+            let message = (0..5).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+            let mut encryptor = encryption.encryptor(&domain, &key, &nonce, &associated_data);
+            let ciphertext = encryptor.update(&message);
+            let tag = encryptor.finalize();
+            assert!(decrypts(&key, &nonce, &associated_data, &ciphertext, &tag));
+
+            // Ensure a mutated ciphertext element fails to decrypt.
+            let mut mutated = ciphertext.clone();
+            mutated[rng.gen_range(0..5)] += Fq::one();
+            assert!(!decrypts(&key, &nonce, &associated_data, &mutated, &tag));
+
+            // Ensure a truncated ciphertext fails to decrypt.
+            assert!(!decrypts(&key, &nonce, &associated_data, &ciphertext[..4], &tag));
+
+            // Ensure mutated or truncated associated data fails to decrypt.
+            assert!(!decrypts(&key, &nonce, &[associated_data[0], associated_data[1] + Fq::one()], &ciphertext, &tag));
+            assert!(!decrypts(&key, &nonce, &associated_data[..1], &ciphertext, &tag));
+
+            // Ensure a mutated tag, key, or nonce fails to decrypt.
+            assert!(!decrypts(&key, &nonce, &associated_data, &ciphertext, &(tag + Fq::one())));
+            assert!(!decrypts(&(key + Fq::one()), &nonce, &associated_data, &ciphertext, &tag));
+            assert!(!decrypts(&key, &(nonce + Fq::one()), &associated_data, &ciphertext, &tag));
+        }
+    }
 }
@@ -31,3 +31,6 @@ pub use signature::*;
 
 pub mod snark;
 pub use snark::*;
+
+pub mod vrf;
+pub use vrf::*;
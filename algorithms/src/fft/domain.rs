@@ -26,6 +26,8 @@
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
 
+#[cfg(feature = "simd")]
+use crate::cfg_chunks;
 use crate::{
     cfg_chunks_mut,
     cfg_into_iter,
@@ -38,6 +40,8 @@ use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
+#[cfg(feature = "simd")]
+use core::any::TypeId;
 use rand::Rng;
 use std::{borrow::Cow, fmt};
 
@@ -563,9 +567,35 @@ impl<F: FftField> EvaluationDomain<F> {
         *hi = neg;
     }
 
+    /// Applies `butterfly_fn_io` to every pair, multiplying by the roots with `Field::batch_mul_in_place`.
+    fn batched_butterfly_fn_io(lo: &mut [F], hi: &mut [F], roots: &[F]) {
+        lo.iter_mut().zip(hi.iter_mut()).for_each(|(lo, hi)| {
+            let neg = *lo - *hi;
+            *lo += *hi;
+            *hi = neg;
+        });
+        F::batch_mul_in_place(hi, roots);
+    }
+
+    /// Applies `butterfly_fn_oi` to every pair, multiplying by the roots with `Field::batch_mul_in_place`.
+    fn batched_butterfly_fn_oi(lo: &mut [F], hi: &mut [F], roots: &[F]) {
+        F::batch_mul_in_place(hi, roots);
+        lo.iter_mut().zip(hi.iter_mut()).for_each(|(lo, hi)| {
+            let neg = *lo - *hi;
+            *lo += *hi;
+            *hi = neg;
+        });
+    }
+
     #[allow(clippy::too_many_arguments)]
-    fn apply_butterfly<T: DomainCoeff<F>, G: Fn(((&mut T, &mut T), &F)) + Copy + Sync + Send>(
+    #[cfg_attr(not(feature = "simd"), allow(unused_variables))]
+    fn apply_butterfly<
+        T: DomainCoeff<F>,
+        G: Fn(((&mut T, &mut T), &F)) + Copy + Sync + Send,
+        H: Fn(&mut [F], &mut [F], &[F]) + Copy + Sync + Send,
+    >(
         g: G,
+        h: H,
         xi: &mut [T],
         roots: &[F],
         step: usize,
@@ -574,6 +604,31 @@ impl<F: FftField> EvaluationDomain<F> {
         max_threads: usize,
         gap: usize,
     ) {
+        // If the coefficients are field elements, we batch the multiplications by the roots,
+        // so that they may be vectorized by the field.
+        #[cfg(feature = "simd")]
+        if TypeId::of::<T>() == TypeId::of::<F>() && gap >= MIN_GAP_SIZE_FOR_BATCHING {
+            // Safety: `T` and `F` are the same type.
+            let xi = unsafe { &mut *(xi as *mut [T] as *mut [F]) };
+            cfg_chunks_mut!(xi, chunk_size).for_each(|cxi| {
+                let (lo, hi) = cxi.split_at_mut(gap);
+                let roots = match step {
+                    1 => Cow::Borrowed(&roots[..gap]),
+                    _ => Cow::Owned(roots.iter().step_by(step).take(gap).copied().collect::<Vec<_>>()),
+                };
+
+                if gap > MIN_GAP_SIZE_FOR_PARALLELISATION && num_chunks < max_threads {
+                    cfg_chunks_mut!(lo, MIN_GAP_SIZE_FOR_BATCHING)
+                        .zip(cfg_chunks_mut!(hi, MIN_GAP_SIZE_FOR_BATCHING))
+                        .zip(cfg_chunks!(roots, MIN_GAP_SIZE_FOR_BATCHING))
+                        .for_each(|((lo, hi), roots)| h(lo, hi, roots));
+                } else {
+                    h(lo, hi, &roots);
+                }
+            });
+            return;
+        }
+
         cfg_chunks_mut!(xi, chunk_size).for_each(|cxi| {
             let (lo, hi) = cxi.split_at_mut(gap);
             // If the chunk is sufficiently big that parallelism helps,
@@ -620,6 +675,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
             Self::apply_butterfly(
                 Self::butterfly_fn_io,
+                Self::batched_butterfly_fn_io,
                 xi,
                 &roots[..],
                 step,
@@ -665,7 +721,17 @@ impl<F: FftField> EvaluationDomain<F> {
                 (roots_cache, num_chunks)
             };
 
-            Self::apply_butterfly(Self::butterfly_fn_oi, xi, roots, step, chunk_size, num_chunks, max_threads, gap);
+            Self::apply_butterfly(
+                Self::butterfly_fn_oi,
+                Self::batched_butterfly_fn_oi,
+                xi,
+                roots,
+                step,
+                chunk_size,
+                num_chunks,
+                max_threads,
+                gap,
+            );
 
             gap *= 2;
         }
@@ -680,6 +746,11 @@ const MIN_NUM_CHUNKS_FOR_COMPACTION: usize = 1 << 7;
 /// beneficial. This value was chosen empirically.
 const MIN_GAP_SIZE_FOR_PARALLELISATION: usize = 1 << 10;
 
+/// The minimum size of a chunk at which batching the multiplications of
+/// `butterfly`s is beneficial.
+#[cfg(feature = "simd")]
+const MIN_GAP_SIZE_FOR_BATCHING: usize = 1 << 4;
+
 // minimum size at which to parallelize.
 #[cfg(feature = "parallel")]
 const LOG_ROOTS_OF_UNITY_PARALLEL_SIZE: u32 = 7;
@@ -791,6 +862,11 @@ impl<F: FftField> FFTPrecomputation<F> {
         IFFTPrecomputation { inverse_roots, domain: self.domain }
     }
 
+    /// Returns the number of bytes allocated on the heap for the precomputed roots.
+    pub fn heap_size(&self) -> usize {
+        self.roots.capacity() * core::mem::size_of::<F>()
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { roots: vec![], domain: *domain }));
@@ -815,6 +891,11 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns the number of bytes allocated on the heap for the precomputed inverse roots.
+    pub fn heap_size(&self) -> usize {
+        self.inverse_roots.capacity() * core::mem::size_of::<F>()
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
@@ -33,7 +33,8 @@ use snarkvm_fields::FftField;
 
 /// Types that can be FFT-ed must implement this trait.
 pub trait DomainCoeff<F: FftField>:
-    Copy
+    'static
+    + Copy
     + Send
     + Sync
     + core::ops::Add<Output = Self>
@@ -48,7 +49,8 @@ pub trait DomainCoeff<F: FftField>:
 impl<T, F> DomainCoeff<F> for T
 where
     F: FftField,
-    T: Copy
+    T: 'static
+        + Copy
         + Send
         + Sync
         + snarkvm_fields::Zero
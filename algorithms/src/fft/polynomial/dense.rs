@@ -18,7 +18,7 @@
 
 use crate::fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_iter_mut, serialize::*, Zeroize};
 
 use rand::Rng;
 use std::{
@@ -35,13 +35,67 @@ use rayon::prelude::*;
 use super::PolyMultiplier;
 
 /// Stores a polynomial in coefficient form.
-#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
 #[must_use]
 pub struct DensePolynomial<F: Field> {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
     pub coeffs: Vec<F>,
 }
 
+impl<F: Field> CanonicalSerialize for DensePolynomial<F> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        serialize_vec_par(&self.coeffs, SerializationMode::Compressed, writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.coeffs.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        serialize_vec_par(&self.coeffs, SerializationMode::Uncompressed, writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.coeffs.uncompressed_size()
+    }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        serialize_vec_par(&self.coeffs, SerializationMode::Compact, writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.coeffs.compact_size()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for DensePolynomial<F> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self { coeffs: deserialize_vec_par(reader, SerializationMode::Compressed)? })
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self { coeffs: deserialize_vec_par(reader, SerializationMode::Uncompressed)? })
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self { coeffs: deserialize_vec_unchecked_par(reader)? })
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self { coeffs: deserialize_vec_par(reader, SerializationMode::Compact)? })
+    }
+}
+
 impl<F: Field> fmt::Debug for DensePolynomial<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         for (i, coeff) in self.coeffs.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
@@ -457,6 +511,13 @@ impl<F: Field> DerefMut for DensePolynomial<F> {
     }
 }
 
+impl<F: Field + Zeroize> Zeroize for DensePolynomial<F> {
+    /// Overwrites the coefficients with zeros, and sets the polynomial to zero.
+    fn zeroize(&mut self) {
+        self.coeffs.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::polynomial::*;
@@ -18,7 +18,7 @@
 
 use crate::fft::{EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{serialize::*, SerializationError};
+use snarkvm_utilities::{serialize::*, SerializationError, Zeroize};
 
 use std::{borrow::Cow, convert::TryInto};
 
@@ -77,6 +77,31 @@ impl<'a, F: Field> CanonicalSerialize for DenseOrSparsePolynomial<'a, F> {
     fn uncompressed_size(&self) -> usize {
         self.serialized_size()
     }
+
+    #[allow(unused_mut, unused_variables)]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        match self {
+            SPolynomial(p) => {
+                let p: DensePolynomial<F> = p.to_owned().into_owned().into();
+                CanonicalSerialize::serialize_compact(&p.coeffs, writer)?;
+            }
+            DPolynomial(p) => {
+                CanonicalSerialize::serialize_compact(&p.coeffs, writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(unused_mut, unused_variables)]
+    fn compact_size(&self) -> usize {
+        match self {
+            SPolynomial(p) => {
+                let p: DensePolynomial<F> = p.to_owned().into_owned().into();
+                p.compact_size()
+            }
+            DPolynomial(p) => p.compact_size(),
+        }
+    }
 }
 impl<'a, F: Field> CanonicalDeserialize for DenseOrSparsePolynomial<'a, F> {
     #[allow(unused_mut, unused_variables)]
@@ -88,6 +113,11 @@ impl<'a, F: Field> CanonicalDeserialize for DenseOrSparsePolynomial<'a, F> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Self::deserialize(reader)
     }
+
+    #[allow(unused_mut, unused_variables)]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        CanonicalDeserialize::deserialize_compact(reader).map(Self::DPolynomial)
+    }
 }
 
 impl<F: Field> From<DensePolynomial<F>> for DenseOrSparsePolynomial<'_, F> {
@@ -135,6 +165,18 @@ impl<F: Field> TryInto<SparsePolynomial<F>> for DenseOrSparsePolynomial<'_, F> {
     }
 }
 
+impl<F: Field + Zeroize> Zeroize for DenseOrSparsePolynomial<'_, F> {
+    /// Overwrites the polynomial with zeros, if it is owned.
+    /// Borrowed polynomials are left to be wiped by their owner.
+    fn zeroize(&mut self) {
+        match self {
+            SPolynomial(Cow::Owned(p)) => p.zeroize(),
+            DPolynomial(Cow::Owned(p)) => p.zeroize(),
+            _ => (),
+        }
+    }
+}
+
 impl<'a, F: Field> DenseOrSparsePolynomial<'a, F> {
     /// Checks if the given polynomial is zero.
     pub fn is_zero(&self) -> bool {
@@ -18,7 +18,7 @@
 
 use crate::fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::serialize::*;
+use snarkvm_utilities::{serialize::*, Zeroize};
 
 use std::{collections::BTreeMap, fmt};
 
@@ -171,6 +171,14 @@ impl<'a, F: PrimeField> core::ops::AddAssign<(F, &'a Self)> for SparsePolynomial
     }
 }
 
+impl<F: Field + Zeroize> Zeroize for SparsePolynomial<F> {
+    /// Overwrites the coefficients with zeros, and sets the polynomial to zero.
+    fn zeroize(&mut self) {
+        self.coeffs.values_mut().for_each(Zeroize::zeroize);
+        self.coeffs.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::ToBytes;
+
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Stores the hashes needed to compute the root from a set of leaves, in a single proof.
+///
+/// Starting from the leaf level, the nodes known to the verifier at each level are the parents
+/// of the nodes known at the level below. For each known node whose sibling is not known,
+/// in order of level and then position, `siblings` stores the hash of its sibling.
+/// The nodes shared by the paths of several leaves are thus only stored once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleBatchPath<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    /// The indices of the leaves, in the order the leaves are given to `verify`.
+    pub leaf_indices: Vec<u64>,
+    /// The hashes of the siblings that cannot be computed from the leaves, from bottom to top.
+    pub siblings: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> MerkleBatchPath<P> {
+    /// Returns a batch path from the given Merkle paths, which must be for distinct leaves of the same tree.
+    pub fn from_paths(paths: &[MerklePath<P>]) -> Result<Self, MerkleError> {
+        let parameters = match paths.first() {
+            Some(path) => path.parameters.clone(),
+            None => return Err(MerkleError::Message("A batch path requires at least one path".into())),
+        };
+
+        // Map each leaf index to its path.
+        let mut known = BTreeMap::new();
+        for (i, path) in paths.iter().enumerate() {
+            if path.path.len() != P::DEPTH {
+                return Err(MerkleError::IncorrectPathLength(path.path.len()));
+            }
+            if known.insert(path.leaf_index, i).is_some() {
+                return Err(MerkleError::Message(format!("Leaf {} is repeated in the batch path", path.leaf_index)));
+            }
+        }
+
+        // Keep the siblings which are not on the path of another leaf.
+        let mut siblings = Vec::new();
+        for level in 0..P::DEPTH {
+            let mut parents = BTreeMap::new();
+            for (&position, &i) in &known {
+                if !known.contains_key(&(position ^ 1)) {
+                    siblings.push(paths[i].path[level]);
+                }
+                parents.entry(position >> 1).or_insert(i);
+            }
+            known = parents;
+        }
+
+        Ok(Self { parameters, leaf_indices: paths.iter().map(|path| path.leaf_index).collect(), siblings })
+    }
+
+    /// Returns `true` if the given leaves are at the leaf indices of the tree with the given root hash.
+    pub fn verify<L: ToBytes>(&self, root_hash: &MerkleTreeDigest<P>, leaves: &[L]) -> Result<bool, MerkleError> {
+        // Check that there is one leaf per leaf index, and that each leaf index is in the tree.
+        if leaves.is_empty()
+            || leaves.len() != self.leaf_indices.len()
+            || self.leaf_indices.iter().any(|index| index.checked_shr(P::DEPTH as u32).unwrap_or(0) != 0)
+        {
+            return Ok(false);
+        }
+
+        let mut known = BTreeMap::new();
+        for (index, leaf) in self.leaf_indices.iter().zip(leaves) {
+            if known.insert(*index, self.parameters.hash_leaf(leaf)?).is_some() {
+                return Ok(false);
+            }
+        }
+
+        let mut siblings = self.siblings.iter();
+        for _ in 0..P::DEPTH {
+            let mut parents = BTreeMap::new();
+            for (&position, hash) in &known {
+                // A right child is hashed together with its left sibling, if the left sibling is known.
+                if position & 1 == 1 && known.contains_key(&(position - 1)) {
+                    continue;
+                }
+
+                let sibling = match known.get(&(position ^ 1)).or_else(|| siblings.next()) {
+                    Some(sibling) => sibling,
+                    None => return Ok(false),
+                };
+
+                let (left, right) = if position & 1 == 0 { (hash, sibling) } else { (sibling, hash) };
+                parents.insert(position >> 1, self.parameters.hash_inner_node(left, right)?);
+            }
+            known = parents;
+        }
+
+        // Check that every sibling was used, and that the final hash is the root.
+        Ok(siblings.next().is_none() && known.get(&0) == Some(root_hash))
+    }
+}
@@ -49,30 +49,43 @@ impl<P: MerkleParameters> MerklePath<P> {
         if self.path.len() == P::DEPTH {
             let claimed_leaf_hash = self.parameters.hash_leaf::<L>(leaf)?;
 
-            let mut index = self.leaf_index;
-            let mut curr_path_node = claimed_leaf_hash;
-
-            // Check levels between leaf level and root.
-            for level in 0..self.path.len() {
-                // Check if path node at this level is left or right.
-                let (left_bytes, right_bytes) =
-                    Self::select_left_right_bytes(index, &curr_path_node, &self.path[level])?;
-                // Update the current path node.
-                curr_path_node = self.parameters.hash_inner_node(&left_bytes, &right_bytes)?;
-                index >>= 1;
-            }
-
             // Check if final hash is root
-            if &curr_path_node != root_hash {
-                return Ok(false);
-            }
+            Ok(&self.compute_root(claimed_leaf_hash)? == root_hash)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Returns `true` if the path is valid for an empty leaf, under the given root hash.
+    pub fn verify_empty(&self, root_hash: &MerkleTreeDigest<P>) -> Result<bool, MerkleError> {
+        // Check that the path has the expected depth.
+        if self.path.len() == P::DEPTH {
+            let empty_hash = self.parameters.hash_empty()?;
 
-            Ok(true)
+            // Check if final hash is root
+            Ok(&self.compute_root(empty_hash)? == root_hash)
         } else {
             Ok(false)
         }
     }
 
+    /// Returns the root hash, computed from the given leaf hash and the path.
+    fn compute_root(&self, leaf_hash: MerkleTreeDigest<P>) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let mut index = self.leaf_index;
+        let mut curr_path_node = leaf_hash;
+
+        // Check levels between leaf level and root.
+        for level in 0..self.path.len() {
+            // Check if path node at this level is left or right.
+            let (left_bytes, right_bytes) = Self::select_left_right_bytes(index, &curr_path_node, &self.path[level])?;
+            // Update the current path node.
+            curr_path_node = self.parameters.hash_inner_node(&left_bytes, &right_bytes)?;
+            index >>= 1;
+        }
+
+        Ok(curr_path_node)
+    }
+
     /// Convert `computed_hash` and `sibling_hash` to bytes. `index` is the first `path.len()` bits of
     /// the position of tree.
     ///
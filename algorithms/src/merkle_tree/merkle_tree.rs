@@ -20,11 +20,17 @@ use crate::{
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::ToBytes;
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The number of leaves that are hashed together in parallel, when constructing a Merkle tree from an iterator.
+pub const LEAF_BATCH_SIZE: usize = 1024;
+
 #[derive(Default)]
 pub struct MerkleTree<P: MerkleParameters> {
     /// The computed root of the full Merkle tree.
@@ -40,6 +46,18 @@ pub struct MerkleTree<P: MerkleParameters> {
     parameters: Arc<P>,
 }
 
+impl<P: MerkleParameters> Clone for MerkleTree<P> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            tree: self.tree.clone(),
+            hashed_leaves_index: self.hashed_leaves_index,
+            padding_tree: self.padding_tree.clone(),
+            parameters: self.parameters.clone(),
+        }
+    }
+}
+
 impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
     pub const DEPTH: usize = P::DEPTH;
 
@@ -47,6 +65,50 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         let new_time = start_timer!(|| "MerkleTree::new");
 
         let last_level_size = leaves.len().next_power_of_two();
+        let tree_depth = tree_depth(2 * last_level_size - 1);
+        if tree_depth > Self::DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(tree_depth, Self::DEPTH));
+        }
+
+        // Compute the hash values for each leaf.
+        let hashed_leaves = Self::hash_row(&*parameters, leaves)?;
+        let tree = Self::from_hashed_leaves(parameters, hashed_leaves);
+
+        end_timer!(new_time);
+        tree
+    }
+
+    /// Returns a new Merkle tree from the given iterator of leaves.
+    ///
+    /// The leaves are consumed in batches of `LEAF_BATCH_SIZE`, and each batch is hashed in parallel,
+    /// such that only the hashes of the leaves are retained, and not the leaves themselves.
+    pub fn new_from_iter<L: ToBytes + Send + Sync, I: IntoIterator<Item = L>>(
+        parameters: Arc<P>,
+        leaves: I,
+    ) -> Result<Self, MerkleError> {
+        let new_time = start_timer!(|| "MerkleTree::new_from_iter");
+
+        // Compute the hash values for each batch of leaves.
+        let mut hashed_leaves = Vec::new();
+        let mut batch = Vec::with_capacity(LEAF_BATCH_SIZE);
+        for leaf in leaves {
+            batch.push(leaf);
+            if batch.len() == LEAF_BATCH_SIZE {
+                hashed_leaves.extend(Self::hash_row(&*parameters, &batch)?);
+                batch.clear();
+            }
+        }
+        hashed_leaves.extend(Self::hash_row(&*parameters, &batch)?);
+
+        let tree = Self::from_hashed_leaves(parameters, hashed_leaves);
+
+        end_timer!(new_time);
+        tree
+    }
+
+    /// Returns a new Merkle tree from the given hashes of its leaves, hashing each level of the tree in parallel.
+    fn from_hashed_leaves(parameters: Arc<P>, hashed_leaves: Vec<MerkleTreeDigest<P>>) -> Result<Self, MerkleError> {
+        let last_level_size = hashed_leaves.len().next_power_of_two();
         let tree_size = 2 * last_level_size - 1;
         let tree_depth = tree_depth(tree_size);
 
@@ -66,35 +128,26 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             index = left_child(index);
         }
 
-        // Compute and store the hash values for each leaf.
+        // Store the hash values for each leaf.
         let last_level_index = level_indices.pop().unwrap_or(0);
+        tree[last_level_index..][..hashed_leaves.len()].copy_from_slice(&hashed_leaves);
 
-        let subsections = Self::hash_row(&*parameters, leaves)?;
-
-        let mut subsection_index = 0;
-        for subsection in subsections.into_iter() {
-            tree[last_level_index + subsection_index..last_level_index + subsection_index + subsection.len()]
-                .copy_from_slice(&subsection[..]);
-            subsection_index += subsection.len();
-        }
-
-        // Compute the hash values for every node in the tree.
+        // Compute the hash values for every node in the tree, in parallel for each level.
         let mut upper_bound = last_level_index;
-        level_indices.reverse();
-        for &start_index in &level_indices {
-            // Iterate over the current level.
-            let hashings =
-                (start_index..upper_bound).map(|i| (&tree[left_child(i)], &tree[right_child(i)])).collect::<Vec<_>>();
-
-            let hashes = Self::hash_row(&*parameters, &hashings[..])?;
-
-            let mut subsection_index = 0;
-            for subsection in hashes.into_iter() {
-                tree[start_index + subsection_index..start_index + subsection_index + subsection.len()]
-                    .copy_from_slice(&subsection[..]);
-                subsection_index += subsection.len();
-            }
+        for start_index in level_indices.into_iter().rev() {
+            let (parents, children) = tree.split_at_mut(upper_bound);
 
+            // Iterate over the current level.
+            crate::cfg_iter_mut!(parents[start_index..upper_bound]).zip(start_index..upper_bound).try_for_each(
+                |(parent, current_index)| {
+                    // Compute Hash(left || right).
+                    *parent = parameters.hash_inner_node(
+                        &children[left_child(current_index) - upper_bound],
+                        &children[right_child(current_index) - upper_bound],
+                    )?;
+                    Ok::<(), MerkleError>(())
+                },
+            )?;
             upper_bound = start_index;
         }
 
@@ -114,8 +167,6 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         }
         let root_hash = current_hash;
 
-        end_timer!(new_time);
-
         Ok(MerkleTree { tree, padding_tree, hashed_leaves_index: last_level_index, parameters, root: root_hash })
     }
 
@@ -152,12 +203,8 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         tree[last_level_index..][..start_index].clone_from_slice(&self.hashed_leaves()[..start_index]);
 
         // The new leaves require hashing.
-        let subsections = Self::hash_row(&*self.parameters, new_leaves)?;
-
-        for (i, subsection) in subsections.into_iter().enumerate() {
-            tree[last_level_index + start_index + i..last_level_index + start_index + i + subsection.len()]
-                .copy_from_slice(&subsection[..]);
-        }
+        let hashed_leaves = Self::hash_row(&*self.parameters, new_leaves)?;
+        tree[last_level_index + start_index..][..hashed_leaves.len()].copy_from_slice(&hashed_leaves);
 
         // Compute the hash values for every node in the tree.
         let mut upper_bound = last_level_index;
@@ -234,6 +281,116 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         })
     }
 
+    /// Replaces the leaf at the given index, and recomputes the path from the leaf to the root.
+    pub fn update<L: ToBytes>(&mut self, index: usize, leaf: &L) -> Result<(), MerkleError> {
+        let tree_depth = tree_depth(self.tree.len());
+        let tree_index = convert_index_to_last_level(index, tree_depth)?;
+
+        if tree_index >= self.tree.len() {
+            return Err(MerkleError::IncorrectLeafIndex(tree_index));
+        }
+
+        // Compute the new hashes from the leaf up to the root of the full tree.
+        let mut current_node = tree_index;
+        let mut current_hash = self.parameters.hash_leaf(leaf)?;
+        let mut updates = vec![(current_node, current_hash)];
+        while let Some(parent_node) = parent(current_node) {
+            let sibling_hash = &self.tree[sibling(current_node).unwrap()];
+            current_hash = match is_left_child(current_node) {
+                true => self.parameters.hash_inner_node(&current_hash, sibling_hash)?,
+                false => self.parameters.hash_inner_node(sibling_hash, &current_hash)?,
+            };
+            updates.push((parent_node, current_hash));
+            current_node = parent_node;
+        }
+
+        // Recompute the dummy nodes until we hit our DEPTH goal.
+        let (padding_tree, root) = self.compute_padding_tree(tree_depth, current_hash)?;
+
+        // update the values at the very end so the tree is not altered in case of failure
+        for (node, hash) in updates {
+            self.tree[node] = hash;
+        }
+        self.padding_tree = padding_tree;
+        self.root = root;
+        Ok(())
+    }
+
+    /// Replaces the leaves at the given indices, and recomputes each affected node once, one level at a time.
+    /// If an index is given more than once, the last leaf for the index is used.
+    pub fn update_many<L: ToBytes + Send + Sync>(&mut self, leaves: &[(usize, L)]) -> Result<(), MerkleError> {
+        if leaves.is_empty() {
+            return Ok(());
+        }
+
+        let tree_depth = tree_depth(self.tree.len());
+
+        // Compute the new hashes of the leaves.
+        let hashed_leaves = crate::cfg_iter!(leaves)
+            .map(|(index, leaf)| {
+                let tree_index = convert_index_to_last_level(*index, tree_depth)?;
+                if tree_index >= self.tree.len() {
+                    return Err(MerkleError::IncorrectLeafIndex(tree_index));
+                }
+                Ok((tree_index, self.parameters.hash_leaf(leaf)?))
+            })
+            .collect::<Result<Vec<_>, MerkleError>>()?;
+
+        // Compute the new hashes of the affected nodes on each level, from the leaves up to the root of the full tree.
+        // Note: As the level is a map, a later leaf for the same index replaces an earlier one.
+        let mut level = hashed_leaves.into_iter().collect::<BTreeMap<_, _>>();
+        let mut updates = Vec::with_capacity(level.len() * (tree_depth + 1));
+        while !level.contains_key(&0) {
+            // Find the parents of the affected nodes, hashing each parent once.
+            let parents = level.keys().filter_map(|node| parent(*node)).collect::<BTreeSet<_>>();
+            let hashes = crate::cfg_into_iter!(parents.into_iter().collect::<Vec<_>>())
+                .map(|parent| {
+                    let (left_index, right_index) = (left_child(parent), right_child(parent));
+                    let left = level.get(&left_index).unwrap_or(&self.tree[left_index]);
+                    let right = level.get(&right_index).unwrap_or(&self.tree[right_index]);
+                    Ok((parent, self.parameters.hash_inner_node(left, right)?))
+                })
+                .collect::<Result<Vec<_>, MerkleError>>()?;
+
+            updates.extend(level);
+            level = hashes.into_iter().collect();
+        }
+        let current_hash = level[&0];
+        updates.extend(level);
+
+        // Recompute the dummy nodes until we hit our DEPTH goal.
+        let (padding_tree, root) = self.compute_padding_tree(tree_depth, current_hash)?;
+
+        // update the values at the very end so the tree is not altered in case of failure
+        for (node, hash) in updates {
+            self.tree[node] = hash;
+        }
+        self.padding_tree = padding_tree;
+        self.root = root;
+        Ok(())
+    }
+
+    /// Returns the padding tree and the root, given the depth and the root of the full tree.
+    fn compute_padding_tree(
+        &self,
+        tree_depth: usize,
+        mut current_hash: MerkleTreeDigest<P>,
+    ) -> Result<(Vec<(MerkleTreeDigest<P>, MerkleTreeDigest<P>)>, MerkleTreeDigest<P>), MerkleError> {
+        let empty_hash = self.parameters.hash_empty()?;
+        let mut current_depth = tree_depth;
+        let mut padding_tree = Vec::with_capacity(self.padding_tree.len());
+        while current_depth < Self::DEPTH {
+            current_hash = self.parameters.hash_inner_node(&current_hash, &empty_hash)?;
+
+            // do not pad at the top-level of the tree
+            if current_depth < Self::DEPTH - 1 {
+                padding_tree.push((current_hash, empty_hash));
+            }
+            current_depth += 1;
+        }
+        Ok((padding_tree, current_hash))
+    }
+
     #[inline]
     pub fn root(&self) -> &<P::H as CRH>::Output {
         &self.root
@@ -293,16 +450,12 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         }
     }
 
+    /// Returns the hash of each of the given leaves, hashing the leaves in parallel.
     fn hash_row<L: ToBytes + Send + Sync>(
         parameters: &P,
         leaves: &[L],
-    ) -> Result<Vec<Vec<<<P as MerkleParameters>::H as CRH>::Output>>, MerkleError> {
-        match leaves.len() {
-            0 => Ok(vec![]),
-            _ => {
-                Ok(vec![crate::cfg_iter!(leaves).map(|leaf| parameters.hash_leaf(&leaf).unwrap()).collect::<Vec<_>>()])
-            }
-        }
+    ) -> Result<Vec<MerkleTreeDigest<P>>, MerkleError> {
+        crate::cfg_iter!(leaves).map(|leaf| parameters.hash_leaf(leaf)).collect()
     }
 }
 
@@ -17,6 +17,9 @@
 pub mod masked_merkle_tree_parameters;
 pub use masked_merkle_tree_parameters::*;
 
+pub mod merkle_batch_path;
+pub use merkle_batch_path::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
@@ -26,5 +29,17 @@ pub use merkle_tree::*;
 pub mod merkle_tree_parameters;
 pub use merkle_tree_parameters::*;
 
+pub mod sparse_merkle_map;
+pub use sparse_merkle_map::*;
+
+pub mod sparse_merkle_path;
+pub use sparse_merkle_path::*;
+
+pub mod sparse_merkle_tree;
+pub use sparse_merkle_tree::*;
+
+pub mod vector_commitment;
+pub use vector_commitment::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerkleTreeDigest, SparseMerklePath, SparseMerkleTree},
+    traits::MerkleParameters,
+};
+use snarkvm_fields::PrimeField;
+
+use std::{collections::HashMap, sync::Arc};
+
+/// A key-value mapping of field elements, committed to by the root of a sparse Merkle tree.
+///
+/// Each entry is stored at the leaf indexed by its key, as in `SparseMerkleTree`,
+/// and the leaf hash is the hash of the key and value. Empty leaves hash to `P::hash_empty()`.
+///
+/// The Merkle path of a key proves the value of the key (or its absence) under the current root.
+/// As a write only changes the leaf of the key, the same path also proves the root after the write,
+/// from which the transition of the root is checked by `verify_update`.
+pub struct SparseMerkleMap<P: MerkleParameters, F: PrimeField> {
+    /// The entries in the map.
+    entries: HashMap<F, F>,
+    /// The nodes of the map. Note: The keys of this tree are unused.
+    tree: SparseMerkleTree<P, F>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters, F: PrimeField> SparseMerkleMap<P, F> {
+    /// Returns a new, empty sparse Merkle map.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        Ok(Self { entries: Default::default(), tree: SparseMerkleTree::new(parameters.clone())?, parameters })
+    }
+
+    /// Returns the root of the map.
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        self.tree.root()
+    }
+
+    /// Returns the number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value of the given key, if it is in the map.
+    pub fn get(&self, key: &F) -> Option<&F> {
+        self.entries.get(key)
+    }
+
+    /// Sets the value of the given key, and updates the path from its leaf to the root.
+    /// Returns the previous value of the key, if it was in the map.
+    pub fn insert(&mut self, key: F, value: F) -> Result<Option<F>, MerkleError> {
+        let leaf_hash = self.parameters.hash_leaf(&(key, value))?;
+        self.tree.update(&key, leaf_hash)?;
+        Ok(self.entries.insert(key, value))
+    }
+
+    /// Removes the given key from the map, and updates the path from its leaf to the root.
+    /// Returns the previous value of the key, if it was in the map.
+    pub fn remove(&mut self, key: &F) -> Result<Option<F>, MerkleError> {
+        if self.get(key).is_none() {
+            return Ok(None);
+        }
+
+        self.tree.update(key, self.tree.empty_leaf_hash())?;
+        Ok(self.entries.remove(key))
+    }
+
+    /// Returns a Merkle path proving the value of the given key, or its absence, under the current root.
+    pub fn prove(&self, key: &F) -> SparseMerklePath<P> {
+        self.tree.path(key)
+    }
+
+    /// Returns `true` if the given path proves the given key has the given value, under the given root.
+    /// If the value is `None`, returns `true` if the given path proves the given key is not in the map.
+    pub fn verify(
+        root: &MerkleTreeDigest<P>,
+        key: &F,
+        value: Option<&F>,
+        path: &SparseMerklePath<P>,
+    ) -> Result<bool, MerkleError> {
+        let leaf_hash = match value {
+            Some(value) => path.parameters.hash_leaf(&(*key, *value))?,
+            None => path.parameters.hash_empty()?,
+        };
+        path.verify_leaf_hash(root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the given path proves that setting the given key from `old_value` to `new_value`
+    /// changes the root from `old_root` to `new_root`. An `old_value` of `None` is an insertion.
+    pub fn verify_update(
+        old_root: &MerkleTreeDigest<P>,
+        new_root: &MerkleTreeDigest<P>,
+        key: &F,
+        old_value: Option<&F>,
+        new_value: &F,
+        path: &SparseMerklePath<P>,
+    ) -> Result<bool, MerkleError> {
+        Ok(Self::verify(old_root, key, old_value, path)? && Self::verify(new_root, key, Some(new_value), path)?)
+    }
+}
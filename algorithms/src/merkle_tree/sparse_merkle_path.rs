@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::MerkleError, merkle_tree::MerkleTreeDigest, traits::MerkleParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger;
+
+use std::sync::Arc;
+
+/// A Merkle path from the leaf of a key to the root of a sparse Merkle tree.
+///
+/// The position of the leaf is the key itself: at each level, the node on the path
+/// is a right child if the bit of the key at that level is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerklePath<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    /// The sibling hashes of the path, from the leaf level to the root.
+    pub path: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> SparseMerklePath<P> {
+    /// Returns `true` if the path is valid for the given leaf hash at the leaf of the given key,
+    /// under the given root hash.
+    pub fn verify_leaf_hash<F: PrimeField>(
+        &self,
+        root_hash: &MerkleTreeDigest<P>,
+        key: &F,
+        leaf_hash: MerkleTreeDigest<P>,
+    ) -> Result<bool, MerkleError> {
+        // Check that the path has one sibling per bit of the key.
+        if self.path.len() == F::size_in_bits() {
+            Ok(&self.compute_root(key, leaf_hash)? == root_hash)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Returns the root hash, computed from the given leaf hash at the leaf of the given key.
+    fn compute_root<F: PrimeField>(
+        &self,
+        key: &F,
+        leaf_hash: MerkleTreeDigest<P>,
+    ) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let key = key.to_repr();
+        self.path.iter().enumerate().try_fold(leaf_hash, |node, (level, sibling)| match key.get_bit(level) {
+            false => self.parameters.hash_inner_node(&node, sibling),
+            true => self.parameters.hash_inner_node(sibling, &node),
+        })
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerkleTreeDigest, SparseMerklePath},
+    traits::MerkleParameters,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger;
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
+
+/// A sparse Merkle tree keyed by field elements, with one leaf per field element.
+///
+/// The depth of the tree is the bit size of the field, and each key is stored at the leaf
+/// indexed by the key itself, so distinct keys never share a leaf. The leaf hash is the hash
+/// of the key, and empty leaves hash to `P::hash_empty()`. Only the non-empty nodes are stored,
+/// and an empty subtree is represented by the precomputed empty hash of its level.
+pub struct SparseMerkleTree<P: MerkleParameters, F: PrimeField> {
+    /// The keys in the tree.
+    keys: HashSet<F>,
+    /// The non-empty nodes of the tree, by `(level, index)`, where level 0 is the leaf level.
+    nodes: BTreeMap<(usize, F::BigInteger), MerkleTreeDigest<P>>,
+    /// The hash of an empty subtree at each level, where level 0 is the leaf level.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters, F: PrimeField> SparseMerkleTree<P, F> {
+    /// Returns a new, empty sparse Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        // Compute the hash of an empty subtree at each level.
+        let mut empty_hashes = Vec::with_capacity(Self::depth() + 1);
+        empty_hashes.push(parameters.hash_empty()?);
+        for level in 0..Self::depth() {
+            empty_hashes.push(parameters.hash_inner_node(&empty_hashes[level], &empty_hashes[level])?);
+        }
+
+        Ok(Self { keys: Default::default(), nodes: Default::default(), empty_hashes, parameters })
+    }
+
+    /// Returns the depth of the tree, which is the bit size of the field.
+    #[inline]
+    pub fn depth() -> usize {
+        F::size_in_bits()
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        self.node(Self::depth(), &F::BigInteger::default())
+    }
+
+    /// Returns the number of keys in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the tree contains no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns `true` if the given key is in the tree.
+    pub fn contains(&self, key: &F) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Inserts the given key into the tree, and updates the path from its leaf to the root.
+    /// Returns `false` if the key is already in the tree.
+    pub fn insert(&mut self, key: F) -> Result<bool, MerkleError> {
+        if self.contains(&key) {
+            return Ok(false);
+        }
+
+        let leaf_hash = self.parameters.hash_leaf(&key)?;
+        self.update(&key, leaf_hash)?;
+        self.keys.insert(key);
+        Ok(true)
+    }
+
+    /// Removes the given key from the tree, and updates the path from its leaf to the root.
+    /// Returns `false` if the key is not in the tree.
+    pub fn remove(&mut self, key: &F) -> Result<bool, MerkleError> {
+        if !self.contains(key) {
+            return Ok(false);
+        }
+
+        self.update(key, self.empty_leaf_hash())?;
+        self.keys.remove(key);
+        Ok(true)
+    }
+
+    /// Returns a Merkle path proving the given key is in the tree.
+    pub fn prove_membership(&self, key: &F) -> Result<SparseMerklePath<P>, MerkleError> {
+        match self.contains(key) {
+            true => Ok(self.path(key)),
+            false => Err(MerkleError::MissingLeaf(key.to_string())),
+        }
+    }
+
+    /// Returns a Merkle path proving the given key is not in the tree, as its leaf is empty.
+    pub fn prove_non_membership(&self, key: &F) -> Result<SparseMerklePath<P>, MerkleError> {
+        match self.contains(key) {
+            true => Err(MerkleError::Message(format!("Key {key} is in the tree"))),
+            false => Ok(self.path(key)),
+        }
+    }
+
+    /// Returns `true` if the given path proves the given key is in the tree with the given root.
+    pub fn verify_membership(
+        root: &MerkleTreeDigest<P>,
+        key: &F,
+        path: &SparseMerklePath<P>,
+    ) -> Result<bool, MerkleError> {
+        path.verify_leaf_hash(root, key, path.parameters.hash_leaf(key)?)
+    }
+
+    /// Returns `true` if the given path proves the given key is not in the tree with the given root.
+    pub fn verify_non_membership(
+        root: &MerkleTreeDigest<P>,
+        key: &F,
+        path: &SparseMerklePath<P>,
+    ) -> Result<bool, MerkleError> {
+        path.verify_leaf_hash(root, key, path.parameters.hash_empty()?)
+    }
+
+    /// Returns the node at the given level and index, or the empty hash for the level if it is not stored.
+    fn node(&self, level: usize, index: &F::BigInteger) -> &MerkleTreeDigest<P> {
+        self.nodes.get(&(level, *index)).unwrap_or(&self.empty_hashes[level])
+    }
+
+    /// Returns the hash of an empty leaf.
+    pub(super) fn empty_leaf_hash(&self) -> MerkleTreeDigest<P> {
+        self.empty_hashes[0]
+    }
+
+    /// Returns the Merkle path for the leaf of the given key.
+    pub(super) fn path(&self, key: &F) -> SparseMerklePath<P> {
+        let path = (0..Self::depth()).map(|level| *self.node(level, &Self::sibling_index(key, level))).collect();
+        SparseMerklePath { parameters: self.parameters.clone(), path }
+    }
+
+    /// Sets the leaf of the given key to the given hash, and recomputes the path to the root.
+    /// Nodes equal to the empty hash of their level are pruned.
+    pub(super) fn update(&mut self, key: &F, leaf_hash: MerkleTreeDigest<P>) -> Result<(), MerkleError> {
+        let mut current = leaf_hash;
+        for level in 0..=Self::depth() {
+            let node_index = Self::node_index(key, level);
+
+            // Store the current node, or prune it if it is empty.
+            match current == self.empty_hashes[level] {
+                true => self.nodes.remove(&(level, node_index)),
+                false => self.nodes.insert((level, node_index), current),
+            };
+
+            // Compute the parent node.
+            if level < Self::depth() {
+                let sibling = self.node(level, &Self::sibling_index(key, level));
+                current = match node_index.is_even() {
+                    true => self.parameters.hash_inner_node(&current, sibling)?,
+                    false => self.parameters.hash_inner_node(sibling, &current)?,
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the node at the given level on the path of the given key.
+    fn node_index(key: &F, level: usize) -> F::BigInteger {
+        let mut index = key.to_repr();
+        index.divn(level as u32);
+        index
+    }
+
+    /// Returns the index of the sibling of the node at the given level on the path of the given key.
+    fn sibling_index(key: &F, level: usize) -> F::BigInteger {
+        let mut index = Self::node_index(key, level);
+        index.as_mut()[0] ^= 1;
+        index
+    }
+}
@@ -16,9 +16,18 @@
 
 use crate::{
     crh::{PedersenCRH, PedersenCompressedCRH},
-    merkle_tree::{MerklePath, MerkleTree, MerkleTreeParameters},
+    merkle_tree::{
+        LEAF_BATCH_SIZE,
+        MerklePath,
+        MerkleTree,
+        MerkleTreeParameters,
+        MerkleVectorCommitment,
+        SparseMerkleMap,
+        SparseMerkleTree,
+    },
     traits::{MerkleParameters, CRH},
 };
+use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
 use rand::{thread_rng, Rng};
@@ -207,6 +216,229 @@ fn run_merkle_path_bincode_test<P: MerkleParameters>() {
     merkle_path_bincode_test::<P, _>(&leaves, parameters);
 }
 
+fn run_sparse_merkle_tree_test<P: MerkleParameters, F: PrimeField>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    // Ensure the root of the empty tree is the root of an empty Merkle tree of the same depth.
+    let mut tree = SparseMerkleTree::<P, F>::new(parameters.clone()).unwrap();
+    let empty_root = *tree.root();
+    let mut expected_root = parameters.hash_empty().unwrap();
+    for _ in 0..F::size_in_bits() {
+        expected_root = parameters.hash_inner_node(&expected_root, &expected_root).unwrap();
+    }
+    assert_eq!(expected_root, empty_root);
+
+    // Insert the keys, and check their membership proofs.
+    let keys = (0..16).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+    for key in &keys {
+        assert!(tree.insert(*key).unwrap());
+        assert!(!tree.insert(*key).unwrap());
+    }
+    assert_eq!(keys.len(), tree.len());
+    for key in &keys {
+        let path = tree.prove_membership(key).unwrap();
+        assert_eq!(F::size_in_bits(), path.path.len());
+        assert!(SparseMerkleTree::<P, F>::verify_membership(tree.root(), key, &path).unwrap());
+        assert!(!SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), key, &path).unwrap());
+        assert!(tree.prove_non_membership(key).is_err());
+    }
+
+    // Check the non-membership proofs of keys not in the tree.
+    for _ in 0..16 {
+        let key = F::rand(&mut rng);
+        let path = tree.prove_non_membership(&key).unwrap();
+        assert!(SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), &key, &path).unwrap());
+        assert!(!SparseMerkleTree::<P, F>::verify_membership(tree.root(), &key, &path).unwrap());
+        assert!(!SparseMerkleTree::<P, F>::verify_non_membership(&empty_root, &key, &path).unwrap());
+        assert!(tree.prove_membership(&key).is_err());
+    }
+
+    // Ensure the root is independent of the order of insertion.
+    let mut reversed_tree = SparseMerkleTree::<P, F>::new(parameters.clone()).unwrap();
+    for key in keys.iter().rev() {
+        reversed_tree.insert(*key).unwrap();
+    }
+    assert_eq!(tree.root(), reversed_tree.root());
+
+    // Remove the keys, and ensure the root returns to the root of the empty tree.
+    for key in &keys {
+        assert!(tree.remove(key).unwrap());
+        assert!(!tree.remove(key).unwrap());
+        assert!(!tree.contains(key));
+    }
+    assert!(tree.is_empty());
+    assert_eq!(&empty_root, tree.root());
+
+    // Ensure keys that share their `P::DEPTH` least significant bits have distinct leaves.
+    let key = F::from(rng.gen::<u32>());
+    let colliding_key = key + F::from(2u64).pow([P::DEPTH as u64]);
+    for (key, other_key) in [(key, colliding_key), (colliding_key, key)] {
+        let path = tree.prove_non_membership(&key).unwrap();
+        assert!(SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), &key, &path).unwrap());
+
+        assert!(tree.insert(key).unwrap());
+        let path = tree.prove_membership(&key).unwrap();
+        assert!(SparseMerkleTree::<P, F>::verify_membership(tree.root(), &key, &path).unwrap());
+        assert!(!SparseMerkleTree::<P, F>::verify_membership(tree.root(), &other_key, &path).unwrap());
+
+        let path = tree.prove_non_membership(&other_key).unwrap();
+        assert!(SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), &other_key, &path).unwrap());
+        assert!(!SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), &key, &path).unwrap());
+
+        assert!(tree.insert(other_key).unwrap());
+        for key in [key, other_key] {
+            let path = tree.prove_membership(&key).unwrap();
+            assert!(SparseMerkleTree::<P, F>::verify_membership(tree.root(), &key, &path).unwrap());
+            assert!(!SparseMerkleTree::<P, F>::verify_non_membership(tree.root(), &key, &path).unwrap());
+        }
+
+        assert!(tree.remove(&key).unwrap());
+        assert!(tree.remove(&other_key).unwrap());
+        assert_eq!(&empty_root, tree.root());
+    }
+}
+
+fn run_sparse_merkle_map_test<P: MerkleParameters, F: PrimeField>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    // Ensure the root of the empty map is the root of the empty sparse Merkle tree.
+    let mut map = SparseMerkleMap::<P, F>::new(parameters.clone()).unwrap();
+    let empty_root = *map.root();
+    assert_eq!(SparseMerkleTree::<P, F>::new(parameters.clone()).unwrap().root(), &empty_root);
+
+    // Insert the entries, and check their update proofs.
+    let entries = (0..16).map(|_| (F::rand(&mut rng), F::rand(&mut rng))).collect::<Vec<_>>();
+    for (key, value) in &entries {
+        let old_root = *map.root();
+        let path = map.prove(key);
+        assert!(SparseMerkleMap::<P, F>::verify(&old_root, key, None, &path).unwrap());
+
+        assert_eq!(None, map.insert(*key, *value).unwrap());
+        assert!(SparseMerkleMap::<P, F>::verify_update(&old_root, map.root(), key, None, value, &path).unwrap());
+        assert!(!SparseMerkleMap::<P, F>::verify_update(&old_root, &old_root, key, None, value, &path).unwrap());
+    }
+    assert_eq!(entries.len(), map.len());
+
+    // Check the proofs of the entries.
+    for (key, value) in &entries {
+        assert_eq!(Some(value), map.get(key));
+        let path = map.prove(key);
+        assert_eq!(F::size_in_bits(), path.path.len());
+        assert!(SparseMerkleMap::<P, F>::verify(map.root(), key, Some(value), &path).unwrap());
+        assert!(!SparseMerkleMap::<P, F>::verify(map.root(), key, Some(&F::rand(&mut rng)), &path).unwrap());
+        assert!(!SparseMerkleMap::<P, F>::verify(map.root(), key, None, &path).unwrap());
+    }
+
+    // Overwrite the value of each entry, and check the update proofs.
+    let mut updated_entries = entries.clone();
+    for (key, value) in updated_entries.iter_mut() {
+        let old_root = *map.root();
+        let path = map.prove(key);
+        let old_value = *value;
+        *value = F::rand(&mut rng);
+
+        assert_eq!(Some(old_value), map.insert(*key, *value).unwrap());
+        assert!(
+            SparseMerkleMap::<P, F>::verify_update(&old_root, map.root(), key, Some(&old_value), value, &path).unwrap()
+        );
+        assert!(!SparseMerkleMap::<P, F>::verify_update(&old_root, map.root(), key, None, value, &path).unwrap());
+    }
+
+    // Ensure the root is independent of the order of writes.
+    let mut reversed_map = SparseMerkleMap::<P, F>::new(parameters.clone()).unwrap();
+    for (key, value) in updated_entries.iter().rev() {
+        reversed_map.insert(*key, *value).unwrap();
+    }
+    assert_eq!(map.root(), reversed_map.root());
+
+    // Remove the entries, and ensure the root returns to the root of the empty map.
+    for (key, value) in &updated_entries {
+        assert_eq!(Some(*value), map.remove(key).unwrap());
+        assert_eq!(None, map.remove(key).unwrap());
+        assert_eq!(None, map.get(key));
+    }
+    assert!(map.is_empty());
+    assert_eq!(&empty_root, map.root());
+
+    // Ensure keys that share their `P::DEPTH` least significant bits have distinct entries.
+    let key = F::from(rng.gen::<u32>());
+    let colliding_key = key + F::from(2u64).pow([P::DEPTH as u64]);
+    let (value, colliding_value) = (F::rand(&mut rng), F::rand(&mut rng));
+    assert_eq!(None, map.insert(key, value).unwrap());
+
+    let path = map.prove(&colliding_key);
+    assert!(SparseMerkleMap::<P, F>::verify(map.root(), &colliding_key, None, &path).unwrap());
+    assert!(!SparseMerkleMap::<P, F>::verify(map.root(), &key, None, &path).unwrap());
+    let old_root = *map.root();
+    assert_eq!(None, map.insert(colliding_key, colliding_value).unwrap());
+    assert!(
+        SparseMerkleMap::<P, F>::verify_update(&old_root, map.root(), &colliding_key, None, &colliding_value, &path)
+            .unwrap()
+    );
+
+    for (key, value, other_value) in [(key, value, colliding_value), (colliding_key, colliding_value, value)] {
+        assert_eq!(Some(&value), map.get(&key));
+        let path = map.prove(&key);
+        assert!(SparseMerkleMap::<P, F>::verify(map.root(), &key, Some(&value), &path).unwrap());
+        assert!(!SparseMerkleMap::<P, F>::verify(map.root(), &key, Some(&other_value), &path).unwrap());
+        assert!(!SparseMerkleMap::<P, F>::verify(map.root(), &key, None, &path).unwrap());
+    }
+
+    // Ensure removing one key leaves the entry of the other.
+    assert_eq!(Some(value), map.remove(&key).unwrap());
+    assert_eq!(Some(&colliding_value), map.get(&colliding_key));
+    let path = map.prove(&key);
+    assert!(SparseMerkleMap::<P, F>::verify(map.root(), &key, None, &path).unwrap());
+    let path = map.prove(&colliding_key);
+    assert!(SparseMerkleMap::<P, F>::verify(map.root(), &colliding_key, Some(&colliding_value), &path).unwrap());
+}
+
+fn run_vector_commitment_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    let values = generate_random_leaves!(13, 8);
+    let mut commitment = MerkleVectorCommitment::<P, _>::commit(parameters.clone(), values.clone()).unwrap();
+    assert_eq!(MerkleTree::<P>::new(parameters.clone(), &values).unwrap().root(), commitment.root());
+
+    // Check the opening of each position.
+    for (i, value) in values.iter().enumerate() {
+        let opening = commitment.open(i).unwrap();
+        assert!(opening.verify(commitment.root(), value).unwrap());
+        assert!(!opening.verify(commitment.root(), &values[(i + 1) % values.len()]).unwrap());
+    }
+    assert!(commitment.open(values.len()).is_err());
+
+    // Check the batch opening of several positions, including positions with shared paths.
+    let indices = [7, 0, 1, 12, 4];
+    let opened = indices.iter().map(|i| values[*i]).collect::<Vec<_>>();
+    let batch_opening = commitment.batch_open(&indices).unwrap();
+    assert!(batch_opening.verify(commitment.root(), &opened).unwrap());
+    assert!(batch_opening.siblings.len() < indices.len() * P::DEPTH);
+    assert!(!batch_opening.verify(commitment.root(), &opened[1..]).unwrap());
+    let mut swapped = opened.clone();
+    swapped.swap(0, 1);
+    assert!(!batch_opening.verify(commitment.root(), &swapped).unwrap());
+    assert!(commitment.batch_open(&[3, 3]).is_err());
+
+    // Update each position, and ensure the commitment matches a commitment to the updated values.
+    let mut updated_values = values.clone();
+    for i in 0..values.len() {
+        let old_root = *commitment.root();
+        rng.fill(&mut updated_values[i]);
+        commitment.update(i, updated_values[i]).unwrap();
+        assert_ne!(&old_root, commitment.root());
+        assert_eq!(MerkleTree::<P>::new(parameters.clone(), &updated_values).unwrap().root(), commitment.root());
+        assert!(commitment.open(i).unwrap().verify(commitment.root(), &updated_values[i]).unwrap());
+    }
+    assert!(commitment.update(values.len(), updated_values[0]).is_err());
+
+    // Ensure an outdated batch opening fails to verify against the new commitment.
+    assert!(!batch_opening.verify(commitment.root(), &opened).unwrap());
+}
+
 mod pedersen_crh_on_projective {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsProjective as Edwards;
@@ -261,7 +493,7 @@ mod pedersen_crh_on_projective {
 mod pedersen_compressed_crh_on_projective {
     use super::*;
     use crate::merkle_tree::MerklePath;
-    use snarkvm_curves::edwards_bls12::EdwardsProjective as Edwards;
+    use snarkvm_curves::edwards_bls12::{EdwardsProjective as Edwards, Fq};
 
     const NUM_WINDOWS: usize = 256;
     const WINDOW_SIZE: usize = 4;
@@ -319,12 +551,97 @@ mod pedersen_compressed_crh_on_projective {
         assert_eq!(tree.root(), new_tree_2.root());
     }
 
+    #[test]
+    fn merkle_tree_from_iter_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        let parameters = Arc::new(MTParameters::setup("merkle_tree_test"));
+
+        for num_leaves in [0, 1, 5, LEAF_BATCH_SIZE, 2 * LEAF_BATCH_SIZE + 7] {
+            let leaves = generate_random_leaves!(num_leaves, 32);
+            let expected = MerkleTree::<MTParameters>::new(parameters.clone(), &leaves[..]).unwrap();
+
+            // Ensure the leaves are not required to be collected beforehand.
+            let candidate =
+                MerkleTree::<MTParameters>::new_from_iter(parameters.clone(), leaves.iter().copied()).unwrap();
+            assert_eq!(expected.root(), candidate.root());
+            assert_eq!(expected.tree(), candidate.tree());
+            assert_eq!(expected.hashed_leaves(), candidate.hashed_leaves());
+        }
+
+        // Ensure a tree that exceeds the depth is rejected.
+        type SmallParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 2>;
+        let leaves = generate_random_leaves!(5, 32);
+        let parameters = Arc::new(SmallParameters::setup("merkle_tree_test"));
+        assert!(MerkleTree::<SmallParameters>::new_from_iter(parameters, leaves).is_err());
+    }
+
+    #[test]
+    fn merkle_tree_update_many_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        let parameters = Arc::new(MTParameters::setup("merkle_tree_test"));
+        let mut rng = thread_rng();
+
+        let mut leaves = generate_random_leaves!(100, 32);
+        let mut tree = MerkleTree::<MTParameters>::new(parameters.clone(), &leaves[..]).unwrap();
+        let mut expected = tree.clone();
+
+        // Update a batch of leaves, including adjacent leaves and a repeated leaf.
+        let indices = [0, 1, 2, 37, 64, 99, 37];
+        let updates = indices.iter().map(|index| (*index, rng.gen::<[u8; 32]>())).collect::<Vec<_>>();
+        for (index, leaf) in &updates {
+            leaves[*index] = *leaf;
+            expected.update(*index, leaf).unwrap();
+        }
+        tree.update_many(&updates).unwrap();
+
+        // Ensure the batch update matches the individual updates, and a tree of the updated leaves.
+        assert_eq!(expected.root(), tree.root());
+        assert_eq!(expected.tree(), tree.tree());
+        assert_eq!(MerkleTree::<MTParameters>::new(parameters, &leaves[..]).unwrap().root(), tree.root());
+        for index in indices {
+            assert!(tree.generate_proof(index, &leaves[index]).unwrap().verify(tree.root(), &leaves[index]).unwrap());
+        }
+
+        // Ensure an empty batch leaves the tree unchanged.
+        tree.update_many::<[u8; 32]>(&[]).unwrap();
+        assert_eq!(expected.root(), tree.root());
+
+        // Ensure a batch with an out-of-bounds index is rejected, and leaves the tree unchanged.
+        assert!(tree.update_many(&[(3, [0u8; 32]), (128, [0u8; 32])]).is_err());
+        assert_eq!(expected.root(), tree.root());
+        assert_eq!(expected.tree(), tree.tree());
+    }
+
     #[test]
     fn merkle_path_serialization_test() {
         type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
         run_merkle_path_serialization_test::<MTParameters>();
     }
 
+    #[test]
+    fn sparse_merkle_tree_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_sparse_merkle_tree_test::<MTParameters, Fq>();
+    }
+
+    #[test]
+    fn sparse_merkle_map_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_sparse_merkle_map_test::<MTParameters, Fq>();
+    }
+
+    #[test]
+    fn vector_commitment_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_vector_commitment_test::<MTParameters>();
+    }
+
+    #[test]
+    fn vector_commitment_unpadded_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 4>;
+        run_vector_commitment_test::<MTParameters>();
+    }
+
     #[test]
     fn merkle_path_bincode_test() {
         type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
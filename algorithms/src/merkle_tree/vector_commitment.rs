@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerkleBatchPath, MerklePath, MerkleTree, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::ToBytes;
+
+use std::sync::Arc;
+
+/// A vector commitment, which commits to a vector of values as the root of a Merkle tree.
+///
+/// An opening of a position is a `MerklePath`, and an opening of several positions is a
+/// `MerkleBatchPath`, which are checked against the root with their `verify` methods.
+pub struct MerkleVectorCommitment<P: MerkleParameters, L: ToBytes + Send + Sync> {
+    /// The Merkle tree of the values.
+    tree: MerkleTree<P>,
+    /// The committed values, by position.
+    values: Vec<L>,
+}
+
+impl<P: MerkleParameters, L: ToBytes + Send + Sync> MerkleVectorCommitment<P, L> {
+    /// Returns a commitment to the given values.
+    pub fn commit(parameters: Arc<P>, values: Vec<L>) -> Result<Self, MerkleError> {
+        Ok(Self { tree: MerkleTree::new(parameters, &values)?, values })
+    }
+
+    /// Returns the commitment, which is the root of the Merkle tree.
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        self.tree.root()
+    }
+
+    /// Returns the committed values.
+    #[inline]
+    pub fn values(&self) -> &[L] {
+        &self.values
+    }
+
+    /// Returns an opening of the value at the given index.
+    pub fn open(&self, index: usize) -> Result<MerklePath<P>, MerkleError> {
+        let value = self.values.get(index).ok_or(MerkleError::IncorrectLeafIndex(index))?;
+        self.tree.generate_proof(index, value)
+    }
+
+    /// Returns a single opening of the values at the given indices.
+    pub fn batch_open(&self, indices: &[usize]) -> Result<MerkleBatchPath<P>, MerkleError> {
+        let paths = indices.iter().map(|index| self.open(*index)).collect::<Result<Vec<_>, _>>()?;
+        MerkleBatchPath::from_paths(&paths)
+    }
+
+    /// Replaces the value at the given index, and updates the commitment.
+    pub fn update(&mut self, index: usize, value: L) -> Result<(), MerkleError> {
+        if index >= self.values.len() {
+            return Err(MerkleError::IncorrectLeafIndex(index));
+        }
+
+        self.tree.update(index, &value)?;
+        self.values[index] = value;
+        Ok(())
+    }
+}
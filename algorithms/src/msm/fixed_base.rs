@@ -16,7 +16,7 @@
 
 use snarkvm_curves::traits::ProjectiveCurve;
 use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, ToBits};
+use snarkvm_utilities::{biginteger::BigInteger, cfg_into_iter, cfg_iter, cfg_iter_mut};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -67,14 +67,14 @@ impl FixedBase {
         multiples_of_g: &[Vec<T>],
         scalar: &T::ScalarField,
     ) -> T {
-        let scalar_val = scalar.to_repr().to_bits_le();
+        let scalar_val = scalar.to_repr();
 
         cfg_into_iter!(0..outerc)
             .map(|outer| {
                 let mut inner = 0usize;
                 for i in 0..window {
                     if outer * window + i < (<T::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize)
-                        && scalar_val[outer * window + i]
+                        && scalar_val.get_bit(outer * window + i)
                     {
                         inner |= 1 << i;
                     }
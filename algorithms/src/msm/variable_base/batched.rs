@@ -160,14 +160,9 @@ fn batch_add_write<G: AffineCurve>(
 
     inversion_tmp = inversion_tmp.inverse().unwrap(); // this is always in Fp*
 
-    for (a, op_b) in addition_result.iter_mut().rev().zip(scratch_space.iter().rev()) {
-        match op_b {
-            Some(b) => {
-                G::batch_add_loop_2(a, *b, &mut inversion_tmp);
-            }
-            None => (),
-        };
-    }
+    // The results of this batch are the trailing entries of `addition_result`.
+    let start = addition_result.len() - scratch_space.len();
+    G::batch_add_loop_2_many(&mut addition_result[start..], scratch_space, &mut inversion_tmp);
     scratch_space.clear();
 }
 
@@ -370,7 +365,9 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    // Determine the number of bits to process, which is less than the field size
+    // if the scalars are short, such as after a GLV decomposition.
+    let num_bits = scalars.iter().map(|scalar| scalar.num_bits() as usize).max().unwrap_or(0).max(1);
 
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
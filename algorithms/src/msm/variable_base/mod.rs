@@ -14,9 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "batch_affine_msm")]
 mod batched;
 mod standard;
 
+mod precomputed;
+pub use precomputed::*;
+
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
 
@@ -24,7 +28,11 @@ mod cuda;
 pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::cfg_iter;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use core::any::TypeId;
 
@@ -38,7 +46,7 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-        // For BLS12-377, we perform variable base MSM using a batched addition technique.
+        // For BLS12-377, we perform variable base MSM on the GPU if available.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
@@ -50,12 +58,53 @@ impl VariableBase {
                     }
                 }
             }
-            batched::msm(bases, scalars)
         }
-        // For all other curves, we perform variable base MSM using Pippenger's algorithm.
-        else {
-            standard::msm(bases, scalars)
+
+        // If the curve has an efficient endomorphism, split each scalar into two scalars of half the length.
+        let decomposition = Self::glv_decompose(bases, scalars);
+        let (bases, scalars) = match &decomposition {
+            Some((bases, scalars)) => (bases.as_slice(), scalars.as_slice()),
+            None => (bases, scalars),
+        };
+
+        // For BLS12-377, we perform variable base MSM using a batched addition technique,
+        // which is enabled by the `batch_affine_msm` feature.
+        #[cfg(feature = "batch_affine_msm")]
+        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            return batched::msm(bases, scalars);
+        }
+
+        // Otherwise, we perform variable base MSM using Pippenger's algorithm.
+        standard::msm(bases, scalars)
+    }
+
+    /// Returns the bases and scalars of the GLV decomposition of the given MSM,
+    /// if the curve has an efficient endomorphism.
+    #[allow(clippy::type_complexity)]
+    fn glv_decompose<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Option<(Vec<G>, Vec<<G::ScalarField as PrimeField>::BigInteger>)> {
+        // Ensure there are scalars to decompose, as the batched MSM requires at least one base.
+        if scalars.is_empty() {
+            return None;
+        }
+
+        // Ensure the curve has an efficient endomorphism.
+        bases.first()?.glv_decompose(&G::ScalarField::zero())?;
+
+        let decompositions = cfg_iter!(bases)
+            .zip(scalars)
+            .map(|(base, scalar)| base.glv_decompose(&G::ScalarField::from_repr(*scalar)?))
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut glv_bases = Vec::with_capacity(2 * decompositions.len());
+        let mut glv_scalars = Vec::with_capacity(2 * decompositions.len());
+        for ((p1, k1), (p2, k2)) in decompositions {
+            glv_bases.extend([p1, p2]);
+            glv_scalars.extend([k1.to_repr(), k2.to_repr()]);
         }
+        Some((glv_bases, glv_scalars))
     }
 
     #[cfg(test)]
@@ -108,8 +157,45 @@ mod tests {
         let candidate = standard::msm(bases.as_slice(), scalars.as_slice());
         assert_eq!(naive_a, candidate);
 
-        let candidate = batched::msm(bases.as_slice(), scalars.as_slice());
+        #[cfg(feature = "batch_affine_msm")]
+        {
+            let candidate = batched::msm(bases.as_slice(), scalars.as_slice());
+            assert_eq!(naive_a, candidate);
+        }
+
+        let candidate = VariableBase::msm(bases.as_slice(), scalars.as_slice());
         assert_eq!(naive_a, candidate);
+
+        // An MSM without scalars is zero, even if there are more bases than scalars.
+        assert!(VariableBase::msm(bases.as_slice(), &[]).is_zero());
+    }
+
+    #[test]
+    fn test_msm_precomputed() {
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1000);
+
+        let precomputed = PrecomputedBases::new(&bases);
+        assert_eq!(bases.len(), precomputed.len());
+
+        for num_scalars in [0, 1, 2, 31, 32, 999, 1000] {
+            let expected = VariableBase::msm_naive(&bases[..num_scalars], &scalars[..num_scalars]);
+            assert_eq!(expected, precomputed.msm(&scalars[..num_scalars]));
+        }
+
+        for window_size in [1, 3, 8, 13] {
+            let precomputed = PrecomputedBases::with_window_size(&bases[..100], window_size);
+            let expected = VariableBase::msm_naive(&bases[..100], &scalars[..100]);
+            assert_eq!(expected, precomputed.msm(&scalars[..100]));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_msm_precomputed_too_many_scalars() {
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 10);
+        let _ = PrecomputedBases::new(&bases[..9]).msm(&scalars);
     }
 
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks, cfg_iter, cfg_reduce, BigInteger};
+
+#[cfg(feature = "batch_affine_msm")]
+use super::batched::{batch_add, BucketPosition};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The maximum window size, in bits, for a table of precomputed bases.
+const MAX_WINDOW_SIZE: usize = 24;
+
+///
+/// A table of precomputed multiples of a fixed list of bases, for repeated variable-base MSMs
+/// over the same bases, such as the powers in a committer key.
+///
+/// For each base `B`, the table stores `2^{j * c} B` for every window `j` of size `c`,
+/// so an MSM sorts every window of every scalar into a single set of buckets,
+/// and skips the doublings between windows of Pippenger's algorithm.
+/// With the `batch_affine_msm` feature, the buckets are accumulated with batch-affine additions.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The window size `c`, in bits.
+    window_size: usize,
+    /// The number of windows in a scalar.
+    num_windows: usize,
+    /// The multiples `2^{j * c} B_i`, stored at index `i * num_windows + j`.
+    table: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Initializes a new table for the given bases, with the window size chosen from the number of bases.
+    pub fn new(bases: &[G]) -> Self {
+        Self::with_window_size(bases, Self::optimal_window_size(bases.len()))
+    }
+
+    /// Initializes a new table for the given bases, with the given window size in bits.
+    pub fn with_window_size(bases: &[G], window_size: usize) -> Self {
+        assert!(window_size > 0 && window_size <= MAX_WINDOW_SIZE, "Invalid window size {}", window_size);
+
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + window_size - 1) / window_size;
+
+        let table = cfg_iter!(bases)
+            .map(|base| {
+                let mut power = base.to_projective();
+                let mut powers = Vec::with_capacity(num_windows);
+                for _ in 0..num_windows {
+                    powers.push(power);
+                    for _ in 0..window_size {
+                        power.double_in_place();
+                    }
+                }
+                powers
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        let table = G::Projective::batch_normalization_into_affine_par(table);
+
+        Self { window_size, num_windows, table }
+    }
+
+    /// Returns the number of bases in the table.
+    pub fn len(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    /// Returns `true` if the table has no bases.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Returns the window size, in bits.
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the MSM of the given scalars with the first `scalars.len()` bases in the table.
+    pub fn msm(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(scalars.len() <= self.len(), "Expected at most {} scalars, found {}", self.len(), scalars.len());
+        if scalars.is_empty() {
+            return G::Projective::zero();
+        }
+
+        let c = self.window_size;
+        let num_windows = self.num_windows;
+
+        // Split the scalars into one chunk per thread.
+        #[cfg(feature = "parallel")]
+        let num_chunks = rayon::current_num_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_chunks = 1;
+        let chunk_size = (scalars.len() + num_chunks - 1) / num_chunks;
+
+        // Sort every window of every scalar into the buckets of its digit.
+        let zero_buckets = || vec![G::Projective::zero(); (1 << c) - 1];
+        let buckets = cfg_reduce!(
+            cfg_chunks!(self.table[..scalars.len() * num_windows], chunk_size * num_windows)
+                .zip(cfg_chunks!(scalars, chunk_size))
+                .map(|(table, scalars)| self.accumulate_buckets(table, scalars)),
+            zero_buckets,
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            }
+        );
+
+        // Compute `sum_d d * bucket_d` with running sums, from the highest digit to the lowest.
+        let mut result = G::Projective::zero();
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            result += running_sum;
+        }
+        result
+    }
+
+    /// Returns the buckets for the given scalars and their rows of the table, using batch-affine additions.
+    #[cfg(feature = "batch_affine_msm")]
+    fn accumulate_buckets(
+        &self,
+        table: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Vec<G::Projective> {
+        let c = self.window_size;
+        let num_buckets = (1 << c) - 1;
+
+        // Assign every window of every scalar to the bucket of its digit.
+        // (Recall that the buckets don't have a zero bucket, so zero digits are out of range and skipped.)
+        let mut bucket_positions = Vec::with_capacity(table.len());
+        for (row, scalar) in scalars.iter().enumerate() {
+            let mut scalar = *scalar;
+            for window in 0..self.num_windows {
+                let digit = (scalar.as_ref()[0] % (1 << c)) as i64;
+                bucket_positions.push(BucketPosition {
+                    bucket_index: (digit - 1) as u32,
+                    scalar_index: (row * self.num_windows + window) as u32,
+                });
+                scalar.divn(c as u32);
+            }
+        }
+
+        batch_add(num_buckets, table, &mut bucket_positions).iter().map(|bucket| bucket.to_projective()).collect()
+    }
+
+    /// Returns the buckets for the given scalars and their rows of the table, using mixed additions.
+    #[cfg(not(feature = "batch_affine_msm"))]
+    fn accumulate_buckets(
+        &self,
+        table: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Vec<G::Projective> {
+        let c = self.window_size;
+
+        // Add every window of every scalar to the bucket of its digit.
+        // (Recall that the buckets don't have a zero bucket.)
+        let mut buckets = vec![G::Projective::zero(); (1 << c) - 1];
+        for (powers, scalar) in table.chunks(self.num_windows).zip(scalars) {
+            let mut scalar = *scalar;
+            for power in powers {
+                let digit = scalar.as_ref()[0] % (1 << c);
+                if digit != 0 {
+                    buckets[(digit - 1) as usize].add_assign_mixed(power);
+                }
+                scalar.divn(c as u32);
+            }
+        }
+        buckets
+    }
+
+    ///
+    /// Returns the window size that minimizes the number of group additions for the given number of bases,
+    /// which is one addition per window of each scalar, plus two additions per bucket.
+    ///
+    fn optimal_window_size(num_bases: usize) -> usize {
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        (1..=MAX_WINDOW_SIZE)
+            .min_by_key(|c| num_bases * ((num_bits + c - 1) / c) + (2 << c))
+            .unwrap_or(1)
+    }
+}
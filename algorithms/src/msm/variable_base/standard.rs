@@ -84,7 +84,9 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    // Determine the number of bits to process, which is less than the field size
+    // if the scalars are short, such as after a GLV decomposition.
+    let num_bits = scalars.iter().map(|scalar| scalar.num_bits() as usize).max().unwrap_or(0).max(1);
 
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
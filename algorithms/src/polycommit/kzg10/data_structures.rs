@@ -30,6 +30,7 @@ use snarkvm_utilities::{
     SerializationError,
     ToBytes,
     ToMinimalBits,
+    Zeroize,
 };
 
 use anyhow::Result;
@@ -81,6 +82,26 @@ impl<E: PairingEngine> CanonicalSerialize for UniversalParams<E> {
             + self.prepared_h.serialized_size()
             + self.prepared_beta_h.serialized_size()
     }
+
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.powers.read().serialize_compact(writer)?;
+        self.h.serialize_compact(writer)?;
+        self.beta_h.serialize_compact(writer)?;
+        self.supported_degree_bounds.serialize_compact(writer)?;
+        self.inverse_neg_powers_of_beta_h.serialize_compact(writer)?;
+        self.prepared_h.serialize_compact(writer)?;
+        self.prepared_beta_h.serialize_compact(writer)
+    }
+
+    fn compact_size(&self) -> usize {
+        self.powers.read().compact_size()
+            + self.h.compact_size()
+            + self.beta_h.compact_size()
+            + self.supported_degree_bounds.compact_size()
+            + self.inverse_neg_powers_of_beta_h.compact_size()
+            + self.prepared_h.compact_size()
+            + self.prepared_beta_h.compact_size()
+    }
 }
 
 impl<E: PairingEngine> CanonicalDeserialize for UniversalParams<E> {
@@ -103,13 +124,57 @@ impl<E: PairingEngine> CanonicalDeserialize for UniversalParams<E> {
             prepared_beta_h,
         })
     }
+
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let powers: PowersOfG<E> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let h: E::G2Affine = CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let beta_h: E::G2Affine = CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let supported_degree_bounds: Vec<usize> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let inverse_neg_powers_of_beta_h: BTreeMap<usize, E::G2Affine> =
+            CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let prepared_h: <E::G2Affine as PairingCurve>::Prepared = CanonicalDeserialize::deserialize_unchecked(reader)?;
+        let prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared =
+            CanonicalDeserialize::deserialize_unchecked(reader)?;
+
+        Ok(Self {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_beta_h,
+            prepared_h,
+            prepared_beta_h,
+        })
+    }
+
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let powers: PowersOfG<E> = CanonicalDeserialize::deserialize_compact(reader)?;
+        let h: E::G2Affine = CanonicalDeserialize::deserialize_compact(reader)?;
+        let beta_h: E::G2Affine = CanonicalDeserialize::deserialize_compact(reader)?;
+        let supported_degree_bounds: Vec<usize> = CanonicalDeserialize::deserialize_compact(reader)?;
+        let inverse_neg_powers_of_beta_h: BTreeMap<usize, E::G2Affine> =
+            CanonicalDeserialize::deserialize_compact(reader)?;
+        let prepared_h: <E::G2Affine as PairingCurve>::Prepared = CanonicalDeserialize::deserialize_compact(reader)?;
+        let prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared =
+            CanonicalDeserialize::deserialize_compact(reader)?;
+
+        Ok(Self {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_beta_h,
+            prepared_h,
+            prepared_beta_h,
+        })
+    }
 }
 
 impl<E: PairingEngine> UniversalParams<E> {
     pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Vec<E::G1Affine> {
         let basis = domain
             .ifft(&self.powers_of_beta_g(0, domain.size()).iter().map(|e| (*e).to_projective()).collect::<Vec<_>>());
-        E::G1Projective::batch_normalization_into_affine(basis)
+        E::G1Projective::batch_normalization_into_affine_par(basis)
     }
 
     pub fn power_of_beta_g(&self, which_power: usize) -> E::G1Affine {
@@ -458,6 +523,13 @@ impl<E: PairingEngine> Randomness<E> {
     }
 }
 
+impl<E: PairingEngine> Zeroize for Randomness<E> {
+    /// Overwrites the blinding polynomial with zeros.
+    fn zeroize(&mut self) {
+        self.blinding_polynomial.zeroize();
+    }
+}
+
 impl<'a, E: PairingEngine> Add<&'a Randomness<E>> for Randomness<E> {
     type Output = Self;
 
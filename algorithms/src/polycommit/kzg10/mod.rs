@@ -154,9 +154,9 @@ impl<E: PairingEngine> KZG10<E> {
         end_timer!(gamma_g_time);
 
         // Reduce `beta^i G` and `gamma beta^i G` to affine representations.
-        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(powers_of_beta_g);
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine_par(powers_of_beta_g);
         let powers_of_beta_times_gamma_g =
-            E::G1Projective::batch_normalization_into_affine(powers_of_beta_times_gamma_g)
+            E::G1Projective::batch_normalization_into_affine_par(powers_of_beta_times_gamma_g)
                 .into_iter()
                 .enumerate()
                 .collect();
@@ -435,13 +435,13 @@ impl<E: PairingEngine> KZG10<E> {
         if let Some(random_v) = proof.random_v {
             inner -= &vk.gamma_g.mul(random_v);
         }
-        let lhs = E::pairing(inner, vk.h);
+        let inner_h = vk.beta_h.to_projective() - vk.h.mul(point);
 
-        let inner = vk.beta_h.to_projective() - vk.h.mul(point);
-        let rhs = E::pairing(proof.w, inner);
+        // Check `e(inner, h) = e(w, inner_h)`, as `e(inner, h) * e(-w, inner_h) = 1`.
+        let result = E::multi_pairing([inner, -proof.w.to_projective()], [vk.h.to_projective(), inner_h]).is_one();
 
-        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
-        Ok(lhs == rhs)
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
     }
 
     /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
@@ -575,7 +575,10 @@ mod tests {
     #![allow(non_camel_case_types)]
     #![allow(clippy::needless_borrow)]
     use super::*;
-    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fr},
+        bw6_761::BW6_761,
+    };
     use snarkvm_utilities::{rand::test_rng, FromBytes, ToBytes};
 
     use std::borrow::Cow;
@@ -710,16 +713,19 @@ mod tests {
     #[test]
     fn test_end_to_end() {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        end_to_end_test_template::<BW6_761>().expect("test failed for bw6-761");
     }
 
     #[test]
     fn test_linear_polynomial() {
         linear_polynomial_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        linear_polynomial_test_template::<BW6_761>().expect("test failed for bw6-761");
     }
 
     #[test]
     fn test_batch_check() {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
+        batch_check_test_template::<BW6_761>().expect("test failed for bw6-761");
     }
 
     #[test]
@@ -16,7 +16,11 @@
 
 use anyhow::{anyhow, Result};
 use rand::Rng;
-use snarkvm_curves::traits::PairingEngine;
+use snarkvm_curves::{bls12_377::Bls12_377, traits::PairingEngine};
+#[cfg(not(feature = "mmap"))]
+use snarkvm_utilities::deserialize_slice_par;
+#[cfg(feature = "mmap")]
+use snarkvm_utilities::MappedFile;
 use snarkvm_utilities::{
     CanonicalDeserialize,
     CanonicalSerialize,
@@ -24,10 +28,12 @@ use snarkvm_utilities::{
     FromBytes,
     Read,
     SerializationError,
+    SerializationMode,
     ToBytes,
     Write,
 };
 
+use core::any::TypeId;
 use itertools::Itertools;
 use std::{
     collections::{BTreeMap, HashMap},
@@ -80,6 +86,9 @@ pub struct PowersOfG<E: PairingEngine> {
     /// Mostly just used for ensuring we download in the proper order,
     /// length checks are actually done by checking file metadata.
     degree: usize,
+    /// A read-only memory map of the file, which is refreshed when the file grows.
+    #[cfg(feature = "mmap")]
+    mapped: Option<MappedFile>,
 }
 
 impl<E: PairingEngine> Default for PowersOfG<E> {
@@ -179,8 +188,8 @@ impl<E: PairingEngine> PowersOfG<E> {
         let mut file = OpenOptions::new().read(true).write(true).create(true).open(file_path.clone())?;
 
         // If the file is empty, let's write the base powers (up to degree 15)
-        // to it.
-        if file.metadata()?.len() == 0 {
+        // to it. These powers are only available for BLS12-377.
+        if file.metadata()?.len() == 0 && Self::has_hosted_powers() {
             file.write_all(&BASE_POWERS)?;
         }
 
@@ -191,12 +200,20 @@ impl<E: PairingEngine> PowersOfG<E> {
             file,
             powers_of_beta_times_gamma_g: BTreeMap::new(),
             degree,
+            #[cfg(feature = "mmap")]
+            mapped: None,
         };
 
         powers.regenerate_powers_of_beta_times_gamma_g();
         Ok(powers)
     }
 
+    /// Returns `true` if the hardcoded and downloadable powers apply to the curve `E`.
+    /// The hosted transcript is only available for BLS12-377.
+    fn has_hosted_powers() -> bool {
+        TypeId::of::<E>() == TypeId::of::<Bls12_377>()
+    }
+
     /// Return the number of current powers of G.
     pub fn len(&self) -> usize {
         self.degree
@@ -215,13 +232,7 @@ impl<E: PairingEngine> PowersOfG<E> {
     // something that does not exist when this function is called.
     pub fn power_of_beta_g(&mut self, which_power: usize) -> E::G1Affine {
         let index_start = self.get_starting_index(which_power);
-
-        // Move our offset to the start of the desired element.
-        let mut reader = BufReader::new(&self.file);
-        reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
-
-        // Now read it out, deserialize it, and return it.
-        E::G1Affine::deserialize(&mut reader).expect("powers of g corrupted")
+        self.read_powers(index_start, 1)[0]
     }
 
     /// Slices the underlying file to return a vector of affine elements
@@ -230,19 +241,37 @@ impl<E: PairingEngine> PowersOfG<E> {
         // Ensure index exists for upper power.
         let _ = self.get_starting_index(upper);
         let index_start = self.get_starting_index(lower);
+        self.read_powers(index_start, upper - lower)
+    }
 
+    /// Deserializes `num_powers` consecutive powers, starting at the byte `index_start` of the file.
+    #[cfg(not(feature = "mmap"))]
+    fn read_powers(&mut self, index_start: usize, num_powers: usize) -> Vec<E::G1Affine> {
         // Move our offset to the start of the desired element.
         let mut reader = BufReader::new(&self.file);
         reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
 
-        // Now iterate until we fill a vector with all desired elements.
-        let mut powers = Vec::with_capacity((upper - lower) as usize);
-        for _ in lower..upper {
-            let power = E::G1Affine::deserialize(&mut reader).expect("powers of g corrupted");
-            powers.push(power);
+        // Read out the bytes of all desired elements, and deserialize them in parallel.
+        let mut bytes = vec![0u8; num_powers * E::G1Affine::SERIALIZED_SIZE];
+        reader.read_exact(&mut bytes).expect("could not read powers of g");
+        deserialize_slice_par(&bytes, SerializationMode::Compressed).expect("powers of g corrupted")
+    }
+
+    /// Deserializes `num_powers` consecutive powers, starting at the byte `index_start` of the file.
+    #[cfg(feature = "mmap")]
+    fn read_powers(&mut self, index_start: usize, num_powers: usize) -> Vec<E::G1Affine> {
+        // (Re)map the file if it has not been mapped yet, or has grown since it was last mapped.
+        let index_end = index_start + num_powers * E::G1Affine::SERIALIZED_SIZE;
+        if self.mapped.as_ref().map_or(true, |mapped| mapped.len() < index_end) {
+            self.mapped = Some(MappedFile::open(&self.file_path).expect("could not map powers of g"));
         }
 
-        powers
+        // Deserialize the desired elements directly from the mapped file.
+        let mapped = self.mapped.as_ref().expect("powers of g should be mapped");
+        mapped
+            .elements::<E::G1Affine>(index_start, num_powers, SerializationMode::Compressed)
+            .and_then(|powers| powers.to_vec())
+            .expect("powers of g corrupted")
     }
 
     /// This function returns the starting byte of the file in which we're indexing
@@ -281,6 +310,10 @@ impl<E: PairingEngine> PowersOfG<E> {
     #[cfg(not(feature = "wasm"))]
     pub fn download_up_to(&mut self, degree: usize) -> Result<()> {
         println!("called download");
+        if !Self::has_hosted_powers() {
+            return Err(anyhow!("no hosted powers of G are available for this curve"));
+        }
+
         let degrees_to_download = self.get_degrees_to_download(degree);
         for d in &degrees_to_download {
             println!("downloading new degree");
@@ -308,6 +341,10 @@ impl<E: PairingEngine> PowersOfG<E> {
     /// Download the transcript up to `degree`.
     #[cfg(feature = "wasm")]
     pub fn download_up_to(&mut self, degree: usize) -> Result<()> {
+        if !Self::has_hosted_powers() {
+            return Err(anyhow!("no hosted powers of G are available for this curve"));
+        }
+
         let degrees_to_download = self.get_degrees_to_download(degree);
         for d in &degrees_to_download {
             if let Some(link) = URLS.get(d) {
@@ -344,6 +381,11 @@ impl<E: PairingEngine> PowersOfG<E> {
     }
 
     fn regenerate_powers_of_beta_times_gamma_g(&mut self) {
+        // The hardcoded powers times gamma G are only available for BLS12-377.
+        if !Self::has_hosted_powers() {
+            return;
+        }
+
         let mut alpha_powers_g1 = vec![];
         let mut reader = BufReader::new(*POWERS_TIMES_GAMMA_G);
         for _ in 0..NUM_POWERS_TIMES_GAMMA_G {
@@ -23,7 +23,7 @@ use crate::{
 };
 use hashbrown::HashMap;
 use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use std::{
@@ -765,3 +765,47 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The group elements of a batch opening check, before the final product of pairings.
+///
+/// Every check against the same verifier key pairs these elements with the same fixed elements
+/// of `G2`, so any number of deferred checks can be folded together with random coefficients
+/// and resolved with a single product of pairings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchCheckElements<E: PairingEngine> {
+    /// The combined commitments, grouped by their degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined opening witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined opening witness, adjusted by the evaluation point and claimed values.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for BatchCheckElements<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> BatchCheckElements<E> {
+    /// Accumulates `coeff * other` into `self`.
+    pub fn add_assign_scaled(&mut self, coeff: E::Fr, other: &Self) {
+        for (degree_bound, comm) in other.combined_comms.iter() {
+            let mut comm = *comm;
+            comm *= coeff;
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += comm;
+        }
+
+        let mut witness = other.combined_witness;
+        witness *= coeff;
+        self.combined_witness += witness;
+
+        let mut adjusted_witness = other.combined_adjusted_witness;
+        adjusted_witness *= coeff;
+        self.combined_adjusted_witness += adjusted_witness;
+    }
+}
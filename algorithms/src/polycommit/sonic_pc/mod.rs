@@ -402,6 +402,23 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let elements = Self::batch_check_elements(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elements(elements, vk)
+    }
+
+    /// Performs every step of [`Self::batch_check`] except for the final product of pairings,
+    /// and returns the group elements that the pairings would be computed over.
+    pub fn batch_check_elements<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<BatchCheckElements<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -417,9 +434,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut elements = BatchCheckElements::default();
 
         let mut batch_kzg_check_fs_rng = S::new();
         batch_kzg_check_fs_rng
@@ -443,9 +458,9 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut elements.combined_comms,
+                &mut elements.combined_witness,
+                &mut elements.combined_adjusted_witness,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -458,7 +473,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             randomizer = batch_kzg_check_fs_rng.squeeze_short_nonnative_field_element()?;
         }
 
-        Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
+        Ok(elements)
     }
 
     pub fn open_combinations<'a>(
@@ -550,6 +565,32 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let elements = Self::check_combinations_elements(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_elements(elements, vk)
+    }
+
+    /// Performs every step of [`Self::check_combinations`] except for the final product of pairings,
+    /// and returns the group elements that the pairings would be computed over.
+    pub fn check_combinations_elements<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<BatchCheckElements<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -604,7 +645,14 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_elements(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Checks the group elements returned by [`Self::batch_check_elements`] or
+    /// [`Self::check_combinations_elements`] with a single product of pairings.
+    pub fn check_elements(elements: BatchCheckElements<E>, vk: &VerifierKey<E>) -> Result<bool, PCError> {
+        let BatchCheckElements { combined_comms, combined_witness, combined_adjusted_witness } = elements;
+        Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
     }
 }
 
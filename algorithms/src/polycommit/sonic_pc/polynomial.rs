@@ -23,7 +23,7 @@ use crate::fft::{
     SparsePolynomial,
 };
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize, Zeroize};
 
 use hashbrown::HashMap;
 use std::borrow::Cow;
@@ -99,6 +99,13 @@ impl<F: Field> LabeledPolynomial<F> {
     }
 }
 
+impl<F: Field + Zeroize> Zeroize for LabeledPolynomial<F> {
+    /// Overwrites the enclosed polynomial with zeros, if it is owned.
+    fn zeroize(&mut self) {
+        self.polynomial.zeroize();
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////////////
 /////////////////////////////////////////////////////////////////////////////////////
 /////////////////////////////////////////////////////////////////////////////////////
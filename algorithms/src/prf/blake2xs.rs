@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crypto_hash::Blake2Xs, ExpandablePRF, PRF};
+
+/// The personalization of the BLAKE2Xs PRF.
+const PRF_PERSONALIZATION: &[u8] = b"AleoPRF0";
+/// The personalization of the BLAKE2Xs seed expansion.
+const EXPAND_PERSONALIZATION: &[u8] = b"AleoKDF0";
+
+///
+/// A PRF over the BLAKE2Xs extendable-output function, for fast native uses such as
+/// deterministic nonce derivation. It is *not* efficient in a circuit, see `PoseidonPRF`.
+///
+#[derive(Clone)]
+pub struct Blake2XsPRF;
+
+impl PRF for Blake2XsPRF {
+    type Input = Vec<u8>;
+    type Output = [u8; 32];
+    type Seed = [u8; 32];
+
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Self::Output {
+        Self::evaluate_at(seed, input, 0)
+    }
+}
+
+impl ExpandablePRF for Blake2XsPRF {
+    fn evaluate_many(seed: &Self::Seed, input: &Self::Input, num_outputs: usize) -> Vec<Self::Output> {
+        (0..num_outputs as u64).map(|index| Self::evaluate_at(seed, input, index)).collect()
+    }
+}
+
+impl Blake2XsPRF {
+    ///
+    /// Returns `num_bytes` of output from expanding the given seed and input, e.g. to expand a beacon into an SRS.
+    ///
+    /// Note: The output for each `num_bytes` is independent, so a shorter output is *not* a prefix of a longer one.
+    ///
+    pub fn expand(seed: &[u8; 32], input: &[u8], num_bytes: u16) -> Vec<u8> {
+        Blake2Xs::evaluate(&Self::preimage(seed, input, None), num_bytes, EXPAND_PERSONALIZATION)
+    }
+
+    /// Returns the output of the PRF at the given index.
+    fn evaluate_at(seed: &[u8; 32], input: &[u8], index: u64) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        output.copy_from_slice(&Blake2Xs::evaluate(&Self::preimage(seed, input, Some(index)), 32, PRF_PERSONALIZATION));
+        output
+    }
+
+    /// Returns the preimage (seed, index, length(input), input).
+    fn preimage(seed: &[u8; 32], input: &[u8], index: Option<u64>) -> Vec<u8> {
+        let mut preimage = Vec::with_capacity(48 + input.len());
+        preimage.extend_from_slice(seed);
+        if let Some(index) = index {
+            preimage.extend_from_slice(&index.to_le_bytes());
+        }
+        preimage.extend_from_slice(&(input.len() as u64).to_le_bytes());
+        preimage.extend_from_slice(input);
+        preimage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    #[test]
+    fn test_evaluate_many() {
+        let rng = &mut test_rng();
+
+        let seed: [u8; 32] = rng.gen();
+        let input = b"Hi, I am an Aleo PRF input!".to_vec();
+
+        let outputs = Blake2XsPRF::evaluate_many(&seed, &input, 10);
+        assert_eq!(10, outputs.len());
+        assert_eq!(Blake2XsPRF::evaluate(&seed, &input), outputs[0]);
+        assert_eq!(outputs, Blake2XsPRF::evaluate_many(&seed, &input, 10));
+
+        // Ensure the outputs are distinct, and depend on the seed and input.
+        for (i, output) in outputs.iter().enumerate() {
+            assert!(outputs[i + 1..].iter().all(|other| other != output));
+        }
+        assert_ne!(outputs[0], Blake2XsPRF::evaluate(&rng.gen(), &input));
+        assert_ne!(outputs[0], Blake2XsPRF::evaluate(&seed, &b"Bad input".to_vec()));
+    }
+
+    #[test]
+    fn test_expand() {
+        let rng = &mut test_rng();
+
+        let seed: [u8; 32] = rng.gen();
+        let input = b"Hi, I am an Aleo beacon!";
+
+        for num_bytes in [1, 31, 32, 33, 1000] {
+            let output = Blake2XsPRF::expand(&seed, input, num_bytes);
+            assert_eq!(num_bytes as usize, output.len());
+            assert_eq!(output, Blake2XsPRF::expand(&seed, input, num_bytes));
+        }
+
+        // Ensure the expansion is separated from the PRF.
+        assert_ne!(&Blake2XsPRF::evaluate(&seed, &input.to_vec())[..], &Blake2XsPRF::expand(&seed, input, 32)[..]);
+    }
+}
@@ -14,5 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod blake2xs;
+pub use blake2xs::*;
+
 pub mod poseidon;
 pub use poseidon::*;
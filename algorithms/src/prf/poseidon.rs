@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::Poseidon, PRF};
+use crate::{crypto_hash::Poseidon, ExpandablePRF, PRF};
 use snarkvm_fields::PrimeField;
 
 use std::marker::PhantomData;
@@ -30,12 +30,51 @@ impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PRF
     type Seed = F;
 
     fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Self::Output {
-        // Construct the preimage.
+        // Evaluate the preimage.
+        Poseidon::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup().evaluate(&Self::preimage(seed, input))
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> ExpandablePRF
+    for PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS>
+{
+    fn evaluate_many(seed: &Self::Seed, input: &Self::Input, num_outputs: usize) -> Vec<Self::Output> {
+        // Squeeze the outputs from the preimage.
+        Poseidon::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup().evaluate_many(&Self::preimage(seed, input), num_outputs)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS> {
+    /// Returns the preimage (seed, length(input), input).
+    fn preimage(seed: &F, input: &[F]) -> Vec<F> {
         let mut preimage = vec![*seed];
         preimage.push(F::from(input.len() as u128)); // Input length
         preimage.extend_from_slice(input);
+        preimage
+    }
+}
 
-        // Evaluate the preimage.
-        Poseidon::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup().evaluate(&preimage)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type TestPRF = PoseidonPRF<Fr, 4, false>;
+
+    #[test]
+    fn test_evaluate_many() {
+        let rng = &mut test_rng();
+
+        let seed = Fr::rand(rng);
+        let input = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let outputs = TestPRF::evaluate_many(&seed, &input, 10);
+        assert_eq!(10, outputs.len());
+        assert_eq!(TestPRF::evaluate(&seed, &input), outputs[0]);
+        assert_eq!(outputs, TestPRF::evaluate_many(&seed, &input, 10));
+
+        // Ensure the outputs depend on the seed.
+        assert_ne!(outputs, TestPRF::evaluate_many(&Fr::rand(rng), &input, 10));
     }
 }
@@ -16,6 +16,7 @@
 
 use crate::{
     crypto_hash::{hash_to_curve, Poseidon},
+    msm::VariableBase,
     SignatureError,
     SignatureScheme,
     SignatureSchemeOperations,
@@ -26,27 +27,33 @@ use snarkvm_curves::{
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
-use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_fields::{PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{
+    cfg_iter,
     io::{Read, Result as IoResult, Write},
     rand::UniformRand,
     serialize::*,
-    FromBits,
+    Choice,
+    ConditionallySelectable,
     FromBytes,
-    ToBits,
+    IterBits,
     ToBytes,
+    Zeroize,
 };
 
 use anyhow::Result;
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
+use rand::{thread_rng, CryptoRng, Rng};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct AleoSignature<TE: TwistedEdwardsParameters> {
     pub prover_response: TE::ScalarField,
     pub verifier_challenge: TE::ScalarField,
-    root_public_key: TE::BaseField,
-    root_randomizer: TE::BaseField,
+    pub(super) root_public_key: TE::BaseField,
+    pub(super) root_randomizer: TE::BaseField,
 }
 
 impl<TE: TwistedEdwardsParameters> AleoSignature<TE> {
@@ -202,7 +209,7 @@ where
         rng: &mut R,
     ) -> Result<Self::Signature> {
         // Sample a random scalar field element.
-        let r = TE::ScalarField::rand(rng);
+        let mut r = TE::ScalarField::rand(rng);
 
         // Compute G^r.
         let g_r = self.g_scalar_multiply(&r);
@@ -230,21 +237,14 @@ where
         let public_key = (g_sk_sig + g_r_sig + g_sk_prf).to_affine();
 
         // Compute the verifier challenge.
-        let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
-            preimage.extend_from_slice(&g_r_affine.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let verifier_challenge = self.verifier_challenge(&public_key, &g_r_affine, message)?;
 
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
 
+        // Wipe the nonce, as it reveals the private key given the signature.
+        r.zeroize();
+
         Ok(AleoSignature {
             prover_response,
             verifier_challenge,
@@ -271,17 +271,7 @@ where
         let g_r = (self.g_scalar_multiply(prover_response) + g_sk_sig_c).to_affine();
 
         // Compute the candidate verifier challenge.
-        let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
-            preimage.extend_from_slice(&g_r.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let candidate_verifier_challenge = self.verifier_challenge(public_key, &g_r, message)?;
 
         // Recover G^r_sig.
         let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
@@ -300,6 +290,80 @@ where
 
         Ok(*verifier_challenge == candidate_verifier_challenge && *public_key == candidate_public_key)
     }
+
+    ///
+    /// Verifies a batch of signatures, where:
+    ///     each verifier challenge is recomputed as in `verify`, in parallel, and
+    ///     the public key equations are checked at once, as a random linear combination of
+    ///     Σ z_i * (public_key_i - G^sk_sig_i - G^r_sig_i - G^sk_prf_i) == 0
+    ///
+    /// The verifier challenge is a hash of G^r, so it cannot be folded into the linear combination.
+    ///
+    fn verify_batch(
+        &self,
+        public_keys: &[Self::PublicKey],
+        messages: &[&[bool]],
+        signatures: &[Self::Signature],
+    ) -> Result<bool> {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return Err(SignatureError::Message(format!(
+                "Mismatched batch lengths: {} public keys, {} messages, {} signatures",
+                public_keys.len(),
+                messages.len(),
+                signatures.len()
+            ))
+            .into());
+        }
+
+        // Check each verifier challenge, and recover (G^sk_sig, G^r_sig, sk_prf) for the public key equations.
+        let recovered = cfg_iter!(public_keys)
+            .zip(messages)
+            .zip(signatures)
+            .map(|((public_key, message), signature)| -> Result<_> {
+                // Extract the signature contents.
+                let AleoSignature { prover_response, verifier_challenge, root_public_key, root_randomizer } = signature;
+
+                // Recover G^sk_sig and G^r_sig.
+                let g_sk_sig = Self::recover_from_x_coordinate(root_public_key)?;
+                let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
+
+                // Compute G^r := G^s G^sk_sig^c.
+                let g_sk_sig_c = self.scalar_multiply(g_sk_sig, verifier_challenge);
+                let g_r = (self.g_scalar_multiply(prover_response) + g_sk_sig_c).to_affine();
+
+                // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+                let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.to_x_coordinate(), g_r_sig.to_x_coordinate()]);
+
+                let is_valid = *verifier_challenge == self.verifier_challenge(public_key, &g_r, message)?;
+                Ok((is_valid, g_sk_sig, g_r_sig, sk_prf))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if recovered.iter().any(|(is_valid, ..)| !is_valid) {
+            return Ok(false);
+        }
+
+        // Sample a 128-bit randomizer z_i for each public key equation.
+        let rng = &mut thread_rng();
+        let randomizers = (0..recovered.len()).map(|_| TE::ScalarField::from(rng.gen::<u128>())).collect::<Vec<_>>();
+
+        // Accumulate Σ z_i * sk_prf_i, so that G^sk_prf_i is computed with a single fixed-base multiplication.
+        let sk_prf_sum = recovered
+            .iter()
+            .zip(&randomizers)
+            .fold(TE::ScalarField::zero(), |sum, ((_, _, _, sk_prf), z)| sum + (*sk_prf * z));
+
+        // Construct the bases and scalars for Σ z_i * (public_key_i - G^sk_sig_i - G^r_sig_i).
+        let mut bases = Vec::with_capacity(3 * recovered.len());
+        let mut scalars = Vec::with_capacity(3 * recovered.len());
+        for ((public_key, (_, g_sk_sig, g_r_sig, _)), z) in public_keys.iter().zip(&recovered).zip(&randomizers) {
+            bases.extend([*public_key, *g_sk_sig, *g_r_sig]);
+            scalars.extend([z.to_repr(), (-*z).to_repr(), (-*z).to_repr()]);
+        }
+
+        // Check that the random linear combination of the public key equations is zero.
+        Ok((VariableBase::msm(&bases, &scalars) - self.g_scalar_multiply(&sk_prf_sum)).is_zero())
+    }
 }
 
 impl<TE: TwistedEdwardsParameters> SignatureSchemeOperations for AleoSignatureScheme<TE>
@@ -320,31 +384,17 @@ where
     }
 
     fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective {
+        // The scalar may be secret, so every base is added, selecting the identity for the unset bits.
+        let zero = TEProjective::<TE>::zero();
         self.g_bases
             .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum::<TEProjective<TE>>()
+            .zip_eq(scalar.iter_bits_le())
+            .fold(zero, |output, (base, bit)| output + TEProjective::conditional_select(&zero, base, Choice::from(bit as u8)))
     }
 
     fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField {
-        // Use Poseidon as a random oracle.
-        let output = self.poseidon.evaluate(input);
-
-        // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
-        let mut bits = output.to_bits_le();
-        bits.resize(TE::ScalarField::size_in_data_bits(), false);
-
-        // Output the scalar field.
-        let biginteger = <TE::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits);
-        match <TE::ScalarField as PrimeField>::from_repr(biginteger) {
-            // We know this case will always work, because we truncate the output to CAPACITY bits in the scalar field.
-            Some(scalar) => scalar,
-            _ => panic!("Failed to hash input into scalar field"),
-        }
+        // Use Poseidon as a random oracle, truncating the output into the scalar field.
+        self.poseidon.hash_to_scalar(input)
     }
 }
 
@@ -356,7 +406,27 @@ where
         base * *scalar
     }
 
-    fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
+    ///
+    /// Returns the verifier challenge c := Hash(G^sk_sig G^r_sig G^sk_prf, G^r, message).
+    ///
+    pub(super) fn verifier_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        g_r: &TEAffine<TE>,
+        message: &[bool],
+    ) -> Result<TE::ScalarField> {
+        // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
+        preimage.extend_from_slice(&g_r.to_x_coordinate().to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Hash to derive the verifier challenge.
+        Ok(self.hash_to_scalar_field(&preimage))
+    }
+
+    pub(super) fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
         if let Some(element) = TEAffine::<TE>::from_x_coordinate(*x_coordinate, true) {
             if element.is_in_correct_subgroup_assuming_on_curve() {
                 return Ok(element);
@@ -380,7 +450,7 @@ mod tests {
         edwards_bls12::EdwardsParameters as EdwardsBls12,
         edwards_bw6::EdwardsParameters as EdwardsBW6,
     };
-    use snarkvm_utilities::test_crypto_rng;
+    use snarkvm_utilities::{test_crypto_rng, ToBits};
 
     fn sign_and_verify<S: SignatureScheme>(message: &[bool]) {
         let rng = &mut test_crypto_rng();
@@ -402,6 +472,38 @@ mod tests {
         assert!(!signature_scheme.verify(&public_key, bad_message, &signature).unwrap());
     }
 
+    fn batch_verification<S: SignatureScheme>(num_signatures: usize) {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = S::setup("batch_verification");
+
+        let mut public_keys = Vec::with_capacity(num_signatures);
+        let mut messages = Vec::with_capacity(num_signatures);
+        let mut signatures = Vec::with_capacity(num_signatures);
+        for i in 0..num_signatures {
+            let private_key = signature_scheme.generate_private_key(rng);
+            let message = format!("Batch message {}", i).as_bytes().to_bits_le();
+            signatures.push(signature_scheme.sign(&private_key, &message, rng).unwrap());
+            public_keys.push(signature_scheme.generate_public_key(&private_key));
+            messages.push(message);
+        }
+        let message_slices = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert!(signature_scheme.verify_batch(&public_keys, &message_slices, &signatures).unwrap());
+
+        // Swap two public keys, so that each signature is checked against the wrong key.
+        public_keys.swap(0, 1);
+        assert!(!signature_scheme.verify_batch(&public_keys, &message_slices, &signatures).unwrap());
+        public_keys.swap(0, 1);
+
+        // Replace one message.
+        let bad_message = b"Bad message".to_bits_le();
+        let mut bad_message_slices = message_slices.clone();
+        bad_message_slices[num_signatures - 1] = bad_message.as_slice();
+        assert!(!signature_scheme.verify_batch(&public_keys, &bad_message_slices, &signatures).unwrap());
+
+        // Drop one signature.
+        assert!(signature_scheme.verify_batch(&public_keys, &message_slices, &signatures[1..]).is_err());
+    }
+
     #[test]
     fn test_aleo_signature_on_edwards_bls12_377() {
         type TestSignature = AleoSignatureScheme<EdwardsBls12>;
@@ -409,6 +511,7 @@ mod tests {
         let message = "Hi, I am an Aleo signature!";
         sign_and_verify::<TestSignature>(&message.as_bytes().to_bits_le());
         failed_verification::<TestSignature>(&message.as_bytes().to_bits_le(), &b"Bad message".to_bits_le());
+        batch_verification::<TestSignature>(8);
     }
 
     #[test]
@@ -418,5 +521,6 @@ mod tests {
         let message = "Hi, I am an Aleo signature!";
         sign_and_verify::<TestSignature>(&message.as_bytes().to_bits_le());
         failed_verification::<TestSignature>(&message.as_bytes().to_bits_le(), &b"Bad message".to_bits_le());
+        batch_verification::<TestSignature>(8);
     }
 }
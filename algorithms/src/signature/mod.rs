@@ -16,3 +16,6 @@
 
 pub mod aleo;
 pub use aleo::*;
+
+pub mod musig2;
+pub use musig2::*;
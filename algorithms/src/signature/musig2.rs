@@ -0,0 +1,499 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    signature::{AleoSignature, AleoSignatureScheme},
+    SignatureError,
+    SignatureSchemeOperations,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+    Zeroize,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The domain separator for the key aggregation coefficients.
+const KEY_AGGREGATION_DOMAIN: &str = "AleoMuSig2KeyAggregation0";
+/// The domain separator for the nonce binding coefficient.
+const NONCE_BINDING_DOMAIN: &str = "AleoMuSig2NonceBinding0";
+
+///
+/// The aggregate public key of a fixed, ordered set of signers.
+///
+/// Each signer contributes their root public keys (G^sk_sig_i, G^r_sig_i), which are combined as
+///     G^sk_sig := Σ a_i G^sk_sig_i, and G^r_sig := Σ a_i G^r_sig_i,
+/// where a_i := Hash(L, G^sk_sig_i, G^r_sig_i) for the list L of all root public keys.
+/// The aggregate public key G^sk_sig G^r_sig G^sk_prf is then an ordinary Aleo public key.
+///
+/// For m-of-n signing, the aggregate public key of each chosen subset of m signers is used.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatePublicKey<TE: TwistedEdwardsParameters> {
+    /// The root public keys (G^sk_sig_i, G^r_sig_i) of the signers.
+    root_public_keys: Vec<(TEAffine<TE>, TEAffine<TE>)>,
+    /// The key aggregation coefficients a_i.
+    coefficients: Vec<TE::ScalarField>,
+    /// The aggregate root public key G^sk_sig.
+    root_public_key: TEAffine<TE>,
+    /// The aggregate root randomizer G^r_sig.
+    root_randomizer: TEAffine<TE>,
+    /// The aggregate public key G^sk_sig G^r_sig G^sk_prf.
+    public_key: TEAffine<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> AggregatePublicKey<TE> {
+    /// Returns the aggregate public key, which verifies aggregate signatures with `SignatureScheme::verify`.
+    pub fn public_key(&self) -> &TEAffine<TE> {
+        &self.public_key
+    }
+
+    /// Returns the number of signers.
+    pub fn num_signers(&self) -> usize {
+        self.root_public_keys.len()
+    }
+}
+
+///
+/// The secret nonces (r_1, r_2) of a signer for a single signing session.
+///
+/// The secret nonces are consumed on signing, and *must not* be reused across sessions.
+///
+pub struct SecretNonce<TE: TwistedEdwardsParameters>([TE::ScalarField; 2]);
+
+/// The public nonces (G^r_1, G^r_2) of a signer, or the sum of the public nonces of all signers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicNonce<TE: TwistedEdwardsParameters>([TEAffine<TE>; 2]);
+
+impl<TE: TwistedEdwardsParameters> FromBytes for PublicNonce<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let first = TEAffine::<TE>::read_le(&mut reader)?;
+        let second = TEAffine::<TE>::read_le(&mut reader)?;
+        Ok(Self([first, second]))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for PublicNonce<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.0[0].write_le(&mut writer)?;
+        self.0[1].write_le(&mut writer)
+    }
+}
+
+/// The partial signature s_i of a signer, which is summed into the prover response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PartialSignature<TE: TwistedEdwardsParameters>(TE::ScalarField);
+
+impl<TE: TwistedEdwardsParameters> FromBytes for PartialSignature<TE> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(TE::ScalarField::read_le(reader)?))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for PartialSignature<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+///
+/// A MuSig2-style multi-signature protocol, whose aggregate signatures are ordinary Aleo signatures.
+///
+/// The protocol proceeds as follows:
+///     1. Each signer shares their root public keys, which are aggregated with `aggregate_public_keys`.
+///     2. Each signer samples nonces with `generate_nonces`, and shares the public nonces,
+///        which are summed with `aggregate_nonces`. This round may precede knowing the message.
+///     3. Each signer computes their partial signature with `partial_sign`.
+///     4. The partial signatures are checked with `verify_partial`, and summed with `aggregate_partial_signatures`.
+///
+/// The signing session uses the nonce R := R_1 + b R_2, where b := Hash(public_key, R_1, R_2, message),
+/// and each signer computes s_i := r_1_i + b r_2_i - c a_i sk_sig_i, so that s := Σ s_i.
+///
+impl<TE: TwistedEdwardsParameters> AleoSignatureScheme<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    ///
+    /// Returns the root public keys (G^sk_sig, G^r_sig) of the given private key, as shared by a signer.
+    ///
+    pub fn root_public_keys(&self, private_key: &(TE::ScalarField, TE::ScalarField)) -> (TEAffine<TE>, TEAffine<TE>) {
+        let (sk_sig, r_sig) = private_key;
+
+        let mut to_invert = [self.g_scalar_multiply(sk_sig), self.g_scalar_multiply(r_sig)];
+        TEProjective::<TE>::batch_normalization(&mut to_invert);
+        let [g_sk_sig, g_r_sig] = to_invert.map(|a| a.to_affine());
+        (g_sk_sig, g_r_sig)
+    }
+
+    ///
+    /// Returns the aggregate public key of the given (ordered) root public keys of the signers.
+    ///
+    pub fn aggregate_public_keys(
+        &self,
+        root_public_keys: &[(TEAffine<TE>, TEAffine<TE>)],
+    ) -> Result<AggregatePublicKey<TE>> {
+        if root_public_keys.is_empty() {
+            return Err(SignatureError::Message("Cannot aggregate an empty set of public keys".into()).into());
+        }
+
+        // Ensure each root public key is in the prime-order subgroup.
+        for (g_sk_sig, g_r_sig) in root_public_keys {
+            for point in [g_sk_sig, g_r_sig] {
+                if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(SignatureError::Message("Invalid root public key for aggregation".into()).into());
+                }
+            }
+        }
+
+        // Construct the hash input (domain, L), where L is the list of root public keys.
+        let mut preimage = Vec::with_capacity(3 + 2 * root_public_keys.len());
+        preimage.push(TE::BaseField::from_bytes_le_mod_order(KEY_AGGREGATION_DOMAIN.as_bytes()));
+        preimage.push(TE::BaseField::from(root_public_keys.len() as u128));
+        for (g_sk_sig, g_r_sig) in root_public_keys {
+            preimage.extend([g_sk_sig.to_x_coordinate(), g_r_sig.to_x_coordinate()]);
+        }
+
+        // Compute the coefficients a_i := Hash(L, G^sk_sig_i, G^r_sig_i).
+        let coefficients = root_public_keys
+            .iter()
+            .map(|(g_sk_sig, g_r_sig)| {
+                let mut preimage = preimage.clone();
+                preimage.extend([g_sk_sig.to_x_coordinate(), g_r_sig.to_x_coordinate()]);
+                self.hash_to_scalar_field(&preimage)
+            })
+            .collect::<Vec<_>>();
+
+        // Compute G^sk_sig := Σ a_i G^sk_sig_i, and G^r_sig := Σ a_i G^r_sig_i.
+        let (g_sk_sig, g_r_sig) = root_public_keys.iter().zip(&coefficients).fold(
+            (TEProjective::<TE>::zero(), TEProjective::<TE>::zero()),
+            |(g_sk_sig, g_r_sig), ((g_sk_sig_i, g_r_sig_i), a_i)| {
+                (g_sk_sig + (*g_sk_sig_i * *a_i), g_r_sig + (*g_r_sig_i * *a_i))
+            },
+        );
+        if g_sk_sig.is_zero() || g_r_sig.is_zero() {
+            return Err(SignatureError::Message("The aggregate root public key is zero".into()).into());
+        }
+
+        let mut to_invert = [g_sk_sig, g_r_sig];
+        TEProjective::<TE>::batch_normalization(&mut to_invert);
+        let [root_public_key, root_randomizer] = to_invert.map(|a| a.to_affine());
+
+        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+        let sk_prf = self.hash_to_scalar_field(&[root_public_key.to_x_coordinate(), root_randomizer.to_x_coordinate()]);
+
+        // Compute G^sk_sig G^r_sig G^sk_prf.
+        let public_key = (g_sk_sig + g_r_sig + self.g_scalar_multiply(&sk_prf)).to_affine();
+
+        Ok(AggregatePublicKey {
+            root_public_keys: root_public_keys.to_vec(),
+            coefficients,
+            root_public_key,
+            root_randomizer,
+            public_key,
+        })
+    }
+
+    ///
+    /// Returns fresh nonces (r_1, r_2) for a single signing session, and their public nonces (G^r_1, G^r_2).
+    ///
+    pub fn generate_nonces<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SecretNonce<TE>, PublicNonce<TE>) {
+        let nonces = [TE::ScalarField::rand(rng), TE::ScalarField::rand(rng)];
+
+        let mut to_invert = nonces.map(|r| self.g_scalar_multiply(&r));
+        TEProjective::<TE>::batch_normalization(&mut to_invert);
+        (SecretNonce(nonces), PublicNonce(to_invert.map(|a| a.to_affine())))
+    }
+
+    ///
+    /// Returns the sum of the public nonces of all signers.
+    ///
+    pub fn aggregate_nonces(&self, public_nonces: &[PublicNonce<TE>]) -> Result<PublicNonce<TE>> {
+        if public_nonces.is_empty() {
+            return Err(SignatureError::Message("Cannot aggregate an empty set of nonces".into()).into());
+        }
+
+        let mut sum = public_nonces.iter().fold([TEProjective::<TE>::zero(); 2], |[first, second], nonce| {
+            [first + nonce.0[0].to_projective(), second + nonce.0[1].to_projective()]
+        });
+        TEProjective::<TE>::batch_normalization(&mut sum);
+        Ok(PublicNonce(sum.map(|a| a.to_affine())))
+    }
+
+    ///
+    /// Returns the partial signature s_i := r_1 + b r_2 - c a_i sk_sig of the signer at the given index.
+    ///
+    pub fn partial_sign(
+        &self,
+        private_key: &(TE::ScalarField, TE::ScalarField),
+        aggregate_public_key: &AggregatePublicKey<TE>,
+        signer_index: usize,
+        secret_nonce: SecretNonce<TE>,
+        aggregate_nonce: &PublicNonce<TE>,
+        message: &[bool],
+    ) -> Result<PartialSignature<TE>> {
+        // Ensure the private key belongs to the signer at the given index.
+        match aggregate_public_key.root_public_keys.get(signer_index) {
+            Some(root_public_keys) if *root_public_keys == self.root_public_keys(private_key) => (),
+            _ => return Err(SignatureError::Message(format!("The private key is not signer {signer_index}")).into()),
+        }
+
+        // Compute the nonce binding coefficient b, and the verifier challenge c.
+        let (binding, verifier_challenge) = self.session_challenges(aggregate_public_key, aggregate_nonce, message)?;
+
+        let SecretNonce(mut nonces) = secret_nonce;
+        let (sk_sig, _) = private_key;
+        let a_i = aggregate_public_key.coefficients[signer_index];
+        let partial_signature = nonces[0] + (binding * nonces[1]) - (verifier_challenge * a_i * sk_sig);
+
+        // Wipe the nonces, as they reveal the private key given the partial signature.
+        nonces.zeroize();
+
+        Ok(PartialSignature(partial_signature))
+    }
+
+    ///
+    /// Verifies (G^s_i G^sk_sig_i^(c a_i) == R_1_i R_2_i^b) for the partial signature of the signer at the given index.
+    ///
+    pub fn verify_partial(
+        &self,
+        aggregate_public_key: &AggregatePublicKey<TE>,
+        signer_index: usize,
+        public_nonce: &PublicNonce<TE>,
+        aggregate_nonce: &PublicNonce<TE>,
+        message: &[bool],
+        partial_signature: &PartialSignature<TE>,
+    ) -> Result<bool> {
+        let (g_sk_sig_i, _) = match aggregate_public_key.root_public_keys.get(signer_index) {
+            Some(root_public_keys) => root_public_keys,
+            None => return Err(SignatureError::Message(format!("Signer {signer_index} does not exist")).into()),
+        };
+        let a_i = aggregate_public_key.coefficients[signer_index];
+
+        // Compute the nonce binding coefficient b, and the verifier challenge c.
+        let (binding, verifier_challenge) = self.session_challenges(aggregate_public_key, aggregate_nonce, message)?;
+
+        let candidate = self.g_scalar_multiply(&partial_signature.0) + (*g_sk_sig_i * (verifier_challenge * a_i));
+        let expected = public_nonce.0[0].to_projective() + (public_nonce.0[1] * binding);
+        Ok(candidate == expected)
+    }
+
+    ///
+    /// Returns the aggregate signature (c, s, G^sk_sig, G^r_sig), where s := Σ s_i.
+    ///
+    pub fn aggregate_partial_signatures(
+        &self,
+        aggregate_public_key: &AggregatePublicKey<TE>,
+        aggregate_nonce: &PublicNonce<TE>,
+        message: &[bool],
+        partial_signatures: &[PartialSignature<TE>],
+    ) -> Result<AleoSignature<TE>> {
+        if partial_signatures.len() != aggregate_public_key.num_signers() {
+            return Err(SignatureError::Message(format!(
+                "Expected {} partial signatures, found {}",
+                aggregate_public_key.num_signers(),
+                partial_signatures.len()
+            ))
+            .into());
+        }
+
+        // Compute the verifier challenge c.
+        let (_, verifier_challenge) = self.session_challenges(aggregate_public_key, aggregate_nonce, message)?;
+
+        Ok(AleoSignature {
+            prover_response: partial_signatures.iter().map(|s_i| s_i.0).sum(),
+            verifier_challenge,
+            root_public_key: aggregate_public_key.root_public_key.to_x_coordinate(),
+            root_randomizer: aggregate_public_key.root_randomizer.to_x_coordinate(),
+        })
+    }
+
+    ///
+    /// Returns the nonce binding coefficient b := Hash(public_key, R_1, R_2, message),
+    /// and the verifier challenge c := Hash(public_key, R_1 R_2^b, message).
+    ///
+    fn session_challenges(
+        &self,
+        aggregate_public_key: &AggregatePublicKey<TE>,
+        aggregate_nonce: &PublicNonce<TE>,
+        message: &[bool],
+    ) -> Result<(TE::ScalarField, TE::ScalarField)> {
+        let PublicNonce([r_1, r_2]) = aggregate_nonce;
+
+        // Construct the hash input (domain, public_key, R_1, R_2, message).
+        let mut preimage = vec![TE::BaseField::from_bytes_le_mod_order(NONCE_BINDING_DOMAIN.as_bytes())];
+        preimage.push(aggregate_public_key.public_key.to_x_coordinate());
+        preimage.push(r_1.to_x_coordinate());
+        preimage.push(r_2.to_x_coordinate());
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Compute the nonce binding coefficient b.
+        let binding = self.hash_to_scalar_field(&preimage);
+
+        // Compute the session nonce R := R_1 R_2^b, and the verifier challenge c.
+        let g_r = (r_1.to_projective() + (*r_2 * binding)).to_affine();
+        let verifier_challenge = self.verifier_challenge(&aggregate_public_key.public_key, &g_r, message)?;
+
+        Ok((binding, verifier_challenge))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SignatureScheme;
+    use snarkvm_curves::edwards_bls12::EdwardsParameters as EdwardsBls12;
+    use snarkvm_utilities::{test_crypto_rng, ToBits};
+
+    type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+    fn multi_sign_and_verify(num_signers: usize) {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("multi_sign_and_verify");
+        let message = b"Hi, I am an Aleo multi-signature!".to_bits_le();
+
+        // Aggregate the public keys of the signers.
+        let private_keys = (0..num_signers).map(|_| signature_scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let root_public_keys =
+            private_keys.iter().map(|private_key| signature_scheme.root_public_keys(private_key)).collect::<Vec<_>>();
+        let aggregate_public_key = signature_scheme.aggregate_public_keys(&root_public_keys).unwrap();
+        assert_eq!(num_signers, aggregate_public_key.num_signers());
+
+        // Aggregate the nonces of the signers.
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+            (0..num_signers).map(|_| signature_scheme.generate_nonces(rng)).unzip();
+        let aggregate_nonce = signature_scheme.aggregate_nonces(&public_nonces).unwrap();
+
+        // Compute and check the partial signatures.
+        let partial_signatures = private_keys
+            .iter()
+            .zip(secret_nonces)
+            .enumerate()
+            .map(|(i, (private_key, secret_nonce))| {
+                signature_scheme
+                    .partial_sign(private_key, &aggregate_public_key, i, secret_nonce, &aggregate_nonce, &message)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for (i, (public_nonce, partial_signature)) in public_nonces.iter().zip(&partial_signatures).enumerate() {
+            assert!(
+                signature_scheme
+                    .verify_partial(
+                        &aggregate_public_key,
+                        i,
+                        public_nonce,
+                        &aggregate_nonce,
+                        &message,
+                        partial_signature
+                    )
+                    .unwrap()
+            );
+        }
+
+        // Ensure the aggregate signature verifies as an ordinary signature.
+        let signature = signature_scheme
+            .aggregate_partial_signatures(&aggregate_public_key, &aggregate_nonce, &message, &partial_signatures)
+            .unwrap();
+        let public_key = aggregate_public_key.public_key();
+        assert!(signature_scheme.verify(public_key, &message, &signature).unwrap());
+        assert!(!signature_scheme.verify(public_key, &b"Bad message".to_bits_le(), &signature).unwrap());
+
+        // Ensure a partial signature is rejected for a different signer.
+        if num_signers > 1 {
+            assert!(
+                !signature_scheme
+                    .verify_partial(
+                        &aggregate_public_key,
+                        1,
+                        &public_nonces[0],
+                        &aggregate_nonce,
+                        &message,
+                        &partial_signatures[0]
+                    )
+                    .unwrap()
+            );
+        }
+
+        // Ensure the aggregate signature requires every partial signature.
+        let missing = &partial_signatures[1..];
+        assert!(
+            signature_scheme
+                .aggregate_partial_signatures(&aggregate_public_key, &aggregate_nonce, &message, missing)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_multi_signature() {
+        multi_sign_and_verify(1);
+        multi_sign_and_verify(2);
+        multi_sign_and_verify(5);
+    }
+
+    #[test]
+    fn test_partial_sign_with_wrong_private_key() {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("partial_sign_with_wrong_private_key");
+        let message = b"Hi, I am an Aleo multi-signature!".to_bits_le();
+
+        let private_keys = (0..2).map(|_| signature_scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let root_public_keys =
+            private_keys.iter().map(|private_key| signature_scheme.root_public_keys(private_key)).collect::<Vec<_>>();
+        let aggregate_public_key = signature_scheme.aggregate_public_keys(&root_public_keys).unwrap();
+
+        let (secret_nonce, public_nonce) = signature_scheme.generate_nonces(rng);
+        let result = signature_scheme.partial_sign(
+            &private_keys[0],
+            &aggregate_public_key,
+            1,
+            secret_nonce,
+            &public_nonce,
+            &message,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_aggregate_public_key_depends_on_order() {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("aggregate_public_key_depends_on_order");
+
+        let mut root_public_keys = (0..3)
+            .map(|_| signature_scheme.root_public_keys(&signature_scheme.generate_private_key(rng)))
+            .collect::<Vec<_>>();
+        let aggregate_public_key = signature_scheme.aggregate_public_keys(&root_public_keys).unwrap();
+        root_public_keys.swap(0, 2);
+        let swapped_public_key = signature_scheme.aggregate_public_keys(&root_public_keys).unwrap();
+        assert_ne!(aggregate_public_key.public_key(), swapped_public_key.public_key());
+
+        assert!(signature_scheme.aggregate_public_keys(&[]).is_err());
+    }
+}
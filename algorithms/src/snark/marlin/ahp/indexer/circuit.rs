@@ -142,6 +142,33 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalSerialize for Circuit<F, MM> {
         size += CanonicalSerialize::uncompressed_size(&self.mode);
         size
     }
+
+    #[allow(unused_mut, unused_variables)]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_compact(&self.index_info, writer)?;
+        CanonicalSerialize::serialize_compact(&self.a, writer)?;
+        CanonicalSerialize::serialize_compact(&self.b, writer)?;
+        CanonicalSerialize::serialize_compact(&self.c, writer)?;
+        CanonicalSerialize::serialize_compact(&self.a_arith, writer)?;
+        CanonicalSerialize::serialize_compact(&self.b_arith, writer)?;
+        CanonicalSerialize::serialize_compact(&self.c_arith, writer)?;
+        CanonicalSerialize::serialize_compact(&self.mode, writer)?;
+        Ok(())
+    }
+
+    #[allow(unused_mut, unused_variables)]
+    fn compact_size(&self) -> usize {
+        let mut size = 0;
+        size += CanonicalSerialize::compact_size(&self.index_info);
+        size += CanonicalSerialize::compact_size(&self.a);
+        size += CanonicalSerialize::compact_size(&self.b);
+        size += CanonicalSerialize::compact_size(&self.c);
+        size += CanonicalSerialize::compact_size(&self.a_arith);
+        size += CanonicalSerialize::compact_size(&self.b_arith);
+        size += CanonicalSerialize::compact_size(&self.c_arith);
+        size += CanonicalSerialize::compact_size(&self.mode);
+        size
+    }
 }
 impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
     #[allow(unused_mut, unused_variables)]
@@ -212,4 +239,37 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
             })
         }
     }
+
+    #[allow(unused_mut, unused_variables)]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let index_info: CircuitInfo<F> = CanonicalDeserialize::deserialize_compact(reader)?;
+        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_constraints)
+            .ok_or(SerializationError::InvalidData)?;
+        let non_zero_a_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_a)
+            .ok_or(SerializationError::InvalidData)?;
+        let non_zero_b_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_b)
+            .ok_or(SerializationError::InvalidData)?;
+        let non_zero_c_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_c)
+            .ok_or(SerializationError::InvalidData)?;
+
+        let (fft_precomputation, ifft_precomputation) = AHPForR1CS::<F, MM>::fft_precomputation(
+            constraint_domain_size,
+            non_zero_a_domain_size,
+            non_zero_b_domain_size,
+            non_zero_c_domain_size,
+        )
+        .ok_or(SerializationError::InvalidData)?;
+        Ok(Circuit {
+            index_info,
+            a: CanonicalDeserialize::deserialize_compact(reader)?,
+            b: CanonicalDeserialize::deserialize_compact(reader)?,
+            c: CanonicalDeserialize::deserialize_compact(reader)?,
+            a_arith: CanonicalDeserialize::deserialize_compact(reader)?,
+            b_arith: CanonicalDeserialize::deserialize_compact(reader)?,
+            c_arith: CanonicalDeserialize::deserialize_compact(reader)?,
+            fft_precomputation,
+            ifft_precomputation,
+            mode: CanonicalDeserialize::deserialize_compact(reader)?,
+        })
+    }
 }
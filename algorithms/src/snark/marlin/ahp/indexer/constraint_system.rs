@@ -14,9 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::ahp::matrices::{make_matrices_square, padded_matrix_dim, to_matrix_helper};
+use crate::snark::marlin::ahp::matrices::{make_matrices_square, padded_matrix_dim};
 use snarkvm_fields::Field;
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintMatrices,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    SparseMatrix,
+    ToConstraintMatrices,
+    Variable,
+};
 use snarkvm_utilities::serialize::*;
 
 /// Stores constraints during index generation.
@@ -42,21 +51,6 @@ impl<F: Field> ConstraintSystem<F> {
         }
     }
 
-    #[inline]
-    pub(crate) fn a_matrix(&self) -> Vec<Vec<(F, usize)>> {
-        to_matrix_helper(&self.a, self.num_public_variables)
-    }
-
-    #[inline]
-    pub(crate) fn b_matrix(&self) -> Vec<Vec<(F, usize)>> {
-        to_matrix_helper(&self.b, self.num_public_variables)
-    }
-
-    #[inline]
-    pub(crate) fn c_matrix(&self) -> Vec<Vec<(F, usize)>> {
-        to_matrix_helper(&self.c, self.num_public_variables)
-    }
-
     #[inline]
     pub(crate) fn make_matrices_square(&mut self) {
         let num_variables = self.num_public_variables + self.num_private_variables;
@@ -76,6 +70,24 @@ impl<F: Field> ConstraintSystem<F> {
     }
 }
 
+impl<F: Field> ToConstraintMatrices<F> for ConstraintSystem<F> {
+    fn to_constraint_matrices(&self) -> ConstraintMatrices<F> {
+        let num_variables = self.num_public_variables + self.num_private_variables;
+        let matrix = |rows: &[Vec<(F, VarIndex)>]| {
+            let rows = rows.iter().map(|row| row.iter().map(|(coeff, index)| (*index, *coeff)));
+            SparseMatrix::from_rows(self.num_public_variables, num_variables, rows)
+        };
+
+        ConstraintMatrices {
+            num_public_variables: self.num_public_variables,
+            num_private_variables: self.num_private_variables,
+            a: matrix(&self.a),
+            b: matrix(&self.b),
+            c: matrix(&self.c),
+        }
+    }
+}
+
 impl<F: Field> CS<F> for ConstraintSystem<F> {
     type Root = Self;
 
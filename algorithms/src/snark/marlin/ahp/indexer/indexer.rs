@@ -28,7 +28,13 @@ use crate::{
     },
 };
 use snarkvm_fields::PrimeField;
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    CountingConstraintSystem,
+    ToConstraintMatrices,
+};
 
 use core::marker::PhantomData;
 
@@ -49,9 +55,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         crate::snark::marlin::ahp::matrices::pad_input_for_indexer_and_prover(&mut ics);
         ics.make_matrices_square();
 
-        let a = ics.a_matrix();
-        let b = ics.b_matrix();
-        let c = ics.c_matrix();
+        let matrices = ics.to_constraint_matrices();
+        let a = matrices.a.to_rows();
+        let b = matrices.b.to_rows();
+        let c = matrices.c.to_rows();
 
         // balance_matrices(&mut a, &mut b);
         end_timer!(padding_time);
@@ -139,4 +146,31 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             mode: PhantomData,
         })
     }
+
+    /// Returns the information of the index for this constraint system, without generating the index.
+    /// The constraints are only counted, which makes this much cheaper than [`Self::index`].
+    pub fn index_info<C: ConstraintSynthesizer<F>>(c: &C) -> Result<CircuitInfo<F>, AHPError> {
+        let mut cs = CountingConstraintSystem::new();
+        c.generate_constraints(&mut cs)?;
+
+        crate::snark::marlin::ahp::matrices::pad_input_for_indexer_and_prover::<F, _>(&mut cs);
+        let num_variables = cs.num_variables();
+        crate::snark::marlin::ahp::matrices::make_matrices_square::<F, _>(&mut cs, num_variables);
+
+        if cs.num_constraints != cs.num_variables() {
+            return Err(AHPError::NonSquareMatrix);
+        }
+        if !Self::num_formatted_public_inputs_is_admissible(cs.num_public_variables) {
+            return Err(AHPError::InvalidPublicInputLength);
+        }
+
+        Ok(CircuitInfo {
+            num_variables: cs.num_variables(),
+            num_constraints: cs.num_constraints,
+            num_non_zero_a: cs.num_non_zero_a,
+            num_non_zero_b: cs.num_non_zero_b,
+            num_non_zero_c: cs.num_non_zero_c,
+            f: PhantomData,
+        })
+    }
 }
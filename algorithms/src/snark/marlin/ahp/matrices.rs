@@ -23,34 +23,14 @@ use crate::{
 };
 use itertools::Itertools;
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
-use snarkvm_r1cs::{ConstraintSystem, Index as VarIndex};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*};
+use snarkvm_r1cs::ConstraintSystem;
+use snarkvm_utilities::{cfg_iter_mut, serialize::*};
 
 use hashbrown::HashMap;
 
-use std::collections::BTreeMap;
-
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-// This function converts a matrix output by Zexe's constraint infrastructure
-// to the one used in this crate.
-pub(crate) fn to_matrix_helper<F: Field>(matrix: &[Vec<(F, VarIndex)>], num_input_variables: usize) -> Matrix<F> {
-    cfg_iter!(matrix)
-        .map(|row| {
-            let mut row_map = BTreeMap::new();
-            row.iter().for_each(|(fe, column)| {
-                let column = match column {
-                    VarIndex::Public(i) => *i,
-                    VarIndex::Private(i) => num_input_variables + i,
-                };
-                *row_map.entry(column).or_insert_with(F::zero) += *fe;
-            });
-            row_map.into_iter().map(|(column, coeff)| (coeff, column)).collect()
-        })
-        .collect()
-}
-
 /// This must *always* be in sync with `make_matrices_square`.
 pub(crate) fn padded_matrix_dim(num_formatted_variables: usize, num_constraints: usize) -> usize {
     core::cmp::max(num_formatted_variables, num_constraints)
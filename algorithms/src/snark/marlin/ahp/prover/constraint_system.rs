@@ -15,10 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::snark::marlin::ahp::matrices::make_matrices_square;
-use snarkvm_fields::Field;
+use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use snarkvm_utilities::Zeroize;
 
-pub(crate) struct ConstraintSystem<F: Field> {
+/// The constraint system used by the prover, which records the assignment of each variable.
+/// The private assignment is wiped when the constraint system is dropped.
+pub(crate) struct ConstraintSystem<F: PrimeField> {
     pub(crate) public_variables: Vec<F>,
     pub(crate) private_variables: Vec<F>,
     pub(crate) num_public_variables: usize,
@@ -26,7 +29,7 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_constraints: usize,
 }
 
-impl<F: Field> ConstraintSystem<F> {
+impl<F: PrimeField> ConstraintSystem<F> {
     pub(crate) fn new() -> Self {
         Self {
             public_variables: vec![F::one()],
@@ -58,7 +61,7 @@ impl<F: Field> ConstraintSystem<F> {
     }
 }
 
-impl<F: Field> CS<F> for ConstraintSystem<F> {
+impl<F: PrimeField> CS<F> for ConstraintSystem<F> {
     type Root = Self;
 
     #[inline]
@@ -133,3 +136,9 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         false
     }
 }
+
+impl<F: PrimeField> Drop for ConstraintSystem<F> {
+    fn drop(&mut self) {
+        self.private_variables.zeroize();
+    }
+}
@@ -26,7 +26,7 @@ use crate::{
     },
 };
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::cfg_into_iter;
+use snarkvm_utilities::{cfg_into_iter, Zeroize};
 
 use rand_core::RngCore;
 
@@ -136,6 +136,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 _ => w_extended[k - (k / ratio) - 1] - x_evals[k],
             })
             .collect();
+        w_extended.zeroize();
         let w_poly = EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, constraint_domain)
             .interpolate_with_pc(state.ifft_precomputation());
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(input_domain).unwrap();
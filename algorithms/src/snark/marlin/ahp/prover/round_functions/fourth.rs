@@ -30,11 +30,11 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the fourth round message and the next state.
     pub fn prover_fourth_round<'a, R: RngCore>(
         verifier_message: &verifier::ThirdMessage<F>,
-        state: prover::State<'a, F, MM>,
+        mut state: prover::State<'a, F, MM>,
         _r: &mut R,
     ) -> Result<prover::FourthOracles<F>, AHPError> {
         let verifier::ThirdMessage { r_b, r_c, .. } = verifier_message;
-        let [mut lhs_a, mut lhs_b, mut lhs_c] = state.lhs_polynomials.unwrap();
+        let [mut lhs_a, mut lhs_b, mut lhs_c] = state.lhs_polynomials.take().unwrap();
         lhs_b *= *r_b;
         lhs_c *= *r_c;
 
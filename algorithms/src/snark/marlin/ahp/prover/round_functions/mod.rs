@@ -52,18 +52,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let num_non_zero_b = index.index_info.num_non_zero_b;
         let num_non_zero_c = index.index_info.num_non_zero_c;
 
-        let prover::ConstraintSystem {
-            public_variables: padded_public_variables,
-            private_variables,
-            num_constraints,
-            num_public_variables,
-            num_private_variables,
-            ..
-        } = pcs;
-
-        assert_eq!(padded_public_variables.len(), num_public_variables);
-        assert!(padded_public_variables[0].is_one());
-        assert_eq!(private_variables.len(), num_private_variables);
+        let num_constraints = pcs.num_constraints;
+        let num_public_variables = pcs.num_public_variables;
+        let num_private_variables = pcs.num_private_variables;
+
+        assert_eq!(pcs.public_variables.len(), num_public_variables);
+        assert!(pcs.public_variables[0].is_one());
+        assert_eq!(pcs.private_variables.len(), num_private_variables);
 
         if cfg!(debug_assertions) {
             println!("Number of padded public variables in Prover::Init: {}", num_public_variables);
@@ -80,7 +75,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             return Err(AHPError::InstanceDoesNotMatchIndex);
         }
 
-        if !Self::formatted_public_input_is_admissible(&padded_public_variables) {
+        if !Self::formatted_public_input_is_admissible(&pcs.public_variables) {
             return Err(AHPError::InvalidPublicInputLength);
         }
 
@@ -91,8 +86,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             for &(ref coefficient, i) in row {
                 // Fetch the variable.
                 let variable = match i < num_public_variables {
-                    true => padded_public_variables[i],
-                    false => private_variables[i - num_public_variables],
+                    true => pcs.public_variables[i],
+                    false => pcs.private_variables[i - num_public_variables],
                 };
 
                 result += &(if coefficient.is_one() { variable } else { variable * coefficient });
@@ -112,6 +107,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let zk_bound = MM::ZK.then(|| 1); // One query is sufficient for our desired soundness
 
         end_timer!(init_time);
+        // Move the assignment into the prover state, which is responsible for wiping it from here on.
+        let padded_public_variables = core::mem::take(&mut pcs.public_variables);
+        let private_variables = core::mem::take(&mut pcs.private_variables);
         let mut state = prover::State::initialize(padded_public_variables, private_variables, zk_bound, index)?;
         state.z_a = Some(z_a);
         state.z_b = Some(z_b);
@@ -38,7 +38,7 @@ use crate::{
     },
 };
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::cfg_iter_mut;
+use snarkvm_utilities::{cfg_iter_mut, Zeroize};
 
 use itertools::Itertools;
 use rand_core::RngCore;
@@ -100,7 +100,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
         };
 
-        state.w_poly = None;
+        state.w_poly.zeroize();
         state.verifier_first_message = Some(*verifier_message);
         end_timer!(round_time);
 
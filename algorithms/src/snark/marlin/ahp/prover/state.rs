@@ -30,8 +30,10 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::Zeroize;
 
 /// State for the AHP prover.
+/// The witness-dependent values are wiped when the state is dropped.
 pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     pub(super) padded_public_variables: Vec<F>,
     pub(super) private_variables: Vec<F>,
@@ -138,3 +140,16 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         &self.index.ifft_precomputation
     }
 }
+
+impl<'a, F: PrimeField, MM: MarlinMode> Drop for State<'a, F, MM> {
+    fn drop(&mut self) {
+        self.private_variables.zeroize();
+        self.z_a.zeroize();
+        self.z_b.zeroize();
+        self.w_poly.zeroize();
+        self.mz_polys.zeroize();
+        self.mz_poly_randomizer.zeroize();
+        self.mask_poly.zeroize();
+        self.lhs_polynomials.zeroize();
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    polycommit::sonic_pc::{BatchCheckElements, SonicKZG10},
+    snark::marlin::{
+        params::OptimizationType,
+        CircuitVerifyingKey,
+        FiatShamirRng,
+        MarlinError,
+        MarlinMode,
+        MarlinSNARK,
+        Proof,
+    },
+};
+use itertools::Itertools;
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, ToConstraintField};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use core::iter;
+
+impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: ToConstraintField<E::Fr>>
+    MarlinSNARK<E, FS, MM, Input>
+{
+    /// The personalization string for batch verification.
+    /// Used to personalize the Fiat-Shamir RNG that samples the batching coefficients.
+    pub const BATCH_VERIFICATION_PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019-BATCH-VERIFY";
+
+    ///
+    /// Verifies that every given proof for the same circuit asserts that all constraints are satisfied
+    /// for its corresponding public input, using a single product of pairings.
+    ///
+    /// Each proof is reduced to the group elements of its final polynomial commitment check,
+    /// and these are folded with Fiat-Shamir coefficients into a single pairing check.
+    /// Note: Only the pairing check is amortized. Every proof is still given to the verifier,
+    /// whose transcript and AHP checks remain linear in the number of proofs.
+    ///
+    /// Returns `false` for an empty batch, and an error if the number of public inputs
+    /// does not match the number of proofs.
+    ///
+    pub fn batch_verify(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[Vec<E::Fr>],
+        proofs: &[Proof<E>],
+    ) -> Result<bool, MarlinError> {
+        let verifier_time = start_timer!(|| format!("Marlin::BatchVerify of {} proofs", proofs.len()));
+
+        if public_inputs.len() != proofs.len() {
+            return Err(MarlinError::BatchSizeMismatch(proofs.len(), public_inputs.len()));
+        }
+        if proofs.is_empty() {
+            return Ok(false);
+        }
+
+        // The batching coefficients are sampled after absorbing every statement in the batch,
+        // so that no proof can be chosen to cancel out a failing check of another.
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(&to_bytes_le![&Self::BATCH_VERIFICATION_PROTOCOL_NAME].unwrap());
+        fs_rng.absorb_native_field_elements(&circuit_verifying_key.circuit_commitments);
+        for (public_input, proof) in public_inputs.iter().zip_eq(proofs) {
+            fs_rng.absorb_nonnative_field_elements(public_input.iter().copied(), OptimizationType::Weight);
+            Self::absorb_proof(proof, &mut fs_rng)?;
+        }
+        let coefficients = iter::once(E::Fr::one())
+            .chain(fs_rng.squeeze_short_nonnative_field_elements(proofs.len() - 1)?)
+            .collect::<Vec<_>>();
+
+        let mut combined_elements = BatchCheckElements::default();
+        for ((public_input, proof), coefficient) in public_inputs.iter().zip_eq(proofs).zip_eq(coefficients) {
+            match Self::verify_elements(circuit_verifying_key, public_input, proof)? {
+                Some(elements) => combined_elements.add_assign_scaled(coefficient, &elements),
+                None => return Ok(false),
+            }
+        }
+
+        let is_valid = SonicKZG10::<E, FS>::check_elements(combined_elements, &circuit_verifying_key.verifier_key)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+
+    fn absorb_proof(proof: &Proof<E>, fs_rng: &mut FS) -> Result<(), MarlinError> {
+        let comms = &proof.commitments;
+        fs_rng.absorb_native_field_elements(&[comms.w, comms.z_a, comms.z_b]);
+        if let Some(mask_poly) = comms.mask_poly {
+            fs_rng.absorb_native_field_elements(&[mask_poly]);
+        }
+        fs_rng.absorb_native_field_elements(&[comms.g_1, comms.h_1, comms.g_a, comms.g_b, comms.g_c, comms.h_2]);
+        fs_rng.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
+        fs_rng.absorb_nonnative_field_elements(
+            [proof.msg.sum_a, proof.msg.sum_b, proof.msg.sum_c],
+            OptimizationType::Weight,
+        );
+        proof.pc_proof.proof.absorb_into_sponge(fs_rng)?;
+        Ok(())
+    }
+}
@@ -15,10 +15,16 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinMode},
+    fft::DenseOrSparsePolynomial,
+    polycommit::sonic_pc::{self, LabeledPolynomial},
+    snark::marlin::{
+        ahp::{indexer::*, matrices::MatrixArithmetization},
+        CircuitVerifyingKey,
+        MarlinMode,
+    },
 };
 use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
     io::{self, Read, Write},
     serialize::*,
@@ -26,6 +32,11 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+#[cfg(feature = "mmap")]
+use core::marker::PhantomData;
+#[cfg(feature = "mmap")]
+use std::path::Path;
+
 /// Proving key for a specific circuit (i.e., R1CS matrices).
 #[derive(Clone, Debug)]
 pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
@@ -60,3 +71,117 @@ impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitProvingKey<E, MM> {
         Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
     }
 }
+
+impl<E: PairingEngine, MM: MarlinMode> CircuitProvingKey<E, MM> {
+    /// Reads a proving key written by `ToBytes`, skipping the subgroup checks on its group elements.
+    /// This must only be used on trusted inputs, such as a proving key stored locally.
+    pub fn read_le_unchecked<R: Read>(mut reader: R) -> io::Result<Self> {
+        let circuit_verifying_key = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let circuit_commitment_randomness = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let circuit = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let committer_key = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
+    }
+
+    /// Returns the approximate number of bytes the proving key occupies in memory, including its heap allocations.
+    /// This is typically several times larger than its serialized size, as it holds the FFT precomputations
+    /// and the evaluations of the indexed polynomials alongside their coefficients.
+    pub fn memory_footprint(&self) -> usize {
+        let vk = &self.circuit_verifying_key;
+        let verifying_key = vec_heap_size(&vk.circuit_commitments)
+            + vk.verifier_key.degree_bounds_and_neg_powers_of_h.as_ref().map_or(0, vec_heap_size)
+            + vk.verifier_key.degree_bounds_and_prepared_neg_powers_of_h.as_ref().map_or(0, vec_heap_size);
+
+        let randomness = vec_heap_size(&self.circuit_commitment_randomness)
+            + self
+                .circuit_commitment_randomness
+                .iter()
+                .map(|r| vec_heap_size(&r.blinding_polynomial.coeffs))
+                .sum::<usize>();
+
+        let indexed = &self.circuit;
+        let circuit = [&indexed.a, &indexed.b, &indexed.c]
+            .iter()
+            .map(|matrix| vec_heap_size(matrix) + matrix.iter().map(vec_heap_size).sum::<usize>())
+            .sum::<usize>()
+            + [&indexed.a_arith, &indexed.b_arith, &indexed.c_arith]
+                .iter()
+                .map(|arith| arithmetization_heap_size(arith))
+                .sum::<usize>()
+            + indexed.fft_precomputation.heap_size()
+            + indexed.ifft_precomputation.heap_size();
+
+        let ck = &self.committer_key;
+        let committer_key = vec_heap_size(&ck.powers_of_beta_g)
+            + ck.lagrange_bases_at_beta_g.values().map(vec_heap_size).sum::<usize>()
+            + vec_heap_size(&ck.powers_of_beta_times_gamma_g)
+            + ck.shifted_powers_of_beta_g.as_ref().map_or(0, vec_heap_size)
+            + ck.shifted_powers_of_beta_times_gamma_g
+                .as_ref()
+                .map_or(0, |powers| powers.values().map(vec_heap_size).sum::<usize>())
+            + ck.enforced_degree_bounds.as_ref().map_or(0, vec_heap_size);
+
+        core::mem::size_of::<Self>() + verifying_key + randomness + circuit + committer_key
+    }
+}
+
+/// Returns the number of bytes allocated on the heap for the elements of the given vector.
+fn vec_heap_size<T>(vector: &Vec<T>) -> usize {
+    vector.capacity() * core::mem::size_of::<T>()
+}
+
+/// Returns the number of bytes allocated on the heap for the given labeled polynomial.
+fn polynomial_heap_size<F: PrimeField>(polynomial: &LabeledPolynomial<F>) -> usize {
+    let coefficients = match polynomial.polynomial() {
+        DenseOrSparsePolynomial::DPolynomial(polynomial) => vec_heap_size(&polynomial.coeffs),
+        DenseOrSparsePolynomial::SPolynomial(polynomial) => {
+            polynomial.coeffs().count() * core::mem::size_of::<(usize, F)>()
+        }
+    };
+    polynomial.label().capacity() + coefficients
+}
+
+/// Returns the number of bytes allocated on the heap for the given matrix arithmetization.
+fn arithmetization_heap_size<F: PrimeField>(arith: &MatrixArithmetization<F>) -> usize {
+    let evals = &arith.evals_on_K;
+    [&arith.row, &arith.col, &arith.row_col, &arith.val].iter().map(|p| polynomial_heap_size(p)).sum::<usize>()
+        + [&evals.row, &evals.col, &evals.row_col, &evals.val]
+            .iter()
+            .map(|evaluations| vec_heap_size(&evaluations.evaluations))
+            .sum::<usize>()
+}
+
+/// A proving key written by `ToBytes` to a file, which is memory-mapped and only deserialized when needed.
+/// This allows a prover to keep handles to the proving keys of many circuits without holding them resident.
+#[cfg(feature = "mmap")]
+#[derive(Clone, Debug)]
+pub struct MappedCircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
+    /// The memory-mapped file containing the proving key.
+    file: MappedFile,
+    _phantom: PhantomData<(E, MM)>,
+}
+
+#[cfg(feature = "mmap")]
+impl<E: PairingEngine, MM: MarlinMode> MappedCircuitProvingKey<E, MM> {
+    /// Maps the proving key file at the given path into memory.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self { file: MappedFile::open(path)?, _phantom: PhantomData })
+    }
+
+    /// Deserializes the circuit verifying key, which is stored at the start of the proving key.
+    pub fn circuit_verifying_key(&self) -> io::Result<CircuitVerifyingKey<E, MM>> {
+        Ok(CanonicalDeserialize::deserialize(&mut self.file.bytes())?)
+    }
+
+    /// Deserializes the proving key.
+    pub fn load(&self) -> io::Result<CircuitProvingKey<E, MM>> {
+        CircuitProvingKey::read_le(self.file.bytes())
+    }
+
+    /// Deserializes the proving key, skipping the subgroup checks on its group elements.
+    /// This must only be used on trusted inputs, such as a proving key stored locally.
+    pub fn load_unchecked(&self) -> io::Result<CircuitProvingKey<E, MM>> {
+        CircuitProvingKey::read_le_unchecked(self.file.bytes())
+    }
+}
@@ -26,6 +26,8 @@ use core::fmt::Debug;
 pub enum MarlinError {
     /// The index is too large for the universal public parameters.
     IndexTooLarge(usize, usize),
+    /// The number of public inputs does not match the number of proofs in a batch.
+    BatchSizeMismatch(usize, usize),
     /// There was an error in the underlying holographic IOP.
     AHPError(AHPError),
     /// There was an error in Fiat-Shamir.
@@ -16,7 +16,7 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{BatchCheckElements, Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
         fiat_shamir::traits::FiatShamirRng,
@@ -37,7 +37,7 @@ use itertools::Itertools;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSynthesizer;
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, ToBytes, Zeroize};
 
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
@@ -73,6 +73,12 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         srs
     }
 
+    /// Returns the maximum degree of the universal SRS required to index the given circuit.
+    /// The circuit is only counted, and not indexed, which makes this cheap enough to size the SRS with.
+    pub fn max_degree<C: ConstraintSynthesizer<E::Fr>>(c: &C) -> Result<usize, MarlinError> {
+        Ok(AHPForR1CS::<_, MM>::index_info(c)?.max_degree::<MM>())
+    }
+
     /// Generate the index-specific (i.e., circuit-specific) prover and verifier
     /// keys. This is a trusted setup.
     ///
@@ -86,8 +92,7 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         c: &C,
         rng: &mut R,
     ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), MarlinError> {
-        let circuit = AHPForR1CS::<_, MM>::index(c)?;
-        let srs = Self::universal_setup(circuit.max_degree(), rng)?;
+        let srs = Self::universal_setup(Self::max_degree(c)?, rng)?;
         Self::circuit_setup(&srs, c)
     }
 
@@ -324,7 +329,7 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
             .collect();
 
         // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
+        let mut commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
             .circuit_commitment_randomness
             .clone()
             .into_iter()
@@ -375,7 +380,10 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
             &query_set.to_set(),
             &commitment_randomnesses,
             &mut fs_rng,
-        )?;
+        );
+        // Wipe the commitment randomness, as it is no longer needed.
+        commitment_randomnesses.zeroize();
+        let pc_proof = pc_proof?;
 
         Self::terminate(terminator)?;
 
@@ -417,6 +425,31 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         proof: &Proof<E>,
     ) -> Result<bool, MarlinError> {
         let verifier_time = start_timer!(|| "Marlin::Verify");
+        let evaluations_are_correct = match Self::verify_elements(circuit_verifying_key, public_input, proof)? {
+            Some(elements) => SonicKZG10::<E, FS>::check_elements(elements, &circuit_verifying_key.verifier_key)?,
+            None => false,
+        };
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::<E, FS>::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Runs the verifier for the given proof up to, but excluding, the final product of pairings,
+    /// and returns the group elements that the pairings would be computed over.
+    ///
+    /// Returns `None` if the proof is malformed for the Marlin mode.
+    pub(crate) fn verify_elements(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_input: &[E::Fr],
+        proof: &Proof<E>,
+    ) -> Result<Option<BatchCheckElements<E>>, MarlinError> {
         let comms = &proof.commitments;
         let first_commitments = if MM::ZK {
             vec![comms.w, comms.z_a, comms.z_b, comms.mask_poly.unwrap()]
@@ -438,7 +471,7 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
                 first_commitments.len(),
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let padded_public_input = {
@@ -533,7 +566,7 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
             &verifier_state,
         )?;
 
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let elements = SonicKZG10::<E, FS>::check_combinations_elements(
             &circuit_verifying_key.verifier_key,
             lc_s.values(),
             &commitments,
@@ -542,16 +575,7 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
             &proof.pc_proof,
             &mut fs_rng,
         )?;
-
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::<E, FS>::Check failed");
-        }
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        Ok(Some(elements))
     }
 
     /// Verify that a proof for the constraint system defined by `C` asserts that
@@ -26,6 +26,9 @@
 #![allow(clippy::module_inception)]
 #![allow(clippy::type_complexity)]
 
+/// Batch verification of Marlin proofs for the same circuit.
+mod batch_verify;
+
 /// Implements an Algebraic Holographic Proof (AHP) for the R1CS indexed relation.
 pub mod ahp;
 pub use ahp::*;
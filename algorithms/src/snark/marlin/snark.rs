@@ -93,6 +93,15 @@ where
     ) -> Result<bool, SNARKError> {
         Self::prepared_verify(prepared_verifying_key, &input.to_field_elements()?, proof).map_err(SNARKError::from)
     }
+
+    fn verify_batch(
+        verifying_key: &Self::VerifyingKey,
+        inputs: &[&Self::VerifierInput],
+        proofs: &[Self::Proof],
+    ) -> Result<bool, SNARKError> {
+        let public_inputs = inputs.iter().map(|input| input.to_field_elements()).collect::<Result<Vec<_>, _>>()?;
+        Self::batch_verify(verifying_key, &public_inputs, proofs).map_err(SNARKError::from)
+    }
 }
 
 #[cfg(test)]
@@ -105,7 +114,7 @@ pub mod test {
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
-    use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+    use snarkvm_r1cs::{ConstraintSystem, FnSynthesizer, InputLayout, SynthesisError};
     use snarkvm_utilities::{test_crypto_rng, UniformRand};
 
     use core::ops::MulAssign;
@@ -175,4 +184,60 @@ pub mod test {
             assert!(TestSNARK::verify(&vk.clone(), &[c], &proof).unwrap(), "The native verification check fails.");
         }
     }
+
+    #[test]
+    fn marlin_fn_synthesizer_test() {
+        let mut rng = test_crypto_rng();
+
+        let a_value = Fr::rand(&mut rng);
+        let b_value = Fr::rand(&mut rng);
+        let c_value = a_value * b_value;
+
+        // Define the circuit inline, instead of declaring a struct for it.
+        let circuit = FnSynthesizer::new(|cs| {
+            let a = cs.alloc(|| "a", || Ok(a_value))?;
+            let b = cs.alloc(|| "b", || Ok(b_value))?;
+            let c = cs.alloc_input(|| "c", || Ok(c_value))?;
+
+            for i in 0..10 {
+                cs.enforce(|| format!("constraint {}", i), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            }
+            Ok(())
+        });
+
+        let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&vk, &[c_value], &proof).unwrap());
+        assert!(!TestSNARK::verify(&vk, &[a_value], &proof).unwrap());
+    }
+
+    #[test]
+    fn marlin_input_layout_test() {
+        let mut rng = test_crypto_rng();
+
+        let a_value = Fr::rand(&mut rng);
+        let b_value = Fr::rand(&mut rng);
+        let c_value = a_value * b_value;
+
+        let circuit = FnSynthesizer::new(|cs| {
+            let a = cs.alloc_input(|| "a", || Ok(a_value))?;
+            let b = cs.alloc(|| "b", || Ok(b_value))?;
+            let mut cs = cs.ns(|| "product");
+            let c = cs.alloc_input(|| "c", || Ok(c_value))?;
+
+            for i in 0..10 {
+                cs.enforce(|| format!("constraint {}", i), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            }
+            Ok(())
+        });
+
+        // Assemble the verifier inputs by name, rather than by allocation order.
+        let layout = InputLayout::of(&circuit).unwrap();
+        let inputs = layout.assemble([("product/c", c_value), ("a", a_value)]).unwrap();
+
+        let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&vk, &inputs, &proof).unwrap());
+        assert!(!TestSNARK::verify(&vk, &[c_value, a_value], &proof).unwrap());
+    }
 }
@@ -16,6 +16,7 @@
 
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::Zeroize;
 
 #[derive(Copy, Clone)]
 pub struct Circuit<F: Field> {
@@ -64,6 +65,13 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
     }
 }
 
+impl<F: Field + Zeroize> Zeroize for Circuit<F> {
+    fn zeroize(&mut self) {
+        self.a.zeroize();
+        self.b.zeroize();
+    }
+}
+
 mod marlin {
     use super::*;
     use crate::snark::marlin::{
@@ -73,9 +81,14 @@ mod marlin {
         MarlinHidingMode,
         MarlinNonHidingMode,
         MarlinSNARK,
+        Proof,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        CanonicalDeserialize,
+        CanonicalSerialize,
+    };
 
     use blake2::Blake2s256;
     use core::ops::MulAssign;
@@ -111,6 +124,13 @@ mod marlin {
                         let proof = $marlin_inst::prove(&index_pk, &circ, rng).unwrap();
                         println!("Called prover");
 
+                        // Ensure the proof round trips through the compact encoding, which is smaller.
+                        let mut compact = vec![];
+                        proof.serialize_compact(&mut compact).unwrap();
+                        assert!(compact.len() < proof.serialized_size());
+                        assert_eq!(compact.len(), proof.compact_size());
+                        assert_eq!(proof, Proof::deserialize_compact(&mut &compact[..]).unwrap());
+
                         assert!($marlin_inst::verify(&index_vk, &[c, d], &proof).unwrap());
                         println!("Called verifier");
                         println!("\nShould not verify (i.e. verifier messages should print below):");
@@ -169,6 +189,46 @@ mod marlin {
                     assert_eq!(index_vk, CircuitVerifyingKey::read_le(&expected_bytes[..]).unwrap());
                     assert_eq!(index_vk, bincode::deserialize(&candidate_bytes[..]).unwrap());
                 }
+
+                #[cfg(feature = "mmap")]
+                pub(crate) fn test_mapped_proving_key(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::MappedCircuitProvingKey;
+                    use rand::Rng;
+                    use snarkvm_utilities::ToBytes;
+
+                    let rng = &mut test_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // Write the proving key to a file, and map it back.
+                    let path = std::env::temp_dir().join(format!("mapped_proving_key_{}", rng.gen::<u64>()));
+                    std::fs::write(&path, index_pk.to_bytes_le().unwrap()).unwrap();
+                    let mapped_pk = MappedCircuitProvingKey::<Bls12_377, $marlin_mode>::open(&path).unwrap();
+
+                    assert_eq!(index_vk, mapped_pk.circuit_verifying_key().unwrap());
+                    assert_eq!(index_pk.to_bytes_le().unwrap(), mapped_pk.load().unwrap().to_bytes_le().unwrap());
+                    assert_eq!(
+                        index_pk.to_bytes_le().unwrap(),
+                        mapped_pk.load_unchecked().unwrap().to_bytes_le().unwrap()
+                    );
+
+                    // Ensure the loaded proving key produces valid proofs.
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+                    let proof = $marlin_inst::prove(&mapped_pk.load().unwrap(), &circ, rng).unwrap();
+                    assert!($marlin_inst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+                    std::fs::remove_file(path).unwrap();
+                }
             }
         };
     }
@@ -249,6 +309,106 @@ mod marlin {
 
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
+
+        #[cfg(feature = "mmap")]
+        {
+            SonicPCTest::test_mapped_proving_key(num_constraints, num_variables);
+            SonicPCPoswTest::test_mapped_proving_key(num_constraints, num_variables);
+        }
+    }
+
+    #[test]
+    fn prove_and_batch_verify() {
+        let rng = &mut test_rng();
+
+        let (num_constraints, num_variables) = (25, 25);
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(max_degree, rng).unwrap();
+
+        let circuit = Circuit { a: None::<Fr>, b: None, num_constraints, num_variables };
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mut proofs = vec![];
+        let mut public_inputs = vec![];
+        for _ in 0..4 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let mut d = c;
+            d.mul_assign(&b);
+
+            let circuit = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+            proofs.push(MarlinSonicInst::prove(&index_pk, &circuit, rng).unwrap());
+            public_inputs.push(vec![c, d]);
+        }
+
+        assert!(!MarlinSonicInst::batch_verify(&index_vk, &[], &[]).unwrap());
+        assert!(MarlinSonicInst::batch_verify(&index_vk, &public_inputs, &proofs).unwrap());
+
+        // A single invalid statement invalidates the batch.
+        let mut invalid_inputs = public_inputs.clone();
+        invalid_inputs[2][0] = Fr::rand(rng);
+        assert!(!MarlinSonicInst::batch_verify(&index_vk, &invalid_inputs, &proofs).unwrap());
+
+        // Statements must match the order of the proofs.
+        let mut swapped_inputs = public_inputs.clone();
+        swapped_inputs.swap(0, 1);
+        assert!(!MarlinSonicInst::batch_verify(&index_vk, &swapped_inputs, &proofs).unwrap());
+
+        // The number of statements must match the number of proofs.
+        assert!(MarlinSonicInst::batch_verify(&index_vk, &public_inputs[..3], &proofs).is_err());
+
+        // The SNARK trait batch verifier delegates to the same pairing check.
+        let inputs = public_inputs.iter().collect::<Vec<_>>();
+        assert!(<MarlinSonicInst as crate::SNARK>::verify_batch(&index_vk, &inputs, &proofs).unwrap());
+        let inputs = invalid_inputs.iter().collect::<Vec<_>>();
+        assert!(!<MarlinSonicInst as crate::SNARK>::verify_batch(&index_vk, &inputs, &proofs).unwrap());
+    }
+
+    #[test]
+    fn prove_and_zeroize() {
+        use crate::SNARK;
+        use snarkvm_utilities::test_crypto_rng;
+
+        let rng = &mut test_crypto_rng();
+
+        let (num_constraints, num_variables) = (25, 25);
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let mut circuit = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure the witness is wiped once the proof is produced.
+        let proof = <MarlinSonicInst as SNARK>::prove_and_zeroize(&index_pk, &mut circuit, rng).unwrap();
+        assert!(circuit.a.is_none());
+        assert!(circuit.b.is_none());
+        assert!(MarlinSonicInst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+        // Ensure the witness is wiped even if proving fails.
+        let mut circuit = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables: num_variables + 1 };
+        assert!(<MarlinSonicInst as SNARK>::prove_and_zeroize(&index_pk, &mut circuit, rng).is_err());
+        assert!(circuit.a.is_none());
+        assert!(circuit.b.is_none());
+    }
+
+    #[test]
+    fn index_info_matches_index() {
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let circuit = Circuit { a: None::<Fr>, b: None, num_constraints, num_variables };
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuit).unwrap();
+            assert_eq!(index.index_info, AHPForR1CS::<Fr, MarlinHidingMode>::index_info(&circuit).unwrap());
+            assert_eq!(index.max_degree(), MarlinSonicInst::max_degree(&circuit).unwrap());
+        }
     }
 }
 
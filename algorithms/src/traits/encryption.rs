@@ -21,6 +21,7 @@ use rand::{CryptoRng, Rng};
 use std::{fmt::Debug, hash::Hash};
 
 pub trait EncryptionScheme: Sized + Debug + Clone + PartialEq + Eq {
+    type Ciphertext: Clone + Debug + Eq + ToBytes + FromBytes + Send + Sync;
     type CiphertextRandomizer: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + ToBits;
     type MessageType: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + ToBits;
     type Parameters: Clone + Debug + Eq;
@@ -58,6 +59,19 @@ pub trait EncryptionScheme: Sized + Debug + Clone + PartialEq + Eq {
 
     fn decrypt(&self, symmetric_key: &Self::SymmetricKey, ciphertext: &[Self::MessageType]) -> Vec<Self::MessageType>;
 
+    fn encrypt_to_public_key<R: Rng + CryptoRng>(
+        &self,
+        public_key: &Self::PublicKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Ciphertext, EncryptionError>;
+
+    fn decrypt_with_private_key(
+        &self,
+        private_key: &Self::PrivateKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Vec<u8>, EncryptionError>;
+
     fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters;
 
     fn private_key_size_in_bits() -> usize;
@@ -25,3 +25,11 @@ pub trait PRF {
 
     fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Self::Output;
 }
+
+///
+/// A PRF that expands a seed into any number of outputs, e.g. for deterministic nonce derivation and seed expansion.
+/// The first output of `evaluate_many` is the output of `PRF::evaluate`.
+///
+pub trait ExpandablePRF: PRF {
+    fn evaluate_many(seed: &Self::Seed, input: &Self::Input, num_outputs: usize) -> Vec<Self::Output>;
+}
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::SignatureError;
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{FromBytes, ToBytes};
@@ -44,6 +45,33 @@ pub trait SignatureScheme: Sized + Debug + Clone + Eq + Send + Sync {
     ) -> Result<Self::Signature>;
 
     fn verify(&self, public_key: &Self::PublicKey, message: &[bool], signature: &Self::Signature) -> Result<bool>;
+
+    ///
+    /// Returns `true` if every signature is valid for its corresponding public key and message.
+    ///
+    fn verify_batch(
+        &self,
+        public_keys: &[Self::PublicKey],
+        messages: &[&[bool]],
+        signatures: &[Self::Signature],
+    ) -> Result<bool> {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return Err(SignatureError::Message(format!(
+                "Mismatched batch lengths: {} public keys, {} messages, {} signatures",
+                public_keys.len(),
+                messages.len(),
+                signatures.len()
+            ))
+            .into());
+        }
+
+        for ((public_key, message), signature) in public_keys.iter().zip(messages).zip(signatures) {
+            if !self.verify(public_key, message, signature)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
 
 pub trait SignatureSchemeOperations {
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::SNARKError;
-use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
+use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits, Zeroize};
 
 use rand::{CryptoRng, Rng};
 use snarkvm_fields::{PrimeField, ToConstraintField};
@@ -85,6 +85,18 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError>;
 
+    /// Proves the given circuit, and wipes its assignment afterwards, whether or not proving succeeds.
+    /// This is intended for long-lived services, which should not retain witnesses once a proof is produced.
+    fn prove_and_zeroize<C: ConstraintSynthesizer<Self::ScalarField> + Zeroize, R: Rng + CryptoRng>(
+        proving_key: &Self::ProvingKey,
+        input_and_witness: &mut C,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        let proof = Self::prove(proving_key, input_and_witness, rng);
+        input_and_witness.zeroize();
+        proof
+    }
+
     fn verify_prepared(
         prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
         input: &Self::VerifierInput,
@@ -99,4 +111,29 @@ pub trait SNARK {
         let processed_verifying_key = verifying_key.prepare();
         Self::verify_prepared(&processed_verifying_key, input, proof)
     }
+
+    /// Returns `true` if every proof is valid for its corresponding input, under the same verifying key.
+    /// Returns an error if the number of inputs does not match the number of proofs.
+    /// By default, each proof is verified in turn. Proof systems that can amortize verification override this.
+    fn verify_batch(
+        verifying_key: &Self::VerifyingKey,
+        inputs: &[&Self::VerifierInput],
+        proofs: &[Self::Proof],
+    ) -> Result<bool, SNARKError> {
+        if inputs.len() != proofs.len() {
+            return Err(SNARKError::Message(format!(
+                "Batch contains {} proofs, but {} inputs were given",
+                proofs.len(),
+                inputs.len()
+            )));
+        }
+
+        let processed_verifying_key = verifying_key.prepare();
+        for (input, proof) in inputs.iter().zip(proofs) {
+            if !Self::verify_prepared(&processed_verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -0,0 +1,335 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::BHPCRH,
+    crypto_hash::{hash_to_curve, Poseidon},
+    CRH,
+    VRFError,
+    VRF,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    Choice,
+    ConditionallySelectable,
+    FromBytes,
+    IterBits,
+    ToBytes,
+    Zeroize,
+};
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+
+/// The domain separator for the output of the VRF.
+const VRF_OUTPUT_DOMAIN: &str = "AleoVRFOutput0";
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECVRFProof<TE: TwistedEdwardsParameters> {
+    /// The x-coordinate of gamma := H^sk, where H is the hash of the input to the group.
+    gamma: TE::BaseField,
+    /// The challenge c.
+    pub challenge: TE::ScalarField,
+    /// The response s := k - c * sk.
+    pub response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> ECVRFProof<TE> {
+    /// Returns gamma := H^sk, which must be in the prime-order subgroup.
+    #[inline]
+    pub fn gamma(&self) -> Result<TEAffine<TE>> {
+        // Exactly one of the two points with this x-coordinate is in the prime-order subgroup.
+        for greatest in [true, false] {
+            if let Some(element) = TEAffine::<TE>::from_x_coordinate(self.gamma, greatest) {
+                if element.is_in_correct_subgroup_assuming_on_curve() {
+                    return Ok(element);
+                }
+            }
+        }
+
+        Err(VRFError::Message("Failed to read the VRF proof gamma".into()).into())
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ECVRFProof<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = TE::BaseField::read_le(&mut reader)?;
+        let challenge = TE::ScalarField::read_le(&mut reader)?;
+        let response = TE::ScalarField::read_le(&mut reader)?;
+
+        Ok(Self { gamma, challenge, response })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ECVRFProof<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.gamma.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+///
+/// An ECVRF-style verifiable random function over a twisted Edwards curve.
+///
+/// The input is hashed to the group with the BHP hash, which is cheap to evaluate in a circuit.
+/// As the BHP hash pads its input to a multiple of 3 bits, the length of the input *must* be fixed
+/// by the application, and the input must be non-empty.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECVRF<TE: TwistedEdwardsParameters, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
+where
+    TE::BaseField: PrimeField,
+{
+    g_bases: Vec<TEProjective<TE>>,
+    bhp: BHPCRH<TEProjective<TE>, NUM_WINDOWS, WINDOW_SIZE>,
+    poseidon: Poseidon<TE::BaseField, 4, false>,
+}
+
+impl<TE: TwistedEdwardsParameters, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> VRF
+    for ECVRF<TE, NUM_WINDOWS, WINDOW_SIZE>
+where
+    TE::BaseField: PrimeField,
+{
+    type Output = TE::BaseField;
+    type PrivateKey = TE::ScalarField;
+    type Proof = ECVRFProof<TE>;
+    type PublicKey = TEAffine<TE>;
+
+    fn setup(message: &str) -> Self {
+        assert!(TE::ScalarField::size_in_data_bits() < TE::BaseField::size_in_data_bits());
+
+        // Compute the powers of G.
+        let g_bases = {
+            let (base, _, _) = hash_to_curve::<TEAffine<TE>>(message);
+
+            let mut g = base.to_projective();
+            let mut g_bases = Vec::with_capacity(TE::ScalarField::size_in_bits());
+            for _ in 0..TE::ScalarField::size_in_bits() {
+                g_bases.push(g);
+                g.double_in_place();
+            }
+            g_bases
+        };
+
+        // The BHP bases are sampled from indexed messages, so they are independent of G.
+        let bhp = BHPCRH::setup(message);
+
+        Self { g_bases, bhp, poseidon: Poseidon::setup() }
+    }
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        TE::ScalarField::rand(rng)
+    }
+
+    ///
+    /// Returns the public key G^sk.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.g_scalar_multiply(private_key).to_affine()
+    }
+
+    ///
+    /// Returns the proof (gamma, c, s), where:
+    ///     gamma := H^sk
+    ///     c := Hash(G^sk, H, gamma, G^k, H^k)
+    ///     s := k - c * sk
+    ///
+    fn prove<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        input: &[bool],
+        rng: &mut R,
+    ) -> Result<Self::Proof> {
+        // Compute H := HashToGroup(input).
+        let h = self.hash_to_group(input)?;
+
+        // Compute gamma := H^sk.
+        // Note: The private key is secret, so it is multiplied in constant time.
+        let gamma = h.to_projective().ct_mul(private_key);
+
+        // Sample a random nonce k.
+        let mut k = TE::ScalarField::rand(rng);
+
+        // Compute G^k and H^k.
+        let g_k = self.g_scalar_multiply(&k);
+        let h_k = h.to_projective().ct_mul(&k);
+
+        let mut to_invert = [self.g_scalar_multiply(private_key), gamma, g_k, h_k];
+        TEProjective::<TE>::batch_normalization(&mut to_invert);
+        let [public_key, gamma, g_k, h_k] = to_invert.map(|a| a.to_affine());
+
+        // Compute the challenge, and the response.
+        let challenge = self.challenge(&public_key, &h, &gamma, &g_k, &h_k);
+        let response = k - (challenge * private_key);
+
+        // Wipe the nonce, as it reveals the private key given the proof.
+        k.zeroize();
+
+        Ok(ECVRFProof { gamma: gamma.to_x_coordinate(), challenge, response })
+    }
+
+    ///
+    /// Verifies (c == c') where:
+    ///     c' := Hash(G^sk, H, gamma, G^s G^sk^c, H^s gamma^c)
+    ///
+    fn verify(&self, public_key: &Self::PublicKey, input: &[bool], proof: &Self::Proof) -> Result<bool> {
+        // Ensure the public key is in the prime-order subgroup.
+        if !public_key.is_on_curve() || !public_key.is_in_correct_subgroup_assuming_on_curve() {
+            return Ok(false);
+        }
+
+        // Compute H := HashToGroup(input).
+        let h = match self.hash_to_group(input) {
+            Ok(h) => h,
+            Err(_) => return Ok(false),
+        };
+
+        // Recover gamma.
+        let gamma = proof.gamma()?;
+
+        // Compute G^k := G^s G^sk^c, and H^k := H^s gamma^c.
+        let g_k = (self.g_scalar_multiply(&proof.response) + (*public_key * proof.challenge)).to_affine();
+        let h_k = ((h * proof.response) + (gamma * proof.challenge)).to_affine();
+
+        Ok(proof.challenge == self.challenge(public_key, &h, &gamma, &g_k, &h_k))
+    }
+
+    ///
+    /// Returns the output Hash(domain, gamma).
+    ///
+    fn proof_to_output(&self, proof: &Self::Proof) -> Result<Self::Output> {
+        let gamma = proof.gamma()?;
+        let domain = TE::BaseField::from_bytes_le_mod_order(VRF_OUTPUT_DOMAIN.as_bytes());
+        Ok(self.poseidon.evaluate(&[domain, gamma.to_x_coordinate()]))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
+    ECVRF<TE, NUM_WINDOWS, WINDOW_SIZE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Returns the scalar multiplication of G by the given (secret) scalar.
+    fn g_scalar_multiply(&self, scalar: &TE::ScalarField) -> TEProjective<TE> {
+        // The scalar may be secret, so every base is added, selecting the identity for the unset bits.
+        let zero = TEProjective::<TE>::zero();
+        self.g_bases
+            .iter()
+            .zip_eq(scalar.iter_bits_le())
+            .fold(zero, |output, (base, bit)| output + TEProjective::conditional_select(&zero, base, Choice::from(bit as u8)))
+    }
+
+    /// Returns the BHP hash of the given input to the group, which must not be the identity.
+    fn hash_to_group(&self, input: &[bool]) -> Result<TEAffine<TE>> {
+        let h = self.bhp.hash_bits_inner(input).map_err(VRFError::from)?.to_affine();
+        match h.is_zero() {
+            true => Err(VRFError::Message("The VRF input hashes to the identity".into()).into()),
+            false => Ok(h),
+        }
+    }
+
+    /// Returns the challenge c := Hash(G^sk, H, gamma, G^k, H^k), truncated into the scalar field.
+    fn challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        h: &TEAffine<TE>,
+        gamma: &TEAffine<TE>,
+        g_k: &TEAffine<TE>,
+        h_k: &TEAffine<TE>,
+    ) -> TE::ScalarField {
+        self.poseidon.hash_to_scalar(&[
+            public_key.to_x_coordinate(),
+            h.to_x_coordinate(),
+            gamma.to_x_coordinate(),
+            g_k.to_x_coordinate(),
+            h_k.to_x_coordinate(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsParameters as EdwardsBls12;
+    use snarkvm_utilities::{test_crypto_rng, ToBits};
+
+    type TestVRF = ECVRF<EdwardsBls12, 8, 32>;
+
+    #[test]
+    fn test_prove_and_verify() {
+        let rng = &mut test_crypto_rng();
+        let vrf = TestVRF::setup("ECVRFTest0");
+
+        let private_key = vrf.generate_private_key(rng);
+        let public_key = vrf.generate_public_key(&private_key);
+
+        let input = b"Hi, I am an Aleo VRF input!".to_bits_le();
+        let proof = vrf.prove(&private_key, &input, rng).unwrap();
+        assert!(vrf.verify(&public_key, &input, &proof).unwrap());
+
+        // Ensure the output is unique, even though the proofs are randomized.
+        let other_proof = vrf.prove(&private_key, &input, rng).unwrap();
+        assert_ne!(proof, other_proof);
+        assert!(vrf.verify(&public_key, &input, &other_proof).unwrap());
+        assert_eq!(vrf.proof_to_output(&proof).unwrap(), vrf.proof_to_output(&other_proof).unwrap());
+
+        // Ensure the output differs for a different input.
+        let other_input = b"Hi, I am an Aleo VRF input?".to_bits_le();
+        let other_proof = vrf.prove(&private_key, &other_input, rng).unwrap();
+        assert_ne!(vrf.proof_to_output(&proof).unwrap(), vrf.proof_to_output(&other_proof).unwrap());
+
+        // Ensure the proof round-trips through bytes.
+        let bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(proof, ECVRFProof::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_failed_verification() {
+        let rng = &mut test_crypto_rng();
+        let vrf = TestVRF::setup("ECVRFTest0");
+
+        let private_key = vrf.generate_private_key(rng);
+        let public_key = vrf.generate_public_key(&private_key);
+
+        let input = b"Hi, I am an Aleo VRF input!".to_bits_le();
+        let proof = vrf.prove(&private_key, &input, rng).unwrap();
+
+        // Check the proof against a different input.
+        assert!(!vrf.verify(&public_key, &b"Bad input".to_bits_le(), &proof).unwrap());
+        // Check the proof against a different public key.
+        let other_public_key = vrf.generate_public_key(&vrf.generate_private_key(rng));
+        assert!(!vrf.verify(&other_public_key, &input, &proof).unwrap());
+        // Check a proof with a different gamma, as for a different output.
+        let other_proof = vrf.prove(&vrf.generate_private_key(rng), &input, rng).unwrap();
+        let forged_proof = ECVRFProof { gamma: other_proof.gamma, ..proof };
+        assert!(!vrf.verify(&public_key, &input, &forged_proof).unwrap());
+        // Check the empty input, which hashes to the identity.
+        assert!(vrf.prove(&private_key, &[], rng).is_err());
+        assert!(!vrf.verify(&public_key, &[], &proof).unwrap());
+    }
+}
@@ -59,19 +59,27 @@ impl<P: Program> Definition<P> {
         }
     }
 
-    /// Returns `true` if the definition matches the format of the given value.
+    /// Returns `true` if the definition matches the format of the given value,
+    /// where the members of nested composites are checked against their own definitions.
     #[inline]
     pub fn matches(&self, value: &Value<P>) -> bool {
         match value {
             Value::Literal(..) => false,
-            Value::Composite(name, literals) => {
+            Value::Composite(name, values) => {
                 name == self.name()
-                    && literals.len() == self.members().len()
-                    && literals
-                        .iter()
-                        .zip_eq(self.members().iter())
-                        // Members in the value are literals.
-                        .all(|(literal, member)| &Annotation::Literal(literal.into()) == member.annotation())
+                    && values.len() == self.members().len()
+                    && values.iter().zip_eq(self.members().iter()).all(|(value, member)| {
+                        match (value, member.annotation()) {
+                            // Members that are literals must match the annotated type.
+                            (Value::Literal(literal), annotation) => &Annotation::Literal(literal.into()) == annotation,
+                            // Members that are composites must match their definition.
+                            (Value::Composite(..), Annotation::Composite(name)) => match P::get_definition(name) {
+                                Some(definition) => definition.matches(value),
+                                None => false,
+                            },
+                            (Value::Composite(..), Annotation::Literal(..)) => false,
+                        }
+                    })
             }
         }
     }
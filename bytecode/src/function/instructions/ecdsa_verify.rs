@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Registers},
+    helpers::Register,
+    Program,
+};
+use snarkvm_circuits::{
+    algorithms::{ECDSASignature, Secp256k1Base, Secp256k1Point, Secp256k1Scalar},
+    Boolean,
+    FromBits,
+    Literal,
+    Parser,
+    ParserResult,
+    ToBits,
+    U8,
+};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map_res;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Verifies the secp256k1 ECDSA signature `first` for the public key `second` and the message hash `third`,
+/// storing the outcome as a boolean in `destination`.
+///
+/// Each 256-bit component is given as a pair of big-endian `u128` halves, such that the signature is
+/// a composite of `r` and `s`, the public key is a composite of its affine coordinates `x` and `y`,
+/// and the message hash is a composite of its two halves.
+pub struct ECDSAVerify<P: Program> {
+    operation: TernaryOperation<P>,
+}
+
+impl<P: Program> ECDSAVerify<P> {
+    /// The expected types of the operands.
+    const OPERAND_TYPES: [OperandType; 3] =
+        [OperandType::ECDSASignature, OperandType::ECDSAPublicKey, OperandType::MessageHash];
+
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns the little-endian bits of the 256-bit value given as the big-endian `u128` halves.
+    fn to_bits_le(halves: &[Literal<P::Environment>]) -> Vec<Boolean<P::Environment>> {
+        halves
+            .iter()
+            .rev()
+            .flat_map(|half| match half {
+                Literal::U128(half) => half.to_bits_le(),
+                _ => P::halt(format!("Invalid operand in '{}'", Self::opcode())),
+            })
+            .collect()
+    }
+}
+
+impl<P: Program> Opcode for ECDSAVerify<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "ecdsa.verify"
+    }
+}
+
+impl<P: Program> Operation<P> for ECDSAVerify<P> {
+    /// Evaluates the operation.
+    ///
+    /// Note that the public key is checked to be on the curve with a constraint, rather than in the outcome.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the signature `(r, s)` from the first operand.
+        let signature = OperandType::ECDSASignature.load(registers, self.operation.first());
+        let signature = ECDSASignature::from(
+            Secp256k1Scalar::from_bits_le_reduced(&Self::to_bits_le(&signature[..2])),
+            Secp256k1Scalar::from_bits_le_reduced(&Self::to_bits_le(&signature[2..])),
+        );
+
+        // Load the public key `(x, y)` from the second operand.
+        let public_key = OperandType::ECDSAPublicKey.load(registers, self.operation.second());
+        let public_key = Secp256k1Point::from_xy_coordinates(
+            Secp256k1Base::from_bits_le_reduced(&Self::to_bits_le(&public_key[..2])),
+            Secp256k1Base::from_bits_le_reduced(&Self::to_bits_le(&public_key[2..])),
+        );
+
+        // Load the message hash from the third operand, as big-endian bytes.
+        let message_hash = OperandType::MessageHash.load(registers, self.operation.third());
+        let message_hash = Self::to_bits_le(&message_hash).chunks(8).rev().map(U8::from_bits_le).collect::<Vec<_>>();
+
+        // Perform the operation.
+        let result = signature.verify(&public_key, &message_hash);
+
+        registers.assign(self.operation.destination(), Literal::Boolean(result));
+    }
+}
+
+impl<P: Program> Parser for ECDSAVerify<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into an 'ecdsa.verify' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string, ensuring each value operand is of the expected type.
+        map_res(TernaryOperation::parse, |operation: TernaryOperation<P>| {
+            for (operand, operand_type) in operation.operands().iter().zip(Self::OPERAND_TYPES) {
+                if !operand_type.is_valid_operand(operand) {
+                    return Err(error(format!("Expected {operand_type} in '{}', found '{operand}'", Self::opcode())));
+                }
+            }
+            Ok(Self { operation })
+        })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for ECDSAVerify<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for ECDSAVerify<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: TernaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for ECDSAVerify<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for ECDSAVerify<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::ECDSAVerify(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, Process, Register, Value};
+
+    type P = Process;
+
+    /// The public key of the private key `c9afa9d8...0f6721` from RFC 6979, Appendix A.2.5.
+    const PUBLIC_KEY: (&str, &str) = (
+        "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+        "64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+    );
+
+    /// The SHA-256 digest of the message "sample".
+    const MESSAGE_HASH: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf";
+
+    /// The signature `(r, s)` of the message "sample".
+    const SIGNATURE: (&str, &str) = (
+        "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8",
+        "530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+    );
+
+    /// Returns the given 256-bit hex strings as a composite of big-endian `u128` halves.
+    fn composite(name: &str, hex: &[&str]) -> Value<P> {
+        let members = hex
+            .iter()
+            .flat_map(|hex| [&hex[..32], &hex[32..]])
+            .map(|half| format!("{}u128.constant", u128::from_str_radix(half, 16).unwrap()))
+            .map(|half| Value::Literal(Literal::from_str(&half)))
+            .collect();
+        Value::Composite(Identifier::from_str(name), members)
+    }
+
+    fn check_ecdsa_verify(signature: Value<P>, public_key: Value<P>, message_hash: Value<P>, expected: Value<P>) {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.define(&Register::from_str("r3"));
+        registers.assign(&Register::from_str("r0"), signature);
+        registers.assign(&Register::from_str("r1"), public_key);
+        registers.assign(&Register::from_str("r2"), message_hash);
+
+        ECDSAVerify::from_str("r0 r1 r2 into r3").evaluate(&registers);
+        let candidate = registers.load(&Register::from_str("r3"));
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_ecdsa_verify() {
+        let signature = composite("signature", &[SIGNATURE.0, SIGNATURE.1]);
+        let public_key = composite("public_key", &[PUBLIC_KEY.0, PUBLIC_KEY.1]);
+        let message_hash = composite("digest", &[MESSAGE_HASH]);
+
+        let expected = Value::<P>::from_str("true.constant");
+        check_ecdsa_verify(signature, public_key, message_hash, expected);
+    }
+
+    #[test]
+    fn test_ecdsa_verify_invalid() {
+        let signature = composite("signature", &[SIGNATURE.0, SIGNATURE.1]);
+        let public_key = composite("public_key", &[PUBLIC_KEY.0, PUBLIC_KEY.1]);
+        let message_hash = composite("digest", &[&MESSAGE_HASH.replace("af2b", "af2c")]);
+
+        let expected = Value::<P>::from_str("false.constant");
+        check_ecdsa_verify(signature, public_key, message_hash, expected);
+    }
+
+    #[test]
+    fn test_ecdsa_verify_parse() {
+        let string = "ecdsa.verify r0 r1 r2 into r3;";
+        let instruction = Instruction::<P>::from_str(string);
+        assert_eq!(string, instruction.to_string());
+
+        let bytes = instruction.to_bytes_le().unwrap();
+        assert_eq!(string, Instruction::<P>::from_bytes_le(&bytes).unwrap().to_string());
+
+        // Ensure a value operand of the wrong type is rejected.
+        assert!(ECDSAVerify::<P>::parse("r0 r1 digest 1u128.public into r3").is_err());
+        assert!(ECDSAVerify::<P>::parse("r0 r1 digest 1u128.public 2u128.public into r3").is_ok());
+    }
+}
@@ -0,0 +1,401 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Registers},
+    helpers::Register,
+    Program,
+};
+use snarkvm_circuits::{
+    prelude::PrimeField,
+    Aleo,
+    Boolean,
+    Environment,
+    Field,
+    FromBits,
+    Inject,
+    Literal,
+    Parser,
+    ParserResult,
+    ToBits,
+    U8,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Performs a BHP hash with an input hasher of 256 bits.
+pub type HashBHP256<P> = Hash<P, 0>;
+/// Performs a BHP hash with an input hasher of 512 bits.
+pub type HashBHP512<P> = Hash<P, 1>;
+/// Performs a BHP hash with an input hasher of 768 bits.
+pub type HashBHP768<P> = Hash<P, 2>;
+/// Performs a BHP hash with an input hasher of 1024 bits.
+pub type HashBHP1024<P> = Hash<P, 3>;
+
+/// Performs a Keccak hash with an output of 256 bits.
+pub type HashKeccak256<P> = Hash<P, 4>;
+
+/// Performs a Poseidon hash with an input rate of 2.
+pub type HashPSD2<P> = Hash<P, 5>;
+/// Performs a Poseidon hash with an input rate of 4.
+pub type HashPSD4<P> = Hash<P, 6>;
+/// Performs a Poseidon hash with an input rate of 8.
+pub type HashPSD8<P> = Hash<P, 7>;
+
+/// Hashes the canonical bits of `first`, storing the outcome as a field element in `destination`.
+/// Note that `first` may be a literal or a composite.
+pub struct Hash<P: Program, const VARIANT: u8> {
+    operation: UnaryOperation<P>,
+}
+
+impl<P: Program, const VARIANT: u8> Hash<P, VARIANT> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    ///
+    /// Returns the number of constraints to hash a private input of the given number of bits,
+    /// which scales with the number of input blocks of the hash function.
+    ///
+    /// For BHP, a block is a 3-bit chunk, which costs a lookup of 2 constraints, and a Montgomery addition
+    /// of 6 constraints for each chunk after the first. The input may not exceed `NUM_WINDOWS * WINDOW_SIZE` chunks.
+    /// For Keccak, a block is the 136-byte rate, so that one block fits at most 135 bytes once padded,
+    /// and each XOR and AND of two variable bits in the 24 rounds costs 1 constraint. The first block is cheaper,
+    /// as the initial state is constant, and the cost of each further block is an upper bound within 8 constraints.
+    /// For Poseidon, a block is the rate in base field elements, and each permutation applies
+    /// `8 * (RATE + 1) + 31` S-boxes of 5 constraints each, except on the constant elements of the initial state.
+    ///
+    /// Note: Constant input bits, such as the name of a composite, may reduce the cost.
+    ///
+    pub fn cost(num_bits: usize) -> u64 {
+        // Returns the number of blocks of the given size needed for the given number of units.
+        let num_blocks = |num_units: usize, block_size: usize| ((num_units + block_size - 1) / block_size) as u64;
+        // Returns the cost of a Poseidon hash with the given rate.
+        let poseidon = |rate: usize| {
+            let num_elements = num_blocks(num_bits, <P::Environment as Environment>::BaseField::size_in_data_bits());
+            let num_constant_elements = 1 + (rate as u64).saturating_sub(num_elements);
+            (num_blocks(num_elements as usize, rate) * 5 * (8 * (rate as u64 + 1) + 31))
+                .saturating_sub(5 * num_constant_elements)
+        };
+
+        match VARIANT {
+            0..=3 => (num_blocks(num_bits, 3) * 8).saturating_sub(6),
+            4 => (num_blocks(num_bits, 8) / 136 + 1) * 154_696 - 2_136,
+            5 => poseidon(2),
+            6 => poseidon(4),
+            7 => poseidon(8),
+            _ => 0,
+        }
+    }
+}
+
+impl<P: Program, const VARIANT: u8> Opcode for Hash<P, VARIANT> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        match VARIANT {
+            0 => "hash.bhp256",
+            1 => "hash.bhp512",
+            2 => "hash.bhp768",
+            3 => "hash.bhp1024",
+            4 => "hash.keccak256",
+            5 => "hash.psd2",
+            6 => "hash.psd4",
+            7 => "hash.psd8",
+            _ => P::halt("Invalid 'hash' instruction opcode"),
+        }
+    }
+}
+
+impl<P: Program, const VARIANT: u8> Operation<P> for Hash<P, VARIANT> {
+    /// Evaluates the operation.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the value for the first operand.
+        let first = registers.load(self.operation.first());
+
+        // Compute the number of data bits in a base field element.
+        let num_data_bits = <P::Environment as Environment>::BaseField::size_in_data_bits();
+
+        // Perform the operation on the canonical little-endian bits of the value.
+        let bits_le = first.to_bits_le();
+        let result = match VARIANT {
+            0 => P::Aleo::hash_bhp256(&bits_le),
+            1 => P::Aleo::hash_bhp512(&bits_le),
+            2 => P::Aleo::hash_bhp768(&bits_le),
+            3 => P::Aleo::hash_bhp1024(&bits_le),
+            4 => {
+                // Pad the bits to a multiple of 8, and pack them into bytes.
+                let mut bits_le = bits_le;
+                bits_le.resize((bits_le.len() + 7) / 8 * 8, Boolean::constant(false));
+                let bytes = bits_le.chunks(8).map(U8::from_bits_le).collect::<Vec<_>>();
+                // Truncate the digest to the data bits of a base field element.
+                let digest_bits_le = P::Aleo::hash_keccak256(&bytes).to_bits_le();
+                Field::from_bits_le(&digest_bits_le[..num_data_bits])
+            }
+            5..=7 => {
+                // Pack the bits into base field elements.
+                let input = bits_le.chunks(num_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+                match VARIANT {
+                    5 => P::Aleo::hash_psd2(&input),
+                    6 => P::Aleo::hash_psd4(&input),
+                    _ => P::Aleo::hash_psd8(&input),
+                }
+            }
+            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+        };
+
+        registers.assign(self.operation.destination(), Literal::Field(result));
+    }
+}
+
+impl<P: Program, const VARIANT: u8> Parser for Hash<P, VARIANT> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'hash' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(UnaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program, const VARIANT: u8> fmt::Display for Hash<P, VARIANT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program, const VARIANT: u8> FromBytes for Hash<P, VARIANT> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: UnaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program, const VARIANT: u8> ToBytes for Hash<P, VARIANT> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program, const VARIANT: u8> Into<Instruction<P>> for Hash<P, VARIANT> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        let operation = self.operation;
+        match VARIANT {
+            0 => Instruction::HashBHP256(Hash { operation }),
+            1 => Instruction::HashBHP512(Hash { operation }),
+            2 => Instruction::HashBHP768(Hash { operation }),
+            3 => Instruction::HashBHP1024(Hash { operation }),
+            4 => Instruction::HashKeccak256(Hash { operation }),
+            5 => Instruction::HashPSD2(Hash { operation }),
+            6 => Instruction::HashPSD4(Hash { operation }),
+            7 => Instruction::HashPSD8(Hash { operation }),
+            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Register, Value};
+    use snarkvm_circuits::{Eject, Mode};
+
+    type P = Process;
+
+    fn check_hash<const VARIANT: u8>(first: Value<P>, expected: Field<<P as Program>::Aleo>) {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), first);
+
+        Hash::<P, VARIANT>::from_str("r0 into r1").evaluate(&registers);
+        let candidate = registers.load(&Register::from_str("r1"));
+        assert_eq!(Value::Literal(Literal::Field(expected)), candidate);
+    }
+
+    #[test]
+    fn test_hash_bhp() {
+        let first = Value::<P>::from_str("1field.public");
+        let bits_le = Literal::<<P as Program>::Aleo>::from_str("1field.public").to_bits_le();
+
+        check_hash::<0>(first.clone(), <P as Program>::Aleo::hash_bhp256(&bits_le));
+        check_hash::<1>(first.clone(), <P as Program>::Aleo::hash_bhp512(&bits_le));
+        check_hash::<2>(first.clone(), <P as Program>::Aleo::hash_bhp768(&bits_le));
+        check_hash::<3>(first, <P as Program>::Aleo::hash_bhp1024(&bits_le));
+    }
+
+    #[test]
+    fn test_hash_psd() {
+        let first = Value::<P>::from_str("1field.public");
+        let bits_le = Literal::<<P as Program>::Aleo>::from_str("1field.public").to_bits_le();
+        let num_data_bits = <<P as Program>::Aleo as Environment>::BaseField::size_in_data_bits();
+        let input = bits_le.chunks(num_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+
+        check_hash::<5>(first.clone(), <P as Program>::Aleo::hash_psd2(&input));
+        check_hash::<6>(first.clone(), <P as Program>::Aleo::hash_psd4(&input));
+        check_hash::<7>(first, <P as Program>::Aleo::hash_psd8(&input));
+    }
+
+    #[test]
+    fn test_hash_composite() {
+        let first = Value::<P>::from_str("message 1field.public (token 2u64.private)");
+        let bits_le = first.to_bits_le();
+        let num_data_bits = <<P as Program>::Aleo as Environment>::BaseField::size_in_data_bits();
+        let input = bits_le.chunks(num_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+
+        check_hash::<3>(first.clone(), <P as Program>::Aleo::hash_bhp1024(&bits_le));
+        check_hash::<6>(first, <P as Program>::Aleo::hash_psd4(&input));
+    }
+
+    #[test]
+    fn test_hash_keccak256() {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), Value::<P>::from_str("1u8.public"));
+
+        HashKeccak256::<P>::from_str("r0 into r1").evaluate(&registers);
+
+        // The Keccak-256 digest of the byte 0x01 is 0x5fe7f977e71dba2ea1a68e21057beebb9be2ac30c6410aa38d4f3fbe41dcffd2,
+        // which is read as a little-endian integer and truncated to the data bits of a base field element.
+        let expected = "1356691855313228020593457889571357370311739853718920504519778797822487947103"
+            .parse::<<<P as Program>::Aleo as Environment>::BaseField>()
+            .unwrap();
+        match registers.load(&Register::from_str("r1")) {
+            Value::Literal(Literal::Field(candidate)) => assert_eq!(expected, candidate.eject_value()),
+            _ => panic!("Expected the output of 'hash.keccak256' to be a field element"),
+        }
+    }
+
+    #[test]
+    fn test_hash_parse() {
+        for string in [
+            "hash.bhp256 r0 into r1;",
+            "hash.bhp1024 r0 into r1;",
+            "hash.keccak256 r0 into r1;",
+            "hash.psd2 r0 into r1;",
+            "hash.psd8 r0 into r1;",
+        ] {
+            let instruction = Instruction::<P>::from_str(string);
+            assert_eq!(string, instruction.to_string());
+
+            let bytes = instruction.to_bytes_le().unwrap();
+            assert_eq!(string, Instruction::<P>::from_bytes_le(&bytes).unwrap().to_string());
+        }
+    }
+
+    /// Returns the number of constraints synthesized by `hash`, measured in its own scope.
+    fn measure_cost<T>(name: &str, hash: impl FnOnce() -> T) -> u64 {
+        let num_constraints = <P as Program>::Aleo::scope(name, || {
+            let _output = hash();
+            <P as Program>::Aleo::num_constraints_in_scope()
+        });
+        <P as Program>::Aleo::reset();
+        num_constraints as u64
+    }
+
+    #[test]
+    fn test_hash_cost_is_measured() {
+        type BaseField = <<P as Program>::Aleo as Environment>::BaseField;
+
+        // Note: The inputs are injected before each scope, so that only the hash is measured.
+        let bits = |num_bits: usize| (0..num_bits).map(|i| Boolean::new(Mode::Private, i % 3 == 0)).collect::<Vec<_>>();
+        let bytes = |num_bytes: usize| (0..num_bytes).map(|i| U8::new(Mode::Private, i as u8)).collect::<Vec<_>>();
+        let fields = |num_fields: u64| {
+            (0..num_fields).map(|i| Field::new(Mode::Private, BaseField::from(i + 1))).collect::<Vec<_>>()
+        };
+        let num_data_bits = BaseField::size_in_data_bits();
+
+        for num_bits in [3 * 57 * 3, 300] {
+            let input = bits(num_bits);
+            let measured = measure_cost("BHP256", || <P as Program>::Aleo::hash_bhp256(&input));
+            assert_eq!(HashBHP256::<P>::cost(num_bits), measured);
+        }
+        let input = bits(6 * 43 * 3);
+        let measured = measure_cost("BHP512", || <P as Program>::Aleo::hash_bhp512(&input));
+        assert_eq!(HashBHP512::<P>::cost(input.len()), measured);
+        let input = bits(15 * 23 * 3);
+        let measured = measure_cost("BHP768", || <P as Program>::Aleo::hash_bhp768(&input));
+        assert_eq!(HashBHP768::<P>::cost(input.len()), measured);
+        let input = bits(8 * 54 * 3);
+        let measured = measure_cost("BHP1024", || <P as Program>::Aleo::hash_bhp1024(&input));
+        assert_eq!(HashBHP1024::<P>::cost(input.len()), measured);
+
+        // Ensure one and two full Keccak blocks, of 135 and 271 bytes once padded, are measured.
+        for num_bytes in [135, 271] {
+            let input = bytes(num_bytes);
+            let measured = measure_cost("Keccak256", || <P as Program>::Aleo::hash_keccak256(&input));
+            assert_eq!(HashKeccak256::<P>::cost(num_bytes * 8), measured);
+        }
+
+        // Ensure partial and multiple Poseidon blocks are measured.
+        for num_fields in [1, 2, 3, 4, 9] {
+            let num_bits = num_fields as usize * num_data_bits;
+            let input = fields(num_fields);
+            assert_eq!(HashPSD2::<P>::cost(num_bits), measure_cost("PSD2", || <P as Program>::Aleo::hash_psd2(&input)));
+            let input = fields(num_fields);
+            assert_eq!(HashPSD4::<P>::cost(num_bits), measure_cost("PSD4", || <P as Program>::Aleo::hash_psd4(&input)));
+            let input = fields(num_fields);
+            assert_eq!(HashPSD8::<P>::cost(num_bits), measure_cost("PSD8", || <P as Program>::Aleo::hash_psd8(&input)));
+        }
+    }
+
+    #[test]
+    fn test_hash_composite_cost_is_measured() {
+        // Ensure the cost of a composite spanning multiple Poseidon blocks matches its measured cost.
+        let first = Value::<P>::from_str(
+            "message 1field.private 2field.private 3field.private (token 4u64.private 5field.private) 6field.private",
+        );
+        let bits_le = first.to_bits_le();
+        let num_data_bits = <<P as Program>::Aleo as Environment>::BaseField::size_in_data_bits();
+        let input = bits_le.chunks(num_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+        assert!(input.len() > 2 * 2);
+
+        let instruction = Instruction::<P>::from_str("hash.psd2 r0 into r1;");
+        let measured = measure_cost("PSD2", || <P as Program>::Aleo::hash_psd2(&input));
+        assert_eq!(Some(measured), instruction.cost(bits_le.len()));
+    }
+
+    #[test]
+    fn test_hash_cost() {
+        let num_bits = 253;
+        assert!(HashPSD2::<P>::cost(num_bits) < HashPSD4::<P>::cost(num_bits));
+        assert!(HashPSD4::<P>::cost(num_bits) < HashPSD8::<P>::cost(num_bits));
+        assert!(HashBHP256::<P>::cost(num_bits) < HashBHP1024::<P>::cost(8 * 54 * 3));
+        assert!(HashBHP1024::<P>::cost(8 * 54 * 3) < HashKeccak256::<P>::cost(num_bits));
+
+        // The cost scales with the number of input blocks.
+        assert!(HashPSD2::<P>::cost(num_bits) < HashPSD2::<P>::cost(4 * num_bits));
+        assert!(HashKeccak256::<P>::cost(135 * 8) < HashKeccak256::<P>::cost(136 * 8));
+
+        // The cost of a hash instruction is exposed through the instruction.
+        let instruction = Instruction::<P>::from_str("hash.psd4 r0 into r1;");
+        assert_eq!(Some(HashPSD4::<P>::cost(num_bits)), instruction.cost(num_bits));
+        assert_eq!(None, Instruction::<P>::from_str("add r0 r1 into r2;").cost(num_bits));
+    }
+}
@@ -17,9 +17,18 @@
 pub(super) mod add;
 pub(super) use add::*;
 
+pub(super) mod ecdsa_verify;
+pub(super) use ecdsa_verify::*;
+
+pub(super) mod hash;
+pub(super) use hash::*;
+
 pub(super) mod neg;
 pub(super) use neg::*;
 
+pub(super) mod sign_verify;
+pub(super) use sign_verify::*;
+
 pub(super) mod sub;
 pub(super) use sub::*;
 
@@ -58,8 +67,30 @@ pub trait Operation<P: Program>: Parser + Into<Instruction<P>> {
 pub enum Instruction<P: Program> {
     /// Adds `first` with `second`, storing the outcome in `destination`.
     Add(Add<P>),
+    /// Verifies the ECDSA signature `first` for the public key `second` and the message hash `third`,
+    /// storing the outcome in `destination`.
+    ECDSAVerify(ECDSAVerify<P>),
+    /// Performs a BHP hash with an input hasher of 256 bits.
+    HashBHP256(HashBHP256<P>),
+    /// Performs a BHP hash with an input hasher of 512 bits.
+    HashBHP512(HashBHP512<P>),
+    /// Performs a BHP hash with an input hasher of 768 bits.
+    HashBHP768(HashBHP768<P>),
+    /// Performs a BHP hash with an input hasher of 1024 bits.
+    HashBHP1024(HashBHP1024<P>),
+    /// Performs a Keccak hash with an output of 256 bits.
+    HashKeccak256(HashKeccak256<P>),
+    /// Performs a Poseidon hash with an input rate of 2.
+    HashPSD2(HashPSD2<P>),
+    /// Performs a Poseidon hash with an input rate of 4.
+    HashPSD4(HashPSD4<P>),
+    /// Performs a Poseidon hash with an input rate of 8.
+    HashPSD8(HashPSD8<P>),
     /// Negates `first`, storing the outcome in `destination`.
     Neg(Neg<P>),
+    /// Verifies the signature `first` for the address `second` and the message `third`,
+    /// storing the outcome in `destination`.
+    SignVerify(SignVerify<P>),
     /// Subtracts `first` from `second`, storing the outcome in `destination`.
     Sub(Sub<P>),
 }
@@ -70,7 +101,17 @@ impl<P: Program> Instruction<P> {
     pub(crate) fn opcode(&self) -> &'static str {
         match self {
             Self::Add(..) => Add::<P>::opcode(),
+            Self::ECDSAVerify(..) => ECDSAVerify::<P>::opcode(),
+            Self::HashBHP256(..) => HashBHP256::<P>::opcode(),
+            Self::HashBHP512(..) => HashBHP512::<P>::opcode(),
+            Self::HashBHP768(..) => HashBHP768::<P>::opcode(),
+            Self::HashBHP1024(..) => HashBHP1024::<P>::opcode(),
+            Self::HashKeccak256(..) => HashKeccak256::<P>::opcode(),
+            Self::HashPSD2(..) => HashPSD2::<P>::opcode(),
+            Self::HashPSD4(..) => HashPSD4::<P>::opcode(),
+            Self::HashPSD8(..) => HashPSD8::<P>::opcode(),
             Self::Neg(..) => Neg::<P>::opcode(),
+            Self::SignVerify(..) => SignVerify::<P>::opcode(),
             Self::Sub(..) => Sub::<P>::opcode(),
         }
     }
@@ -80,7 +121,17 @@ impl<P: Program> Instruction<P> {
     pub(crate) fn operands(&self) -> Vec<Operand<P>> {
         match self {
             Self::Add(add) => add.operands(),
+            Self::ECDSAVerify(ecdsa_verify) => ecdsa_verify.operands(),
+            Self::HashBHP256(hash) => hash.operands(),
+            Self::HashBHP512(hash) => hash.operands(),
+            Self::HashBHP768(hash) => hash.operands(),
+            Self::HashBHP1024(hash) => hash.operands(),
+            Self::HashKeccak256(hash) => hash.operands(),
+            Self::HashPSD2(hash) => hash.operands(),
+            Self::HashPSD4(hash) => hash.operands(),
+            Self::HashPSD8(hash) => hash.operands(),
             Self::Neg(neg) => neg.operands(),
+            Self::SignVerify(sign_verify) => sign_verify.operands(),
             Self::Sub(sub) => sub.operands(),
         }
     }
@@ -90,17 +141,54 @@ impl<P: Program> Instruction<P> {
     pub(crate) fn destination(&self) -> &Register<P> {
         match self {
             Self::Add(add) => add.destination(),
+            Self::ECDSAVerify(ecdsa_verify) => ecdsa_verify.destination(),
+            Self::HashBHP256(hash) => hash.destination(),
+            Self::HashBHP512(hash) => hash.destination(),
+            Self::HashBHP768(hash) => hash.destination(),
+            Self::HashBHP1024(hash) => hash.destination(),
+            Self::HashKeccak256(hash) => hash.destination(),
+            Self::HashPSD2(hash) => hash.destination(),
+            Self::HashPSD4(hash) => hash.destination(),
+            Self::HashPSD8(hash) => hash.destination(),
             Self::Neg(neg) => neg.destination(),
+            Self::SignVerify(sign_verify) => sign_verify.destination(),
             Self::Sub(sub) => sub.destination(),
         }
     }
 
+    /// Returns the number of constraints to process a private operand of the given number of bits,
+    /// or `None` if the instruction has no cost metadata. See `Hash::cost` for the hash instructions.
+    #[inline]
+    pub fn cost(&self, num_bits: usize) -> Option<u64> {
+        match self {
+            Self::HashBHP256(..) => Some(HashBHP256::<P>::cost(num_bits)),
+            Self::HashBHP512(..) => Some(HashBHP512::<P>::cost(num_bits)),
+            Self::HashBHP768(..) => Some(HashBHP768::<P>::cost(num_bits)),
+            Self::HashBHP1024(..) => Some(HashBHP1024::<P>::cost(num_bits)),
+            Self::HashKeccak256(..) => Some(HashKeccak256::<P>::cost(num_bits)),
+            Self::HashPSD2(..) => Some(HashPSD2::<P>::cost(num_bits)),
+            Self::HashPSD4(..) => Some(HashPSD4::<P>::cost(num_bits)),
+            Self::HashPSD8(..) => Some(HashPSD8::<P>::cost(num_bits)),
+            Self::Add(..) | Self::ECDSAVerify(..) | Self::Neg(..) | Self::SignVerify(..) | Self::Sub(..) => None,
+        }
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub(crate) fn evaluate(&self, registers: &Registers<P>) {
         match self {
             Self::Add(instruction) => instruction.evaluate(registers),
+            Self::ECDSAVerify(instruction) => instruction.evaluate(registers),
+            Self::HashBHP256(instruction) => instruction.evaluate(registers),
+            Self::HashBHP512(instruction) => instruction.evaluate(registers),
+            Self::HashBHP768(instruction) => instruction.evaluate(registers),
+            Self::HashBHP1024(instruction) => instruction.evaluate(registers),
+            Self::HashKeccak256(instruction) => instruction.evaluate(registers),
+            Self::HashPSD2(instruction) => instruction.evaluate(registers),
+            Self::HashPSD4(instruction) => instruction.evaluate(registers),
+            Self::HashPSD8(instruction) => instruction.evaluate(registers),
             Self::Neg(instruction) => instruction.evaluate(registers),
+            Self::SignVerify(instruction) => instruction.evaluate(registers),
             Self::Sub(instruction) => instruction.evaluate(registers),
         }
     }
@@ -118,7 +206,17 @@ impl<P: Program> Parser for Instruction<P> {
         let (string, instruction) = alt((
             // Note that order of the individual parsers matters.
             preceded(pair(tag(Add::<P>::opcode()), tag(" ")), map(Add::parse, Into::into)),
+            preceded(pair(tag(ECDSAVerify::<P>::opcode()), tag(" ")), map(ECDSAVerify::parse, Into::into)),
+            preceded(pair(tag(HashBHP256::<P>::opcode()), tag(" ")), map(HashBHP256::parse, Into::into)),
+            preceded(pair(tag(HashBHP512::<P>::opcode()), tag(" ")), map(HashBHP512::parse, Into::into)),
+            preceded(pair(tag(HashBHP768::<P>::opcode()), tag(" ")), map(HashBHP768::parse, Into::into)),
+            preceded(pair(tag(HashBHP1024::<P>::opcode()), tag(" ")), map(HashBHP1024::parse, Into::into)),
+            preceded(pair(tag(HashKeccak256::<P>::opcode()), tag(" ")), map(HashKeccak256::parse, Into::into)),
+            preceded(pair(tag(HashPSD2::<P>::opcode()), tag(" ")), map(HashPSD2::parse, Into::into)),
+            preceded(pair(tag(HashPSD4::<P>::opcode()), tag(" ")), map(HashPSD4::parse, Into::into)),
+            preceded(pair(tag(HashPSD8::<P>::opcode()), tag(" ")), map(HashPSD8::parse, Into::into)),
             preceded(pair(tag(Neg::<P>::opcode()), tag(" ")), map(Neg::parse, Into::into)),
+            preceded(pair(tag(SignVerify::<P>::opcode()), tag(" ")), map(SignVerify::parse, Into::into)),
             preceded(pair(tag(Sub::<P>::opcode()), tag(" ")), map(Sub::parse, Into::into)),
         ))(string)?;
         // Parse the semicolon from the string.
@@ -132,7 +230,17 @@ impl<P: Program> fmt::Display for Instruction<P> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Add(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::ECDSAVerify(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashBHP256(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashBHP512(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashBHP768(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashBHP1024(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashKeccak256(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashPSD2(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashPSD4(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::HashPSD8(instruction) => write!(f, "{} {};", self.opcode(), instruction),
             Self::Neg(instruction) => write!(f, "{} {};", self.opcode(), instruction),
+            Self::SignVerify(instruction) => write!(f, "{} {};", self.opcode(), instruction),
             Self::Sub(instruction) => write!(f, "{} {};", self.opcode(), instruction),
         }
     }
@@ -145,7 +253,17 @@ impl<P: Program> FromBytes for Instruction<P> {
             0 => Ok(Self::Add(Add::read_le(&mut reader)?)),
             1 => Ok(Self::Neg(Neg::read_le(&mut reader)?)),
             2 => Ok(Self::Sub(Sub::read_le(&mut reader)?)),
-            3.. => Err(error(format!("Failed to deserialize an instruction of code {code}"))),
+            3 => Ok(Self::HashBHP256(HashBHP256::read_le(&mut reader)?)),
+            4 => Ok(Self::HashBHP512(HashBHP512::read_le(&mut reader)?)),
+            5 => Ok(Self::HashBHP768(HashBHP768::read_le(&mut reader)?)),
+            6 => Ok(Self::HashBHP1024(HashBHP1024::read_le(&mut reader)?)),
+            7 => Ok(Self::HashKeccak256(HashKeccak256::read_le(&mut reader)?)),
+            8 => Ok(Self::HashPSD2(HashPSD2::read_le(&mut reader)?)),
+            9 => Ok(Self::HashPSD4(HashPSD4::read_le(&mut reader)?)),
+            10 => Ok(Self::HashPSD8(HashPSD8::read_le(&mut reader)?)),
+            11 => Ok(Self::ECDSAVerify(ECDSAVerify::read_le(&mut reader)?)),
+            12 => Ok(Self::SignVerify(SignVerify::read_le(&mut reader)?)),
+            13.. => Err(error(format!("Failed to deserialize an instruction of code {code}"))),
         }
     }
 }
@@ -157,10 +275,50 @@ impl<P: Program> ToBytes for Instruction<P> {
                 u16::write_le(&0u16, &mut writer)?;
                 instruction.write_le(&mut writer)
             }
+            Self::ECDSAVerify(instruction) => {
+                u16::write_le(&11u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashBHP256(instruction) => {
+                u16::write_le(&3u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashBHP512(instruction) => {
+                u16::write_le(&4u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashBHP768(instruction) => {
+                u16::write_le(&5u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashBHP1024(instruction) => {
+                u16::write_le(&6u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashKeccak256(instruction) => {
+                u16::write_le(&7u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashPSD2(instruction) => {
+                u16::write_le(&8u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashPSD4(instruction) => {
+                u16::write_le(&9u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::HashPSD8(instruction) => {
+                u16::write_le(&10u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
             Self::Neg(instruction) => {
                 u16::write_le(&1u16, &mut writer)?;
                 instruction.write_le(&mut writer)
             }
+            Self::SignVerify(instruction) => {
+                u16::write_le(&12u16, &mut writer)?;
+                instruction.write_le(&mut writer)
+            }
             Self::Sub(instruction) => {
                 u16::write_le(&2u16, &mut writer)?;
                 instruction.write_le(&mut writer)
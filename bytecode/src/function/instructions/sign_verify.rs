@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Registers},
+    helpers::Register,
+    Program,
+};
+use snarkvm_circuits::{account::Signature, Literal, Parser, ParserResult};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map_res;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Verifies the Aleo signature `first` for the address `second` and the message `third`,
+/// storing the outcome as a boolean in `destination`.
+///
+/// The signature is a composite of `scalar scalar field field` members, and the message is
+/// either a literal, or a composite whose members are signed in order.
+pub struct SignVerify<P: Program> {
+    operation: TernaryOperation<P>,
+}
+
+impl<P: Program> SignVerify<P> {
+    /// The expected types of the operands.
+    const OPERAND_TYPES: [OperandType; 3] = [OperandType::Signature, OperandType::Address, OperandType::Message];
+
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+}
+
+impl<P: Program> Opcode for SignVerify<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "sign.verify"
+    }
+}
+
+impl<P: Program> Operation<P> for SignVerify<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the signature from the first operand.
+        let signature = match &OperandType::Signature.load(registers, self.operation.first())[..] {
+            [
+                Literal::Scalar(prover_response),
+                Literal::Scalar(verifier_challenge),
+                Literal::Field(pk_sig),
+                Literal::Field(pr_sig),
+            ] => Signature::from(prover_response.clone(), verifier_challenge.clone(), pk_sig.clone(), pr_sig.clone()),
+            _ => P::halt(format!("Invalid signature operand in '{}'", Self::opcode())),
+        };
+
+        // Load the address from the second operand.
+        let address = match &OperandType::Address.load(registers, self.operation.second())[..] {
+            [Literal::Address(address)] => address.clone(),
+            _ => P::halt(format!("Invalid address operand in '{}'", Self::opcode())),
+        };
+
+        // Load the message from the third operand.
+        let message = OperandType::Message.load(registers, self.operation.third());
+
+        // Perform the operation.
+        let result = signature.verify(&address, &message);
+
+        registers.assign(self.operation.destination(), Literal::Boolean(result));
+    }
+}
+
+impl<P: Program> Parser for SignVerify<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'sign.verify' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string, ensuring each value operand is of the expected type.
+        map_res(TernaryOperation::parse, |operation: TernaryOperation<P>| {
+            for (operand, operand_type) in operation.operands().iter().zip(Self::OPERAND_TYPES) {
+                if !operand_type.is_valid_operand(operand) {
+                    return Err(error(format!("Expected {operand_type} in '{}', found '{operand}'", Self::opcode())));
+                }
+            }
+            Ok(Self { operation })
+        })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for SignVerify<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for SignVerify<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: TernaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for SignVerify<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for SignVerify<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::SignVerify(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, Process, Register, Value};
+    use snarkvm_algorithms::{signature::AleoSignatureScheme, SignatureScheme, SignatureSchemeOperations};
+    use snarkvm_circuits::{Address, Devnet, Eject, Environment, Field, Inject, Mode, Scalar, ToBits};
+    use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+    use snarkvm_utilities::{test_crypto_rng, test_rng, UniformRand};
+
+    type P = Process;
+
+    /// Returns a random address, and a signature on the given message for the address.
+    fn sample_signature(message: &[Literal<Devnet>]) -> (Value<P>, Value<P>) {
+        let rng = &mut test_rng();
+        let native = AleoSignatureScheme::<<Devnet as Environment>::AffineParameters>::setup(
+            "AleoAccountEncryptionAndSignatureScheme0",
+        );
+
+        // Sample a private key, and compute its address.
+        let sk_sig = UniformRand::rand(rng);
+        let r_sig = UniformRand::rand(rng);
+        let pk_sig = native.g_scalar_multiply(&sk_sig);
+        let pr_sig = native.g_scalar_multiply(&r_sig);
+        let sk_prf =
+            native.hash_to_scalar_field(&[pk_sig.to_affine().to_x_coordinate(), pr_sig.to_affine().to_x_coordinate()]);
+        let address: <Devnet as Environment>::Affine = (pk_sig + pr_sig + native.g_scalar_multiply(&sk_prf)).into();
+
+        // Sign the message.
+        let message = message.to_bits_le().eject_value();
+        let signature = native.sign(&(sk_sig, r_sig), &message, &mut test_crypto_rng()).unwrap();
+
+        let address = Value::Literal(Literal::Address(Address::new(Mode::Public, address)));
+        let signature = Value::Composite(Identifier::from_str("signature"), vec![
+            Literal::Scalar(Scalar::new(Mode::Private, signature.prover_response)).into(),
+            Literal::Scalar(Scalar::new(Mode::Private, signature.verifier_challenge)).into(),
+            Literal::Field(Field::new(Mode::Private, signature.root_public_key().unwrap().to_x_coordinate())).into(),
+            Literal::Field(Field::new(Mode::Private, signature.root_randomizer().unwrap().to_x_coordinate())).into(),
+        ]);
+        (address, signature)
+    }
+
+    fn check_sign_verify(signature: Value<P>, address: Value<P>, message: Value<P>, expected: Value<P>) {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.define(&Register::from_str("r3"));
+        registers.assign(&Register::from_str("r0"), signature);
+        registers.assign(&Register::from_str("r1"), address);
+        registers.assign(&Register::from_str("r2"), message);
+
+        SignVerify::from_str("r0 r1 r2 into r3").evaluate(&registers);
+        let candidate = registers.load(&Register::from_str("r3"));
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let message = Literal::from_str("1field.public");
+        let (address, signature) = sample_signature(&[message.clone()]);
+
+        let expected = Value::<P>::from_str("true.private");
+        check_sign_verify(signature, address, Value::Literal(message), expected);
+    }
+
+    #[test]
+    fn test_sign_verify_composite_message() {
+        let members = vec![Literal::from_str("1field.public"), Literal::from_str("2group.public")];
+        let (address, signature) = sample_signature(&members);
+
+        let expected = Value::<P>::from_str("true.private");
+        let message =
+            Value::Composite(Identifier::from_str("message"), members.into_iter().map(Value::Literal).collect());
+        check_sign_verify(signature, address, message, expected);
+    }
+
+    #[test]
+    fn test_sign_verify_invalid() {
+        let (address, signature) = sample_signature(&[Literal::from_str("1field.public")]);
+
+        let expected = Value::<P>::from_str("false.private");
+        check_sign_verify(signature, address, Value::from_str("2field.public"), expected);
+    }
+
+    #[test]
+    fn test_sign_verify_parse() {
+        let string = "sign.verify r0 r1 r2 into r3;";
+        let instruction = Instruction::<P>::from_str(string);
+        assert_eq!(string, instruction.to_string());
+
+        let bytes = instruction.to_bytes_le().unwrap();
+        assert_eq!(string, Instruction::<P>::from_bytes_le(&bytes).unwrap().to_string());
+
+        // Ensure a value operand of the wrong type is rejected.
+        assert!(SignVerify::<P>::parse("1field.public r1 r2 into r3").is_err());
+        assert!(SignVerify::<P>::parse("r0 1field.public r2 into r3").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{function::Registers, LiteralType, Program, Value};
+use snarkvm_circuits::prelude::*;
+
+use core::fmt;
+
+/// The operand type enum represents the expected type of an operand in an instruction,
+/// for operands that do not correspond to a single literal type (such as public keys and signatures).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum OperandType {
+    /// An Aleo address, as an `address` literal.
+    Address,
+    /// An Aleo signature, as a composite of `scalar scalar field field` members for
+    /// the prover response, the verifier challenge, and the x-coordinates of `pk_sig` and `pr_sig`.
+    Signature,
+    /// A secp256k1 public key, as a composite of `u128 u128 u128 u128` members for
+    /// the affine coordinates `x` and `y`, where each coordinate is given as big-endian halves.
+    ECDSAPublicKey,
+    /// A secp256k1 signature, as a composite of `u128 u128 u128 u128` members for
+    /// the components `r` and `s`, where each component is given as big-endian halves.
+    ECDSASignature,
+    /// A 256-bit message hash, as a composite of `u128 u128` members given as big-endian halves.
+    MessageHash,
+    /// A message, as a literal, or as the literals of a (possibly nested) composite.
+    Message,
+}
+
+impl OperandType {
+    /// Returns `true` if the operand type is a composite.
+    fn is_composite(&self) -> bool {
+        !matches!(self, Self::Address | Self::Message)
+    }
+
+    /// Returns the expected literal types of the operand, or `None` if any literal types are allowed.
+    fn expected_types(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Address => Some(&["address"]),
+            Self::Signature => Some(&["scalar", "scalar", "field", "field"]),
+            Self::ECDSAPublicKey | Self::ECDSASignature => Some(&["u128", "u128", "u128", "u128"]),
+            Self::MessageHash => Some(&["u128", "u128"]),
+            Self::Message => None,
+        }
+    }
+
+    /// Returns `true` if the given value is of the operand type.
+    pub(crate) fn is_valid<P: Program>(&self, value: &Value<P>) -> bool {
+        // Returns `true` if the given value is a literal of the expected type.
+        let is_literal_of = |value: &Value<P>, expected: &str| match value {
+            Value::Literal(literal) => LiteralType::<P>::from(literal).type_name() == expected,
+            Value::Composite(..) => false,
+        };
+
+        match (value, self.expected_types()) {
+            (_, None) => true,
+            (Value::Literal(..), Some(expected_types)) => {
+                !self.is_composite() && expected_types.len() == 1 && is_literal_of(value, expected_types[0])
+            }
+            (Value::Composite(_, members), Some(expected_types)) => {
+                self.is_composite()
+                    && members.len() == expected_types.len()
+                    && members.iter().zip_eq(expected_types).all(|(member, expected)| is_literal_of(member, expected))
+            }
+        }
+    }
+
+    /// Returns `true` if the given operand is of the operand type, or is a register.
+    /// Note that the type of a register is only known when the instruction is evaluated.
+    pub(crate) fn is_valid_operand<P: Program>(&self, operand: &Operand<P>) -> bool {
+        match operand {
+            Operand::Value(value) => self.is_valid(value),
+            Operand::Register(..) => true,
+        }
+    }
+
+    ///
+    /// Loads the value of the given operand, returning its literals in order of a depth-first traversal.
+    ///
+    /// # Errors
+    /// This method will halt if the value is not of the operand type.
+    ///
+    pub(crate) fn load<P: Program>(
+        &self,
+        registers: &Registers<P>,
+        operand: &Operand<P>,
+    ) -> Vec<Literal<P::Environment>> {
+        match registers.load(operand) {
+            value if !self.is_valid(&value) => P::halt(format!("Expected {self}, found '{value}'")),
+            value => value.to_literals(),
+        }
+    }
+}
+
+impl fmt::Display for OperandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Address => write!(f, "an address"),
+            Self::Signature => write!(f, "a signature"),
+            Self::ECDSAPublicKey => write!(f, "an ECDSA public key"),
+            Self::ECDSASignature => write!(f, "an ECDSA signature"),
+            Self::MessageHash => write!(f, "a message hash"),
+            Self::Message => write!(f, "a message"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, Process};
+
+    type P = Process;
+
+    fn composite(members: &[&str]) -> Value<P> {
+        Value::Composite(
+            Identifier::from_str("composite"),
+            members.iter().map(|member| Value::Literal(Literal::from_str(member))).collect(),
+        )
+    }
+
+    #[test]
+    fn test_operand_type_address() {
+        let address = Address::from(Group::from_str("2group.private"));
+        assert!(OperandType::Address.is_valid(&Value::<P>::Literal(Literal::Address(address))));
+        assert!(!OperandType::Address.is_valid(&Value::<P>::from_str("1field.private")));
+        assert!(OperandType::Address.is_valid_operand(&Operand::<P>::from_str("r0")));
+    }
+
+    #[test]
+    fn test_operand_type_signature() {
+        let signature = composite(&["1scalar.private", "2scalar.private", "3field.private", "4field.private"]);
+        assert!(OperandType::Signature.is_valid(&signature));
+        assert!(!OperandType::ECDSASignature.is_valid(&signature));
+        assert!(OperandType::Message.is_valid(&signature));
+
+        let signature = composite(&["1scalar.private", "2scalar.private", "3field.private"]);
+        assert!(!OperandType::Signature.is_valid(&signature));
+    }
+
+    #[test]
+    fn test_operand_type_ecdsa() {
+        let public_key = composite(&["1u128.public", "2u128.public", "3u128.public", "4u128.public"]);
+        assert!(OperandType::ECDSAPublicKey.is_valid(&public_key));
+        assert!(OperandType::ECDSASignature.is_valid(&public_key));
+        assert!(!OperandType::MessageHash.is_valid(&public_key));
+
+        let message_hash = composite(&["1u128.public", "2u128.public"]);
+        assert!(OperandType::MessageHash.is_valid(&message_hash));
+        assert!(!OperandType::MessageHash.is_valid(&Value::<P>::from_str("1u128.public")));
+    }
+}
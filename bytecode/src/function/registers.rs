@@ -135,7 +135,7 @@ impl<P: Program> Registers<P> {
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
+    /// In the case of register members, this method will halt if any member along the path is not found.
     #[inline]
     pub fn load<O: Into<Operand<P>>>(&self, operand: O) -> Value<P> {
         // Retrieve the register.
@@ -158,30 +158,11 @@ impl<P: Program> Registers<P> {
         match register {
             // If the register is a locator, then return the value.
             Register::Locator(..) => value,
-            // If the register is a register member, then load the specific value.
-            Register::Member(_, ref member_name) => match value {
-                // Halts if the value is not a composite.
-                Value::Literal(..) => P::halt("Cannot load a register member from a literal"),
-                // Retrieve the value of the member (from the value).
-                Value::Composite(definition, members) => {
-                    // Retrieve the member index of the identifier (from the definition).
-                    let member_index = match P::get_definition(&definition) {
-                        Some(definition) => {
-                            definition.members().iter().position(|member| member.name() == member_name).unwrap_or_else(
-                                || P::halt(format!("Failed to locate \'{member_name}\' in \'{definition}\'")),
-                            )
-                        }
-                        // Halts if the definition does not exist.
-                        None => P::halt(format!("Failed to locate \'{definition}\'")),
-                    };
-                    // Return the value of the member.
-                    match members.get(member_index) {
-                        Some(literal) => (*literal).clone().into(),
-                        // Halts if the member does not exist.
-                        None => P::halt(format!("Failed to locate \'{register}\'")),
-                    }
-                }
-            },
+            // If the register is a register member, then load the specific value,
+            // by accessing each member along the path (from the definitions).
+            Register::Member(_, ref member_names) => {
+                member_names.iter().fold(value, |value, member_name| value.member(member_name).clone())
+            }
         }
     }
 
@@ -22,6 +22,7 @@ use snarkvm_circuits::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
 use core::{cmp::Ordering, fmt};
+use nom::sequence::preceded;
 use std::io::{Read, Result as IoResult, Write};
 
 pub type Locator = u64;
@@ -31,8 +32,8 @@ pub type Locator = u64;
 pub enum Register<P: Program> {
     /// A register contains its locator in memory.
     Locator(Locator),
-    /// A register member contains its locator and identifier in memory.
-    Member(Locator, Identifier<P>),
+    /// A register member contains its locator and the path of identifiers to the member in memory.
+    Member(Locator, Vec<Identifier<P>>),
 }
 
 impl<P: Program> Register<P> {
@@ -50,7 +51,8 @@ impl<P: Program> Parser for Register<P> {
     type Environment = P::Environment;
 
     /// Parses a string into a register.
-    /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
+    /// The register is of the form `r{locator}` or `r{locator}.{identifier}`, where nested members
+    /// are accessed as `r{locator}.{identifier}.{identifier}`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
@@ -58,12 +60,12 @@ impl<P: Program> Parser for Register<P> {
         // Parse the locator from the string.
         let (string, locator) =
             map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
-        // Parse the identifier from the string, if it is a register member.
-        let (string, identifier) = opt(pair(tag("."), Identifier::parse))(string)?;
+        // Parse the identifiers from the string, if it is a register member.
+        let (string, identifiers) = many0(preceded(tag("."), Identifier::parse))(string)?;
         // Return the register.
-        Ok((string, match identifier {
-            Some((_, identifier)) => Self::Member(locator, identifier),
-            None => Self::Locator(locator),
+        Ok((string, match identifiers.is_empty() {
+            true => Self::Locator(locator),
+            false => Self::Member(locator, identifiers),
         }))
     }
 }
@@ -74,8 +76,11 @@ impl<P: Program> fmt::Display for Register<P> {
         match self {
             // Prints the register, i.e. r0
             Self::Locator(locator) => write!(f, "r{locator}"),
-            // Prints the register member, i.e. r0.owner
-            Self::Member(locator, identifier) => write!(f, "r{locator}.{identifier}"),
+            // Prints the register member, i.e. r0.owner or r0.token.owner
+            Self::Member(locator, identifiers) => {
+                write!(f, "r{locator}")?;
+                identifiers.iter().try_for_each(|identifier| write!(f, ".{identifier}"))
+            }
         }
     }
 }
@@ -86,7 +91,17 @@ impl<P: Program> FromBytes for Register<P> {
         let locator = read_variable_length_integer(&mut reader)?;
         match variant {
             0 => Ok(Self::Locator(locator)),
-            1 => Ok(Self::Member(locator, Identifier::read_le(&mut reader)?)),
+            1 => {
+                let num_identifiers = u8::read_le(&mut reader)?;
+                if num_identifiers == 0 {
+                    return Err(error("Failed to deserialize a register member without identifiers"));
+                }
+                let mut identifiers = Vec::with_capacity(num_identifiers as usize);
+                for _ in 0..num_identifiers {
+                    identifiers.push(Identifier::read_le(&mut reader)?);
+                }
+                Ok(Self::Member(locator, identifiers))
+            }
             2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
     }
@@ -99,10 +114,11 @@ impl<P: Program> ToBytes for Register<P> {
                 u8::write_le(&0u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)
             }
-            Self::Member(locator, member) => {
+            Self::Member(locator, identifiers) => {
                 u8::write_le(&1u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)?;
-                member.write_le(&mut writer)
+                (identifiers.len() as u8).write_le(&mut writer)?;
+                identifiers.write_le(&mut writer)
             }
         }
     }
@@ -139,11 +155,11 @@ mod tests {
         assert_eq!("r4", format!("{}", Register::<P>::Locator(4)));
 
         // Register::Member
-        assert_eq!("r0.owner", format!("{}", Register::<P>::Member(0, Identifier::from_str("owner"))));
-        assert_eq!("r1.owner", format!("{}", Register::<P>::Member(1, Identifier::from_str("owner"))));
-        assert_eq!("r2.owner", format!("{}", Register::<P>::Member(2, Identifier::from_str("owner"))));
-        assert_eq!("r3.owner", format!("{}", Register::<P>::Member(3, Identifier::from_str("owner"))));
-        assert_eq!("r4.owner", format!("{}", Register::<P>::Member(4, Identifier::from_str("owner"))));
+        assert_eq!("r0.owner", format!("{}", Register::<P>::Member(0, vec![Identifier::from_str("owner")])));
+        assert_eq!("r1.owner", format!("{}", Register::<P>::Member(1, vec![Identifier::from_str("owner")])));
+        assert_eq!("r2.owner", format!("{}", Register::<P>::Member(2, vec![Identifier::from_str("owner")])));
+        assert_eq!("r3.owner", format!("{}", Register::<P>::Member(3, vec![Identifier::from_str("owner")])));
+        assert_eq!("r4.owner", format!("{}", Register::<P>::Member(4, vec![Identifier::from_str("owner")])));
     }
 
     #[test]
@@ -156,18 +172,18 @@ mod tests {
         // Register::Member
         assert_eq!(
             Some(Ordering::Equal),
-            Register::<P>::Member(0, Identifier::from_str("owner"))
-                .partial_cmp(&Register::<P>::Member(0, Identifier::from_str("owner")))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")])
+                .partial_cmp(&Register::<P>::Member(0, vec![Identifier::from_str("owner")]))
         );
         assert_eq!(
             Some(Ordering::Less),
-            Register::<P>::Member(0, Identifier::from_str("owner"))
-                .partial_cmp(&Register::<P>::Member(1, Identifier::from_str("owner")))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")])
+                .partial_cmp(&Register::<P>::Member(1, vec![Identifier::from_str("owner")]))
         );
         assert_eq!(
             Some(Ordering::Greater),
-            Register::<P>::Member(1, Identifier::from_str("owner"))
-                .partial_cmp(&Register::<P>::Member(0, Identifier::from_str("owner")))
+            Register::<P>::Member(1, vec![Identifier::from_str("owner")])
+                .partial_cmp(&Register::<P>::Member(0, vec![Identifier::from_str("owner")]))
         );
     }
 
@@ -182,24 +198,24 @@ mod tests {
 
         // Register::Member
         assert_eq!(
-            Register::<P>::Member(0, Identifier::from_str("owner")),
-            Register::<P>::Member(0, Identifier::from_str("owner"))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")]),
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")])
         );
         assert_ne!(
-            Register::<P>::Member(0, Identifier::from_str("owner")),
-            Register::<P>::Member(1, Identifier::from_str("owner"))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")]),
+            Register::<P>::Member(1, vec![Identifier::from_str("owner")])
         );
         assert_ne!(
-            Register::<P>::Member(0, Identifier::from_str("owner")),
-            Register::<P>::Member(2, Identifier::from_str("owner"))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")]),
+            Register::<P>::Member(2, vec![Identifier::from_str("owner")])
         );
         assert_ne!(
-            Register::<P>::Member(0, Identifier::from_str("owner")),
-            Register::<P>::Member(3, Identifier::from_str("owner"))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")]),
+            Register::<P>::Member(3, vec![Identifier::from_str("owner")])
         );
         assert_ne!(
-            Register::<P>::Member(0, Identifier::from_str("owner")),
-            Register::<P>::Member(4, Identifier::from_str("owner"))
+            Register::<P>::Member(0, vec![Identifier::from_str("owner")]),
+            Register::<P>::Member(4, vec![Identifier::from_str("owner")])
         );
     }
 
@@ -213,11 +229,11 @@ mod tests {
         assert_eq!(Register::<P>::Locator(4).to_string(), "r4".to_string());
 
         // Register::Member
-        assert_eq!(Register::<P>::Member(0, Identifier::from_str("owner")).to_string(), "r0.owner".to_string());
-        assert_eq!(Register::<P>::Member(1, Identifier::from_str("owner")).to_string(), "r1.owner".to_string());
-        assert_eq!(Register::<P>::Member(2, Identifier::from_str("owner")).to_string(), "r2.owner".to_string());
-        assert_eq!(Register::<P>::Member(3, Identifier::from_str("owner")).to_string(), "r3.owner".to_string());
-        assert_eq!(Register::<P>::Member(4, Identifier::from_str("owner")).to_string(), "r4.owner".to_string());
+        assert_eq!(Register::<P>::Member(0, vec![Identifier::from_str("owner")]).to_string(), "r0.owner".to_string());
+        assert_eq!(Register::<P>::Member(1, vec![Identifier::from_str("owner")]).to_string(), "r1.owner".to_string());
+        assert_eq!(Register::<P>::Member(2, vec![Identifier::from_str("owner")]).to_string(), "r2.owner".to_string());
+        assert_eq!(Register::<P>::Member(3, vec![Identifier::from_str("owner")]).to_string(), "r3.owner".to_string());
+        assert_eq!(Register::<P>::Member(4, vec![Identifier::from_str("owner")]).to_string(), "r4.owner".to_string());
     }
 
     #[test]
@@ -230,11 +246,10 @@ mod tests {
         assert_eq!(("", Register::<P>::Locator(4)), Register::parse("r4").unwrap());
 
         // Register::Member
-        assert_eq!(("", Register::<P>::Member(0, Identifier::from_str("owner"))), Register::parse("r0.owner").unwrap());
-        assert_eq!(("", Register::<P>::Member(1, Identifier::from_str("owner"))), Register::parse("r1.owner").unwrap());
-        assert_eq!(("", Register::<P>::Member(2, Identifier::from_str("owner"))), Register::parse("r2.owner").unwrap());
-        assert_eq!(("", Register::<P>::Member(3, Identifier::from_str("owner"))), Register::parse("r3.owner").unwrap());
-        assert_eq!(("", Register::<P>::Member(4, Identifier::from_str("owner"))), Register::parse("r4.owner").unwrap());
+        for locator in 0..5 {
+            let expected = Register::<P>::Member(locator, vec![Identifier::from_str("owner")]);
+            assert_eq!(("", expected), Register::parse(&format!("r{locator}.owner")).unwrap());
+        }
     }
 
     #[test]
@@ -249,4 +264,14 @@ mod tests {
         // assert!(Register::<P>::parse("r0.owner.owner.owner.owner.owner.owner.owner").is_err());
         // assert!(Register::<P>::parse("r0.owner.owner.owner.owner.owner.owner.owner.owner").is_err());
     }
+
+    #[test]
+    fn test_register_nested_member() {
+        let expected = Register::<P>::Member(0, vec![Identifier::from_str("token"), Identifier::from_str("owner")]);
+        assert_eq!(("", expected.clone()), Register::parse("r0.token.owner").unwrap());
+        assert_eq!("r0.token.owner", expected.to_string());
+
+        let candidate = Register::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+    }
 }
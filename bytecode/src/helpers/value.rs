@@ -14,11 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Identifier, LiteralType, Program};
+use crate::{Annotation, Identifier, LiteralType, Program, Register};
 use snarkvm_circuits::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
 use core::fmt;
+use nom::{
+    error::{VerboseError, VerboseErrorKind},
+    sequence::{delimited, preceded},
+};
 use std::io::{Read, Result as IoResult, Write};
 
 /// A value contains the underlying literal(s) in memory.
@@ -26,8 +30,8 @@ use std::io::{Read, Result as IoResult, Write};
 pub enum Value<P: Program> {
     /// A literal contains its declared literal value.
     Literal(Literal<P::Environment>),
-    /// A composite contains its declared member literals.
-    Composite(Identifier<P>, Vec<Literal<P::Environment>>),
+    /// A composite contains its declared member values, which may be literals or nested composites.
+    Composite(Identifier<P>, Vec<Value<P>>),
 }
 
 impl<P: Program> From<Literal<P::Environment>> for Value<P> {
@@ -37,6 +41,9 @@ impl<P: Program> From<Literal<P::Environment>> for Value<P> {
 }
 
 impl<P: Program> Value<P> {
+    /// The maximum depth of a composite, which bounds the recursion when parsing or reading a value.
+    pub const MAX_DEPTH: usize = 32;
+
     /// Returns the annotation.
     #[inline]
     pub fn annotation(&self) -> Annotation<P> {
@@ -51,8 +58,111 @@ impl<P: Program> Value<P> {
     pub fn is_constant(&self) -> bool {
         match self {
             Self::Literal(literal) => literal.is_constant(),
-            Self::Composite(_, members) => members.iter().all(|literal| literal.is_constant()),
+            Self::Composite(_, members) => members.iter().all(|member| member.is_constant()),
+        }
+    }
+
+    ///
+    /// Returns the value of the member with the given name.
+    ///
+    /// # Errors
+    /// This method will halt if the value is not a composite.
+    /// This method will halt if the definition of the composite, or the member, is not found.
+    ///
+    #[inline]
+    pub fn member(&self, member_name: &Identifier<P>) -> &Value<P> {
+        match self {
+            // Halts if the value is not a composite.
+            Self::Literal(..) => P::halt(format!("Cannot load member \'{member_name}\' from a literal")),
+            Self::Composite(name, members) => {
+                // Retrieve the member index of the identifier (from the definition).
+                let member_index = match P::get_definition(name) {
+                    Some(definition) => {
+                        definition.members().iter().position(|member| member.name() == member_name).unwrap_or_else(
+                            || P::halt(format!("Failed to locate \'{member_name}\' in \'{definition}\'")),
+                        )
+                    }
+                    // Halts if the definition does not exist.
+                    None => P::halt(format!("Failed to locate \'{name}\'")),
+                };
+                // Return the value of the member.
+                match members.get(member_index) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => P::halt(format!("Failed to locate \'{member_name}\' in \'{name}\'")),
+                }
+            }
+        }
+    }
+
+    /// Returns the literals of the value, in order of a depth-first traversal of its members.
+    #[inline]
+    pub fn to_literals(&self) -> Vec<Literal<P::Environment>> {
+        match self {
+            Self::Literal(literal) => vec![literal.clone()],
+            Self::Composite(_, members) => members.iter().flat_map(|member| member.to_literals()).collect(),
+        }
+    }
+
+    ///
+    /// Returns the canonical little-endian bits of the value.
+    ///
+    /// A literal is encoded as its bits. A composite is encoded as the bytes of its name, followed by
+    /// the encoding of each member, where the name and each member are prefixed with their number
+    /// of bits as a `u32`. As such, the encoding is injective, and is suitable for hashing.
+    ///
+    pub fn to_bits_le(&self) -> Vec<Boolean<P::Environment>> {
+        // Returns the given bits, prefixed with their number of bits as a `u32`.
+        let length_prefixed = |bits: Vec<Boolean<P::Environment>>| {
+            let length = bits.len() as u32;
+            (0..32).map(|i| Boolean::constant((length >> i) & 1 == 1)).chain(bits).collect::<Vec<_>>()
+        };
+
+        match self {
+            Self::Literal(literal) => literal.to_bits_le(),
+            Self::Composite(name, members) => {
+                let name_bits = name
+                    .to_string()
+                    .as_bytes()
+                    .iter()
+                    .flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1)))
+                    .collect();
+
+                let mut bits_le = length_prefixed(name_bits);
+                for member in members {
+                    bits_le.extend(length_prefixed(member.to_bits_le()));
+                }
+                bits_le
+            }
+        }
+    }
+
+    /// Parses a composite of the form `name member member ... member`, where each member is
+    /// either a literal, or a nested composite enclosed in parentheses, at the given depth.
+    fn parse_composite(string: &str, depth: usize) -> ParserResult<Self> {
+        // Ensure the composite does not exceed the maximum depth.
+        if depth > Self::MAX_DEPTH {
+            let error = VerboseErrorKind::Context("Composite exceeds the maximum depth");
+            return Err(nom::Err::Failure(VerboseError { errors: vec![(string, error)] }));
         }
+        // Parse the name from the string, ensuring it is not a register.
+        let (string, name) =
+            map_res(Identifier::parse, |name: Identifier<P>| match Register::<P>::parse(&name.to_string()) {
+                Ok(("", register)) => Err(error(format!("Expected a composite name, found register {register}"))),
+                _ => Ok(name),
+            })(string)?;
+        // Parse the members from the string.
+        let (string, members) = many1(preceded(tag(" "), |string| Self::parse_member(string, depth)))(string)?;
+
+        Ok((string, Self::Composite(name, members)))
+    }
+
+    /// Parses a member of a composite at the given depth, of the form: literal, or (name member ... member).
+    fn parse_member(string: &str, depth: usize) -> ParserResult<Self> {
+        alt((
+            map(Literal::parse, |literal| Self::Literal(literal)),
+            delimited(tag("("), |string| Self::parse_composite(string, depth + 1), tag(")")),
+        ))(string)
     }
 }
 
@@ -62,20 +172,8 @@ impl<P: Program> Parser for Value<P> {
     /// Parses a string into a value.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        // Parses a sequence of form: literal literal ... literal
-        let sequence_parse =
-            map(pair(pair(many0(Literal::parse), tag(" ")), Literal::parse), |((literals, _), literal)| {
-                let mut literals = literals;
-                literals.push(literal);
-                literals
-            });
-        // Parses a composite of form: name literal literal ... literal
-        let composite_parser = map(pair(pair(Identifier::parse, tag(" ")), sequence_parse), |((name, _), literals)| {
-            Self::Composite(name, literals)
-        });
-
         // Parse to determine the value (order matters).
-        alt((map(Literal::parse, |literal| Self::Literal(literal)), composite_parser))(string)
+        alt((map(Literal::parse, |literal| Self::Literal(literal)), |string| Self::parse_composite(string, 1)))(string)
     }
 }
 
@@ -85,11 +183,14 @@ impl<P: Program> fmt::Display for Value<P> {
         match self {
             // Prints the literal, i.e. 10field.private
             Self::Literal(literal) => fmt::Display::fmt(literal, f),
-            // Prints the composite, i.e. message aleo1xxx.public 10i64.private
+            // Prints the composite, i.e. message aleo1xxx.public 10i64.private (token 5u64.private)
             Self::Composite(name, members) => {
                 let mut output = format!("{name} ");
-                for value in members.iter() {
-                    output += &format!("{value} ");
+                for member in members.iter() {
+                    match member {
+                        Self::Literal(..) => output += &format!("{member} "),
+                        Self::Composite(..) => output += &format!("({member}) "),
+                    }
                 }
                 output.pop(); // trailing space
                 write!(f, "{output}")
@@ -98,51 +199,68 @@ impl<P: Program> fmt::Display for Value<P> {
     }
 }
 
-impl<P: Program> FromBytes for Value<P> {
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let variant = u8::read_le(&mut reader)?;
+impl<P: Program> Value<P> {
+    /// Reads a value at the given depth from the given reader, recursing into the members of a composite.
+    /// The reader is taken by reference so each recursive call reuses the same reader type.
+    fn read_value_le<R: Read>(reader: &mut R, depth: usize) -> IoResult<Self> {
+        let variant = u8::read_le(&mut *reader)?;
         match variant {
-            0 => Ok(Self::Literal(Literal::read_le(&mut reader)?)),
+            0 => Ok(Self::Literal(Literal::read_le(&mut *reader)?)),
+            // Ensure the composite does not exceed the maximum depth.
+            1 if depth > Self::MAX_DEPTH => {
+                Err(error(format!("Composite exceeds the maximum depth of {}", Self::MAX_DEPTH)))
+            }
             1 => {
                 // Read the name.
-                let name = Identifier::read_le(&mut reader)?;
+                let name = Identifier::read_le(&mut *reader)?;
                 // Read the members.
-                let num_members = u16::read_le(&mut reader)?;
-                let mut members = Vec::with_capacity(num_members as usize);
-                for _ in 0..num_members {
-                    members.push(Literal::read_le(&mut reader)?);
-                }
+                // Note: The members are read one by one, as the given length is not trusted.
+                let num_members = u16::read_le(&mut *reader)?;
+                let members =
+                    (0..num_members).map(|_| Self::read_value_le(reader, depth + 1)).collect::<IoResult<_>>()?;
                 Ok(Self::Composite(name, members))
             }
             2.. => Err(error(format!("Failed to deserialize value variant {variant}"))),
         }
     }
-}
 
-impl<P: Program> ToBytes for Value<P> {
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the value to the given writer, recursing into the members of a composite.
+    /// The writer is taken by reference so each recursive call reuses the same writer type.
+    fn write_value_le<W: Write>(&self, writer: &mut W) -> IoResult<()> {
         match self {
             Self::Literal(literal) => {
-                u8::write_le(&0u8, &mut writer)?;
-                literal.write_le(&mut writer)
+                u8::write_le(&0u8, &mut *writer)?;
+                literal.write_le(&mut *writer)
             }
             Self::Composite(name, members) => {
-                u8::write_le(&1u8, &mut writer)?;
-                name.write_le(&mut writer)?;
-                (members.len() as u16).write_le(&mut writer)?;
-                members.write_le(&mut writer)
+                u8::write_le(&1u8, &mut *writer)?;
+                name.write_le(&mut *writer)?;
+                (members.len() as u16).write_le(&mut *writer)?;
+                members.iter().try_for_each(|member| member.write_value_le(writer))
             }
         }
     }
 }
 
+impl<P: Program> FromBytes for Value<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Self::read_value_le(&mut reader, 1)
+    }
+}
+
+impl<P: Program> ToBytes for Value<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.write_value_le(&mut writer)
+    }
+}
+
 #[cfg(test)] // Do not remove this. It is not a performant way to compare values.
 impl<P: Program> PartialEq for Value<P> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Literal(literal), Self::Literal(other_literal)) => literal.eject() == other_literal.eject(),
             (Self::Composite(name, members), Self::Composite(other_name, other_members)) => {
-                name == other_name && members.eject() == other_members.eject()
+                name == other_name && members == other_members
             }
             _ => false,
         }
@@ -167,10 +285,99 @@ mod tests {
         // Test parsing a composite.
         assert_eq!(
             Value::<P>::Composite(Identifier::from_str("message"), vec![
-                Literal::from_str("2group.public"),
-                Literal::from_str("10field.private"),
+                Value::Literal(Literal::from_str("2group.public")),
+                Value::Literal(Literal::from_str("10field.private")),
             ]),
             Value::parse("message 2group.public 10field.private").unwrap().1,
         );
+
+        // Test parsing a composite with more than two members.
+        assert_eq!(
+            Value::<P>::Composite(Identifier::from_str("message"), vec![
+                Value::Literal(Literal::from_str("1field.public")),
+                Value::Literal(Literal::from_str("2field.public")),
+                Value::Literal(Literal::from_str("3field.public")),
+            ]),
+            Value::parse("message 1field.public 2field.public 3field.public").unwrap().1,
+        );
+
+        // Test parsing a nested composite.
+        assert_eq!(
+            Value::<P>::Composite(Identifier::from_str("message"), vec![
+                Value::Literal(Literal::from_str("2group.public")),
+                Value::Composite(Identifier::from_str("token"), vec![
+                    Value::Literal(Literal::from_str("1u64.private")),
+                    Value::Literal(Literal::from_str("true.private")),
+                ]),
+            ]),
+            Value::parse("message 2group.public (token 1u64.private true.private)").unwrap().1,
+        );
+
+        // Test that a register is not parsed as a composite.
+        assert!(Value::<P>::parse("r0 1field.public").is_err());
+    }
+
+    #[test]
+    fn test_value_display() {
+        for string in [
+            "10field.private",
+            "message 2group.public 10field.private",
+            "message 2group.public (token 1u64.private (amount 5u64.public)) true.constant",
+        ] {
+            assert_eq!(string, Value::<P>::from_str(string).to_string());
+        }
+    }
+
+    #[test]
+    fn test_value_bytes() {
+        let expected = Value::<P>::from_str("message 2group.public (token 1u64.private true.private)");
+        let candidate = Value::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_value_max_depth() {
+        // Returns a composite string with the given depth.
+        let nested = |depth: usize| {
+            format!("message {}1field.public{}", "(message ".repeat(depth - 1), ")".repeat(depth - 1))
+        };
+
+        // Ensure a composite of the maximum depth is parsed and read back.
+        let value = Value::<P>::parse(&nested(Value::<P>::MAX_DEPTH)).unwrap().1;
+        assert_eq!(value, Value::<P>::from_bytes_le(&value.to_bytes_le().unwrap()).unwrap());
+
+        // Ensure a composite exceeding the maximum depth is rejected by the parser.
+        assert!(Value::<P>::parse(&nested(Value::<P>::MAX_DEPTH + 1)).is_err());
+        assert!(Value::<P>::parse(&nested(100_000)).is_err());
+
+        // Ensure a composite exceeding the maximum depth is rejected when read, without exhausting the stack.
+        let literal = Value::<P>::from_str("1field.public").to_bytes_le().unwrap();
+        let composite = Value::<P>::from_str("message 1field.public").to_bytes_le().unwrap();
+        let prefix = &composite[..composite.len() - literal.len()];
+        for depth in [Value::<P>::MAX_DEPTH + 1, 100_000] {
+            let bytes = [prefix.repeat(depth), literal.clone()].concat();
+            assert!(Value::<P>::from_bytes_le(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_value_to_bits_le() {
+        let first = Value::<P>::from_str("message 1field.public (token 2field.public)");
+        let second = Value::<P>::from_str("message (token 1field.public) 2field.public");
+        let third = Value::<P>::from_str("token 1field.public (token 2field.public)");
+
+        // Ensure the encoding of a literal is its bits.
+        let literal = Literal::<<P as Program>::Aleo>::from_str("1field.public");
+        assert_eq!(literal.to_bits_le().eject_value(), Value::<P>::Literal(literal).to_bits_le().eject_value());
+
+        // Ensure the encoding distinguishes the structure and name of a composite.
+        assert_ne!(first.to_bits_le().eject_value(), second.to_bits_le().eject_value());
+        assert_ne!(first.to_bits_le().eject_value(), third.to_bits_le().eject_value());
+
+        // Ensure the literals are in order of a depth-first traversal.
+        let literals = second.to_literals();
+        assert_eq!(2, literals.len());
+        assert_eq!(Value::<P>::from_str("1field.public"), Value::Literal(literals[0].clone()));
+        assert_eq!(Value::<P>::from_str("2field.public"), Value::Literal(literals[1].clone()));
     }
 }
@@ -222,6 +222,39 @@ function compute:
         assert_eq!(expected, output[0]);
     }
 
+    #[test]
+    fn test_process_nested_member() {
+        // Create a new program.
+        Process::parse(
+            r"
+type token:
+    owner as group.private;
+    amount as field.private;
+
+type wallet:
+    balance as token;
+    fee as field.public;
+
+function spend:
+    input r0 as wallet;
+    sub r0.balance.amount r0.fee into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+
+        // Declare the input value, and ensure it matches the nested definitions.
+        let input = Value::from_str("wallet (token 2group.private 5field.private) 2field.public");
+        let wallet = Process::get_definition(&Identifier::from_str("wallet")).unwrap();
+        assert!(wallet.matches(&input));
+        assert!(!wallet.matches(&Value::from_str("wallet 5field.private 2field.public")));
+
+        // Compute the output value.
+        let spend = Process::get_function(&Identifier::from_str("spend")).unwrap();
+        let output = spend.evaluate(&[input]);
+        assert_eq!(1, output.len());
+        assert_eq!(Value::from_str("3field.private"), output[0]);
+    }
+
     #[test]
     fn test_process_display() {
         // Create a new program.
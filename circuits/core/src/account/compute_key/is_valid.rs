@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ComputeKey<A> {
+    /// Returns `true` if the compute key is well-formed, i.e. `sk_prf = RO(G^sk_sig || G^r_sig)`.
+    pub fn is_valid(&self) -> Boolean<A> {
+        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+        let candidate_sk_prf = A::hash_to_scalar(&[self.pk_sig.to_x_coordinate(), self.pr_sig.to_x_coordinate()]);
+
+        self.sk_prf.is_equal(&candidate_sk_prf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::compute_key::from_private_key::tests::generate_private_and_compute_key, Devnet as Circuit};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    fn check_is_valid(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Generate the private key and compute key components.
+            let (_, _, pk_sig, pr_sig, sk_prf) = generate_private_and_compute_key();
+
+            // Initialize a valid compute key, and one with a random PRF secret key.
+            let valid = ComputeKey::<Circuit>::new(mode, (pk_sig, pr_sig, sk_prf));
+            let invalid = ComputeKey::<Circuit>::new(mode, (pk_sig, pr_sig, UniformRand::rand(&mut test_rng())));
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                assert!(valid.is_valid().eject_value());
+                assert!(!invalid.is_valid().eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_is_valid_constant() {
+        check_is_valid(Mode::Constant);
+    }
+
+    #[test]
+    fn test_is_valid_public() {
+        check_is_valid(Mode::Public);
+    }
+
+    #[test]
+    fn test_is_valid_private() {
+        check_is_valid(Mode::Private);
+    }
+}
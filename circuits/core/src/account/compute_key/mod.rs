@@ -15,12 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod from_private_key;
+pub mod is_valid;
+pub mod to_address;
 
 #[cfg(test)]
 use snarkvm_circuits_types::environment::assert_scope;
 
 use crate::{account::PrivateKey, Aleo};
-use snarkvm_circuits_types::{environment::prelude::*, Group, Scalar};
+use snarkvm_circuits_types::{environment::prelude::*, Address, Boolean, Group, Scalar};
 
 pub struct ComputeKey<A: Aleo> {
     /// The signature public key `pk_sig` := G^sk_sig.
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ComputeKey<A> {
+    /// Returns the account address for this account compute key, as `pk_sig + pr_sig + G^sk_prf`.
+    pub fn to_address(&self) -> Address<A> {
+        // Compute pk_prf := G^sk_prf.
+        let pk_prf = A::g_scalar_multiply(&self.sk_prf);
+
+        // Compute the address := pk_sig + pr_sig + pk_prf.
+        Address::from(&self.pk_sig + &self.pr_sig + pk_prf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::compute_key::from_private_key::tests::generate_private_and_compute_key, Devnet as Circuit};
+    use snarkvm_algorithms::SignatureSchemeOperations;
+    use snarkvm_curves::ProjectiveCurve;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_address(mode: Mode) {
+        let native = Circuit::native_signature_scheme();
+
+        for i in 0..ITERATIONS {
+            // Generate the private key and compute key components.
+            let (sk_sig, r_sig, pk_sig, pr_sig, sk_prf) = generate_private_and_compute_key();
+
+            // Compute the address := G^(sk_sig + r_sig + sk_prf).
+            let expected = native.g_scalar_multiply(&(sk_sig + r_sig + sk_prf)).to_affine();
+
+            // Initialize the compute key.
+            let compute_key = ComputeKey::<Circuit>::new(mode, (pk_sig, pr_sig, sk_prf));
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = compute_key.to_address();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_address_constant() {
+        check_to_address(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_address_public() {
+        check_to_address(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_address_private() {
+        check_to_address(Mode::Private);
+    }
+}
@@ -14,13 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod to_address;
 pub mod to_compute_key;
+pub mod to_view_key;
 
 #[cfg(test)]
 use snarkvm_circuits_types::environment::assert_scope;
 
-use crate::{account::ComputeKey, Aleo};
-use snarkvm_circuits_types::{environment::prelude::*, Scalar};
+use crate::{
+    account::{ComputeKey, ViewKey},
+    Aleo,
+};
+use snarkvm_circuits_types::{environment::prelude::*, Address, Scalar};
 
 pub struct PrivateKey<A: Aleo> {
     /// The signature secret key.
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> PrivateKey<A> {
+    /// Returns the account address for this account private key.
+    pub fn to_address(&self) -> Address<A> {
+        self.to_compute_key().to_address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::compute_key::from_private_key::tests::generate_private_and_compute_key, Devnet as Circuit};
+    use snarkvm_algorithms::SignatureSchemeOperations;
+    use snarkvm_curves::ProjectiveCurve;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_address(mode: Mode) {
+        let native = Circuit::native_signature_scheme();
+
+        for i in 0..ITERATIONS {
+            // Generate the private key and compute key components.
+            let (sk_sig, r_sig, _, _, sk_prf) = generate_private_and_compute_key();
+
+            // Compute the address := G^(sk_sig + r_sig + sk_prf).
+            let expected = native.g_scalar_multiply(&(sk_sig + r_sig + sk_prf)).to_affine();
+
+            // Initialize the private key.
+            let candidate = PrivateKey::<Circuit>::new(mode, (sk_sig, r_sig));
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_address();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_address_constant() {
+        check_to_address(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_address_public() {
+        check_to_address(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_address_private() {
+        check_to_address(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_address_matches_view_key() {
+        let (sk_sig, r_sig, _, _, _) = generate_private_and_compute_key();
+        let private_key = PrivateKey::<Circuit>::new(Mode::Private, (sk_sig, r_sig));
+
+        // Ensure the address derived from the compute key matches the address derived from the view key.
+        let candidate = private_key.to_address().is_equal(&private_key.to_view_key().to_address());
+        assert!(candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
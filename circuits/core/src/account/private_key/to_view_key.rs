@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> PrivateKey<A> {
+    /// Returns the account view key for this account private key.
+    pub fn to_view_key(&self) -> ViewKey<A> {
+        ViewKey::from_private_key(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::compute_key::from_private_key::tests::generate_private_and_compute_key, Devnet as Circuit};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_view_key(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Generate the private key and compute key components.
+            let (sk_sig, r_sig, _, _, sk_prf) = generate_private_and_compute_key();
+
+            // Initialize the private key.
+            let candidate = PrivateKey::<Circuit>::new(mode, (sk_sig, r_sig));
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_view_key();
+                assert_eq!(sk_sig + r_sig + sk_prf, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_view_key_constant() {
+        check_to_view_key(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_view_key_public() {
+        check_to_view_key(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_view_key_private() {
+        check_to_view_key(Mode::Private);
+    }
+}
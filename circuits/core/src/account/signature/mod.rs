@@ -33,17 +33,30 @@ pub struct Signature<A: Aleo> {
     pr_sig: Group<A>,
 }
 
+impl<A: Aleo> Signature<A> {
+    /// Initializes a signature from the given `prover_response`, `verifier_challenge`,
+    /// and the x-coordinates of `pk_sig` and `pr_sig`.
+    pub fn from(prover_response: Scalar<A>, verifier_challenge: Scalar<A>, pk_sig: Field<A>, pr_sig: Field<A>) -> Self {
+        Self {
+            prover_response,
+            verifier_challenge,
+            pk_sig: Group::from_x_coordinate(pk_sig),
+            pr_sig: Group::from_x_coordinate(pr_sig),
+        }
+    }
+}
+
 impl<A: Aleo> Inject for Signature<A> {
     type Primitive = (A::ScalarField, A::ScalarField, A::BaseField, A::BaseField);
 
     /// Initializes a signature from the given mode and `(prover_response, verifier_challenge, pk_sig, pr_sig)`.
     fn new(mode: Mode, (prover_response, verifier_challenge, pk_sig, pr_sig): Self::Primitive) -> Signature<A> {
-        Self {
-            prover_response: Scalar::new(mode, prover_response),
-            verifier_challenge: Scalar::new(mode, verifier_challenge),
-            pk_sig: Group::from_x_coordinate(Field::new(mode, pk_sig)),
-            pr_sig: Group::from_x_coordinate(Field::new(mode, pr_sig)),
-        }
+        Self::from(
+            Scalar::new(mode, prover_response),
+            Scalar::new(mode, verifier_challenge),
+            Field::new(mode, pk_sig),
+            Field::new(mode, pr_sig),
+        )
     }
 }
 
@@ -19,6 +19,16 @@ use super::*;
 impl<A: Aleo> Signature<A> {
     /// Returns `true` if the signature is valid for the given `address` and `message`.
     pub fn verify(&self, address: &Address<A>, message: &[Literal<A>]) -> Boolean<A> {
+        self.verify_bits(address, &message.to_bits_le())
+    }
+
+    ///
+    /// Returns `true` if the signature is valid for the given `address` and `message`,
+    /// where the message is given as little-endian bits, as in the native `AleoSignatureScheme`.
+    ///
+    /// Note: The number of message bits is part of the challenge preimage, and *must* be constant.
+    ///
+    pub fn verify_bits(&self, address: &Address<A>, message_bits: &[Boolean<A>]) -> Boolean<A> {
         // Compute G^sk_sig^c.
         let pk_sig_c = &self.pk_sig * &self.verifier_challenge;
 
@@ -27,8 +37,7 @@ impl<A: Aleo> Signature<A> {
 
         // Compute the candidate verifier challenge.
         let candidate_verifier_challenge = {
-            // Convert the message into little-endian bits.
-            let message_bits = message.to_bits_le();
+            // Pack the message bits into field elements.
             let message_elements =
                 message_bits.chunks(A::BaseField::size_in_data_bits()).map(FromBits::from_bits_le).collect::<Vec<_>>();
 
@@ -157,16 +166,91 @@ pub(crate) mod tests {
 
     #[test]
     fn test_verify_constant() {
-        check_verify(Mode::Constant, 4287, 0, 0, 0);
+        check_verify(Mode::Constant, 2534, 0, 0, 0);
     }
 
     #[test]
     fn test_verify_public() {
-        check_verify(Mode::Public, 1770, 0, 7325, 7330);
+        check_verify(Mode::Public, 1526, 0, 6838, 6843);
     }
 
     #[test]
     fn test_verify_private() {
-        check_verify(Mode::Private, 1770, 0, 7325, 7330);
+        check_verify(Mode::Private, 1526, 0, 6838, 6843);
+    }
+
+    /// Returns the circuit signature for the given native signature.
+    fn signature_from_native(mode: Mode, signature: &AleoSignature<NativeAffineParameters>) -> Signature<Circuit> {
+        Signature::new(
+            mode,
+            (
+                signature.prover_response,
+                signature.verifier_challenge,
+                signature.root_public_key().expect("Failed to recover").to_x_coordinate(),
+                signature.root_randomizer().expect("Failed to recover").to_x_coordinate(),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_verify_bits() {
+        let rng = &mut test_rng();
+        let native = Circuit::native_signature_scheme();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 252, 253, 600] {
+                // Sample a random message.
+                let message = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+                // Sign the message natively.
+                let (sk_sig, r_sig, address) = generate_private_key_and_address();
+                let signature =
+                    native.sign(&(sk_sig, r_sig), &message, &mut test_crypto_rng()).expect("Failed to sign");
+
+                let address = Address::<Circuit>::new(mode, address);
+                let signature = signature_from_native(mode, &signature);
+                let message: Vec<Boolean<_>> = Inject::new(mode, message);
+
+                Circuit::scope(format!("{mode} {num_bits}"), || {
+                    assert!(signature.verify_bits(&address, &message).eject_value());
+                });
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_invalid() {
+        let rng = &mut test_rng();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random message.
+            let message = vec![Literal::Field(Field::new(mode, UniformRand::rand(rng)))];
+
+            // Generate the private key and signature.
+            let (sk_sig, r_sig, address) = generate_private_key_and_address();
+            let signature = generate_signature((&sk_sig, &r_sig), &address, &message);
+            let signature = signature_from_native(mode, &signature);
+            let address = Address::<Circuit>::new(mode, address);
+
+            Circuit::scope(format!("{mode} invalid"), || {
+                // Check the signature against a different message.
+                let other_message = vec![Literal::Field(Field::new(mode, UniformRand::rand(rng)))];
+                assert!(!signature.verify(&address, &other_message).eject_value());
+
+                // Check the signature against a different address.
+                let (_, _, other_address) = generate_private_key_and_address();
+                let other_address = Address::<Circuit>::new(mode, other_address);
+                assert!(!signature.verify(&other_address, &message).eject_value());
+
+                // Check the signature against a message of a different length.
+                let mut longer_message = message.clone();
+                longer_message.push(Literal::Boolean(Boolean::new(mode, false)));
+                assert!(!signature.verify(&address, &longer_message).eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
     }
 }
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ViewKey<A> {
+    /// Returns the account view key for this account private key.
+    pub fn from_private_key(private_key: &PrivateKey<A>) -> Self {
+        // Compute the compute key.
+        let compute_key = private_key.to_compute_key();
+
+        // Compute view_key := sk_sig + r_sig + sk_prf.
+        Self(private_key.sk_sig() + private_key.r_sig() + compute_key.sk_prf())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::compute_key::from_private_key::tests::generate_private_and_compute_key, Devnet as Circuit};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_from_private_key(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Generate the private key and compute key components.
+            let (sk_sig, r_sig, _, _, sk_prf) = generate_private_and_compute_key();
+
+            // Initialize the private key.
+            let private_key = PrivateKey::<Circuit>::new(mode, (sk_sig, r_sig));
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = ViewKey::from_private_key(&private_key);
+                assert_eq!(sk_sig + r_sig + sk_prf, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_from_private_key_constant() {
+        check_from_private_key(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_private_key_public() {
+        check_from_private_key(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_private_key_private() {
+        check_from_private_key(Mode::Private);
+    }
+}
@@ -14,11 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod from_private_key;
+pub mod to_address;
+
 #[cfg(test)]
 use snarkvm_circuits_types::environment::assert_scope;
 
-use crate::Aleo;
-use snarkvm_circuits_types::{environment::prelude::*, Scalar};
+use crate::{account::PrivateKey, Aleo};
+use snarkvm_circuits_types::{environment::prelude::*, Address, Scalar};
 
 /// The account view key is able to decrypt records and ciphertext messages.
 pub struct ViewKey<A: Aleo>(Scalar<A>);
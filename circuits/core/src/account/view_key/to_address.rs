@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ViewKey<A> {
+    /// Returns the account address for this account view key, as `G^view_key`.
+    pub fn to_address(&self) -> Address<A> {
+        Address::from(A::g_scalar_multiply(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Devnet as Circuit;
+    use snarkvm_algorithms::SignatureSchemeOperations;
+    use snarkvm_curves::ProjectiveCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_address(mode: Mode) {
+        let native = Circuit::native_signature_scheme();
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a random view key, and compute its address.
+            let view_key: <Circuit as Environment>::ScalarField = UniformRand::rand(rng);
+            let expected = native.g_scalar_multiply(&view_key).to_affine();
+
+            let candidate = ViewKey::<Circuit>::new(mode, view_key);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_address();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_address_constant() {
+        check_to_address(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_address_public() {
+        check_to_address(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_address_private() {
+        check_to_address(Mode::Private);
+    }
+}
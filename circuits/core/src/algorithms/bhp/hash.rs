@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Hash for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the BHP hash of the given input as a field element.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Compute the BHP hash as an affine group element, and return the x-coordinate.
+        self.hash_uncompressed(input).to_x_coordinate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "BHPCircuit0";
+
+    fn check_hash<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(mode: Mode) {
+        // Initialize the BHP hash.
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            // Compute the uncompressed hash, recording its cost.
+            let (expected, num_constants, num_public, num_private, num_constraints) =
+                Circuit::scope(format!("BHP uncompressed {mode} {i}"), || {
+                    let expected = circuit.hash_uncompressed(&circuit_input).to_x_coordinate().eject_value();
+                    let num_constants = Circuit::num_constants_in_scope();
+                    let num_public = Circuit::num_public_in_scope();
+                    let num_private = Circuit::num_private_in_scope();
+                    let num_constraints = Circuit::num_constraints_in_scope();
+                    (expected, num_constants, num_public, num_private, num_constraints)
+                });
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Ensure the hash is the x-coordinate of the uncompressed hash, and the projection is free.
+                let candidate = circuit.hash(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        check_hash::<1, 32>(Mode::Constant);
+        check_hash::<2, 32>(Mode::Constant);
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hash::<1, 32>(Mode::Public);
+        check_hash::<2, 32>(Mode::Public);
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hash::<1, 32>(Mode::Private);
+        check_hash::<2, 32>(Mode::Private);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> HashUncompressed
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Input = Boolean<E>;
+    type Output = Group<E>;
+
+    /// Returns the BHP hash of the given input as an affine group element.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the input size is within the parameter size.
+        if input.len() > NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
+            E::halt(format!("The BHP hash input cannot exceed {} bits.", NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE))
+        }
+
+        // Compute the sum of the selected multiples of the bases.
+        // Note: The input is padded to a multiple of `BHP_CHUNK_SIZE` by the lookup.
+        self.hasher.hash_bits(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{crh::BHPCRH, CRH};
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "BHPCircuit0";
+
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_hash_uncompressed<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        // Initialize the BHP hash.
+        let native = BHPCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_uncompressed(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.to_x_coordinate().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_uncompressed_constant() {
+        check_hash_uncompressed::<1, 32>(Mode::Constant, 4, 0, 0, 0);
+        check_hash_uncompressed::<2, 32>(Mode::Constant, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_hash_uncompressed_public() {
+        check_hash_uncompressed::<1, 32>(Mode::Public, 62, 0, 250, 250);
+        check_hash_uncompressed::<2, 32>(Mode::Public, 126, 0, 506, 506);
+    }
+
+    #[test]
+    fn test_hash_uncompressed_private() {
+        check_hash_uncompressed::<1, 32>(Mode::Private, 62, 0, 250, 250);
+        check_hash_uncompressed::<2, 32>(Mode::Private, 126, 0, 506, 506);
+    }
+
+    #[test]
+    fn test_hash_uncompressed_short_input() {
+        let native = BHPCRH::<Projective, 8, 63>::setup(MESSAGE);
+        let circuit = BHP::<Circuit, 8, 63>::setup(MESSAGE);
+
+        for num_input_bits in [0, 1, 2, 3, 188, 189, 190, 1000] {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Perform the hash operation.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+            let candidate = circuit.hash_uncompressed(&circuit_input);
+            assert_eq!(expected, candidate.to_x_coordinate().eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_uncompressed_input_too_large() {
+        let circuit = BHP::<Circuit, 1, 32>::setup(MESSAGE);
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![false; 32 * BHP_CHUNK_SIZE + 1]);
+        let _candidate = circuit.hash_uncompressed(&input);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+mod hash_uncompressed;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use crate::{Hash, HashUncompressed};
+use snarkvm_algorithms::crypto_hash::hash_to_curve;
+use snarkvm_circuits_types::{group::HashToGroup, prelude::*};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+
+/// The number of input bits consumed by each lookup, as in the native BHP hash.
+const BHP_CHUNK_SIZE: usize = 3;
+
+/// BHP is a collision-resistant hash function that takes a variable-length input.
+/// The input is hashed with constant-base windowed lookups, matching `BHPCRH` in `algorithms`.
+pub struct BHP<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
+    /// The lookup tables for the bases of the BHP hash.
+    hasher: HashToGroup<E>,
+}
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of BHP with the given setup message.
+    pub fn setup(message: &str) -> Self {
+        // Ensure the number of windows and the window size are valid.
+        // Note: The maximum window size ensures the signed multiples of each base do not wrap
+        // around the scalar field, which matches the bound checked in `BHPCRH::setup`.
+        let maximum_window_size = (E::ScalarField::size_in_bits() + 1) / 4;
+        if NUM_WINDOWS == 0 || WINDOW_SIZE == 0 || WINDOW_SIZE > maximum_window_size {
+            E::halt(format!(
+                "Invalid BHP parameters: {NUM_WINDOWS} windows of size {WINDOW_SIZE} (maximum {maximum_window_size})"
+            ))
+        }
+
+        let bases = (0..NUM_WINDOWS)
+            .map(|index| {
+                // Construct an indexed message to attempt to sample a base.
+                let (generator, _, _) = hash_to_curve::<E::Affine>(&format!("{message} at {index}"));
+                // Construct the window with the base, where each base is `2^4` times the previous base.
+                let mut base = generator.to_projective();
+                let mut powers = Vec::with_capacity(WINDOW_SIZE);
+                for _ in 0..WINDOW_SIZE {
+                    powers.push(base.to_affine());
+                    for _ in 0..4 {
+                        base.double_in_place();
+                    }
+                }
+                powers
+            })
+            .collect::<Vec<_>>();
+
+        Self { hasher: HashToGroup::new(&bases) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{crh::BHPCRH, CRH};
+    use snarkvm_circuits_environment::Circuit;
+
+    const MESSAGE: &str = "BHPCircuit0";
+
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_setup<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>() {
+        // Initialize the native BHP hash.
+        let native = BHPCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+
+        Circuit::scope("BHP::setup", || {
+            // Perform the setup operation.
+            let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+            assert_scope!(0, 0, 0, 0);
+            assert_eq!(NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE, circuit.hasher.max_input_size());
+        });
+        assert_eq!(NUM_WINDOWS, native.parameters().len());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_setup() {
+        check_setup::<1, 32>();
+        check_setup::<2, 32>();
+        check_setup::<8, 63>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_setup_window_size_too_large() {
+        let _circuit = BHP::<Circuit, 1, 64>::setup(MESSAGE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_setup_zero_windows() {
+        let _circuit = BHP::<Circuit, 0, 32>::setup(MESSAGE);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod point;
+pub use point::*;
+
+mod verify;
+
+use snarkvm_circuits_types::{
+    emulated::{EmulatedField, EmulatedParameters},
+    environment::prelude::*,
+    Boolean,
+    U8,
+};
+
+use num_bigint::BigUint;
+
+pub use snarkvm_circuits_types::emulated::{Secp256k1BaseParameters, Secp256k1ScalarParameters};
+
+/// An element of the secp256k1 base field, emulated over the base field of the environment.
+pub type Secp256k1Base<E> = EmulatedField<E, Secp256k1BaseParameters>;
+
+/// An element of the secp256k1 scalar field, emulated over the base field of the environment.
+pub type Secp256k1Scalar<E> = EmulatedField<E, Secp256k1ScalarParameters>;
+
+/// An ECDSA signature over secp256k1, as in Bitcoin and Ethereum.
+pub struct ECDSASignature<E: Environment> {
+    /// The x-coordinate of the signature nonce point, reduced by the group order.
+    r: Secp256k1Scalar<E>,
+    /// The signature proof.
+    s: Secp256k1Scalar<E>,
+}
+
+impl<E: Environment> ECDSASignature<E> {
+    /// Initializes a signature from the given `(r, s)`.
+    pub fn from(r: Secp256k1Scalar<E>, s: Secp256k1Scalar<E>) -> Self {
+        Self { r, s }
+    }
+}
+
+impl<E: Environment> Inject for ECDSASignature<E> {
+    type Primitive = (BigUint, BigUint);
+
+    /// Initializes a signature from the given mode and `(r, s)`.
+    fn new(mode: Mode, (r, s): Self::Primitive) -> Self {
+        Self::from(Secp256k1Scalar::new(mode, r), Secp256k1Scalar::new(mode, s))
+    }
+}
+
+impl<E: Environment> Eject for ECDSASignature<E> {
+    type Primitive = (BigUint, BigUint);
+
+    ///
+    /// Ejects the mode of the signature.
+    ///
+    fn eject_mode(&self) -> Mode {
+        (&self.r, &self.s).eject_mode()
+    }
+
+    ///
+    /// Ejects the signature as `(r, s)`.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        (&self.r, &self.s).eject_value()
+    }
+}
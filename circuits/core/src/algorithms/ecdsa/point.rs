@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The x-coordinate of the secp256k1 generator.
+const GENERATOR_X: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// The y-coordinate of the secp256k1 generator.
+const GENERATOR_Y: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// The coefficient `b` of the secp256k1 curve equation `y^2 = x^3 + b`.
+const COEFFICIENT_B: u64 = 7;
+
+/// A point on secp256k1 in projective coordinates `(X : Y : Z)`, representing the affine point `(X/Z, Y/Z)`.
+/// The identity is represented with `Z = 0`.
+///
+/// Points are added with the complete formulas of Renes, Costello, and Batina (2016) for `a = 0`,
+/// which are valid for all inputs, including the identity and doublings.
+pub struct Secp256k1Point<E: Environment> {
+    x: Secp256k1Base<E>,
+    y: Secp256k1Base<E>,
+    z: Secp256k1Base<E>,
+}
+
+impl<E: Environment> Secp256k1Point<E> {
+    /// Returns the point at infinity.
+    pub fn identity() -> Self {
+        Self { x: Secp256k1Base::zero(), y: Secp256k1Base::one(), z: Secp256k1Base::zero() }
+    }
+
+    /// Returns the generator of the secp256k1 group.
+    pub fn generator() -> Self {
+        let parse = |hex: &str| match BigUint::parse_bytes(hex.as_bytes(), 16) {
+            Some(value) => value,
+            None => E::halt("Failed to parse the secp256k1 generator"),
+        };
+        Self::constant((parse(GENERATOR_X), parse(GENERATOR_Y)))
+    }
+
+    /// Initializes a point from the given affine coordinates `(x, y)`, ensuring it is on the curve.
+    pub fn from_xy_coordinates(x: Secp256k1Base<E>, y: Secp256k1Base<E>) -> Self {
+        // Ensure `y^2 = x^3 + b`.
+        let b = Secp256k1Base::constant(BigUint::from(COEFFICIENT_B));
+        let is_on_curve = y.square().is_equal(&(&(&x.square() * &x) + &b));
+        match is_on_curve.is_constant() {
+            true if !is_on_curve.eject_value() => E::halt("Attempted to initialize a point that is not on secp256k1"),
+            true => (),
+            false => E::assert(is_on_curve),
+        }
+
+        Self { x, y, z: Secp256k1Base::one() }
+    }
+
+    /// Returns `true` if the point is the identity.
+    pub fn is_identity(&self) -> Boolean<E> {
+        self.z.is_zero()
+    }
+
+    /// Returns the affine x-coordinate of the point, or zero if the point is the identity.
+    pub fn to_affine_x(&self) -> Secp256k1Base<E> {
+        // Note: The denominator is substituted with one for the identity, to ensure it is invertible.
+        let z = Secp256k1Base::ternary(&self.is_identity(), &Secp256k1Base::one(), &self.z);
+        &self.x / &z
+    }
+
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        let b3 = Secp256k1Base::constant(BigUint::from(3 * COEFFICIENT_B));
+
+        let t0 = self.y.square();
+        let z3 = &t0 + &t0;
+        let z3 = &z3 + &z3;
+        let z3 = &z3 + &z3;
+        let t1 = &self.y * &self.z;
+        let t2 = self.z.square();
+        let t2 = &b3 * &t2;
+        let x3 = &t2 * &z3;
+        let y3 = &t0 + &t2;
+        let z3 = &t1 * &z3;
+        let t1 = &t2 + &t2;
+        let t2 = &t1 + &t2;
+        let t0 = &t0 - &t2;
+        let y3 = &t0 * &y3;
+        let y3 = &x3 + &y3;
+        let t1 = &self.x * &self.y;
+        let x3 = &t0 * &t1;
+        let x3 = &x3 + &x3;
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    ///
+    /// Returns `a * self + b * other`, where the scalars are given as big-endian bits.
+    ///
+    /// The scalars are processed jointly (Shamir's trick), with one doubling and one addition per bit,
+    /// where each addition selects from the precomputed table `[O, self, other, self + other]`.
+    ///
+    pub fn double_scalar_multiply(&self, a_bits_be: &[Boolean<E>], other: &Self, b_bits_be: &[Boolean<E>]) -> Self {
+        let sum = self + other;
+
+        let mut output = Self::identity();
+        for (a_bit, b_bit) in a_bits_be.iter().zip_eq(b_bits_be) {
+            // Select the table entry indexed by `a_bit + 2 * b_bit`.
+            let with_other = Self::ternary(a_bit, &sum, other);
+            let without_other = Self::ternary(a_bit, self, &Self::identity());
+            let addend = Self::ternary(b_bit, &with_other, &without_other);
+
+            output = &output.double() + &addend;
+        }
+        output
+    }
+}
+
+impl<E: Environment> Add<Self> for Secp256k1Point<E> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<E: Environment> Add<&Secp256k1Point<E>> for &Secp256k1Point<E> {
+    type Output = Secp256k1Point<E>;
+
+    /// Returns `self + other`.
+    fn add(self, other: &Secp256k1Point<E>) -> Self::Output {
+        let b3 = Secp256k1Base::constant(BigUint::from(3 * COEFFICIENT_B));
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = &(x1 + y1) * &(x2 + y2);
+        let t4 = &t0 + &t1;
+        let t3 = &t3 - &t4;
+        let t4 = &(y1 + z1) * &(y2 + z2);
+        let x3 = &t1 + &t2;
+        let t4 = &t4 - &x3;
+        let x3 = &(x1 + z1) * &(x2 + z2);
+        let y3 = &t0 + &t2;
+        let y3 = &x3 - &y3;
+        let x3 = &t0 + &t0;
+        let t0 = &x3 + &t0;
+        let t2 = &b3 * &t2;
+        let z3 = &t1 + &t2;
+        let t1 = &t1 - &t2;
+        let y3 = &b3 * &y3;
+        let x3 = &t4 * &y3;
+        let t2 = &t3 * &t1;
+        let x3 = &t2 - &x3;
+        let y3 = &y3 * &t0;
+        let t1 = &t1 * &z3;
+        let y3 = &t1 + &y3;
+        let t0 = &t0 * &t3;
+        let z3 = &z3 * &t4;
+        let z3 = &z3 + &t0;
+
+        Secp256k1Point { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl<E: Environment> Ternary for Secp256k1Point<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self {
+            x: Secp256k1Base::ternary(condition, &first.x, &second.x),
+            y: Secp256k1Base::ternary(condition, &first.y, &second.y),
+            z: Secp256k1Base::ternary(condition, &first.z, &second.z),
+        }
+    }
+}
+
+impl<E: Environment> Inject for Secp256k1Point<E> {
+    type Primitive = (BigUint, BigUint);
+
+    /// Initializes a point from the given mode and affine coordinates `(x, y)`, ensuring it is on the curve.
+    fn new(mode: Mode, (x, y): Self::Primitive) -> Self {
+        Self::from_xy_coordinates(Secp256k1Base::new(mode, x), Secp256k1Base::new(mode, y))
+    }
+}
+
+impl<E: Environment> Eject for Secp256k1Point<E> {
+    type Primitive = (BigUint, BigUint);
+
+    ///
+    /// Ejects the mode of the point.
+    ///
+    fn eject_mode(&self) -> Mode {
+        (&self.x, &self.y, &self.z).eject_mode()
+    }
+
+    ///
+    /// Ejects the point as affine coordinates `(x, y)`, where the identity is ejected as `(0, 0)`.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        let modulus = Secp256k1BaseParameters::modulus();
+        let (x, y, z) = (&self.x, &self.y, &self.z).eject_value();
+        match z == BigUint::from(0u64) {
+            true => (BigUint::from(0u64), BigUint::from(0u64)),
+            false => {
+                let z_inv = z.modpow(&(&modulus - 2u64), &modulus);
+                (x * &z_inv % &modulus, y * &z_inv % &modulus)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+
+    /// The affine coordinates of `2 * G`.
+    const DOUBLE_GENERATOR: (&str, &str) = (
+        "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+    );
+
+    fn parse(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    #[test]
+    fn test_generator() {
+        let generator = Secp256k1Point::<Circuit>::generator();
+        assert_eq!((parse(GENERATOR_X), parse(GENERATOR_Y)), generator.eject_value());
+        assert!(!generator.is_identity().eject_value());
+        assert!(Secp256k1Point::<Circuit>::identity().is_identity().eject_value());
+    }
+
+    #[test]
+    fn test_double() {
+        let expected = (parse(DOUBLE_GENERATOR.0), parse(DOUBLE_GENERATOR.1));
+
+        for mode in [Mode::Constant, Mode::Private] {
+            let generator = Secp256k1Point::<Circuit>::new(mode, (parse(GENERATOR_X), parse(GENERATOR_Y)));
+
+            Circuit::scope(format!("Double {mode}"), || {
+                assert_eq!(expected, generator.double().eject_value());
+                assert_eq!(expected, (&generator + &generator).eject_value());
+                assert_eq!(expected.0, generator.double().to_affine_x().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_add_identity() {
+        for mode in [Mode::Constant, Mode::Private] {
+            let generator = Secp256k1Point::<Circuit>::new(mode, (parse(GENERATOR_X), parse(GENERATOR_Y)));
+            let identity = Secp256k1Point::identity();
+
+            Circuit::scope(format!("Identity {mode}"), || {
+                assert_eq!(generator.eject_value(), (&generator + &identity).eject_value());
+                assert_eq!(generator.eject_value(), (&identity + &generator).eject_value());
+                assert!((&identity + &identity).is_identity().eject_value());
+                assert!(identity.double().is_identity().eject_value());
+                assert_eq!(BigUint::from(0u64), identity.to_affine_x().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_add_inverse() {
+        let modulus = Secp256k1BaseParameters::modulus();
+        let generator = Secp256k1Point::<Circuit>::new(Mode::Private, (parse(GENERATOR_X), parse(GENERATOR_Y)));
+        let negation =
+            Secp256k1Point::<Circuit>::new(Mode::Private, (parse(GENERATOR_X), modulus - parse(GENERATOR_Y)));
+        assert!((&generator + &negation).is_identity().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_double_scalar_multiply() {
+        // Compute `3 * G + 1 * (2 * G) = 5 * G`, and compare against `2 * (2 * G) + G`.
+        let generator = Secp256k1Point::<Circuit>::generator();
+        let double = generator.double();
+        let expected = (&double.double() + &generator).eject_value();
+
+        let bits = |value: u8| (0..8).rev().map(|i| Boolean::constant((value >> i) & 1 == 1)).collect::<Vec<_>>();
+        let candidate = generator.double_scalar_multiply(&bits(3), &double, &bits(1));
+        assert_eq!(expected, candidate.eject_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_not_on_curve() {
+        let _point = Secp256k1Point::<Circuit>::new(Mode::Constant, (parse(GENERATOR_X), parse(GENERATOR_X)));
+    }
+
+    #[test]
+    fn test_new_not_on_curve_private() {
+        let _point = Secp256k1Point::<Circuit>::new(Mode::Private, (parse(GENERATOR_X), parse(GENERATOR_X)));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
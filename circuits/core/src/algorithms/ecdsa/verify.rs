@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bytes of the message hash used by ECDSA over secp256k1.
+const HASH_SIZE_IN_BYTES: usize = 32;
+
+impl<E: Environment> ECDSASignature<E> {
+    ///
+    /// Returns `true` if the signature is valid for the given `public_key` and `message_hash`,
+    /// where the message hash is given as big-endian bytes, e.g. a SHA-256 or Keccak-256 digest.
+    ///
+    /// As in the standard, only the leftmost 256 bits of the message hash are used.
+    ///
+    pub fn verify(&self, public_key: &Secp256k1Point<E>, message_hash: &[U8<E>]) -> Boolean<E> {
+        // Compute `z` as the leftmost 256 bits of the message hash, reduced by the group order.
+        let message_hash = &message_hash[..message_hash.len().min(HASH_SIZE_IN_BYTES)];
+        let z_bits_le = message_hash.iter().rev().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let z = Secp256k1Scalar::from_bits_le_reduced(&z_bits_le);
+
+        // Ensure `r` and `s` are nonzero.
+        let is_r_zero = self.r.is_zero();
+        let is_s_zero = self.s.is_zero();
+
+        // Compute `w := s^(-1)`.
+        // Note: `s` is substituted with one if it is zero, to ensure it is invertible.
+        let w = Secp256k1Scalar::ternary(&is_s_zero, &Secp256k1Scalar::one(), &self.s).inv();
+
+        // Compute `u1 := z * w` and `u2 := r * w`.
+        let u1 = &z * &w;
+        let u2 = &self.r * &w;
+
+        // Compute `R := u1 * G + u2 * public_key`.
+        let candidate =
+            Secp256k1Point::generator().double_scalar_multiply(&u1.to_bits_be(), public_key, &u2.to_bits_be());
+
+        // Ensure `R` is not the identity, and its x-coordinate, reduced by the group order, equals `r`.
+        let is_identity = candidate.is_identity();
+        let is_r_valid = Secp256k1Scalar::from_emulated(&candidate.to_affine_x()).is_equal(&self.r);
+
+        is_r_valid & !is_r_zero & !is_s_zero & !is_identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+
+    /// The public key of the private key `c9afa9d8...0f6721` from RFC 6979, Appendix A.2.5.
+    const PUBLIC_KEY: (&str, &str) = (
+        "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+        "64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+    );
+
+    /// The SHA-256 digest of the message "sample".
+    const MESSAGE_HASH: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf";
+
+    /// The signature `(r, s)` of the message "sample".
+    const SIGNATURE: (&str, &str) = (
+        "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8",
+        "530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+    );
+
+    fn parse(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    fn check_verify(
+        mode: Mode,
+        public_key: (BigUint, BigUint),
+        message_hash: &[u8],
+        signature: (BigUint, BigUint),
+    ) -> bool {
+        let public_key = Secp256k1Point::<Circuit>::new(mode, public_key);
+        let message_hash = message_hash.iter().map(|byte| U8::new(mode, *byte)).collect::<Vec<_>>();
+        let signature = ECDSASignature::<Circuit>::new(mode, signature);
+
+        let candidate = Circuit::scope(format!("ECDSA {mode}"), || signature.verify(&public_key, &message_hash));
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        candidate.eject_value()
+    }
+
+    fn public_key() -> (BigUint, BigUint) {
+        (parse(PUBLIC_KEY.0), parse(PUBLIC_KEY.1))
+    }
+
+    fn message_hash() -> Vec<u8> {
+        parse(MESSAGE_HASH).to_bytes_be()
+    }
+
+    fn signature() -> (BigUint, BigUint) {
+        (parse(SIGNATURE.0), parse(SIGNATURE.1))
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        assert!(check_verify(Mode::Constant, public_key(), &message_hash(), signature()));
+    }
+
+    #[test]
+    fn test_verify_negated_s() {
+        // ECDSA signatures are malleable, as `(r, n - s)` is also a valid signature.
+        let (r, s) = signature();
+        let signature = (r, Secp256k1ScalarParameters::modulus() - s);
+        assert!(check_verify(Mode::Constant, public_key(), &message_hash(), signature));
+    }
+
+    #[test]
+    fn test_verify_invalid() {
+        // Check the signature against a different message hash.
+        let mut other_message_hash = message_hash();
+        other_message_hash[0] ^= 1;
+        assert!(!check_verify(Mode::Constant, public_key(), &other_message_hash, signature()));
+
+        // Check the signature against a different public key.
+        let generator = Secp256k1Point::<Circuit>::generator().eject_value();
+        assert!(!check_verify(Mode::Constant, generator, &message_hash(), signature()));
+
+        // Check a signature with a different `r`.
+        let (r, s) = signature();
+        assert!(!check_verify(Mode::Constant, public_key(), &message_hash(), (r + 1u64, s)));
+
+        // Check a signature with a different `s`.
+        let (r, s) = signature();
+        assert!(!check_verify(Mode::Constant, public_key(), &message_hash(), (r, s + 1u64)));
+
+        // Check the signatures with a zero `r` or `s`.
+        let (r, s) = signature();
+        assert!(!check_verify(Mode::Constant, public_key(), &message_hash(), (BigUint::from(0u64), s)));
+        assert!(!check_verify(Mode::Constant, public_key(), &message_hash(), (r, BigUint::from(0u64))));
+    }
+
+    #[test]
+    fn test_verify_truncates_message_hash() {
+        // Only the leftmost 256 bits of the message hash are used.
+        let mut longer_message_hash = message_hash();
+        longer_message_hash.extend_from_slice(&[0xff; 32]);
+        assert!(check_verify(Mode::Constant, public_key(), &longer_message_hash, signature()));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_verify_private() {
+        // Note: This test synthesizes the full verification circuit, and is expensive to run.
+        assert!(check_verify(Mode::Private, public_key(), &message_hash(), signature()));
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Keccak256<E> {
+    type Input = U8<E>;
+    type Output = Vec<U8<E>>;
+
+    /// Returns the Keccak-256 digest of the given input bytes, as 32 bytes.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Pad the input with `0x01`, followed by zeros, and a final `0x80`, to a multiple of the rate.
+        // Note: As the input length is a constant, the padding is a constant.
+        let num_padding_bytes = RATE_IN_BYTES - input.len() % RATE_IN_BYTES;
+        let mut padding = vec![0u8; num_padding_bytes];
+        padding[0] |= 0x01;
+        padding[num_padding_bytes - 1] |= 0x80;
+
+        let mut message = input.to_vec();
+        message.extend(padding.into_iter().map(U8::constant));
+        debug_assert_eq!(message.len() % RATE_IN_BYTES, 0);
+
+        // Absorb each block into the state.
+        let state = message.chunks(RATE_IN_BYTES).fold(vec![U64::zero(); NUM_LANES], |mut state, block| {
+            // Construct the lanes of the block from little-endian bytes, and XOR them into the state.
+            for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+                *lane ^= U64::from_bits_le(&bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+            }
+            self.permute(&state)
+        });
+
+        // Squeeze the digest from the first lanes, as little-endian bytes.
+        state
+            .iter()
+            .flat_map(|lane| lane.to_bits_le().chunks(8).map(U8::from_bits_le).collect::<Vec<_>>())
+            .take(DIGEST_SIZE_IN_BYTES)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+
+    /// Returns the Keccak-256 digest of the given input in the given mode, as a hex string.
+    fn keccak256(mode: Mode, input: &[u8]) -> String {
+        let input = input.iter().map(|byte| U8::<Circuit>::new(mode, *byte)).collect::<Vec<_>>();
+        let candidate = Keccak256::<Circuit>::new().hash(&input);
+        candidate.eject_value().iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn check_hash(mode: Mode, input: &[u8], expected: &str) {
+        Circuit::scope(format!("Keccak-256 {mode} {}", input.len()), || {
+            assert_eq!(expected, keccak256(mode, input));
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_empty() {
+        let expected = "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        check_hash(Mode::Constant, b"", expected);
+        check_hash(Mode::Public, b"", expected);
+        check_hash(Mode::Private, b"", expected);
+    }
+
+    #[test]
+    fn test_hash_abc() {
+        let expected = "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+        check_hash(Mode::Constant, b"abc", expected);
+        check_hash(Mode::Public, b"abc", expected);
+        check_hash(Mode::Private, b"abc", expected);
+    }
+
+    #[test]
+    fn test_hash_multiple_blocks() {
+        // Note: An input of exactly one rate requires a second block for the padding.
+        let expected = "a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e";
+        check_hash(Mode::Private, &[b'a'; RATE_IN_BYTES], expected);
+    }
+
+    #[test]
+    fn test_hash_padding_boundary() {
+        // Note: An input one byte short of the rate is padded with the single byte `0x81`.
+        let expected = "03c527855334eb2e62b3b9b4d02ab76721707d3dde5fb218369640ee2edc7f3a";
+        check_hash(Mode::Private, &[0x5a; RATE_IN_BYTES - 1], expected);
+
+        let expected = "37a14cb79c82d4b7d837a3f8ea134a324b138a5e4e3bb0814b75e52b17975b9c";
+        check_hash(Mode::Private, &[0x5a; RATE_IN_BYTES + 1], expected);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuits_types::{prelude::*, U64, U8};
+
+/// The Keccak-f[1600] round constants.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000, 0x000000000000808B,
+    0x0000000080000001, 0x8000000080008081, 0x8000000000008009, 0x000000000000008A, 0x0000000000000088,
+    0x0000000080008009, 0x000000008000000A, 0x000000008000808B, 0x800000000000008B, 0x8000000000008089,
+    0x8000000000008003, 0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// The Keccak-f[1600] rotation offsets, for the lane at `(x, y)` at index `x + 5 * y`.
+const ROTATION_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The number of lanes in the Keccak-f[1600] state.
+const NUM_LANES: usize = 25;
+
+/// The number of bytes absorbed into the state per permutation, for Keccak-256.
+const RATE_IN_BYTES: usize = 136;
+
+/// The number of bytes in a Keccak-256 digest.
+const DIGEST_SIZE_IN_BYTES: usize = 32;
+
+/// Keccak-256 is the hash function with a 1088-bit rate over Keccak-f[1600], as used by Ethereum.
+/// Note: This is the original Keccak submission, which differs from SHA3-256 in its padding.
+pub struct Keccak256<E: Environment> {
+    /// The round constants.
+    round_constants: Vec<U64<E>>,
+}
+
+impl<E: Environment> Keccak256<E> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { round_constants: ROUND_CONSTANTS.iter().map(|constant| U64::constant(*constant)).collect() }
+    }
+
+    ///
+    /// Returns the updated state, after applying the Keccak-f[1600] permutation
+    /// to the given state of 25 lanes, where the lane at `(x, y)` is at index `x + 5 * y`.
+    ///
+    pub fn permute(&self, state: &[U64<E>]) -> Vec<U64<E>> {
+        // Ensure the state is well-formed.
+        if state.len() != NUM_LANES {
+            E::halt("Keccak-f[1600] expects a state of 25 lanes")
+        }
+
+        let mut a = state.to_vec();
+        for round_constant in &self.round_constants {
+            // Theta: XOR each lane with the parities of two neighbouring columns.
+            let c = (0..5).map(|x| (1..5).fold(a[x].clone(), |c, y| c ^ &a[x + 5 * y])).collect::<Vec<_>>();
+            let d = (0..5).map(|x| &c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1)).collect::<Vec<_>>();
+            for (index, lane) in a.iter_mut().enumerate() {
+                *lane ^= &d[index % 5];
+            }
+
+            // Rho and pi: Rotate each lane, and move the lane at `(x, y)` to `(y, 2x + 3y)`.
+            let mut b = a.clone();
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+
+            // Chi: XOR each lane with the AND of the negated next lane and the lane after it, in its row.
+            for x in 0..5 {
+                for y in 0..5 {
+                    a[x + 5 * y] = &b[x + 5 * y] ^ (!&b[(x + 1) % 5 + 5 * y] & &b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant.
+            a[0] ^= round_constant;
+        }
+        a
+    }
+}
@@ -14,8 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod bhp;
+pub use bhp::*;
+
+pub mod ecdsa;
+pub use ecdsa::*;
+
+pub mod keccak;
+pub use keccak::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
+
+pub mod sha256;
+pub use sha256::*;
+
+pub mod vrf;
+pub use vrf::*;
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Commit
+    for Pedersen<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Input = Boolean<E>;
+    type Output = Group<E>;
+    type Randomness = Scalar<E>;
+
+    /// Returns the Pedersen commitment of the given input and randomness as an affine group element.
+    fn commit(&self, input: &[Self::Input], randomness: &Self::Randomness) -> Self::Output {
+        // Compute h^r, where h is the (constant) random base.
+        let randomizer = self.random_base.mul_by_constant_base(&randomness.to_bits_le());
+
+        // Compute the Pedersen hash of the input, and add h^r.
+        self.hash_uncompressed(input) + randomizer
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Pedersen<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns `true` if the given input and randomness are an opening of the given commitment.
+    pub fn verify_opening(&self, commitment: &Group<E>, input: &[Boolean<E>], randomness: &Scalar<E>) -> Boolean<E> {
+        commitment.is_equal(&self.commit(input, randomness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{commitment::PedersenCommitment, CommitmentScheme};
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const WINDOW_SIZE_MULTIPLIER: usize = 8;
+
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_commit<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        // Initialize the Pedersen commitment.
+        let native = PedersenCommitment::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Sample a randomizer.
+            let randomness = UniformRand::rand(&mut test_rng());
+            // Compute the expected commitment.
+            let expected = native.commit(&input, &randomness).expect("Failed to commit native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            // Prepare the circuit randomness.
+            let circuit_randomness = Scalar::new(mode, randomness);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the commit operation.
+                let candidate = circuit.commit(&circuit_input, &circuit_randomness);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+        }
+    }
+
+    fn check_verify_opening<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(mode: Mode) {
+        // Initialize the Pedersen commitment.
+        let circuit = Pedersen::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE;
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            let randomness = UniformRand::rand(&mut test_rng());
+
+            // Prepare the circuit input and randomness, and compute the commitment.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let circuit_randomness = Scalar::new(mode, randomness);
+            let commitment = circuit.commit(&circuit_input, &circuit_randomness);
+
+            // Ensure the opening is valid.
+            assert!(circuit.verify_opening(&commitment, &circuit_input, &circuit_randomness).eject_value());
+
+            // Ensure an opening with a different input is invalid.
+            let mut other_input = input;
+            other_input[0] = !other_input[0];
+            let other_input: Vec<Boolean<_>> = Inject::new(mode, other_input);
+            assert!(!circuit.verify_opening(&commitment, &other_input, &circuit_randomness).eject_value());
+
+            // Ensure an opening with a different randomness is invalid.
+            let other_randomness = Scalar::new(mode, UniformRand::rand(&mut test_rng()));
+            assert!(!circuit.verify_opening(&commitment, &circuit_input, &other_randomness).eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_commit_constant() {
+        // Set the number of windows, and modulate the window size.
+        check_commit::<1, WINDOW_SIZE_MULTIPLIER>(Mode::Constant, 40, 0, 0, 0);
+        check_commit::<1, { 2 * WINDOW_SIZE_MULTIPLIER }>(Mode::Constant, 72, 0, 0, 0);
+
+        // Set the window size, and modulate the number of windows.
+        check_commit::<2, WINDOW_SIZE_MULTIPLIER>(Mode::Constant, 72, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_commit_public() {
+        // Set the number of windows, and modulate the window size.
+        check_commit::<1, WINDOW_SIZE_MULTIPLIER>(Mode::Public, 184, 0, 799, 799);
+        check_commit::<1, { 2 * WINDOW_SIZE_MULTIPLIER }>(Mode::Public, 200, 0, 847, 847);
+
+        // Set the window size, and modulate the number of windows.
+        check_commit::<2, WINDOW_SIZE_MULTIPLIER>(Mode::Public, 200, 0, 847, 847);
+    }
+
+    #[test]
+    fn test_commit_private() {
+        // Set the number of windows, and modulate the window size.
+        check_commit::<1, WINDOW_SIZE_MULTIPLIER>(Mode::Private, 184, 0, 799, 799);
+        check_commit::<1, { 2 * WINDOW_SIZE_MULTIPLIER }>(Mode::Private, 200, 0, 847, 847);
+
+        // Set the window size, and modulate the number of windows.
+        check_commit::<2, WINDOW_SIZE_MULTIPLIER>(Mode::Private, 200, 0, 847, 847);
+    }
+
+    #[test]
+    fn test_verify_opening() {
+        check_verify_opening::<1, WINDOW_SIZE_MULTIPLIER>(Mode::Constant);
+        check_verify_opening::<1, WINDOW_SIZE_MULTIPLIER>(Mode::Public);
+        check_verify_opening::<2, WINDOW_SIZE_MULTIPLIER>(Mode::Private);
+    }
+}
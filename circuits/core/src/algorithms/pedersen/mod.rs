@@ -14,13 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod commit;
 mod hash;
 mod hash_uncompressed;
 
 #[cfg(test)]
 use snarkvm_circuits_environment::assert_scope;
 
-use crate::{Hash, HashUncompressed};
+use crate::{Commit, Hash, HashUncompressed};
 use snarkvm_algorithms::crypto_hash::hash_to_curve;
 use snarkvm_circuits_types::prelude::*;
 
@@ -40,6 +41,8 @@ pub type Pedersen1024<E> = Pedersen<E, 8, 128>;
 pub struct Pedersen<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
     /// The base windows for the Pedersen hash.
     bases: Vec<Vec<Group<E>>>,
+    /// The random base for the Pedersen commitment.
+    random_base: Group<E>,
 }
 
 impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Pedersen<E, NUM_WINDOWS, WINDOW_SIZE> {
@@ -61,6 +64,12 @@ impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Pederse
                     powers
                 })
                 .collect(),
+            random_base: {
+                // Construct a message to attempt to sample the random base.
+                let (generator, _, _) = hash_to_curve(&format!("{message} for random base"));
+                // Inject the random base.
+                Group::constant(generator)
+            },
         }
     }
 }
@@ -106,17 +115,17 @@ mod tests {
     #[test]
     fn test_setup_constant() {
         // Set the number of windows, and modulate the window size.
-        check_setup::<1, WINDOW_SIZE_MULTIPLIER>(28, 0, 0, 0);
-        check_setup::<1, { 2 * WINDOW_SIZE_MULTIPLIER }>(52, 0, 0, 0);
-        check_setup::<1, { 3 * WINDOW_SIZE_MULTIPLIER }>(76, 0, 0, 0);
-        check_setup::<1, { 4 * WINDOW_SIZE_MULTIPLIER }>(100, 0, 0, 0);
-        check_setup::<1, { 5 * WINDOW_SIZE_MULTIPLIER }>(124, 0, 0, 0);
+        check_setup::<1, WINDOW_SIZE_MULTIPLIER>(32, 0, 0, 0);
+        check_setup::<1, { 2 * WINDOW_SIZE_MULTIPLIER }>(56, 0, 0, 0);
+        check_setup::<1, { 3 * WINDOW_SIZE_MULTIPLIER }>(80, 0, 0, 0);
+        check_setup::<1, { 4 * WINDOW_SIZE_MULTIPLIER }>(104, 0, 0, 0);
+        check_setup::<1, { 5 * WINDOW_SIZE_MULTIPLIER }>(128, 0, 0, 0);
 
         // Set the window size, and modulate the number of windows.
-        check_setup::<1, WINDOW_SIZE_MULTIPLIER>(28, 0, 0, 0);
-        check_setup::<2, WINDOW_SIZE_MULTIPLIER>(56, 0, 0, 0);
-        check_setup::<3, WINDOW_SIZE_MULTIPLIER>(84, 0, 0, 0);
-        check_setup::<4, WINDOW_SIZE_MULTIPLIER>(112, 0, 0, 0);
-        check_setup::<5, WINDOW_SIZE_MULTIPLIER>(140, 0, 0, 0);
+        check_setup::<1, WINDOW_SIZE_MULTIPLIER>(32, 0, 0, 0);
+        check_setup::<2, WINDOW_SIZE_MULTIPLIER>(60, 0, 0, 0);
+        check_setup::<3, WINDOW_SIZE_MULTIPLIER>(88, 0, 0, 0);
+        check_setup::<4, WINDOW_SIZE_MULTIPLIER>(116, 0, 0, 0);
+        check_setup::<5, WINDOW_SIZE_MULTIPLIER>(144, 0, 0, 0);
     }
 }
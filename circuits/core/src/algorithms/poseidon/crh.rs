@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// PoseidonCRH is a collision-resistant hash function on bits, matching `PoseidonCRH` in `algorithms`.
+/// The input bits are zero-padded to `INPUT_SIZE_FE` field elements, and packed into field elements.
+pub struct PoseidonCRH<E: Environment, const INPUT_SIZE_FE: usize> {
+    /// The Poseidon hash function.
+    poseidon: Poseidon<E>,
+}
+
+impl<E: Environment, const INPUT_SIZE_FE: usize> PoseidonCRH<E, INPUT_SIZE_FE> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { poseidon: Poseidon::new() }
+    }
+}
+
+impl<E: Environment, const INPUT_SIZE_FE: usize> Hash for PoseidonCRH<E, INPUT_SIZE_FE> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the Poseidon hash of the given input bits, packed into field elements.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the input is within the supported input size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let max_input_size = INPUT_SIZE_FE * size_in_data_bits;
+        if input.len() > max_input_size {
+            E::halt(format!("PoseidonCRH input of {} bits exceeds {max_input_size} bits", input.len()))
+        }
+
+        // Pad the input with zeros, and pack the bits into field elements.
+        let mut input = input.to_vec();
+        input.resize(max_input_size, Boolean::constant(false));
+        let elements = input.chunks(size_in_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+
+        self.poseidon.hash(&elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{crh::PoseidonCRH as NativePoseidonCRH, CRH};
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const INPUT_SIZE_FE: usize = 4;
+
+    type NativeCRH = NativePoseidonCRH<<Circuit as Environment>::BaseField, INPUT_SIZE_FE>;
+
+    fn check_hash(mode: Mode, num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let native = NativeCRH::setup("PoseidonCRHCircuit0");
+        let circuit = PoseidonCRH::<Circuit, INPUT_SIZE_FE>::new();
+
+        // Determine the number of inputs.
+        let num_input_bits = INPUT_SIZE_FE * <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("PoseidonCRH {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        check_hash(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hash(Mode::Public, 0, 0, 350, 350);
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hash(Mode::Private, 0, 0, 350, 350);
+    }
+
+    #[test]
+    fn test_hash_short_input() {
+        let native = NativeCRH::setup("PoseidonCRHCircuit0");
+        let circuit = PoseidonCRH::<Circuit, INPUT_SIZE_FE>::new();
+
+        for num_input_bits in [0, 1, 251, 252, 253, 512, 1000] {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Perform the hash operation.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+            let candidate = circuit.hash(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_input_too_large() {
+        let num_input_bits = INPUT_SIZE_FE * <Circuit as Environment>::BaseField::size_in_data_bits() + 1;
+        let input: Vec<Boolean<Circuit>> = Inject::new(Mode::Private, vec![false; num_input_bits]);
+        let _candidate = PoseidonCRH::<Circuit, INPUT_SIZE_FE>::new().hash(&input);
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    ///
+    /// Returns the ciphertext and tag of the given message, for the given domain, key, and nonce,
+    /// as in the native `PoseidonDuplexEncryption`.
+    ///
+    /// Note: The message length is absorbed into the sponge, and *must* be constant.
+    ///
+    pub fn encrypt(
+        &self,
+        domain: &Field<E>,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        message: &[Field<E>],
+    ) -> (Vec<Field<E>>, Field<E>) {
+        let (mut state, mut mode) = self.initialize_duplex(domain, key, nonce, message.len());
+
+        let ciphertext = message
+            .iter()
+            .map(|plaintext| {
+                // Compute the ciphertext element, and absorb it into the sponge.
+                let ciphertext = plaintext + &self.squeeze(&mut state, &mut mode, 1)[0];
+                self.absorb(&mut state, &mut mode, &[ciphertext.clone()]);
+                ciphertext
+            })
+            .collect();
+
+        let tag = self.squeeze(&mut state, &mut mode, 1)[0].clone();
+        (ciphertext, tag)
+    }
+
+    ///
+    /// Returns the message of the given ciphertext, and `true` if the tag is valid
+    /// for the given domain, key, and nonce, as in the native `PoseidonDuplexEncryption`.
+    ///
+    /// Note: The ciphertext length is absorbed into the sponge, and *must* be constant.
+    ///
+    pub fn decrypt(
+        &self,
+        domain: &Field<E>,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        ciphertext: &[Field<E>],
+        tag: &Field<E>,
+    ) -> (Vec<Field<E>>, Boolean<E>) {
+        let (mut state, mut mode) = self.initialize_duplex(domain, key, nonce, ciphertext.len());
+
+        let message = ciphertext
+            .iter()
+            .map(|ciphertext| {
+                // Compute the plaintext element, and absorb the ciphertext element into the sponge.
+                let plaintext = ciphertext - &self.squeeze(&mut state, &mut mode, 1)[0];
+                self.absorb(&mut state, &mut mode, &[ciphertext.clone()]);
+                plaintext
+            })
+            .collect();
+
+        let is_tag_valid = self.squeeze(&mut state, &mut mode, 1)[0].is_equal(tag);
+        (message, is_tag_valid)
+    }
+
+    /// Returns a new sponge state and mode, after absorbing the domain, key, nonce, and message length.
+    fn initialize_duplex(
+        &self,
+        domain: &Field<E>,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        length: usize,
+    ) -> (Vec<Field<E>>, DuplexSpongeMode) {
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+
+        let length = Field::constant((length as u128).into()); // <- Message length *must* be constant.
+        self.absorb(&mut state, &mut mode, &[domain.clone(), key.clone(), nonce.clone(), length]);
+
+        (state, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::encryption::PoseidonDuplexEncryption;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: &str = "AleoSymmetricEncryption0";
+
+    type NativeEncryption = PoseidonDuplexEncryption<<Circuit as Environment>::BaseField, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>;
+
+    fn check_encrypt_and_decrypt(mode: Mode, num_elements: usize) {
+        let rng = &mut test_rng();
+        let native = NativeEncryption::setup();
+        let poseidon = Poseidon::<Circuit>::new();
+
+        let native_domain = NativeEncryption::domain(DOMAIN);
+        let domain = Field::constant(native_domain);
+
+        for i in 0..ITERATIONS {
+            // Sample a random key, nonce, and message.
+            let native_key = UniformRand::rand(rng);
+            let native_nonce = UniformRand::rand(rng);
+            let native_message = (0..num_elements).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+            // Compute the native ciphertext and tag.
+            let (expected_ciphertext, expected_tag) =
+                native.encrypt(&native_domain, &native_key, &native_nonce, &native_message);
+
+            let key = Field::<Circuit>::new(mode, native_key);
+            let nonce = Field::<Circuit>::new(mode, native_nonce);
+            let message = native_message.iter().map(|element| Field::new(mode, *element)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("Poseidon Encryption {mode} {num_elements} {i}"), || {
+                let (ciphertext, tag) = poseidon.encrypt(&domain, &key, &nonce, &message);
+                assert_eq!(expected_ciphertext, ciphertext.eject_value());
+                assert_eq!(expected_tag, tag.eject_value());
+
+                let (candidate, is_tag_valid) = poseidon.decrypt(&domain, &key, &nonce, &ciphertext, &tag);
+                assert_eq!(native_message, candidate.eject_value());
+                assert!(is_tag_valid.eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_constant() {
+        for num_elements in 0..=2 * DEFAULT_RATE {
+            check_encrypt_and_decrypt(Mode::Constant, num_elements);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_public() {
+        for num_elements in 0..=2 * DEFAULT_RATE {
+            check_encrypt_and_decrypt(Mode::Public, num_elements);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_private() {
+        for num_elements in 0..=2 * DEFAULT_RATE {
+            check_encrypt_and_decrypt(Mode::Private, num_elements);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_invalid() {
+        let rng = &mut test_rng();
+        let native = NativeEncryption::setup();
+        let poseidon = Poseidon::<Circuit>::new();
+
+        let native_domain = NativeEncryption::domain(DOMAIN);
+        let native_other_domain = NativeEncryption::domain("AleoSymmetricEncryption1");
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let native_key = UniformRand::rand(rng);
+            let native_nonce = UniformRand::rand(rng);
+            let native_message = (0..DEFAULT_RATE).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (native_ciphertext, native_tag) =
+                native.encrypt(&native_domain, &native_key, &native_nonce, &native_message);
+
+            let domain = Field::constant(native_domain);
+            let other_domain = Field::constant(native_other_domain);
+            let key = Field::<Circuit>::new(mode, native_key);
+            let nonce = Field::<Circuit>::new(mode, native_nonce);
+            let ciphertext = native_ciphertext.iter().map(|element| Field::new(mode, *element)).collect::<Vec<_>>();
+            let tag = Field::new(mode, native_tag);
+
+            Circuit::scope(format!("Poseidon Decryption {mode}"), || {
+                // Ensure the ciphertext fails to decrypt under a different domain.
+                let (_, is_tag_valid) = poseidon.decrypt(&other_domain, &key, &nonce, &ciphertext, &tag);
+                assert!(!is_tag_valid.eject_value());
+
+                // Ensure a mutated ciphertext element fails to decrypt.
+                let mut mutated = ciphertext.clone();
+                mutated[0] += Field::one();
+                let (_, is_tag_valid) = poseidon.decrypt(&domain, &key, &nonce, &mutated, &tag);
+                assert!(!is_tag_valid.eject_value());
+
+                // Ensure a mutated tag fails to decrypt.
+                let (_, is_tag_valid) = poseidon.decrypt(&domain, &key, &nonce, &ciphertext, &(&tag + Field::one()));
+                assert!(!is_tag_valid.eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
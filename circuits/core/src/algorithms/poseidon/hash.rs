@@ -16,9 +16,13 @@
 
 use super::*;
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the Poseidon hash of the given input as a field element.
     #[inline]
-    pub fn hash(&self, input: &[Field<E>]) -> Field<E> {
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
         // Initialize a new sponge.
         let mut state = vec![Field::zero(); RATE + CAPACITY];
         let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
@@ -29,7 +33,7 @@ impl<E: Environment> Poseidon<E> {
     }
 }
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Absorbs the input elements into state.
     #[inline]
     pub(super) fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
@@ -209,8 +213,8 @@ mod tests {
         num_constraints: usize,
     ) {
         let rng = &mut test_rng();
-        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
-        let poseidon = Poseidon::new();
+        let native_poseidon = NativePoseidon::<_, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<Circuit>::new();
 
         for i in 0..ITERATIONS {
             // Prepare the preimage.
@@ -232,7 +236,7 @@ mod tests {
 
     #[test]
     fn test_hash_constant() {
-        for num_inputs in 0..=RATE {
+        for num_inputs in 0..=DEFAULT_RATE {
             check_hash(Mode::Constant, num_inputs, 0, 0, 0, 0);
         }
     }
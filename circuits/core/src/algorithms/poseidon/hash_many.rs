@@ -16,7 +16,7 @@
 
 use super::*;
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     #[inline]
     pub fn hash_many(&self, input: &[Field<E>], num_outputs: usize) -> Vec<Field<E>> {
         // Initialize a new sponge.
@@ -48,8 +48,8 @@ mod tests {
         num_constraints: usize,
     ) {
         let rng = &mut test_rng();
-        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
-        let poseidon = Poseidon::new();
+        let native_poseidon = NativePoseidon::<_, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<Circuit>::new();
 
         for i in 0..ITERATIONS {
             // Prepare the preimage.
@@ -73,8 +73,8 @@ mod tests {
 
     #[test]
     fn test_hash_many_constant() {
-        for num_inputs in 0..=RATE {
-            for num_outputs in 0..=RATE {
+        for num_inputs in 0..=DEFAULT_RATE {
+            for num_outputs in 0..=DEFAULT_RATE {
                 check_hash_many(Mode::Constant, num_inputs, num_outputs, 0, 0, 0, 0);
             }
         }
@@ -82,10 +82,10 @@ mod tests {
 
     #[test]
     fn test_hash_many_public() {
-        for num_outputs in 0..=RATE {
+        for num_outputs in 0..=DEFAULT_RATE {
             check_hash_many(Mode::Public, 0, num_outputs, 0, 0, 0, 0);
         }
-        for num_outputs in 1..=RATE {
+        for num_outputs in 1..=DEFAULT_RATE {
             check_hash_many(Mode::Public, 1, num_outputs, 0, 0, 335, 335);
             check_hash_many(Mode::Public, 2, num_outputs, 0, 0, 340, 340);
             check_hash_many(Mode::Public, 3, num_outputs, 0, 0, 345, 345);
@@ -93,7 +93,7 @@ mod tests {
             check_hash_many(Mode::Public, 5, num_outputs, 0, 0, 705, 705);
             check_hash_many(Mode::Public, 6, num_outputs, 0, 0, 705, 705);
         }
-        for num_outputs in (RATE + 1)..=(RATE * 2) {
+        for num_outputs in (DEFAULT_RATE + 1)..=(DEFAULT_RATE * 2) {
             check_hash_many(Mode::Public, 1, num_outputs, 0, 0, 690, 690);
             check_hash_many(Mode::Public, 2, num_outputs, 0, 0, 695, 695);
             check_hash_many(Mode::Public, 3, num_outputs, 0, 0, 700, 700);
@@ -105,10 +105,10 @@ mod tests {
 
     #[test]
     fn test_hash_many_private() {
-        for num_outputs in 0..=RATE {
+        for num_outputs in 0..=DEFAULT_RATE {
             check_hash_many(Mode::Private, 0, num_outputs, 0, 0, 0, 0);
         }
-        for num_outputs in 1..=RATE {
+        for num_outputs in 1..=DEFAULT_RATE {
             check_hash_many(Mode::Private, 1, num_outputs, 0, 0, 335, 335);
             check_hash_many(Mode::Private, 2, num_outputs, 0, 0, 340, 340);
             check_hash_many(Mode::Private, 3, num_outputs, 0, 0, 345, 345);
@@ -116,7 +116,7 @@ mod tests {
             check_hash_many(Mode::Private, 5, num_outputs, 0, 0, 705, 705);
             check_hash_many(Mode::Private, 6, num_outputs, 0, 0, 705, 705);
         }
-        for num_outputs in (RATE + 1)..=(RATE * 2) {
+        for num_outputs in (DEFAULT_RATE + 1)..=(DEFAULT_RATE * 2) {
             check_hash_many(Mode::Private, 1, num_outputs, 0, 0, 690, 690);
             check_hash_many(Mode::Private, 2, num_outputs, 0, 0, 695, 695);
             check_hash_many(Mode::Private, 3, num_outputs, 0, 0, 700, 700);
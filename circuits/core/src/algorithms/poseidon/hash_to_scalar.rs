@@ -16,11 +16,15 @@
 
 use super::*;
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> HashToScalar for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Scalar<E>;
+
     /// Returns a scalar from hashing the input.
-    /// This method uses truncation (up to data bits) to project onto the scalar field.
+    /// This method uses truncation (up to data bits) to project onto the scalar field,
+    /// matching `Poseidon::hash_to_scalar` in `algorithms`.
     #[inline]
-    pub fn hash_to_scalar(&self, input: &[Field<E>]) -> Scalar<E> {
+    fn hash_to_scalar(&self, input: &[Self::Input]) -> Self::Output {
         // Hash the input to the base field.
         let output = self.hash(input);
 
@@ -35,7 +39,7 @@ mod tests {
     use super::*;
     use snarkvm_algorithms::crypto_hash::Poseidon as NativePoseidon;
     use snarkvm_circuits_types::environment::Circuit;
-    use snarkvm_utilities::{test_rng, FromBits, ToBits, UniformRand};
+    use snarkvm_utilities::{test_rng, UniformRand};
 
     const ITERATIONS: usize = 10;
 
@@ -48,8 +52,8 @@ mod tests {
         num_constraints: usize,
     ) {
         let rng = &mut test_rng();
-        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
-        let poseidon = Poseidon::new();
+        let native_poseidon = NativePoseidon::<_, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<Circuit>::new();
 
         for i in 0..ITERATIONS {
             // Prepare the preimage.
@@ -58,22 +62,7 @@ mod tests {
             let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
 
             // Compute the native hash to scalar.
-            let expected = {
-                // Use Poseidon as a random oracle.
-                let output = native_poseidon.evaluate(&native_input);
-
-                // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
-                let mut bits = output.to_bits_le();
-                bits.resize(<Circuit as Environment>::ScalarField::size_in_data_bits(), false);
-
-                // Output the scalar field.
-                let biginteger = <<Circuit as Environment>::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits);
-                match <<Circuit as Environment>::ScalarField as PrimeField>::from_repr(biginteger) {
-                    // We know this case will always work, because we truncate the output to CAPACITY bits in the scalar field.
-                    Some(scalar) => scalar,
-                    _ => panic!("Failed to hash input into scalar field"),
-                }
-            };
+            let expected: <Circuit as Environment>::ScalarField = native_poseidon.hash_to_scalar(&native_input);
 
             // Compute the circuit hash.
             Circuit::scope(format!("Poseidon {mode} {i}"), || {
@@ -87,7 +76,7 @@ mod tests {
 
     #[test]
     fn test_hash_to_scalar_constant() {
-        for num_inputs in 0..=RATE {
+        for num_inputs in 0..=DEFAULT_RATE {
             check_hash_to_scalar(Mode::Constant, num_inputs, 253, 0, 0, 0);
         }
     }
@@ -14,6 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod crh;
+pub use crh::*;
+
+pub mod encryption;
 pub mod hash;
 pub mod hash_many;
 pub mod hash_to_scalar;
@@ -22,15 +26,19 @@ pub mod prf;
 #[cfg(test)]
 use snarkvm_circuits_types::environment::assert_scope;
 
+use crate::{Hash, HashToScalar, PRF};
 use snarkvm_algorithms::DuplexSpongeMode;
-use snarkvm_circuits_types::{environment::prelude::*, Field, Scalar};
+use snarkvm_circuits_types::{environment::prelude::*, Boolean, Field, Scalar};
 use snarkvm_fields::PoseidonDefaultField;
 
-const RATE: usize = 4;
+/// The rate of the Poseidon sponge used by default.
+pub const DEFAULT_RATE: usize = 4;
 const OPTIMIZED_FOR_WEIGHTS: bool = false;
 const CAPACITY: usize = 1;
 
-pub struct Poseidon<E: Environment> {
+/// The Poseidon hash function, with a sponge that absorbs `RATE` field elements per permutation.
+/// Note: The default rate must match `DEFAULT_RATE`.
+pub struct Poseidon<E: Environment, const RATE: usize = 4> {
     /// The number of rounds in a full-round operation.
     full_rounds: usize,
     /// The number of rounds in a partial-round operation.
@@ -44,7 +52,7 @@ pub struct Poseidon<E: Environment> {
     mds: Vec<Vec<Field<E>>>,
 }
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         match E::BaseField::default_poseidon_parameters::<RATE>(OPTIMIZED_FOR_WEIGHTS) {
@@ -73,3 +81,37 @@ impl<E: Environment> Poseidon<E> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::crypto_hash::Poseidon as NativePoseidon;
+    use snarkvm_circuits_types::environment::Circuit;
+
+    #[test]
+    fn test_parameters_match_native() {
+        let native_poseidon = NativePoseidon::<<Circuit as Environment>::BaseField, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let native_parameters = native_poseidon.parameters();
+        let poseidon = Poseidon::<Circuit>::new();
+
+        // Ensure the number of rounds and the exponent match.
+        let native_alpha = <Circuit as Environment>::BaseField::from(native_parameters.alpha as u128);
+        assert_eq!(native_parameters.full_rounds, poseidon.full_rounds);
+        assert_eq!(native_parameters.partial_rounds, poseidon.partial_rounds);
+        assert_eq!(native_alpha, poseidon.alpha.eject_value());
+
+        // Ensure the additive round keys match for every round.
+        assert_eq!(poseidon.full_rounds + poseidon.partial_rounds, poseidon.ark.len());
+        for (native_round, round) in native_parameters.ark.iter().zip(&poseidon.ark) {
+            assert_eq!(DEFAULT_RATE + CAPACITY, round.len());
+            assert_eq!(native_round[..DEFAULT_RATE + CAPACITY], round.eject_value()[..]);
+        }
+
+        // Ensure the MDS matrix matches.
+        assert_eq!(DEFAULT_RATE + CAPACITY, poseidon.mds.len());
+        for (native_row, row) in native_parameters.mds.iter().zip(&poseidon.mds) {
+            assert_eq!(DEFAULT_RATE + CAPACITY, row.len());
+            assert_eq!(native_row[..DEFAULT_RATE + CAPACITY], row.eject_value()[..]);
+        }
+    }
+}
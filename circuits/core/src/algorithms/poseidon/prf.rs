@@ -16,9 +16,14 @@
 
 use super::*;
 
-impl<E: Environment> Poseidon<E> {
+impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+    type Seed = Field<E>;
+
+    /// Returns the Poseidon PRF output for the given seed and input, as in the native `PoseidonPRF`.
     #[inline]
-    pub fn prf(&self, seed: &Field<E>, input: &[Field<E>]) -> Field<E> {
+    fn evaluate(&self, seed: &Self::Seed, input: &[Self::Input]) -> Self::Output {
         // Construct the preimage: seed || length(input) || input.
         let mut preimage = Vec::with_capacity(2 + input.len());
         preimage.push(seed.clone());
@@ -48,7 +53,7 @@ mod tests {
         num_constraints: usize,
     ) {
         let rng = &mut test_rng();
-        let poseidon = Poseidon::new();
+        let poseidon = Poseidon::<Circuit>::new();
 
         for i in 0..ITERATIONS {
             // Prepare the seed.
@@ -61,10 +66,10 @@ mod tests {
             let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
 
             // Compute the native hash.
-            let expected = NativePoseidonPRF::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::evaluate(&native_seed, &native_input);
+            let expected = NativePoseidonPRF::<_, DEFAULT_RATE, OPTIMIZED_FOR_WEIGHTS>::evaluate(&native_seed, &native_input);
             // Compute the circuit hash.
             Circuit::scope(format!("Poseidon PRF {mode} {i}"), || {
-                let candidate = poseidon.prf(&seed, &input);
+                let candidate = poseidon.evaluate(&seed, &input);
                 assert_eq!(expected, candidate.eject_value());
                 let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
                 assert_scope!(case, num_constants, num_public, num_private, num_constraints);
@@ -74,7 +79,7 @@ mod tests {
 
     #[test]
     fn test_prf_constant() {
-        for num_inputs in 0..=RATE {
+        for num_inputs in 0..=DEFAULT_RATE {
             check_prf(Mode::Constant, num_inputs, 1, 0, 0, 0);
         }
     }
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = U8<E>;
+    type Output = Vec<U8<E>>;
+
+    /// Returns the SHA-256 digest of the given input bytes, as 32 bytes.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Pad the input with `0x80`, followed by zeros, and the input length in bits as a big-endian `u64`.
+        // Note: As the input length is a constant, the padding is a constant.
+        let num_zeros = (2 * BLOCK_SIZE_IN_BYTES - 9 - input.len() % BLOCK_SIZE_IN_BYTES) % BLOCK_SIZE_IN_BYTES;
+        let mut message = input.to_vec();
+        message.push(U8::constant(0x80));
+        message.extend((0..num_zeros).map(|_| U8::constant(0)));
+        message.extend(((input.len() as u64) * 8).to_be_bytes().into_iter().map(U8::constant));
+        debug_assert_eq!(message.len() % BLOCK_SIZE_IN_BYTES, 0);
+
+        // Compress each block into the state.
+        let state = message.chunks(BLOCK_SIZE_IN_BYTES).fold(self.initial_state.clone(), |state, block| {
+            // Construct the words of the block from big-endian bytes.
+            let block = block
+                .chunks(4)
+                .map(|bytes| bytes.iter().rev().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())
+                .map(|bits_le| U32::from_bits_le(&bits_le))
+                .collect::<Vec<_>>();
+            self.compress(&state, &block)
+        });
+
+        // Output the state words as big-endian bytes.
+        state
+            .iter()
+            .flat_map(|word| word.to_bits_le().chunks(8).rev().map(U8::from_bits_le).collect::<Vec<_>>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::crypto_hash::sha256;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    fn check_hash(mode: Mode, num_bytes: usize) {
+        let rng = &mut test_rng();
+        let sha256_circuit = Sha256::<Circuit>::new();
+
+        // Sample a random input.
+        let native_input = (0..num_bytes).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        let input = native_input.iter().map(|byte| U8::<Circuit>::new(mode, *byte)).collect::<Vec<_>>();
+
+        // Compute the native hash.
+        let expected = sha256(&native_input);
+        // Compute the circuit hash.
+        Circuit::scope(format!("SHA-256 {mode} {num_bytes}"), || {
+            let candidate = sha256_circuit.hash(&input);
+            assert_eq!(expected.to_vec(), candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        for num_bytes in [0, 3, 55, 56, 64] {
+            check_hash(Mode::Constant, num_bytes);
+        }
+    }
+
+    #[test]
+    fn test_hash_public() {
+        for num_bytes in [0, 3, 55, 56, 64] {
+            check_hash(Mode::Public, num_bytes);
+        }
+    }
+
+    #[test]
+    fn test_hash_private() {
+        for num_bytes in [0, 3, 55, 56, 64, 130] {
+            check_hash(Mode::Private, num_bytes);
+        }
+    }
+
+    #[test]
+    fn test_hash_known_vector() {
+        let input = "abc".bytes().map(|byte| U8::<Circuit>::new(Mode::Private, byte)).collect::<Vec<_>>();
+        let candidate = Sha256::<Circuit>::new().hash(&input);
+
+        let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let candidate = candidate.eject_value().iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        assert_eq!(expected, candidate);
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuits_types::{prelude::*, Boolean, Field, U32, U8};
+
+/// The SHA-256 round constants.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// The SHA-256 initial hash value.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The number of bytes in a SHA-256 block.
+const BLOCK_SIZE_IN_BYTES: usize = 64;
+
+/// SHA-256 is the hash function from the SHA-2 family with a 256-bit digest, as specified in FIPS 180-4.
+pub struct Sha256<E: Environment> {
+    /// The round constants.
+    round_constants: Vec<U32<E>>,
+    /// The initial hash value.
+    initial_state: Vec<U32<E>>,
+}
+
+impl<E: Environment> Sha256<E> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            round_constants: ROUND_CONSTANTS.iter().map(|constant| U32::constant(*constant)).collect(),
+            initial_state: INITIAL_STATE.iter().map(|constant| U32::constant(*constant)).collect(),
+        }
+    }
+
+    ///
+    /// Returns the updated state, after applying the compression function
+    /// to the given state of 8 words and the given block of 16 words.
+    ///
+    pub fn compress(&self, state: &[U32<E>], block: &[U32<E>]) -> Vec<U32<E>> {
+        // Ensure the state and block are well-formed.
+        if state.len() != 8 || block.len() != 16 {
+            E::halt("SHA-256 compression expects a state of 8 words and a block of 16 words")
+        }
+
+        // Compute the message schedule.
+        let mut schedule = block.to_vec();
+        for t in 16..64 {
+            let s0 = Self::small_sigma(&schedule[t - 15], 7, 18, 3);
+            let s1 = Self::small_sigma(&schedule[t - 2], 17, 19, 10);
+            let word = Self::add_many_wrapped(&[&schedule[t - 16], &s0, &schedule[t - 7], &s1]);
+            schedule.push(word);
+        }
+
+        // Initialize the working variables.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+
+        // Apply the 64 rounds.
+        for (round_constant, word) in self.round_constants.iter().zip_eq(&schedule) {
+            let s1 = Self::big_sigma(&e, 6, 11, 25);
+            let ch = Self::choose(&e, &f, &g);
+            let s0 = Self::big_sigma(&a, 2, 13, 22);
+            let maj = Self::majority(&a, &b, &c);
+
+            let new_e = Self::add_many_wrapped(&[&d, &h, &s1, &ch, round_constant, word]);
+            let new_a = Self::add_many_wrapped(&[&h, &s1, &ch, round_constant, word, &s0, &maj]);
+
+            h = g;
+            g = f;
+            f = e;
+            e = new_e;
+            d = c;
+            c = b;
+            b = a;
+            a = new_a;
+        }
+
+        // Add the working variables to the state.
+        state
+            .iter()
+            .zip_eq([a, b, c, d, e, f, g, h])
+            .map(|(word, update)| Self::add_many_wrapped(&[word, &update]))
+            .collect()
+    }
+
+    /// Returns `(x >>> r1) ^ (x >>> r2) ^ (x >> s)`.
+    fn small_sigma(x: &U32<E>, r1: u32, r2: u32, s: u8) -> U32<E> {
+        x.rotate_right(r1) ^ x.rotate_right(r2) ^ x.shr_wrapped(&U8::constant(s))
+    }
+
+    /// Returns `(x >>> r1) ^ (x >>> r2) ^ (x >>> r3)`.
+    fn big_sigma(x: &U32<E>, r1: u32, r2: u32, r3: u32) -> U32<E> {
+        x.rotate_right(r1) ^ x.rotate_right(r2) ^ x.rotate_right(r3)
+    }
+
+    /// Returns `(x & y) ^ (!x & z)`, computed as a ternary on each bit of `x`.
+    fn choose(x: &U32<E>, y: &U32<E>, z: &U32<E>) -> U32<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le().iter().zip_eq(z.to_bits_le().iter()))
+            .map(|(x, (y, z))| Boolean::ternary(x, y, z))
+            .collect::<Vec<_>>();
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Returns `(x & y) ^ (x & z) ^ (y & z)`, computed as `(x ^ y) ? z : x` on each bit.
+    fn majority(x: &U32<E>, y: &U32<E>, z: &U32<E>) -> U32<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le().iter().zip_eq(z.to_bits_le().iter()))
+            .map(|(x, (y, z))| Boolean::ternary(&(x ^ y), z, x))
+            .collect::<Vec<_>>();
+        U32::from_bits_le(&bits_le)
+    }
+
+    ///
+    /// Returns the sum of the given words, modulo `2^32`.
+    ///
+    /// The words are summed in the base field, and the carry bits are discarded
+    /// from a single bit decomposition of the sum.
+    ///
+    fn add_many_wrapped(words: &[&U32<E>]) -> U32<E> {
+        // Compute the number of carry bits.
+        let num_carry_bits = (usize::BITS - (words.len().max(1) - 1).leading_zeros()) as usize;
+
+        // Sum the words in the base field.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + word.to_field());
+
+        // Decompose the sum, and discard the carry bits.
+        U32::from_bits_le(&sum.to_lower_bits_le(32 + num_carry_bits)[..32])
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use crate::{
+    algorithms::{Poseidon, BHP},
+    Hash,
+    HashToScalar,
+    HashUncompressed,
+};
+use snarkvm_algorithms::crypto_hash::hash_to_curve;
+use snarkvm_circuits_types::prelude::*;
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+
+/// The domain separator for the output of the VRF, matching `ECVRF` in `algorithms`.
+const VRF_OUTPUT_DOMAIN: &str = "AleoVRFOutput0";
+
+/// An ECVRF-style verifiable random function, matching `ECVRF` in `algorithms`.
+/// The input is hashed to the group with the BHP hash, and *must* have a constant length.
+pub struct VRF<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
+    /// The powers of the generator G.
+    bases: Vec<Group<E>>,
+    /// The BHP hash used to hash the input to the group.
+    bhp: BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The Poseidon hash used to derive the challenge and the output.
+    poseidon: Poseidon<E>,
+}
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> VRF<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of the VRF with the given setup message.
+    pub fn setup(message: &str) -> Self {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = hash_to_curve::<E::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut base = base.to_projective();
+        let mut bases = Vec::with_capacity(E::ScalarField::size_in_bits());
+        for _ in 0..E::ScalarField::size_in_bits() {
+            bases.push(Group::constant(base.to_affine()));
+            base.double_in_place();
+        }
+
+        Self { bases, bhp: BHP::setup(message), poseidon: Poseidon::new() }
+    }
+
+    /// Returns the scalar multiplication on the powers of G.
+    fn g_scalar_multiply(&self, scalar: &Scalar<E>) -> Group<E> {
+        self.bases
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .fold(Group::zero(), |output, (base, bit)| Group::ternary(bit, &(&output + base), &output))
+    }
+}
+
+/// A VRF proof, as produced by `ECVRF::prove` in `algorithms`.
+pub struct VRFProof<E: Environment> {
+    /// The point gamma := H^sk, where H is the hash of the input to the group.
+    gamma: Group<E>,
+    /// The challenge c.
+    challenge: Scalar<E>,
+    /// The response s := k - c * sk.
+    response: Scalar<E>,
+}
+
+impl<E: Environment> Inject for VRFProof<E> {
+    type Primitive = (E::BaseField, E::ScalarField, E::ScalarField);
+
+    /// Initializes a VRF proof from the given mode and `(gamma, challenge, response)`.
+    fn new(mode: Mode, (gamma, challenge, response): Self::Primitive) -> Self {
+        // Recover gamma from its x-coordinate, and ensure it is in the prime-order subgroup.
+        let gamma = Group::from_x_coordinate(Field::new(mode, gamma));
+        gamma.enforce_in_prime_subgroup();
+
+        Self { gamma, challenge: Scalar::new(mode, challenge), response: Scalar::new(mode, response) }
+    }
+}
+
+impl<E: Environment> Eject for VRFProof<E> {
+    type Primitive = (E::BaseField, E::ScalarField, E::ScalarField);
+
+    ///
+    /// Ejects the mode of the VRF proof.
+    ///
+    fn eject_mode(&self) -> Mode {
+        (&self.gamma, &self.challenge, &self.response).eject_mode()
+    }
+
+    ///
+    /// Ejects the VRF proof as `(gamma, challenge, response)`.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        (&self.gamma.to_x_coordinate(), &self.challenge, &self.response).eject_value()
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> VRF<E, NUM_WINDOWS, WINDOW_SIZE> {
+    ///
+    /// Returns `true` if the proof is valid for the given `public_key` and `input`, i.e. c == c', where:
+    ///     c' := Hash(G^sk, H, gamma, G^s G^sk^c, H^s gamma^c)
+    ///
+    /// Note: The number of input bits *must* be constant, as the BHP hash pads its input.
+    ///
+    pub fn verify(&self, public_key: &Group<E>, input: &[Boolean<E>], proof: &VRFProof<E>) -> Boolean<E> {
+        // Ensure the public key is in the prime-order subgroup.
+        public_key.enforce_in_prime_subgroup();
+
+        // Compute H := HashToGroup(input), and ensure it is not the identity.
+        let h = self.bhp.hash_uncompressed(input);
+        let is_h_zero = h.is_equal(&Group::zero());
+
+        // Compute G^k := G^s G^sk^c, and H^k := H^s gamma^c.
+        let g_k = self.g_scalar_multiply(&proof.response) + (public_key * &proof.challenge);
+        let h_k = (&h * &proof.response) + (&proof.gamma * &proof.challenge);
+
+        // Compute the candidate challenge.
+        let candidate_challenge = self.poseidon.hash_to_scalar(&[
+            public_key.to_x_coordinate(),
+            h.to_x_coordinate(),
+            proof.gamma.to_x_coordinate(),
+            g_k.to_x_coordinate(),
+            h_k.to_x_coordinate(),
+        ]);
+
+        proof.challenge.is_equal(&candidate_challenge) & !is_h_zero
+    }
+
+    ///
+    /// Returns the output Hash(domain, gamma) of the given proof.
+    ///
+    /// Note: The output is only meaningful if the proof is checked with `verify`.
+    ///
+    pub fn output(&self, proof: &VRFProof<E>) -> Field<E> {
+        let domain = Field::constant(E::BaseField::from_bytes_le_mod_order(VRF_OUTPUT_DOMAIN.as_bytes()));
+        self.poseidon.hash(&[domain, proof.gamma.to_x_coordinate()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{vrf::ECVRF, VRF as NativeVRF};
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_crypto_rng, ToBits as NativeToBits};
+
+    const ITERATIONS: usize = 5;
+    const MESSAGE: &str = "VRFCircuit0";
+
+    type NativeECVRF = ECVRF<<Circuit as Environment>::AffineParameters, 8, 32>;
+
+    fn check_verify(mode: Mode) {
+        let rng = &mut test_crypto_rng();
+        let native = NativeECVRF::setup(MESSAGE);
+        let circuit = VRF::<Circuit, 8, 32>::setup(MESSAGE);
+
+        for i in 0..ITERATIONS {
+            // Prove and verify the input natively.
+            let private_key = native.generate_private_key(rng);
+            let public_key = native.generate_public_key(&private_key);
+            let native_input = format!("Hi, I am VRF input {i}").as_bytes().to_bits_le();
+            let native_proof = native.prove(&private_key, &native_input, rng).unwrap();
+            let native_output = native.proof_to_output(&native_proof).unwrap();
+            let gamma = native_proof.gamma().unwrap().to_x_coordinate();
+
+            // Inject the public key, input, and proof.
+            let public_key = Group::<Circuit>::new(mode, public_key);
+            let input = native_input.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+            let proof = VRFProof::<Circuit>::new(mode, (gamma, native_proof.challenge, native_proof.response));
+
+            Circuit::scope(format!("VRF {mode} {i}"), || {
+                let candidate = circuit.verify(&public_key, &input, &proof);
+                assert!(candidate.eject_value());
+                assert_eq!(native_output, circuit.output(&proof).eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+
+            // Check the proof against a different input.
+            let other_input = native_input.iter().map(|bit| Boolean::new(mode, !*bit)).collect::<Vec<_>>();
+            let candidate = circuit.verify(&public_key, &other_input, &proof);
+            assert!(!candidate.eject_value());
+
+            // Check the proof against a different public key.
+            let other_public_key = native.generate_public_key(&native.generate_private_key(rng));
+            let candidate = circuit.verify(&Group::new(mode, other_public_key), &input, &proof);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant);
+    }
+
+    #[test]
+    fn test_verify_public() {
+        check_verify(Mode::Public);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        check_verify(Mode::Private);
+    }
+}
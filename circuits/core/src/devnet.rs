@@ -14,13 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{algorithms::Poseidon, Aleo};
+use crate::{
+    algorithms::{Keccak256, Poseidon, BHP},
+    Aleo,
+    Hash,
+    HashToScalar,
+};
 use snarkvm_algorithms::crypto_hash::hash_to_curve;
 use snarkvm_circuits_types::{
     environment::{prelude::*, Circuit},
+    Boolean,
     Field,
     Group,
     Scalar,
+    U8,
 };
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 
@@ -32,8 +39,22 @@ pub type E = Circuit;
 static ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT: &str = "AleoAccountEncryptionAndSignatureScheme0";
 
 thread_local! {
-    /// The Poseidon hash function.
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    static BHP_256: BHP<Devnet, 3, 57> = BHP::<Devnet, 3, 57>::setup("AleoBHP256");
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    static BHP_512: BHP<Devnet, 6, 43> = BHP::<Devnet, 6, 43>::setup("AleoBHP512");
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    static BHP_768: BHP<Devnet, 15, 23> = BHP::<Devnet, 15, 23>::setup("AleoBHP768");
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    static BHP_1024: BHP<Devnet, 8, 54> = BHP::<Devnet, 8, 54>::setup("AleoBHP1024");
+    /// The Keccak-256 hash function.
+    static KECCAK_256: Keccak256<Devnet> = Keccak256::<Devnet>::new();
+    /// The Poseidon hash function, with an input rate of 2.
+    static POSEIDON_2: Poseidon<Devnet, 2> = Poseidon::<Devnet, 2>::new();
+    /// The Poseidon hash function, with an input rate of 4.
     static POSEIDON: Poseidon<Devnet> = Poseidon::<Devnet>::new();
+    /// The Poseidon hash function, with an input rate of 8.
+    static POSEIDON_8: Poseidon<Devnet, 8> = Poseidon::<Devnet, 8>::new();
     /// The group bases for the Aleo signature and encryption schemes.
     static BASES: Vec<Group<Devnet >> = Devnet::new_bases(ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT);
 }
@@ -85,6 +106,46 @@ impl Aleo for Devnet {
     fn hash_to_scalar(input: &[Field<Self>]) -> Scalar<Self> {
         POSEIDON.with(|poseidon| poseidon.hash_to_scalar(input))
     }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self> {
+        BHP_256.with(|bhp| bhp.hash(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[Boolean<Self>]) -> Field<Self> {
+        BHP_512.with(|bhp| bhp.hash(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self> {
+        BHP_768.with(|bhp| bhp.hash(input))
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self> {
+        BHP_1024.with(|bhp| bhp.hash(input))
+    }
+
+    /// Returns the Keccak-256 digest of the given input bytes.
+    fn hash_keccak256(input: &[U8<Self>]) -> Vec<U8<Self>> {
+        KECCAK_256.with(|keccak| keccak.hash(input))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_2.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_8.with(|poseidon| poseidon.hash(input))
+    }
 }
 
 impl Environment for Devnet {
@@ -145,6 +206,20 @@ impl Environment for Devnet {
         E::is_satisfied_in_scope()
     }
 
+    /// Attaches the given annotation to every constraint enforced in the given logic,
+    /// to be reported by `diagnostics` if the constraint is unsatisfied.
+    fn annotate<Fn, Output>(annotation: Annotation, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::annotate(annotation, logic)
+    }
+
+    /// Returns a diagnostic for each unsatisfied constraint in the circuit.
+    fn diagnostics() -> Vec<Diagnostic> {
+        E::diagnostics()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> usize {
         E::num_constants()
@@ -206,6 +281,26 @@ impl Environment for Devnet {
         E::halt(message)
     }
 
+    /// Starts recording every injection and constraint in the circuit into a replay log.
+    fn start_tracing() {
+        E::start_tracing()
+    }
+
+    /// Stops recording, and returns the replay log, if tracing was started.
+    fn stop_tracing() -> Option<ReplayLog<Self::BaseField>> {
+        E::stop_tracing()
+    }
+
+    /// Clears the circuit, and reconstructs the circuit recorded in the given replay log.
+    fn replay(log: &ReplayLog<Self::BaseField>) -> Result<(), String> {
+        E::replay(log)
+    }
+
+    /// Returns the values of the public and private variables in the circuit.
+    fn to_assignment() -> Assignment<Self::BaseField> {
+        E::to_assignment()
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         E::reset()
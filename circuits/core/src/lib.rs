@@ -19,6 +19,8 @@
 
 pub mod account;
 pub mod algorithms;
+pub mod memory;
+pub mod merkle_tree;
 
 pub mod devnet;
 pub use devnet::*;
@@ -26,7 +28,7 @@ pub use devnet::*;
 pub mod traits;
 pub use traits::*;
 
-use snarkvm_circuits_types::{environment::Environment, Field, Group, Scalar};
+use snarkvm_circuits_types::{environment::Environment, Boolean, Field, Group, Scalar, U8};
 
 pub trait Aleo: Environment {
     /// Returns the scalar multiplication on the group bases.
@@ -34,4 +36,28 @@ pub trait Aleo: Environment {
 
     /// Returns a hash on the scalar field for the given input.
     fn hash_to_scalar(input: &[Field<Self>]) -> Scalar<Self>;
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self>;
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[Boolean<Self>]) -> Field<Self>;
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self>;
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self>;
+
+    /// Returns the Keccak-256 digest of the given input bytes.
+    fn hash_keccak256(input: &[U8<Self>]) -> Vec<U8<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 }
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Memory<E> {
+    ///
+    /// Returns the value stored at the given address.
+    ///
+    /// If the address is not within the memory, the consistency check fails.
+    ///
+    pub fn read(&mut self, address: &Field<E>) -> Field<E> {
+        self.access(address, None)
+    }
+
+    ///
+    /// Stores the given value at the given address.
+    ///
+    /// If the address is not within the memory, the consistency check fails.
+    ///
+    pub fn write(&mut self, address: &Field<E>, value: &Field<E>) {
+        self.access(address, Some(value));
+    }
+
+    ///
+    /// Reads the value at the given address, and writes back the given value, or the read value if none is given.
+    /// Returns the read value.
+    ///
+    /// The read value and timestamp are witnessed, and the read timestamp is enforced to be
+    /// less than the current timestamp. This costs `TIMESTAMP_SIZE_IN_BITS + 1` constraints.
+    ///
+    fn access(&mut self, address: &Field<E>, value: Option<&Field<E>>) -> Field<E> {
+        // Ensure the number of accesses is within range.
+        if self.timestamp >= 1 << TIMESTAMP_SIZE_IN_BITS {
+            E::halt(format!("Exceeded {} accesses to memory", self.num_accesses()))
+        }
+
+        // Retrieve the value and timestamp of the cell.
+        let (previous_value, previous_timestamp) = match self.cell_index(address) {
+            Some(index) => self.cells[index],
+            None if address.is_constant() => E::halt(format!("Address {address} exceeds the memory size")),
+            // Note: As no entry exists for an address outside the memory, the consistency check fails.
+            None => (E::BaseField::zero(), 0),
+        };
+
+        // Witness the read value and timestamp.
+        let read_value = Field::new(Mode::Private, previous_value);
+        let read_timestamp = Field::new(Mode::Private, E::BaseField::from(previous_timestamp as u128));
+
+        // Ensure the read timestamp is less than the current timestamp.
+        let timestamp = Field::constant(E::BaseField::from(self.timestamp as u128));
+        (&timestamp - &read_timestamp - Field::one()).to_lower_bits_le(TIMESTAMP_SIZE_IN_BITS);
+
+        // Write back the new value with the current timestamp.
+        let written_value = value.unwrap_or(&read_value).clone();
+        if let Some(index) = self.cell_index(address) {
+            self.cells[index] = (written_value.eject_value(), self.timestamp);
+        }
+
+        self.reads.push((address.clone(), read_value.clone(), read_timestamp));
+        self.writes.push((address.clone(), written_value, timestamp));
+        self.timestamp += 1;
+
+        read_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::tests::sample_values;
+    use snarkvm_circuits_types::environment::Circuit;
+
+    const SIZE: usize = 16;
+
+    fn check_read(mode_values: Mode, mode_address: Mode) {
+        let values = sample_values(SIZE);
+        let mut memory = Memory::<Circuit>::new(mode_values, values.clone());
+
+        for (i, expected) in values.iter().enumerate() {
+            let address = Field::new(mode_address, (i as u128).into());
+            Circuit::scope(format!("Read {mode_values} {mode_address} {i}"), || {
+                let candidate = memory.read(&address);
+                assert_eq!(*expected, candidate.eject_value());
+                assert_scope!(1, 0, 2 + TIMESTAMP_SIZE_IN_BITS, TIMESTAMP_SIZE_IN_BITS + 1);
+            });
+        }
+        assert_eq!(SIZE as u64, memory.num_accesses());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_read() {
+        for mode_values in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_address in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_read(mode_values, mode_address);
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_then_read() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        let new_values = sample_values(SIZE);
+
+        for (i, value) in new_values.iter().enumerate() {
+            let address = Field::new(Mode::Private, (i as u128).into());
+            memory.write(&address, &Field::new(Mode::Private, *value));
+        }
+        for (i, expected) in new_values.iter().enumerate().rev() {
+            let address = Field::new(Mode::Public, (i as u128).into());
+            assert_eq!(*expected, memory.read(&address).eject_value());
+        }
+        assert_eq!(2 * SIZE as u64, memory.num_accesses());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_access_cost_is_independent_of_size() {
+        for size in [1, 16, 256] {
+            let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(size));
+            let address = Field::new(Mode::Private, ((size - 1) as u128).into());
+            Circuit::scope(format!("Access {size}"), || {
+                let value = memory.read(&address);
+                memory.write(&address, &value);
+                assert_scope!(2, 0, 2 * (2 + TIMESTAMP_SIZE_IN_BITS), 2 * (TIMESTAMP_SIZE_IN_BITS + 1));
+            });
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_constant_address_exceeds_size() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        memory.read(&Field::constant((SIZE as u128).into()));
+    }
+}
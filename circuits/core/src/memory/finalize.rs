@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Memory<E> {
+    ///
+    /// Returns `true` if every read from the memory returned the value last written to its address.
+    ///
+    /// Each cell is read a final time, after which the memory is consistent if the multiset of reads
+    /// equals the multiset of writes. The multisets are compared with a grand product, over the
+    /// fingerprint `gamma - (address + alpha * value + alpha^2 * timestamp)` of each entry,
+    /// where `alpha` and `gamma` are derived by hashing the entries with the given hasher.
+    ///
+    pub fn finalize<H: Hash<Input = Field<E>, Output = Field<E>>>(mut self, hasher: &H) -> Boolean<E> {
+        // Read the final value and timestamp of each cell.
+        for (address, (value, timestamp)) in self.cells.iter().enumerate() {
+            self.reads.push((
+                Field::constant(E::BaseField::from(address as u128)),
+                Field::new(Mode::Private, *value),
+                Field::new(Mode::Private, E::BaseField::from(*timestamp as u128)),
+            ));
+        }
+
+        // Derive the challenges from the entries.
+        let entries = self.reads.iter().chain(&self.writes);
+        let transcript = entries.flat_map(|(address, value, timestamp)| [address, value, timestamp]);
+        let alpha = hasher.hash(&transcript.cloned().collect::<Vec<_>>());
+        let gamma = hasher.hash(&[alpha.clone()]);
+
+        // Compute the grand product of the fingerprints of the given entries.
+        let alpha_squared = alpha.square();
+        let grand_product = |entries: &[Entry<E>]| {
+            entries.iter().fold(Field::one(), |product, (address, value, timestamp)| {
+                product * (&gamma - &(address + &alpha * value + &alpha_squared * timestamp))
+            })
+        };
+
+        grand_product(&self.reads).is_equal(&grand_product(&self.writes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::Poseidon, memory::tests::sample_values};
+    use snarkvm_circuits_types::environment::Circuit;
+
+    const SIZE: usize = 8;
+
+    #[test]
+    fn test_finalize() {
+        let poseidon = Poseidon::<Circuit>::new();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let mut memory = Memory::<Circuit>::new(mode, sample_values(SIZE));
+            let new_values = sample_values(SIZE);
+            for (i, value) in new_values.iter().enumerate().filter(|(i, _)| i % 3 != 0) {
+                memory.write(&Field::new(Mode::Private, (i as u128).into()), &Field::new(mode, *value));
+            }
+            for i in (0..SIZE).rev() {
+                memory.read(&Field::new(Mode::Private, (i as u128).into()));
+            }
+
+            Circuit::scope(format!("Finalize {mode}"), || {
+                let candidate = memory.finalize(&poseidon);
+                assert!(candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_finalize_without_accesses() {
+        let memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        assert!(memory.finalize(&Poseidon::<Circuit>::new()).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_finalize_fails_on_incorrect_value() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        memory.write(&Field::new(Mode::Private, 3u128.into()), &Field::new(Mode::Private, 7u128.into()));
+
+        // Witness a read of a value that was never written.
+        memory.cells[3].0 = 8u128.into();
+        let candidate = memory.read(&Field::new(Mode::Private, 3u128.into()));
+        assert_eq!(<Circuit as Environment>::BaseField::from(8u128), candidate.eject_value());
+        assert!(!memory.finalize(&Poseidon::<Circuit>::new()).eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_finalize_fails_on_stale_value() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        let initial_value = memory.cells[5];
+        memory.write(&Field::new(Mode::Private, 5u128.into()), &Field::new(Mode::Private, 7u128.into()));
+
+        // Witness a read of the initial value, which has since been overwritten.
+        memory.cells[5] = initial_value;
+        memory.read(&Field::new(Mode::Private, 5u128.into()));
+        assert!(Circuit::is_satisfied());
+        assert!(!memory.finalize(&Poseidon::<Circuit>::new()).eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_finalize_fails_on_future_timestamp() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+
+        // Witness a read with a timestamp that is not less than the current timestamp.
+        memory.cells[2].1 = 1;
+        memory.read(&Field::new(Mode::Private, 2u128.into()));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_finalize_fails_on_address_exceeding_size() {
+        let mut memory = Memory::<Circuit>::new(Mode::Private, sample_values(SIZE));
+        memory.read(&Field::new(Mode::Private, (SIZE as u128).into()));
+        assert!(Circuit::is_satisfied());
+        assert!(!memory.finalize(&Poseidon::<Circuit>::new()).eject_value());
+        Circuit::reset();
+    }
+}
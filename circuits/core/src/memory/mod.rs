@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod access;
+pub mod finalize;
+
+#[cfg(test)]
+use snarkvm_circuits_types::environment::assert_scope;
+
+use crate::Hash;
+use snarkvm_circuits_types::{Boolean, Field, environment::prelude::*};
+
+/// The number of bits in a memory timestamp, which bounds the number of accesses to a memory.
+const TIMESTAMP_SIZE_IN_BITS: usize = 32;
+
+/// An `(address, value, timestamp)` tuple, read from or written to memory.
+type Entry<E> = (Field<E>, Field<E>, Field<E>);
+
+///
+/// A random-access memory of field elements, checked offline.
+///
+/// Each access reads the value and timestamp last written to an address, and writes back
+/// a value with the current timestamp, which is a constant that increases with each access.
+/// Rather than selecting the value from every cell, the read value and timestamp are witnessed,
+/// and the memory is consistent if, once each cell is read a final time, the multiset of reads
+/// equals the multiset of writes. This costs a constant number of constraints per access,
+/// plus a single consistency check that is linear in the size of the memory.
+///
+/// A read-only memory (ROM) is a memory that is never written to.
+///
+pub struct Memory<E: Environment> {
+    /// The value and timestamp of each cell, used to witness the reads.
+    cells: Vec<(E::BaseField, u64)>,
+    /// The entries read from memory.
+    reads: Vec<Entry<E>>,
+    /// The entries written to memory.
+    writes: Vec<Entry<E>>,
+    /// The timestamp of the next access.
+    timestamp: u64,
+}
+
+impl<E: Environment> Memory<E> {
+    /// Initializes a memory with the given values, where the value at index `i` is stored at address `i`.
+    pub fn from_values(values: Vec<Field<E>>) -> Self {
+        let cells = values.iter().map(|value| (value.eject_value(), 0)).collect();
+        // Write the initial value of each cell, at timestamp zero.
+        let writes = values
+            .into_iter()
+            .enumerate()
+            .map(|(address, value)| (Field::constant(E::BaseField::from(address as u128)), value, Field::zero()))
+            .collect();
+
+        Self { cells, reads: Vec::new(), writes, timestamp: 1 }
+    }
+
+    /// Returns the number of cells in the memory.
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of reads and writes to the memory.
+    pub fn num_accesses(&self) -> u64 {
+        self.timestamp - 1
+    }
+
+    /// Returns the index of the cell at the given address, if it is within the memory.
+    fn cell_index(&self, address: &Field<E>) -> Option<usize> {
+        let address = address.eject_value().to_repr();
+        match address.as_ref().iter().skip(1).all(|limb| *limb == 0) {
+            true => address.as_ref().first().and_then(|limb| usize::try_from(*limb).ok()).filter(|i| *i < self.size()),
+            false => None,
+        }
+    }
+}
+
+impl<E: Environment> Inject for Memory<E> {
+    type Primitive = Vec<E::BaseField>;
+
+    /// Initializes a memory from the given mode and initial values.
+    fn new(mode: Mode, values: Self::Primitive) -> Self {
+        Self::from_values(Inject::new(mode, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    pub(super) fn sample_values(size: usize) -> Vec<<Circuit as Environment>::BaseField> {
+        (0..size).map(|_| UniformRand::rand(&mut test_rng())).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let values = sample_values(16);
+            Circuit::scope(format!("New {mode}"), || {
+                let memory = Memory::<Circuit>::new(mode, values.clone());
+                assert_eq!(16, memory.size());
+                assert_eq!(0, memory.num_accesses());
+                assert_eq!(values, memory.writes.iter().map(|(_, value, _)| value.eject_value()).collect::<Vec<_>>());
+                assert!(memory.reads.is_empty());
+            });
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_cell_index() {
+        let memory = Memory::<Circuit>::new(Mode::Private, sample_values(4));
+        for i in 0..4 {
+            assert_eq!(Some(i), memory.cell_index(&Field::constant((i as u128).into())));
+        }
+        assert_eq!(None, memory.cell_index(&Field::constant(4u128.into())));
+        assert_eq!(None, memory.cell_index(&Field::constant(-<Circuit as Environment>::BaseField::one())));
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> SparseMerklePath<E> {
+    ///
+    /// Returns `true` if the Merkle path proves the given `key` has the given `value` in the sparse Merkle map
+    /// with the given `root`, matching `SparseMerkleMap::verify` in `algorithms`.
+    /// If the value is `None`, returns `true` if the Merkle path proves the given `key` is not in the map.
+    ///
+    pub fn verify_entry<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        root: &Field<E>,
+        key: &Field<E>,
+        value: Option<&Field<E>>,
+    ) -> Boolean<E> {
+        let candidate_root = self.compute_root(hasher, key, Self::entry_hash(hasher, key, value));
+        Self::is_canonical_key(key) & root.is_equal(&candidate_root)
+    }
+
+    ///
+    /// Returns `true` if the Merkle path proves that setting the given `key` from `old_value` to `new_value`
+    /// changes the root of the sparse Merkle map from `old_root` to `new_root`,
+    /// matching `SparseMerkleMap::verify_update` in `algorithms`. An `old_value` of `None` is an insertion.
+    ///
+    pub fn verify_update<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        old_root: &Field<E>,
+        new_root: &Field<E>,
+        key: &Field<E>,
+        old_value: Option<&Field<E>>,
+        new_value: &Field<E>,
+    ) -> Boolean<E> {
+        // Compute the candidate roots before and after the write.
+        // Note: As a write only changes the leaf of the key, both roots are computed from the same path.
+        let old_candidate = self.compute_root(hasher, key, Self::entry_hash(hasher, key, old_value));
+        let new_candidate = self.compute_root(hasher, key, Self::entry_hash(hasher, key, Some(new_value)));
+
+        Self::is_canonical_key(key) & old_root.is_equal(&old_candidate) & new_root.is_equal(&new_candidate)
+    }
+
+    /// Returns the leaf hash of the given entry, which is the hash of the empty leaf if the value is `None`.
+    fn entry_hash<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        hasher: &H,
+        key: &Field<E>,
+        value: Option<&Field<E>>,
+    ) -> Field<E> {
+        match value {
+            Some(value) => hasher.hash(&[node_to_bits_le(key), node_to_bits_le(value)].concat()),
+            None => Self::empty_leaf_hash(hasher),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BHP;
+    use snarkvm_algorithms::{
+        crh::BHPCRH,
+        merkle_tree::{MerkleTreeParameters, SparseMerkleMap},
+        MerkleParameters,
+    };
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use std::sync::Arc;
+
+    const DEPTH: usize = 8;
+    const MESSAGE: &str = "SparseMerkleMapCircuit0";
+
+    type BaseField = <Circuit as Environment>::BaseField;
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+    type Parameters = MerkleTreeParameters<BHPCRH<Projective, 8, 32>, DEPTH>;
+    type NativeMap = SparseMerkleMap<Parameters, BaseField>;
+
+    /// Returns a sparse Merkle map with two entries, whose keys share their `DEPTH` least significant bits,
+    /// and the entries.
+    fn sample_map() -> (NativeMap, [(BaseField, BaseField); 2]) {
+        let key = BaseField::from(u32::rand(&mut test_rng()));
+        let colliding_key = key + BaseField::from(1u64 << DEPTH);
+        let entries = [(key, BaseField::rand(&mut test_rng())), (colliding_key, BaseField::rand(&mut test_rng()))];
+
+        let mut map = NativeMap::new(Arc::new(Parameters::setup(MESSAGE))).unwrap();
+        for (key, value) in entries {
+            assert_eq!(None, map.insert(key, value).unwrap());
+        }
+        (map, entries)
+    }
+
+    /// Checks the given update proof in a fresh circuit, and resets the circuit.
+    fn check_update<H: Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>>(
+        name: &str,
+        mode: Mode,
+        hasher: &H,
+        path: &[BaseField],
+        (old_root, new_root): (BaseField, BaseField),
+        (key, old_value, new_value): (BaseField, Option<BaseField>, BaseField),
+        expected: bool,
+    ) {
+        Circuit::scope(format!("SparseMerkleMap {name} {mode}"), || {
+            let path = SparseMerklePath::<Circuit>::new(mode, path.to_vec());
+            let (old_root, new_root) = (Field::new(mode, old_root), Field::new(mode, new_root));
+            let (key, new_value) = (Field::new(mode, key), Field::new(mode, new_value));
+            let old_value = old_value.map(|old_value| Field::new(mode, old_value));
+
+            let candidate = path.verify_update(hasher, &old_root, &new_root, &key, old_value.as_ref(), &new_value);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_entry() {
+        let hasher = BHP::<Circuit, 8, 32>::setup(MESSAGE);
+        let (map, entries) = sample_map();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for ((key, value), (other_key, other_value)) in [(entries[0], entries[1]), (entries[1], entries[0])] {
+                let native_path = map.prove(&key);
+                assert!(NativeMap::verify(map.root(), &key, Some(&value), &native_path).unwrap());
+                assert!(!NativeMap::verify(map.root(), &other_key, Some(&other_value), &native_path).unwrap());
+
+                let path = SparseMerklePath::<Circuit>::new(mode, native_path.path);
+                let root = Field::new(mode, *map.root());
+                let (key, value) = (Field::new(mode, key), Field::new(mode, value));
+                let (other_key, other_value) = (Field::new(mode, other_key), Field::new(mode, other_value));
+
+                Circuit::scope(format!("SparseMerkleMap entry {mode}"), || {
+                    // Check the path against the entry.
+                    assert!(path.verify_entry(&hasher, &root, &key, Some(&value)).eject_value());
+                    // Check the path against the entry whose key shares its `DEPTH` least significant bits.
+                    assert!(!path.verify_entry(&hasher, &root, &other_key, Some(&other_value)).eject_value());
+                    // Check the path as a proof of absence.
+                    assert!(!path.verify_entry(&hasher, &root, &key, None).eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_update() {
+        let hasher = BHP::<Circuit, 8, 32>::setup(MESSAGE);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let (mut map, [(key, old_value), (colliding_key, colliding_value)]) = sample_map();
+
+            // Overwrite the value of an existing key.
+            let new_value = BaseField::rand(&mut test_rng());
+            let old_root = *map.root();
+            let native_path = map.prove(&key);
+            assert_eq!(Some(old_value), map.insert(key, new_value).unwrap());
+            assert_eq!(Some(&colliding_value), map.get(&colliding_key));
+            let roots = (old_root, *map.root());
+            assert!(
+                NativeMap::verify_update(&roots.0, &roots.1, &key, Some(&old_value), &new_value, &native_path).unwrap()
+            );
+
+            let path = &native_path.path;
+            check_update("update", mode, &hasher, path, roots, (key, Some(old_value), new_value), true);
+            // Check the path against swapped roots.
+            let swapped_roots = (roots.1, roots.0);
+            check_update("update", mode, &hasher, path, swapped_roots, (key, Some(old_value), new_value), false);
+            // Check the path against the key that shares its `DEPTH` least significant bits.
+            let update = (colliding_key, Some(colliding_value), new_value);
+            check_update("update", mode, &hasher, path, roots, update, false);
+
+            // Remove the colliding key, and insert it again.
+            assert_eq!(Some(colliding_value), map.remove(&colliding_key).unwrap());
+            let old_root = *map.root();
+            let native_path = map.prove(&colliding_key);
+            assert!(NativeMap::verify(&old_root, &colliding_key, None, &native_path).unwrap());
+            assert_eq!(None, map.insert(colliding_key, colliding_value).unwrap());
+            let roots = (old_root, *map.root());
+            assert!(
+                NativeMap::verify_update(&roots.0, &roots.1, &colliding_key, None, &colliding_value, &native_path)
+                    .unwrap()
+            );
+
+            let path = &native_path.path;
+            check_update("insert", mode, &hasher, path, roots, (colliding_key, None, colliding_value), true);
+            // Check the path as an overwrite.
+            let update = (colliding_key, Some(colliding_value), colliding_value);
+            check_update("insert", mode, &hasher, path, roots, update, false);
+            // Check the path as an insertion of the key that shares its `DEPTH` least significant bits.
+            check_update("insert", mode, &hasher, path, roots, (key, None, colliding_value), false);
+        }
+    }
+}
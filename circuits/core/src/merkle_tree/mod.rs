@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod mapping;
+pub mod sparse;
+pub mod verify;
+
+pub use sparse::SparseMerklePath;
+
+#[cfg(test)]
+use snarkvm_circuits_types::environment::assert_scope;
+
+use crate::Hash;
+use snarkvm_circuits_types::{environment::prelude::*, Boolean, Field};
+
+/// A Merkle path from a leaf to the root of a Merkle tree of depth `DEPTH`,
+/// matching `MerklePath` in `algorithms`.
+pub struct MerklePath<E: Environment, const DEPTH: usize> {
+    /// The position of the leaf, as little-endian bits, where `true` denotes a right child.
+    leaf_index: Vec<Boolean<E>>,
+    /// The sibling hashes of the path, from the leaf level to the root.
+    siblings: Vec<Field<E>>,
+}
+
+/// Returns the little-endian bits of the byte representation of the given node.
+fn node_to_bits_le<E: Environment>(node: &Field<E>) -> Vec<Boolean<E>> {
+    let mut bits_le = node.to_bits_le();
+    bits_le.resize((E::BaseField::size_in_bits() + 7) / 8 * 8, Boolean::constant(false));
+    bits_le
+}
+
+impl<E: Environment, const DEPTH: usize> Inject for MerklePath<E, DEPTH> {
+    type Primitive = (u64, Vec<E::BaseField>);
+
+    /// Initializes a Merkle path from the given mode and `(leaf_index, siblings)`.
+    fn new(mode: Mode, (leaf_index, siblings): Self::Primitive) -> Self {
+        // Ensure the path has the expected depth.
+        if DEPTH > u64::BITS as usize || siblings.len() != DEPTH {
+            E::halt(format!("Merkle path must contain {DEPTH} siblings, found {}", siblings.len()))
+        }
+        // Ensure the leaf index is within the tree.
+        if DEPTH < u64::BITS as usize && leaf_index >> DEPTH != 0 {
+            E::halt(format!("Leaf index {leaf_index} exceeds a Merkle tree of depth {DEPTH}"))
+        }
+
+        Self {
+            leaf_index: (0..DEPTH).map(|i| Boolean::new(mode, (leaf_index >> i) & 1 == 1)).collect(),
+            siblings: Inject::new(mode, siblings),
+        }
+    }
+}
+
+impl<E: Environment, const DEPTH: usize> Eject for MerklePath<E, DEPTH> {
+    type Primitive = (u64, Vec<E::BaseField>);
+
+    ///
+    /// Ejects the mode of the Merkle path.
+    ///
+    fn eject_mode(&self) -> Mode {
+        (&self.leaf_index, &self.siblings).eject_mode()
+    }
+
+    ///
+    /// Ejects the Merkle path as `(leaf_index, siblings)`.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        let (leaf_index, siblings) = (&self.leaf_index, &self.siblings).eject_value();
+        let leaf_index = leaf_index.iter().rev().fold(0u64, |index, bit| (index << 1) | *bit as u64);
+        (leaf_index, siblings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+    const DEPTH: usize = 16;
+
+    fn check_new(mode: Mode, num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let leaf_index = u64::rand(rng) % (1 << DEPTH);
+            let siblings = (0..DEPTH).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("New {mode} {i}"), || {
+                let candidate = MerklePath::<Circuit, DEPTH>::new(mode, (leaf_index, siblings.clone()));
+                assert_eq!((leaf_index, siblings), candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_merkle_path_new() {
+        check_new(Mode::Constant, 32, 0, 0, 0);
+        check_new(Mode::Public, 0, 32, 0, 16);
+        check_new(Mode::Private, 0, 0, 32, 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merkle_path_new_invalid_depth() {
+        let _candidate = MerklePath::<Circuit, DEPTH>::new(Mode::Private, (0, vec![Default::default(); DEPTH - 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merkle_path_new_invalid_leaf_index() {
+        let siblings = vec![Default::default(); DEPTH];
+        let _candidate = MerklePath::<Circuit, DEPTH>::new(Mode::Private, (1 << DEPTH, siblings));
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits hashed for an empty leaf, matching `MerkleParameters::hash_empty` in `algorithms`.
+const EMPTY_LEAF_SIZE_IN_BITS: usize = 512;
+
+/// A Merkle path from the leaf of a key to the root of a sparse Merkle tree,
+/// matching `SparseMerklePath` in `algorithms`.
+///
+/// The depth of the tree is the bit size of the base field, and the position of the leaf is the key itself.
+pub struct SparseMerklePath<E: Environment> {
+    /// The sibling hashes of the path, from the leaf level to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment> Inject for SparseMerklePath<E> {
+    type Primitive = Vec<E::BaseField>;
+
+    /// Initializes a sparse Merkle path from the given mode and siblings.
+    fn new(mode: Mode, siblings: Self::Primitive) -> Self {
+        // Ensure the path has one sibling per bit of a key.
+        if siblings.len() != E::BaseField::size_in_bits() {
+            E::halt(format!(
+                "Sparse Merkle path must contain {} siblings, found {}",
+                E::BaseField::size_in_bits(),
+                siblings.len()
+            ))
+        }
+
+        Self { siblings: Inject::new(mode, siblings) }
+    }
+}
+
+impl<E: Environment> Eject for SparseMerklePath<E> {
+    type Primitive = Vec<E::BaseField>;
+
+    ///
+    /// Ejects the mode of the sparse Merkle path.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.siblings.eject_mode()
+    }
+
+    ///
+    /// Ejects the sparse Merkle path as its siblings.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        self.siblings.eject_value()
+    }
+}
+
+impl<E: Environment> SparseMerklePath<E> {
+    ///
+    /// Returns `true` if the Merkle path proves the given `key` is in the sparse Merkle tree
+    /// with the given `root`, matching `SparseMerkleTree::verify_membership` in `algorithms`.
+    ///
+    pub fn verify_membership<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        root: &Field<E>,
+        key: &Field<E>,
+    ) -> Boolean<E> {
+        let leaf_hash = hasher.hash(&node_to_bits_le(key));
+        Self::is_canonical_key(key) & root.is_equal(&self.compute_root(hasher, key, leaf_hash))
+    }
+
+    ///
+    /// Returns `true` if the Merkle path proves the given `key` is not in the sparse Merkle tree
+    /// with the given `root`, matching `SparseMerkleTree::verify_non_membership` in `algorithms`.
+    ///
+    pub fn verify_non_membership<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        root: &Field<E>,
+        key: &Field<E>,
+    ) -> Boolean<E> {
+        Self::is_canonical_key(key) & root.is_equal(&self.compute_root(hasher, key, Self::empty_leaf_hash(hasher)))
+    }
+
+    /// Returns the candidate root, computed from the given leaf hash at the leaf of the given key.
+    pub(super) fn compute_root<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        key: &Field<E>,
+        leaf_hash: Field<E>,
+    ) -> Field<E> {
+        // Compute the candidate root, from the leaf level to the root.
+        key.to_bits_le().iter().zip_eq(&self.siblings).fold(leaf_hash, |node, (is_right, sibling)| {
+            // Order the node and its sibling, based on the position of the node.
+            let left = Field::ternary(is_right, sibling, &node);
+            let right = Field::ternary(is_right, &node, sibling);
+            // Compute the hash of the parent node.
+            hasher.hash(&[node_to_bits_le(&left), node_to_bits_le(&right)].concat())
+        })
+    }
+
+    ///
+    /// Returns `true` if the bits of the given key, which give the position of its leaf, are less than the modulus.
+    ///
+    /// As `Field::to_bits_le` only constrains the bits to sum to the key, a prover could otherwise
+    /// take the path of the key plus the modulus, which is the leaf of a different key.
+    ///
+    pub(super) fn is_canonical_key(key: &Field<E>) -> Boolean<E> {
+        let modulus_minus_one = Field::<E>::constant(-E::BaseField::one()).to_bits_le();
+        !Boolean::is_less_than_bits_le(&modulus_minus_one, &key.to_bits_le())
+    }
+
+    /// Returns the hash of an empty leaf.
+    /// Note: As the input is constant, the hash is a constant.
+    pub(super) fn empty_leaf_hash<H: Hash<Input = Boolean<E>, Output = Field<E>>>(hasher: &H) -> Field<E> {
+        hasher.hash(&vec![Boolean::constant(false); EMPTY_LEAF_SIZE_IN_BITS])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BHP;
+    use snarkvm_algorithms::{
+        crh::BHPCRH,
+        merkle_tree::{MerkleTreeParameters, SparseMerkleTree},
+        MerkleParameters,
+    };
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use std::sync::Arc;
+
+    const DEPTH: usize = 8;
+    const MESSAGE: &str = "SparseMerkleTreeCircuit0";
+
+    type BaseField = <Circuit as Environment>::BaseField;
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+    type Parameters = MerkleTreeParameters<BHPCRH<Projective, 8, 32>, DEPTH>;
+    type NativeTree = SparseMerkleTree<Parameters, BaseField>;
+
+    /// Returns a random key, and a key that shares its `DEPTH` least significant bits.
+    fn sample_colliding_keys() -> (BaseField, BaseField) {
+        let key = BaseField::from(u32::rand(&mut test_rng()));
+        (key, key + BaseField::from(1u64 << DEPTH))
+    }
+
+    #[test]
+    fn test_sparse_merkle_path_new() {
+        let siblings = (0..BaseField::size_in_bits()).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = SparseMerklePath::<Circuit>::new(mode, siblings.clone());
+            assert_eq!(siblings, candidate.eject_value());
+            assert_eq!(mode, candidate.eject_mode());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sparse_merkle_path_new_invalid_depth() {
+        let _candidate = SparseMerklePath::<Circuit>::new(Mode::Private, vec![Default::default(); DEPTH]);
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        let hasher = BHP::<Circuit, 8, 32>::setup(MESSAGE);
+
+        // Note: Both keys are stored in the tree, as their leaves are distinct.
+        let (key, colliding_key) = sample_colliding_keys();
+        let mut tree = NativeTree::new(Arc::new(Parameters::setup(MESSAGE))).unwrap();
+        assert!(tree.insert(key).unwrap());
+        assert!(tree.insert(colliding_key).unwrap());
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for (key, other_key) in [(key, colliding_key), (colliding_key, key)] {
+                let native_path = tree.prove_membership(&key).unwrap();
+                assert!(NativeTree::verify_membership(tree.root(), &key, &native_path).unwrap());
+
+                let path = SparseMerklePath::<Circuit>::new(mode, native_path.path);
+                let root = Field::new(mode, *tree.root());
+                let (key, other_key) = (Field::new(mode, key), Field::new(mode, other_key));
+
+                Circuit::scope(format!("SparseMerkleTree membership {mode}"), || {
+                    // Check the path against the key.
+                    assert!(path.verify_membership(&hasher, &root, &key).eject_value());
+                    // Check the path against the key that shares its `DEPTH` least significant bits.
+                    assert!(!path.verify_membership(&hasher, &root, &other_key).eject_value());
+                    // Check the path as a non-membership proof.
+                    assert!(!path.verify_non_membership(&hasher, &root, &key).eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_non_membership() {
+        let hasher = BHP::<Circuit, 8, 32>::setup(MESSAGE);
+
+        // Note: The colliding key is not in the tree, even though the key that shares its leaf index
+        // in a tree of depth `DEPTH` is.
+        let (key, colliding_key) = sample_colliding_keys();
+        let mut tree = NativeTree::new(Arc::new(Parameters::setup(MESSAGE))).unwrap();
+        assert!(tree.insert(key).unwrap());
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let native_path = tree.prove_non_membership(&colliding_key).unwrap();
+            assert!(NativeTree::verify_non_membership(tree.root(), &colliding_key, &native_path).unwrap());
+
+            let path = SparseMerklePath::<Circuit>::new(mode, native_path.path);
+            let root = Field::new(mode, *tree.root());
+            let (key, colliding_key) = (Field::new(mode, key), Field::new(mode, colliding_key));
+
+            Circuit::scope(format!("SparseMerkleTree non-membership {mode}"), || {
+                // Check the path against the key.
+                assert!(path.verify_non_membership(&hasher, &root, &colliding_key).eject_value());
+                // Check the path against an incorrect root.
+                let incorrect_root = Field::new(mode, UniformRand::rand(&mut test_rng()));
+                assert!(!path.verify_non_membership(&hasher, &incorrect_root, &colliding_key).eject_value());
+                // Check the path as a membership proof.
+                assert!(!path.verify_membership(&hasher, &root, &colliding_key).eject_value());
+                // Check the path against the key that is in the tree.
+                assert!(!path.verify_non_membership(&hasher, &root, &key).eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: usize> MerklePath<E, DEPTH> {
+    ///
+    /// Returns `true` if the Merkle path is valid for the given `root` and `leaf`,
+    /// where `leaf` is given as the little-endian bits of its byte representation.
+    ///
+    /// As in `MerkleTree` in `algorithms`, the leaf is hashed directly, and each inner node
+    /// is the hash of the concatenated byte representations of its children.
+    /// A path from a tree of smaller depth is padded to `DEPTH` with the empty hash as sibling,
+    /// and is verified as is, as the leaf index of its padding levels is zero.
+    ///
+    pub fn verify<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        root: &Field<E>,
+        leaf: &[Boolean<E>],
+    ) -> Boolean<E> {
+        // Compute the hash of the leaf.
+        let leaf_hash = hasher.hash(leaf);
+
+        // Check that the candidate root matches the given root.
+        root.is_equal(&self.compute_root(hasher, leaf_hash))
+    }
+
+    /// Returns the candidate root, computed from the given leaf hash and the path.
+    pub(super) fn compute_root<H: Hash<Input = Boolean<E>, Output = Field<E>>>(
+        &self,
+        hasher: &H,
+        leaf_hash: Field<E>,
+    ) -> Field<E> {
+        // Compute the candidate root, from the leaf level to the root.
+        self.leaf_index.iter().zip_eq(&self.siblings).fold(leaf_hash, |node, (is_right, sibling)| {
+            // Order the node and its sibling, based on the position of the node.
+            let left = Field::ternary(is_right, sibling, &node);
+            let right = Field::ternary(is_right, &node, sibling);
+            // Compute the hash of the parent node.
+            hasher.hash(&[node_to_bits_le(&left), node_to_bits_le(&right)].concat())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{PoseidonCRH, BHP};
+    use snarkvm_algorithms::{
+        crh::{BHPCRH, PoseidonCRH as NativePoseidonCRH},
+        merkle_tree::{MerkleTree, MerkleTreeParameters},
+        MerkleParameters,
+        CRH,
+    };
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, ToBits as TBits, UniformRand};
+
+    use std::sync::Arc;
+
+    const DEPTH: usize = 4;
+    const MESSAGE: &str = "MerkleTreeCircuit0";
+
+    type BaseField = <Circuit as Environment>::BaseField;
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_verify<P: MerkleParameters, H: Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>>(
+        mode: Mode,
+        hasher: &H,
+        num_leaves: usize,
+    ) where
+        P::H: CRH<Output = BaseField>,
+    {
+        // Construct the native Merkle tree.
+        let leaves = (0..num_leaves).map(|_| u64::rand(&mut test_rng()).to_le_bytes()).collect::<Vec<_>>();
+        let tree = MerkleTree::new(Arc::new(P::setup(MESSAGE)), &leaves).unwrap();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let native_path = tree.generate_proof(i, leaf).unwrap();
+            assert!(native_path.verify(tree.root(), leaf).unwrap());
+
+            let path = MerklePath::<Circuit, DEPTH>::new(mode, (native_path.leaf_index, native_path.path.clone()));
+            let root = Field::new(mode, *tree.root());
+            let leaf: Vec<Boolean<_>> = Inject::new(mode, leaf.to_bits_le());
+
+            Circuit::scope(format!("MerklePath {mode} {num_leaves} {i}"), || {
+                // Check the path against the root.
+                assert!(path.verify(hasher, &root, &leaf).eject_value());
+
+                // Check the path against an incorrect root.
+                let incorrect_root = Field::new(mode, UniformRand::rand(&mut test_rng()));
+                assert!(!path.verify(hasher, &incorrect_root, &leaf).eject_value());
+
+                // Check the path against an incorrect leaf.
+                let incorrect_leaf = u64::rand(&mut test_rng()).to_le_bytes().to_bits_le();
+                let incorrect_leaf: Vec<Boolean<_>> = Inject::new(mode, incorrect_leaf);
+                assert!(!path.verify(hasher, &root, &incorrect_leaf).eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_bhp() {
+        type Parameters = MerkleTreeParameters<BHPCRH<Projective, 8, 32>, DEPTH>;
+        let hasher = BHP::<Circuit, 8, 32>::setup(MESSAGE);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Note: Trees with fewer than `2^DEPTH` leaves are padded to `DEPTH`.
+            for num_leaves in [1, 3, 5, 16] {
+                check_verify::<Parameters, _>(mode, &hasher, num_leaves);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_poseidon() {
+        type Parameters = MerkleTreeParameters<NativePoseidonCRH<BaseField, 4>, DEPTH>;
+        let hasher = PoseidonCRH::<Circuit, 4>::new();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Note: Trees with fewer than `2^DEPTH` leaves are padded to `DEPTH`.
+            for num_leaves in [1, 3, 5, 16] {
+                check_verify::<Parameters, _>(mode, &hasher, num_leaves);
+            }
+        }
+    }
+}
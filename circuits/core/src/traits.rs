@@ -31,3 +31,32 @@ pub trait HashUncompressed {
     /// Returns the hash of the given input.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output;
 }
+
+/// A trait for a hash function that projects onto the scalar field.
+pub trait HashToScalar {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given input on the scalar field.
+    fn hash_to_scalar(&self, input: &[Self::Input]) -> Self::Output;
+}
+
+/// A trait for a pseudorandom function.
+pub trait PRF {
+    type Seed;
+    type Input;
+    type Output;
+
+    /// Returns the output for the given seed and input.
+    fn evaluate(&self, seed: &Self::Seed, input: &[Self::Input]) -> Self::Output;
+}
+
+/// A trait for a commitment scheme.
+pub trait Commit {
+    type Input;
+    type Output;
+    type Randomness;
+
+    /// Returns the commitment to the given input and randomness.
+    fn commit(&self, input: &[Self::Input], randomness: &Self::Randomness) -> Self::Output;
+}
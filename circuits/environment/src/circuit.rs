@@ -26,6 +26,7 @@ use std::rc::Rc;
 thread_local! {
     pub(super) static CIRCUIT: Rc<RefCell<R1CS<Fq>>> = Rc::new(RefCell::new(R1CS::<Fq>::new()));
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static TRACE: Rc<RefCell<Option<ReplayLog<Fq>>>> = Rc::new(RefCell::new(None));
     pub(super) static ZERO: LinearCombination<Fq> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Fq> = LinearCombination::one();
 }
@@ -33,6 +34,17 @@ thread_local! {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Circuit;
 
+impl Circuit {
+    /// Appends the event to the replay log, if tracing is enabled.
+    fn trace<Fn: FnOnce() -> TraceEvent<Fq>>(event: Fn) {
+        TRACE.with(|trace| {
+            if let Some(log) = (**trace).borrow_mut().as_mut() {
+                log.push(event())
+            }
+        })
+    }
+}
+
 impl Environment for Circuit {
     type Affine = EdwardsAffine;
     type AffineParameters = EdwardsParameters;
@@ -57,10 +69,13 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| match mode {
-                    Mode::Constant => (**circuit).borrow_mut().new_constant(value),
-                    Mode::Public => (**circuit).borrow_mut().new_public(value),
-                    Mode::Private => (**circuit).borrow_mut().new_private(value),
+                CIRCUIT.with(|circuit| {
+                    Self::trace(|| TraceEvent::new_variable(mode, value, (**circuit).borrow().scope()));
+                    match mode {
+                        Mode::Constant => (**circuit).borrow_mut().new_constant(value),
+                        Mode::Public => (**circuit).borrow_mut().new_public(value),
+                        Mode::Private => (**circuit).borrow_mut().new_private(value),
+                    }
                 })
             } else {
                 Self::halt("Tried to initialize a new variable in witness mode")
@@ -70,6 +85,8 @@ impl Environment for Circuit {
 
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+        Self::trace(|| TraceEvent::Witness(mode, CIRCUIT.with(|circuit| (**circuit).borrow().scope())));
+
         IN_WITNESS.with(|in_witness| {
             // Set the entire environment to witness mode.
             *(**in_witness).borrow_mut() = true;
@@ -122,11 +139,13 @@ impl Environment for Circuit {
                     if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
                         Self::halt(error)
                     }
+                    Self::trace(|| TraceEvent::PushScope(name.clone()));
 
                     // Run the logic.
                     let output = logic();
 
                     // Return the entire environment to the previous scope.
+                    Self::trace(|| TraceEvent::PopScope(name.clone()));
                     if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
                         Self::halt(error)
                     }
@@ -170,6 +189,7 @@ impl Environment for Circuit {
                         false => {
                             // Construct the constraint object.
                             let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
+                            Self::trace(|| TraceEvent::new_constraint(&constraint));
                             // Append the constraint.
                             (**circuit).borrow_mut().enforce(constraint)
                         }
@@ -189,6 +209,31 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
     }
 
+    /// Attaches the given annotation to every constraint enforced in the given logic,
+    /// to be reported by `diagnostics` if the constraint is unsatisfied.
+    fn annotate<Fn, Output>(annotation: Annotation, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        CIRCUIT.with(|circuit| {
+            // Set the annotation for the constraints enforced in the logic.
+            let previous = (**circuit).borrow_mut().set_annotation(Some(Rc::new(annotation)));
+
+            // Run the logic.
+            let output = logic();
+
+            // Restore the previous annotation, in case the annotations are nested.
+            (**circuit).borrow_mut().set_annotation(previous);
+
+            output
+        })
+    }
+
+    /// Returns a diagnostic for each unsatisfied constraint in the circuit.
+    fn diagnostics() -> Vec<Diagnostic> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().diagnostics())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> usize {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
@@ -264,8 +309,37 @@ impl Environment for Circuit {
         panic!("{}", &error)
     }
 
+    /// Starts recording every injection and constraint in the circuit into a replay log.
+    fn start_tracing() {
+        TRACE.with(|trace| *(**trace).borrow_mut() = Some(ReplayLog::new()))
+    }
+
+    /// Stops recording, and returns the replay log, if tracing was started.
+    fn stop_tracing() -> Option<ReplayLog<Self::BaseField>> {
+        TRACE.with(|trace| (**trace).borrow_mut().take())
+    }
+
+    /// Clears the circuit, and reconstructs the circuit recorded in the given replay log.
+    /// Returns an error, and leaves the circuit unchanged, if the replay log is inconsistent.
+    fn replay(log: &ReplayLog<Self::BaseField>) -> Result<(), String> {
+        let r1cs = log.to_r1cs()?;
+        CIRCUIT.with(|circuit| *(**circuit).borrow_mut() = r1cs);
+        Ok(())
+    }
+
+    /// Returns the values of the public and private variables in the circuit.
+    fn to_assignment() -> Assignment<Self::BaseField> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().to_assignment())
+    }
+
     /// Clears the circuit and initializes an empty environment.
+    /// If tracing is enabled, the replay log is cleared as well.
     fn reset() {
+        TRACE.with(|trace| {
+            if let Some(log) = (**trace).borrow_mut().as_mut() {
+                log.clear()
+            }
+        });
         CIRCUIT.with(|circuit| {
             *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
             assert_eq!(0, (**circuit).borrow().num_constants());
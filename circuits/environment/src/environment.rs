@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Inject, LinearCombination, Mode, Variable};
+use crate::{
+    Annotation,
+    Assignment,
+    ConstraintBudget,
+    Count,
+    Diagnostic,
+    Inject,
+    LinearCombination,
+    Mode,
+    ReplayLog,
+    Variable,
+};
 use snarkvm_curves::{AffineCurve, TwistedEdwardsParameters};
 use snarkvm_fields::traits::*;
 
@@ -74,6 +85,15 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Attaches the given annotation to every constraint enforced in the given logic,
+    /// to be reported by `diagnostics` if the constraint is unsatisfied.
+    fn annotate<Fn, Output>(annotation: Annotation, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Returns a diagnostic for each unsatisfied constraint in the environment.
+    fn diagnostics() -> Vec<Diagnostic>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> usize;
 
@@ -104,6 +124,26 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
     /// Returns the number of gates for the current scope.
     fn num_gates_in_scope() -> usize;
 
+    /// Asserts that the current scope has exactly the given number of constants, public variables,
+    /// private variables, and constraints, and that the constraints in the current scope are satisfied.
+    fn expect_scope(num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let expected = Count::new(num_constants, num_public, num_private, num_constraints);
+        let candidate = Count::of_scope::<Self>();
+        assert_eq!(expected, candidate, "Expected the scope to have {}, found {}", expected, candidate);
+        assert!(Self::is_satisfied_in_scope(), "Expected the constraints in the scope to be satisfied");
+    }
+
+    /// Returns a guard that asserts, when dropped, that exactly the given number of constants, public variables,
+    /// private variables, and constraints were synthesized in the environment during its lifetime.
+    fn expect_budget(
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) -> ConstraintBudget<Self> {
+        ConstraintBudget::new(Count::new(num_constants, num_public, num_private, num_constraints))
+    }
+
     /// A helper method to recover the y-coordinate given the x-coordinate for
     /// a twisted Edwards point, returning the affine curve point.
     fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine;
@@ -113,6 +153,19 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
         panic!("{}", message.into())
     }
 
+    /// Starts recording every injection and constraint in the environment into a replay log.
+    fn start_tracing();
+
+    /// Stops recording, and returns the replay log, if tracing was started.
+    fn stop_tracing() -> Option<ReplayLog<Self::BaseField>>;
+
+    /// Clears the environment, and reconstructs the circuit recorded in the given replay log.
+    /// Returns an error, and leaves the environment unchanged, if the replay log is inconsistent.
+    fn replay(log: &ReplayLog<Self::BaseField>) -> Result<(), String>;
+
+    /// Returns the values of the public and private variables in the environment.
+    fn to_assignment() -> Assignment<Self::BaseField>;
+
     /// Clears and initializes an empty environment.
     fn reset();
 }
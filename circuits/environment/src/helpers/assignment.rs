@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::*;
+
+///
+/// The values of the public and private variables of a circuit, without the structure of its constraints.
+///
+/// An assignment is produced by synthesizing a circuit on its real inputs, and can be serialized
+/// and sent to another process, which substitutes it into a replay log of the same circuit.
+/// This allows witness generation and proving to run on separate machines.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Assignment<F: PrimeField> {
+    public: Vec<F>,
+    private: Vec<F>,
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Initializes a new assignment from the given public and private values.
+    /// Note: The public values include the leading public variable of one.
+    pub fn new(public: Vec<F>, private: Vec<F>) -> Self {
+        Self { public, private }
+    }
+
+    /// Returns the values of the public variables, in the order they were allocated.
+    pub fn public(&self) -> &[F] {
+        &self.public
+    }
+
+    /// Returns the values of the private variables, in the order they were allocated.
+    pub fn private(&self) -> &[F] {
+        &self.private
+    }
+
+    /// Returns the number of public values, including the leading public variable of one.
+    pub fn num_public(&self) -> usize {
+        self.public.len()
+    }
+
+    /// Returns the number of private values.
+    pub fn num_private(&self) -> usize {
+        self.private.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Circuit};
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_utilities::serialize::*;
+
+    const ITERATIONS: usize = 16;
+
+    /// Computes `base^(2^ITERATIONS)` by repeated squaring, returning the result.
+    fn create_example_circuit(base: Fq) -> Variable<Fq> {
+        let mut accumulator = Circuit::new_variable(Mode::Public, base);
+        for i in 0..ITERATIONS {
+            accumulator = Circuit::scope(format!("square_{i}"), || {
+                let square = Circuit::new_variable(Mode::Private, accumulator.value().square());
+                Circuit::enforce(|| (&accumulator, &accumulator, &square));
+                square
+            });
+        }
+        accumulator
+    }
+
+    #[test]
+    fn test_assignment() {
+        // Record the structure of the circuit on a placeholder input, as a prover would during setup.
+        Circuit::reset();
+        Circuit::start_tracing();
+        create_example_circuit(Fq::one());
+        let log = Circuit::stop_tracing().unwrap();
+
+        // Synthesize the circuit on the real input, and serialize its assignment.
+        Circuit::reset();
+        let base = Fq::one().double().double() + Fq::one();
+        let expected = create_example_circuit(base).value();
+        assert!(Circuit::is_satisfied());
+        let assignment = Circuit::to_assignment();
+        assert_eq!(2, assignment.num_public());
+        assert_eq!(ITERATIONS, assignment.num_private());
+
+        let mut bytes = Vec::new();
+        assignment.serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), assignment.serialized_size());
+
+        // Substitute the deserialized assignment into the replay log, without rerunning the circuit logic.
+        let candidate = Assignment::<Fq>::deserialize(&mut &bytes[..]).unwrap();
+        assert_eq!(assignment, candidate);
+
+        Circuit::reset();
+        Circuit::replay(&log.assign(&candidate).unwrap()).unwrap();
+        assert!(Circuit::is_satisfied());
+        assert_eq!(assignment, Circuit::to_assignment());
+        assert_eq!(expected, *candidate.private().last().unwrap());
+        assert_eq!(2, Circuit::num_public());
+        assert_eq!(ITERATIONS, Circuit::num_constraints());
+    }
+
+    #[test]
+    fn test_assignment_mismatch() {
+        Circuit::reset();
+        Circuit::start_tracing();
+        create_example_circuit(Fq::one());
+        let log = Circuit::stop_tracing().unwrap();
+        let assignment = Circuit::to_assignment();
+
+        // Ensure an assignment with the wrong number of values is rejected.
+        let public = assignment.public().to_vec();
+        let private = assignment.private()[1..].to_vec();
+        assert!(log.assign(&Assignment::new(public.clone(), private)).is_err());
+        assert!(log.assign(&Assignment::new(public[1..].to_vec(), assignment.private().to_vec())).is_err());
+
+        // Ensure an assignment with a leading public value other than one is rejected.
+        let mut public = public;
+        public[0] = Fq::zero();
+        assert!(log.assign(&Assignment::new(public, assignment.private().to_vec())).is_err());
+
+        // Ensure an assignment that does not satisfy the circuit is replayed, and is unsatisfied.
+        let mut private = assignment.private().to_vec();
+        private[0] = private[0].double();
+        let log = log.assign(&Assignment::new(assignment.public().to_vec(), private)).unwrap();
+        Circuit::replay(&log).unwrap();
+        assert!(!Circuit::is_satisfied());
+    }
+}
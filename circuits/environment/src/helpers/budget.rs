@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Environment;
+
+use core::{fmt, marker::PhantomData};
+
+/// The number of constants, public variables, private variables, and constraints in a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Count {
+    pub num_constants: usize,
+    pub num_public: usize,
+    pub num_private: usize,
+    pub num_constraints: usize,
+}
+
+impl Count {
+    /// Initializes a new count.
+    pub const fn new(num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) -> Self {
+        Self { num_constants, num_public, num_private, num_constraints }
+    }
+
+    /// Returns the count of the entire environment.
+    pub fn of_environment<E: Environment>() -> Self {
+        Self::new(E::num_constants(), E::num_public(), E::num_private(), E::num_constraints())
+    }
+
+    /// Returns the count of the current scope in the environment.
+    pub fn of_scope<E: Environment>() -> Self {
+        Self::new(
+            E::num_constants_in_scope(),
+            E::num_public_in_scope(),
+            E::num_private_in_scope(),
+            E::num_constraints_in_scope(),
+        )
+    }
+
+    /// Returns the difference between this count and the given earlier count.
+    fn since(&self, earlier: &Self) -> Self {
+        Self::new(
+            self.num_constants.saturating_sub(earlier.num_constants),
+            self.num_public.saturating_sub(earlier.num_public),
+            self.num_private.saturating_sub(earlier.num_private),
+            self.num_constraints.saturating_sub(earlier.num_constraints),
+        )
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "(Constants: {}, Public: {}, Private: {}, Constraints: {})",
+            self.num_constants, self.num_public, self.num_private, self.num_constraints
+        )
+    }
+}
+
+///
+/// A guard that asserts, when dropped, that the circuit synthesized during its lifetime
+/// has exactly the expected number of constants, public variables, private variables, and constraints.
+///
+/// This allows gadget libraries to guard against constraint regressions in their own test suites.
+/// If the thread is already panicking, the check is skipped.
+///
+#[must_use = "the budget is checked when the guard is dropped"]
+pub struct ConstraintBudget<E: Environment> {
+    /// The expected count.
+    expected: Count,
+    /// The count of the environment when the guard was created.
+    start: Count,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> ConstraintBudget<E> {
+    /// Initializes a guard that expects the given count to be synthesized during its lifetime.
+    pub fn new(expected: Count) -> Self {
+        Self { expected, start: Count::of_environment::<E>(), _phantom: PhantomData }
+    }
+
+    /// Returns the expected count.
+    pub fn expected(&self) -> Count {
+        self.expected
+    }
+
+    /// Returns the count synthesized since the guard was created.
+    pub fn spent(&self) -> Count {
+        Count::of_environment::<E>().since(&self.start)
+    }
+}
+
+impl<E: Environment> Drop for ConstraintBudget<E> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            let spent = self.spent();
+            assert_eq!(self.expected, spent, "Constraint budget of {} was not met, found {}", self.expected, spent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Mode};
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{Field, One};
+
+    /// Squares a private variable, returning the square.
+    fn square(value: Fq) -> Fq {
+        let variable = Circuit::new_variable(Mode::Private, value);
+        let square = Circuit::new_variable(Mode::Private, value.square());
+        Circuit::enforce(|| (&variable, &variable, &square));
+        square.value()
+    }
+
+    #[test]
+    fn test_expect_scope() {
+        Circuit::scope("test_expect_scope", || {
+            Circuit::new_variable(Mode::Constant, Fq::one());
+            square(Fq::one().double());
+            Circuit::expect_scope(1, 0, 2, 1);
+            assert_eq!(Count::new(1, 0, 2, 1), Count::of_scope::<Circuit>());
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expect_scope_fails() {
+        Circuit::scope("test_expect_scope_fails", || {
+            square(Fq::one().double());
+            Circuit::expect_scope(0, 0, 2, 2);
+        });
+    }
+
+    #[test]
+    fn test_constraint_budget() {
+        Circuit::reset();
+        square(Fq::one().double());
+
+        let budget = Circuit::expect_budget(0, 0, 4, 2);
+        let value = square(Fq::one().double());
+        square(value);
+        assert_eq!(Count::new(0, 0, 4, 2), budget.spent());
+        drop(budget);
+
+        // Ensure the budget only counts the circuit synthesized during its lifetime.
+        assert_eq!(Count::new(0, 1, 6, 3), Count::of_environment::<Circuit>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_constraint_budget_fails() {
+        Circuit::reset();
+        let _budget = Circuit::expect_budget(0, 0, 2, 1);
+        let value = square(Fq::one().double());
+        square(value);
+    }
+}
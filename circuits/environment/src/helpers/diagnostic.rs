@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Mode, Scope};
+
+use core::fmt;
+
+/// Machine-readable metadata attached to the constraints that enforce a condition of an operation,
+/// such as the absence of an overflow, which explains why those constraints are unsatisfied.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Annotation {
+    /// The name of the operation, e.g. `multiplication`.
+    operation: &'static str,
+    /// The condition the constraints guard against, e.g. `overflow`.
+    condition: &'static str,
+    /// The name of the type of the operands, e.g. `u64`.
+    type_name: &'static str,
+    /// The bit width of the operands.
+    bit_width: usize,
+    /// The modes of the operands.
+    modes: Vec<Mode>,
+}
+
+impl Annotation {
+    /// Initializes a new annotation for the given operation and condition, on operands of the given type and modes.
+    pub fn new(
+        operation: &'static str,
+        condition: &'static str,
+        type_name: &'static str,
+        bit_width: usize,
+        modes: &[Mode],
+    ) -> Self {
+        Self { operation, condition, type_name, bit_width, modes: modes.to_vec() }
+    }
+
+    /// Returns the name of the operation.
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// Returns the condition the constraints guard against.
+    pub fn condition(&self) -> &'static str {
+        self.condition
+    }
+
+    /// Returns the name of the type of the operands.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the bit width of the operands.
+    pub fn bit_width(&self) -> usize {
+        self.bit_width
+    }
+
+    /// Returns the modes of the operands.
+    pub fn modes(&self) -> &[Mode] {
+        &self.modes
+    }
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.type_name, self.operation, self.condition)
+    }
+}
+
+/// An unsatisfied constraint in the environment, with the annotation of the operation that enforced it, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The index of the constraint in the environment.
+    index: usize,
+    /// The scope in which the constraint was enforced.
+    scope: Scope,
+    /// The annotation of the constraint, if any.
+    annotation: Option<Annotation>,
+}
+
+impl Diagnostic {
+    /// Initializes a new diagnostic for the unsatisfied constraint at the given index.
+    pub(crate) fn new(index: usize, scope: Scope, annotation: Option<Annotation>) -> Self {
+        Self { index, scope, annotation }
+    }
+
+    /// Returns the index of the constraint in the environment.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the scope in which the constraint was enforced.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    /// Returns the annotation of the constraint, if any.
+    pub fn annotation(&self) -> Option<&Annotation> {
+        self.annotation.as_ref()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.annotation {
+            Some(annotation) => write!(f, "{} at {} (constraint {})", annotation, self.scope, self.index),
+            None => write!(f, "Unsatisfied constraint at {} (constraint {})", self.scope, self.index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Environment};
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_diagnostics() {
+        Circuit::reset();
+        let zero = Circuit::new_variable(Mode::Private, Fq::zero());
+        let one = Circuit::new_variable(Mode::Private, Fq::one());
+
+        // Enforce one unannotated and two annotated constraints, of which the first two are unsatisfied.
+        Circuit::assert_eq(&zero, &one);
+        let annotation = Annotation::new("addition", "overflow", "u8", 8, &[Mode::Private, Mode::Constant]);
+        Circuit::scope("test_diagnostics", || {
+            Circuit::annotate(annotation.clone(), || {
+                Circuit::assert_eq(&one, &zero);
+                Circuit::assert_eq(&one, &one);
+            })
+        });
+        // Ensure the annotation does not outlive its logic.
+        Circuit::assert_eq(&one, &zero);
+
+        let diagnostics = Circuit::diagnostics();
+        assert_eq!(3, diagnostics.len());
+        assert_eq!(None, diagnostics[0].annotation());
+        assert_eq!(Some(&annotation), diagnostics[1].annotation());
+        assert_eq!(1, diagnostics[1].index());
+        assert_eq!(None, diagnostics[2].annotation());
+        assert_eq!("u8 addition overflow", annotation.to_string());
+        assert!(diagnostics[1].to_string().starts_with("u8 addition overflow at "));
+        Circuit::reset();
+
+        // Ensure a satisfied circuit has no diagnostics.
+        assert!(Circuit::diagnostics().is_empty());
+    }
+}
@@ -116,6 +116,13 @@ impl<F: PrimeField> LinearCombination<F> {
         }
     }
 
+    /// Returns a linear combination from the given constant value and terms.
+    pub(super) fn from_parts(constant: F, terms: IndexMap<Variable<F>, F>) -> Self {
+        let value =
+            terms.iter().fold(constant, |value, (variable, coefficient)| value + variable.value() * coefficient);
+        Self { constant, terms, value }
+    }
+
     /// Returns only the constant value (excluding the terms) in the linear combination.
     pub(super) fn to_constant(&self) -> F {
         self.constant
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod assignment;
+pub use assignment::*;
+
+pub mod budget;
+pub use budget::*;
+
 pub(crate) mod constraint;
 pub(crate) use constraint::*;
 
@@ -22,6 +28,9 @@ pub(super) mod converter;
 pub(super) mod counter;
 pub(super) use counter::*;
 
+pub mod diagnostic;
+pub use diagnostic::*;
+
 pub mod linear_combination;
 pub use linear_combination::*;
 
@@ -33,3 +42,6 @@ pub use variable::*;
 
 pub(super) mod r1cs;
 pub(super) use r1cs::*;
+
+pub mod trace;
+pub use trace::*;
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Annotation, Assignment, Constraint, Counter, Diagnostic},
     prelude::*,
 };
 
@@ -30,6 +30,8 @@ pub(crate) struct R1CS<F: PrimeField> {
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
     constraints: Vec<Constraint<F>>,
+    annotations: Vec<Option<Rc<Annotation>>>,
+    annotation: Option<Rc<Annotation>>,
     counter: Counter<F>,
     gates: usize,
 }
@@ -42,6 +44,8 @@ impl<F: PrimeField> R1CS<F> {
             public: vec![Variable::Public(0u64, Rc::new(F::one()))],
             private: Default::default(),
             constraints: Default::default(),
+            annotations: Default::default(),
+            annotation: None,
             counter: Default::default(),
             gates: 0,
         }
@@ -85,6 +89,7 @@ impl<F: PrimeField> R1CS<F> {
     pub(crate) fn enforce(&mut self, constraint: Constraint<F>) {
         self.gates += constraint.num_gates();
         self.constraints.push(constraint.clone());
+        self.annotations.push(self.annotation.clone());
         self.counter.add_constraint(constraint);
     }
 
@@ -98,6 +103,27 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.is_satisfied_in_scope()
     }
 
+    /// Sets the annotation of the constraints enforced from now on, returning the previous annotation.
+    pub(crate) fn set_annotation(&mut self, annotation: Option<Rc<Annotation>>) -> Option<Rc<Annotation>> {
+        core::mem::replace(&mut self.annotation, annotation)
+    }
+
+    /// Returns a diagnostic for each unsatisfied constraint in the environment.
+    pub(crate) fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.constraints
+            .iter()
+            .zip_eq(&self.annotations)
+            .enumerate()
+            .filter(|(_, (constraint, _))| {
+                let (a, b, c) = constraint.to_terms();
+                a.value() * b.value() != c.value()
+            })
+            .map(|(index, (constraint, annotation))| {
+                Diagnostic::new(index, constraint.0.clone(), annotation.as_deref().cloned())
+            })
+            .collect()
+    }
+
     /// Returns the current scope.
     pub(crate) fn scope(&self) -> Scope {
         self.counter.scope()
@@ -163,6 +189,14 @@ impl<F: PrimeField> R1CS<F> {
         &self.private
     }
 
+    /// Returns the values of the public and private variables in the constraint system.
+    pub(crate) fn to_assignment(&self) -> Assignment<F> {
+        Assignment::new(
+            self.public.iter().map(|variable| variable.value()).collect(),
+            self.private.iter().map(|variable| variable.value()).collect(),
+        )
+    }
+
     /// Returns the constraints in the constraint system.
     pub(crate) fn to_constraints(&self) -> &Vec<Constraint<F>> {
         &self.constraints
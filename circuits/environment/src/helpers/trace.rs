@@ -0,0 +1,412 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{Constraint, R1CS},
+    prelude::*,
+    *,
+};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use indexmap::IndexMap;
+use std::io::{Read, Result as IoResult, Write};
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Returns the hash of the given value, which is stable across machines.
+pub(crate) fn hash_value<F: PrimeField>(value: &F) -> u64 {
+    let bytes = value.to_bytes_le().unwrap_or_default();
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ *byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+/// A linear combination recorded in a replay log, with each term given as `(mode, index, coefficient)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracedLinearCombination<F: PrimeField> {
+    constant: F,
+    terms: Vec<(Mode, Index, F)>,
+}
+
+impl<F: PrimeField> TracedLinearCombination<F> {
+    /// Returns the linear combination, resolving each term to a variable in the given constraint system.
+    fn to_linear_combination(&self, r1cs: &R1CS<F>) -> Result<LinearCombination<F>, String> {
+        let mut terms = IndexMap::with_capacity(self.terms.len());
+        for (mode, index, coefficient) in &self.terms {
+            let variables = match mode {
+                Mode::Public => r1cs.to_public_variables(),
+                Mode::Private => r1cs.to_private_variables(),
+                Mode::Constant => return Err("Replay log contains a constant term in a linear combination".into()),
+            };
+            match variables.get(*index as usize) {
+                Some(variable) => terms.insert(variable.clone(), *coefficient),
+                None => return Err(format!("Replay log references a missing {mode} variable at index {index}")),
+            };
+        }
+        Ok(LinearCombination::from_parts(self.constant, terms))
+    }
+}
+
+impl<F: PrimeField> From<&LinearCombination<F>> for TracedLinearCombination<F> {
+    fn from(linear_combination: &LinearCombination<F>) -> Self {
+        let mut constant = linear_combination.to_constant();
+        let mut terms = Vec::with_capacity(linear_combination.to_terms().len());
+        for (variable, coefficient) in linear_combination.to_terms() {
+            match variable {
+                // Constant terms are folded into the constant value, as they are by `LinearCombination`.
+                Variable::Constant(value) => constant += **value * coefficient,
+                _ => terms.push((variable.mode(), variable.index(), *coefficient)),
+            }
+        }
+        Self { constant, terms }
+    }
+}
+
+/// An event recorded while tracing the environment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceEvent<F: PrimeField> {
+    /// A call to `new_witness`, as `(mode, scope)`.
+    Witness(Mode, Scope),
+    /// A call to `new_variable`, as `(mode, value, value hash, scope)`.
+    Variable(Mode, F, u64, Scope),
+    /// A constraint enforcing `(A * B) == C`, as `(scope, A, B, C)`.
+    Constraint(Scope, TracedLinearCombination<F>, TracedLinearCombination<F>, TracedLinearCombination<F>),
+    /// Entering the scope of the given name.
+    PushScope(String),
+    /// Exiting the scope of the given name.
+    PopScope(String),
+}
+
+impl<F: PrimeField> TraceEvent<F> {
+    /// Returns a new variable event for the given mode, value, and scope.
+    pub(crate) fn new_variable(mode: Mode, value: F, scope: Scope) -> Self {
+        Self::Variable(mode, value, hash_value(&value), scope)
+    }
+
+    /// Returns a new constraint event for the given constraint.
+    pub(crate) fn new_constraint(constraint: &Constraint<F>) -> Self {
+        let (a, b, c) = constraint.to_terms();
+        Self::Constraint(constraint.0.clone(), a.into(), b.into(), c.into())
+    }
+}
+
+///
+/// A replay log of the injections and constraints of a circuit, recorded while tracing the environment.
+///
+/// As the log records the value of each injection, replaying the log reconstructs an identical circuit,
+/// which allows a circuit that was synthesized on one machine to be inspected on another.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplayLog<F: PrimeField> {
+    events: Vec<TraceEvent<F>>,
+}
+
+impl<F: PrimeField> ReplayLog<F> {
+    /// Initializes an empty replay log.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Returns the recorded events, in the order they occurred.
+    pub fn events(&self) -> &[TraceEvent<F>] {
+        &self.events
+    }
+
+    /// Returns the number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Appends the given event to the log.
+    pub(crate) fn push(&mut self, event: TraceEvent<F>) {
+        self.events.push(event);
+    }
+
+    /// Clears the recorded events.
+    pub(crate) fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns a copy of the log, with the values of its public and private variables replaced by the given assignment.
+    /// Replaying the returned log reconstructs the circuit on the assigned witnesses, without rerunning its logic.
+    /// Returns an error if the assignment does not match the number of public and private variables in the log.
+    pub fn assign(&self, assignment: &Assignment<F>) -> Result<Self, String> {
+        // The leading public variable of one is allocated by the constraint system, and is not recorded in the log.
+        if assignment.public().first() != Some(&F::one()) {
+            return Err("The assignment must start with a public variable of one".into());
+        }
+
+        let (mut public, mut private) = (assignment.public()[1..].iter(), assignment.private().iter());
+        let mut events = Vec::with_capacity(self.events.len());
+        for event in &self.events {
+            events.push(match event {
+                TraceEvent::Variable(Mode::Public, _, _, scope) => match public.next() {
+                    Some(value) => TraceEvent::new_variable(Mode::Public, *value, scope.clone()),
+                    None => return Err("The assignment is missing a public value".into()),
+                },
+                TraceEvent::Variable(Mode::Private, _, _, scope) => match private.next() {
+                    Some(value) => TraceEvent::new_variable(Mode::Private, *value, scope.clone()),
+                    None => return Err("The assignment is missing a private value".into()),
+                },
+                event => event.clone(),
+            });
+        }
+
+        // Ensure every value in the assignment was used.
+        match public.next().is_none() && private.next().is_none() {
+            true => Ok(Self { events }),
+            false => Err("The assignment contains more values than the replay log".into()),
+        }
+    }
+
+    /// Reconstructs the constraint system recorded in the log.
+    /// Returns an error if the log is inconsistent, such as a value that does not match its hash.
+    pub(crate) fn to_r1cs(&self) -> Result<R1CS<F>, String> {
+        let mut r1cs = R1CS::new();
+        for (i, event) in self.events.iter().enumerate() {
+            match event {
+                TraceEvent::Witness(_, scope) => Self::check_scope(i, &r1cs, scope)?,
+                TraceEvent::Variable(mode, value, hash, scope) => {
+                    Self::check_scope(i, &r1cs, scope)?;
+                    if *hash != hash_value(value) {
+                        return Err(format!("Replay diverged at event {i}: the value does not match its hash"));
+                    }
+                    match mode {
+                        Mode::Constant => r1cs.new_constant(*value),
+                        Mode::Public => r1cs.new_public(*value),
+                        Mode::Private => r1cs.new_private(*value),
+                    };
+                }
+                TraceEvent::Constraint(scope, a, b, c) => {
+                    Self::check_scope(i, &r1cs, scope)?;
+                    let (a, b, c) = (
+                        a.to_linear_combination(&r1cs)?,
+                        b.to_linear_combination(&r1cs)?,
+                        c.to_linear_combination(&r1cs)?,
+                    );
+                    r1cs.enforce(Constraint(scope.clone(), a, b, c));
+                }
+                TraceEvent::PushScope(name) => r1cs.push_scope(name)?,
+                TraceEvent::PopScope(name) => r1cs.pop_scope(name)?,
+            }
+        }
+        Ok(r1cs)
+    }
+
+    /// Returns an error if the scope of the event at the given index does not match the current scope.
+    fn check_scope(i: usize, r1cs: &R1CS<F>, scope: &Scope) -> Result<(), String> {
+        match r1cs.scope() == *scope {
+            true => Ok(()),
+            false => Err(format!("Replay diverged at event {i}: expected scope '{scope}', found '{}'", r1cs.scope())),
+        }
+    }
+}
+
+/// Writes the given string as a length-prefixed sequence of bytes.
+fn write_string<W: Write>(string: &str, mut writer: W) -> IoResult<()> {
+    (string.len() as u32).write_le(&mut writer)?;
+    writer.write_all(string.as_bytes())
+}
+
+/// Reads a length-prefixed sequence of bytes as a string.
+fn read_string<R: Read>(mut reader: R) -> IoResult<String> {
+    let length = u32::read_le(&mut reader)?;
+    let mut bytes = vec![0u8; length as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| error("Invalid string in replay log"))
+}
+
+impl<F: PrimeField> ToBytes for TracedLinearCombination<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        (self.terms.len() as u32).write_le(&mut writer)?;
+        for (mode, index, coefficient) in &self.terms {
+            mode.write_le(&mut writer)?;
+            index.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for TracedLinearCombination<F> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let num_terms = u32::read_le(&mut reader)?;
+        let terms = (0..num_terms)
+            .map(|_| Ok((Mode::read_le(&mut reader)?, Index::read_le(&mut reader)?, F::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for TraceEvent<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Witness(mode, scope) => {
+                0u8.write_le(&mut writer)?;
+                mode.write_le(&mut writer)?;
+                write_string(scope, &mut writer)
+            }
+            Self::Variable(mode, value, hash, scope) => {
+                1u8.write_le(&mut writer)?;
+                mode.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+                hash.write_le(&mut writer)?;
+                write_string(scope, &mut writer)
+            }
+            Self::Constraint(scope, a, b, c) => {
+                2u8.write_le(&mut writer)?;
+                write_string(scope, &mut writer)?;
+                a.write_le(&mut writer)?;
+                b.write_le(&mut writer)?;
+                c.write_le(&mut writer)
+            }
+            Self::PushScope(name) => {
+                3u8.write_le(&mut writer)?;
+                write_string(name, &mut writer)
+            }
+            Self::PopScope(name) => {
+                4u8.write_le(&mut writer)?;
+                write_string(name, &mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for TraceEvent<F> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Witness(Mode::read_le(&mut reader)?, read_string(&mut reader)?)),
+            1 => Ok(Self::Variable(
+                Mode::read_le(&mut reader)?,
+                F::read_le(&mut reader)?,
+                u64::read_le(&mut reader)?,
+                read_string(&mut reader)?,
+            )),
+            2 => Ok(Self::Constraint(
+                read_string(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+            )),
+            3 => Ok(Self::PushScope(read_string(&mut reader)?)),
+            4 => Ok(Self::PopScope(read_string(&mut reader)?)),
+            _ => Err(error("Invalid trace event")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for ReplayLog<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.events.len() as u64).write_le(&mut writer)?;
+        self.events.iter().try_for_each(|event| event.write_le(&mut writer))
+    }
+}
+
+impl<F: PrimeField> FromBytes for ReplayLog<F> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_events = u64::read_le(&mut reader)?;
+        let events = (0..num_events).map(|_| TraceEvent::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { events })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_curves::edwards_bls12::Fq;
+
+    const ITERATIONS: usize = 16;
+
+    /// Squares a public variable repeatedly, with each square in its own scope.
+    fn create_example_circuit() {
+        let mut accumulator = Circuit::new_variable(Mode::Public, Fq::one() + Fq::one());
+        for i in 0..ITERATIONS {
+            accumulator = Circuit::scope(format!("square_{i}"), || {
+                let square = Circuit::new_variable(Mode::Private, accumulator.value().square());
+                Circuit::enforce(|| (&accumulator, &accumulator, &square));
+                square
+            });
+        }
+        let four = Circuit::new_variable(Mode::Constant, Fq::one().double().double());
+        Circuit::enforce(|| (&accumulator, &four, LinearCombination::from(&accumulator) * four.value()));
+    }
+
+    /// Returns the size and the printed constraints of the circuit in the environment.
+    fn circuit_summary() -> (usize, usize, usize, usize, usize, String) {
+        (
+            Circuit::num_constants(),
+            Circuit::num_public(),
+            Circuit::num_private(),
+            Circuit::num_constraints(),
+            Circuit::num_gates(),
+            format!("{}", Circuit),
+        )
+    }
+
+    #[test]
+    fn test_replay() {
+        Circuit::reset();
+        Circuit::start_tracing();
+        create_example_circuit();
+        let log = Circuit::stop_tracing().unwrap();
+        assert!(Circuit::is_satisfied());
+        assert_eq!(1 + 4 * ITERATIONS + 2, log.len());
+
+        let expected = circuit_summary();
+
+        // Ensure the log round-trips through its byte encoding.
+        let log = ReplayLog::<Fq>::read_le(&log.to_bytes_le().unwrap()[..]).unwrap();
+
+        // Ensure the replayed circuit is identical.
+        Circuit::reset();
+        Circuit::replay(&log).unwrap();
+        assert_eq!(expected, circuit_summary());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure tracing is disabled once stopped.
+        assert!(Circuit::stop_tracing().is_none());
+    }
+
+    #[test]
+    fn test_replay_divergence() {
+        Circuit::reset();
+        Circuit::start_tracing();
+        create_example_circuit();
+        let mut log = Circuit::stop_tracing().unwrap();
+
+        // Tamper with the value of the first private variable.
+        let index =
+            log.events.iter().position(|event| matches!(event, TraceEvent::Variable(Mode::Private, ..))).unwrap();
+        if let TraceEvent::Variable(_, value, _, _) = &mut log.events[index] {
+            *value = value.double();
+        }
+        assert!(Circuit::replay(&log).is_err());
+
+        // Ensure an event from a scope that was never entered is rejected.
+        let mut log = ReplayLog::new();
+        log.push(TraceEvent::new_variable(Mode::Private, Fq::one(), "missing".into()));
+        assert!(Circuit::replay(&log).is_err());
+    }
+}
@@ -35,11 +35,26 @@ pub use helpers::*;
 pub mod macros;
 pub use macros::*;
 
+pub mod test_utilities;
+
 pub mod traits;
 pub use traits::*;
 
 pub mod prelude {
-    pub use crate::{rename_selfs, traits::*, witness, witness_mode, Environment, LinearCombination, Mode, Variable};
+    pub use crate::{
+        rename_selfs,
+        traits::*,
+        witness,
+        witness_mode,
+        Annotation,
+        Assignment,
+        Diagnostic,
+        Environment,
+        LinearCombination,
+        Mode,
+        ReplayLog,
+        Variable,
+    };
     pub use snarkvm_fields::{Field as F, One as O, PrimeField, Zero as Z};
 
     pub use core::{
@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers for testing circuit operations against their native counterparts.
+//!
+//! The `check_operation_*` helpers check a single invocation of an operation, while the
+//! `check_differential*` helpers run an operation on corner-case and randomized inputs,
+//! in every combination of modes, and check the circuit agrees with the native operation.
+
+#![allow(clippy::too_many_arguments)]
+
+use crate::{assert_scope, assert_scope_fails, Circuit, Eject, Environment, Inject, Mode};
+use snarkvm_utilities::{test_rng, UniformRand};
+
+use core::{
+    fmt::{Debug, Display},
+    panic::{AssertUnwindSafe, UnwindSafe},
+};
+
+/// The modes of a unary operation, in every combination.
+pub const UNARY_MODES: [Mode; 3] = [Mode::Constant, Mode::Public, Mode::Private];
+
+/// The modes of a binary operation, in every combination.
+pub const BINARY_MODES: [(Mode, Mode); 9] = [
+    (Mode::Constant, Mode::Constant),
+    (Mode::Constant, Mode::Public),
+    (Mode::Constant, Mode::Private),
+    (Mode::Public, Mode::Constant),
+    (Mode::Public, Mode::Public),
+    (Mode::Public, Mode::Private),
+    (Mode::Private, Mode::Constant),
+    (Mode::Private, Mode::Public),
+    (Mode::Private, Mode::Private),
+];
+
+pub fn check_operation_passes<V: Debug + Display + PartialEq, LHS, RHS, OUT: Eject<Primitive = V>>(
+    name: &str,
+    case: &str,
+    expected: V,
+    a: LHS,
+    b: RHS,
+    operation: impl FnOnce(LHS, RHS) -> OUT,
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+) {
+    Circuit::scope(name, || {
+        let candidate = operation(a, b);
+        assert_eq!(expected, candidate.eject_value(), "{} != {} := {}", expected, candidate.eject_value(), case);
+        assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+    });
+    Circuit::reset();
+}
+
+pub fn check_operation_passes_without_counts<V: Debug + Display + PartialEq, LHS, RHS, OUT: Eject<Primitive = V>>(
+    name: &str,
+    case: &str,
+    expected: V,
+    a: LHS,
+    b: RHS,
+    operation: impl FnOnce(LHS, RHS) -> OUT,
+) {
+    Circuit::scope(name, || {
+        let candidate = operation(a, b);
+        assert_eq!(expected, candidate.eject_value(), "{} != {} := {}", expected, candidate.eject_value(), case);
+    });
+    Circuit::reset();
+}
+
+pub fn check_operation_fails<LHS, RHS, OUT>(
+    name: &str,
+    case: &str,
+    a: LHS,
+    b: RHS,
+    operation: impl FnOnce(LHS, RHS) -> OUT,
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+) {
+    Circuit::scope(name, || {
+        let _candidate = operation(a, b);
+        assert_scope_fails!(case, num_constants, num_public, num_private, num_constraints);
+    });
+    Circuit::reset();
+}
+
+pub fn check_operation_fails_without_counts<LHS, RHS, OUT>(
+    name: &str,
+    case: &str,
+    a: LHS,
+    b: RHS,
+    operation: impl FnOnce(LHS, RHS) -> OUT,
+) {
+    Circuit::scope(name, || {
+        let _candidate = operation(a, b);
+        assert!(!Circuit::is_satisfied(), "{} (!is_satisfied)", case);
+    });
+    Circuit::reset();
+}
+
+pub fn check_operation_halts<LHS: UnwindSafe, RHS: UnwindSafe, OUT>(
+    a: LHS,
+    b: RHS,
+    operation: impl FnOnce(LHS, RHS) -> OUT + UnwindSafe,
+) {
+    let result = std::panic::catch_unwind(|| operation(a, b));
+    assert!(result.is_err());
+}
+
+pub fn check_unary_operation_passes<V: Debug + Display + PartialEq, IN, OUT: Eject<Primitive = V>>(
+    name: &str,
+    case: &str,
+    expected: V,
+    input: IN,
+    operation: impl FnOnce(IN) -> OUT,
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+) {
+    Circuit::scope(name, || {
+        let candidate = operation(input);
+        assert_eq!(expected, candidate.eject_value(), "{}", case);
+        assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+    });
+    Circuit::reset();
+}
+
+pub fn check_unary_operation_fails<IN, OUT>(
+    name: &str,
+    case: &str,
+    input: IN,
+    operation: impl FnOnce(IN) -> OUT,
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+) {
+    Circuit::scope(name, || {
+        let _candidate = operation(input);
+        assert_scope_fails!(case, num_constants, num_public, num_private, num_constraints);
+    });
+    Circuit::reset();
+}
+
+pub fn check_unary_operation_halts<IN: UnwindSafe, OUT>(input: IN, operation: impl FnOnce(IN) -> OUT + UnwindSafe) {
+    let result = std::panic::catch_unwind(|| operation(input));
+    assert!(result.is_err());
+}
+
+/// Checks the given binary circuit operation against the given native operation, on each of the given
+/// corner cases and on `num_random` randomly-sampled inputs, with the operands in every combination of modes.
+///
+/// If the native operation returns `Some(expected)`, the circuit operation must output `expected`
+/// and the circuit must be satisfied. Otherwise, the circuit operation must halt if both operands
+/// are constant, and the circuit must not be satisfied if either operand is not constant.
+pub fn check_differential<A, B, V, LHS, RHS, OUT>(
+    name: &str,
+    corner_cases: &[(A, B)],
+    num_random: usize,
+    native: impl Fn(&A, &B) -> Option<V>,
+    circuit: impl Fn(&LHS, &RHS) -> OUT,
+) where
+    A: Clone + Display + UniformRand,
+    B: Clone + Display + UniformRand,
+    V: Debug + Display + PartialEq,
+    LHS: Inject<Primitive = A>,
+    RHS: Inject<Primitive = B>,
+    OUT: Eject<Primitive = V>,
+{
+    let random_cases =
+        (0..num_random).map(|_| (UniformRand::rand(&mut test_rng()), UniformRand::rand(&mut test_rng())));
+
+    for (i, (first, second)) in corner_cases.iter().cloned().chain(random_cases).enumerate() {
+        let expected = native(&first, &second);
+
+        for (mode_a, mode_b) in BINARY_MODES {
+            let case = format!("{} #{}: ({}, {}) as ({}, {})", name, i, first, second, mode_a, mode_b);
+
+            let a = LHS::new(mode_a, first.clone());
+            let b = RHS::new(mode_b, second.clone());
+
+            match &expected {
+                Some(expected) => Circuit::scope(name, || {
+                    let candidate = circuit(&a, &b).eject_value();
+                    assert_eq!(*expected, candidate, "{} != {} := {}", expected, candidate, case);
+                    assert!(Circuit::is_satisfied(), "{} (is_satisfied)", case);
+                }),
+                None if mode_a.is_constant() && mode_b.is_constant() => {
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| circuit(&a, &b)));
+                    assert!(result.is_err(), "{} (halts)", case);
+                }
+                None => Circuit::scope(name, || {
+                    let _candidate = circuit(&a, &b);
+                    assert!(!Circuit::is_satisfied(), "{} (!is_satisfied)", case);
+                }),
+            }
+            Circuit::reset();
+        }
+    }
+}
+
+/// Checks the given unary circuit operation against the given native operation, on each of the given
+/// corner cases and on `num_random` randomly-sampled inputs, with the operand in every mode.
+///
+/// If the native operation returns `Some(expected)`, the circuit operation must output `expected`
+/// and the circuit must be satisfied. Otherwise, the circuit operation must halt if the operand
+/// is constant, and the circuit must not be satisfied if the operand is not constant.
+pub fn check_unary_differential<A, V, IN, OUT>(
+    name: &str,
+    corner_cases: &[A],
+    num_random: usize,
+    native: impl Fn(&A) -> Option<V>,
+    circuit: impl Fn(&IN) -> OUT,
+) where
+    A: Clone + Display + UniformRand,
+    V: Debug + Display + PartialEq,
+    IN: Inject<Primitive = A>,
+    OUT: Eject<Primitive = V>,
+{
+    let random_cases = (0..num_random).map(|_| UniformRand::rand(&mut test_rng()));
+
+    for (i, input) in corner_cases.iter().cloned().chain(random_cases).enumerate() {
+        let expected = native(&input);
+
+        for mode in UNARY_MODES {
+            let case = format!("{} #{}: {} as {}", name, i, input, mode);
+
+            let a = IN::new(mode, input.clone());
+
+            match &expected {
+                Some(expected) => Circuit::scope(name, || {
+                    let candidate = circuit(&a).eject_value();
+                    assert_eq!(*expected, candidate, "{} != {} := {}", expected, candidate, case);
+                    assert!(Circuit::is_satisfied(), "{} (is_satisfied)", case);
+                }),
+                None if mode.is_constant() => {
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| circuit(&a)));
+                    assert!(result.is_err(), "{} (halts)", case);
+                }
+                None => Circuit::scope(name, || {
+                    let _candidate = circuit(&a);
+                    assert!(!Circuit::is_satisfied(), "{} (!is_satisfied)", case);
+                }),
+            }
+            Circuit::reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearCombination;
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{Field, One, Zero};
+
+    const ITERATIONS: usize = 32;
+
+    /// A minimal field element gadget, for testing the harness.
+    struct Element(LinearCombination<Fq>);
+
+    impl Inject for Element {
+        type Primitive = Fq;
+
+        fn new(mode: Mode, value: Self::Primitive) -> Self {
+            Self(Circuit::new_variable(mode, value).into())
+        }
+    }
+
+    impl Eject for Element {
+        type Primitive = Fq;
+
+        fn eject_mode(&self) -> Mode {
+            self.0.mode()
+        }
+
+        fn eject_value(&self) -> Self::Primitive {
+            self.0.value()
+        }
+    }
+
+    /// Returns the inverse of the given element, enforcing `element * inverse == 1`.
+    fn inverse(element: &Element) -> Element {
+        let value = element.eject_value();
+        match element.eject_mode().is_constant() {
+            true => match value.inverse() {
+                Some(inverse) => Element::new(Mode::Constant, inverse),
+                None => Circuit::halt("Attempted to invert zero"),
+            },
+            false => {
+                let inverse = Element::new(Mode::Private, value.inverse().unwrap_or_else(Fq::zero));
+                Circuit::enforce(|| (element.0.clone(), inverse.0.clone(), Circuit::one()));
+                inverse
+            }
+        }
+    }
+
+    /// Returns the quotient of the given elements, enforcing the divisor is invertible.
+    fn divide(dividend: &Element, divisor: &Element) -> Element {
+        match dividend.eject_mode().is_constant() && divisor.eject_mode().is_constant() {
+            true => match divisor.eject_value().inverse() {
+                Some(inverse) => Element::new(Mode::Constant, dividend.eject_value() * inverse),
+                None => Circuit::halt("Attempted to divide by zero"),
+            },
+            false => {
+                let inverse = Element::new(Mode::Private, divisor.eject_value().inverse().unwrap_or_else(Fq::zero));
+                Circuit::enforce(|| (divisor.0.clone(), inverse.0.clone(), Circuit::one()));
+                let quotient = Element::new(Mode::Private, dividend.eject_value() * inverse.eject_value());
+                Circuit::enforce(|| (dividend.0.clone(), inverse.0.clone(), quotient.0.clone()));
+                quotient
+            }
+        }
+    }
+
+    #[test]
+    fn test_unary_differential() {
+        check_unary_differential("inverse", &[Fq::zero(), Fq::one()], ITERATIONS, |a: &Fq| a.inverse(), inverse);
+    }
+
+    #[test]
+    fn test_differential() {
+        let corner_cases = [(Fq::one(), Fq::zero()), (Fq::zero(), Fq::zero()), (Fq::zero(), Fq::one())];
+        check_differential("divide", &corner_cases, ITERATIONS, |a: &Fq, b: &Fq| b.inverse().map(|b| *a * b), divide);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_differential_detects_mismatch() {
+        // An incorrect gadget, which does not enforce the divisor is non-zero.
+        let unchecked_divide = |dividend: &Element, divisor: &Element| {
+            let quotient = divisor.eject_value().inverse().map(|inverse| dividend.eject_value() * inverse);
+            Element::new(Mode::Private, quotient.unwrap_or_else(Fq::zero))
+        };
+        check_differential(
+            "divide",
+            &[(Fq::one(), Fq::zero())],
+            0,
+            |a: &Fq, b: &Fq| b.inverse().map(|b| *a * b),
+            unchecked_divide,
+        );
+    }
+}
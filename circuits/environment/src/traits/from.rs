@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::BooleanTrait;
+use crate::{BooleanTrait, FieldTrait};
 
 /// Unary operator for instantiating from a boolean.
 pub trait FromBoolean {
@@ -35,3 +35,11 @@ pub trait FromBits {
     where
         Self: Sized;
 }
+
+/// Unary operator for instantiating from a base field.
+pub trait FromField {
+    type Field: FieldTrait;
+
+    /// Casts a circuit from a base field.
+    fn from_field(field: &Self::Field) -> Self;
+}
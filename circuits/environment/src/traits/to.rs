@@ -66,6 +66,12 @@ pub trait ToFields {
     fn to_fields(&self) -> Vec<Self::Field>;
 }
 
+/// Unary operator for converting to a scalar field.
+pub trait ToScalar<S: ScalarTrait> {
+    /// Casts a circuit into a scalar field.
+    fn to_scalar(&self) -> S;
+}
+
 /// Unary operator for converting to an affine group.
 pub trait ToGroup {
     type Scalar: ScalarTrait;
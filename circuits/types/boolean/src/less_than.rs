@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    ///
+    /// Returns `true` if `a` is less than `b`, where both are unsigned integers given as
+    /// little-endian bits of equal length.
+    ///
+    /// The bits are compared lexicographically from the MSB. A prefix of bits that are constant
+    /// in both `a` and `b` is compared natively, and if it already decides the comparison,
+    /// a constant is returned without allocating any constraints.
+    ///
+    pub fn is_less_than_bits_le(a: &[Boolean<E>], b: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the bit vectors are of equal length.
+        if a.len() != b.len() {
+            E::halt(format!("Attempted to compare bit vectors of length {} and {}", a.len(), b.len()))
+        }
+
+        // Initialize an iterator over `a` and `b` from MSB to LSB.
+        let mut bits_be = a.iter().rev().zip_eq(b.iter().rev()).peekable();
+
+        // Compare the constant prefix natively.
+        while let Some((a_bit, b_bit)) = bits_be.next_if(|(a_bit, b_bit)| a_bit.is_constant() && b_bit.is_constant()) {
+            if a_bit.eject_value() != b_bit.eject_value() {
+                return Boolean::constant(!a_bit.eject_value() & b_bit.eject_value());
+            }
+        }
+
+        let mut is_less_than = Boolean::constant(false);
+        let mut are_previous_bits_equal = Boolean::constant(true);
+
+        while let Some((a_bit, b_bit)) = bits_be.next() {
+            // Determine if `a` is less than `b` up to the current bit.
+            is_less_than |= &are_previous_bits_equal & (!a_bit & b_bit);
+
+            // Skip the update to the LSB, as this boolean is subsequently discarded.
+            if bits_be.peek().is_some() {
+                are_previous_bits_equal &= a_bit.is_equal(b_bit);
+            }
+        }
+
+        is_less_than
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
+    /// Returns the little-endian bits of `value`, with the given number of bits.
+    fn bits_le(mode: Mode, value: u64, num_bits: usize) -> Vec<Boolean<Circuit>> {
+        (0..num_bits).map(|i| Boolean::new(mode, (value >> i) & 1 == 1)).collect()
+    }
+
+    fn check_is_less_than_bits_le(mode_a: Mode, mode_b: Mode, num_bits: usize) {
+        for a in 0..(1u64 << num_bits) {
+            for b in 0..(1u64 << num_bits) {
+                let candidate_a = bits_le(mode_a, a, num_bits);
+                let candidate_b = bits_le(mode_b, b, num_bits);
+
+                Circuit::scope(format!("{mode_a} {mode_b} ({a} < {b})"), || {
+                    let candidate = Boolean::is_less_than_bits_le(&candidate_a, &candidate_b);
+                    assert_eq!(a < b, candidate.eject_value());
+                    if mode_a.is_constant() && mode_b.is_constant() {
+                        assert_scope!(0, 0, 0, 0);
+                    }
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_less_than_bits_le() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_bits in 0..5 {
+                    check_is_less_than_bits_le(mode_a, mode_b, num_bits);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_less_than_bits_le_constant_prefix() {
+        // The constant MSB decides the comparison, so the variable LSBs are ignored.
+        let mut a = bits_le(Mode::Private, 3, 2);
+        a.push(Boolean::constant(false));
+        let mut b = bits_le(Mode::Private, 0, 2);
+        b.push(Boolean::constant(true));
+
+        Circuit::scope("constant prefix", || {
+            let candidate = Boolean::is_less_than_bits_le(&a, &b);
+            assert!(candidate.is_constant());
+            assert!(candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_is_less_than_bits_le_mismatched_lengths() {
+        let a = bits_le(Mode::Private, 1, 2);
+        let b = bits_le(Mode::Private, 1, 3);
+        let _candidate = Boolean::is_less_than_bits_le(&a, &b);
+    }
+}
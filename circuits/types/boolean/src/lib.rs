@@ -21,16 +21,21 @@ pub mod adder;
 pub mod and;
 pub mod equal;
 pub mod from_bits;
+pub mod less_than;
+pub mod mux;
 pub mod nand;
 pub mod nor;
 pub mod not;
 pub mod or;
+pub mod reduce;
 pub mod subtractor;
 pub mod ternary;
 pub mod to_bits;
 // pub mod to_field;
 pub mod xor;
 
+pub use mux::Mux;
+
 #[cfg(test)]
 use snarkvm_circuits_environment::assert_scope;
 
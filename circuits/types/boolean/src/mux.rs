@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// A multiplexer, selecting one of many options of any circuit type that implements `Ternary`.
+pub struct Mux<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Mux<E> {
+    ///
+    /// Returns `options[index]`, where `index` is given as a list of `k` little-endian selector bits,
+    /// and exactly `2^k` options are given.
+    ///
+    /// The options are reduced as a binary tree of ternaries, where the `i`-th selector bit chooses
+    /// between adjacent pairs at the `i`-th level of the tree. This costs `2^k - 1` ternaries.
+    ///
+    pub fn select<T>(selector_bits_le: &[Boolean<E>], options: &[T]) -> T
+    where
+        T: Clone + Ternary<Boolean = Boolean<E>, Output = T>,
+    {
+        // Ensure the number of options matches the number of selector bits.
+        if selector_bits_le.len() >= usize::BITS as usize || options.len() != 1 << selector_bits_le.len() {
+            E::halt(format!(
+                "Mux expects 2^{} options, found {} options",
+                selector_bits_le.len(),
+                options.len()
+            ))
+        }
+
+        let mut level = options.to_vec();
+        for bit in selector_bits_le {
+            level = level.chunks(2).map(|pair| T::ternary(bit, &pair[1], &pair[0])).collect();
+        }
+
+        match level.pop() {
+            Some(output) => output,
+            None => E::halt("Mux is missing an output"),
+        }
+    }
+
+    ///
+    /// Returns `options[i]`, where `selectors` is a one-hot list with `selectors[i]` set to `true`.
+    ///
+    /// Enforces that exactly one selector is `true` with a single linear-combination constraint,
+    /// and selects the option as a chain of ternaries. This costs `n - 1` ternaries for `n` options.
+    ///
+    pub fn select_one_hot<T>(selectors: &[Boolean<E>], options: &[T]) -> T
+    where
+        T: Clone + Ternary<Boolean = Boolean<E>, Output = T>,
+    {
+        // Ensure there is one selector for each option.
+        if selectors.is_empty() || selectors.len() != options.len() {
+            E::halt(format!("Mux expects one selector per option, found {} selectors", selectors.len()))
+        }
+
+        // Ensure exactly one selector is set.
+        match selectors.iter().all(|selector| selector.is_constant()) {
+            true => {
+                if selectors.iter().filter(|selector| selector.eject_value()).count() != 1 {
+                    E::halt("Mux expects exactly one selector to be set")
+                }
+            }
+            false => {
+                let sum = selectors.iter().fold(E::zero(), |sum, selector| sum + &**selector);
+                E::assert_eq(sum, E::one());
+            }
+        }
+
+        // As the selectors are one-hot, `options[0]` is chosen if none of the remaining selectors are set.
+        selectors
+            .iter()
+            .zip_eq(options)
+            .skip(1)
+            .fold(options[0].clone(), |output, (selector, option)| T::ternary(selector, option, &output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
+    /// Returns the little-endian bits of `index`, with the given number of bits.
+    fn index_bits(mode: Mode, index: usize, num_bits: usize) -> Vec<Boolean<Circuit>> {
+        (0..num_bits).map(|i| Boolean::new(mode, (index >> i) & 1 == 1)).collect()
+    }
+
+    fn check_select(selector_mode: Mode, option_mode: Mode, num_bits: usize, num_private: usize) {
+        let values = (0..(1usize << num_bits)).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        for index in 0..values.len() {
+            let options = values.iter().map(|value| Boolean::<Circuit>::new(option_mode, *value)).collect::<Vec<_>>();
+            let selector_bits_le = index_bits(selector_mode, index, num_bits);
+
+            Circuit::scope(format!("{selector_mode} {option_mode} {index}"), || {
+                let candidate = Mux::select(&selector_bits_le, &options);
+                assert_eq!(values[index], candidate.eject_value());
+                assert_scope!(0, 0, num_private, num_private);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_select_one_hot(selector_mode: Mode, option_mode: Mode, num_options: usize, num_constraints: usize) {
+        let values = (0..num_options).map(|i| i % 2 == 1).collect::<Vec<_>>();
+        for index in 0..num_options {
+            let options = values.iter().map(|value| Boolean::<Circuit>::new(option_mode, *value)).collect::<Vec<_>>();
+            let selectors = (0..num_options).map(|i| Boolean::new(selector_mode, i == index)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{selector_mode} {option_mode} {index}"), || {
+                let candidate = Mux::select_one_hot(&selectors, &options);
+                assert_eq!(values[index], candidate.eject_value());
+                assert_scope!(0, 0, num_constraints.saturating_sub(1), num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_select_constant() {
+        check_select(Mode::Constant, Mode::Constant, 3, 0);
+        check_select(Mode::Constant, Mode::Private, 3, 0);
+    }
+
+    #[test]
+    fn test_select_public() {
+        check_select(Mode::Public, Mode::Private, 1, 1);
+        check_select(Mode::Public, Mode::Private, 3, 7);
+    }
+
+    #[test]
+    fn test_select_private() {
+        check_select(Mode::Private, Mode::Private, 1, 1);
+        check_select(Mode::Private, Mode::Private, 2, 3);
+        check_select(Mode::Private, Mode::Private, 4, 15);
+    }
+
+    #[test]
+    fn test_select_one_hot_private() {
+        check_select_one_hot(Mode::Private, Mode::Private, 1, 1);
+        check_select_one_hot(Mode::Private, Mode::Private, 5, 5);
+    }
+
+    #[test]
+    fn test_select_one_hot_not_one_hot_fails() {
+        let options = [false, true, true].map(|value| Boolean::<Circuit>::new(Mode::Private, value));
+        let selectors = [false, true, true].map(|value| Boolean::<Circuit>::new(Mode::Private, value));
+
+        let _candidate = Mux::select_one_hot(&selectors, &options);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_wrong_number_of_options() {
+        let options = [false, true, true].map(|value| Boolean::<Circuit>::new(Mode::Private, value));
+        let selector_bits_le = index_bits(Mode::Private, 1, 2);
+
+        let _candidate = Mux::select(&selector_bits_le, &options);
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    ///
+    /// Returns `true` if any of the given booleans is `true`.
+    ///
+    /// Constant `false` inputs are discarded, and a constant `true` input short-circuits
+    /// to a constant output. The remaining variables are reduced as a balanced tree of ORs,
+    /// costing `n - 1` constraints for `n` variables with a depth of `log2(n)`.
+    ///
+    pub fn any(bits: &[Boolean<E>]) -> Boolean<E> {
+        match bits.iter().any(|bit| bit.is_constant() && bit.eject_value()) {
+            true => Boolean::constant(true),
+            false => Self::reduce_balanced(Self::variables(bits), |a, b| a | b)
+                .unwrap_or_else(|| Boolean::constant(false)),
+        }
+    }
+
+    ///
+    /// Returns `true` if all of the given booleans are `true`.
+    ///
+    /// Constant `true` inputs are discarded, and a constant `false` input short-circuits
+    /// to a constant output. The remaining variables are reduced as a balanced tree of ANDs,
+    /// costing `n - 1` constraints for `n` variables with a depth of `log2(n)`.
+    ///
+    pub fn all(bits: &[Boolean<E>]) -> Boolean<E> {
+        match bits.iter().any(|bit| bit.is_constant() && !bit.eject_value()) {
+            true => Boolean::constant(false),
+            false => Self::reduce_balanced(Self::variables(bits), |a, b| a & b)
+                .unwrap_or_else(|| Boolean::constant(true)),
+        }
+    }
+
+    ///
+    /// Returns the XOR of all of the given booleans, i.e. `true` if an odd number of them are `true`.
+    ///
+    /// The parity of the constant inputs is computed natively, and the remaining variables
+    /// are reduced as a balanced tree of XORs, costing `n - 1` constraints for `n` variables.
+    ///
+    pub fn xor_all(bits: &[Boolean<E>]) -> Boolean<E> {
+        // Compute the parity of the constant inputs.
+        let parity = bits.iter().filter(|bit| bit.is_constant()).fold(false, |parity, bit| parity ^ bit.eject_value());
+
+        match Self::reduce_balanced(Self::variables(bits), |a, b| a ^ b) {
+            Some(output) => output ^ Boolean::constant(parity),
+            None => Boolean::constant(parity),
+        }
+    }
+
+    ///
+    /// Returns the number of `true` booleans in the given list, as little-endian bits.
+    ///
+    /// The output has as many bits as are needed to represent `bits.len()`. For variable inputs,
+    /// the bits of the count are witnessed and checked against the sum of the inputs with a single
+    /// linear-combination constraint, costing `k + 1` constraints for a `k`-bit output.
+    ///
+    pub fn count(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Compute the number of bits needed to represent the count.
+        let num_bits = (usize::BITS - bits.len().leading_zeros()) as usize;
+
+        // Compute the count natively.
+        let count = bits.iter().filter(|bit| bit.eject_value()).count();
+
+        // If all of the inputs are constant, return the constant count.
+        if bits.iter().all(|bit| bit.is_constant()) {
+            return (0..num_bits).map(|i| Boolean::constant((count >> i) & 1 == 1)).collect();
+        }
+
+        // Witness the bits of the count.
+        let count_bits_le =
+            (0..num_bits).map(|i| Boolean::new(Mode::Private, (count >> i) & 1 == 1)).collect::<Vec<_>>();
+
+        // Ensure the sum of the inputs matches the weighted sum of the count bits.
+        let sum = bits.iter().fold(E::zero(), |sum, bit| sum + &**bit);
+        let mut coefficient = E::BaseField::one();
+        let mut weighted_sum = E::zero();
+        for bit in &count_bits_le {
+            weighted_sum += &**bit * coefficient;
+            coefficient = coefficient.double();
+        }
+        E::assert_eq(sum, weighted_sum);
+
+        count_bits_le
+    }
+
+    /// Returns the non-constant booleans in the given list.
+    fn variables(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        bits.iter().filter(|bit| !bit.is_constant()).cloned().collect()
+    }
+
+    /// Reduces the given booleans pairwise with `op` as a balanced tree, returning `None` if the list is empty.
+    fn reduce_balanced(
+        mut bits: Vec<Boolean<E>>,
+        op: impl Fn(&Boolean<E>, &Boolean<E>) -> Boolean<E>,
+    ) -> Option<Boolean<E>> {
+        while bits.len() > 1 {
+            bits = bits
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => op(a, b),
+                    [a] => a.clone(),
+                    _ => unreachable!("Chunks of two always contain one or two elements"),
+                })
+                .collect();
+        }
+        bits.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
+    const NUM_INPUTS: [usize; 6] = [0, 1, 2, 3, 8, 13];
+
+    /// Returns every combination of booleans of the given length, for short lengths, and a sample otherwise.
+    fn sample_values(num_inputs: usize) -> Vec<Vec<bool>> {
+        match num_inputs <= 3 {
+            true => (0..(1u32 << num_inputs)).map(|v| (0..num_inputs).map(|i| (v >> i) & 1 == 1).collect()).collect(),
+            false => vec![
+                vec![false; num_inputs],
+                vec![true; num_inputs],
+                (0..num_inputs).map(|i| i % 2 == 0).collect(),
+                (0..num_inputs).map(|i| i % 3 == 1).collect(),
+            ],
+        }
+    }
+
+    fn check_reduce(
+        name: &str,
+        mode: Mode,
+        reduce: impl Fn(&[Boolean<Circuit>]) -> Boolean<Circuit>,
+        expected: impl Fn(&[bool]) -> bool,
+    ) {
+        for num_inputs in NUM_INPUTS {
+            for values in sample_values(num_inputs) {
+                let bits = values.iter().map(|value| Boolean::<Circuit>::new(mode, *value)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{name} {mode} {values:?}"), || {
+                    let candidate = reduce(&bits);
+                    assert_eq!(expected(&values), candidate.eject_value());
+                    match mode.is_constant() {
+                        true => assert_scope!(0, 0, 0, 0),
+                        false => assert_scope!(0, 0, num_inputs.saturating_sub(1), num_inputs.saturating_sub(1)),
+                    }
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_any() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_reduce("any", mode, Boolean::any, |values| values.iter().any(|v| *v));
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_reduce("all", mode, Boolean::all, |values| values.iter().all(|v| *v));
+        }
+    }
+
+    #[test]
+    fn test_xor_all() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_reduce("xor_all", mode, Boolean::xor_all, |values| values.iter().fold(false, |a, b| a ^ b));
+        }
+    }
+
+    #[test]
+    fn test_any_all_constant_short_circuit() {
+        let bits = vec![
+            Boolean::<Circuit>::new(Mode::Private, false),
+            Boolean::<Circuit>::new(Mode::Constant, true),
+            Boolean::<Circuit>::new(Mode::Private, false),
+        ];
+        Circuit::scope("any", || {
+            let candidate = Boolean::any(&bits);
+            assert!(candidate.is_constant());
+            assert!(candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+
+        let bits = vec![
+            Boolean::<Circuit>::new(Mode::Private, true),
+            Boolean::<Circuit>::new(Mode::Constant, false),
+            Boolean::<Circuit>::new(Mode::Private, true),
+        ];
+        Circuit::scope("all", || {
+            let candidate = Boolean::all(&bits);
+            assert!(candidate.is_constant());
+            assert!(!candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_count() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in NUM_INPUTS {
+                for values in sample_values(num_inputs) {
+                    let bits = values.iter().map(|value| Boolean::<Circuit>::new(mode, *value)).collect::<Vec<_>>();
+                    let num_bits = (usize::BITS - num_inputs.leading_zeros()) as usize;
+
+                    Circuit::scope(format!("count {mode} {values:?}"), || {
+                        let candidate = Boolean::count(&bits);
+                        assert_eq!(num_bits, candidate.len());
+
+                        let count =
+                            candidate.iter().rev().fold(0, |count, bit| (count << 1) | bit.eject_value() as usize);
+                        assert_eq!(values.iter().filter(|v| **v).count(), count);
+
+                        match mode.is_constant() || num_inputs == 0 {
+                            true => assert_scope!(0, 0, 0, 0),
+                            false => assert_scope!(0, 0, num_bits, num_bits + 1),
+                        }
+                    });
+                    assert!(Circuit::is_satisfied());
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const N: usize> FromBits for Bytes<E, N> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes new bytes from a list of `8 * N` little-endian bits, as output by `to_bits_le`.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits matches the number of bytes.
+        let num_bits = bits_le.len();
+        match num_bits == 8 * N {
+            true => Self { bytes: bits_le.chunks(8).map(U8::from_bits_le).collect() },
+            false => E::halt(format!("Attempted to instantiate {N} bytes from {num_bits} bits")),
+        }
+    }
+
+    /// Initializes new bytes from a list of `8 * N` big-endian bits, as output by `to_bits_be`.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Reverse the given bits from big-endian into little-endian.
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_from_bits(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let expected = (0..16).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let given = Bytes::<Circuit, 16>::new(mode, expected.clone());
+            let bits_le = given.to_bits_le();
+            let bits_be = given.to_bits_be();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = Bytes::<Circuit, 16>::from_bits_le(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+                let candidate = Bytes::<Circuit, 16>::from_bits_be(&bits_be);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_constant() {
+        check_from_bits(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_bits_public() {
+        check_from_bits(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_bits_private() {
+        check_from_bits(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_wrong_length() {
+        let bits_le = vec![Boolean::<Circuit>::new(Mode::Private, true); 12];
+        let _candidate = Bytes::<Circuit, 2>::from_bits_le(&bits_le);
+    }
+}
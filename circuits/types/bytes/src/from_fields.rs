@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuits_types_field::Field;
+
+impl<E: Environment, const N: usize> FromField for Bytes<E, N> {
+    type Field = Field<E>;
+
+    ///
+    /// Initializes new bytes from a base field, as cast by `to_field`.
+    /// Halts if the bytes do not fit in the data bits of a base field.
+    ///
+    /// Enforces that the base field element fits in `N` bytes.
+    ///
+    fn from_field(field: &Self::Field) -> Self {
+        // Ensure the bytes fit in a base field.
+        if 8 * N > E::BaseField::size_in_data_bits() {
+            E::halt(format!("Attempted to cast a base field into {N} bytes"))
+        }
+        Self::from_bits_le(&field.to_lower_bits_le(8 * N))
+    }
+}
+
+impl<E: Environment, const N: usize> Bytes<E, N> {
+    ///
+    /// Initializes new bytes from a list of base fields, as packed by `to_fields`.
+    ///
+    /// Enforces that each base field element fits in its chunk of bytes.
+    ///
+    pub fn from_fields(fields: &[Field<E>]) -> Self {
+        // Compute the number of data bits in each chunk, aligned to bytes.
+        let num_data_bits = (E::BaseField::size_in_data_bits() / 8) * 8;
+
+        // Ensure the number of field elements matches the number of bytes.
+        let num_chunks = (8 * N + num_data_bits - 1) / num_data_bits;
+        if fields.len() != num_chunks {
+            E::halt(format!("Expected {num_chunks} field elements for {N} bytes, found {}", fields.len()))
+        }
+
+        // Unpack the bits of each field element, where the last chunk holds the remaining bits.
+        let bits_le = fields
+            .iter()
+            .enumerate()
+            .flat_map(|(index, field)| field.to_lower_bits_le(num_data_bits.min(8 * N - index * num_data_bits)))
+            .collect::<Vec<_>>();
+
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_from_field(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let expected = (0..4).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let field = Bytes::<Circuit, 4>::new(mode, expected.clone()).to_field();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = Bytes::<Circuit, 4>::from_field(&field);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_fields(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let expected = (0..64).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let fields = Bytes::<Circuit, 64>::new(mode, expected.clone()).to_fields();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = Bytes::<Circuit, 64>::from_fields(&fields);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!();
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_field_constant() {
+        check_from_field(Mode::Constant, 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_field_public() {
+        check_from_field(Mode::Public, 0, 0, 32, 33);
+    }
+
+    #[test]
+    fn test_from_field_private() {
+        check_from_field(Mode::Private, 0, 0, 32, 33);
+    }
+
+    #[test]
+    fn test_from_field_overflow() {
+        // A base field element with more than 32 bits does not fit in 4 bytes.
+        let field = Bytes::<Circuit, 5>::new(Mode::Private, vec![1, 2, 3, 4, 5]).to_field();
+        let _candidate = Bytes::<Circuit, 4>::from_field(&field);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_fields_constant() {
+        check_from_fields(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_fields_public() {
+        check_from_fields(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_fields_private() {
+        check_from_fields(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fields_wrong_length() {
+        let fields = Bytes::<Circuit, 4>::new(Mode::Private, vec![1, 2, 3, 4]).to_fields();
+        let _candidate = Bytes::<Circuit, 64>::from_fields(&fields);
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuits_types_integers::Integer;
+
+impl<E: Environment, const N: usize> Bytes<E, N> {
+    ///
+    /// Initializes new bytes from the little-endian bytes of the given integer.
+    /// Halts if the integer is not `N` bytes.
+    ///
+    /// As the bits of the integer are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn from_integer<I: IntegerType>(integer: &Integer<E, I>) -> Self {
+        match I::BITS == 8 * N {
+            true => Self::from_bits_le(&integer.to_bits_le()),
+            false => E::halt(format!("Attempted to cast a {}-bit integer into {N} bytes", I::BITS)),
+        }
+    }
+
+    ///
+    /// Casts the bytes into an integer, in little-endian order.
+    /// Halts if the integer is not `N` bytes.
+    ///
+    /// As the bits of the bytes are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn to_integer<I: IntegerType>(&self) -> Integer<E, I> {
+        match I::BITS == 8 * N {
+            true => Integer::from_bits_le(&self.to_bits_le()),
+            false => E::halt(format!("Attempted to cast {N} bytes into a {}-bit integer", I::BITS)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_integer<I: IntegerType, const N: usize>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let expected: I = UniformRand::rand(&mut test_rng());
+            let integer = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let bytes = Bytes::<Circuit, N>::from_integer(&integer);
+                assert_eq!(expected.to_bytes_le().unwrap(), bytes.eject_value());
+                let candidate = bytes.to_integer::<I>();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_u32_constant() {
+        check_integer::<u32, 4>(Mode::Constant);
+    }
+
+    #[test]
+    fn test_u64_public() {
+        check_integer::<u64, 8>(Mode::Public);
+    }
+
+    #[test]
+    fn test_i64_private() {
+        check_integer::<i64, 8>(Mode::Private);
+    }
+
+    #[test]
+    fn test_u128_private() {
+        check_integer::<u128, 16>(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_integer_wrong_width() {
+        let integer = Integer::<Circuit, u32>::new(Mode::Private, 1);
+        let _candidate = Bytes::<Circuit, 8>::from_integer(&integer);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_integer_wrong_width() {
+        let candidate = Bytes::<Circuit, 4>::new(Mode::Private, vec![1, 2, 3, 4]);
+        let _integer = candidate.to_integer::<u64>();
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+pub mod equal;
+pub mod from_bits;
+pub mod from_fields;
+pub mod integers;
+pub mod slice;
+pub mod string;
+pub mod to_bits;
+pub mod to_fields;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use snarkvm_circuits_environment::prelude::*;
+use snarkvm_circuits_types_boolean::Boolean;
+use snarkvm_circuits_types_integers::U8;
+
+/// A fixed-length array of `N` bytes.
+#[derive(Clone)]
+pub struct Bytes<E: Environment, const N: usize> {
+    bytes: Vec<U8<E>>,
+}
+
+impl<E: Environment, const N: usize> Bytes<E, N> {
+    /// Initializes a new instance of bytes from the given bytes.
+    /// Halts if the number of given bytes is not `N`.
+    pub fn from_bytes(bytes: &[U8<E>]) -> Self {
+        match bytes.len() == N {
+            true => Self { bytes: bytes.to_vec() },
+            false => E::halt(format!("Attempted to instantiate {N} bytes from {} bytes", bytes.len())),
+        }
+    }
+
+    /// Returns the bytes of `self`.
+    pub fn as_bytes(&self) -> &[U8<E>] {
+        &self.bytes
+    }
+}
+
+impl<E: Environment, const N: usize> Inject for Bytes<E, N> {
+    type Primitive = Vec<u8>;
+
+    /// Initializes a new instance of bytes. Halts if the number of given bytes is not `N`.
+    fn new(mode: Mode, bytes: Self::Primitive) -> Self {
+        match bytes.len() == N {
+            true => Self { bytes: bytes.into_iter().map(|byte| U8::new(mode, byte)).collect() },
+            false => E::halt(format!("Attempted to allocate {N} bytes from {} bytes", bytes.len())),
+        }
+    }
+}
+
+impl<E: Environment, const N: usize> Eject for Bytes<E, N> {
+    type Primitive = Vec<u8>;
+
+    ///
+    /// Ejects the mode of the bytes.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.bytes.eject_mode()
+    }
+
+    ///
+    /// Ejects the bytes as a list of bytes.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        self.bytes.eject_value()
+    }
+}
+
+impl<E: Environment, const N: usize> fmt::Debug for Bytes<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.eject_value())
+    }
+}
+
+impl<E: Environment, const N: usize> fmt::Display for Bytes<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_new(mode: Mode, num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let expected = (0..32).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = Bytes::<Circuit, 32>::new(mode, expected.clone());
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_new_constant() {
+        check_new(Mode::Constant, 256, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        check_new(Mode::Public, 0, 256, 0, 256);
+    }
+
+    #[test]
+    fn test_new_private() {
+        check_new(Mode::Private, 0, 0, 256, 256);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_wrong_length() {
+        let _candidate = Bytes::<Circuit, 4>::new(Mode::Private, vec![0u8; 5]);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = [1u8, 2, 3, 4].iter().map(|byte| U8::<Circuit>::new(Mode::Private, *byte)).collect::<Vec<_>>();
+        let candidate = Bytes::<Circuit, 4>::from_bytes(&bytes);
+        assert_eq!(vec![1u8, 2, 3, 4], candidate.eject_value());
+        assert_eq!(4, candidate.as_bytes().len());
+    }
+
+    #[test]
+    fn test_display() {
+        let candidate = Bytes::<Circuit, 2>::new(Mode::Public, vec![1, 2]);
+        assert_eq!("[1, 2].public", format!("{}", candidate));
+        assert_eq!("[1, 2]", format!("{:?}", candidate));
+    }
+}
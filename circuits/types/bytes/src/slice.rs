@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const N: usize> Bytes<E, N> {
+    ///
+    /// Returns the `M` bytes of `self`, starting at the byte index `start`.
+    ///
+    /// As the bytes are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn slice<const M: usize>(&self, start: usize) -> Bytes<E, M> {
+        // Ensure the slice is within the bounds of the bytes.
+        match start.checked_add(M) {
+            Some(end) if end <= N => Bytes { bytes: self.bytes[start..end].to_vec() },
+            _ => E::halt(format!("Attempted to take {M} bytes at {start} from {N} bytes")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    fn check_slice(mode: Mode) {
+        let rng = &mut test_rng();
+        let given = (0..16).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        let candidate = Bytes::<Circuit, 16>::new(mode, given.clone());
+
+        for start in 0..=12 {
+            Circuit::scope(&format!("{} {}", mode, start), || {
+                let slice = candidate.slice::<4>(start);
+                assert_eq!(&given[start..start + 4], slice.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+
+        // Ensure the empty and full slices are supported.
+        assert!(candidate.slice::<0>(16).eject_value().is_empty());
+        assert_eq!(given, candidate.slice::<16>(0).eject_value());
+    }
+
+    #[test]
+    fn test_slice_constant() {
+        check_slice(Mode::Constant);
+    }
+
+    #[test]
+    fn test_slice_public() {
+        check_slice(Mode::Public);
+    }
+
+    #[test]
+    fn test_slice_private() {
+        check_slice(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let candidate = Bytes::<Circuit, 16>::new(Mode::Private, vec![0u8; 16]);
+        let _slice = candidate.slice::<4>(13);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuits_types_string::StringType;
+
+impl<E: Environment, const N: usize> Bytes<E, N> {
+    ///
+    /// Initializes new bytes from the UTF-8 bytes of the given string.
+    /// Halts if the string is not `N` bytes.
+    ///
+    /// As the bytes of a string are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn from_string(string: &StringType<E>) -> Self {
+        let bits_le = string.to_bits_le();
+        match bits_le.len() == 8 * N {
+            true => Self::from_bits_le(&bits_le),
+            false => E::halt(format!("Attempted to cast a string of size {} into {N} bytes", bits_le.len() / 8)),
+        }
+    }
+
+    ///
+    /// Casts the bytes into a string.
+    ///
+    /// Note: This method does not enforce that the bytes are well-formed UTF-8,
+    /// which may be checked with `StringType::is_valid_utf8`.
+    ///
+    pub fn to_string_type(&self) -> StringType<E> {
+        StringType::from_bits_le(&self.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
+    fn check_string(mode: Mode) {
+        let expected = "snarkVM-✓";
+        let string = StringType::<Circuit>::new(mode, expected.to_string());
+
+        Circuit::scope(&format!("{}", mode), || {
+            let bytes = Bytes::<Circuit, 11>::from_string(&string);
+            assert_eq!(expected.as_bytes(), bytes.eject_value());
+            let candidate = bytes.to_string_type();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_string_constant() {
+        check_string(Mode::Constant);
+    }
+
+    #[test]
+    fn test_string_public() {
+        check_string(Mode::Public);
+    }
+
+    #[test]
+    fn test_string_private() {
+        check_string(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_string_type_malformed() {
+        // The bytes are not well-formed UTF-8, which is detected by the string.
+        let bytes = Bytes::<Circuit, 2>::new(Mode::Private, vec![0xc0, 0x80]);
+        let candidate = bytes.to_string_type();
+        assert!(!candidate.is_valid_utf8().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_string_wrong_length() {
+        let string = StringType::<Circuit>::new(Mode::Private, "snarkVM".to_string());
+        let _candidate = Bytes::<Circuit, 8>::from_string(&string);
+    }
+}
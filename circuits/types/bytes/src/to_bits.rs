@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const N: usize> ToBits for Bytes<E, N> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`, with the bits of each byte in little-endian order.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_le()
+    }
+
+    /// Outputs the big-endian bit representation of `self`.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_be()
+    }
+}
+
+impl<E: Environment, const N: usize> ToBits for &Bytes<E, N> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`, with the bits of each byte in little-endian order.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bytes.iter().flat_map(|byte| byte.to_bits_le()).collect()
+    }
+
+    /// Outputs the big-endian bit representation of `self`.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.to_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_bits_le(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let given = (0..16).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let expected = given.iter().flat_map(|byte| (0..8).map(move |j| (byte >> j) & 1 == 1)).collect::<Vec<_>>();
+            let candidate = Bytes::<Circuit, 16>::new(mode, given);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_bits_le();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_to_bits_be(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let given = (0..16).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let expected =
+                given.iter().rev().flat_map(|byte| (0..8).rev().map(move |j| (byte >> j) & 1 == 1)).collect::<Vec<_>>();
+            let candidate = Bytes::<Circuit, 16>::new(mode, given);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_bits_be();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bits_le_constant() {
+        check_to_bits_le(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bits_le_public() {
+        check_to_bits_le(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bits_le_private() {
+        check_to_bits_le(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_bits_be_constant() {
+        check_to_bits_be(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bits_be_public() {
+        check_to_bits_be(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bits_be_private() {
+        check_to_bits_be(Mode::Private);
+    }
+}
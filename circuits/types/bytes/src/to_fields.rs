@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuits_types_field::Field;
+
+impl<E: Environment, const N: usize> ToField for Bytes<E, N> {
+    type Field = Field<E>;
+
+    ///
+    /// Casts the bytes into a base field, in little-endian order.
+    /// Halts if the bytes do not fit in the data bits of a base field.
+    ///
+    /// As the bits of the bytes are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    fn to_field(&self) -> Self::Field {
+        // Ensure the bytes fit in a base field.
+        if 8 * N > E::BaseField::size_in_data_bits() {
+            E::halt(format!("Attempted to cast {N} bytes into a base field"))
+        }
+        Field::from_bits_le(&self.to_bits_le())
+    }
+}
+
+impl<E: Environment, const N: usize> ToFields for Bytes<E, N> {
+    type Field = Field<E>;
+
+    ///
+    /// Casts the bytes into a list of base fields.
+    ///
+    /// The bytes are packed in little-endian order into chunks of `E::BaseField::size_in_data_bits() / 8`
+    /// bytes each, where the last chunk holds the remaining bytes. As the number of bytes is fixed,
+    /// no padding is required for the packing to be injective, and this method costs 0 constraints.
+    ///
+    fn to_fields(&self) -> Vec<Self::Field> {
+        // Compute the number of data bits in each chunk, aligned to bytes.
+        let num_data_bits = (E::BaseField::size_in_data_bits() / 8) * 8;
+
+        // Recover the base field element for each chunk.
+        self.to_bits_le().chunks(num_data_bits).map(Field::from_bits_le).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, FromBytes, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the base field elements packed from the given bytes, in chunks of the given size.
+    fn native_bytes_to_fields(bytes: &[u8], num_chunk_bytes: usize) -> Vec<<Circuit as Environment>::BaseField> {
+        let num_bytes = (<Circuit as Environment>::BaseField::size_in_bits() + 7) / 8;
+        bytes
+            .chunks(num_chunk_bytes)
+            .map(|chunk| {
+                let mut chunk = chunk.to_vec();
+                chunk.resize(num_bytes, 0u8);
+                <Circuit as Environment>::BaseField::from_bytes_le(&chunk).unwrap()
+            })
+            .collect()
+    }
+
+    fn check_to_field(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let given = (0..31).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let expected = native_bytes_to_fields(&given, 31)[0];
+            let candidate = Bytes::<Circuit, 31>::new(mode, given);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_field();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_to_fields(mode: Mode) {
+        let rng = &mut test_rng();
+        let num_chunk_bytes = <Circuit as Environment>::BaseField::size_in_data_bits() / 8;
+
+        for i in 0..ITERATIONS {
+            let given = (0..64).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let expected = native_bytes_to_fields(&given, num_chunk_bytes);
+            let candidate = Bytes::<Circuit, 64>::new(mode, given);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = candidate.to_fields();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_field_constant() {
+        check_to_field(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_field_public() {
+        check_to_field(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_field_private() {
+        check_to_field(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_field_exceeds_capacity() {
+        let candidate = Bytes::<Circuit, 32>::new(Mode::Private, vec![0u8; 32]);
+        let _candidate = candidate.to_field();
+    }
+
+    #[test]
+    fn test_to_fields_constant() {
+        check_to_fields(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_fields_public() {
+        check_to_fields(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_fields_private() {
+        check_to_fields(Mode::Private);
+    }
+}
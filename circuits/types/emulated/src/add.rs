@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Add<EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn add(self, other: EmulatedField<E, P>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Add<EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn add(self, other: EmulatedField<E, P>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Add<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn add(self, other: &EmulatedField<E, P>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Add<&EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    ///
+    /// Returns the sum of `self` and `other`, without reducing by the modulus.
+    ///
+    /// The limbs are added pairwise, which grows them by one bit. If the limbs would exceed
+    /// `MAX_LIMB_SIZE_IN_BITS` bits, the sum is reduced, at the cost of a multiplication.
+    ///
+    fn add(self, other: &EmulatedField<E, P>) -> Self::Output {
+        // If the operands are constant, return the sum as a constant.
+        if self.is_constant() && other.is_constant() {
+            return EmulatedField::constant((self.unreduced_value() + other.unreduced_value()) % P::modulus());
+        }
+
+        let limbs = self.limbs.iter().zip_eq(&other.limbs).map(|(a, b)| a + b).collect();
+        EmulatedField::from_limbs(limbs, self.limb_size_in_bits.max(other.limb_size_in_bits) + 1)
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> AddAssign<EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn add_assign(&mut self, other: EmulatedField<E, P>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> AddAssign<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn add_assign(&mut self, other: &EmulatedField<E, P>) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_add<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let expected = (&first + &second) % P::modulus();
+
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first);
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scope(format!("Add {mode_a} {mode_b} {i}"), || {
+                let candidate = &a + &b;
+                assert_eq!(expected, candidate.eject_value());
+                match mode_a.is_constant() && mode_b.is_constant() {
+                    true => assert_scope!(P::num_limbs(), 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, 0),
+                }
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_add_chain<P: EmulatedParameters>(mode: Mode) {
+        let value = sample::<P>();
+        let a = EmulatedField::<Circuit, P>::new(mode, value.clone());
+
+        // Add the element to itself until its limbs are reduced.
+        let mut candidate = a.clone();
+        for i in 2..=(2 * (MAX_LIMB_SIZE_IN_BITS - LIMB_SIZE_IN_BITS) as u64) {
+            candidate += &a;
+            assert_eq!((&value * i) % P::modulus(), candidate.eject_value());
+            assert!(candidate.limb_size_in_bits() <= MAX_LIMB_SIZE_IN_BITS);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_add::<Secp256k1>(mode_a, mode_b);
+                check_add::<Ed25519>(mode_a, mode_b);
+                check_add::<Bls12_377Fq>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_chain() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_add_chain::<Secp256k1>(mode);
+            check_add_chain::<Ed25519>(mode);
+            check_add_chain::<Bls12_377Fq>(mode);
+        }
+    }
+}
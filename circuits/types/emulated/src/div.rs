@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Div<EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn div(self, other: EmulatedField<E, P>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Div<EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn div(self, other: EmulatedField<E, P>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Div<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn div(self, other: &EmulatedField<E, P>) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Div<&EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    /// Returns `(self / other) mod p`, halting if `other` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: &EmulatedField<E, P>) -> Self::Output {
+        self * &other.inv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_div<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first.clone());
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second.clone());
+
+            Circuit::scope(format!("Div {mode_a} {mode_b} {i}"), || {
+                let candidate = &a / &b;
+                assert_eq!(first, (candidate.eject_value() * &second) % P::modulus());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_div() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_div::<Secp256k1>(mode_a, mode_b);
+                check_div::<Ed25519>(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Equal<Self> for EmulatedField<E, P> {
+    type Boolean = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal modulo `p`.
+    ///
+    /// The difference of `self` and `other` is reduced to its canonical form, whose limbs are then checked for zero.
+    ///
+    fn is_equal(&self, other: &Self) -> Self::Boolean {
+        // If the operands are constant, return the result as a constant.
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() == other.eject_value());
+        }
+
+        let difference = (self - other).reduce();
+        let zero = Field::zero();
+        Boolean::all(&difference.limbs.iter().map(|limb| limb.is_equal(&zero)).collect::<Vec<_>>())
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal modulo `p`.
+    ///
+    fn is_not_equal(&self, other: &Self) -> Self::Boolean {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_is_equal<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first.clone());
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second.clone());
+            let c = EmulatedField::<Circuit, P>::new(mode_b, first.clone());
+
+            Circuit::scope(format!("Equal {mode_a} {mode_b} {i}"), || {
+                assert_eq!(first == second, a.is_equal(&b).eject_value());
+                assert_eq!(first != second, a.is_not_equal(&b).eject_value());
+                assert!(a.is_equal(&c).eject_value());
+                assert!(!a.is_not_equal(&c).eject_value());
+                assert_scope!();
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_is_equal_unreduced<P: EmulatedParameters>(mode: Mode) {
+        let (first, second) = (sample::<P>(), sample::<P>());
+        let a = EmulatedField::<Circuit, P>::new(mode, first);
+        let b = EmulatedField::<Circuit, P>::new(mode, second);
+
+        // Ensure elements with distinct limbs, that are congruent modulo `p`, are equal.
+        let left = &(&a + &b) - &b;
+        let right = &(&a - &b) + &b;
+        assert!(left.is_equal(&a).eject_value());
+        assert!(right.is_equal(&left).eject_value());
+        assert!(!(&left + &EmulatedField::one()).is_equal(&right).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_is_equal::<Secp256k1>(mode_a, mode_b);
+                check_is_equal::<Ed25519>(mode_a, mode_b);
+                check_is_equal::<Bls12_377Fq>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_equal_unreduced() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_is_equal_unreduced::<Secp256k1>(mode);
+            check_is_equal_unreduced::<Ed25519>(mode);
+            check_is_equal_unreduced::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_zero_and_one() {
+        let zero = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, BigUint::from(0u64));
+        let one = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, BigUint::from(1u64));
+        assert!(zero.is_zero().eject_value());
+        assert!(!zero.is_one().eject_value());
+        assert!(one.is_one().eject_value());
+        assert!(!one.is_zero().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
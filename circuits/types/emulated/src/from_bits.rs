@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> EmulatedField<E, P> {
+    ///
+    /// Returns the emulated field element `value mod p`,
+    /// where `value` is given as at most `num_limbs * LIMB_SIZE_IN_BITS` little-endian bits.
+    ///
+    /// The bits are packed into limbs, which are range-checked by construction, so this costs no constraints.
+    ///
+    pub fn from_bits_le_reduced(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is within the capacity of the limbs.
+        let num_bits = P::num_limbs() * LIMB_SIZE_IN_BITS;
+        if bits_le.len() > num_bits {
+            E::halt(format!("Attempted to pack {} bits into an emulated field element", bits_le.len()))
+        }
+
+        let mut bits_le = bits_le.to_vec();
+        bits_le.resize(num_bits, Boolean::constant(false));
+        let limbs = bits_le.chunks(LIMB_SIZE_IN_BITS).map(Field::from_bits_le).collect();
+
+        Self { limbs, limb_size_in_bits: LIMB_SIZE_IN_BITS, _parameters: PhantomData }
+    }
+
+    ///
+    /// Returns the emulated field element `other mod p`,
+    /// where `other` is an element of another emulated field with at most as many limbs.
+    ///
+    pub fn from_emulated<Q: EmulatedParameters>(other: &EmulatedField<E, Q>) -> Self {
+        if Q::num_limbs() > P::num_limbs() {
+            E::halt(format!("Attempted to cast {} limbs into {} limbs", Q::num_limbs(), P::num_limbs()))
+        }
+
+        // Reduce `other` to its canonical form, so that its limbs hold the value of `other mod q`.
+        let mut limbs = match other.is_constant() {
+            true => EmulatedField::<E, Q>::constant(other.eject_value()).limbs,
+            false => other.reduce().limbs,
+        };
+        limbs.resize(P::num_limbs(), Field::zero());
+
+        Self { limbs, limb_size_in_bits: LIMB_SIZE_IN_BITS, _parameters: PhantomData }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    fn check_from_bits_le_reduced<P: EmulatedParameters>(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for num_bits in [0, 1, 64, 255, P::num_limbs() * LIMB_SIZE_IN_BITS] {
+            for i in 0..ITERATIONS {
+                let bits_le = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+                let expected = bits_le.iter().rev().fold(BigUint::from(0u64), |value, bit| (value << 1) + *bit as u64);
+                let expected = expected % P::modulus();
+
+                let candidate_bits_le =
+                    bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("FromBits {mode} {num_bits} {i}"), || {
+                    let candidate = EmulatedField::<Circuit, P>::from_bits_le_reduced(&candidate_bits_le);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                });
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_reduced() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_from_bits_le_reduced::<Secp256k1>(mode);
+            check_from_bits_le_reduced::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_reduced_exceeds_modulus() {
+        // The maximum 256-bit value exceeds the modulus.
+        let bits_le = (0..256).map(|_| Boolean::<Circuit>::new(Mode::Private, true)).collect::<Vec<_>>();
+        let candidate = EmulatedField::<Circuit, Secp256k1>::from_bits_le_reduced(&bits_le);
+        let expected = (BigUint::from(1u64) << 256) - 1u64 - Secp256k1::modulus();
+        assert_eq!(expected, candidate.eject_value());
+
+        // The element is reduced to its canonical form when it is compared.
+        assert!(candidate.is_equal(&EmulatedField::constant(expected)).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_reduced_too_many_bits() {
+        let bits_le = (0..257).map(|_| Boolean::<Circuit>::new(Mode::Private, false)).collect::<Vec<_>>();
+        let _candidate = EmulatedField::<Circuit, Secp256k1>::from_bits_le_reduced(&bits_le);
+    }
+
+    #[test]
+    fn test_from_emulated() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for value in [sample::<Secp256k1>(), Secp256k1::modulus() - 1u64, Secp256k1ScalarParameters::modulus()] {
+                let expected = &value % Secp256k1ScalarParameters::modulus();
+                let a = EmulatedField::<Circuit, Secp256k1>::new(mode, value);
+
+                // Grow the limbs with an addition, so that the cast reduces `a` first.
+                let sum = &a + &EmulatedField::zero();
+
+                Circuit::scope(format!("FromEmulated {mode}"), || {
+                    let candidate = EmulatedField::<Circuit, Secp256k1ScalarParameters>::from_emulated(&sum);
+                    assert_eq!(expected, candidate.eject_value());
+                });
+            }
+
+            // An element of a field with fewer limbs is padded.
+            let value = sample::<Ed25519>();
+            let a = EmulatedField::<Circuit, Ed25519>::new(mode, value.clone());
+            assert_eq!(value, EmulatedField::<Circuit, Bls12_377Fq>::from_emulated(&a).eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_emulated_too_many_limbs() {
+        let a = EmulatedField::<Circuit, Bls12_377Fq>::new(Mode::Private, sample::<Bls12_377Fq>());
+        let _candidate = EmulatedField::<Circuit, Secp256k1>::from_emulated(&a);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Inv for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn inv(self) -> Self::Output {
+        (&self).inv()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Inv for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    ///
+    /// Returns `self^(-1) mod p`, halting if `self` is zero.
+    ///
+    /// The inverse `w` is witnessed in canonical form, and `self * w = q * p + 1` is enforced over the integers.
+    ///
+    fn inv(self) -> Self::Output {
+        // Compute the inverse natively, as `self^(p - 2)`, since the modulus is prime.
+        let modulus = P::modulus();
+        let value = self.eject_value();
+        if value == BigUint::from(0u64) {
+            E::halt("Failed to compute the inverse of an emulated field element")
+        }
+        let inverse = value.modpow(&(&modulus - 2u64), &modulus);
+
+        // If `self` is constant, return the inverse as a constant.
+        if self.is_constant() {
+            return EmulatedField::constant(inverse);
+        }
+
+        // Witness the inverse, and ensure it is less than the modulus.
+        let output = EmulatedField::new(Mode::Private, inverse);
+
+        // Witness the quotient, and ensure `self * w = q * p + 1`.
+        let quotient = (self.unreduced_value() * output.unreduced_value()) / &modulus;
+        let num_quotient_limbs = EmulatedField::<E, P>::num_quotient_limbs(self.limb_size_in_bits, LIMB_SIZE_IN_BITS);
+        let (quotient, _) = EmulatedField::<E, P>::new_witness_limbs(&quotient, num_quotient_limbs);
+        EmulatedField::enforce_mul(self, &output, &quotient, &EmulatedField::<E, P>::one().limbs);
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_inv<P: EmulatedParameters>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let value = sample::<P>();
+            let a = EmulatedField::<Circuit, P>::new(mode, value.clone());
+
+            Circuit::scope(format!("Inv {mode} {i}"), || {
+                let candidate = (&a).inv();
+                assert_eq!(BigUint::from(1u64), (&value * candidate.eject_value()) % P::modulus());
+                match mode.is_constant() {
+                    true => assert_scope!(P::num_limbs(), 0, 0, 0),
+                    false => assert_scope!(),
+                }
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_inv() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_inv::<Secp256k1>(mode);
+            check_inv::<Ed25519>(mode);
+            check_inv::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_inv_unreduced() {
+        let value = sample::<Secp256k1>();
+        let a = EmulatedField::<Circuit, Secp256k1>::new(Mode::Private, value.clone());
+
+        // Invert an element whose limbs have grown with additions.
+        let sum = (0..15).fold(a.clone(), |sum, _| &sum + &a);
+        let candidate = sum.inv();
+        assert_eq!(BigUint::from(1u64), (value * 16u64 * candidate.eject_value()) % Secp256k1::modulus());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_zero() {
+        let _candidate = EmulatedField::<Circuit, Secp256k1>::new(Mode::Private, BigUint::from(0u64)).inv();
+    }
+}
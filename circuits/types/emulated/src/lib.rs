@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+pub mod add;
+pub mod div;
+pub mod equal;
+pub mod from_bits;
+pub mod inv;
+pub mod mul;
+pub mod neg;
+pub mod reduce;
+pub mod sub;
+pub mod ternary;
+pub mod to_bits;
+
+mod parameters;
+pub use parameters::*;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use snarkvm_circuits_environment::prelude::*;
+use snarkvm_circuits_types_boolean::Boolean;
+use snarkvm_circuits_types_field::Field;
+use snarkvm_utilities::BigInteger;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in each limb of a reduced emulated field element.
+pub const LIMB_SIZE_IN_BITS: usize = 64;
+
+/// The maximum number of bits in each limb of an unreduced emulated field element.
+/// An element is reduced as soon as an addition or subtraction grows its limbs beyond this size.
+pub const MAX_LIMB_SIZE_IN_BITS: usize = 96;
+
+/// The parameters of a prime field, emulated over the base field.
+pub trait EmulatedParameters {
+    /// The modulus of the emulated field, as little-endian 64-bit words, where the last word is nonzero.
+    const MODULUS: &'static [u64];
+
+    /// Returns the modulus of the emulated field.
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(&Self::MODULUS.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>())
+    }
+
+    /// Returns the number of limbs in a reduced element of the emulated field.
+    fn num_limbs() -> usize {
+        Self::MODULUS.len()
+    }
+}
+
+/// An element of a prime field, emulated over the base field as little-endian limbs of `LIMB_SIZE_IN_BITS` bits.
+///
+/// Additions and subtractions are computed limb-wise without any reduction, which grows the limbs.
+/// The size of the limbs is tracked at synthesis, and the element is lazily reduced by the modulus,
+/// when it is multiplied, or when its limbs would exceed `MAX_LIMB_SIZE_IN_BITS` bits.
+///
+/// A reduced element has range-checked limbs, but is not necessarily less than the modulus.
+/// The canonical element is only enforced by `reduce`, and when checking for equality.
+pub struct EmulatedField<E: Environment, P: EmulatedParameters> {
+    /// The little-endian limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// An upper bound on the number of bits in each limb.
+    limb_size_in_bits: usize,
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: EmulatedParameters> EmulatedField<E, P> {
+    /// Returns an upper bound on the number of bits in each limb of `self`.
+    pub fn limb_size_in_bits(&self) -> usize {
+        self.limb_size_in_bits
+    }
+
+    /// Initializes an element from the given limbs, reducing it if its limbs exceed `MAX_LIMB_SIZE_IN_BITS` bits.
+    fn from_limbs(limbs: Vec<Field<E>>, limb_size_in_bits: usize) -> Self {
+        let element = Self { limbs, limb_size_in_bits, _parameters: PhantomData };
+        match limb_size_in_bits > MAX_LIMB_SIZE_IN_BITS {
+            true => element.mul_reduce(&Self::one(), false),
+            false => element,
+        }
+    }
+
+    /// Returns the value of the limbs of `self`, which may exceed the modulus.
+    fn unreduced_value(&self) -> BigUint {
+        self.limbs.iter().rev().fold(BigUint::from(0u64), |value, limb| {
+            (value << LIMB_SIZE_IN_BITS) + limb.eject_value().to_repr().to_biguint()
+        })
+    }
+
+    /// Returns the little-endian limbs of the given value, with the given number of limbs.
+    fn biguint_to_limbs(value: &BigUint, num_limbs: usize) -> Vec<E::BaseField> {
+        let mut limbs = value.to_u64_digits();
+        if limbs.len() > num_limbs {
+            E::halt(format!("Value {value} exceeds {} bits", num_limbs * LIMB_SIZE_IN_BITS))
+        }
+        limbs.resize(num_limbs, 0);
+        limbs.into_iter().map(|limb| E::BaseField::from(limb as u128)).collect()
+    }
+
+    /// Returns the given value as a base field element.
+    fn biguint_to_base_field(value: &BigUint) -> E::BaseField {
+        let shift = E::BaseField::from(1u128 << LIMB_SIZE_IN_BITS);
+        value
+            .to_u64_digits()
+            .iter()
+            .rev()
+            .fold(E::BaseField::zero(), |sum, word| sum * shift + E::BaseField::from(*word as u128))
+    }
+
+    /// Returns the limbs of the given value as witnesses, along with the bits of their range checks.
+    fn new_witness_limbs(value: &BigUint, num_limbs: usize) -> (Vec<Field<E>>, Vec<Boolean<E>>) {
+        let limbs = Self::biguint_to_limbs(value, num_limbs).into_iter().map(|limb| Field::new(Mode::Private, limb));
+        let limbs = limbs.collect::<Vec<_>>();
+        let bits_le = limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect();
+        (limbs, bits_le)
+    }
+
+    /// Returns the little-endian bits of the modulus, as constants.
+    fn modulus_bits_le() -> Vec<Boolean<E>> {
+        P::MODULUS
+            .iter()
+            .flat_map(|word| (0..LIMB_SIZE_IN_BITS).map(move |i| Boolean::constant((word >> i) & 1 == 1)))
+            .collect()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Inject for EmulatedField<E, P> {
+    type Primitive = BigUint;
+
+    /// Initializes an emulated field element from the given mode and value.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        // Ensure the value is reduced.
+        if value >= P::modulus() {
+            E::halt(format!("Value {value} exceeds the modulus of the emulated field"))
+        }
+
+        let limbs = Self::biguint_to_limbs(&value, P::num_limbs()).into_iter().map(|limb| Field::new(mode, limb));
+        let limbs = limbs.collect::<Vec<_>>();
+
+        // Ensure each limb is within range, and the element is less than the modulus.
+        if !mode.is_constant() {
+            let bits_le = limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect::<Vec<_>>();
+            E::assert(Boolean::is_less_than_bits_le(&bits_le, &Self::modulus_bits_le()));
+        }
+
+        Self { limbs, limb_size_in_bits: LIMB_SIZE_IN_BITS, _parameters: PhantomData }
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Eject for EmulatedField<E, P> {
+    type Primitive = BigUint;
+
+    ///
+    /// Ejects the mode of the emulated field element.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    ///
+    /// Ejects the emulated field element, reduced by the modulus.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        self.unreduced_value() % P::modulus()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Clone for EmulatedField<E, P> {
+    fn clone(&self) -> Self {
+        Self { limbs: self.limbs.clone(), limb_size_in_bits: self.limb_size_in_bits, _parameters: PhantomData }
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Zero for EmulatedField<E, P> {
+    type Boolean = Boolean<E>;
+
+    fn zero() -> Self {
+        Self::constant(BigUint::from(0u64))
+    }
+
+    fn is_zero(&self) -> Self::Boolean {
+        self.is_equal(&Self::zero())
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> One for EmulatedField<E, P> {
+    type Boolean = Boolean<E>;
+
+    fn one() -> Self {
+        Self::constant(BigUint::from(1u64))
+    }
+
+    fn is_one(&self) -> Self::Boolean {
+        self.is_equal(&Self::one())
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> fmt::Debug for EmulatedField<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> fmt::Display for EmulatedField<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    /// The parameters of the secp256k1 base field.
+    pub(crate) type Secp256k1 = Secp256k1BaseParameters;
+
+    /// The parameters of the Ed25519 base field, i.e. `2^255 - 19`.
+    pub(crate) struct Ed25519;
+
+    impl EmulatedParameters for Ed25519 {
+        const MODULUS: &'static [u64] =
+            &[0xFFFFFFFFFFFFFFED, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF];
+    }
+
+    /// The parameters of the BLS12-377 base field, which has 377 bits.
+    pub(crate) struct Bls12_377Fq;
+
+    impl EmulatedParameters for Bls12_377Fq {
+        const MODULUS: &'static [u64] = &[
+            0x8508c00000000001,
+            0x170b5d4430000000,
+            0x1ef3622fba094800,
+            0x1a22d9f300f5138f,
+            0xc63b05c06ca1493b,
+            0x01ae3a4617c510ea,
+        ];
+    }
+
+    /// Samples a random element of the emulated field.
+    pub(crate) fn sample<P: EmulatedParameters>() -> BigUint {
+        let rng = &mut test_rng();
+        let bytes = (0..(P::num_limbs() * LIMB_SIZE_IN_BITS / 8 + 16)).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        BigUint::from_bytes_le(&bytes) % P::modulus()
+    }
+
+    fn check_new<P: EmulatedParameters>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let expected = sample::<P>();
+
+            Circuit::scope(format!("New {mode} {i}"), || {
+                let candidate = EmulatedField::<Circuit, P>::new(mode, expected.clone());
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(LIMB_SIZE_IN_BITS, candidate.limb_size_in_bits());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_modulus() {
+        let expected = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        assert_eq!(expected, Secp256k1::modulus().to_str_radix(16));
+        let expected = "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+        assert_eq!(expected, Ed25519::modulus().to_str_radix(16));
+        assert_eq!(377, Bls12_377Fq::modulus().bits());
+    }
+
+    #[test]
+    fn test_new() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_new::<Secp256k1>(mode);
+            check_new::<Ed25519>(mode);
+            check_new::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_new_constant_is_free() {
+        Circuit::scope("New", || {
+            let _candidate = EmulatedField::<Circuit, Secp256k1>::new(Mode::Constant, sample::<Secp256k1>());
+            assert_scope!(4, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_exceeds_modulus() {
+        let _candidate = EmulatedField::<Circuit, Secp256k1>::new(Mode::Private, Secp256k1::modulus());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Mul<EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn mul(self, other: EmulatedField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn mul(self, other: EmulatedField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn mul(self, other: &EmulatedField<E, P>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<&EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    ///
+    /// Returns the product of `self` and `other`, reduced by the modulus.
+    ///
+    /// The result has range-checked limbs, but is not necessarily less than the modulus.
+    ///
+    fn mul(self, other: &EmulatedField<E, P>) -> Self::Output {
+        self.mul_reduce(other, false)
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> MulAssign<EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn mul_assign(&mut self, other: EmulatedField<E, P>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> MulAssign<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn mul_assign(&mut self, other: &EmulatedField<E, P>) {
+        *self = &*self * other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Square for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn square(&self) -> Self::Output {
+        (&self).square()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Square for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn square(&self) -> Self::Output {
+        *self * *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_mul<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let expected = (&first * &second) % P::modulus();
+
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first);
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scope(format!("Mul {mode_a} {mode_b} {i}"), || {
+                let candidate = &a * &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(LIMB_SIZE_IN_BITS, candidate.limb_size_in_bits());
+                match mode_a.is_constant() && mode_b.is_constant() {
+                    true => assert_scope!(P::num_limbs(), 0, 0, 0),
+                    false => assert_scope!(),
+                }
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_mul_unreduced<P: EmulatedParameters>(mode: Mode) {
+        let modulus = P::modulus();
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let a = EmulatedField::<Circuit, P>::new(mode, first.clone());
+            let b = EmulatedField::<Circuit, P>::new(mode, second.clone());
+
+            // Multiply elements whose limbs are near the maximum size.
+            let sum = (0..31).fold(a.clone(), |sum, _| &sum + &a);
+            let difference = (0..15).fold(b.clone(), |difference, _| &difference - &a);
+            let expected_sum = (&first * 32u64) % &modulus;
+            let expected_difference = (&second + &modulus * 15u64 - &first * 15u64) % &modulus;
+            let expected = (expected_sum * expected_difference) % &modulus;
+
+            Circuit::scope(format!("Mul unreduced {mode} {i}"), || {
+                let candidate = &sum * &difference;
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!();
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_mul() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_mul::<Secp256k1>(mode_a, mode_b);
+                check_mul::<Ed25519>(mode_a, mode_b);
+                check_mul::<Bls12_377Fq>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_unreduced() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_mul_unreduced::<Secp256k1>(mode);
+            check_mul_unreduced::<Ed25519>(mode);
+            check_mul_unreduced::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_square() {
+        let value = sample::<Secp256k1>();
+        let expected = (&value * &value) % Secp256k1::modulus();
+        let a = EmulatedField::<Circuit, Secp256k1>::new(Mode::Private, value);
+        assert_eq!(expected, a.square().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_mul_is_not_satisfied_by_incorrect_witness() {
+        let a = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, sample::<Ed25519>());
+        let b = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, sample::<Ed25519>());
+        let candidate = &a * &b;
+        assert!(Circuit::is_satisfied());
+
+        // Ensure an unrelated witness cannot stand in for the product.
+        let product = a.unreduced_value() * b.unreduced_value();
+        let (quotient, _) = EmulatedField::<Circuit, Ed25519>::new_witness_limbs(&(&product / Ed25519::modulus()), 5);
+        let remainder = (candidate.unreduced_value() + 1u64) % Ed25519::modulus();
+        let (remainder, _) = EmulatedField::<Circuit, Ed25519>::new_witness_limbs(&remainder, 4);
+        EmulatedField::enforce_mul(&a, &b, &quotient, &remainder);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
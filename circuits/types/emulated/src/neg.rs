@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Neg for EmulatedField<E, P> {
+    type Output = Self;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Neg for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        EmulatedField::zero() - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_neg<P: EmulatedParameters>(mode: Mode) {
+        let modulus = P::modulus();
+        for i in 0..ITERATIONS {
+            let value = sample::<P>();
+            let expected = (&modulus - &value) % &modulus;
+            let a = EmulatedField::<Circuit, P>::new(mode, value);
+
+            Circuit::scope(format!("Neg {mode} {i}"), || {
+                let candidate = -&a;
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(BigUint::from(0u64), (&a + &candidate).eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_neg() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_neg::<Secp256k1>(mode);
+            check_neg::<Ed25519>(mode);
+            check_neg::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_neg_zero() {
+        let zero = EmulatedField::<Circuit, Secp256k1>::new(Mode::Private, BigUint::from(0u64));
+        assert_eq!(BigUint::from(0u64), (-zero).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
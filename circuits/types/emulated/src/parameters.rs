@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The parameters of the secp256k1 base field.
+pub struct Secp256k1BaseParameters;
+
+impl EmulatedParameters for Secp256k1BaseParameters {
+    const MODULUS: &'static [u64] = &[0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+}
+
+/// The parameters of the secp256k1 scalar field, i.e. the order of the secp256k1 group.
+pub struct Secp256k1ScalarParameters;
+
+impl EmulatedParameters for Secp256k1ScalarParameters {
+    const MODULUS: &'static [u64] = &[0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secp256k1_moduli() {
+        let expected = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        assert_eq!(expected, Secp256k1BaseParameters::modulus().to_str_radix(16));
+        let expected = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        assert_eq!(expected, Secp256k1ScalarParameters::modulus().to_str_radix(16));
+    }
+}
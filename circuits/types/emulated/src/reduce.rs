@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> EmulatedField<E, P> {
+    ///
+    /// Returns the canonical form of `self`, which has range-checked limbs and is less than the modulus.
+    ///
+    pub fn reduce(&self) -> Self {
+        self.mul_reduce(&Self::one(), true)
+    }
+
+    ///
+    /// Returns `(self * other) mod p`, with range-checked limbs.
+    ///
+    /// The quotient `q` and remainder `w` are witnessed, and `self * other = q * p + w` is enforced
+    /// over the integers. If `is_canonical` is set, the remainder is also enforced to be less than `p`.
+    ///
+    pub(crate) fn mul_reduce(&self, other: &Self, is_canonical: bool) -> Self {
+        // Compute the quotient and remainder natively.
+        let modulus = P::modulus();
+        let product = self.unreduced_value() * other.unreduced_value();
+        let (quotient, remainder) = (&product / &modulus, &product % &modulus);
+
+        // If the operands are constant, return the remainder as a constant.
+        if self.is_constant() && other.is_constant() {
+            return Self::constant(remainder);
+        }
+
+        // Witness the quotient and remainder, and ensure `self * other = q * p + w`.
+        let num_quotient_limbs = Self::num_quotient_limbs(self.limb_size_in_bits, other.limb_size_in_bits);
+        let (quotient, _) = Self::new_witness_limbs(&quotient, num_quotient_limbs);
+        let (remainder, remainder_bits_le) = Self::new_witness_limbs(&remainder, P::num_limbs());
+        Self::enforce_mul(self, other, &quotient, &remainder);
+
+        // Ensure the remainder is less than the modulus, if requested.
+        if is_canonical {
+            E::assert(Boolean::is_less_than_bits_le(&remainder_bits_le, &Self::modulus_bits_le()));
+        }
+
+        Self { limbs: remainder, limb_size_in_bits: LIMB_SIZE_IN_BITS, _parameters: PhantomData }
+    }
+
+    /// Returns the number of limbs in the quotient of the product of elements with limbs of the given sizes.
+    pub(crate) fn num_quotient_limbs(this_limb_size_in_bits: usize, that_limb_size_in_bits: usize) -> usize {
+        // An element with `n` limbs of `b` bits is less than `2^(64 * (n - 1) + b + 1)`.
+        let num_limbs = P::num_limbs();
+        let product_size_in_bits = 128 * (num_limbs - 1) + this_limb_size_in_bits + that_limb_size_in_bits + 2;
+        // The quotient is less than `product / 2^(modulus_size_in_bits - 1)`.
+        let modulus_size_in_bits = P::modulus().bits() as usize;
+        let quotient_size_in_bits = product_size_in_bits + 1 - modulus_size_in_bits;
+        (quotient_size_in_bits + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS
+    }
+
+    ///
+    /// Enforces `x * y = q * p + w` over the integers, where `q` and `w` are range-checked little-endian limbs.
+    ///
+    /// The identity is checked column by column, where column `k` comprises the terms of weight `2^(64k)`.
+    /// The carry from each column to the next is witnessed and range-checked with an offset, as it may be negative.
+    /// As the limbs and carries are bounded, no column wraps around the base field modulus,
+    /// and the column-wise identities imply the identity over the integers.
+    ///
+    pub(crate) fn enforce_mul(x: &Self, y: &Self, q: &[Field<E>], w: &[Field<E>]) {
+        let num_limbs = P::num_limbs();
+        let num_columns = (2 * num_limbs).max(q.len() + num_limbs) - 1;
+
+        // Each column sums fewer than `2 * num_limbs + 1` terms, each less than `2^(x_size + y_size)`.
+        let term_size_in_bits = (x.limb_size_in_bits + y.limb_size_in_bits).max(2 * LIMB_SIZE_IN_BITS);
+        let num_terms_in_bits = (usize::BITS - (2 * num_limbs).leading_zeros()) as usize;
+        let column_size_in_bits = term_size_in_bits + num_terms_in_bits + 1;
+        let carry_size_in_bits = column_size_in_bits + 1 - LIMB_SIZE_IN_BITS;
+
+        // Ensure the columns cannot wrap around the base field modulus.
+        if column_size_in_bits + 1 >= E::BaseField::size_in_bits() {
+            E::halt(format!("Emulated field columns of {column_size_in_bits} bits exceed the base field"))
+        }
+
+        // Returns the pairs of limb indices `(i, j)` where `i + j = k`, for operands with the given numbers of limbs.
+        let pairs = |k: usize, num_left: usize, num_right: usize| {
+            (0..num_left).filter(move |i| k >= *i && k - i < num_right).map(move |i| (i, k - i))
+        };
+
+        // Compute the carries between the columns natively.
+        let value = |limb: &Field<E>| BigInt::from(limb.eject_value().to_repr().to_biguint());
+        let offset = BigInt::from(1u64) << (carry_size_in_bits - 1);
+        let mut carry = BigInt::from(0u64);
+        let carries = (0..num_columns - 1)
+            .map(|k| {
+                let mut column = carry.clone();
+                for (i, j) in pairs(k, num_limbs, num_limbs) {
+                    column += value(&x.limbs[i]) * value(&y.limbs[j]);
+                }
+                for (i, j) in pairs(k, q.len(), num_limbs) {
+                    column -= value(&q[i]) * BigInt::from(P::MODULUS[j]);
+                }
+                if k < num_limbs {
+                    column -= value(&w[k]);
+                }
+                carry = column >> LIMB_SIZE_IN_BITS;
+
+                // Offset the carry to be non-negative.
+                match (&carry + &offset).to_biguint() {
+                    Some(carry) => Self::biguint_to_base_field(&carry),
+                    None => E::halt("Failed to compute the carry of an emulated field operation"),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Witness the carries, and ensure each is within range.
+        let carries = carries
+            .into_iter()
+            .map(|carry| {
+                let carry = Field::new(Mode::Private, carry);
+                carry.to_lower_bits_le(carry_size_in_bits);
+                carry
+            })
+            .collect::<Vec<_>>();
+
+        let shift = Field::constant(E::BaseField::from(1u128 << LIMB_SIZE_IN_BITS));
+        let offset = Field::constant(Self::biguint_to_base_field(&(BigUint::from(1u64) << (carry_size_in_bits - 1))));
+
+        // Ensure each column, with its incoming carry, equals its outgoing carry times `2^64`.
+        let mut carry_in = Field::zero();
+        for k in 0..num_columns {
+            let mut lhs = carry_in.clone();
+            let mut rhs = Field::zero();
+            for (i, j) in pairs(k, num_limbs, num_limbs) {
+                lhs += &x.limbs[i] * &y.limbs[j];
+            }
+            for (i, j) in pairs(k, q.len(), num_limbs) {
+                rhs += &q[i] * Field::constant(E::BaseField::from(P::MODULUS[j] as u128));
+            }
+            if k < num_limbs {
+                rhs += &w[k];
+            }
+            // Note: The final column has no outgoing carry.
+            if k < num_columns - 1 {
+                let carry_out = &carries[k] - &offset;
+                rhs += &carry_out * &shift;
+                carry_in = carry_out;
+            }
+            E::assert_eq(lhs, rhs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_reduce<P: EmulatedParameters>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let expected = sample::<P>();
+            let a = EmulatedField::<Circuit, P>::new(mode, expected.clone());
+
+            // Grow the limbs with additions, so that the element exceeds the modulus before it is reduced.
+            let sum = (0..8).fold(a.clone(), |sum, _| &sum + &a);
+            let expected = (&expected * 9u64) % P::modulus();
+
+            Circuit::scope(format!("Reduce {mode} {i}"), || {
+                let candidate = sum.reduce();
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(expected, candidate.unreduced_value());
+                assert_eq!(LIMB_SIZE_IN_BITS, candidate.limb_size_in_bits());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_reduce() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_reduce::<Secp256k1>(mode);
+            check_reduce::<Ed25519>(mode);
+            check_reduce::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_incorrect_remainder_fails() {
+        let a = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, sample::<Ed25519>());
+        let one = EmulatedField::one();
+
+        // Witness a remainder that is congruent to `a`, but with a zero quotient.
+        let remainder = a.unreduced_value() + Ed25519::modulus();
+        let (quotient, _) = EmulatedField::<Circuit, Ed25519>::new_witness_limbs(&BigUint::from(0u64), 3);
+        let (remainder, _) = EmulatedField::<Circuit, Ed25519>::new_witness_limbs(&remainder, 4);
+
+        // As `a * 1 != 0 * p + (a + p)`, the circuit is not satisfied.
+        EmulatedField::enforce_mul(&a, &one, &quotient, &remainder);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Sub<EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn sub(self, other: EmulatedField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn sub(self, other: EmulatedField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    fn sub(self, other: &EmulatedField<E, P>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<&EmulatedField<E, P>> for &EmulatedField<E, P> {
+    type Output = EmulatedField<E, P>;
+
+    ///
+    /// Returns the difference of `self` and `other`, without reducing by the modulus.
+    ///
+    /// As the limbs may not underflow, a constant multiple of the modulus is added to `self`,
+    /// whose limbs each exceed the corresponding limb of `other`. If the limbs would exceed
+    /// `MAX_LIMB_SIZE_IN_BITS` bits, the difference is reduced, at the cost of a multiplication.
+    ///
+    fn sub(self, other: &EmulatedField<E, P>) -> Self::Output {
+        let modulus = P::modulus();
+
+        // If the operands are constant, return the difference as a constant.
+        if self.is_constant() && other.is_constant() {
+            let (first, second) = (self.unreduced_value() % &modulus, other.unreduced_value() % &modulus);
+            return EmulatedField::constant((first + &modulus - second) % modulus);
+        }
+
+        // Compute the padding `d`, whose limbs are each at least `2^other.limb_size_in_bits`,
+        // such that `d` is a multiple of the modulus.
+        let num_limbs = P::num_limbs();
+        let base = BigUint::from(1u64) << other.limb_size_in_bits;
+        let minimum = (0..num_limbs).fold(BigUint::from(0u64), |sum, i| sum + (&base << (LIMB_SIZE_IN_BITS * i)));
+        let delta = (&modulus - &minimum % &modulus) % &modulus;
+        let padding = EmulatedField::<E, P>::biguint_to_limbs(&delta, num_limbs)
+            .into_iter()
+            .map(|delta| Field::constant(EmulatedField::<E, P>::biguint_to_base_field(&base) + delta));
+
+        let limbs = self.limbs.iter().zip_eq(padding).zip_eq(&other.limbs).map(|((a, d), b)| a + d - b).collect();
+        EmulatedField::from_limbs(limbs, self.limb_size_in_bits.max(other.limb_size_in_bits + 1) + 1)
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> SubAssign<EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn sub_assign(&mut self, other: EmulatedField<E, P>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> SubAssign<&EmulatedField<E, P>> for EmulatedField<E, P> {
+    fn sub_assign(&mut self, other: &EmulatedField<E, P>) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_sub<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        let modulus = P::modulus();
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let expected = (&first + &modulus - &second) % &modulus;
+
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first);
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scope(format!("Sub {mode_a} {mode_b} {i}"), || {
+                let candidate = &a - &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(P::num_limbs(), 0, 0, 0);
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_sub_chain<P: EmulatedParameters>(mode: Mode) {
+        let (first, second) = (sample::<P>(), sample::<P>());
+        let a = EmulatedField::<Circuit, P>::new(mode, first.clone());
+        let b = EmulatedField::<Circuit, P>::new(mode, second.clone());
+
+        // Subtract repeatedly until the limbs are reduced.
+        let modulus = P::modulus();
+        let mut candidate = a;
+        for i in 1..=(MAX_LIMB_SIZE_IN_BITS - LIMB_SIZE_IN_BITS) as u64 {
+            candidate -= &b;
+            let expected = (&first + &modulus * i - &second * i) % &modulus;
+            assert_eq!(expected, candidate.eject_value());
+            assert!(candidate.limb_size_in_bits() <= MAX_LIMB_SIZE_IN_BITS);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_sub() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_sub::<Secp256k1>(mode_a, mode_b);
+                check_sub::<Ed25519>(mode_a, mode_b);
+                check_sub::<Bls12_377Fq>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub_chain() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_sub_chain::<Secp256k1>(mode);
+            check_sub_chain::<Ed25519>(mode);
+            check_sub_chain::<Bls12_377Fq>(mode);
+        }
+    }
+
+    #[test]
+    fn test_sub_is_lazy() {
+        let a = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, sample::<Ed25519>());
+        let b = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, sample::<Ed25519>());
+        let candidate = &a - &b;
+        assert_eq!(LIMB_SIZE_IN_BITS + 2, candidate.limb_size_in_bits());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Ternary for EmulatedField<E, P> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            }
+        }
+        // Variables
+        else {
+            let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b));
+            Self {
+                limbs: limbs.collect(),
+                limb_size_in_bits: first.limb_size_in_bits.max(second.limb_size_in_bits),
+                _parameters: PhantomData,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Ed25519, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    fn check_ternary<P: EmulatedParameters>(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        for flag in [true, false] {
+            let (first, second) = (sample::<P>(), sample::<P>());
+            let expected = if flag { first.clone() } else { second.clone() };
+
+            let condition = Boolean::<Circuit>::new(mode_condition, flag);
+            let a = EmulatedField::<Circuit, P>::new(mode_a, first);
+            let b = EmulatedField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scope(format!("{mode_condition}({flag}) ? {mode_a} : {mode_b}"), || {
+                let candidate = EmulatedField::ternary(&condition, &a, &b);
+                assert_eq!(expected, candidate.eject_value());
+                match mode_condition.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(),
+                }
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ternary() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    check_ternary::<Secp256k1>(mode_condition, mode_a, mode_b);
+                    check_ternary::<Ed25519>(mode_condition, mode_a, mode_b);
+                    check_ternary::<Bls12_377Fq>(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ternary_unreduced() {
+        let (first, second) = (sample::<Ed25519>(), sample::<Ed25519>());
+        let a = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, first.clone());
+        let b = EmulatedField::<Circuit, Ed25519>::new(Mode::Private, second);
+        let sum = &a + &a;
+
+        let candidate = EmulatedField::ternary(&Boolean::new(Mode::Private, true), &sum, &b);
+        assert_eq!((&first + &first) % Ed25519::modulus(), candidate.eject_value());
+        assert_eq!(LIMB_SIZE_IN_BITS + 1, candidate.limb_size_in_bits());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
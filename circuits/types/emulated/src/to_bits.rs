@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> ToBits for EmulatedField<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the little-endian bits of the canonical form of the element, as `num_limbs * LIMB_SIZE_IN_BITS` bits.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        let canonical = match self.is_constant() {
+            true => Self::constant(self.eject_value()),
+            false => self.reduce(),
+        };
+        canonical.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect()
+    }
+
+    /// Returns the big-endian bits of the canonical form of the element, as `num_limbs * LIMB_SIZE_IN_BITS` bits.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = self.to_bits_le();
+        bits_be.reverse();
+        bits_be
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{Bls12_377Fq, Secp256k1, sample};
+    use snarkvm_circuits_environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_to_bits<P: EmulatedParameters>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let value = sample::<P>();
+            let a = EmulatedField::<Circuit, P>::new(mode, value.clone());
+
+            // Grow the limbs with additions, so that the bits are taken from the canonical form.
+            let sum = &a + &a;
+            let expected = (&value * 2u64) % P::modulus();
+
+            Circuit::scope(format!("ToBits {mode} {i}"), || {
+                let candidate = sum.to_bits_le();
+                assert_eq!(P::num_limbs() * LIMB_SIZE_IN_BITS, candidate.len());
+                for (i, bit) in candidate.iter().enumerate() {
+                    assert_eq!(expected.bit(i as u64), bit.eject_value());
+                }
+
+                let candidate = sum.to_bits_be();
+                assert_eq!(expected.bit(0), candidate.last().unwrap().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_to_bits::<Secp256k1>(mode);
+            check_to_bits::<Bls12_377Fq>(mode);
+        }
+    }
+}
@@ -27,6 +27,12 @@ impl<E: Environment> Add<Self> for Group<E> {
 impl<E: Environment> Add<&Self> for Group<E> {
     type Output = Self;
 
+    ///
+    /// Returns the sum of `self` and `other`, using the unified twisted Edwards addition law.
+    ///
+    /// As `a` is a square and `d` is a non-square, the denominators `1 + v2` and `1 - v2` are never zero,
+    /// so the addition law is complete, and holds for the identity, for `self == other`, and for `self == -other`.
+    ///
     fn add(self, other: &Self) -> Self::Output {
         // This swap reduces the number of constants by one.
         let (this, that) = match other.is_constant() {
@@ -313,4 +319,33 @@ mod tests {
         let candidate_b = first + second;
         assert_eq!(expected, candidate_b.eject_value());
     }
+
+    #[test]
+    fn test_add_identity() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let point = <Circuit as Environment>::Affine::rand(&mut test_rng());
+                let a = Group::<Circuit>::new(mode, point);
+
+                // Ensure the identity, as a constant and as an injected element, is the additive identity.
+                let identity = <Circuit as Environment>::Affine::zero();
+                for zero in [Group::zero(), Group::new(mode, identity)] {
+                    Circuit::scope(&format!("{} {}", mode, i), || {
+                        assert_eq!(point, (&a + &zero).eject_value());
+                        assert_eq!(point, (&zero + &a).eject_value());
+                        assert_eq!(identity, (&zero + &zero).eject_value());
+                    });
+                }
+
+                // Ensure the sum of an element and its negation is the identity.
+                Circuit::scope(&format!("{} {}", mode, i), || {
+                    let candidate = &a + &-&a;
+                    assert_eq!(identity, candidate.eject_value());
+                    assert!(candidate.is_zero().eject_value());
+                });
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
 }
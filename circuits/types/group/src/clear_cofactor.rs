@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Returns `cofactor * self`, which is an element of the prime-order subgroup.
+    ///
+    /// As the cofactor is a constant, this is computed with a double-and-add over its bits,
+    /// which costs 5 constraints per doubling and 6 constraints per addition.
+    ///
+    pub fn clear_cofactor(&self) -> Group<E> {
+        // Initialize an iterator over the bits of the cofactor, from MSB to LSB, without leading zeros.
+        let mut bits_be = E::AffineParameters::COFACTOR
+            .iter()
+            .rev()
+            .flat_map(|limb| (0..64).rev().map(move |i| (limb >> i) & 1 == 1))
+            .skip_while(|bit| !bit);
+
+        // The MSB is always set, so the output is initialized to `self`.
+        match bits_be.next() {
+            Some(_) => bits_be.fold(self.clone(), |output, bit| match bit {
+                true => output.double() + self,
+                false => output.double(),
+            }),
+            None => Group::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_clear_cofactor(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let expected = point.mul_by_cofactor();
+
+            let candidate = Group::<Circuit>::new(mode, point);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = candidate.clear_cofactor();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor_constant() {
+        check_clear_cofactor(Mode::Constant, 6, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_clear_cofactor_public() {
+        check_clear_cofactor(Mode::Public, 2, 0, 10, 10);
+    }
+
+    #[test]
+    fn test_clear_cofactor_private() {
+        check_clear_cofactor(Mode::Private, 2, 0, 10, 10);
+    }
+}
@@ -27,6 +27,12 @@ impl<E: Environment> Double for Group<E> {
 impl<E: Environment> Double for &Group<E> {
     type Output = Group<E>;
 
+    ///
+    /// Returns `2 * self`, using the dedicated twisted Edwards doubling law.
+    ///
+    /// As `ax^2 + y^2 = 1 + dx^2y^2`, the denominators `ax^2 + y^2` and `2 - ax^2 - y^2` are never zero
+    /// for a point on the curve, so doubling is complete, and doubling the identity yields the identity.
+    ///
     fn double(self) -> Self::Output {
         let a = Field::constant(E::AffineParameters::COEFF_A);
         let two = Field::one().double();
@@ -138,4 +144,19 @@ mod tests {
         let candidate_b = Group::<Circuit>::new(Mode::Private, a).double();
         assert_eq!(expected, candidate_b.eject_value());
     }
+
+    #[test]
+    fn test_double_identity() {
+        let identity = <Circuit as Environment>::Affine::zero();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for zero in [Group::<Circuit>::zero(), Group::new(mode, identity)] {
+                let candidate = zero.double();
+                assert_eq!(identity, candidate.eject_value());
+                assert!(candidate.is_zero().eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
 }
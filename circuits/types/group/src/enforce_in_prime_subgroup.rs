@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Enforces that `self` is an element of the prime-order subgroup.
+    ///
+    /// Rather than multiplying by the (251-bit) group order, this method witnesses the point
+    /// `Q = self * cofactor^{-1}` and enforces `self == cofactor * Q`. As every multiple of the
+    /// cofactor lies in the prime-order subgroup, this check rejects any small-order or torsion
+    /// component of `self`, at the cost of a curve check and `Group::clear_cofactor`.
+    ///
+    pub fn enforce_in_prime_subgroup(&self) {
+        // Witness the coordinates of `Q = self * cofactor^{-1}`.
+        let (x, y) = (&self.x, &self.y);
+        let (q_x, q_y) = witness!(|x, y| {
+            // Recover the point with the given coordinates, defaulting to the generator if it is not on the curve,
+            // as no valid witness exists for such a point.
+            let point = [true, false]
+                .into_iter()
+                .filter_map(|greatest| E::Affine::from_x_coordinate(x, greatest))
+                .find(|point| point.to_y_coordinate() == y)
+                .unwrap_or_else(E::Affine::prime_subgroup_generator);
+            let q = point.mul_by_cofactor_inv();
+            (q.to_x_coordinate(), q.to_y_coordinate())
+        });
+
+        // Compute `cofactor * Q`, ensuring `Q` is on the curve.
+        let candidate = Self::from_xy_coordinates(q_x, q_y).clear_cofactor();
+
+        // Ensure a constant `self` is in the prime-order subgroup, as constant constraints are not checked.
+        if self.is_constant() {
+            let is_equal = self.x.eject_value() == candidate.x.eject_value()
+                && self.y.eject_value() == candidate.y.eject_value();
+            if !is_equal {
+                E::halt("The constant group element is not in the prime-order subgroup")
+            }
+        }
+
+        // Ensure `self == cofactor * Q`.
+        E::assert_eq(&self.x, &candidate.x);
+        E::assert_eq(&self.y, &candidate.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_enforce_in_prime_subgroup(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let candidate = Group::<Circuit>::new(mode, point);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                candidate.enforce_in_prime_subgroup();
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_enforce_in_prime_subgroup_fails(mode: Mode) {
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+
+            // Add the point of order 2, `(0, -1)`, which maps `(x, y)` to `(-x, -y)`.
+            let x = Field::new(mode, -point.to_x_coordinate());
+            let y = Field::new(mode, -point.to_y_coordinate());
+            let candidate = Group::<Circuit>::from_xy_coordinates(x, y);
+
+            candidate.enforce_in_prime_subgroup();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_enforce_in_prime_subgroup_constant() {
+        check_enforce_in_prime_subgroup(Mode::Constant, 10, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_enforce_in_prime_subgroup_public() {
+        check_enforce_in_prime_subgroup(Mode::Public, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_prime_subgroup_private() {
+        check_enforce_in_prime_subgroup(Mode::Private, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_prime_subgroup_fails_public() {
+        check_enforce_in_prime_subgroup_fails(Mode::Public);
+    }
+
+    #[test]
+    fn test_enforce_in_prime_subgroup_fails_private() {
+        check_enforce_in_prime_subgroup_fails(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enforce_in_prime_subgroup_fails_constant() {
+        check_enforce_in_prime_subgroup_fails(Mode::Constant);
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Initializes an affine group element from its compressed form, given as an x-coordinate and a sign bit.
+    ///
+    /// The y-coordinate is witnessed as the square root selected by the sign bit (see `Group::to_x_with_sign`),
+    /// and is enforced to satisfy both the curve equation and the sign bit with constraints.
+    ///
+    /// Note: This method does not enforce the resulting point is in the prime-order subgroup.
+    ///
+    pub fn from_x_with_sign(x: Field<E>, sign: Boolean<E>) -> Self {
+        // Derive the y-coordinate.
+        let y: Field<E> = witness!(|x, sign| match E::Affine::from_x_coordinate(x, sign) {
+            Some(point) => point.to_y_coordinate(),
+            None => E::halt(format!("Failed to recover an affine group from an x-coordinate of {}", x)),
+        });
+
+        // Ensure the point is on the curve.
+        let group = Self::from_xy_coordinates(x, y);
+
+        // Ensure the y-coordinate matches the sign bit.
+        E::assert_eq(group.to_x_with_sign().1, sign);
+
+        group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_from_x_with_sign(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let sign = point.to_y_coordinate() > -point.to_y_coordinate();
+
+            // Inject the x-coordinate and sign bit.
+            let x_coordinate = Field::new(mode, point.to_x_coordinate());
+            let sign = Boolean::new(mode, sign);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let affine = Group::<Circuit>::from_x_with_sign(x_coordinate, sign);
+                assert_eq!(point, affine.eject_value());
+                assert_eq!(mode.is_constant(), affine.is_constant());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_round_trip(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let expected = Group::<Circuit>::new(mode, point);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (x, sign) = expected.to_x_with_sign();
+                let candidate = Group::<Circuit>::from_x_with_sign(x, sign);
+                assert_eq!(point, candidate.eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_x_with_sign_constant() {
+        check_from_x_with_sign(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_x_with_sign_public() {
+        check_from_x_with_sign(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_x_with_sign_private() {
+        check_from_x_with_sign(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_x_with_sign_round_trip() {
+        check_round_trip(Mode::Constant);
+        check_round_trip(Mode::Public);
+        check_round_trip(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_x_with_sign_wrong_sign_fails() {
+        // Sample a random element.
+        let point: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+        let sign = point.to_y_coordinate() > -point.to_y_coordinate();
+
+        // Inject the x-coordinate and sign bit, where the y-coordinate is witnessed with the opposite sign.
+        let x_coordinate = Field::<Circuit>::new(Mode::Private, point.to_x_coordinate());
+        let y_coordinate = Field::new(Mode::Private, -point.to_y_coordinate());
+        let group = Group::from_xy_coordinates(x_coordinate, y_coordinate);
+
+        // Ensure the sign bit of the negated y-coordinate does not match.
+        let (_, candidate) = group.to_x_with_sign();
+        Circuit::assert_eq(candidate, Boolean::<Circuit>::new(Mode::Private, sign));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
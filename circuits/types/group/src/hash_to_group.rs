@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of input bits consumed by each lookup.
+const CHUNK_SIZE: usize = 3;
+/// The number of entries in each lookup table.
+const LOOKUP_SIZE: usize = 1 << CHUNK_SIZE;
+
+/// HashToGroup maps a sequence of bits to an affine group element, following the BHP hash in `algorithms`.
+///
+/// The input is split into 3-bit chunks `(c_0, c_1, c_2)`, and each chunk selects the multiple
+/// `(1 - 2 * c_2) * (1 + c_0 + 2 * c_1)` of its (constant) base. The output is the sum of the selected multiples.
+/// As each selected multiple is a constant point on the curve, the output is always a valid group element.
+pub struct HashToGroup<E: Environment> {
+    /// The lookup tables for each base, grouped by window.
+    lookups: Vec<Vec<[E::Affine; LOOKUP_SIZE]>>,
+}
+
+impl<E: Environment> HashToGroup<E> {
+    ///
+    /// Initializes a new instance of HashToGroup with the given windows of bases.
+    ///
+    /// To match the native BHP hash, these are the bases of `BHPCRH`, where each window contains
+    /// `WINDOW_SIZE` bases, and each base is `2^4` times the previous base in the window.
+    ///
+    pub fn new(bases: &[Vec<E::Affine>]) -> Self {
+        Self {
+            lookups: bases
+                .iter()
+                .map(|window| {
+                    window
+                        .iter()
+                        .map(|base| {
+                            // Compute the table of signed multiples `[1, 2, 3, 4, -1, -2, -3, -4] * base`.
+                            let base = base.to_projective();
+                            [0, 1, 2, 3, 4, 5, 6, 7].map(|i: usize| {
+                                let mut element = base;
+                                if (i & 0x01) != 0 {
+                                    element += base;
+                                }
+                                if (i & 0x02) != 0 {
+                                    element += base.double();
+                                }
+                                match (i & 0x04) != 0 {
+                                    true => -element.to_affine(),
+                                    false => element.to_affine(),
+                                }
+                            })
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the maximum number of input bits.
+    pub fn max_input_size(&self) -> usize {
+        self.lookups.iter().map(|window| window.len() * CHUNK_SIZE).sum()
+    }
+
+    ///
+    /// Returns the hash of the given input bits as an affine group element.
+    ///
+    /// Each variable chunk costs 3 constraints for the lookup, and 6 constraints for the sum.
+    ///
+    pub fn hash_bits(&self, input: &[Boolean<E>]) -> Group<E> {
+        // Ensure the input size is within the parameter size.
+        if input.len() > self.max_input_size() {
+            E::halt(format!("The hash-to-group input cannot exceed {} bits.", self.max_input_size()))
+        }
+
+        // Returns the bits of each chunk, padded with `false` bits to the chunk size.
+        let pad = |chunk: &[Boolean<E>], index: usize| {
+            chunk.get(index).cloned().unwrap_or_else(|| Boolean::constant(false))
+        };
+
+        // If the input is a constant, compute the hash natively.
+        if input.iter().all(|bit| bit.is_constant()) {
+            let output = input
+                .chunks(self.window_size_in_bits())
+                .zip(self.lookups.iter())
+                .flat_map(|(bits, lookups)| {
+                    bits.chunks(CHUNK_SIZE).zip(lookups).map(|(chunk, table)| {
+                        let index = (0..CHUNK_SIZE).map(|i| (pad(chunk, i).eject_value() as usize) << i);
+                        table[index.sum::<usize>()].to_projective()
+                    })
+                })
+                .fold(<E::Affine as AffineCurve>::Projective::zero(), |output, point| output + point);
+            return Group::constant(output.to_affine());
+        }
+
+        // Compute the sum of the selected multiples for all chunks.
+        //
+        // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
+        // the maximum input size in length.
+        input
+            .chunks(self.window_size_in_bits())
+            .zip(self.lookups.iter())
+            .flat_map(|(bits, lookups)| {
+                bits.chunks(CHUNK_SIZE)
+                    .zip(lookups)
+                    .map(|(chunk, table)| Group::lookup(table, &pad(chunk, 0), &pad(chunk, 1), &pad(chunk, 2)))
+                    .collect::<Vec<_>>()
+            })
+            .reduce(|output, point| output + point)
+            .unwrap_or_else(Group::zero)
+    }
+
+    ///
+    /// Returns the hash of the given input field elements as an affine group element.
+    ///
+    /// The field elements are hashed as the concatenation of their little-endian bits.
+    ///
+    pub fn hash_fields(&self, input: &[Field<E>]) -> Group<E> {
+        self.hash_bits(&input.iter().flat_map(|element| element.to_bits_le()).collect::<Vec<_>>())
+    }
+
+    /// Returns the number of input bits consumed by each window.
+    fn window_size_in_bits(&self) -> usize {
+        self.lookups.first().map(|window| window.len() * CHUNK_SIZE).unwrap_or(CHUNK_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{crh::BHPCRH, CRH};
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, ToBits as TBits, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "HashToGroupCircuit0";
+
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_hash_bits<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        // Initialize the native BHP hash, and the hash-to-group circuit with the same bases.
+        let native = BHPCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let bases = native
+            .parameters()
+            .iter()
+            .map(|window| window.iter().map(|base| base.to_affine()).collect())
+            .collect::<Vec<Vec<_>>>();
+        let circuit = HashToGroup::<Circuit>::new(&bases);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * CHUNK_SIZE;
+        assert_eq!(num_input_bits, circuit.max_input_size());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("HashToGroup {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_bits(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.to_x_coordinate().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_bits_constant() {
+        check_hash_bits::<1, 32>(Mode::Constant, 4, 0, 0, 0);
+        check_hash_bits::<2, 32>(Mode::Constant, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_hash_bits_public() {
+        check_hash_bits::<1, 32>(Mode::Public, 62, 0, 250, 250);
+        check_hash_bits::<2, 32>(Mode::Public, 126, 0, 506, 506);
+    }
+
+    #[test]
+    fn test_hash_bits_private() {
+        check_hash_bits::<1, 32>(Mode::Private, 62, 0, 250, 250);
+        check_hash_bits::<2, 32>(Mode::Private, 126, 0, 506, 506);
+    }
+
+    #[test]
+    fn test_hash_bits_short_input() {
+        // Initialize the native BHP hash, and the hash-to-group circuit with the same bases.
+        let native = BHPCRH::<Projective, 2, 32>::setup(MESSAGE);
+        let bases = native
+            .parameters()
+            .iter()
+            .map(|window| window.iter().map(|base| base.to_affine()).collect())
+            .collect::<Vec<Vec<_>>>();
+        let circuit = HashToGroup::<Circuit>::new(&bases);
+
+        for num_input_bits in [1, 2, 3, 95, 96, 97, 100] {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Perform the hash operation.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+            let candidate = circuit.hash_bits(&circuit_input);
+            assert_eq!(expected, candidate.to_x_coordinate().eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_fields() {
+        // Initialize the native BHP hash, and the hash-to-group circuit with the same bases.
+        let native = BHPCRH::<Projective, 4, 32>::setup(MESSAGE);
+        let bases = native
+            .parameters()
+            .iter()
+            .map(|window| window.iter().map(|base| base.to_affine()).collect())
+            .collect::<Vec<Vec<_>>>();
+        let circuit = HashToGroup::<Circuit>::new(&bases);
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input: <Circuit as Environment>::BaseField = UniformRand::rand(&mut test_rng());
+            // Compute the expected hash.
+            let expected = native.hash(&input.to_bits_le()).expect("Failed to hash native input");
+
+            Circuit::scope(format!("HashToGroup {i}"), || {
+                let candidate = circuit.hash_fields(&[Field::new(Mode::Private, input)]);
+                assert_eq!(expected, candidate.to_x_coordinate().eject_value());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -18,20 +18,29 @@
 #![allow(clippy::too_many_arguments)]
 
 pub mod add;
+pub mod clear_cofactor;
 pub mod double;
+pub mod enforce_in_prime_subgroup;
 pub mod equal;
 pub mod from_bits;
 pub mod from_x_coordinate;
+pub mod from_x_with_sign;
 pub mod from_xy_coordinates;
+pub mod hash_to_group;
 pub mod mul;
+pub mod mul_by_constant_base;
 pub mod neg;
 pub mod sub;
+pub mod sum;
 pub mod ternary;
 pub mod to_bits;
 pub mod to_x_coordinate;
+pub mod to_x_with_sign;
 pub mod to_y_coordinate;
 pub mod zero;
 
+pub use hash_to_group::HashToGroup;
+
 #[cfg(test)]
 use snarkvm_circuits_environment::assert_scope;
 
@@ -39,7 +48,7 @@ use snarkvm_circuits_environment::prelude::*;
 use snarkvm_circuits_types_boolean::Boolean;
 use snarkvm_circuits_types_field::Field;
 use snarkvm_circuits_types_scalar::Scalar;
-use snarkvm_curves::{AffineCurve, TwistedEdwardsParameters};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters};
 
 #[derive(Clone)]
 pub struct Group<E: Environment> {
@@ -169,12 +169,41 @@ impl<E: Environment, const N: usize> MulAssign<[Boolean<E>; N]> for Group<E> {
 impl<E: Environment> MulAssign<&[Boolean<E>]> for Group<E> {
     #[allow(clippy::suspicious_op_assign_impl)]
     fn mul_assign(&mut self, other: &[Boolean<E>]) {
-        let base = self.clone();
+        // If the base is a constant, use the windowed fixed-base scalar multiplication.
+        if self.is_constant() {
+            // Reverse the given bits from big-endian into little-endian.
+            let bits_le = other.iter().rev().cloned().collect::<Vec<_>>();
+            *self = self.mul_by_constant_base(&bits_le);
+            return;
+        }
+
+        // Otherwise, use a 2-bit fixed-window scalar multiplication on the variable base.
+        // Note: A GLV decomposition is not applicable here, as the embedded twisted Edwards curve
+        // does not admit an efficiently-computable endomorphism.
+
+        // Precompute the window table `[0, 1, 2, 3] * base`.
+        let table = {
+            let double = (&*self).double();
+            let triple = &double + &*self;
+            [Group::zero(), self.clone(), double, triple]
+        };
+
+        // Pad the big-endian bits with a leading zero bit to an even length.
+        let mut bits_be = Vec::with_capacity(other.len() + 1);
+        if other.len() % 2 == 1 {
+            bits_be.push(Boolean::constant(false));
+        }
+        bits_be.extend_from_slice(other);
 
         let mut output = Group::zero();
-        for bit in other.iter() {
-            output = output.double();
-            output = Ternary::ternary(bit, &(&base + &output), &output);
+        for window in bits_be.chunks(2) {
+            let (high, low) = (&window[0], &window[1]);
+            // Shift the accumulator by the window width.
+            output = output.double().double();
+            // Select the multiple of the base for this window, and add it to the accumulator.
+            let lower = Group::ternary(low, &table[1], &table[0]);
+            let upper = Group::ternary(low, &table[3], &table[2]);
+            output += Group::ternary(high, &upper, &lower);
         }
         *self = output;
     }
@@ -237,9 +266,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Constant, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 1757, 0, 0, 0);
+            check_mul(&name, &expected, &a, &b, 4, 0, 0, 0);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 1757, 0, 0, 0);
+            check_mul_assign(&name, &expected, &a, &b, 4, 0, 0, 0);
         }
     }
 
@@ -254,9 +283,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Public, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 757, 0, 2500, 2500);
+            check_mul(&name, &expected, &a, &b, 166, 0, 748, 748);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 757, 0, 2500, 2500);
+            check_mul_assign(&name, &expected, &a, &b, 166, 0, 748, 748);
         }
     }
 
@@ -271,37 +300,33 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Private, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 757, 0, 2500, 2500);
+            check_mul(&name, &expected, &a, &b, 166, 0, 748, 748);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 757, 0, 2500, 2500);
+            check_mul_assign(&name, &expected, &a, &b, 166, 0, 748, 748);
         }
     }
 
-    #[allow(clippy::identity_op)]
     #[test]
     fn test_public_times_scalar_constant() {
-        use snarkvm_utilities::BigInteger;
-
         for i in 0..ITERATIONS {
             let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
             let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
 
             let (num_constant, num_private, num_constraints) = {
-                const MODULUS_BITS: usize = 251;
-                let num_nonzero_bits = scalar.to_repr().to_biguint().bits() as usize;
-                let num_leading_zero_bits = MODULUS_BITS - num_nonzero_bits;
-
-                let num_constant = 2
-                    + (3 /* DOUBLE constant */ + 2/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (1 /* DOUBLE private */ + 2/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits; // Typically around 760.
-                let num_private = 2
-                    + (0 /* DOUBLE constant */ + 3/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (5 /* DOUBLE private */ + 6/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits
-                    - 10; // Typically around 2700.
-                let num_constraints = 2
-                    + (0 /* DOUBLE constant */ + 3/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (5 /* DOUBLE private */ + 6/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits
-                    - 10; // Typically around 2700.
+                use snarkvm_utilities::ToBits;
+
+                // Pad the big-endian scalar bits to an even length, and determine which 2-bit windows are nonzero.
+                let bits_be = [vec![false], scalar.to_bits_be()].concat();
+                let windows = bits_be.chunks(2).map(|window| window[0] || window[1]).collect::<Vec<_>>();
+                let num_leading_zero_windows = windows.iter().take_while(|is_nonzero| !**is_nonzero).count();
+                let num_remaining_windows = windows.len() - num_leading_zero_windows - 1;
+                let num_zero_windows =
+                    windows.iter().skip(num_leading_zero_windows).filter(|is_nonzero| !**is_nonzero).count();
+
+                let num_constant = 511 + 6 /* DOUBLE constant */ * num_leading_zero_windows; // Typically around 520.
+                let num_private = 14 + (10 /* DOUBLE private */ + 6/* ADD private */) * num_remaining_windows
+                    - 3 /* ADD constant */ * num_zero_windows; // Typically around 1900.
+                let num_constraints = num_private;
 
                 (num_constant, num_private, num_constraints)
             };
@@ -317,31 +342,27 @@ mod tests {
         }
     }
 
-    #[allow(clippy::identity_op)]
     #[test]
     fn test_private_times_scalar_constant() {
-        use snarkvm_utilities::BigInteger;
-
         for i in 0..ITERATIONS {
             let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
             let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
 
             let (num_constant, num_private, num_constraints) = {
-                const MODULUS_BITS: usize = 251;
-                let num_nonzero_bits = scalar.to_repr().to_biguint().bits() as usize;
-                let num_leading_zero_bits = MODULUS_BITS - num_nonzero_bits;
-
-                let num_constant = 2
-                    + (3 /* DOUBLE constant */ + 2/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (1 /* DOUBLE private */ + 2/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits; // Typically around 760.
-                let num_private = 2
-                    + (0 /* DOUBLE constant */ + 3/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (5 /* DOUBLE private */ + 6/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits
-                    - 10; // Typically around 2700.
-                let num_constraints = 2
-                    + (0 /* DOUBLE constant */ + 3/* public ADD constant */ + 0/* TERNARY */) * num_leading_zero_bits
-                    + (5 /* DOUBLE private */ + 6/* public ADD private */ + 0/* TERNARY */) * num_nonzero_bits
-                    - 10; // Typically around 2700.
+                use snarkvm_utilities::ToBits;
+
+                // Pad the big-endian scalar bits to an even length, and determine which 2-bit windows are nonzero.
+                let bits_be = [vec![false], scalar.to_bits_be()].concat();
+                let windows = bits_be.chunks(2).map(|window| window[0] || window[1]).collect::<Vec<_>>();
+                let num_leading_zero_windows = windows.iter().take_while(|is_nonzero| !**is_nonzero).count();
+                let num_remaining_windows = windows.len() - num_leading_zero_windows - 1;
+                let num_zero_windows =
+                    windows.iter().skip(num_leading_zero_windows).filter(|is_nonzero| !**is_nonzero).count();
+
+                let num_constant = 511 + 6 /* DOUBLE constant */ * num_leading_zero_windows; // Typically around 520.
+                let num_private = 14 + (10 /* DOUBLE private */ + 6/* ADD private */) * num_remaining_windows
+                    - 3 /* ADD constant */ * num_zero_windows; // Typically around 1900.
+                let num_constraints = num_private;
 
                 (num_constant, num_private, num_constraints)
             };
@@ -368,9 +389,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Public, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul(&name, &expected, &a, &b, 511, 0, 2768, 2768);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul_assign(&name, &expected, &a, &b, 511, 0, 2768, 2768);
         }
     }
 
@@ -385,9 +406,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Private, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul(&name, &expected, &a, &b, 511, 0, 2768, 2768);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul_assign(&name, &expected, &a, &b, 511, 0, 2768, 2768);
         }
     }
 
@@ -402,9 +423,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Public, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul(&name, &expected, &a, &b, 511, 0, 2768, 2768);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul_assign(&name, &expected, &a, &b, 511, 0, 2768, 2768);
         }
     }
 
@@ -419,9 +440,9 @@ mod tests {
             let b = Scalar::<Circuit>::new(Mode::Private, scalar);
 
             let name = format!("Mul: a * b {}", i);
-            check_mul(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul(&name, &expected, &a, &b, 511, 0, 2768, 2768);
             let name = format!("MulAssign: a * b {}", i);
-            check_mul_assign(&name, &expected, &a, &b, 755, 0, 3255, 3255);
+            check_mul_assign(&name, &expected, &a, &b, 511, 0, 2768, 2768);
         }
     }
 
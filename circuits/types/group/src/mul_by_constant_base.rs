@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of scalar bits consumed by each lookup table.
+const WINDOW_SIZE: usize = 3;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Returns `self * scalar`, where `self` is a constant group element,
+    /// and `scalar` is given as a list of little-endian bits.
+    ///
+    /// The scalar is split into 3-bit windows. For each window, a table of the 8 multiples
+    /// of the (constant) window base is precomputed natively, and the window bits select
+    /// an entry of the table as a linear combination. The selected entries are then summed.
+    ///
+    /// Each variable window costs 3 constraints for the lookup, and 6 constraints for the sum.
+    ///
+    pub fn mul_by_constant_base(&self, bits_le: &[Boolean<E>]) -> Group<E> {
+        // Ensure the base is a constant.
+        if !self.is_constant() {
+            E::halt("Attempted to perform a fixed-base scalar multiplication on a variable base")
+        }
+
+        // If the scalar is also a constant, compute the product natively.
+        if bits_le.iter().all(|bit| bit.is_constant()) {
+            let bits_be = bits_le.iter().rev().map(|bit| bit.eject_value());
+            return Group::constant(self.eject_value().mul_bits(bits_be).to_affine());
+        }
+
+        // Initialize the base for the first window.
+        let mut base = self.eject_value().to_projective();
+
+        bits_le
+            .chunks(WINDOW_SIZE)
+            .map(|window| {
+                // Precompute the table of multiples `[0 * base, 1 * base, ..., 7 * base]` for this window.
+                let mut table = Vec::with_capacity(1 << WINDOW_SIZE);
+                let mut multiple = <E::Affine as AffineCurve>::Projective::zero();
+                for _ in 0..(1 << WINDOW_SIZE) {
+                    table.push(multiple.to_affine());
+                    multiple += base;
+                }
+
+                // Update the base for the next window, as `2^WINDOW_SIZE * base`.
+                for _ in 0..WINDOW_SIZE {
+                    base.double_in_place();
+                }
+
+                // Pad the (final) window with `false` bits, if necessary.
+                let bit = |index: usize| window.get(index).cloned().unwrap_or_else(|| Boolean::constant(false));
+
+                Self::lookup(&table, &bit(0), &bit(1), &bit(2))
+            })
+            .reduce(|output, point| output + point)
+            .unwrap_or_else(Group::zero)
+    }
+
+    ///
+    /// Returns the entry of the given 8-element table at index `b0 + 2 * b1 + 4 * b2`.
+    ///
+    /// The entry is selected as `lo + b2 * (hi - lo)`, where `lo` and `hi` are linear combinations
+    /// over `(b0, b1, b0 * b1)` selecting from the lower and upper half of the table respectively.
+    /// As each entry of the table is a valid curve point, the selected point is on the curve.
+    ///
+    pub(crate) fn lookup(table: &[E::Affine], b0: &Boolean<E>, b1: &Boolean<E>, b2: &Boolean<E>) -> Group<E> {
+        debug_assert_eq!(table.len(), 1 << WINDOW_SIZE);
+
+        // Compute `b0 * b1`.
+        let b0_b1 = b0 & b1;
+
+        // Returns the linear combination selecting one of the 4 given constants with `(b0, b1)`.
+        let select = |c: [E::BaseField; 4]| -> LinearCombination<E::BaseField> {
+            E::one() * c[0] + &**b0 * (c[1] - c[0]) + &**b1 * (c[2] - c[0]) + &*b0_b1 * (c[3] - c[2] - c[1] + c[0])
+        };
+
+        // Returns the coordinate selected by `(b0, b1, b2)`, given the coordinates of the table.
+        let coordinate = |c: [E::BaseField; 8]| -> Field<E> {
+            let lo = select([c[0], c[1], c[2], c[3]]);
+            let hi = select([c[4], c[5], c[6], c[7]]);
+            Field::from(&lo) + Field::from_boolean(b2) * Field::from(hi - lo)
+        };
+
+        let x = coordinate([0, 1, 2, 3, 4, 5, 6, 7].map(|i| table[i].to_x_coordinate()));
+        let y = coordinate([0, 1, 2, 3, 4, 5, 6, 7].map(|i| table[i].to_y_coordinate()));
+
+        Group { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, ToBits as TBits, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    fn check_mul_by_constant_base(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        for i in 0..ITERATIONS {
+            let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+
+            let expected: <Circuit as Environment>::Affine = (base * scalar).into();
+            let a = Group::<Circuit>::new(Mode::Constant, base);
+            let bits_le: Vec<Boolean<_>> = Inject::new(mode, scalar.to_bits_le());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = a.mul_by_constant_base(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_by_constant_base_constant() {
+        check_mul_by_constant_base(Mode::Constant, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_mul_by_constant_base_public() {
+        check_mul_by_constant_base(Mode::Public, 166, 0, 748, 748);
+    }
+
+    #[test]
+    fn test_mul_by_constant_base_private() {
+        check_mul_by_constant_base(Mode::Private, 166, 0, 748, 748);
+    }
+
+    #[test]
+    fn test_mul_by_constant_base_matches_double_and_add() {
+        let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+        let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+
+        // Compute the product with the windowed method.
+        let a = Group::<Circuit>::new(Mode::Constant, base);
+        let bits_le: Vec<Boolean<_>> = Inject::new(Mode::Private, scalar.to_bits_le());
+        let candidate_a = a.mul_by_constant_base(&bits_le);
+
+        // Compute the product with double-and-add.
+        let b = Group::<Circuit>::new(Mode::Private, base);
+        let candidate_b = b * Scalar::<Circuit>::new(Mode::Private, scalar);
+
+        assert_eq!(candidate_a.eject_value(), candidate_b.eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_mul_by_constant_base_short_scalar() {
+        let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+
+        for num_bits in 1..8 {
+            for value in 0..(1u64 << num_bits) {
+                let bits_le =
+                    (0..num_bits).map(|i| Boolean::new(Mode::Private, (value >> i) & 1 == 1)).collect::<Vec<_>>();
+                let expected = base.mul_bits((0..num_bits).rev().map(|i| (value >> i) & 1 == 1)).to_affine();
+
+                let candidate = Group::<Circuit>::new(Mode::Constant, base).mul_by_constant_base(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
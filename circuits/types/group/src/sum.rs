@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Returns the sum of the given group elements.
+    ///
+    /// The constant elements are summed natively, and added to the sum of the variable elements once,
+    /// so the cost is 6 constraints per variable element, and 3 constraints for the constant elements.
+    ///
+    /// Note: Unlike the short Weierstrass chord-and-tangent law, the twisted Edwards addition law
+    /// witnesses `x3` and `y3` directly, enforcing each with a single constraint. As there is no slope
+    /// to invert, batching the inverses of the denominators with a Montgomery batch inverse witness
+    /// would add constraints (to check the batched inverses) rather than remove them.
+    ///
+    pub fn sum(elements: &[Group<E>]) -> Group<E> {
+        // Partition the elements into constants and variables.
+        let (constants, variables): (Vec<_>, Vec<_>) = elements.iter().partition(|element| element.is_constant());
+
+        // Sum the constant elements natively.
+        let constant = constants
+            .iter()
+            .fold(<E::Affine as AffineCurve>::Projective::zero(), |output, element| {
+                output + element.eject_value().to_projective()
+            })
+            .to_affine();
+
+        // Sum the variable elements.
+        let variable = variables.into_iter().cloned().reduce(|output, element| output + element);
+
+        match (constant.is_zero(), variable) {
+            (true, Some(variable)) => variable,
+            (false, Some(variable)) => variable + Group::constant(constant),
+            (_, None) => Group::constant(constant),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    fn check_sum(
+        modes: &[Mode],
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        for i in 0..ITERATIONS {
+            // Sample the random elements.
+            let points: Vec<<Circuit as Environment>::Affine> =
+                (0..modes.len()).map(|_| UniformRand::rand(&mut test_rng())).collect();
+            let expected = points.iter().fold(<Circuit as Environment>::Affine::zero(), |output, point| {
+                (output.to_projective() + point.to_projective()).to_affine()
+            });
+
+            // Inject the elements.
+            let elements: Vec<_> =
+                modes.iter().zip_eq(&points).map(|(mode, point)| Group::new(*mode, *point)).collect();
+
+            Circuit::scope(format!("Sum {i}"), || {
+                let candidate = Group::<Circuit>::sum(&elements);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        check_sum(&[], 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_sum_constant() {
+        check_sum(&[Mode::Constant; 8], 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_sum_public() {
+        check_sum(&[Mode::Public], 0, 0, 0, 0);
+        check_sum(&[Mode::Public; 8], 14, 0, 42, 42);
+    }
+
+    #[test]
+    fn test_sum_private() {
+        check_sum(&[Mode::Private], 0, 0, 0, 0);
+        check_sum(&[Mode::Private; 8], 14, 0, 42, 42);
+    }
+
+    #[test]
+    fn test_sum_mixed() {
+        let modes = [Mode::Constant, Mode::Public, Mode::Private, Mode::Constant];
+        check_sum(&[modes, modes].concat(), 12, 0, 21, 21);
+    }
+
+    #[test]
+    fn test_sum_matches_fold() {
+        // Sample the random elements.
+        let points: Vec<<Circuit as Environment>::Affine> =
+            (0..8).map(|_| UniformRand::rand(&mut test_rng())).collect();
+        let elements: Vec<_> = points.iter().map(|point| Group::<Circuit>::new(Mode::Private, *point)).collect();
+
+        let expected = elements.iter().fold(Group::zero(), |output, element| output + element);
+        let candidate = Group::sum(&elements);
+        assert_eq!(expected.eject_value(), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+}
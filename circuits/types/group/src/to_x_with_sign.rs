@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Returns the compressed form of the group element, as its x-coordinate and a sign bit.
+    ///
+    /// The sign bit is `true` if the y-coordinate is the lexicographically greatest of `y` and `-y`,
+    /// following the convention of `AffineCurve::from_x_coordinate`. As `-y = p - y` for a nonzero `y`,
+    /// this is equivalent to checking that `y > (p - 1) / 2`.
+    ///
+    pub fn to_x_with_sign(&self) -> (Field<E>, Boolean<E>) {
+        // Compute `(p - 1) / 2`, which is `-1 / 2` in the base field.
+        let half_modulus = Field::constant(-E::BaseField::half());
+
+        (self.x.clone(), self.y.is_greater_than(&half_modulus))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_x_with_sign(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let expected_sign = expected.to_y_coordinate() > -expected.to_y_coordinate();
+            let candidate = Group::<Circuit>::new(mode, expected);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let (x, sign) = candidate.to_x_with_sign();
+                assert_eq!(expected.to_x_coordinate(), x.eject_value());
+                assert_eq!(expected_sign, sign.eject_value());
+                assert_eq!(mode.is_constant(), sign.is_constant());
+            });
+
+            // Ensure the sign bit recovers the original point.
+            let x_coordinate = expected.to_x_coordinate();
+            let recovered = <Circuit as Environment>::Affine::from_x_coordinate(x_coordinate, expected_sign);
+            assert_eq!(Some(expected), recovered);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_x_with_sign_constant() {
+        check_to_x_with_sign(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_x_with_sign_public() {
+        check_to_x_with_sign(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_x_with_sign_private() {
+        check_to_x_with_sign(Mode::Private);
+    }
+}
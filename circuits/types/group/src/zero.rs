@@ -19,10 +19,12 @@ use super::*;
 impl<E: Environment> Zero for Group<E> {
     type Boolean = Boolean<E>;
 
+    /// Returns the identity of the group, `(0, 1)`, as a constant.
     fn zero() -> Self {
         Group { x: Field::zero(), y: Field::one() }
     }
 
+    /// Returns `true` if `self` is the identity of the group.
     fn is_zero(&self) -> Self::Boolean {
         let is_x_zero = self.x.is_equal(&Field::zero());
         let is_y_one = self.y.is_equal(&Field::one());
@@ -34,6 +36,9 @@ impl<E: Environment> Zero for Group<E> {
 mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
 
     #[test]
     fn test_zero() {
@@ -55,4 +60,30 @@ mod tests {
         // Should equal 0.
         assert!(candidate.is_zero().eject_value());
     }
+
+    #[test]
+    fn test_is_zero_variable() {
+        let identity = <Circuit as Environment>::Affine::zero();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the injected identity is zero.
+            let candidate = Group::<Circuit>::new(mode, identity);
+            assert!(candidate.is_zero().eject_value());
+
+            // Ensure the point of order 2, `(0, -1)`, is not zero.
+            let x = Field::new(mode, <Circuit as Environment>::BaseField::zero());
+            let y = Field::new(mode, -<Circuit as Environment>::BaseField::one());
+            let candidate = Group::<Circuit>::from_xy_coordinates(x, y);
+            assert!(!candidate.is_zero().eject_value());
+
+            // Ensure random elements are not zero.
+            for _ in 0..ITERATIONS {
+                let point = <Circuit as Environment>::Affine::rand(&mut test_rng());
+                let candidate = Group::<Circuit>::new(mode, point);
+                assert!(!candidate.is_zero().eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
 }
@@ -88,7 +88,7 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
             };
 
             // Check for overflow.
-            match I::is_signed() {
+            E::annotate(self.annotation(other, "addition", "overflow"), || match I::is_signed() {
                 // For signed addition, overflow and underflow conditions are:
                 //   - a > 0 && b > 0 && a + b < 0 (Overflow)
                 //   - a < 0 && b < 0 && a + b > 0 (Underflow)
@@ -101,7 +101,7 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
                 }
                 // For unsigned addition, ensure the carry bit is zero.
                 false => E::assert_eq(carry, E::zero()),
-            }
+            });
 
             // Return the sum of `self` and `other`.
             sum
@@ -25,12 +25,15 @@ impl<E: Environment, I: IntegerType> Equal<Self> for Integer<E, I> {
     fn is_equal(&self, other: &Self) -> Self::Boolean {
         // Determine if this operation is constant or variable.
         match self.is_constant() && other.is_constant() {
-            true => self
-                .bits_le
-                .iter()
-                .zip_eq(other.bits_le.iter())
-                .map(|(this, that)| this.is_equal(that))
-                .fold(Boolean::constant(true), |a, b| a & b),
+            true => {
+                let bits_are_equal = self
+                    .bits_le
+                    .iter()
+                    .zip_eq(other.bits_le.iter())
+                    .map(|(this, that)| this.is_equal(that))
+                    .collect::<Vec<_>>();
+                Boolean::all(&bits_are_equal)
+            }
             false => {
                 // Instead of comparing the bits of `self` and `other` directly, the integers are
                 // converted into a field elements, and checked if they are equivalent as field elements.
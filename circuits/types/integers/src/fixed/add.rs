@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Add<Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Add<Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn add(self, other: Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Add<&Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Add<&Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn add(self, other: &Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        let mut output = self.clone();
+        output += other;
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> AddAssign<Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn add_assign(&mut self, other: Fixed<E, I, FRAC_BITS>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> AddAssign<&Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn add_assign(&mut self, other: &Fixed<E, I, FRAC_BITS>) {
+        // Stores the sum of `self` and `other` in `self`.
+        // As both operands share the same scale, the sum of their underlying integers is exact.
+        self.integer = self.integer.add_checked(&other.integer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_add<I, const FRAC_BITS: u32>(first: I, second: I, mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + std::panic::RefUnwindSafe,
+    {
+        let a = Fixed::<Circuit, I, FRAC_BITS>::new(mode_a, first);
+        let b = Fixed::<Circuit, I, FRAC_BITS>::new(mode_b, second);
+        let case = format!("({} + {})", a, b);
+        let operation = |a: &Fixed<Circuit, I, FRAC_BITS>, b: &Fixed<Circuit, I, FRAC_BITS>| a + b;
+        match first.checked_add(&second) {
+            Some(expected) => check_operation_passes_without_counts("Add", &case, expected, &a, &b, operation),
+            None => match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, operation),
+                _ => check_operation_fails_without_counts("Add", &case, &a, &b, operation),
+            },
+        }
+    }
+
+    fn run_test<I, const FRAC_BITS: u32>(mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + std::panic::RefUnwindSafe,
+    {
+        for _ in 0..ITERATIONS {
+            let first: I = UniformRand::rand(&mut test_rng());
+            let second: I = UniformRand::rand(&mut test_rng());
+            check_add::<I, FRAC_BITS>(first, second, mode_a, mode_b);
+        }
+        check_add::<I, FRAC_BITS>(I::MAX, I::one(), mode_a, mode_b);
+        check_add::<I, FRAC_BITS>(I::MIN, I::one(), mode_a, mode_b);
+        check_add::<I, FRAC_BITS>(I::zero(), I::MAX, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_fixed_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<u32, 16>(mode_a, mode_b);
+                run_test::<i64, 32>(mode_a, mode_b);
+                run_test::<i128, 64>(mode_a, mode_b);
+            }
+        }
+    }
+}
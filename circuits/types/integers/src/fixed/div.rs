@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Div<Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Div<Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn div(self, other: Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Div<&Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn div(self, other: &Self) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Div<&Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn div(self, other: &Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        let mut output = self.clone();
+        output /= other;
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> DivAssign<Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn div_assign(&mut self, other: Fixed<E, I, FRAC_BITS>) {
+        *self /= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> DivAssign<&Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn div_assign(&mut self, other: &Fixed<E, I, FRAC_BITS>) {
+        // Stores the quotient of `self` and `other` in `self`, rounded towards zero.
+        *self = self.div_rounded(other, Rounding::TowardZero);
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Fixed<E, I, FRAC_BITS> {
+    /// Returns the quotient of `self` and `other`, rounded with the given rounding mode.
+    /// Enforces that the rounded quotient is representable by `I`, and halts on division by zero.
+    pub fn div_rounded(&self, other: &Self, rounding: Rounding) -> Self {
+        // Halt on division by zero as there is no sound way to perform this operation.
+        if other.eject_value().is_zero() {
+            E::halt("Division by zero error")
+        }
+
+        // Note that it is safe to use `abs_wrapped`, since the magnitudes are interpreted as unsigned integers.
+        let dividend = (&self.integer).abs_wrapped();
+        let divisor = (&other.integer).abs_wrapped();
+
+        // Scale the dividend up by `2^FRAC_BITS`, into a `2 * I::BITS`-bit integer.
+        let scaled_le = vec![Boolean::constant(false); FRAC_BITS as usize]
+            .into_iter()
+            .chain(dividend.bits_le)
+            .chain(vec![Boolean::constant(false); I::BITS - FRAC_BITS as usize])
+            .collect::<Vec<_>>();
+
+        // Compute the quotient and remainder as witnesses.
+        let (quotient, remainder) = Self::div_witness(&scaled_le, &divisor.bits_le);
+        let remainder_field = Field::from_bits_le(&remainder);
+        let divisor_field = Field::from_bits_le(&divisor.bits_le);
+
+        // Ensure that Euclidean division holds for these values, by comparing the lower and upper halves.
+        let product = Self::mul_wide(&quotient, &divisor.bits_le);
+        let (product_lo, product_hi) = product.split_at(I::BITS);
+        let (scaled_lo, scaled_hi) = scaled_le.split_at(I::BITS);
+        let mut sum_lo = (Field::from_bits_le(product_lo) + &remainder_field).to_lower_bits_le(I::BITS + 1);
+        let carry = match sum_lo.pop() {
+            Some(carry) => carry,
+            None => E::halt("Malformed sum detected during fixed-point division"),
+        };
+        E::assert_eq(Field::from_bits_le(&sum_lo), Field::from_bits_le(scaled_lo));
+        E::assert_eq(Field::from_bits_le(product_hi) + Field::from_boolean(&carry), Field::from_bits_le(scaled_hi));
+
+        // Ensure that the remainder is less than the divisor.
+        (&divisor_field - &remainder_field - Field::one()).to_lower_bits_le(I::BITS);
+
+        // The remainder is at least half of the divisor if `2 * remainder - divisor` is non-negative,
+        // which is determined from the bits of `2 * remainder - divisor + 2^(I::BITS + 1)`.
+        let offset =
+            Field::from_bits_le(&[vec![Boolean::constant(false); I::BITS + 1], vec![Boolean::constant(true)]].concat());
+        let difference = (remainder_field.double() + offset - divisor_field).to_lower_bits_le(I::BITS + 2);
+        let is_half = difference[I::BITS + 1].clone();
+        let is_inexact = Boolean::any(&remainder);
+
+        // Round the quotient, and return it with the appropriate sign.
+        let is_negative = self.is_negative() ^ other.is_negative();
+        Self { integer: Self::round_magnitude(quotient, &is_negative, &is_inexact, &is_half, rounding) }
+    }
+
+    /// Returns the `I::BITS`-bit quotient and remainder of the given unsigned integers as witnesses.
+    /// Halts if the operands are constant and the quotient exceeds `I::BITS` bits.
+    fn div_witness(dividend_le: &[Boolean<E>], divisor_le: &[Boolean<E>]) -> (Vec<Boolean<E>>, Vec<Boolean<E>>) {
+        let mode = match dividend_le.iter().chain(divisor_le).all(|bit| bit.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Perform binary long division, where the divisor is at most `I::BITS <= 128` bits.
+        let divisor = divisor_le.iter().rev().fold(0u128, |value, bit| (value << 1) | bit.eject_value() as u128);
+        let mut quotient = vec![false; dividend_le.len()];
+        let mut remainder = 0u128;
+        for (index, bit) in dividend_le.iter().enumerate().rev() {
+            // The remainder is less than the divisor, so it overflows `u128` by at most the shifted-out bit.
+            let carry = remainder >> 127 == 1;
+            remainder = (remainder << 1) | bit.eject_value() as u128;
+            if carry || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient[index] = true;
+            }
+        }
+
+        // For variables, an overflowing quotient is truncated, and the Euclidean division constraints are unsatisfied.
+        if mode.is_constant() && quotient[I::BITS..].iter().any(|bit| *bit) {
+            E::halt("Fixed-point overflow on division of two constants")
+        }
+
+        let quotient = quotient[..I::BITS].iter().map(|bit| Boolean::new(mode, *bit)).collect();
+        let remainder = (0..I::BITS).map(|index| Boolean::new(mode, (remainder >> index) & 1 == 1)).collect();
+        (quotient, remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_helpers::*, *};
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 16;
+
+    fn check_div<I, const FRAC_BITS: u32>(first: I, second: I, mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        let a = Fixed::<Circuit, I, FRAC_BITS>::new(mode_a, first);
+        let b = Fixed::<Circuit, I, FRAC_BITS>::new(mode_b, second);
+        for rounding in ROUNDING_MODES {
+            let case = format!("({} / {}, {:?})", a, b, rounding);
+            let operation =
+                |a: &Fixed<Circuit, I, FRAC_BITS>, b: &Fixed<Circuit, I, FRAC_BITS>| a.div_rounded(b, rounding);
+            if second == I::zero() {
+                check_operation_halts(&a, &b, operation);
+                continue;
+            }
+            match round_div::<I>(Into::<i128>::into(first) << FRAC_BITS, second.into(), rounding) {
+                Some(expected) => check_operation_passes_without_counts("Div", &case, expected, &a, &b, operation),
+                None => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, operation),
+                    _ => check_operation_fails_without_counts("Div", &case, &a, &b, operation),
+                },
+            }
+        }
+    }
+
+    fn run_test<I, const FRAC_BITS: u32>(mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        for _ in 0..ITERATIONS {
+            let first: I = UniformRand::rand(&mut test_rng());
+            let second: I = UniformRand::rand(&mut test_rng());
+            check_div::<I, FRAC_BITS>(first, second, mode_a, mode_b);
+            check_div::<I, FRAC_BITS>(second, first, mode_a, mode_b);
+        }
+        check_div::<I, FRAC_BITS>(I::MAX, I::one(), mode_a, mode_b);
+        check_div::<I, FRAC_BITS>(I::MIN, I::MAX, mode_a, mode_b);
+        check_div::<I, FRAC_BITS>(I::MAX, I::MAX, mode_a, mode_b);
+        check_div::<I, FRAC_BITS>(I::one(), I::zero(), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_fixed_div() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<u32, 16>(mode_a, mode_b);
+                run_test::<i32, 8>(mode_a, mode_b);
+                run_test::<i64, 32>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_div_i128() {
+        // -3.375 / 1.5 == -2.25, where the scaled dividend exceeds 128 bits.
+        let a = Fixed::<Circuit, i128, 64>::new(Mode::Private, -(27 << 61));
+        let b = Fixed::<Circuit, i128, 64>::new(Mode::Private, 3 << 63);
+        assert_eq!(-(9 << 62), (a / b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod add;
+mod div;
+mod mul;
+mod sub;
+
+use super::*;
+
+/// The rounding mode of a fixed-point operation whose result is not exactly representable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds towards zero, discarding the inexact bits.
+    TowardZero,
+    /// Rounds to the nearest representable value, with ties rounded away from zero.
+    Nearest,
+}
+
+impl Rounding {
+    /// Returns `true` if the magnitude of an inexact result should be incremented,
+    /// given the sign of the result, and whether the discarded part is nonzero or at least one half.
+    fn increment<E: Environment>(
+        &self,
+        is_negative: &Boolean<E>,
+        is_inexact: &Boolean<E>,
+        is_half: &Boolean<E>,
+    ) -> Boolean<E> {
+        match self {
+            Rounding::Floor => is_negative & is_inexact,
+            Rounding::Ceil => !is_negative & is_inexact,
+            Rounding::TowardZero => Boolean::constant(false),
+            Rounding::Nearest => is_half.clone(),
+        }
+    }
+}
+
+/// A fixed-point number, represented as an integer `I` scaled by `2^FRAC_BITS`.
+#[derive(Clone)]
+pub struct Fixed<E: Environment, I: IntegerType, const FRAC_BITS: u32> {
+    integer: Integer<E, I>,
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Fixed<E, I, FRAC_BITS> {
+    /// Initializes a fixed-point number from its underlying integer, i.e. the value scaled by `2^FRAC_BITS`.
+    pub fn from_raw(integer: Integer<E, I>) -> Self {
+        Self::check_frac_bits();
+        Self { integer }
+    }
+
+    /// Returns the underlying integer, i.e. the value scaled by `2^FRAC_BITS`.
+    pub fn raw(&self) -> &Integer<E, I> {
+        &self.integer
+    }
+
+    /// Returns the fixed-point number with the value of the given integer.
+    /// Enforces that the integer is representable with `FRAC_BITS` fractional bits.
+    pub fn from_integer(integer: &Integer<E, I>) -> Self {
+        Self::check_frac_bits();
+
+        // Shift the integer left by `FRAC_BITS`, and ensure the bits shifted out are a sign extension of the result.
+        let (lower, upper) = integer.bits_le.split_at(I::BITS - FRAC_BITS as usize);
+        let sign = match I::is_signed() {
+            true => lower[lower.len() - 1].clone(),
+            false => Boolean::constant(false),
+        };
+        let mismatches = upper.iter().map(|bit| bit ^ &sign).collect::<Vec<_>>();
+        Self::assert_no_overflow(Boolean::any(&mismatches));
+
+        let bits_le = vec![Boolean::constant(false); FRAC_BITS as usize].into_iter().chain(lower.iter().cloned());
+        Self { integer: Integer { bits_le: bits_le.collect(), phantom: Default::default() } }
+    }
+
+    /// Returns `self` as an integer, rounding the fractional bits with the given rounding mode.
+    pub fn to_integer(&self, rounding: Rounding) -> Integer<E, I> {
+        // Note that it is safe to use `abs_wrapped`, since the magnitude is interpreted as an unsigned integer.
+        let magnitude = (&self.integer).abs_wrapped();
+        let (remainder, quotient) = magnitude.bits_le.split_at(FRAC_BITS as usize);
+        let quotient = quotient.iter().cloned().chain(vec![Boolean::constant(false); FRAC_BITS as usize]).collect();
+
+        let (is_inexact, is_half) = Self::remainder_flags(remainder);
+        Self::round_magnitude(quotient, &self.is_negative(), &is_inexact, &is_half, rounding)
+    }
+
+    /// Returns `true` if `self` is negative.
+    fn is_negative(&self) -> Boolean<E> {
+        match I::is_signed() {
+            true => self.integer.msb().clone(),
+            false => Boolean::constant(false),
+        }
+    }
+
+    /// Returns whether the given little-endian fractional bits are nonzero, and whether they are at least one half.
+    fn remainder_flags(remainder_le: &[Boolean<E>]) -> (Boolean<E>, Boolean<E>) {
+        let is_inexact = Boolean::any(remainder_le);
+        let is_half = remainder_le.last().cloned().unwrap_or_else(|| Boolean::constant(false));
+        (is_inexact, is_half)
+    }
+
+    /// Rounds the given `I::BITS`-bit unsigned magnitude, and returns it as an integer with the given sign.
+    /// Enforces that the rounded result is representable by `I`.
+    fn round_magnitude(
+        magnitude_le: Vec<Boolean<E>>,
+        is_negative: &Boolean<E>,
+        is_inexact: &Boolean<E>,
+        is_half: &Boolean<E>,
+        rounding: Rounding,
+    ) -> Integer<E, I> {
+        // Increment the magnitude in the base field, and ensure it does not carry.
+        let increment = rounding.increment(is_negative, is_inexact, is_half);
+        let sum = Field::from_bits_le(&magnitude_le) + Field::from_boolean(&increment);
+        let mut bits_le = sum.to_lower_bits_le(I::BITS + 1);
+        let carry = match bits_le.pop() {
+            Some(carry) => carry,
+            None => E::halt("Malformed sum detected during fixed-point rounding"),
+        };
+        let magnitude = Integer::<E, I> { bits_le, phantom: Default::default() };
+
+        match I::is_signed() {
+            true => {
+                // A positive result cannot exceed the signed maximum,
+                // and a negative result cannot exceed the absolute value of the signed minimum.
+                let lower_bits_nonzero = Boolean::any(&magnitude.bits_le[..(I::BITS - 1)]);
+                let exceeds_signed_bounds = magnitude.msb() & (!is_negative | lower_bits_nonzero);
+                Self::assert_no_overflow(carry | exceeds_signed_bounds);
+
+                // Return the magnitude with the appropriate sign.
+                Integer::ternary(is_negative, &Integer::zero().sub_wrapped(&magnitude), &magnitude)
+            }
+            false => {
+                Self::assert_no_overflow(carry);
+                magnitude
+            }
+        }
+    }
+
+    /// Returns the full `2 * I::BITS`-bit product of the given `I::BITS`-bit unsigned magnitudes.
+    fn mul_wide(this: &[Boolean<E>], that: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        if 2 * I::BITS < E::BaseField::size_in_bits() - 1 {
+            // The product fits in the base field, so it is computed directly.
+            (Field::from_bits_le(this) * Field::from_bits_le(that)).to_lower_bits_le(2 * I::BITS)
+        } else {
+            // Otherwise, the magnitudes are widened and multiplied as 256-bit integers.
+            let widen = |bits_le: &[Boolean<E>]| {
+                let padding = vec![Boolean::constant(false); Uint256::BITS - bits_le.len()];
+                U256::<E>::from_bits_le(&bits_le.iter().cloned().chain(padding).collect::<Vec<_>>())
+            };
+            let mut product = widen(this).mul_wrapped(&widen(that)).to_bits_le();
+            product.truncate(2 * I::BITS);
+            product
+        }
+    }
+
+    /// Enforces that `overflow` is `false`, halting if it is a constant.
+    fn assert_no_overflow(overflow: Boolean<E>) {
+        match overflow.is_constant() {
+            true => {
+                if overflow.eject_value() {
+                    E::halt("Fixed-point overflow on an operation over constants")
+                }
+            }
+            false => E::assert_eq(overflow, E::zero()),
+        }
+    }
+
+    /// Halts if `FRAC_BITS` leaves no integer bits in `I`.
+    fn check_frac_bits() {
+        if FRAC_BITS as usize >= I::BITS {
+            E::halt(format!("A fixed-point {} cannot have {FRAC_BITS} fractional bits", I::type_name()))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Inject for Fixed<E, I, FRAC_BITS> {
+    type Primitive = I;
+
+    /// Initializes a new fixed-point number from its underlying integer value.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self::from_raw(Integer::new(mode, value))
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Eject for Fixed<E, I, FRAC_BITS> {
+    type Primitive = I;
+
+    ///
+    /// Ejects the mode of the fixed-point number.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.integer.eject_mode()
+    }
+
+    ///
+    /// Ejects the underlying integer value of the fixed-point number.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        self.integer.eject_value()
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> fmt::Debug for Fixed<E, I, FRAC_BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> fmt::Display for Fixed<E, I, FRAC_BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({} >> {FRAC_BITS})", self.integer)
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+
+    /// Returns `numerator / denominator`, rounded with the given rounding mode, or `None` if it does not fit in `I`.
+    pub fn round_div<I: IntegerType + TryFrom<i128>>(
+        numerator: i128,
+        denominator: i128,
+        rounding: Rounding,
+    ) -> Option<I> {
+        let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+        let is_negative = (numerator < 0) != (denominator < 0);
+        let is_inexact = remainder != 0;
+        let is_half = 2 * remainder.abs() >= denominator.abs();
+        let increment = match rounding {
+            Rounding::Floor => is_negative && is_inexact,
+            Rounding::Ceil => !is_negative && is_inexact,
+            Rounding::TowardZero => false,
+            Rounding::Nearest => is_half,
+        };
+        let magnitude = quotient.abs() + increment as i128;
+        I::try_from(if is_negative { -magnitude } else { magnitude }).ok()
+    }
+
+    pub const ROUNDING_MODES: [Rounding; 4] =
+        [Rounding::Floor, Rounding::Ceil, Rounding::TowardZero, Rounding::Nearest];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::*, *};
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_from_integer<I, const FRAC_BITS: u32>(mode: Mode, value: I)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        let integer = Integer::<Circuit, I>::new(mode, value);
+        let case = format!("from_integer({})", value);
+        let operation = |integer: &Integer<Circuit, I>, _: ()| Fixed::<Circuit, I, FRAC_BITS>::from_integer(integer);
+        match I::try_from(Into::<i128>::into(value) << FRAC_BITS) {
+            Ok(expected) => {
+                check_operation_passes_without_counts("FromInteger", &case, expected, &integer, (), operation)
+            }
+            Err(_) => match mode {
+                Mode::Constant => check_operation_halts(&integer, (), operation),
+                _ => check_operation_fails_without_counts("FromInteger", &case, &integer, (), operation),
+            },
+        }
+    }
+
+    fn check_to_integer<I, const FRAC_BITS: u32>(mode: Mode, value: I)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        let fixed = Fixed::<Circuit, I, FRAC_BITS>::new(mode, value);
+        for rounding in ROUNDING_MODES {
+            let case = format!("to_integer({}, {:?})", value, rounding);
+            let operation = |fixed: &Fixed<Circuit, I, FRAC_BITS>, _: ()| fixed.to_integer(rounding);
+            match round_div::<I>(value.into(), 1 << FRAC_BITS, rounding) {
+                Some(expected) => {
+                    check_operation_passes_without_counts("ToInteger", &case, expected, &fixed, (), operation)
+                }
+                None => match mode {
+                    Mode::Constant => check_operation_halts(&fixed, (), operation),
+                    _ => check_operation_fails_without_counts("ToInteger", &case, &fixed, (), operation),
+                },
+            }
+        }
+    }
+
+    fn run_test<I, const FRAC_BITS: u32>()
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let value: I = UniformRand::rand(&mut test_rng());
+                check_from_integer::<I, FRAC_BITS>(mode, value);
+                check_to_integer::<I, FRAC_BITS>(mode, value);
+            }
+            for value in [I::zero(), I::one(), I::MAX, I::MIN] {
+                check_from_integer::<I, FRAC_BITS>(mode, value);
+                check_to_integer::<I, FRAC_BITS>(mode, value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_u32_conversions() {
+        run_test::<u32, 0>();
+        run_test::<u32, 8>();
+        run_test::<u32, 31>();
+    }
+
+    #[test]
+    fn test_i32_conversions() {
+        run_test::<i32, 0>();
+        run_test::<i32, 8>();
+        run_test::<i32, 31>();
+    }
+
+    #[test]
+    fn test_i64_conversions() {
+        run_test::<i64, 16>();
+    }
+
+    #[test]
+    fn test_halfway_rounding() {
+        // -2.5 and 2.5 in an i32 with a single fractional bit.
+        let check = |value: i32, rounding: Rounding, expected: i32| {
+            let fixed = Fixed::<Circuit, i32, 1>::new(Mode::Private, value);
+            assert_eq!(expected, fixed.to_integer(rounding).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        };
+        check(5, Rounding::Floor, 2);
+        check(5, Rounding::Ceil, 3);
+        check(5, Rounding::TowardZero, 2);
+        check(5, Rounding::Nearest, 3);
+        check(-5, Rounding::Floor, -3);
+        check(-5, Rounding::Ceil, -2);
+        check(-5, Rounding::TowardZero, -2);
+        check(-5, Rounding::Nearest, -3);
+    }
+
+    #[test]
+    fn test_invalid_frac_bits() {
+        let result = std::panic::catch_unwind(|| Fixed::<Circuit, u8, 8>::new(Mode::Constant, 1));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Mul<Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Mul<Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn mul(self, other: Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Mul<&Fixed<E, I, FRAC_BITS>> for Fixed<E, I, FRAC_BITS> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Mul<&Fixed<E, I, FRAC_BITS>> for &Fixed<E, I, FRAC_BITS> {
+    type Output = Fixed<E, I, FRAC_BITS>;
+
+    fn mul(self, other: &Fixed<E, I, FRAC_BITS>) -> Self::Output {
+        let mut output = self.clone();
+        output *= other;
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> MulAssign<Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn mul_assign(&mut self, other: Fixed<E, I, FRAC_BITS>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> MulAssign<&Fixed<E, I, FRAC_BITS>>
+    for Fixed<E, I, FRAC_BITS>
+{
+    fn mul_assign(&mut self, other: &Fixed<E, I, FRAC_BITS>) {
+        // Stores the product of `self` and `other` in `self`, rounded towards zero.
+        *self = self.mul_rounded(other, Rounding::TowardZero);
+    }
+}
+
+impl<E: Environment, I: IntegerType, const FRAC_BITS: u32> Fixed<E, I, FRAC_BITS> {
+    /// Returns the product of `self` and `other`, rounded with the given rounding mode.
+    /// Enforces that the rounded product is representable by `I`.
+    pub fn mul_rounded(&self, other: &Self, rounding: Rounding) -> Self {
+        // Multiply the absolute values of `self` and `other` into a `2 * I::BITS`-bit product.
+        // Note that it is safe to use `abs_wrapped`, since the magnitudes are interpreted as unsigned integers.
+        let product = Self::mul_wide(&(&self.integer).abs_wrapped().bits_le, &(&other.integer).abs_wrapped().bits_le);
+
+        // Scale the product down by `2^FRAC_BITS`, and ensure the result fits in `I::BITS` bits.
+        let (remainder, quotient) = product.split_at(FRAC_BITS as usize);
+        let (quotient, upper) = quotient.split_at(I::BITS);
+        Self::assert_no_overflow(Boolean::any(upper));
+
+        // Round the quotient, and return it with the appropriate sign.
+        let (is_inexact, is_half) = Self::remainder_flags(remainder);
+        let is_negative = self.is_negative() ^ other.is_negative();
+        Self { integer: Self::round_magnitude(quotient.to_vec(), &is_negative, &is_inexact, &is_half, rounding) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test_helpers::*, *};
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 16;
+
+    fn check_mul<I, const FRAC_BITS: u32>(first: I, second: I, mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        let a = Fixed::<Circuit, I, FRAC_BITS>::new(mode_a, first);
+        let b = Fixed::<Circuit, I, FRAC_BITS>::new(mode_b, second);
+        for rounding in ROUNDING_MODES {
+            let case = format!("({} * {}, {:?})", a, b, rounding);
+            let operation =
+                |a: &Fixed<Circuit, I, FRAC_BITS>, b: &Fixed<Circuit, I, FRAC_BITS>| a.mul_rounded(b, rounding);
+            match round_div::<I>(Into::<i128>::into(first) * Into::<i128>::into(second), 1 << FRAC_BITS, rounding) {
+                Some(expected) => check_operation_passes_without_counts("Mul", &case, expected, &a, &b, operation),
+                None => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, operation),
+                    _ => check_operation_fails_without_counts("Mul", &case, &a, &b, operation),
+                },
+            }
+        }
+    }
+
+    fn run_test<I, const FRAC_BITS: u32>(mode_a: Mode, mode_b: Mode)
+    where
+        I: IntegerType + TryFrom<i128> + Into<i128> + std::panic::RefUnwindSafe,
+    {
+        // Halve the bits of the random operands, so that most products do not overflow.
+        let sample = || {
+            let value: I = UniformRand::rand(&mut test_rng());
+            I::try_from(Into::<i128>::into(value) >> (I::BITS / 2)).ok().unwrap()
+        };
+        for _ in 0..ITERATIONS {
+            check_mul::<I, FRAC_BITS>(sample(), sample(), mode_a, mode_b);
+            check_mul::<I, FRAC_BITS>(
+                UniformRand::rand(&mut test_rng()),
+                UniformRand::rand(&mut test_rng()),
+                mode_a,
+                mode_b,
+            );
+        }
+        check_mul::<I, FRAC_BITS>(I::MAX, I::one(), mode_a, mode_b);
+        check_mul::<I, FRAC_BITS>(I::MIN, I::one(), mode_a, mode_b);
+        check_mul::<I, FRAC_BITS>(I::MAX, I::MAX, mode_a, mode_b);
+        check_mul::<I, FRAC_BITS>(I::zero(), I::MIN, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_fixed_mul() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<u32, 16>(mode_a, mode_b);
+                run_test::<i32, 8>(mode_a, mode_b);
+                run_test::<i64, 32>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_mul_i128() {
+        // 1.5 * -2.25 == -3.375, where the full product exceeds the base field.
+        let a = Fixed::<Circuit, i128, 64>::new(Mode::Private, 3 << 63);
+        let b = Fixed::<Circuit, i128, 64>::new(Mode::Private, -(9 << 62));
+        assert_eq!(-(27 << 61), (a * b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
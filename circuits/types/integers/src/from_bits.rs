@@ -25,7 +25,7 @@ impl<E: Environment, I: IntegerType> FromBits for Integer<E, I> {
         let num_bits = bits_le.len();
         if num_bits > I::BITS {
             // Check if all excess bits are zero.
-            let should_be_zero = bits_le[I::BITS..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+            let should_be_zero = Boolean::any(&bits_le[I::BITS..]);
             // Ensure `should_be_zero` is zero.
             E::assert_eq(E::zero(), should_be_zero);
         }
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Add<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Add<Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn add(self, other: Integer256<E, SIGNED>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Add<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Add<&Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn add(self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        let mut output = self.clone();
+        output += other;
+        output
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> AddAssign<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn add_assign(&mut self, other: Integer256<E, SIGNED>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> AddAssign<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn add_assign(&mut self, other: &Integer256<E, SIGNED>) {
+        // Stores the sum of `self` and `other` in `self`.
+        *self = self.add_checked(other);
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> AddWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn add_wrapped(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the sum and return the new constant.
+            Integer256::constant(self.eject_value().wrapping_add(&other.eject_value()))
+        } else {
+            // Sum the integers limb by limb, and drop the final carry bit as the operation is wrapped addition.
+            let (bits_le, _) = Self::add_with_carry(&self.bits_le, &other.bits_le, Boolean::constant(false));
+            Integer256 { bits_le }
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> AddChecked<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn add_checked(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the sum and return the new constant.
+            match self.eject_value().checked_add(&other.eject_value()) {
+                Some(value) => Integer256::constant(value),
+                None => E::halt("Integer overflow on addition of two constants"),
+            }
+        } else {
+            // Sum the integers limb by limb, keeping the final carry bit.
+            let (bits_le, carry) = Self::add_with_carry(&self.bits_le, &other.bits_le, Boolean::constant(false));
+            let sum = Integer256 { bits_le };
+
+            // Check for overflow.
+            match SIGNED {
+                // For signed addition, overflow and underflow conditions are:
+                //   - a > 0 && b > 0 && a + b < 0 (Overflow)
+                //   - a < 0 && b < 0 && a + b > 0 (Underflow)
+                true => {
+                    let is_same_sign = self.msb().is_equal(other.msb());
+                    let is_overflow = is_same_sign & sum.msb().is_not_equal(self.msb());
+                    E::assert_eq(is_overflow, E::zero());
+                }
+                // For unsigned addition, ensure the carry bit is zero.
+                false => E::assert_eq(carry, E::zero()),
+            }
+
+            sum
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_add<const SIGNED: bool>(
+        first: Primitive256<SIGNED>,
+        second: Primitive256<SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+        let case = format!("({} + {})", first, second);
+
+        let expected = first.wrapping_add(&second);
+        check_operation_passes_without_counts("AddWrapped", &case, expected, &a, &b, Integer256::add_wrapped);
+
+        match first.checked_add(&second) {
+            Some(expected) => {
+                check_operation_passes_without_counts("AddChecked", &case, expected, &a, &b, Integer256::add_checked)
+            }
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer256::add_checked),
+                false => check_operation_fails_without_counts("AddChecked", &case, &a, &b, Integer256::add_checked),
+            },
+        }
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let first = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            check_add::<SIGNED>(first, second, mode_a, mode_b);
+        }
+
+        // Check the carry between the limbs, and the bounds of the type.
+        let (min, max, one) = (Primitive256::<SIGNED>::MIN, Primitive256::<SIGNED>::MAX, Primitive256::<SIGNED>::ONE);
+        check_add(Primitive256::from_limbs(u128::MAX, 0), one, mode_a, mode_b);
+        check_add(max, one, mode_a, mode_b);
+        check_add(max, min, mode_a, mode_b);
+        check_add(min, min, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_u256_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
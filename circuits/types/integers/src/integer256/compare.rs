@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Compare<Self> for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Boolean {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the comparison and return the new constant.
+            Self::Boolean::new(Mode::Constant, self.eject_value() < other.eject_value())
+        } else {
+            // For signed integers, flipping the sign bits maps the signed order onto the unsigned order.
+            let flip_sign = |integer: &Self| {
+                let mut bits_le = integer.bits_le.clone();
+                if SIGNED {
+                    let msb = !integer.msb();
+                    bits_le[Primitive256::<SIGNED>::BITS - 1] = msb;
+                }
+                Integer256::<E, SIGNED> { bits_le }
+            };
+            // If a - b = a + !b + 1 does not carry, then a < b, otherwise a >= b.
+            let (_, carry) = flip_sign(self).sub_with_carry(&flip_sign(other));
+            !carry
+        }
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Boolean {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Boolean {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Boolean {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_compare<const SIGNED: bool>(
+        first: Primitive256<SIGNED>,
+        second: Primitive256<SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+
+        let case = format!("({} < {})", first, second);
+        check_operation_passes_without_counts("LessThan", &case, first < second, &a, &b, Integer256::is_less_than);
+
+        let case = format!("({} <= {})", first, second);
+        let operation = Integer256::is_less_than_or_equal;
+        check_operation_passes_without_counts("LessThanOrEqual", &case, first <= second, &a, &b, operation);
+
+        let case = format!("({} > {})", first, second);
+        let operation = Integer256::is_greater_than;
+        check_operation_passes_without_counts("GreaterThan", &case, first > second, &a, &b, operation);
+
+        let case = format!("({} >= {})", first, second);
+        let operation = Integer256::is_greater_than_or_equal;
+        check_operation_passes_without_counts("GreaterThanOrEqual", &case, first >= second, &a, &b, operation);
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let first = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            check_compare::<SIGNED>(first, second, mode_a, mode_b);
+            check_compare::<SIGNED>(first, first, mode_a, mode_b);
+            // Check integers which only differ in their lower limb.
+            check_compare::<SIGNED>(first, Primitive256::from_limbs(second.lo, first.hi), mode_a, mode_b);
+        }
+
+        // Check the bounds of the type.
+        let (min, max, one) = (Primitive256::<SIGNED>::MIN, Primitive256::<SIGNED>::MAX, Primitive256::<SIGNED>::ONE);
+        check_compare(min, max, mode_a, mode_b);
+        check_compare(max, min, mode_a, mode_b);
+        check_compare(min, one, mode_a, mode_b);
+        check_compare(one.wrapping_neg(), one, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_u256_compare() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_compare() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Equal<Self> for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    fn is_equal(&self, other: &Self) -> Self::Boolean {
+        // Determine if this operation is constant or variable.
+        match self.is_constant() && other.is_constant() {
+            true => Self::Boolean::new(Mode::Constant, self.eject_value() == other.eject_value()),
+            false => {
+                // Instead of comparing the bits of `self` and `other` directly, each 128-bit limb
+                // is converted into a field element, and checked for equality as a field element.
+                let limbs_are_equal = self
+                    .bits_le
+                    .chunks(128)
+                    .zip_eq(other.bits_le.chunks(128))
+                    .map(|(this, that)| Field::from_bits_le(this).is_equal(&Field::from_bits_le(that)))
+                    .collect::<Vec<_>>();
+                Boolean::all(&limbs_are_equal)
+            }
+        }
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    fn is_not_equal(&self, other: &Self) -> Self::Boolean {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_equal<const SIGNED: bool>(
+        first: Primitive256<SIGNED>,
+        second: Primitive256<SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+
+        let case = format!("({} == {})", first, second);
+        check_operation_passes_without_counts("Equal", &case, first == second, &a, &b, Integer256::is_equal);
+        let case = format!("({} != {})", first, second);
+        check_operation_passes_without_counts("NotEqual", &case, first != second, &a, &b, Integer256::is_not_equal);
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let first = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            check_equal::<SIGNED>(first, second, mode_a, mode_b);
+            check_equal::<SIGNED>(first, first, mode_a, mode_b);
+            // Check integers which only differ in one of their limbs.
+            check_equal::<SIGNED>(first, Primitive256::from_limbs(second.lo, first.hi), mode_a, mode_b);
+            check_equal::<SIGNED>(first, Primitive256::from_limbs(first.lo, second.hi), mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_u256_equal() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_equal() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod add;
+mod compare;
+mod equal;
+mod mul;
+mod primitive;
+mod sub;
+mod ternary;
+
+pub use primitive::*;
+
+use super::*;
+
+/// A 256-bit integer circuit, which is signed if `SIGNED` is `true`.
+///
+/// As a sum or product of two 256-bit integers does not fit in the base field, arithmetic
+/// is performed on 128-bit limbs, and multiplication further splits each limb into 64-bit halves.
+#[derive(Clone)]
+pub struct Integer256<E: Environment, const SIGNED: bool> {
+    bits_le: Vec<Boolean<E>>,
+}
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    /// Initializes a new integer from its lower and upper 128 bits.
+    pub fn from_u128_limbs(lo: &U128<E>, hi: &U128<E>) -> Self {
+        Self { bits_le: lo.bits_le.iter().chain(&hi.bits_le).cloned().collect() }
+    }
+
+    /// Returns the lower and upper 128 bits of the integer.
+    pub fn to_u128_limbs(&self) -> (U128<E>, U128<E>) {
+        let (lo, hi) = self.bits_le.split_at(128);
+        (Integer::from_bits_le(lo), Integer::from_bits_le(hi))
+    }
+
+    /// Returns the sum of the given 256-bit little-endian integers and the carry bit, along with the carry out.
+    /// Each 128-bit limb is summed in the base field, and its carry is propagated to the next limb.
+    fn add_with_carry(this: &[Boolean<E>], that: &[Boolean<E>], carry: Boolean<E>) -> (Vec<Boolean<E>>, Boolean<E>) {
+        let mut bits_le = Vec::with_capacity(Primitive256::<SIGNED>::BITS);
+        let mut carry = carry;
+        for (this, that) in this.chunks(128).zip_eq(that.chunks(128)) {
+            let sum = Field::from_bits_le(this) + Field::from_bits_le(that) + Field::from_boolean(&carry);
+            let mut limb = sum.to_lower_bits_le(129);
+            carry = match limb.pop() {
+                Some(carry) => carry,
+                None => E::halt("Malformed sum detected during 256-bit integer addition"),
+            };
+            bits_le.extend(limb);
+        }
+        (bits_le, carry)
+    }
+
+    /// Returns the difference of `self` and `other`, along with the carry bit of `self + !other + 1`,
+    /// which is set if and only if `self >= other` as unsigned integers.
+    fn sub_with_carry(&self, other: &Self) -> (Self, Boolean<E>) {
+        let not_other = other.bits_le.iter().map(|bit| !bit).collect::<Vec<_>>();
+        let (bits_le, carry) = Self::add_with_carry(&self.bits_le, &not_other, Boolean::constant(true));
+        (Integer256 { bits_le }, carry)
+    }
+
+    /// Returns the two's complement negation of the given 256-bit little-endian integer.
+    fn neg_wrapped(bits_le: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let not_bits_le = bits_le.iter().map(|bit| !bit).collect::<Vec<_>>();
+        let zero = vec![Boolean::constant(false); Primitive256::<SIGNED>::BITS];
+        Self::add_with_carry(&not_bits_le, &zero, Boolean::constant(true)).0
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Inject for Integer256<E, SIGNED> {
+    type Primitive = Primitive256<SIGNED>;
+
+    /// Initializes a new integer.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let bits_le = (0..Primitive256::<SIGNED>::BITS).map(|index| Boolean::new(mode, value.bit(index)));
+        Self::from_bits_le(&bits_le.collect::<Vec<_>>())
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Eject for Integer256<E, SIGNED> {
+    type Primitive = Primitive256<SIGNED>;
+
+    ///
+    /// Ejects the mode of the integer.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    ///
+    /// Ejects the integer as a constant integer value.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        Primitive256::from_bits_le(self.bits_le.iter().map(|bit| bit.eject_value()))
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> FromBits for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new integer from a list of little-endian bits *with* trailing zeros.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let num_bits = Primitive256::<SIGNED>::BITS;
+        // Ensure the list of booleans is within the allowed size in bits.
+        if bits_le.len() > num_bits {
+            // Check if all excess bits are zero.
+            let should_be_zero = Boolean::any(&bits_le[num_bits..]);
+            // Ensure `should_be_zero` is zero.
+            E::assert_eq(E::zero(), should_be_zero);
+        }
+
+        // Construct the sanitized list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.iter().take(num_bits).cloned().collect::<Vec<_>>();
+        bits_le.resize(num_bits, Boolean::constant(false));
+
+        Self { bits_le }
+    }
+
+    /// Initializes a new integer from a list of big-endian bits *with* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> ToBits for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the little-endian bits of the integer.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+
+    /// Returns the big-endian bits of the integer.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = self.to_bits_le();
+        bits_be.reverse();
+        bits_be
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MSB for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the MSB of the value.
+    fn msb(&self) -> &Self::Boolean {
+        match self.bits_le.last() {
+            Some(msb) => msb,
+            None => E::halt("Malformed integer detected while retrieving the MSB"),
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> TypeName for Integer256<E, SIGNED> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        Primitive256::<SIGNED>::type_name()
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Debug for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.eject_value())
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Display for Integer256<E, SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}.{}", self.eject_value(), Self::type_name(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_new<const SIGNED: bool>(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let expected = Primitive256::<SIGNED>::from_limbs(u128::rand(rng), u128::rand(rng));
+
+            Circuit::scope(format!("New {mode}"), || {
+                let candidate = Integer256::<Circuit, SIGNED>::new(mode, expected);
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(256, 0, 0, 0),
+                    false => assert_scope!(0, mode.is_public() as usize * 256, mode.is_private() as usize * 256, 256),
+                }
+            })
+        }
+        // Check that the minimum and maximum integer bounds are correct.
+        let (min, max) = (Primitive256::<SIGNED>::MIN, Primitive256::<SIGNED>::MAX);
+        assert_eq!(min, Integer256::<Circuit, SIGNED>::new(mode, min).eject_value());
+        assert_eq!(max, Integer256::<Circuit, SIGNED>::new(mode, max).eject_value());
+    }
+
+    fn check_u128_limbs<const SIGNED: bool>(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let (lo, hi) = (u128::rand(rng), u128::rand(rng));
+            let candidate = Integer256::<Circuit, SIGNED>::from_u128_limbs(&U128::new(mode, lo), &U128::new(mode, hi));
+            assert_eq!(Primitive256::from_limbs(lo, hi), candidate.eject_value());
+
+            let (candidate_lo, candidate_hi) = candidate.to_u128_limbs();
+            assert_eq!((lo, hi), (candidate_lo.eject_value(), candidate_hi.eject_value()));
+        }
+    }
+
+    #[test]
+    fn test_u256_new() {
+        check_new::<false>(Mode::Constant);
+        check_new::<false>(Mode::Public);
+        check_new::<false>(Mode::Private);
+    }
+
+    #[test]
+    fn test_i256_new() {
+        check_new::<true>(Mode::Constant);
+        check_new::<true>(Mode::Public);
+        check_new::<true>(Mode::Private);
+    }
+
+    #[test]
+    fn test_u128_limbs() {
+        check_u128_limbs::<false>(Mode::Constant);
+        check_u128_limbs::<false>(Mode::Private);
+        check_u128_limbs::<true>(Mode::Public);
+    }
+
+    #[test]
+    fn test_display() {
+        let candidate = I256::<Circuit>::new(Mode::Private, Primitive256::from(-2i128));
+        assert_eq!("-2i256.private", format!("{}", candidate));
+
+        let candidate = U256::<Circuit>::new(Mode::Constant, Primitive256::from(2u128));
+        assert_eq!("2u256.constant", format!("{}", candidate));
+    }
+}
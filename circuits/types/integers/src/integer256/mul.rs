@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Mul<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Mul<Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn mul(self, other: Integer256<E, SIGNED>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Mul<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Mul<&Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn mul(self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        let mut output = self.clone();
+        output *= other;
+        output
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MulAssign<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn mul_assign(&mut self, other: Integer256<E, SIGNED>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MulAssign<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn mul_assign(&mut self, other: &Integer256<E, SIGNED>) {
+        // Stores the product of `self` and `other` in `self`.
+        *self = self.mul_checked(other);
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MulWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_wrapped(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and return the new constant.
+            Integer256::constant(self.eject_value().wrapping_mul(&other.eject_value()))
+        } else {
+            // The lower 256 bits of the product are the same for signed and unsigned integers.
+            Integer256 { bits_le: Self::mul_unsigned(&self.bits_le, &other.bits_le, false) }
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> MulChecked<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_checked(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and return the new constant.
+            match self.eject_value().checked_mul(&other.eject_value()) {
+                Some(value) => Integer256::constant(value),
+                None => E::halt("Integer overflow on multiplication of two constants"),
+            }
+        } else {
+            match SIGNED {
+                true => {
+                    // Multiply the absolute values of `self` and `other`, and restore the sign afterwards.
+                    let abs = |integer: &Self| {
+                        let negated = Integer256 { bits_le: Self::neg_wrapped(&integer.bits_le) };
+                        Self::ternary(integer.msb(), &negated, integer)
+                    };
+                    let product =
+                        Integer256 { bits_le: Self::mul_unsigned(&abs(self).bits_le, &abs(other).bits_le, true) };
+                    let is_negative = self.msb() ^ other.msb();
+
+                    // The absolute value of the product must fit in 255 bits,
+                    // unless the product is `MIN`, whose absolute value is 2^255.
+                    let (lower_bits_le, _) = product.bits_le.split_at(255);
+                    let lower_bits_are_zero = (Field::from_bits_le(&lower_bits_le[..128])
+                        + Field::from_bits_le(&lower_bits_le[128..]))
+                    .is_equal(&Field::zero());
+                    E::assert(!product.msb() | (&is_negative & lower_bits_are_zero));
+
+                    let negated = Integer256 { bits_le: Self::neg_wrapped(&product.bits_le) };
+                    Self::ternary(&is_negative, &negated, &product)
+                }
+                false => Integer256 { bits_le: Self::mul_unsigned(&self.bits_le, &other.bits_le, true) },
+            }
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Integer256<E, SIGNED> {
+    /// Returns the lower 256 bits of the product of the given unsigned 256-bit little-endian integers.
+    /// If `checked` is `true`, this method enforces that the upper 256 bits of the product are zero.
+    fn mul_unsigned(this: &[Boolean<E>], that: &[Boolean<E>], checked: bool) -> Vec<Boolean<E>> {
+        // Split the integers into 64-bit limbs, so that the product of two limbs fits in the base field.
+        let this = this.chunks(64).map(Field::from_bits_le).collect::<Vec<_>>();
+        let that = that.chunks(64).map(Field::from_bits_le).collect::<Vec<_>>();
+
+        // Compute the lower 256 bits of the product, column by column, in schoolbook fashion.
+        // Note: A column holds up to four products of 64-bit limbs, along with the carry of the previous column,
+        // and is thus less than 2^131, which is safe as the field is larger than 131 bits.
+        let mut bits_le = Vec::with_capacity(Primitive256::<SIGNED>::BITS);
+        let mut carry = Field::zero();
+        for k in 0..4 {
+            let mut column = carry;
+            for i in 0..=k {
+                column += &this[i] * &that[k - i];
+            }
+            let mut limb = column.to_lower_bits_le(131);
+            carry = Field::from_bits_le(&limb.split_off(64));
+            bits_le.extend(limb);
+        }
+
+        if checked {
+            // Ensure the upper 256 bits of the product are zero. As every term is non-negative,
+            // and their sum is less than 2^133, their sum is zero if and only if each term is zero.
+            let mut upper = carry;
+            for (i, this) in this.iter().enumerate().skip(1) {
+                for that in &that[(4 - i)..] {
+                    upper += this * that;
+                }
+            }
+            E::assert_eq(upper, E::zero());
+        }
+
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 16;
+
+    fn check_mul<const SIGNED: bool>(
+        first: Primitive256<SIGNED>,
+        second: Primitive256<SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+        let case = format!("({} * {})", first, second);
+
+        let expected = first.wrapping_mul(&second);
+        check_operation_passes_without_counts("MulWrapped", &case, expected, &a, &b, Integer256::mul_wrapped);
+
+        match first.checked_mul(&second) {
+            Some(expected) => {
+                check_operation_passes_without_counts("MulChecked", &case, expected, &a, &b, Integer256::mul_checked)
+            }
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer256::mul_checked),
+                false => check_operation_fails_without_counts("MulChecked", &case, &a, &b, Integer256::mul_checked),
+            },
+        }
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Random 256-bit integers almost always overflow.
+            let first = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            check_mul::<SIGNED>(first, second, mode_a, mode_b);
+
+            // Sign-extended 127-bit integers never overflow.
+            let first = Primitive256::from_limbs(u128::rand(rng) >> 1, 0);
+            let second = Primitive256::from_limbs(u128::rand(rng) >> 1, 0);
+            check_mul::<SIGNED>(first, second, mode_a, mode_b);
+            check_mul::<SIGNED>(first.wrapping_neg(), second, mode_a, mode_b);
+            check_mul::<SIGNED>(first.wrapping_neg(), second.wrapping_neg(), mode_a, mode_b);
+        }
+
+        // Check the bounds of the type.
+        let (min, max, one) = (Primitive256::<SIGNED>::MIN, Primitive256::<SIGNED>::MAX, Primitive256::<SIGNED>::ONE);
+        let two = one.wrapping_add(&one);
+        check_mul(max, one, mode_a, mode_b);
+        check_mul(max, two, mode_a, mode_b);
+        check_mul(min, one, mode_a, mode_b);
+        check_mul(min, one.wrapping_neg(), mode_a, mode_b);
+        check_mul(Primitive256::from_limbs(0, 1 << 126), two.wrapping_neg(), mode_a, mode_b);
+        check_mul(Primitive256::from_limbs(0, 1 << 126), two, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_u256_mul() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_mul() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+};
+
+/// A 256-bit integer value, stored as two 128-bit limbs in two's complement.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Primitive256<const SIGNED: bool> {
+    /// The lower 128 bits.
+    pub lo: u128,
+    /// The upper 128 bits.
+    pub hi: u128,
+}
+
+/// An unsigned 256-bit integer value.
+pub type Uint256 = Primitive256<false>;
+/// A signed 256-bit integer value.
+pub type Int256 = Primitive256<true>;
+
+impl<const SIGNED: bool> Primitive256<SIGNED> {
+    /// The number of bits of the integer.
+    pub const BITS: usize = 256;
+    /// The largest value representable by the integer.
+    pub const MAX: Self = match SIGNED {
+        true => Self::from_limbs(u128::MAX, i128::MAX as u128),
+        false => Self::from_limbs(u128::MAX, u128::MAX),
+    };
+    /// The smallest value representable by the integer.
+    pub const MIN: Self = match SIGNED {
+        true => Self::from_limbs(0, i128::MIN as u128),
+        false => Self::from_limbs(0, 0),
+    };
+    /// The value `1`.
+    pub const ONE: Self = Self::from_limbs(1, 0);
+    /// The value `0`.
+    pub const ZERO: Self = Self::from_limbs(0, 0);
+
+    /// Returns the integer with the given lower and upper 128 bits.
+    pub const fn from_limbs(lo: u128, hi: u128) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Returns the name of the integer type as a string slice. (i.e. "u256")
+    pub fn type_name() -> &'static str {
+        match SIGNED {
+            true => "i256",
+            false => "u256",
+        }
+    }
+
+    /// Returns `true` if the integer is negative.
+    pub fn is_negative(&self) -> bool {
+        SIGNED && (self.hi >> 127) == 1
+    }
+
+    /// Returns the bit at the given little-endian position.
+    pub fn bit(&self, index: usize) -> bool {
+        match index < 128 {
+            true => (self.lo >> index) & 1 == 1,
+            false => (self.hi >> (index - 128)) & 1 == 1,
+        }
+    }
+
+    /// Returns the integer with the given little-endian bits, ignoring any bit past the 256th.
+    pub fn from_bits_le(bits_le: impl IntoIterator<Item = bool>) -> Self {
+        bits_le.into_iter().take(Self::BITS).enumerate().fold(Self::ZERO, |value, (index, bit)| match index < 128 {
+            true => Self::from_limbs(value.lo | ((bit as u128) << index), value.hi),
+            false => Self::from_limbs(value.lo, value.hi | ((bit as u128) << (index - 128))),
+        })
+    }
+
+    /// Returns the sum of `self` and `other`, along with the carry out of the 256th bit.
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let (lo, carry_lo) = self.lo.overflowing_add(other.lo);
+        let (hi, carry_hi) = self.hi.overflowing_add(other.hi);
+        let (hi, carry) = hi.overflowing_add(carry_lo as u128);
+        (Self::from_limbs(lo, hi), carry_hi | carry)
+    }
+
+    /// Returns `self + other`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Returns `self + other`, or `None` if an overflow occurred.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let (sum, carry) = self.overflowing_add(other);
+        let is_overflow = match SIGNED {
+            true => self.is_negative() == other.is_negative() && sum.is_negative() != self.is_negative(),
+            false => carry,
+        };
+        match is_overflow {
+            true => None,
+            false => Some(sum),
+        }
+    }
+
+    /// Returns `-self`, wrapping around at the boundary of the type.
+    pub fn wrapping_neg(&self) -> Self {
+        Self::from_limbs(!self.lo, !self.hi).wrapping_add(&Self::ONE)
+    }
+
+    /// Returns `self - other`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        self.wrapping_add(&other.wrapping_neg())
+    }
+
+    /// Returns `self - other`, or `None` if an overflow occurred.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let difference = self.wrapping_sub(other);
+        let is_overflow = match SIGNED {
+            true => self.is_negative() != other.is_negative() && difference.is_negative() != self.is_negative(),
+            false => self < other,
+        };
+        match is_overflow {
+            true => None,
+            false => Some(difference),
+        }
+    }
+
+    /// Returns `self * other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(&self, other: &Self) -> Self {
+        let product = Self::full_mul(&self.to_u64_limbs(), &other.to_u64_limbs());
+        Self::from_u64_limbs(&product[..4])
+    }
+
+    /// Returns `self * other`, or `None` if an overflow occurred.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Multiply the magnitudes, and restore the sign afterwards.
+        let magnitude = |value: &Self| match value.is_negative() {
+            true => value.wrapping_neg(),
+            false => *value,
+        };
+        let product = Self::full_mul(&magnitude(self).to_u64_limbs(), &magnitude(other).to_u64_limbs());
+        if product[4..].iter().any(|limb| *limb != 0) {
+            return None;
+        }
+
+        let product = Self::from_u64_limbs(&product[..4]);
+        match SIGNED {
+            true => {
+                let is_negative = self.is_negative() != other.is_negative();
+                // The magnitude must fit in 255 bits, except for `MIN`, whose magnitude is `2^255`.
+                match (product.hi >> 127 == 0, is_negative) {
+                    (true, true) => Some(product.wrapping_neg()),
+                    (true, false) => Some(product),
+                    (false, true) if product == Self::MIN => Some(product),
+                    (false, _) => None,
+                }
+            }
+            false => Some(product),
+        }
+    }
+
+    /// Returns the little-endian 64-bit limbs of the integer.
+    fn to_u64_limbs(self) -> [u64; 4] {
+        [self.lo as u64, (self.lo >> 64) as u64, self.hi as u64, (self.hi >> 64) as u64]
+    }
+
+    /// Returns the integer with the given little-endian 64-bit limbs.
+    fn from_u64_limbs(limbs: &[u64]) -> Self {
+        Self::from_limbs(limbs[0] as u128 | ((limbs[1] as u128) << 64), limbs[2] as u128 | ((limbs[3] as u128) << 64))
+    }
+
+    /// Returns the 512-bit product of the given unsigned integers, as little-endian 64-bit limbs.
+    fn full_mul(this: &[u64; 4], that: &[u64; 4]) -> [u64; 8] {
+        let mut product = [0u64; 8];
+        for (i, this) in this.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, that) in that.iter().enumerate() {
+                // Note: (2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1, so this never overflows.
+                let sum = (*this as u128) * (*that as u128) + product[i + j] as u128 + carry;
+                product[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+        product
+    }
+}
+
+impl From<u128> for Uint256 {
+    fn from(value: u128) -> Self {
+        Self::from_limbs(value, 0)
+    }
+}
+
+impl From<i128> for Int256 {
+    fn from(value: i128) -> Self {
+        // Sign-extend the value into the upper limb.
+        Self::from_limbs(value as u128, (value >> 127) as u128)
+    }
+}
+
+impl<const SIGNED: bool> Ord for Primitive256<SIGNED> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match SIGNED {
+            true => (self.hi as i128, self.lo).cmp(&(other.hi as i128, other.lo)),
+            false => (self.hi, self.lo).cmp(&(other.hi, other.lo)),
+        }
+    }
+}
+
+impl<const SIGNED: bool> PartialOrd for Primitive256<SIGNED> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const SIGNED: bool> Display for Primitive256<SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+        let magnitude = match self.is_negative() {
+            true => self.wrapping_neg(),
+            false => *self,
+        };
+
+        // Divide the magnitude by 10^19 repeatedly, collecting the remainders as chunks of 19 digits.
+        let mut limbs = magnitude.to_u64_limbs();
+        let mut chunks = Vec::new();
+        loop {
+            let mut remainder = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let current = (remainder << 64) | *limb as u128;
+                *limb = (current / CHUNK) as u64;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder);
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        if let Some(chunk) = chunks.next() {
+            write!(f, "{}", chunk)?;
+        }
+        chunks.try_for_each(|chunk| write!(f, "{:019}", chunk))
+    }
+}
+
+impl<const SIGNED: bool> Debug for Primitive256<SIGNED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("0", Uint256::ZERO.to_string());
+        assert_eq!("340282366920938463463374607431768211455", Uint256::from(u128::MAX).to_string());
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            Uint256::MAX.to_string()
+        );
+        assert_eq!("-1", Int256::from(-1i128).to_string());
+        assert_eq!(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967",
+            Int256::MAX.to_string()
+        );
+        assert_eq!(
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            Int256::MIN.to_string()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_matches_128_bits() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Unsigned values below 2^64 never overflow, neither in 128 nor in 256 bits.
+            let (a, b) = (u64::rand(rng) as u128, u64::rand(rng) as u128);
+            let (x, y) = (Uint256::from(a), Uint256::from(b));
+            assert_eq!(Uint256::from(a + b), x.wrapping_add(&y));
+            assert_eq!(Some(Uint256::from(a * b)), x.checked_mul(&y));
+            assert_eq!(a.checked_sub(b).map(Uint256::from), x.checked_sub(&y));
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+
+            // Signed values between -2^63 and 2^63 never overflow, neither in 128 nor in 256 bits.
+            let (a, b) = (i64::rand(rng) as i128, i64::rand(rng) as i128);
+            let (x, y) = (Int256::from(a), Int256::from(b));
+            assert_eq!(Some(Int256::from(a + b)), x.checked_add(&y));
+            assert_eq!(Some(Int256::from(a - b)), x.checked_sub(&y));
+            assert_eq!(Some(Int256::from(a * b)), x.checked_mul(&y));
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(None, Uint256::MAX.checked_add(&Uint256::ONE));
+        assert_eq!(Uint256::ZERO, Uint256::MAX.wrapping_add(&Uint256::ONE));
+        assert_eq!(None, Uint256::ZERO.checked_sub(&Uint256::ONE));
+        assert_eq!(Uint256::MAX, Uint256::ZERO.wrapping_sub(&Uint256::ONE));
+        assert_eq!(None, Uint256::MAX.checked_mul(&Uint256::from(2u128)));
+        assert_eq!(Uint256::MAX.wrapping_sub(&Uint256::ONE), Uint256::MAX.wrapping_mul(&Uint256::from(2u128)));
+
+        assert_eq!(None, Int256::MAX.checked_add(&Int256::ONE));
+        assert_eq!(Int256::MIN, Int256::MAX.wrapping_add(&Int256::ONE));
+        assert_eq!(None, Int256::MIN.checked_sub(&Int256::ONE));
+        assert_eq!(None, Int256::MIN.checked_mul(&Int256::from(-1i128)));
+        assert_eq!(Some(Int256::MIN), Int256::MIN.checked_mul(&Int256::ONE));
+        assert_eq!(Some(Int256::MIN), Int256::from(i128::MIN).checked_mul(&Int256::from_limbs(0, 1)));
+        assert_eq!(None, Int256::from(i128::MAX).checked_mul(&Int256::from_limbs(0, 2)));
+        assert!(Int256::MIN < Int256::from(-1i128));
+        assert!(Int256::from(-1i128) < Int256::ZERO);
+    }
+
+    #[test]
+    fn test_bits() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let value = Int256::from_limbs(u128::rand(rng), u128::rand(rng));
+            assert_eq!(value, Int256::from_bits_le((0..256).map(|index| value.bit(index))));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Sub<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Sub<Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn sub(self, other: Integer256<E, SIGNED>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Sub<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> Sub<&Integer256<E, SIGNED>> for &Integer256<E, SIGNED> {
+    type Output = Integer256<E, SIGNED>;
+
+    fn sub(self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        let mut output = self.clone();
+        output -= other;
+        output
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SubAssign<Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn sub_assign(&mut self, other: Integer256<E, SIGNED>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SubAssign<&Integer256<E, SIGNED>> for Integer256<E, SIGNED> {
+    fn sub_assign(&mut self, other: &Integer256<E, SIGNED>) {
+        // Stores the difference of `self` and `other` in `self`.
+        *self = self.sub_checked(other);
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SubWrapped<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn sub_wrapped(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and return the new constant.
+            Integer256::constant(self.eject_value().wrapping_sub(&other.eject_value()))
+        } else {
+            // Drop the carry bit as the operation is wrapped subtraction.
+            self.sub_with_carry(other).0
+        }
+    }
+}
+
+impl<E: Environment, const SIGNED: bool> SubChecked<Self> for Integer256<E, SIGNED> {
+    type Output = Self;
+
+    #[inline]
+    fn sub_checked(&self, other: &Integer256<E, SIGNED>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and return the new constant.
+            match self.eject_value().checked_sub(&other.eject_value()) {
+                Some(value) => Integer256::constant(value),
+                None => E::halt("Integer underflow on subtraction of two constants"),
+            }
+        } else {
+            let (difference, carry) = self.sub_with_carry(other);
+
+            // Check for underflow.
+            match SIGNED {
+                // For signed subtraction, overflow and underflow conditions are:
+                //   - a > 0 && b < 0 && a - b < 0 (Overflow)
+                //   - a < 0 && b > 0 && a - b > 0 (Underflow)
+                true => {
+                    let is_different_sign = self.msb().is_not_equal(other.msb());
+                    let is_underflow = is_different_sign & difference.msb().is_not_equal(self.msb());
+                    E::assert_eq(is_underflow, E::zero());
+                }
+                // For unsigned subtraction, ensure the carry bit is set, i.e. that `self >= other`.
+                false => E::assert(carry),
+            }
+
+            difference
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_sub<const SIGNED: bool>(
+        first: Primitive256<SIGNED>,
+        second: Primitive256<SIGNED>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+        let b = Integer256::<Circuit, SIGNED>::new(mode_b, second);
+        let case = format!("({} - {})", first, second);
+
+        let expected = first.wrapping_sub(&second);
+        check_operation_passes_without_counts("SubWrapped", &case, expected, &a, &b, Integer256::sub_wrapped);
+
+        match first.checked_sub(&second) {
+            Some(expected) => {
+                check_operation_passes_without_counts("SubChecked", &case, expected, &a, &b, Integer256::sub_checked)
+            }
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer256::sub_checked),
+                false => check_operation_fails_without_counts("SubChecked", &case, &a, &b, Integer256::sub_checked),
+            },
+        }
+    }
+
+    fn run_test<const SIGNED: bool>(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let first = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::from_limbs(u128::rand(rng), u128::rand(rng));
+            check_sub::<SIGNED>(first, second, mode_a, mode_b);
+            check_sub::<SIGNED>(first, first, mode_a, mode_b);
+        }
+
+        // Check the borrow between the limbs, and the bounds of the type.
+        let (min, max, one) = (Primitive256::<SIGNED>::MIN, Primitive256::<SIGNED>::MAX, Primitive256::<SIGNED>::ONE);
+        check_sub(Primitive256::from_limbs(0, 1), one, mode_a, mode_b);
+        check_sub(min, one, mode_a, mode_b);
+        check_sub(max, min, mode_a, mode_b);
+        check_sub(min, max, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_u256_sub() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<false>(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_sub() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test::<true>(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const SIGNED: bool> Ternary for Integer256<E, SIGNED> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            }
+        }
+        // Variables
+        else {
+            Self {
+                bits_le: first
+                    .bits_le
+                    .iter()
+                    .zip_eq(second.bits_le.iter())
+                    .map(|(first_bit, second_bit)| Self::Boolean::ternary(condition, first_bit, second_bit))
+                    .collect(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    fn run_test<const SIGNED: bool>(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let rng = &mut test_rng();
+
+        for flag in [true, false] {
+            let first = Primitive256::<SIGNED>::from_limbs(u128::rand(rng), u128::rand(rng));
+            let second = Primitive256::<SIGNED>::from_limbs(u128::rand(rng), u128::rand(rng));
+
+            let name = format!("Ternary({}): if ({}) then ({}) else ({})", flag, mode_condition, mode_a, mode_b);
+            let case = format!("if ({}) then ({}) else ({})", flag, first, second);
+
+            let condition = Boolean::<Circuit>::new(mode_condition, flag);
+            let a = Integer256::<Circuit, SIGNED>::new(mode_a, first);
+            let b = Integer256::new(mode_b, second);
+
+            // Capture the condition in a closure and use the binary operation check.
+            let operation = |a: &Integer256<Circuit, SIGNED>, b: &Integer256<Circuit, SIGNED>| {
+                Integer256::ternary(&condition, a, b)
+            };
+            let expected = if flag { first } else { second };
+            check_operation_passes_without_counts(&name, &case, expected, &a, &b, operation);
+        }
+    }
+
+    #[test]
+    fn test_u256_ternary() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    run_test::<false>(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_i256_ternary() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    run_test::<true>(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
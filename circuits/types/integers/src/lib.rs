@@ -26,7 +26,9 @@ pub mod compare;
 pub mod div_checked;
 pub mod div_wrapped;
 pub mod equal;
+pub mod fixed;
 pub mod from_bits;
+pub mod integer256;
 pub mod msb;
 pub mod mul_checked;
 pub mod mul_wrapped;
@@ -36,6 +38,7 @@ pub mod one;
 pub mod or;
 pub mod pow_checked;
 pub mod pow_wrapped;
+pub mod rotate;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
@@ -54,12 +57,17 @@ pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
 pub type I64<E> = Integer<E, i64>;
 pub type I128<E> = Integer<E, i128>;
+pub type I256<E> = Integer256<E, true>;
 
 pub type U8<E> = Integer<E, u8>;
 pub type U16<E> = Integer<E, u16>;
 pub type U32<E> = Integer<E, u32>;
 pub type U64<E> = Integer<E, u64>;
 pub type U128<E> = Integer<E, u128>;
+pub type U256<E> = Integer256<E, false>;
+
+pub use fixed::{Fixed, Rounding};
+pub use integer256::{Int256, Integer256, Primitive256, Uint256};
 
 #[cfg(test)]
 use snarkvm_circuits_environment::assert_scope;
@@ -100,6 +108,12 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     fn cast_as_dual(self) -> Integer<E, I::Dual> {
         Integer::<E, I::Dual> { bits_le: self.bits_le, phantom: Default::default() }
     }
+
+    /// Returns the annotation for the constraints enforcing that the given operation on `self` and `other`
+    /// does not meet the given condition, to be reported by `E::diagnostics` if they are unsatisfied.
+    fn annotation(&self, other: &Self, operation: &'static str, condition: &'static str) -> Annotation {
+        Annotation::new(operation, condition, I::type_name(), I::BITS, &[self.eject_mode(), other.eject_mode()])
+    }
 }
 
 impl<E: Environment, I: IntegerType> Eject for Integer<E, I> {
@@ -488,131 +502,5 @@ mod tests {
 
 #[cfg(test)]
 mod test_utilities {
-    use core::{
-        fmt::{Debug, Display},
-        panic::UnwindSafe,
-    };
-    use snarkvm_circuits_environment::{assert_scope, assert_scope_fails, Circuit, Eject, Environment};
-
-    pub fn check_operation_passes<V: Debug + Display + PartialEq, LHS, RHS, OUT: Eject<Primitive = V>>(
-        name: &str,
-        case: &str,
-        expected: V,
-        a: LHS,
-        b: RHS,
-        operation: impl FnOnce(LHS, RHS) -> OUT,
-        num_constants: usize,
-        num_public: usize,
-        num_private: usize,
-        num_constraints: usize,
-    ) {
-        Circuit::scope(name, || {
-            let candidate = operation(a, b);
-            assert_eq!(expected, candidate.eject_value(), "{} != {} := {}", expected, candidate.eject_value(), case);
-            assert_scope!(case, num_constants, num_public, num_private, num_constraints);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_operation_passes_without_counts<
-        V: Debug + Display + PartialEq,
-        LHS,
-        RHS,
-        OUT: Eject<Primitive = V>,
-    >(
-        name: &str,
-        case: &str,
-        expected: V,
-        a: LHS,
-        b: RHS,
-        operation: impl FnOnce(LHS, RHS) -> OUT,
-    ) {
-        Circuit::scope(name, || {
-            let candidate = operation(a, b);
-            assert_eq!(expected, candidate.eject_value(), "{} != {} := {}", expected, candidate.eject_value(), case);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_operation_fails<LHS, RHS, OUT>(
-        name: &str,
-        case: &str,
-        a: LHS,
-        b: RHS,
-        operation: impl FnOnce(LHS, RHS) -> OUT,
-        num_constants: usize,
-        num_public: usize,
-        num_private: usize,
-        num_constraints: usize,
-    ) {
-        Circuit::scope(name, || {
-            let _candidate = operation(a, b);
-            assert_scope_fails!(case, num_constants, num_public, num_private, num_constraints);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_operation_fails_without_counts<LHS, RHS, OUT>(
-        name: &str,
-        case: &str,
-        a: LHS,
-        b: RHS,
-        operation: impl FnOnce(LHS, RHS) -> OUT,
-    ) {
-        Circuit::scope(name, || {
-            let _candidate = operation(a, b);
-            assert!(!Circuit::is_satisfied(), "{} (!is_satisfied)", case);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_operation_halts<LHS: UnwindSafe, RHS: UnwindSafe, OUT>(
-        a: LHS,
-        b: RHS,
-        operation: impl FnOnce(LHS, RHS) -> OUT + UnwindSafe,
-    ) {
-        let result = std::panic::catch_unwind(|| operation(a, b));
-        assert!(result.is_err());
-    }
-
-    pub fn check_unary_operation_passes<V: Debug + Display + PartialEq, IN, OUT: Eject<Primitive = V>>(
-        name: &str,
-        case: &str,
-        expected: V,
-        input: IN,
-        operation: impl FnOnce(IN) -> OUT,
-        num_constants: usize,
-        num_public: usize,
-        num_private: usize,
-        num_constraints: usize,
-    ) {
-        Circuit::scope(name, || {
-            let candidate = operation(input);
-            assert_eq!(expected, candidate.eject_value(), "{}", case);
-            assert_scope!(case, num_constants, num_public, num_private, num_constraints);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_unary_operation_fails<IN, OUT>(
-        name: &str,
-        case: &str,
-        input: IN,
-        operation: impl FnOnce(IN) -> OUT,
-        num_constants: usize,
-        num_public: usize,
-        num_private: usize,
-        num_constraints: usize,
-    ) {
-        Circuit::scope(name, || {
-            let _candidate = operation(input);
-            assert_scope_fails!(case, num_constants, num_public, num_private, num_constraints);
-        });
-        Circuit::reset();
-    }
-
-    pub fn check_unary_operation_halts<IN: UnwindSafe, OUT>(input: IN, operation: impl FnOnce(IN) -> OUT + UnwindSafe) {
-        let result = std::panic::catch_unwind(|| operation(input));
-        assert!(result.is_err());
-    }
+    pub use snarkvm_circuits_environment::test_utilities::*;
 }
@@ -81,7 +81,7 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
             let (product, carry) = Self::mul_with_carry(&self.abs_wrapped(), &other.abs_wrapped(), true);
 
             // We need to check that the abs(a) * abs(b) did not exceed the unsigned maximum.
-            let carry_bits_nonzero = carry.iter().fold(Boolean::constant(false), |a, b| a | b);
+            let carry_bits_nonzero = Boolean::any(&carry);
 
             // If the product should be positive, then it cannot exceed the signed maximum.
             let operands_same_sign = &self.msb().is_equal(other.msb());
@@ -89,8 +89,7 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
 
             // If the product should be negative, then it cannot exceed the absolute value of the signed minimum.
             let negative_product_underflows = {
-                let lower_product_bits_nonzero =
-                    product.bits_le[..(I::BITS - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                let lower_product_bits_nonzero = Boolean::any(&product.bits_le[..(I::BITS - 1)]);
                 let negative_product_lt_or_eq_signed_min =
                     !product.msb() | (product.msb() & !lower_product_bits_nonzero);
                 !operands_same_sign & !negative_product_lt_or_eq_signed_min
@@ -98,7 +97,7 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
 
             // Ensure there are no overflows.
             let overflow = carry_bits_nonzero | positive_product_overflows | negative_product_underflows;
-            E::assert_eq(overflow, E::zero());
+            E::annotate(self.annotation(other, "multiplication", "overflow"), || E::assert_eq(overflow, E::zero()));
 
             // Return the product of `self` and `other` with the appropriate sign.
             Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product))
@@ -107,8 +106,8 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
             let (product, carry) = Self::mul_with_carry(self, other, true);
 
             // For unsigned multiplication, check that none of the carry bits are set.
-            let overflow = carry.iter().fold(Boolean::constant(false), |a, b| a | b);
-            E::assert_eq(overflow, E::zero());
+            let overflow = Boolean::any(&carry);
+            E::annotate(self.annotation(other, "multiplication", "overflow"), || E::assert_eq(overflow, E::zero()));
 
             // Return the product of `self` and `other`.
             product
@@ -634,6 +633,25 @@ mod tests {
         run_test::<I>(Mode::Private, Mode::Private, 0, 0, 192, 194);
     }
 
+    #[test]
+    fn test_u64_overflow_diagnostics() {
+        let a = Integer::<Circuit, u64>::new(Mode::Private, u64::MAX);
+        let b = Integer::<Circuit, u64>::new(Mode::Public, 2);
+        Circuit::scope("test_u64_overflow_diagnostics", || {
+            let _candidate = a.mul_checked(&b);
+        });
+
+        // Ensure the only unsatisfied constraint is the overflow check, and that it is annotated.
+        let diagnostics = Circuit::diagnostics();
+        assert_eq!(1, diagnostics.len());
+        assert_eq!("test_u64_overflow_diagnostics", diagnostics[0].scope());
+        let annotation = diagnostics[0].annotation().unwrap();
+        assert_eq!("u64 multiplication overflow", annotation.to_string());
+        assert_eq!(64, annotation.bit_width());
+        assert_eq!(&[Mode::Private, Mode::Public], annotation.modes());
+        Circuit::reset();
+    }
+
     // Tests for i64
 
     #[test]
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    ///
+    /// Returns `self` with its bits rotated left by `n` positions, where `n` is a constant.
+    ///
+    /// As the rotation is a permutation of the bits, this method costs 0 constraints.
+    ///
+    pub fn rotate_left(&self, n: u32) -> Self {
+        let mut bits_le = self.bits_le.clone();
+        bits_le.rotate_right(n as usize % I::BITS);
+        Self { bits_le, phantom: Default::default() }
+    }
+
+    ///
+    /// Returns `self` with its bits rotated right by `n` positions, where `n` is a constant.
+    ///
+    /// As the rotation is a permutation of the bits, this method costs 0 constraints.
+    ///
+    pub fn rotate_right(&self, n: u32) -> Self {
+        let mut bits_le = self.bits_le.clone();
+        bits_le.rotate_left(n as usize % I::BITS);
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 32;
+
+    fn check_rotate<I: IntegerType>(
+        mode: Mode,
+        native_rotate_left: impl Fn(I, u32) -> I,
+        native_rotate_right: impl Fn(I, u32) -> I,
+    ) {
+        for i in 0..ITERATIONS {
+            let value: I = UniformRand::rand(&mut test_rng());
+            let n = (i * 3) as u32;
+            let candidate = Integer::<Circuit, I>::new(mode, value);
+
+            Circuit::scope(format!("{mode} rotate {n}"), || {
+                assert_eq!(native_rotate_left(value, n), candidate.rotate_left(n).eject_value());
+                assert_eq!(native_rotate_right(value, n), candidate.rotate_right(n).eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_test<I: IntegerType>(
+        native_rotate_left: impl Fn(I, u32) -> I + Copy,
+        native_rotate_right: impl Fn(I, u32) -> I + Copy,
+    ) {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_rotate::<I>(mode, native_rotate_left, native_rotate_right);
+        }
+    }
+
+    #[test]
+    fn test_u8_rotate() {
+        run_test::<u8>(u8::rotate_left, u8::rotate_right);
+    }
+
+    #[test]
+    fn test_i8_rotate() {
+        run_test::<i8>(i8::rotate_left, i8::rotate_right);
+    }
+
+    #[test]
+    fn test_u32_rotate() {
+        run_test::<u32>(u32::rotate_left, u32::rotate_right);
+    }
+
+    #[test]
+    fn test_i32_rotate() {
+        run_test::<i32>(i32::rotate_left, i32::rotate_right);
+    }
+
+    #[test]
+    fn test_u64_rotate() {
+        run_test::<u64>(u64::rotate_left, u64::rotate_right);
+    }
+
+    #[test]
+    fn test_u128_rotate() {
+        run_test::<u128>(u128::rotate_left, u128::rotate_right);
+    }
+}
@@ -81,8 +81,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for
             // Therefore there is at least one trailing zero.
             let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
 
-            let upper_bits_are_nonzero =
-                rhs.bits_le[first_upper_bit_index..].iter().fold(Boolean::constant(false), |a, b| a | b);
+            let upper_bits_are_nonzero = Boolean::any(&rhs.bits_le[first_upper_bit_index..]);
 
             // Halt if upper bits of rhs are constant and nonzero.
             if upper_bits_are_nonzero.is_constant() && upper_bits_are_nonzero.eject_value() {
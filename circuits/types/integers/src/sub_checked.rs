@@ -88,7 +88,7 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
             };
 
             // Check for underflow.
-            match I::is_signed() {
+            E::annotate(self.annotation(other, "subtraction", "underflow"), || match I::is_signed() {
                 // For signed subtraction, overflow and underflow conditions are:
                 //   - a > 0 && b < 0 && a - b > 0 (Overflow)
                 //   - a < 0 && b > 0 && a - b < 0 (Underflow)
@@ -101,7 +101,7 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
                 }
                 // For unsigned subtraction, ensure the carry bit is one.
                 false => E::assert_eq(carry, E::one()),
-            }
+            });
 
             // Return the difference of `self` and `other`.
             difference
@@ -15,7 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use snarkvm_utilities::{FromBytes, ToBytes};
 
 impl<E: Environment> Add<Scalar<E>> for Scalar<E> {
     type Output = Scalar<E>;
@@ -82,13 +81,7 @@ impl<E: Environment> AddAssign<&Scalar<E>> for Scalar<E> {
             // Note: We are reconstituting the scalar field into a base field here in order to
             // compute the difference between the sum and modulus. This is safe as the scalar field modulus
             // is less that the base field modulus, and thus will always fit in a base field element.
-            let modulus = Field::constant(match E::ScalarField::modulus().to_bytes_le() {
-                Ok(modulus_bytes) => match E::BaseField::from_bytes_le(&modulus_bytes) {
-                    Ok(modulus) => modulus,
-                    Err(error) => E::halt(format!("Failed to load the scalar modulus as a constant: {error}")),
-                },
-                Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
-            });
+            let modulus = Scalar::<E>::modulus();
 
             // Determine the wrapping sum, by computing the difference between the sum and modulus, if `sum` < `modulus`.
             let wrapping_sum = Ternary::ternary(&sum.is_less_than(&modulus), &sum, &(&sum - &modulus));
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    ///
+    /// Returns `true` if the bits of `self` encode an integer less than the scalar field modulus.
+    ///
+    /// As the scalar field modulus is less than `2^251`, a 251-bit decomposition can alias
+    /// two distinct integers to the same scalar field element. This method detects the alias.
+    ///
+    pub fn is_canonical(&self) -> Boolean<E> {
+        // Initialize the scalar field modulus as a constant scalar, from its bits.
+        // Note: This scalar must never be ejected, as the modulus is not a scalar field element.
+        let modulus = Scalar {
+            bits_le: E::ScalarField::modulus()
+                .to_bits_le()
+                .into_iter()
+                .take(E::ScalarField::size_in_bits())
+                .map(Boolean::constant)
+                .collect(),
+        };
+
+        self.is_less_than(&modulus)
+    }
+
+    ///
+    /// Enforces that the bits of `self` encode an integer less than the scalar field modulus.
+    ///
+    /// This should be called on any scalar whose bits are provided externally (e.g. injected scalars),
+    /// and which is subsequently used in a context where its integer value matters (e.g. nonces or commitments).
+    ///
+    pub fn enforce_canonical(&self) {
+        let is_canonical = self.is_canonical();
+
+        // Ensure a constant `self` is canonical, as constant constraints are not checked.
+        if is_canonical.is_constant() && !is_canonical.eject_value() {
+            E::halt("The constant scalar is not less than the scalar field modulus")
+        }
+
+        E::assert(is_canonical);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the scalar with the given bits, which may not be canonical.
+    fn scalar_from_bits(mode: Mode, bits_le: Vec<bool>) -> Scalar<Circuit> {
+        Scalar { bits_le: Inject::new(mode, bits_le) }
+    }
+
+    /// Returns the bits of the scalar field modulus.
+    fn modulus_bits_le() -> Vec<bool> {
+        let size_in_bits = <Circuit as Environment>::ScalarField::size_in_bits();
+        <Circuit as Environment>::ScalarField::modulus().to_bits_le().into_iter().take(size_in_bits).collect()
+    }
+
+    fn check_canonical(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let value: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let candidate = Scalar::<Circuit>::new(mode, value);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                assert!(candidate.is_canonical().eject_value());
+                candidate.enforce_canonical();
+            });
+        }
+
+        // Ensure `modulus - 1` is canonical.
+        let mut bits_le = modulus_bits_le();
+        assert!(bits_le[0]);
+        bits_le[0] = false;
+        let candidate = scalar_from_bits(mode, bits_le);
+        assert!(candidate.is_canonical().eject_value());
+        candidate.enforce_canonical();
+
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_non_canonical(mode: Mode) {
+        let size_in_bits = <Circuit as Environment>::ScalarField::size_in_bits();
+
+        // Check the modulus, and the largest 251-bit integer.
+        for bits_le in [modulus_bits_le(), vec![true; size_in_bits]] {
+            let candidate = scalar_from_bits(mode, bits_le);
+            assert!(!candidate.is_canonical().eject_value());
+
+            candidate.enforce_canonical();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_canonical_constant() {
+        check_canonical(Mode::Constant);
+    }
+
+    #[test]
+    fn test_canonical_public() {
+        check_canonical(Mode::Public);
+    }
+
+    #[test]
+    fn test_canonical_private() {
+        check_canonical(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_canonical_constant() {
+        check_non_canonical(Mode::Constant);
+    }
+
+    #[test]
+    fn test_non_canonical_public() {
+        check_non_canonical(Mode::Public);
+    }
+
+    #[test]
+    fn test_non_canonical_private() {
+        check_non_canonical(Mode::Private);
+    }
+}
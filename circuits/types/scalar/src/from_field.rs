@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromField for Scalar<E> {
+    type Field = Field<E>;
+
+    ///
+    /// Casts a base field element into a scalar field element.
+    ///
+    /// This method enforces that the base field element is less than the scalar field modulus.
+    /// To derive a scalar from an arbitrary base field element (e.g. a hash output), take its lower
+    /// `E::ScalarField::size_in_data_bits()` bits with `Field::to_lower_bits_le` instead.
+    ///
+    fn from_field(field: &Self::Field) -> Self {
+        let size_in_bits = E::ScalarField::size_in_bits();
+
+        // Determine the variable mode.
+        if field.is_constant() {
+            // Ensure the upper bits of the base field element are zero.
+            let bits_le = field.eject_value().to_bits_le();
+            if bits_le[size_in_bits..].iter().any(|bit| *bit) {
+                E::halt("The constant base field element is not less than the scalar field modulus")
+            }
+
+            // Ensure the base field element is less than the scalar field modulus.
+            match <E::ScalarField as FBits>::from_bits_le(&bits_le[..size_in_bits]) {
+                Ok(scalar) => Scalar::constant(scalar),
+                Err(_) => E::halt("The constant base field element is not less than the scalar field modulus"),
+            }
+        } else {
+            // Extract the lower bits of the base field element, ensuring the upper bits are zero.
+            let output = Scalar { bits_le: field.to_lower_bits_le(size_in_bits) };
+
+            // Ensure the scalar is less than the scalar field modulus.
+            output.enforce_canonical();
+
+            output
+        }
+    }
+}
+
+impl<E: Environment> ToScalar<Scalar<E>> for Field<E> {
+    /// Casts a base field element into a scalar field element, enforcing it is less than the scalar field modulus.
+    fn to_scalar(&self) -> Scalar<E> {
+        Scalar::from_field(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_scalar(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random scalar, and cast it into a base field element.
+            let expected: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let field = Scalar::<Circuit>::new(mode, expected).to_field();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate: Scalar<Circuit> = field.to_scalar();
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode.is_constant(), candidate.is_constant());
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_to_scalar_fails(mode: Mode) {
+        let modulus = Scalar::<Circuit>::modulus().eject_value();
+        let one = <Circuit as Environment>::BaseField::one();
+
+        // Check the scalar field modulus, and the base field modulus minus one.
+        for value in [modulus, -one] {
+            let field = Field::<Circuit>::new(mode, value);
+            let _candidate: Scalar<Circuit> = field.to_scalar();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_scalar_constant() {
+        check_to_scalar(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_scalar_public() {
+        check_to_scalar(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_scalar_private() {
+        check_to_scalar(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_scalar_fails_constant() {
+        check_to_scalar_fails(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_scalar_fails_public() {
+        check_to_scalar_fails(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_scalar_fails_private() {
+        check_to_scalar_fails(Mode::Private);
+    }
+}
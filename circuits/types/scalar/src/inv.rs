@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Inv for Scalar<E> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        (&self).inv()
+    }
+}
+
+impl<E: Environment> Inv for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn inv(self) -> Self::Output {
+        let inverse: Scalar<E> = witness!(|self| match self.inverse() {
+            Some(inverse) => inverse,
+            None => E::halt("Failed to compute the inverse for a scalar field element"),
+        });
+
+        // Ensure self * self^(-1) == 1.
+        if !self.is_constant() {
+            E::assert_eq(self * &inverse, E::one());
+        }
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_inv(name: &str, mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let given: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let candidate = Scalar::<Circuit>::new(mode, given);
+
+            Circuit::scope(&format!("{} {}", name, i), || {
+                if let Some(expected) = given.inverse() {
+                    let candidate = candidate.inv();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode.is_constant(), candidate.is_constant());
+                }
+            });
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_inv() {
+        check_inv("Constant", Mode::Constant);
+        check_inv("Public", Mode::Public);
+        check_inv("Private", Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_zero() {
+        let zero = <Circuit as Environment>::ScalarField::zero();
+        let _ = Scalar::<Circuit>::new(Mode::Private, zero).inv();
+    }
+}
@@ -19,9 +19,15 @@
 
 pub mod add;
 pub mod compare;
+pub mod enforce_canonical;
 pub mod equal;
 pub mod from_bits;
+pub mod from_field;
+pub mod inv;
+pub mod mul;
+pub mod neg;
 pub mod one;
+pub mod sub;
 pub mod ternary;
 pub mod to_bits;
 pub mod to_field;
@@ -34,7 +40,7 @@ use snarkvm_circuits_environment::assert_scope;
 use snarkvm_circuits_environment::prelude::*;
 use snarkvm_circuits_types_boolean::Boolean;
 use snarkvm_circuits_types_field::Field;
-use snarkvm_utilities::{FromBits as FBits, ToBits as TBits};
+use snarkvm_utilities::{FromBits as FBits, FromBytes, ToBits as TBits, ToBytes};
 
 #[derive(Clone)]
 pub struct Scalar<E: Environment> {
@@ -80,14 +86,31 @@ impl<E: Environment> Eject for Scalar<E> {
     ///
     fn eject_value(&self) -> Self::Primitive {
         let bits = self.bits_le.eject_value();
-        let biginteger = <E::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits[..]);
-        match <E::ScalarField as PrimeField>::from_repr(biginteger) {
-            Some(scalar) => scalar,
-            None => E::halt("Failed to eject scalar field value"),
+        match <E::ScalarField as FBits>::from_bits_le(&bits[..]) {
+            Ok(scalar) => scalar,
+            Err(_) => E::halt("Failed to eject scalar field value"),
         }
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    ///
+    /// Returns the scalar field modulus as a constant base field element.
+    ///
+    /// Note: This is safe as the scalar field modulus is less than the base field modulus,
+    /// and thus will always fit in a base field element.
+    ///
+    pub(crate) fn modulus() -> Field<E> {
+        Field::constant(match E::ScalarField::modulus().to_bytes_le() {
+            Ok(modulus_bytes) => match E::BaseField::from_bytes_le(&modulus_bytes) {
+                Ok(modulus) => modulus,
+                Err(error) => E::halt(format!("Failed to load the scalar modulus as a constant: {error}")),
+            },
+            Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
+        })
+    }
+}
+
 impl<E: Environment> Parser for Scalar<E> {
     type Environment = E;
 
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits in each limb, for non-native multiplication.
+const LIMB_SIZE: usize = 84;
+/// The number of bits in each (offset) carry between limbs, for non-native multiplication.
+const CARRY_SIZE: usize = 88;
+
+impl<E: Environment> Mul<Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn mul(self, other: Scalar<E>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn mul(self, other: Scalar<E>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<&Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn mul(self, other: &Scalar<E>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment> Mul<&Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn mul(self, other: &Scalar<E>) -> Self::Output {
+        let mut result = self.clone();
+        result *= other;
+        result
+    }
+}
+
+impl<E: Environment> MulAssign<Scalar<E>> for Scalar<E> {
+    fn mul_assign(&mut self, other: Scalar<E>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment> MulAssign<&Scalar<E>> for Scalar<E> {
+    fn mul_assign(&mut self, other: &Scalar<E>) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and set the new constant in `self`.
+            *self = witness!(|self, other| self * other);
+        } else {
+            // Compute the product with non-native arithmetic on the base field.
+            *self = self.mul_non_native(other);
+        }
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    ///
+    /// Returns `self * other` on the scalar field, using non-native arithmetic on the base field.
+    ///
+    /// The product `c` and quotient `q` are witnessed, such that `a * b = q * r + c` over the integers,
+    /// where `r` is the scalar field modulus. As `a * b` exceeds the base field modulus, the equation
+    /// is checked on 84-bit limbs, for which the sum of limb products fits in the base field.
+    /// The carries between limbs are witnessed and range checked, which ensures the equation
+    /// holds over the integers, and not only modulo the base field modulus.
+    ///
+    fn mul_non_native(&self, other: &Scalar<E>) -> Scalar<E> {
+        // Initialize the scalar field modulus as a constant base field variable.
+        let modulus = Scalar::<E>::modulus();
+
+        // Witness the product `c`, and ensure it is less than the modulus.
+        let product: Scalar<E> = witness!(|self, other| self * other);
+        product.enforce_canonical();
+
+        // Witness the quotient `q`, and ensure it is less than `2^251`.
+        // Note: As `a * b - c` is a multiple of `r`, the division on the base field is exact.
+        let (a, b, c) = (self.to_field(), other.to_field(), product.to_field());
+        let quotient: Field<E> = witness!(|a, b, c, modulus| (a * b - c) / modulus);
+        let quotient_bits_le = quotient.to_lower_bits_le(E::ScalarField::size_in_bits());
+
+        // Decompose the operands, product, and quotient into limbs.
+        let to_limbs = |bits_le: &[Boolean<E>]| bits_le.chunks(LIMB_SIZE).map(Field::from_bits_le).collect::<Vec<_>>();
+        let a = to_limbs(&self.bits_le);
+        let b = to_limbs(&other.bits_le);
+        let c = to_limbs(&product.bits_le);
+        let q = to_limbs(&quotient_bits_le);
+
+        // Decompose the modulus into constant limbs.
+        let modulus_bits_le = E::ScalarField::modulus().to_bits_le();
+        let r = modulus_bits_le[..E::ScalarField::size_in_bits()]
+            .chunks(LIMB_SIZE)
+            .map(|chunk| {
+                Field::constant(chunk.iter().rev().fold(E::BaseField::zero(), |limb, bit| match bit {
+                    true => limb.double() + E::BaseField::one(),
+                    false => limb.double(),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the limbs of `a * b - q * r - c`, without carries.
+        let mut limbs = vec![Field::zero(); 2 * a.len() - 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                limbs[i + j] += &a[i] * &b[j];
+                limbs[i + j] -= &q[i] * &r[j];
+            }
+            limbs[i] -= &c[i];
+        }
+
+        // Initialize the limb base `2^LIMB_SIZE` and the carry offset `2^(CARRY_SIZE - 1)` as constants.
+        let power_of_two = |exponent: usize| (0..exponent).fold(E::BaseField::one(), |power, _| power.double());
+        let base = Field::constant(power_of_two(LIMB_SIZE));
+        let offset = Field::constant(power_of_two(CARRY_SIZE - 1));
+
+        // Propagate the carries, and ensure `a * b - q * r - c` is zero over the integers.
+        let mut carry = Field::zero();
+        for (index, limb) in limbs.iter().enumerate() {
+            let sum = limb + &carry;
+
+            match index == limbs.len() - 1 {
+                // Ensure the final sum is zero.
+                true => E::assert_eq(&sum, E::zero()),
+                false => {
+                    // Witness the carry (which may be negative), offset to be nonnegative, and range check it.
+                    let offset_carry: Field<E> = witness!(|sum, base, offset| sum / base + offset);
+                    offset_carry.to_lower_bits_le(CARRY_SIZE);
+
+                    // Ensure the sum is `carry * 2^LIMB_SIZE`.
+                    carry = offset_carry - &offset;
+                    E::assert_eq(&sum, &carry * &base);
+                }
+            }
+        }
+
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_mul(
+        name: &str,
+        first: <Circuit as Environment>::ScalarField,
+        second: <Circuit as Environment>::ScalarField,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+        let case = format!("({} * {})", a.eject_value(), b.eject_value());
+        let expected = first * second;
+
+        Circuit::scope(name, || {
+            let candidate = a * b;
+            assert_eq!(expected, candidate.eject_value(), "{}", case);
+            assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant(), "{}", case);
+        });
+        assert!(Circuit::is_satisfied(), "{}", case);
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first = UniformRand::rand(&mut test_rng());
+            let second = UniformRand::rand(&mut test_rng());
+
+            let name = format!("Mul: {} * {} {}", mode_a, mode_b, i);
+            check_mul(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Mul: {} * {} {} (commutative)", mode_a, mode_b, i);
+            check_mul(&name, second, first, mode_a, mode_b);
+        }
+
+        // Check the edge cases with zero, one, and negative one.
+        let zero = <Circuit as Environment>::ScalarField::zero();
+        let one = <Circuit as Environment>::ScalarField::one();
+        for first in [zero, one, -one] {
+            for second in [zero, one, -one] {
+                let name = format!("Mul: {} * {} ({} * {})", mode_a, mode_b, first, second);
+                check_mul(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_constant_times_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_constant_times_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_constant_times_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_public_times_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_private_times_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_public_times_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_public_times_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_private_times_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_private_times_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Neg for Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Returns the `negation` of `self`.
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<E: Environment> Neg for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Returns the `negation` of `self`.
+    fn neg(self) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() {
+            // Compute the negation as a constant.
+            witness!(|self| -self)
+        } else {
+            // Compute `modulus - self` on the base field, which lies in `[1, modulus]`.
+            // Note: This is safe as the base field is larger than the scalar field.
+            let field = self.to_field();
+            let difference = Scalar::<E>::modulus() - &field;
+
+            // If `self` is zero, the negation is zero (rather than the modulus).
+            let negation = Field::ternary(&field.is_equal(&Field::zero()), &Field::zero(), &difference);
+
+            // Extract the scalar field bits from the negation.
+            Scalar { bits_le: negation.to_lower_bits_le(E::ScalarField::size_in_bits()) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_neg(name: &str, expected: <Circuit as Environment>::ScalarField, candidate_input: Scalar<Circuit>) {
+        Circuit::scope(name, || {
+            let candidate_output = -candidate_input;
+            assert_eq!(expected, candidate_output.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let value: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let candidate = Scalar::<Circuit>::new(mode, value);
+            check_neg(&format!("Neg: {} {}", mode, i), -value, candidate);
+        }
+
+        // Check the negation of zero and one.
+        let zero = <Circuit as Environment>::ScalarField::zero();
+        check_neg(&format!("Neg: {} zero", mode), zero, Scalar::new(mode, zero));
+        let one = <Circuit as Environment>::ScalarField::one();
+        check_neg(&format!("Neg: {} one", mode), -one, Scalar::new(mode, one));
+    }
+
+    #[test]
+    fn test_neg_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_neg_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_neg_private() {
+        run_test(Mode::Private);
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Sub<Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: Scalar<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: Scalar<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: &Scalar<E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: &Scalar<E>) -> Self::Output {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<E: Environment> SubAssign<Scalar<E>> for Scalar<E> {
+    fn sub_assign(&mut self, other: Scalar<E>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment> SubAssign<&Scalar<E>> for Scalar<E> {
+    fn sub_assign(&mut self, other: &Scalar<E>) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and set the new constant in `self`.
+            *self = witness!(|self, other| self - other);
+        } else {
+            // Compute the difference as the sum of `self` and the negation of `other`.
+            *self += -other;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_sub(
+        name: &str,
+        first: <Circuit as Environment>::ScalarField,
+        second: <Circuit as Environment>::ScalarField,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+        let case = format!("({} - {})", a.eject_value(), b.eject_value());
+        let expected = first - second;
+
+        Circuit::scope(name, || {
+            let candidate = a - b;
+            assert_eq!(expected, candidate.eject_value(), "{}", case);
+            assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant(), "{}", case);
+        });
+        assert!(Circuit::is_satisfied(), "{}", case);
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first = UniformRand::rand(&mut test_rng());
+            let second = UniformRand::rand(&mut test_rng());
+
+            let name = format!("Sub: {} - {} {}", mode_a, mode_b, i);
+            check_sub(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Sub: {} - {} {} (reverse)", mode_a, mode_b, i);
+            check_sub(&name, second, first, mode_a, mode_b);
+
+            let name = format!("Sub: {} - {} {} (self)", mode_a, mode_b, i);
+            check_sub(&name, first, first, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -22,20 +22,26 @@ extern crate enum_index_derive;
 pub use snarkvm_circuits_environment as environment;
 pub use snarkvm_circuits_types_address as address;
 pub use snarkvm_circuits_types_boolean as boolean;
+pub use snarkvm_circuits_types_bytes as bytes;
+pub use snarkvm_circuits_types_emulated as emulated;
 pub use snarkvm_circuits_types_field as field;
 pub use snarkvm_circuits_types_group as group;
 pub use snarkvm_circuits_types_integers as integers;
 pub use snarkvm_circuits_types_scalar as scalar;
 pub use snarkvm_circuits_types_string as string;
+pub use snarkvm_circuits_types_vector as vector;
 
 pub use address::Address;
-pub use boolean::Boolean;
+pub use boolean::{Boolean, Mux};
+pub use bytes::Bytes;
+pub use emulated::EmulatedField;
 pub use environment::prelude::*;
 pub use field::Field;
 pub use group::Group;
 pub use integers::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
 pub use scalar::Scalar;
 pub use string::StringType;
+pub use vector::Vector;
 
 mod literal;
 pub use literal::*;
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Initializes a new string of the given mode, which holds at most `capacity` bytes.
+    ///
+    pub fn new_with_capacity(mode: Mode, string: String, capacity: u32) -> Self {
+        // Ensure the string is within the given capacity.
+        let num_bytes = string.len();
+        match num_bytes <= capacity as usize {
+            true => Self { mode, bytes: string.as_bytes().iter().map(|byte| U8::new(mode, *byte)).collect(), capacity },
+            false => E::halt(format!("Attempted to allocate a string of size {num_bytes} (capacity {capacity})")),
+        }
+    }
+
+    ///
+    /// Returns the maximum number of bytes in `self`.
+    ///
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    ///
+    /// Returns the bytes of `self`, padded with zero bytes up to the capacity of `self`.
+    ///
+    /// As the padding bytes are constant, this method costs 0 constraints.
+    ///
+    pub fn to_padded_bytes(&self) -> Vec<U8<E>> {
+        let mut bytes = self.bytes.clone();
+        bytes.resize(self.capacity as usize, U8::zero());
+        bytes
+    }
+
+    ///
+    /// Initializes a new string of `num_bytes` bytes from a list of bytes, as padded by `to_padded_bytes`.
+    /// The capacity of the string is the number of given bytes.
+    ///
+    /// Enforces that each of the padding bytes is zero.
+    ///
+    pub fn from_padded_bytes(bytes: &[U8<E>], num_bytes: usize) -> Self {
+        // Ensure the string is within the capacity.
+        let capacity = match u32::try_from(bytes.len()) {
+            Ok(capacity) if num_bytes <= bytes.len() => capacity,
+            _ => E::halt(format!("Attempted to unpad a string of size {num_bytes} from {} bytes", bytes.len())),
+        };
+
+        // Ensure the padding bytes are zero.
+        let padding_bits_le = bytes[num_bytes..].iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        match padding_bits_le.iter().all(|bit| bit.is_constant()) {
+            true => {
+                if padding_bits_le.iter().any(|bit| bit.eject_value()) {
+                    E::halt("Attempted to unpad a string with malformed padding")
+                }
+            }
+            false => {
+                // Note: As the padding bits are booleans, their sum is zero if and only if every bit is zero.
+                let padding = padding_bits_le.iter().fold(E::zero(), |padding, bit| padding + &**bit);
+                E::assert_eq(padding, E::zero());
+            }
+        }
+
+        Self { mode: bytes.eject_mode(), bytes: bytes[..num_bytes].to_vec(), capacity }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::{assert_scope_fails, Circuit};
+
+    const CAPACITY: u32 = 16;
+
+    fn check_padded_bytes(mode: Mode) {
+        for given in ["", "snarkVM", "snarkVM-ñ-✓", "0123456789abcdef"] {
+            let candidate = StringType::<Circuit>::new_with_capacity(mode, given.to_string(), CAPACITY);
+            assert_eq!(CAPACITY, candidate.capacity());
+
+            // Ensure the string is padded with zero bytes up to its capacity.
+            let padded_bytes = candidate.to_padded_bytes();
+            let mut expected = given.as_bytes().to_vec();
+            expected.resize(CAPACITY as usize, 0u8);
+            assert_eq!(expected, padded_bytes.eject_value());
+
+            // Note: As the padding bytes are constant, unpadding the bytes costs 0 constraints.
+            Circuit::scope(&format!("{} {}", mode, given), || {
+                let unpadded = StringType::from_padded_bytes(&padded_bytes, given.len());
+                assert_eq!(given, unpadded.eject_value());
+                assert_eq!(CAPACITY, unpadded.capacity());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_padded_bytes_constant() {
+        check_padded_bytes(Mode::Constant);
+    }
+
+    #[test]
+    fn test_padded_bytes_public() {
+        check_padded_bytes(Mode::Public);
+    }
+
+    #[test]
+    fn test_padded_bytes_private() {
+        check_padded_bytes(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_padded_bytes_malformed() {
+        let bytes = "snarkVM\0\0\0".bytes().map(|byte| U8::<Circuit>::new(Mode::Private, byte)).collect::<Vec<_>>();
+
+        // Ensure the padding is enforced to be zero.
+        Circuit::scope("malformed", || {
+            let _candidate = StringType::from_padded_bytes(&bytes, 6);
+            assert_scope_fails!(0, 0, 0, 1);
+        });
+        Circuit::reset();
+
+        // Ensure well-formed padding is accepted.
+        Circuit::scope("well-formed", || {
+            let candidate = StringType::from_padded_bytes(&bytes, 7);
+            assert_eq!("snarkVM", candidate.eject_value());
+            assert_scope!(0, 0, 0, 1);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_padded_bytes_malformed_constant() {
+        let bytes = "snarkVM".bytes().map(|byte| U8::<Circuit>::new(Mode::Constant, byte)).collect::<Vec<_>>();
+        let _candidate = StringType::from_padded_bytes(&bytes, 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_exceeds_capacity() {
+        let _candidate = StringType::<Circuit>::new_with_capacity(Mode::Private, "snarkVM".to_string(), 6);
+    }
+
+    #[test]
+    fn test_concat_capacity() {
+        let a = StringType::<Circuit>::new_with_capacity(Mode::Private, "snark".to_string(), 8);
+        let b = StringType::<Circuit>::new_with_capacity(Mode::Private, "VM".to_string(), 4);
+        let candidate = a.concat(&b);
+        assert_eq!("snarkVM", candidate.eject_value());
+        assert_eq!(8, candidate.capacity());
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Returns the concatenation of `self` and `other`.
+    ///
+    /// As the bytes of a string are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenated string is within the larger capacity of the two strings.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        let capacity = self.capacity.max(other.capacity);
+        if num_bytes > capacity as usize {
+            E::halt(format!("Attempted to concatenate into a string of size {num_bytes} (capacity {capacity})"))
+        }
+
+        // The mode of an empty operand is kept, so the output takes the larger mode of the two operands.
+        let mode = max_mode(self.eject_mode(), other.eject_mode());
+        let bytes = self.bytes.iter().chain(&other.bytes).cloned().collect::<Vec<_>>();
+
+        Self { mode, bytes, capacity }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode, expected_mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample two random strings. Take 1/8th to ensure we fit for all code points.
+            let first: String = (0..(Circuit::NUM_STRING_BYTES - i) / 8).map(|_| rng.gen::<char>()).collect();
+            let second: String = (0..1 + i % 8).map(|_| rng.gen::<char>()).collect();
+
+            let a = StringType::<Circuit>::new(mode_a, first.clone());
+            let b = StringType::<Circuit>::new(mode_b, second.clone());
+
+            Circuit::scope(&format!("{} {} {}", mode_a, mode_b, i), || {
+                let candidate = a.concat(&b);
+                assert_eq!(format!("{first}{second}"), candidate.eject_value());
+                assert_eq!(expected_mode, candidate.eject_mode());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_concat_empty(mode_a: Mode, mode_b: Mode, expected_mode: Mode) {
+        let rng = &mut test_rng();
+
+        // Sample a random string.
+        let given: String = (0..8).map(|_| rng.gen::<char>()).collect();
+
+        // Check an empty left operand, an empty right operand, and two empty operands.
+        let cases = [(String::new(), given.clone()), (given, String::new()), (String::new(), String::new())];
+        for (first, second) in cases {
+            let a = StringType::<Circuit>::new(mode_a, first.clone());
+            let b = StringType::<Circuit>::new(mode_b, second.clone());
+
+            Circuit::scope(&format!("{} {} ({:?}, {:?})", mode_a, mode_b, first, second), || {
+                let candidate = a.concat(&b);
+                assert_eq!(format!("{first}{second}"), candidate.eject_value());
+                assert_eq!(expected_mode, candidate.eject_mode());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_concat_constant() {
+        check_concat(Mode::Constant, Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_concat_public() {
+        check_concat(Mode::Public, Mode::Public, Mode::Public);
+        check_concat(Mode::Constant, Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_concat_private() {
+        check_concat(Mode::Private, Mode::Private, Mode::Private);
+        check_concat(Mode::Public, Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_concat_empty() {
+        let a = StringType::<Circuit>::new(Mode::Private, String::new());
+        let b = StringType::<Circuit>::new(Mode::Private, String::new());
+        let candidate = a.concat(&b);
+        assert_eq!("", candidate.eject_value());
+        assert_eq!(Mode::Private, candidate.eject_mode());
+    }
+
+    #[test]
+    fn test_concat_empty_constant() {
+        check_concat_empty(Mode::Constant, Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_concat_empty_public() {
+        check_concat_empty(Mode::Constant, Mode::Public, Mode::Public);
+        check_concat_empty(Mode::Public, Mode::Constant, Mode::Public);
+        check_concat_empty(Mode::Public, Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_concat_empty_private() {
+        check_concat_empty(Mode::Constant, Mode::Private, Mode::Private);
+        check_concat_empty(Mode::Private, Mode::Constant, Mode::Private);
+        check_concat_empty(Mode::Public, Mode::Private, Mode::Private);
+        check_concat_empty(Mode::Private, Mode::Public, Mode::Private);
+        check_concat_empty(Mode::Private, Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_exceeds_capacity() {
+        let half = "a".repeat(Circuit::NUM_STRING_BYTES as usize / 2 + 1);
+        let a = StringType::<Circuit>::new(Mode::Private, half.clone());
+        let b = StringType::<Circuit>::new(Mode::Private, half);
+        let _candidate = a.concat(&b);
+    }
+}
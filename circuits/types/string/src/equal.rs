@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Equal<Self> for StringType<E> {
+    type Boolean = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// As the length of a string is fixed at circuit synthesis, strings of differing lengths are
+    /// never equal, even if one is the other padded with zero bytes up to its capacity.
+    /// Strings of equal length are packed into field elements, which are compared pairwise.
+    ///
+    fn is_equal(&self, other: &Self) -> Self::Boolean {
+        // Determine if this operation is constant or variable.
+        if self.bytes.len() != other.bytes.len() {
+            Boolean::constant(false)
+        } else if self.is_constant() && other.is_constant() {
+            Boolean::constant(self.eject_value() == other.eject_value())
+        } else {
+            // Note: As the strings are of equal length, packing them into field elements is injective.
+            let fields_are_equal = self
+                .to_fields()
+                .iter()
+                .zip_eq(other.to_fields().iter())
+                .map(|(this, that)| this.is_equal(that))
+                .collect::<Vec<_>>();
+            Boolean::all(&fields_are_equal)
+        }
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    fn is_not_equal(&self, other: &Self) -> Self::Boolean {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_is_equal(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string of at most 31 bytes, to fit in one field element.
+            let first: String = (0..(1 + i % 7)).map(|_| rng.gen::<char>()).collect();
+            // Sample a second string of the same length, which is equal to the first half the time.
+            let second: String = match i % 2 == 0 {
+                true => first.clone(),
+                false => first.chars().rev().collect(),
+            };
+
+            let a = StringType::<Circuit>::new(mode_a, first.clone());
+            let b = StringType::<Circuit>::new(mode_b, second.clone());
+
+            Circuit::scope(&format!("{} {} {}", mode_a, mode_b, i), || {
+                let candidate = a.is_equal(&b);
+                assert_eq!(first == second, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::scope(&format!("{} {} {} (not equal)", mode_a, mode_b, i), || {
+                let candidate = a.is_not_equal(&b);
+                assert_eq!(first != second, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_is_equal_constant() {
+        check_is_equal(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_is_equal_public() {
+        check_is_equal(Mode::Constant, Mode::Public, 0, 0, 2, 3);
+    }
+
+    #[test]
+    fn test_public_is_equal_private() {
+        check_is_equal(Mode::Public, Mode::Private, 0, 0, 2, 3);
+    }
+
+    #[test]
+    fn test_private_is_equal_private() {
+        check_is_equal(Mode::Private, Mode::Private, 0, 0, 2, 3);
+    }
+
+    #[test]
+    fn test_is_equal_long_strings() {
+        // Sample strings that span multiple field elements.
+        let first = "a".repeat(Circuit::NUM_STRING_BYTES as usize);
+        let mut second = first.clone();
+        second.replace_range(100..101, "b");
+
+        let a = StringType::<Circuit>::new(Mode::Private, first.clone());
+        let b = StringType::<Circuit>::new(Mode::Private, second);
+        assert!(a.is_equal(&a).eject_value());
+        assert!(!a.is_equal(&b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal_padding() {
+        // A zero-padded string is not equal to the original string.
+        let a = StringType::<Circuit>::new(Mode::Private, "aleo".to_string());
+        let b = StringType::<Circuit>::new(Mode::Private, "aleo\0".to_string());
+
+        Circuit::scope("padding", || {
+            let candidate = a.is_equal(&b);
+            assert!(!candidate.eject_value());
+            assert!(candidate.is_constant());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuits_types_field::Field;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Initializes a new string of `num_bytes` bytes from a list of base fields, as packed by `to_fields`.
+    ///
+    /// Enforces that each field element fits in a chunk, and that the canonical padding
+    /// (a `1u8` terminator byte followed by zero bytes) is well-formed.
+    ///
+    /// An empty list of field elements unpacks into the empty constant string.
+    ///
+    pub fn from_fields(fields: &[Field<E>], num_bytes: usize) -> Self {
+        // Ensure the string is within the allowed capacity.
+        if num_bytes > E::NUM_STRING_BYTES as usize {
+            E::halt(format!("Attempted to unpack a string of size {num_bytes}"))
+        }
+
+        // If there are no field elements, return the empty string.
+        if fields.is_empty() {
+            match num_bytes == 0 {
+                true => return Self { mode: Mode::Constant, bytes: Vec::new(), capacity: E::NUM_STRING_BYTES },
+                false => E::halt(format!("Expected field elements for a string of size {num_bytes}, found none")),
+            }
+        }
+
+        // Compute the number of data bits in each chunk, aligned to bytes.
+        let num_data_bits = (E::BaseField::size_in_data_bits() / 8) * 8;
+
+        // Ensure the number of field elements matches the number of bytes.
+        let num_chunks = ((num_bytes + 1) * 8 + num_data_bits - 1) / num_data_bits;
+        if fields.len() != num_chunks {
+            E::halt(format!(
+                "Expected {num_chunks} field elements for a string of size {num_bytes}, found {}",
+                fields.len()
+            ))
+        }
+
+        // Unpack the bits of each field element.
+        let bits_le = fields.iter().flat_map(|field| field.to_lower_bits_le(num_data_bits)).collect::<Vec<_>>();
+        let (bits_le, padding_bits_le) = bits_le.split_at(num_bytes * 8);
+
+        // Ensure the padding is canonical, i.e. a `1u8` terminator byte followed by zero bytes.
+        match padding_bits_le.iter().all(|bit| bit.is_constant()) {
+            true => {
+                if padding_bits_le.iter().enumerate().any(|(index, bit)| bit.eject_value() != (index == 0)) {
+                    E::halt("Attempted to unpack a string with malformed padding")
+                }
+            }
+            false => {
+                // Note: As the padding bits are booleans, `(1 - b_0) + b_1 + ... + b_n` is zero
+                // if and only if `b_0` is one and the remaining bits are zero.
+                let padding = padding_bits_le
+                    .iter()
+                    .skip(1)
+                    .fold(E::one() - &*padding_bits_le[0], |padding, bit| padding + &**bit);
+                E::assert_eq(padding, E::zero());
+            }
+        }
+
+        Self {
+            mode: fields.eject_mode(),
+            bytes: bits_le.chunks(8).map(U8::from_bits_le).collect(),
+            capacity: E::NUM_STRING_BYTES,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_from_fields(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string that fits in one field element.
+            let expected: String = (0..(i % 7)).map(|_| rng.gen::<char>()).collect();
+            let fields = StringType::<Circuit>::new(mode, expected.clone()).to_fields();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = StringType::<Circuit>::from_fields(&fields, expected.len());
+                assert_eq!(expected, candidate.eject_value());
+                match expected.is_empty() {
+                    // The empty string is packed into the padding alone, which is constant.
+                    true => {
+                        assert_eq!(Mode::Constant, candidate.eject_mode());
+                        assert_scope!(248, 0, 0, 0);
+                    }
+                    false => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_fields_constant() {
+        check_from_fields(Mode::Constant, 248, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_fields_public() {
+        check_from_fields(Mode::Public, 0, 0, 248, 250);
+    }
+
+    #[test]
+    fn test_from_fields_private() {
+        check_from_fields(Mode::Private, 0, 0, 248, 250);
+    }
+
+    #[test]
+    fn test_from_fields_empty() {
+        Circuit::scope("empty", || {
+            let candidate = StringType::<Circuit>::from_fields(&[], 0);
+            assert_eq!("", candidate.eject_value());
+            assert_eq!(Mode::Constant, candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fields_empty_with_bytes() {
+        let _candidate = StringType::<Circuit>::from_fields(&[], 1);
+    }
+
+    #[test]
+    fn test_from_fields_round_trip_long() {
+        let rng = &mut test_rng();
+
+        // Sample a random string that spans multiple field elements.
+        let expected: String = (0..Circuit::NUM_STRING_BYTES / 4).map(|_| rng.gen::<char>()).collect();
+        let fields = StringType::<Circuit>::new(Mode::Private, expected.clone()).to_fields();
+
+        let candidate = StringType::<Circuit>::from_fields(&fields, expected.len());
+        assert_eq!(expected, candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_fields_malformed_padding() {
+        // Unpacking with a shorter length leaves the final byte in the padding.
+        let fields = StringType::<Circuit>::new(Mode::Private, "aleo".to_string()).to_fields();
+
+        let _candidate = StringType::<Circuit>::from_fields(&fields, 3);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fields_malformed_padding_constant() {
+        let fields = StringType::<Circuit>::new(Mode::Constant, "aleo".to_string()).to_fields();
+        let _candidate = StringType::<Circuit>::from_fields(&fields, 3);
+    }
+}
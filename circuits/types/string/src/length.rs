@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Returns the number of bytes in `self`.
+    ///
+    /// As the bytes of a string are fixed at circuit synthesis, the length is a constant.
+    ///
+    pub fn length(&self) -> U32<E> {
+        U32::constant(self.bytes.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_length(mode: Mode) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let given: String = (0..(Circuit::NUM_STRING_BYTES - i) / 4).map(|_| rng.gen::<char>()).collect();
+            let candidate = StringType::<Circuit>::new(mode, given.clone());
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let length = candidate.length();
+                assert_eq!(given.len() as u32, length.eject_value());
+                assert!(length.is_constant());
+                assert_scope!(32, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_length_constant() {
+        check_length(Mode::Constant);
+    }
+
+    #[test]
+    fn test_length_public() {
+        check_length(Mode::Public);
+    }
+
+    #[test]
+    fn test_length_private() {
+        check_length(Mode::Private);
+    }
+}
@@ -16,21 +16,29 @@
 
 #![forbid(unsafe_code)]
 
+pub mod capacity;
+pub mod concat;
+pub mod equal;
 pub mod from_bits;
+pub mod from_fields;
+pub mod length;
+pub mod substring;
 pub mod to_bits;
 pub mod to_fields;
+pub mod utf8;
 
 #[cfg(test)]
 use snarkvm_circuits_environment::assert_scope;
 
 use snarkvm_circuits_environment::{prelude::*, string_parser::parse_string};
 use snarkvm_circuits_types_boolean::Boolean;
-use snarkvm_circuits_types_integers::U8;
+use snarkvm_circuits_types_integers::{U32, U8};
 
 #[derive(Clone)]
 pub struct StringType<E: Environment> {
     mode: Mode,
     bytes: Vec<U8<E>>,
+    capacity: u32,
 }
 
 impl<E: Environment> StringTrait for StringType<E> {}
@@ -38,14 +46,9 @@ impl<E: Environment> StringTrait for StringType<E> {}
 impl<E: Environment> Inject for StringType<E> {
     type Primitive = String;
 
-    /// Initializes a new instance of a string.
+    /// Initializes a new instance of a string, with a capacity of `E::NUM_STRING_BYTES` bytes.
     fn new(mode: Mode, string: String) -> Self {
-        // Ensure the string is within the allowed capacity.
-        let num_bytes = string.len();
-        match num_bytes <= E::NUM_STRING_BYTES as usize {
-            true => Self { mode, bytes: string.as_bytes().iter().map(|byte| U8::new(mode, *byte)).collect() },
-            false => E::halt(format!("Attempted to allocate a string of size {num_bytes}")),
-        }
+        Self::new_with_capacity(mode, string, E::NUM_STRING_BYTES)
     }
 }
 
@@ -58,7 +61,7 @@ impl<E: Environment> Eject for StringType<E> {
     fn eject_mode(&self) -> Mode {
         match self.bytes.is_empty() {
             true => self.mode,
-            false => self.bytes.eject_mode(),
+            false => max_mode(self.mode, self.bytes.eject_mode()),
         }
     }
 
@@ -66,9 +69,9 @@ impl<E: Environment> Eject for StringType<E> {
     /// Ejects the string as a string literal.
     ///
     fn eject_value(&self) -> Self::Primitive {
-        // Ensure the string is within the allowed capacity.
+        // Ensure the string is within its capacity.
         let num_bytes = self.bytes.len();
-        match num_bytes <= E::NUM_STRING_BYTES as usize {
+        match num_bytes <= self.capacity as usize {
             true => String::from_utf8(self.bytes.eject_value())
                 .unwrap_or_else(|error| E::halt(&format!("Failed to eject a string value: {error}"))),
             false => E::halt(format!("Attempted to eject a string of size {num_bytes}")),
@@ -76,6 +79,15 @@ impl<E: Environment> Eject for StringType<E> {
     }
 }
 
+/// Returns the more restrictive of the two given modes, ordered as `Constant < Public < Private`.
+fn max_mode(first: Mode, second: Mode) -> Mode {
+    match (first, second) {
+        (Mode::Private, _) | (_, Mode::Private) => Mode::Private,
+        (Mode::Public, _) | (_, Mode::Public) => Mode::Public,
+        (Mode::Constant, Mode::Constant) => Mode::Constant,
+    }
+}
+
 impl<E: Environment> Parser for StringType<E> {
     type Environment = E;
 
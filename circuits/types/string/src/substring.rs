@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Returns the substring of `self` with `length` bytes, starting at the byte index `start`.
+    ///
+    /// The substring must begin and end on a UTF-8 character boundary.
+    /// As the bytes of a string are fixed at circuit synthesis, this method costs 0 constraints.
+    ///
+    pub fn substring(&self, start: usize, length: usize) -> Self {
+        // Ensure the substring is within the bounds of the string.
+        let end = match start.checked_add(length) {
+            Some(end) if end <= self.bytes.len() => end,
+            _ => E::halt(format!(
+                "Attempted to take {length} bytes at {start} from a string of size {}",
+                self.bytes.len()
+            )),
+        };
+
+        // Ensure the substring lies on character boundaries.
+        let string = self.eject_value();
+        if !string.is_char_boundary(start) || !string.is_char_boundary(end) {
+            E::halt(format!("Attempted to take a substring at {start}..{end}, which is not on a character boundary"))
+        }
+
+        Self { mode: self.mode, bytes: self.bytes[start..end].to_vec(), capacity: self.capacity }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
+    fn check_substring(mode: Mode) {
+        let given = "snarkVM-ñ-✓";
+        let candidate = StringType::<Circuit>::new(mode, given.to_string());
+
+        for start in 0..=given.len() {
+            for end in start..=given.len() {
+                if !given.is_char_boundary(start) || !given.is_char_boundary(end) {
+                    continue;
+                }
+
+                Circuit::scope(&format!("{} {} {}", mode, start, end), || {
+                    let substring = candidate.substring(start, end - start);
+                    assert_eq!(&given[start..end], substring.eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_substring_constant() {
+        check_substring(Mode::Constant);
+    }
+
+    #[test]
+    fn test_substring_public() {
+        check_substring(Mode::Public);
+    }
+
+    #[test]
+    fn test_substring_private() {
+        check_substring(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_substring_out_of_bounds() {
+        let candidate = StringType::<Circuit>::new(Mode::Private, "snarkVM".to_string());
+        let _substring = candidate.substring(5, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_substring_not_on_char_boundary() {
+        let candidate = StringType::<Circuit>::new(Mode::Private, "ñ".to_string());
+        let _substring = candidate.substring(0, 1);
+    }
+}
@@ -20,12 +20,27 @@ use snarkvm_circuits_types_field::Field;
 impl<E: Environment> ToFields for StringType<E> {
     type Field = Field<E>;
 
+    ///
     /// Casts a string into a list of base fields.
+    ///
+    /// The bytes are packed into chunks of `E::BaseField::size_in_data_bits() / 8` bytes each, after
+    /// appending the canonical padding: a `1u8` terminator byte, followed by zero bytes up to a multiple
+    /// of the chunk size. This matches `snarkvm_utilities::pack_bytes_le`, and costs 0 constraints.
+    ///
     fn to_fields(&self) -> Vec<Self::Field> {
-        // Convert the string bytes into bits, then chunk them into lists of size
-        // `E::BaseField::size_in_data_bits()` and recover the base field element for each chunk.
-        // (For advanced users: Chunk into CAPACITY bits and create a linear combination per chunk.)
-        self.to_bits_le().chunks(E::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+        // Compute the number of data bits in each chunk, aligned to bytes.
+        let num_data_bits = (E::BaseField::size_in_data_bits() / 8) * 8;
+
+        // Compute the number of chunks needed for the bytes and the terminator byte.
+        let num_chunks = ((self.bytes.len() + 1) * 8 + num_data_bits - 1) / num_data_bits;
+
+        // Append the terminator byte, then pad with zeros up to a multiple of the chunk size.
+        let mut bits_le = self.to_bits_le();
+        bits_le.push(Boolean::constant(true));
+        bits_le.resize(num_chunks * num_data_bits, Boolean::constant(false));
+
+        // Recover the base field element for each chunk.
+        bits_le.chunks(num_data_bits).map(Field::from_bits_le).collect()
     }
 }
 
@@ -33,23 +48,16 @@ impl<E: Environment> ToFields for StringType<E> {
 mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
-    use snarkvm_utilities::{bytes_from_bits_le, test_rng, FromBytes, ToBits as TBits};
+    use snarkvm_utilities::{pack_bytes_le, test_rng, FromBytes};
 
     use rand::Rng;
 
     fn native_string_to_fields(string: &str) -> Vec<<Circuit as Environment>::BaseField> {
-        string
-            .as_bytes()
-            .to_bits_le()
-            .chunks(<Circuit as Environment>::BaseField::size_in_data_bits())
-            .map(|chunk| {
-                // Resize the list to match the field size in bits.
-                let mut chunk = chunk.to_vec();
-                chunk.resize(<Circuit as Environment>::BaseField::size_in_bits(), false);
-                // Recover the field element.
-                let chunk = bytes_from_bits_le(&chunk);
-                <Circuit as Environment>::BaseField::from_bytes_le(&chunk).unwrap()
-            })
+        let num_data_bytes = <Circuit as Environment>::BaseField::size_in_data_bits() / 8;
+        let num_bytes = (<Circuit as Environment>::BaseField::size_in_bits() + 7) / 8;
+        pack_bytes_le(string.as_bytes(), num_data_bytes, num_bytes)
+            .iter()
+            .map(|chunk| <Circuit as Environment>::BaseField::from_bytes_le(chunk).unwrap())
             .collect()
     }
 
@@ -111,4 +119,16 @@ mod tests {
         let candidate = StringType::<Circuit>::new(Mode::Private, given);
         check_to_fields("Private", &expected, &candidate, 0, 0, 0, 0);
     }
+
+    #[test]
+    fn test_to_fields_padding() {
+        // A string and its zero-padded counterpart are packed into distinct field elements.
+        let a = StringType::<Circuit>::new(Mode::Private, "aleo".to_string()).to_fields();
+        let b = StringType::<Circuit>::new(Mode::Private, "aleo\0".to_string()).to_fields();
+        assert_eq!(a.len(), b.len());
+        assert_ne!(a.eject_value(), b.eject_value());
+        assert_eq!(native_string_to_fields("aleo"), a.eject_value());
+        assert_eq!(native_string_to_fields("aleo\0"), b.eject_value());
+        Circuit::reset();
+    }
 }
@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    ///
+    /// Returns `true` if the bytes of `self` are a well-formed UTF-8 encoding, as defined in RFC 3629.
+    ///
+    /// The bytes are checked by a finite automaton, which rejects truncated characters, overlong encodings,
+    /// surrogates (U+D800 to U+DFFF), and code points above U+10FFFF.
+    ///
+    pub fn is_valid_utf8(&self) -> Boolean<E> {
+        // If the string is constant, check the bytes directly.
+        if self.is_constant() {
+            return Boolean::constant(std::str::from_utf8(&self.bytes.eject_value()).is_ok());
+        }
+
+        // The states of the automaton, where at most one state is set at any time:
+        //   - `accept` is set if the bytes so far are a sequence of complete characters.
+        //   - `remaining[i]` is set if the current character requires `i + 1` more continuation bytes.
+        //   - `after_e0`, `after_ed`, `after_f0`, and `after_f4` are set if the previous byte is the given
+        //     leading byte, for which the range of the first continuation byte is restricted.
+        // Note: If no state is set, the bytes are malformed, and no state is set for the remaining bytes.
+        let mut accept = Boolean::constant(true);
+        let mut remaining = [Boolean::constant(false), Boolean::constant(false), Boolean::constant(false)];
+        let mut after_e0 = Boolean::constant(false);
+        let mut after_ed = Boolean::constant(false);
+        let mut after_f0 = Boolean::constant(false);
+        let mut after_f4 = Boolean::constant(false);
+
+        for byte in &self.bytes {
+            let bits = byte.to_bits_le();
+
+            // Classify the byte by its leading bits.
+            let is_ascii = !&bits[7];
+            let is_continuation = &bits[7] & !&bits[6];
+            let is_two_byte_leader = &bits[7] & &bits[6] & !&bits[5];
+            let is_three_byte_leader = &bits[7] & &bits[6] & &bits[5] & !&bits[4];
+            let is_four_byte_leader = &bits[7] & &bits[6] & &bits[5] & &bits[4] & !&bits[3];
+
+            // Classify the leading bytes of the characters, excluding 0xC0, 0xC1, and 0xF5 to 0xFF, which never appear.
+            let is_c2_to_df = &is_two_byte_leader & Boolean::any(&bits[1..5]);
+            let is_e0 = &is_three_byte_leader & !Boolean::any(&bits[..4]);
+            let is_ed = &is_three_byte_leader & &bits[3] & &bits[2] & !&bits[1] & &bits[0];
+            let is_e1_to_ef = &is_three_byte_leader & !&is_e0 & !&is_ed;
+            let is_f0 = &is_four_byte_leader & !Boolean::any(&bits[..3]);
+            let is_f1_to_f3 = &is_four_byte_leader & !&bits[2] & (&bits[1] | &bits[0]);
+            let is_f4 = &is_four_byte_leader & &bits[2] & !&bits[1] & !&bits[0];
+
+            // Classify the continuation bytes in the restricted ranges.
+            let is_a0_to_bf = &is_continuation & &bits[5];
+            let is_80_to_9f = &is_continuation & !&bits[5];
+            let is_90_to_bf = &is_continuation & (&bits[5] | &bits[4]);
+            let is_80_to_8f = &is_continuation & !&bits[5] & !&bits[4];
+
+            // Transition to the next state.
+            let next_accept = (&accept & &is_ascii) | (&remaining[0] & &is_continuation);
+            let next_remaining = [
+                (&accept & &is_c2_to_df)
+                    | (&remaining[1] & &is_continuation)
+                    | (&after_e0 & &is_a0_to_bf)
+                    | (&after_ed & &is_80_to_9f),
+                (&accept & &is_e1_to_ef)
+                    | (&remaining[2] & &is_continuation)
+                    | (&after_f0 & &is_90_to_bf)
+                    | (&after_f4 & &is_80_to_8f),
+                &accept & &is_f1_to_f3,
+            ];
+            after_e0 = &accept & &is_e0;
+            after_ed = &accept & &is_ed;
+            after_f0 = &accept & &is_f0;
+            after_f4 = &accept & &is_f4;
+            accept = next_accept;
+            remaining = next_remaining;
+        }
+
+        // Ensure the last character is complete.
+        accept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns a string of the given bytes, which need not be well-formed UTF-8.
+    fn string_from_bytes(mode: Mode, bytes: &[u8]) -> StringType<Circuit> {
+        let bits_le = bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        StringType::from_bits_le(&bits_le.into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>())
+    }
+
+    fn check_is_valid_utf8(mode: Mode, bytes: &[u8]) {
+        let expected = std::str::from_utf8(bytes).is_ok();
+        let candidate = string_from_bytes(mode, bytes);
+
+        Circuit::scope(&format!("{} {:?}", mode, bytes), || {
+            let is_valid = candidate.is_valid_utf8();
+            assert_eq!(expected, is_valid.eject_value(), "{:?}", bytes);
+            match mode.is_constant() {
+                true => assert_scope!(0, 0, 0, 0),
+                false => assert_scope!(),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        // Check the boundaries of each range of leading and continuation bytes.
+        let corner_cases: [&[u8]; 24] = [
+            b"",
+            b"snarkVM",
+            "snarkVM-\u{7ff}-\u{ffff}-\u{10000}-\u{10ffff}".as_bytes(),
+            &[0x7f],
+            &[0x80],
+            &[0xbf],
+            &[0xc0, 0x80],
+            &[0xc1, 0xbf],
+            &[0xc2, 0x80],
+            &[0xdf, 0xbf],
+            &[0xe0, 0x80, 0x80],
+            &[0xe0, 0xa0, 0x80],
+            &[0xed, 0x9f, 0xbf],
+            &[0xed, 0xa0, 0x80],
+            &[0xee, 0x80, 0x80],
+            &[0xef, 0xbf, 0xbf],
+            &[0xe2, 0x9c],
+            &[0xf0, 0x8f, 0xbf, 0xbf],
+            &[0xf0, 0x90, 0x80, 0x80],
+            &[0xf3, 0xbf, 0xbf, 0xbf],
+            &[0xf4, 0x8f, 0xbf, 0xbf],
+            &[0xf4, 0x90, 0x80, 0x80],
+            &[0xf5, 0x80, 0x80, 0x80],
+            &[0xff],
+        ];
+        for bytes in corner_cases {
+            check_is_valid_utf8(mode, bytes);
+        }
+
+        let rng = &mut test_rng();
+        for _ in 0..ITERATIONS {
+            // Check a random sequence of bytes, which is likely malformed.
+            let bytes = (0..rng.gen_range(0..8)).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+            check_is_valid_utf8(mode, &bytes);
+
+            // Check a random string, which is well-formed.
+            let string = (0..rng.gen_range(0..8)).map(|_| rng.gen::<char>()).collect::<String>();
+            check_is_valid_utf8(mode, string.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_is_valid_utf8_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_is_valid_utf8_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_is_valid_utf8_private() {
+        run_test(Mode::Private);
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, T> Vector<E, T>
+where
+    T: Clone + Ternary<Boolean = Boolean<E>, Output = T>,
+{
+    ///
+    /// Returns the element at `index`, and enforces that `index` is less than the length of `self`.
+    ///
+    /// A constant index selects the element directly. Otherwise, as the index is less than the capacity,
+    /// the element is selected with a multiplexer over the lower `k = ceil(log2(capacity))` bits of the index,
+    /// which costs `2^k - 1` ternaries.
+    ///
+    pub fn get(&self, index: &U32<E>) -> T {
+        // Ensure the index is within the bounds of the vector.
+        self.assert_in_bounds(index);
+
+        match index.is_constant() {
+            true => match self.elements.get(index.eject_value() as usize) {
+                Some(element) => element.clone(),
+                None => E::halt(format!("Attempted to get index {index} of a vector of capacity {}", self.capacity())),
+            },
+            false => {
+                let last = match self.elements.last() {
+                    Some(last) => last,
+                    None => E::halt("Attempted to get an element of a vector with no capacity"),
+                };
+
+                // Pad the elements to a power of two, as the padding is never selected by an in-bounds index.
+                let num_bits = (usize::BITS - (self.capacity() - 1).leading_zeros()) as usize;
+                let mut options = self.elements.clone();
+                options.resize(1 << num_bits, last.clone());
+
+                Mux::select(&index.to_bits_le()[..num_bits], &options)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_circuits_types_integers::U8;
+
+    fn check_get(element_mode: Mode, index_mode: Mode, length_mode: Mode, capacity: u32, length: u32) {
+        let values = (0..capacity).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+
+        for index in 0..(capacity + 2) {
+            let elements = values.iter().map(|value| U8::<Circuit>::new(element_mode, *value)).collect();
+            let vector = Vector::from_elements_with_length(elements, U32::new(length_mode, length));
+            let candidate_index = U32::new(index_mode, index);
+
+            if index < length {
+                let candidate = vector.get(&candidate_index);
+                assert_eq!(values[index as usize], candidate.eject_value());
+                assert!(Circuit::is_satisfied(), "get({index}) of length {length} is unsatisfied");
+            } else if index_mode.is_constant() && (length_mode.is_constant() || index >= capacity) {
+                let result = std::panic::catch_unwind(|| vector.get(&candidate_index));
+                assert!(result.is_err(), "get({index}) of length {length} should halt");
+            } else {
+                let _candidate = vector.get(&candidate_index);
+                assert!(!Circuit::is_satisfied(), "get({index}) of length {length} should be unsatisfied");
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        for element_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for index_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for length_mode in [Mode::Constant, Mode::Private] {
+                    check_get(element_mode, index_mode, length_mode, 1, 1);
+                    check_get(element_mode, index_mode, length_mode, 5, 5);
+                    check_get(element_mode, index_mode, length_mode, 5, 3);
+                    check_get(element_mode, index_mode, length_mode, 8, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_constant_index_is_free() {
+        let elements = (0..4).map(|i| U8::<Circuit>::new(Mode::Private, i)).collect();
+        let vector = Vector::from_elements(elements);
+        let index = U32::constant(2);
+
+        Circuit::scope("Get", || {
+            let candidate = vector.get(&index);
+            assert_eq!(2, candidate.eject_value());
+            // The bounds check of a constant index against a constant length yields a constant boolean.
+            assert_scope!(1, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_length_exceeds_capacity_fails() {
+        let elements = (0..4).map(|i| U8::<Circuit>::new(Mode::Private, i)).collect::<Vec<_>>();
+        let _vector = Vector::<Circuit, _>::from_elements_with_length(elements, U32::new(Mode::Private, 5));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_constant_length_exceeds_capacity_halts() {
+        let elements = (0..4).map(|i| U8::<Circuit>::new(Mode::Private, i)).collect::<Vec<_>>();
+        let _vector = Vector::<Circuit, _>::from_elements_with_length(elements, U32::constant(5));
+    }
+}
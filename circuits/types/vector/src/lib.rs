@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+pub mod get;
+pub mod set;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use snarkvm_circuits_environment::prelude::*;
+use snarkvm_circuits_types_boolean::{Boolean, Mux};
+use snarkvm_circuits_types_integers::U32;
+
+/// A vector of circuits with a fixed capacity, and a length that may be a variable.
+///
+/// The elements beyond the length are padding, which is retained so that the shape
+/// of the circuit does not depend on the length of the vector.
+#[derive(Clone)]
+pub struct Vector<E: Environment, T> {
+    elements: Vec<T>,
+    length: U32<E>,
+}
+
+impl<E: Environment, T> Vector<E, T> {
+    /// Initializes a vector of the given elements, with a constant length of `elements.len()`.
+    pub fn from_elements(elements: Vec<T>) -> Self {
+        let length = U32::constant(Self::check_capacity(elements.len()));
+        Self { elements, length }
+    }
+
+    /// Initializes a vector of the first `length` of the given elements, where the remaining elements are padding.
+    /// Enforces that `length` does not exceed the number of elements.
+    pub fn from_elements_with_length(elements: Vec<T>, length: U32<E>) -> Self {
+        let capacity = U32::constant(Self::check_capacity(elements.len()));
+        let is_within_capacity = length.is_less_than_or_equal(&capacity);
+        match is_within_capacity.is_constant() {
+            true => {
+                if !is_within_capacity.eject_value() {
+                    E::halt(format!("Attempted to allocate a vector of length {} with {capacity} elements", length))
+                }
+            }
+            false => E::assert(is_within_capacity),
+        }
+        Self { elements, length }
+    }
+
+    /// Returns the maximum number of elements in the vector.
+    pub fn capacity(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn length(&self) -> &U32<E> {
+        &self.length
+    }
+
+    /// Enforces that `index` is less than the length of the vector.
+    fn assert_in_bounds(&self, index: &U32<E>) {
+        let is_in_bounds = index.is_less_than(&self.length);
+        match is_in_bounds.is_constant() {
+            true => {
+                if !is_in_bounds.eject_value() {
+                    E::halt(format!("Attempted to access index {} of a vector of length {}", index, self.length))
+                }
+            }
+            false => E::assert(is_in_bounds),
+        }
+    }
+
+    /// Halts if the given capacity exceeds the maximum length of a vector.
+    fn check_capacity(capacity: usize) -> u32 {
+        match u32::try_from(capacity) {
+            Ok(capacity) => capacity,
+            Err(_) => E::halt(format!("Attempted to allocate a vector of capacity {capacity}")),
+        }
+    }
+}
+
+impl<E: Environment, T: Inject> Inject for Vector<E, T> {
+    type Primitive = Vec<T::Primitive>;
+
+    /// Initializes a new vector, with a constant length of `values.len()`.
+    fn new(mode: Mode, values: Self::Primitive) -> Self {
+        Self::from_elements(values.into_iter().map(|value| T::new(mode, value)).collect())
+    }
+}
+
+impl<E: Environment, T: Eject> Eject for Vector<E, T> {
+    type Primitive = Vec<T::Primitive>;
+
+    ///
+    /// Ejects the mode of the vector.
+    ///
+    fn eject_mode(&self) -> Mode {
+        match (self.elements.eject_mode(), self.length.eject_mode()) {
+            (Mode::Private, _) | (_, Mode::Private) => Mode::Private,
+            (Mode::Public, _) | (_, Mode::Public) => Mode::Public,
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+        }
+    }
+
+    ///
+    /// Ejects the first `length` elements of the vector.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        let length = (self.length.eject_value() as usize).min(self.elements.len());
+        self.elements[..length].iter().map(Eject::eject_value).collect()
+    }
+}
+
+impl<E: Environment, T: Eject> fmt::Debug for Vector<E, T>
+where
+    T::Primitive: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.eject_value())
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, T> Vector<E, T>
+where
+    T: Clone + Ternary<Boolean = Boolean<E>, Output = T>,
+{
+    ///
+    /// Replaces the element at `index` with `value`, and enforces that `index` is less than the length of `self`.
+    ///
+    /// A constant index replaces the element directly. Otherwise, each element is conditionally replaced
+    /// if its position is equal to the index, which costs one equality check and one ternary per element.
+    ///
+    pub fn set(&mut self, index: &U32<E>, value: &T) {
+        // Ensure the index is within the bounds of the vector.
+        self.assert_in_bounds(index);
+
+        match index.is_constant() {
+            true => match self.elements.get_mut(index.eject_value() as usize) {
+                Some(element) => *element = value.clone(),
+                None => E::halt(format!("Attempted to set index {index} of a vector of capacity {}", self.capacity())),
+            },
+            false => {
+                for (position, element) in self.elements.iter_mut().enumerate() {
+                    let is_selected = index.is_equal(&U32::constant(position as u32));
+                    *element = T::ternary(&is_selected, value, element);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_circuits_types_integers::U8;
+
+    fn check_set(element_mode: Mode, index_mode: Mode, length_mode: Mode, capacity: u32, length: u32) {
+        let values = (0..capacity).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+
+        for index in 0..(capacity + 2) {
+            let elements = values.iter().map(|value| U8::<Circuit>::new(element_mode, *value)).collect();
+            let mut vector = Vector::from_elements_with_length(elements, U32::new(length_mode, length));
+            let candidate_index = U32::new(index_mode, index);
+            let value = U8::new(element_mode, 255);
+
+            if index < length {
+                vector.set(&candidate_index, &value);
+
+                let mut expected = values[..length as usize].to_vec();
+                expected[index as usize] = 255;
+                assert_eq!(expected, vector.eject_value());
+                assert!(Circuit::is_satisfied(), "set({index}) of length {length} is unsatisfied");
+            } else if index_mode.is_constant() && (length_mode.is_constant() || index >= capacity) {
+                let result = std::panic::catch_unwind(move || vector.set(&candidate_index, &value));
+                assert!(result.is_err(), "set({index}) of length {length} should halt");
+            } else {
+                vector.set(&candidate_index, &value);
+                assert!(!Circuit::is_satisfied(), "set({index}) of length {length} should be unsatisfied");
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_set() {
+        for element_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for index_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for length_mode in [Mode::Constant, Mode::Private] {
+                    check_set(element_mode, index_mode, length_mode, 1, 1);
+                    check_set(element_mode, index_mode, length_mode, 5, 5);
+                    check_set(element_mode, index_mode, length_mode, 5, 3);
+                    check_set(element_mode, index_mode, length_mode, 8, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_then_get() {
+        let elements = (0..6).map(|i| U8::<Circuit>::new(Mode::Private, i)).collect();
+        let mut vector = Vector::from_elements(elements);
+
+        let index = U32::new(Mode::Private, 4);
+        vector.set(&index, &U8::new(Mode::Private, 42));
+        assert_eq!(42, vector.get(&index).eject_value());
+        assert_eq!(3, vector.get(&U32::new(Mode::Private, 3)).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::bls12_377::Fq;
-use snarkvm_fields::{Field, PrimeField, SquareRootField};
+use snarkvm_fields::{Field, FieldParameters, PrimeField, SquareRootField};
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384 as FqRepr},
     rand::UniformRand,
@@ -242,6 +242,42 @@ pub(crate) fn bench_fq_inverse(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fq_inverse_fast(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_inverse_fast", |c| {
+        c.iter(|| {
+            count = (count + 1) % SAMPLES;
+            v[count].inverse_fast()
+        })
+    });
+}
+
+pub(crate) fn bench_fq_inverse_pow(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    // MODULUS - 2
+    let mut modulus_minus_two = <Fq as PrimeField>::Parameters::MODULUS;
+    modulus_minus_two.sub_noborrow(&FqRepr::from(2));
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_inverse_pow", |c| {
+        c.iter(|| {
+            count = (count + 1) % SAMPLES;
+            v[count].pow(modulus_minus_two)
+        })
+    });
+}
+
 pub(crate) fn bench_fq_negate(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{Field, PrimeField, SquareRootField};
+use snarkvm_fields::{Field, FieldParameters, PrimeField, SquareRootField};
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256 as FrRepr},
     rand::UniformRand,
@@ -242,6 +242,42 @@ pub(crate) fn bench_fr_inverse(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fr_inverse_fast(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fr_inverse_fast", |c| {
+        c.iter(|| {
+            count = (count + 1) % SAMPLES;
+            v[count].inverse_fast()
+        })
+    });
+}
+
+pub(crate) fn bench_fr_inverse_pow(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+
+    // MODULUS - 2
+    let mut modulus_minus_two = <Fr as PrimeField>::Parameters::MODULUS;
+    modulus_minus_two.sub_noborrow(&FrRepr::from(2));
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fr_inverse_pow", |c| {
+        c.iter(|| {
+            count = (count + 1) % SAMPLES;
+            v[count].pow(modulus_minus_two)
+        })
+    });
+}
+
 pub(crate) fn bench_fr_negate(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
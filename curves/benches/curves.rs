@@ -45,6 +45,8 @@ criterion_group!(
     bls12_377::fq::bench_fq_double,
     bls12_377::fq::bench_fq_square,
     bls12_377::fq::bench_fq_inverse,
+    bls12_377::fq::bench_fq_inverse_fast,
+    bls12_377::fq::bench_fq_inverse_pow,
     bls12_377::fq::bench_fq_negate,
     bls12_377::fq::bench_fq_sqrt,
     bls12_377::fq::bench_fq_into_repr,
@@ -85,6 +87,8 @@ criterion_group!(
     bls12_377::fr::bench_fr_double,
     bls12_377::fr::bench_fr_square,
     bls12_377::fr::bench_fr_inverse,
+    bls12_377::fr::bench_fr_inverse_fast,
+    bls12_377::fr::bench_fr_inverse_pow,
     bls12_377::fr::bench_fr_negate,
     bls12_377::fr::bench_fr_sqrt,
     bls12_377::fr::bench_fr_into_repr,
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::{
+    AdditionChain,
     FftParameters,
     FieldParameters,
     Fp384,
@@ -61,6 +62,37 @@ impl FieldParameters for FqParameters {
     ]);
     #[rustfmt::skip]
     const INV: u64 = 9586122913090633727u64;
+    /// An addition chain for MODULUS - 2.
+    #[rustfmt::skip]
+    const INVERSION_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 6), (4, 3), (8, 14), (7, 8), (1, 0), (9, 11), (2, 1), (6, 2),
+            (4, 0), (9, 14), (5, 5), (5, 1), (8, 14), (1, 0), (10, 11), (12, 13),
+            (5, 2), (7, 2), (6, 4), (7, 14), (5, 8), (3, 2), (8, 8), (6, 13),
+            (7, 15), (4, 1), (12, 7), (4, 2), (8, 9), (5, 8), (3, 3), (7, 7),
+            (5, 7), (7, 13), (8, 8), (6, 15), (6, 14), (9, 4), (5, 4), (19, 11),
+            (8, 5), (6, 11), (4, 2), (4, 0), (6, 1), (29, 0), (7, 2), (9, 8),
+            (6, 15), (5, 15), (5, 15), (5, 15), (5, 15), (5, 15), (5, 15), (5, 15),
+            (5, 15), (1, 0),
+        ],
+        final_squarings: 0,
+    });
+    /// An addition chain for (MODULUS - 1) / 2.
+    #[rustfmt::skip]
+    const LEGENDRE_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 6), (4, 3), (8, 14), (7, 8), (1, 0), (9, 11), (2, 1), (6, 2),
+            (4, 0), (9, 14), (5, 5), (5, 1), (8, 14), (1, 0), (10, 11), (12, 13),
+            (5, 2), (7, 2), (6, 4), (7, 14), (5, 8), (3, 2), (8, 8), (6, 13),
+            (7, 15), (4, 1), (12, 7), (4, 2), (8, 9), (5, 8), (3, 3), (7, 7),
+            (5, 7), (7, 13), (8, 8), (6, 15), (6, 14), (9, 4), (5, 4), (19, 11),
+            (8, 5), (6, 11), (4, 2), (4, 0), (6, 1), (29, 0), (7, 2), (9, 8),
+            (1, 0),
+        ],
+        final_squarings: 45,
+    });
     /// MODULUS = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
     #[rustfmt::skip]
     const MODULUS: BigInteger = BigInteger([
@@ -102,6 +134,20 @@ impl FieldParameters for FqParameters {
     ]);
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 7;
+    /// An addition chain for (t - 1) / 2.
+    #[rustfmt::skip]
+    const SQRT_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 6), (4, 3), (8, 14), (7, 8), (1, 0), (9, 11), (2, 1), (6, 2),
+            (4, 0), (9, 14), (5, 5), (5, 1), (8, 14), (1, 0), (10, 11), (12, 13),
+            (5, 2), (7, 2), (6, 4), (7, 14), (5, 8), (3, 2), (8, 8), (6, 13),
+            (7, 15), (4, 1), (12, 7), (4, 2), (8, 9), (5, 8), (3, 3), (7, 7),
+            (5, 7), (7, 13), (8, 8), (6, 15), (6, 14), (9, 4), (5, 4), (19, 11),
+            (8, 5), (6, 11), (4, 2), (4, 0), (6, 1), (29, 0), (7, 2), (9, 8),
+        ],
+        final_squarings: 0,
+    });
     // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
 
     /// T = (MODULUS - 1) // 2^S =
@@ -126,6 +172,194 @@ impl FieldParameters for FqParameters {
         0x748c2f8a21d58c76,
         0x35c,
     ]);
+    /// TWO_ADIC_ROOT_OF_UNITY^(2^i) for i in 0..TWO_ADICITY, in Montgomery form.
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY_POWERS: &'static [BigInteger] = &[
+        BigInteger([
+            0x1c104955744e6e0f, 0xf1bd15c3898dd1af, 0x76da78169a7f3950,
+            0xee086c1fe367c337, 0xf95564f4cbc1b61f, 0x00f3c1414ef58c54,
+        ]),
+        BigInteger([
+            0x0f3b4ffbdd4b949d, 0x988517b331cada8d, 0x17a4fd356a2a11ec,
+            0x4a12876a1a826173, 0x746af78e4ab1b450, 0x0129a5a0875b760e,
+        ]),
+        BigInteger([
+            0x53900b5a392887dd, 0x2aa10664f232801a, 0xf6ef99270816666f,
+            0x3e71600cd4d9f22f, 0x3346fc0906f83185, 0x007f54eae28869fc,
+        ]),
+        BigInteger([
+            0x3992274e2edc7d89, 0xe4cb85c74a0e23f5, 0xbdddd063e3b240ef,
+            0x84701a2557a3f791, 0xd93e977ae143037e, 0x012e9da4a57368d5,
+        ]),
+        BigInteger([
+            0x3d9f15602065de89, 0x70e211ba294bc916, 0x51321d109344cd43,
+            0xff3194f87a32d7a0, 0x37fa8ba4de67be0a, 0x0158695a6edbf22a,
+        ]),
+        BigInteger([
+            0xb8e11bcdde7c721c, 0xc1b2517497a9ab53, 0x6f541a2950aa5f5e,
+            0xd1a1d3c61e6f2cb4, 0x2faf7d65733fead9, 0x003a9ccbb0d8c87b,
+        ]),
+        BigInteger([
+            0xb0d04aac326b1c4b, 0xbbc38b4bc2da1631, 0xdde0cca7290a2aba,
+            0x4838a7f887f7ad9f, 0xa96026aaabb84db5, 0x00c2fd574cc568fb,
+        ]),
+        BigInteger([
+            0x63ecc035777f7408, 0x01c9d30a5e76a29a, 0x1b5e4c362a052666,
+            0x6832365624d810a3, 0xfbaa08cb8e3bb563, 0x01411e7b8228236e,
+        ]),
+        BigInteger([
+            0x9b31634f20145cc4, 0x2e84e86a441002d2, 0x0a58ec99471bf64b,
+            0x92544cb14140a2df, 0xc1843190747cc3ca, 0x010e8d4d24ec07d6,
+        ]),
+        BigInteger([
+            0x4ba8d2c5321f78d1, 0x9d6e5250f41dee8a, 0x334d01850d7047d3,
+            0x0bcb208e0921df91, 0x6b60bee792b23926, 0x0093407e1f2d8190,
+        ]),
+        BigInteger([
+            0x4433956c7d0bbc08, 0x9ecc2e5c12747560, 0x02c9533a60cf7f78,
+            0x487c8eac7fc8134d, 0xad13d2655908512a, 0x0034825f2182c900,
+        ]),
+        BigInteger([
+            0x5ede251e4e4e42d0, 0x899f56a3ffbe9125, 0xd23e3279afd02dd1,
+            0x2d1f426c08d02d99, 0xe0db4b4c5db4051f, 0x011760481b55b975,
+        ]),
+        BigInteger([
+            0x8b1ce2c3efa33c66, 0x25fd3ca61160b810, 0xa08e09c4bc3aa53b,
+            0x5f8331b10718efcd, 0xf93258c957df06a1, 0x0137127d1e449bcb,
+        ]),
+        BigInteger([
+            0x6c14337ab19a4b63, 0x379f02be2df1998b, 0x507b1b95cfe9371c,
+            0x4e6dc88696efecd0, 0x4a6681498995c8da, 0x011736cac7b5af49,
+        ]),
+        BigInteger([
+            0x796e2b629b62ee88, 0x69ae34907e988c90, 0xb5657ec40ef23f90,
+            0x12f1200382f4be0f, 0x8c4abc4afbc14101, 0x01727c84f1a94f94,
+        ]),
+        BigInteger([
+            0xe82fe0c6bbe7de7f, 0xd5b96ddcc509f8ee, 0x37f663161df310e9,
+            0xd7d0f9a14443142a, 0x054f722e6cf7f3b1, 0x0110492e8d4db53e,
+        ]),
+        BigInteger([
+            0x7154066cb67c2277, 0x17a7b5d580380996, 0xb9934896d71f9737,
+            0x86d42b5de840db59, 0x43d3d7cbd4129467, 0x0016558cdbbd083a,
+        ]),
+        BigInteger([
+            0x188b18c17484b767, 0x11468e228be42770, 0x7f07e8fa979dc2f4,
+            0xac9c20ba730d7e8e, 0x22f7c939aea909b1, 0x0085837edf7abc1e,
+        ]),
+        BigInteger([
+            0xbaeb04fe389e7172, 0x003552d7defa9e7d, 0xc655d19c4f6eb70c,
+            0x80d92526784f4587, 0xfe2dc0f791441ccd, 0x0092d7e8dc3dd37b,
+        ]),
+        BigInteger([
+            0x72c4dc24b9366608, 0xe8ade3c7825afe34, 0x04b506de0da1dcec,
+            0x7d4e8ce34bf4b4ce, 0xeb3165a282b2c499, 0x00041a705d42d4ce,
+        ]),
+        BigInteger([
+            0x931505fb739cf71f, 0x1180481a60509f8e, 0x9f93b939d6d8df01,
+            0x76ea8fc08097c713, 0x102f9984456ccf4b, 0x00be0511a7c86524,
+        ]),
+        BigInteger([
+            0x161ac56b9b6db58f, 0xdf6466a32cc72c86, 0x6df1d4a902b0d565,
+            0xc4d11505977bedd1, 0x0efee4d009981482, 0x012d2a07485e2d71,
+        ]),
+        BigInteger([
+            0xf02939908a4dc94d, 0x7afcaa9e0975335f, 0xbb47950938d529c5,
+            0x33983d12c31b1619, 0x91ef3a794e1aa380, 0x018f673a6de3d3cc,
+        ]),
+        BigInteger([
+            0x96ea679d7645f39a, 0xbd2cf53bb779e676, 0xa5f88665cc377a0b,
+            0xc1b396e0135c3da8, 0x661507c78c8b0475, 0x00b5d4a9da282fd9,
+        ]),
+        BigInteger([
+            0x8a0372f183472ee7, 0xa204fef88faae216, 0x3d7b2a28aea41c75,
+            0x4a7649637669922a, 0xb4c165e1e8ac347a, 0x011ced1e85047de5,
+        ]),
+        BigInteger([
+            0xa454b877ba574a7d, 0x2e2d1c7276a42757, 0x86dd140935528a2d,
+            0xf32a97782aec43a3, 0xf3950c5a3e3e3cd2, 0x00893d95631116ea,
+        ]),
+        BigInteger([
+            0xb525ca3ba63aade2, 0x15d64121b998e8c5, 0x6b54f1fbbd52c3ec,
+            0xdc5e6bb36154257d, 0x4530c7e2dd3a128f, 0x00b1b30b06655095,
+        ]),
+        BigInteger([
+            0x52d2b57d7d52c469, 0x3c771152b9720c60, 0xca29dcbbd9d5211b,
+            0x4d915690aabb47e8, 0xc17c6ee6cc08cdd9, 0x0113739c263ca48c,
+        ]),
+        BigInteger([
+            0x15199dc9651f7235, 0x3e8639cbad9e5597, 0x9aebca72611b1b55,
+            0xce1530a202ce9aa3, 0x206fa99fde9243f7, 0x01a6edc1a781a9be,
+        ]),
+        BigInteger([
+            0xf7cd44af92ff59ae, 0xf9682fafb3b099a1, 0xf28bfbb1afd73c11,
+            0x108608eac8c95fcb, 0x7672a5201e4f20be, 0x01395b5b872a886c,
+        ]),
+        BigInteger([
+            0x3225e7484ddaaf0c, 0xf043acd7c41d7e6e, 0xca6355c3b1c8b1b6,
+            0xda14458155b4629f, 0x323c669c934d17b2, 0x016cde64f8e132ca,
+        ]),
+        BigInteger([
+            0x28545abe07eac4e7, 0x2d0d516b7648aff7, 0x87040abb4d2fb024,
+            0xc484ac2472e5ad63, 0xf4dd762847e46dba, 0x0088b9d17a9888eb,
+        ]),
+        BigInteger([
+            0xf89e9d2b018cfe4b, 0x8cfc3f0adab82a6d, 0xf2f983c4162cc6f9,
+            0x03372dd4d9554aa6, 0xe6ffc79823ca1233, 0x0100507f3e53c414,
+        ]),
+        BigInteger([
+            0x36ef89727dee4bf0, 0x8fe21e73ab7f8a3e, 0xa732ad82665440a1,
+            0x05a244f29110481c, 0x23b0f1ef6723d14c, 0x00ae5e3154a8ab90,
+        ]),
+        BigInteger([
+            0x0832cea733bf7219, 0x637ded8fb47d9947, 0x2b2f28b4f07ad983,
+            0x30b48574184ca79c, 0x825a00030774e2f4, 0x000a50ae854bdece,
+        ]),
+        BigInteger([
+            0x97305d5844ac4a44, 0x6439bdc9a9dd445b, 0x6bae95a47af109ec,
+            0xfc229ec60ec5e4aa, 0x0e63ed33bc131101, 0x012de9654274f23e,
+        ]),
+        BigInteger([
+            0x565cdf7816fb28e6, 0x8b2f869a789d7fdb, 0x18bb516a7238f5ba,
+            0x41eadc43be219942, 0xf3b054dc69899706, 0x004a7200e368e201,
+        ]),
+        BigInteger([
+            0x86d8ca42f6099ba1, 0x2886eec9bbffee98, 0x45e952f97989e192,
+            0x8c1bb96c86c019dd, 0xdb3e5fdedb135ef9, 0x010dfe222511adaa,
+        ]),
+        BigInteger([
+            0x3c7dcaf661ebd74b, 0xd0ef07952829fdd4, 0xce8f3ad0e97445e9,
+            0xe4d07d22e80439ce, 0x7f29944e8317e85b, 0x0151e840e3322b3b,
+        ]),
+        BigInteger([
+            0x1bead8a78ae989d6, 0xda211c774a0d7db4, 0x03e5d2eaf43c7e19,
+            0x1c641ed6bb6cfb6e, 0xdc10e82e4c2e26fa, 0x000085c10417485a,
+        ]),
+        BigInteger([
+            0xe8979e97b252fabf, 0x0af7b7d531472e75, 0x5e4b03e8bc7795c2,
+            0x1a56b111907850f0, 0x89d17fc177e98875, 0x00de7da6c55a1474,
+        ]),
+        BigInteger([
+            0x5f31e95db54144e1, 0x0c9e08dae2226212, 0xdd984236c13d8dd2,
+            0x90eb8b7b58cc80f8, 0x6de3ece6719ce6eb, 0x0173b6bb019297a5,
+        ]),
+        BigInteger([
+            0x6417fa691ba3243a, 0x15fedb488c42b284, 0x8a46f92fa2adedfe,
+            0xa0f7d5a240049835, 0x2d4c7535a957d02e, 0x004844cda5ea8e74,
+        ]),
+        BigInteger([
+            0x1dbc570b239f21ee, 0x528a320870911b45, 0x4f0e34a56bad6f8d,
+            0xbf3d6d8ad20a225c, 0x948799b3dcb95688, 0x007868308f981f96,
+        ]),
+        BigInteger([
+            0x982c13d9d084771f, 0xfd49de0c6da34a32, 0x61a530d183ab0e53,
+            0xdf8fe44106dd9879, 0x40f29b58d88472bc, 0x0158723199046d5d,
+        ]),
+        BigInteger([
+            0x823ac00000000099, 0xc5cabdc0b000004f, 0x7f75ae862f8c080d,
+            0x9ed4423b9278b089, 0x79467000ec64c452, 0x0120d3e434c71c50,
+        ]),
+    ];
 }
 
 impl PoseidonDefaultParameters for FqParameters {
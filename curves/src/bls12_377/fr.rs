@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::{
+    AdditionChain,
     FftParameters,
     FieldParameters,
     Fp256,
@@ -86,6 +87,32 @@ impl FieldParameters for FrParameters {
     ]);
     #[rustfmt::skip]
     const INV: u64 = 725501752471715839u64;
+    /// An addition chain for MODULUS - 2.
+    #[rustfmt::skip]
+    const INVERSION_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 4), (6, 10), (6, 13), (7, 10), (5, 7), (6, 9), (6, 8), (6, 12),
+            (5, 4), (6, 10), (6, 12), (1, 0), (9, 5), (6, 8), (6, 6), (7, 7),
+            (7, 11), (9, 13), (5, 13), (16, 10), (5, 9), (6, 10), (7, 14), (5, 11),
+            (4, 7), (5, 6), (28, 0), (7, 2), (9, 8), (6, 15), (5, 15), (5, 15),
+            (5, 15), (5, 15), (5, 15), (5, 15), (5, 15), (5, 15), (2, 1),
+        ],
+        final_squarings: 0,
+    });
+    /// An addition chain for (MODULUS - 1) / 2.
+    #[rustfmt::skip]
+    const LEGENDRE_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 8,
+        steps: &[
+            (0, 4), (4, 2), (5, 5), (3, 1), (5, 2), (5, 5), (4, 6), (6, 6),
+            (7, 5), (5, 2), (5, 2), (4, 2), (3, 1), (4, 1), (9, 5), (2, 0),
+            (7, 4), (3, 2), (7, 7), (6, 5), (1, 0), (8, 6), (3, 3), (3, 1),
+            (14, 2), (3, 1), (6, 6), (4, 2), (5, 3), (5, 6), (4, 7), (4, 6),
+            (3, 2), (28, 0), (7, 2), (5, 0), (5, 1),
+        ],
+        final_squarings: 46,
+    });
     /// MODULUS = 8444461749428370424248824938781546531375899335154063827935233455917409239041
     #[rustfmt::skip]
     const MODULUS: BigInteger = BigInteger([
@@ -121,6 +148,18 @@ impl FieldParameters for FrParameters {
     ]);
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 3;
+    /// An addition chain for (t - 1) / 2.
+    #[rustfmt::skip]
+    const SQRT_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 15,
+        steps: &[
+            (0, 4), (6, 10), (6, 13), (7, 10), (5, 7), (6, 9), (6, 8), (6, 12),
+            (5, 4), (6, 10), (6, 12), (1, 0), (9, 5), (6, 8), (6, 6), (7, 7),
+            (7, 11), (9, 13), (5, 13), (16, 10), (5, 9), (6, 10), (7, 14), (5, 11),
+            (4, 7), (5, 6), (28, 0), (7, 2), (9, 8),
+        ],
+        final_squarings: 0,
+    });
     // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
 
     /// t = (r - 1) / 2^s =
@@ -141,6 +180,57 @@ impl FieldParameters for FrParameters {
         0x655e9a2ca55660b4,
         0x12ab,
     ]);
+    /// TWO_ADIC_ROOT_OF_UNITY^(2^i) for i in 0..TWO_ADICITY, in Montgomery form.
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY_POWERS: &'static [BigInteger] = &[
+        BigInteger([0xaf80da4dda3ad648, 0x5e223adbfc381dac, 0x03ba0666b2f92525, 0x0f906c5b3befb0ce]),
+        BigInteger([0x89832c301f7d7347, 0x0dbd1eaadd07018b, 0xb76f3b3206370de0, 0x0458c8bce631e66b]),
+        BigInteger([0x3f6f3ff17326cc26, 0xc6d71ac6eb5da228, 0x717d2664f89ba32c, 0x0a29fd79b39afdce]),
+        BigInteger([0x68bc6cac04fc7429, 0xa2d76e481d4ac39f, 0x73c90fa2b2e506eb, 0x0801e08d721b25b7]),
+        BigInteger([0xd7262345756b148f, 0x2009de2d296bffd7, 0xe20029ffab567e28, 0x0170a9c5d279aeed]),
+        BigInteger([0x77773544a1eb2706, 0x570824476ff28770, 0x0eda9ad4fd33602d, 0x077ab5c762faa37a]),
+        BigInteger([0x58d7895260e38028, 0x10014dfaf04dd21b, 0xcdb8265f34d4e72f, 0x06edad9aedc8a15b]),
+        BigInteger([0xf77e90efa5bdde95, 0x54870dbf87faf539, 0xaddc8af0f33dc989, 0x0be74b87f6dd376a]),
+        BigInteger([0x1b81a6d7dd77a5a8, 0xa8bb487c3e6bc378, 0xbde95c77155369a9, 0x0bab1284d398faab]),
+        BigInteger([0xfc6c79e8d02a7b32, 0x8ba9f919180b0110, 0xfb8c7378fbeca933, 0x0183cbb147cba996]),
+        BigInteger([0x39890dfe7137cc6b, 0x06a976c31642dbf1, 0xd2be68273bb1ea2c, 0x0dd79056a1964ec8]),
+        BigInteger([0xabd1ea6e59cd8662, 0xcf14da155f588557, 0x6e5f908066ebbac1, 0x0a4705816176056e]),
+        BigInteger([0xf32eef07bf615993, 0x71f0bc0575600c8b, 0xa7f5e0fa576e29ae, 0x00f64695d6b9ceec]),
+        BigInteger([0xb6f93b88916a2f3d, 0x45e2313d42427291, 0x968ece12da27d3d5, 0x0f2c27f1af060240]),
+        BigInteger([0x32943098d36de428, 0xf3597b4847aba111, 0x206340abad0d776e, 0x10cfdd357a8fbb83]),
+        BigInteger([0xdac72d53a7d6e38d, 0xebf0c43a8b12743d, 0x9c1d0a5e0fdc055f, 0x12199274d0de1e1d]),
+        BigInteger([0xec40797c6027c013, 0xf0771a3e8998af87, 0xd35354464ddb40f0, 0x0746e328a8c24178]),
+        BigInteger([0xd737d2e52d132c04, 0x7bbc8b757b01556c, 0x43a015853524a269, 0x070a19c53db20e87]),
+        BigInteger([0xc15ce3367897160b, 0x60ccb65ea5553888, 0x03f8025a28ca87c3, 0x0ca0ef4ae6c09c6c]),
+        BigInteger([0x18edfc4b06a79893, 0xffe5168fd9942118, 0x76b85d81b4ab2c5c, 0x0a43904047c2ecc0]),
+        BigInteger([0x7ace170448bd341f, 0x99cbcaffce59c019, 0x50740d592e332bf8, 0x065b7a947b4d6259]),
+        BigInteger([0x5280a8809d734f5b, 0x0521871ce21ea4d6, 0x0051682243728a35, 0x0f968a5c29db897c]),
+        BigInteger([0x72306a59bb5d59f0, 0x2d113f940470200b, 0x651a62d4a7038bb9, 0x024c8b173544a763]),
+        BigInteger([0xdf95a592762b62a6, 0x4b33079206cd7776, 0x9cdeeba3892b3401, 0x0406b7da1bb7b875]),
+        BigInteger([0x95159cf85a70a78e, 0x61ed434408bea666, 0xaf13234ccb93c8e5, 0x079ca85ab5f61a08]),
+        BigInteger([0x705ff163618ada97, 0x92e8577fc756b3fd, 0x518f5a48eabda19b, 0x069ff405aaf3e6f5]),
+        BigInteger([0xd98f135463d4e247, 0xaa2bebd26ae5ab2a, 0x687b3c9d51e7fca9, 0x0904f8eae20035ff]),
+        BigInteger([0xfd92ff20bb375929, 0x9a19190be3b9fea2, 0xe5cc2e60c8a4cade, 0x0cd7703c762eef58]),
+        BigInteger([0x363a8c55a97bcae9, 0x994a338b541d6703, 0x0142b614d82123eb, 0x0c16027c73265ce5]),
+        BigInteger([0x30f67ce7e7d7cbac, 0x9a57d99687c53f40, 0x9e47662488d63896, 0x094d7b965cf5d2e6]),
+        BigInteger([0xbabf311d2a4e6774, 0x7d76e06a4df5f875, 0x3cb67900f44a84a0, 0x05de3c1fa1a2eb41]),
+        BigInteger([0xeefb02d3872ad28b, 0x6aec95274e058bc1, 0xcc0b97a6435ad922, 0x0194c4d9b06162c4]),
+        BigInteger([0xd68025fb06508dfb, 0x590c1be9db0b556f, 0x3d313c6d67a5f2f6, 0x0bdea4d8c18105b2]),
+        BigInteger([0xcb4b02e89ad1d94a, 0x09286f228aa9f768, 0xbe4b4d11e43d7830, 0x098e8002e6b7e267]),
+        BigInteger([0xf39b3ddec812dec9, 0xac4acef2a9e798f6, 0xb156be66ef6fbfd6, 0x044d02c2d8f648fe]),
+        BigInteger([0xbdbbe39e63c9ff05, 0xbc0d9400155388cf, 0xcf9daa6d01214e28, 0x02bcbc362ad494c3]),
+        BigInteger([0xcdf2ec3a5ae23c46, 0x6a3067507ae7991b, 0x9eca9511c4d698aa, 0x05adb6ca20eeeb4c]),
+        BigInteger([0x512dd09e5dca3d19, 0x5c4913b21dc70202, 0xdf2a5b8150c5548a, 0x0c9079137b1b24b6]),
+        BigInteger([0xb89ab895675f3dba, 0x32200ccb96ac3995, 0x3029e5dc63b9ba0f, 0x0d2fb38c17b5de92]),
+        BigInteger([0xe2c1d978aec0317b, 0x433f4156355a9b4e, 0x5ff43674ebb7842b, 0x0714a7883c6b13af]),
+        BigInteger([0x1dc55c8a8bf61422, 0x8dfaaca46ec7175a, 0x640623d46a8ba80f, 0x02e960ed5e6447d2]),
+        BigInteger([0x7fcc4602d6873602, 0x9b015cba0d84664c, 0x736cd5304e2e0179, 0x0f21c7bdeed774bf]),
+        BigInteger([0xff7759ccfefe873d, 0xc9e469cc2e15a1d7, 0x53eb598bcd5ccadd, 0x0a119f08b45ed5aa]),
+        BigInteger([0x172fa063ffae379a, 0x6d2c389cd26cc040, 0x8b26d6078c01617e, 0x0ad7a02c1199ccbf]),
+        BigInteger([0x43c377a2d0b10cfb, 0x6fdcdd33e1ab2ffe, 0x41a160295c736721, 0x0fed8f09fbb492e4]),
+        BigInteger([0x4bcacfd5d2103b0e, 0x4597a1b1b0ddeb5c, 0x713273b2dd764c92, 0x1208f485585ae1ea]),
+        BigInteger([0x8cf500000000000e, 0xe75281ef6000000e, 0x49dc37a90b0ba012, 0x055f8b2c6e710ab9]),
+    ];
 }
 
 impl PoseidonDefaultParameters for FrParameters {
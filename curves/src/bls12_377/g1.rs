@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{Fq, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    traits::{GLVParameters, ModelParameters, SWUParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -56,12 +56,239 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
         BigInteger256([2013239619100046060, 4201184776506987597, 2526766393982337036, 1114629510922847535,])
     );
 
+    /// GLV = (beta, lambda), where `lambda = x^2 - 1` for the curve parameter `x`,
+    /// with the lattice basis `[(lambda, -1), (1, x^2)]`.
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        // beta = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945
+        beta: field!(
+            Fq,
+            BigInteger384([
+                0xdacd106da5847973,
+                0xd8fe2454bac2a79a,
+                0x1ada4fd6fd832edc,
+                0xfb9868449d150908,
+                0xd63eb8aeea32285e,
+                0x167d6a36f873fd0,
+            ])
+        ),
+        lambda: GLV_LAMBDA,
+        basis: [
+            (
+                GLV_LAMBDA,
+                // -1
+                field!(
+                    Fr,
+                    BigInteger256([0x8cf500000000000e, 0xe75281ef6000000e, 0x49dc37a90b0ba012, 0x55f8b2c6e710ab9])
+                ),
+            ),
+            (
+                // 1
+                field!(
+                    Fr,
+                    BigInteger256([0x7d1c7ffffffffff3, 0x7257f50f6ffffff2, 0x16d81575512c0fee, 0xd4bda322bbb9a9d])
+                ),
+                // x^2 = 91893752504881257701523279626832445441
+                field!(
+                    Fr,
+                    BigInteger256([0x218b12cd506fe7d4, 0xa388dd4bbec9d528, 0xcbda283a9b644306, 0x3bfa7e6d4aef998])
+                ),
+            ),
+        ],
+        // round(2^256 * x^2 / r) = 1260064869275694167304791240164355610668, round(2^256 / r) = 14
+        rounded_quotients: [[0x7f72ed32af90182c, 0xb3f7aa969fd37160, 0x3, 0x0], [0xe, 0x0, 0x0, 0x0]],
+    });
+
+    /// SWU = (a, b, z), the simplified SWU map to the 2-isogenous curve `y^2 = x^3 - 15 * x + 22`,
+    /// with the isogeny `(x, y) -> ((x^2 - 2 * x - 3) / (4 * (x - 2)), y * (x^2 - 4 * x + 7) / (8 * (x - 2)^2))`.
+    const SWU: Option<SWUParameters<Self>> = Some(SWUParameters {
+        // a = -15
+        coeff_a: field!(
+            Fq,
+            BigInteger384([
+                0x6f19c000000008ed,
+                0xb06f79a0700004a3,
+                0x426363ff84d7a8cd,
+                0x49135dff8b7f9477,
+                0x5cd256899b9aa488,
+                0x1e23a229f7ff8b,
+            ])
+        ),
+        // b = 22
+        coeff_b: field!(
+            Fq,
+            BigInteger384([
+                0x9a76bffffffff2e9,
+                0x5a3e286faffff932,
+                0xdc25c143d08286d2,
+                0xe1cd141e77fcf991,
+                0x3167b6320cca6b5c,
+                0x63347edb6f8ed7,
+            ])
+        ),
+        // z = -11
+        z: field!(
+            Fq,
+            BigInteger384([
+                0xf54900000000068c,
+                0xde669a6a40000366,
+                0xa166d075f4c36096,
+                0x1c2ae2ea447c0cfe,
+                0xca69a7c72feb6eef,
+                0xa582e39e2ac109,
+            ])
+        ),
+        x_numerator: &[
+            // -3/4
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc2ee400000000073,
+                    0x5a1ae5a19000003b,
+                    0xe7551b70922b580a,
+                    0xbda7e8296e17c94a,
+                    0xc839570cc73e58c,
+                    0x1442d7cad869977,
+                ])
+            ),
+            // -1/2
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3a1c0000000004d,
+                    0xee6b0d8270000028,
+                    0x4f34885af4caa806,
+                    0xdc7b8e1749b6e20c,
+                    0x9fc0bae0ac8306c6,
+                    0x16787152646169d,
+                ])
+            ),
+            // 1/4
+            field!(
+                Fq,
+                BigInteger384([
+                    0x40b37fffffffffda,
+                    0x945027e0dfffffec,
+                    0x67df6cea629f4ffc,
+                    0x1ed3a5eddb9f18c1,
+                    0x933d256fe00f213a,
+                    0x23599878bf7d26,
+                ])
+            ),
+        ],
+        x_denominator: &[
+            // -2
+            field!(
+                Fq,
+                BigInteger384([
+                    0x7f6cc00000000131,
+                    0x748a1e3d3000009e,
+                    0xdff7fadca50ec81b,
+                    0x2385aa8423fc4d83,
+                    0x2c51da416c283f6a,
+                    0x936d8251c927b6,
+                ])
+            ),
+            // 1
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+        ],
+        y_numerator: &[
+            // 7/8
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe2743fffffffff7b,
+                    0x7188b930fffffba,
+                    0xeb8dfd34592d97f4,
+                    0x6be4c4c080acd6a4,
+                    0x35603079034f44b,
+                    0x7bb995a69e3607,
+                ])
+            ),
+            // -1/2
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3a1c0000000004d,
+                    0xee6b0d8270000028,
+                    0x4f34885af4caa806,
+                    0xdc7b8e1749b6e20c,
+                    0x9fc0bae0ac8306c6,
+                    0x16787152646169d,
+                ])
+            ),
+            // 1/8
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2059bfffffffffed,
+                    0x4a2813f06ffffff6,
+                    0xb3efb675314fa7fe,
+                    0xf69d2f6edcf8c60,
+                    0x499e92b7f007909d,
+                    0x11accc3c5fbe93,
+                ])
+            ),
+        ],
+        y_denominator: &[
+            // 4
+            field!(
+                Fq,
+                BigInteger384([
+                    0x862f3ffffffffd9f,
+                    0x2df720c9cffffec3,
+                    0x5f036c766febb7c9,
+                    0xd31784eab8fc7887,
+                    0x6d97513d9450ca66,
+                    0x875f417432c17e,
+                ])
+            ),
+            // -4
+            field!(
+                Fq,
+                BigInteger384([
+                    0xfed9800000000262,
+                    0xe9143c7a6000013c,
+                    0xbfeff5b94a1d9036,
+                    0x470b550847f89b07,
+                    0x58a3b482d8507ed4,
+                    0x126db04a3924f6c,
+                ])
+            ),
+            // 1
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+        ],
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
 }
 
+/// GLV_LAMBDA = x^2 - 1 = 91893752504881257701523279626832445440
+const GLV_LAMBDA: Fr =
+    field!(Fr, BigInteger256([0xae8012cd506fe7e2, 0x8adb5f3b1ec9d536, 0x15b65fe3a66fe319, 0x91f331343200452]));
+
 ///
 /// G1_GENERATOR_X =
 /// 89363714989903307245735717098563574705733591463163614225748337416674727625843187853442697973404985688481508350822
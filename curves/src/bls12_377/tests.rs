@@ -39,6 +39,7 @@ use crate::{
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
+        HashToCurve,
         PairingEngine,
         ProjectiveCurve,
         ShortWeierstrassParameters,
@@ -60,6 +61,7 @@ use snarkvm_fields::{
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384},
     rand::{test_rng, UniformRand},
+    BitIteratorBE,
 };
 
 use rand::{thread_rng, Rng, SeedableRng};
@@ -570,6 +572,83 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_glv() {
+    let mut rng = test_rng();
+    let glv = Bls12_377G1Parameters::GLV.unwrap();
+
+    // Ensure the endomorphism acts as multiplication by lambda.
+    let generator = G1Affine::prime_subgroup_generator();
+    let expected = generator.mul_bits(BitIteratorBE::new(glv.lambda.to_repr()));
+    assert_eq!(expected, G1Affine::new(generator.x * glv.beta, generator.y, false));
+
+    let mut scalars = vec![Fr::zero(), Fr::one(), -Fr::one(), glv.lambda, -glv.lambda];
+    scalars.extend((0..100).map(|_| Fr::rand(&mut rng)));
+    for scalar in scalars {
+        // Ensure the decomposition is correct, and each coefficient is at most 128 bits.
+        let ((is_k1_negative, k1), (is_k2_negative, k2)) = glv.decompose(&scalar);
+        let k1_signed = if is_k1_negative { -k1 } else { k1 };
+        let k2_signed = if is_k2_negative { -k2 } else { k2 };
+        assert_eq!(scalar, k1_signed + k2_signed * glv.lambda);
+        assert!(k1.to_repr().num_bits() <= 128);
+        assert!(k2.to_repr().num_bits() <= 128);
+
+        // Ensure the GLV scalar multiplication matches double-and-add.
+        let point: G1Projective = rng.gen();
+        let expected = point.to_affine().mul_bits(BitIteratorBE::new(scalar.to_repr()));
+        assert_eq!(expected, point * scalar);
+        assert_eq!(expected, point.to_affine() * scalar);
+
+        // Ensure the affine decomposition recomposes to the same point.
+        let ((p1, k1), (p2, k2)) = point.to_affine().glv_decompose(&scalar).unwrap();
+        assert_eq!(expected, p1 * k1 + p2 * k2);
+    }
+}
+
+#[test]
+fn test_g1_hash_to_curve() {
+    const DST: &[u8] = b"SNARKVM-V01-CS01-with-BLS12377G1_XMD:SHA-256_SSWU_RO_";
+
+    // Ensure the 2-isogeny maps the SWU curve into G1.
+    let swu = Bls12_377G1Parameters::SWU.unwrap();
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        if let Some((x, y)) = swu.map_to_curve(&Fq::rand(&mut rng)) {
+            assert!(G1Affine::new(x, y, false).is_on_curve());
+        }
+    }
+
+    // Check the output against fixed test vectors.
+    let point = G1Affine::hash_to_curve(b"", DST).unwrap();
+    assert_eq!(
+        point.x.to_string(),
+        "79361356257544696339115914911712556065958122692291531329470612417916496305462799109763582172723057685604121376048"
+    );
+    assert_eq!(
+        point.y.to_string(),
+        "212290389736205107479764793485812275989050392729013479033492308006386224809081630212853106082237634743876533138581"
+    );
+
+    let point = G1Affine::hash_to_curve(b"abc", DST).unwrap();
+    assert_eq!(
+        point.x.to_string(),
+        "220186059136776915114829835942068843245731812781177960225043280760149910581332224669217558140527264439932741120866"
+    );
+    assert_eq!(
+        point.y.to_string(),
+        "145122767049236134662243053478518380479727607268022100094642691262250191651247090322128812679576273248899324913583"
+    );
+
+    for message in [b"".as_ref(), b"abc", b"abcdef0123456789", &[b'a'; 512]] {
+        let point = G1Affine::hash_to_curve(message, DST).unwrap();
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+
+        // Ensure the domain separation tag changes the output.
+        assert_ne!(point, G1Affine::hash_to_curve(message, b"SNARKVM-V01-CS01-with-another-tag").unwrap());
+    }
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -614,3 +693,20 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_multi_pairing() {
+    for num_pairs in [0, 1, 2, 5] {
+        let g1s: Vec<G1Projective> = (0..num_pairs).map(|_| rand::random()).collect();
+        let g2s: Vec<G2Projective> = (0..num_pairs).map(|_| rand::random()).collect();
+
+        let expected = g1s.iter().zip(&g2s).map(|(a, b)| Bls12_377::pairing(*a, *b)).product::<Fq12>();
+        assert_eq!(expected, Bls12_377::multi_pairing(g1s, g2s));
+    }
+
+    // Ensure `e(s * a, b) * e(-a, s * b) = 1`.
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+    assert!(Bls12_377::multi_pairing([a * s, -a], [b, b * s]).is_one());
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BLS12-381 scalar field.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 10238227357739495823651030575849232062558860180284477541189508159991286009131
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xb9b58d8c5f0e466a,
+        0x5b1b4c801819d7ec,
+        0xaf53ae352a31e64,
+        0x5bf3adda19e9b27b,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    /// Encoded in Montgomery form, so the value is
+    /// (7 * R) % r = 24006497034320510773280787438025867407531605151569380937148207556313189711857
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xefffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 18446744069414584319u64;
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (r - 1)/2 =
+    /// 26217937587563095239723870254092982918845276250263818911301829349969290592256
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fffffff80000000,
+        0xa9ded2017fff2dff,
+        0x199cec0404d0ec02,
+        0x39f6d3a994cebea4,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x1fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xc999e990f3f29c6d,
+        0x2b6cedcb87925c23,
+        0x5d314967254398f,
+        0x748d9d99f59ff11,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 12208678567578594777604504606729831043093128246378069236549469339647
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xfffe5bfeffffffff,
+        0x9a1d80553bda402,
+        0x299d7d483339d808,
+        0x73eda753,
+    ]);
+    /// (t - 1) / 2 =
+    /// 6104339283789297388802252303364915521546564123189034618274734669823
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fff2dff7fffffff,
+        0x4d0ec02a9ded201,
+        0x94cebea4199cec04,
+        0x39f6d3a9,
+    ]);
+    /// TWO_ADIC_ROOT_OF_UNITY^(2^i) for i in 0..TWO_ADICITY, in Montgomery form.
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY_POWERS: &'static [BigInteger] = &[
+        BigInteger([0xb9b58d8c5f0e466a, 0x5b1b4c801819d7ec, 0x0af53ae352a31e64, 0x5bf3adda19e9b27b]),
+        BigInteger([0x1f2fda6f3141e583, 0x8d44592227fe5b46, 0xe33cee1b81e59a5f, 0x22c666251ad8ae31]),
+        BigInteger([0x8ea72d687bb12408, 0xa9ed127cb02387bc, 0x9c59d748e7669430, 0x4e720080e9ced390]),
+        BigInteger([0x250a9d0e4b092216, 0x525a653781be1b5e, 0xe4381082889cdfb0, 0x02536cde1d9f1a1c]),
+        BigInteger([0x2c75ff0b8d27bcb7, 0x00fc392e4413b9d7, 0xef31d1fd8752020f, 0x6d5074c8e7a75dc5]),
+        BigInteger([0xe7b5ec2bfbd35ce8, 0xa99a0f8a35e37e7b, 0x312c2051a70cacc4, 0x415444dfc9407c7d]),
+        BigInteger([0x8a0719bd9fc1ac27, 0x744144bb29716a74, 0xffea9e0b541fcb9a, 0x4459ada388bb635f]),
+        BigInteger([0x25acfadbd37328ca, 0xa2157fa336ffb229, 0x432bc6f21ac4d49e, 0x60eb5b8db0894969]),
+        BigInteger([0x6a4fac5435b444fa, 0xa4c9d25df8a367da, 0xa33932bd4c0632ff, 0x038a3b43cfb507b5]),
+        BigInteger([0xbcd2a6e09538df40, 0x290e2f0c95e6fafd, 0x37b754afe7fe2f1d, 0x500b5365d11e6548]),
+        BigInteger([0x0db3e6ccbf7c975b, 0x9d463b98af4d41ec, 0x555e1ff59ab7a3e7, 0x6154be202a31f630]),
+        BigInteger([0x245f9cbc1f88b5f3, 0x5dfbb5c68ef4a333, 0xa9ac7cdbc68f208f, 0x44448a9838255605]),
+        BigInteger([0x146391f8784685ce, 0x6975fe2172dbcd2c, 0x7bfaf2b2edc848d6, 0x13c5c6b3afb23c0c]),
+        BigInteger([0x3b801e7d90f9b375, 0xaada3338524be4d6, 0x5f0b11a6bdbd3f25, 0x04063ff85adb38c4]),
+        BigInteger([0x56e7145ccb39b4a6, 0x79825deadcbe3def, 0x3e516f05f02abbe0, 0x532595f8bef5bd2d]),
+        BigInteger([0x860cb52e2d964320, 0x4c5059aa9678fcc9, 0xd92f98d7cfe87e16, 0x40329fd2d283e2d3]),
+        BigInteger([0x4526e48b11c947f9, 0xccc36140c614d3aa, 0xf2e56f1a03cd5f24, 0x57db5a4c159e0248]),
+        BigInteger([0x6ebe497bf4e11f70, 0x1533f4823c55cafc, 0x66a7f2da7eb4d017, 0x5b6be79ab07f3e81]),
+        BigInteger([0x9e468a30f2a9a5d6, 0x1fe34726ca6bcf1b, 0x54a621ae87eb73ac, 0x100a9d2ebfd61a47]),
+        BigInteger([0xa33d279ff0ccffc9, 0x41fac79f59e91972, 0x065d227fead1139b, 0x71db41abda03e055]),
+        BigInteger([0xf2df262c09458a39, 0x048cdf5b99dff177, 0x16857bc5c7cce57b, 0x043b3dbca4a915ae]),
+        BigInteger([0xf4a46fbb8dc619e5, 0x82fa97dcb8e77487, 0xaf909c5bf198f018, 0x00edba7d83a038fa]),
+        BigInteger([0xa4a6e37a36d7822a, 0xfc83a2ce8787e5f1, 0xe89553dae7ea8f80, 0x6f6d38bb2a92ff1a]),
+        BigInteger([0x1ff70724b651888b, 0xcd8166cbe56a5af4, 0xe4a992a398e1e256, 0x5705ea3686fd3b03]),
+        BigInteger([0xb4b87dd6a9d4956c, 0x6a51d0bcd1482f03, 0x7e8146f7e0dbcb1e, 0x21f4b238cccb85ae]),
+        BigInteger([0xdaf6401c4aed2336, 0xa14c0cba41de4f6a, 0xdfdc7d04fc7f2e5b, 0x6f61ff4e296cff18]),
+        BigInteger([0xbcf7e31ae8d6f162, 0x15d1c587662fc7a4, 0xb7ed5ecd71278a57, 0x329d0d9340ccecb3]),
+        BigInteger([0x30c78850dd04d52f, 0xe27f8794adaddbcb, 0x0a59f321ae22c531, 0x1958b127ba7f8ca3]),
+        BigInteger([0x1acf64e488202b65, 0x5b18b637819f004f, 0xee649cab3107fdf5, 0x27a8543dce9012a0]),
+        BigInteger([0x6ecf49952c42eff8, 0x6ec1fa834d5dfa57, 0x3ff5cf385656b15c, 0x54b80267229e031b]),
+        BigInteger([0xf3b05674aa89cfb1, 0x072f01406006b9fe, 0xce9a0dbf25667a26, 0x4d2ce4052d598374]),
+        BigInteger([0xfffffffd00000003, 0xfb38ec08fffb13fc, 0x99ad88181ce5880f, 0x5bc8f5f97cd877d8]),
+    ];
+}
+
+impl PoseidonDefaultParameters for FrParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(4, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(5, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(6, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(7, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(8, 17, 8, 31, 0),
+    ];
+    const PARAMS_OPT_FOR_WEIGHTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(3, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(4, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(5, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(6, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(7, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(8, 257, 8, 13, 0),
+    ];
+}
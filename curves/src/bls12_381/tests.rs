@@ -0,0 +1,306 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bls12_381::{
+        g1::Bls12_381G1Parameters,
+        g2::Bls12_381G2Parameters,
+        Bls12_381,
+        Fq,
+        Fq12,
+        Fq2,
+        Fq2Parameters,
+        Fq6,
+        Fq6Parameters,
+        FqParameters,
+        Fr,
+        FrParameters,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingEngine,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{
+    fp6_3over2::Fp6Parameters,
+    FftField,
+    FftParameters,
+    Field,
+    FieldParameters,
+    Fp2Parameters,
+    LegendreSymbol::*,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::BigInteger384,
+    rand::{test_rng, UniformRand},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, PointFormat},
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bls12_381_fr() {
+    for _ in 0..ITERATIONS {
+        let a: Fr = rand::random();
+        let b: Fr = rand::random();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        field_serialization_test::<Fr>();
+    }
+}
+
+#[test]
+fn test_bls12_381_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_bls12_381_fq2() {
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rand::random();
+        let b: Fq2 = rand::random();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq2>();
+}
+
+#[test]
+fn test_bls12_381_fq6() {
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rand::random();
+        let h: Fq6 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq6>();
+}
+
+#[test]
+fn test_bls12_381_fq12() {
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = rand::random();
+        let h: Fq12 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq12>();
+}
+
+#[test]
+fn test_fr_root_of_unity() {
+    assert_eq!(FrParameters::TWO_ADICITY, 32);
+    assert_eq!(Fr::multiplicative_generator().pow(FrParameters::T), Fr::two_adic_root_of_unity());
+    assert_eq!(Fr::two_adic_root_of_unity().pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(Fr::two_adic_root_of_unity().pow([1 << (FrParameters::TWO_ADICITY - 1)]), Fr::one());
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(FqParameters::TWO_ADICITY, 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_legendre() {
+    assert_eq!(QuadraticResidue, Fq::one().legendre());
+    assert_eq!(Zero, Fq::zero().legendre());
+    assert_eq!(QuadraticResidue, Fq::from_repr(BigInteger384::from(4)).unwrap().legendre());
+    assert_eq!(QuadraticNonResidue, Fq::from_repr(BigInteger384::from(2)).unwrap().legendre());
+    // -1 is a non-residue, as q = 3 mod 4.
+    assert_eq!(QuadraticNonResidue, (-Fq::one()).legendre());
+}
+
+#[test]
+fn test_fq2_legendre() {
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // u^2 = -1
+    let mut m1 = -Fq2::one();
+    assert_eq!(QuadraticResidue, m1.legendre());
+    m1 = Fq6Parameters::mul_fp2_by_nonresidue(&m1);
+    assert_eq!(QuadraticNonResidue, m1.legendre());
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = test_rng();
+
+    let nonresidue = Fq2::new(Fq2Parameters::NONRESIDUE, Fq::zero());
+    let quadratic_non_residue = Fq2::new(Fq2Parameters::QUADRATIC_NONRESIDUE.0, Fq2Parameters::QUADRATIC_NONRESIDUE.1);
+    assert_eq!(QuadraticNonResidue, quadratic_non_residue.legendre());
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(Fq2::new(Fq2Parameters::mul_fp_by_nonresidue(&a), Fq::zero()), nonresidue * Fq2::new(a, Fq::zero()));
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<Bls12_381G1Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let a: G1Projective = rand::random();
+    let b: G1Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<Bls12_381G2Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let a: G2Projective = rand::random();
+    let b: G2Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_affine_serialization() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let g1 = G1Projective::rand(&mut rng).to_affine();
+        let mut bytes = vec![];
+        g1.serialize(&mut bytes).unwrap();
+        assert_eq!(g1, G1Affine::deserialize(&mut &bytes[..]).unwrap());
+
+        let g2 = G2Projective::rand(&mut rng).to_affine();
+        let mut bytes = vec![];
+        g2.serialize(&mut bytes).unwrap();
+        assert_eq!(g2, G2Affine::deserialize(&mut &bytes[..]).unwrap());
+    }
+}
+
+#[test]
+fn test_zcash_point_encoding() {
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    // Ensure the generators match the compressed encodings used by ZCash.
+    let mut bytes = vec![];
+    G1Affine::prime_subgroup_generator().serialize_with_format(&mut bytes, PointFormat::ZcashCompressed).unwrap();
+    assert_eq!(
+        to_hex(&bytes),
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+    );
+    assert_eq!(
+        G1Affine::deserialize_with_format(&mut &bytes[..], PointFormat::ZcashCompressed).unwrap(),
+        G1Affine::prime_subgroup_generator()
+    );
+
+    let mut bytes = vec![];
+    G2Affine::prime_subgroup_generator().serialize_with_format(&mut bytes, PointFormat::ZcashCompressed).unwrap();
+    assert_eq!(
+        to_hex(&bytes),
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+         024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+    );
+    assert_eq!(
+        G2Affine::deserialize_with_format(&mut &bytes[..], PointFormat::ZcashCompressed).unwrap(),
+        G2Affine::prime_subgroup_generator()
+    );
+
+    // Ensure the point at infinity only sets the compression and infinity flags.
+    let mut bytes = vec![];
+    G1Affine::zero().serialize_with_format(&mut bytes, PointFormat::ZcashCompressed).unwrap();
+    assert_eq!(bytes[0], 0xc0);
+    assert!(bytes[1..].iter().all(|byte| *byte == 0));
+
+    let mut bytes = vec![];
+    G1Affine::zero().serialize_with_format(&mut bytes, PointFormat::ZcashUncompressed).unwrap();
+    assert_eq!(bytes[0], 0x40);
+    assert!(bytes[1..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn test_bilinearity() {
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bls12_381::pairing(sa, b);
+    let ans2 = Bls12_381::pairing(a, sb);
+    let ans3 = Bls12_381::pairing(a, b).pow(s.to_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_ne!(ans2, Fq12::one());
+    assert_ne!(ans3, Fq12::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
+}
+
+#[test]
+fn test_multi_pairing() {
+    // Ensure `e(s * a, b) * e(-a, s * b) = 1`.
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+    assert!(Bls12_381::multi_pairing([a * s, -a], [b, b * s]).is_one());
+}
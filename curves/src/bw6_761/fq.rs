@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp768,
+    Fp768Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger768 as BigInteger;
+
+pub type Fq = Fp768<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp768Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1u32;
+    /// TWO_ADIC_ROOT_OF_UNITY = -1
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xf29a000000007ab6,
+        0x8c391832e000739b,
+        0x77738a6b6870f959,
+        0xbe36179047832b03,
+        0x84f3089e56574722,
+        0xc5a3614ac0b1d984,
+        0x5c81153f4906e9fe,
+        0x4d28be3a9f55c815,
+        0xd72c1d6f77d5f5c5,
+        0x73a18e069ac04458,
+        0xf9dfaa846595555f,
+        0xd0f0a60a5be58c,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 2
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x405ffffffff0baa,
+        0xb4b04c6b1fff19ce,
+        0x3d32dc8704ff55bc,
+        0xb4d5fe641dc8fbe9,
+        0xd9d3967c3b297017,
+        0x81cccf44a4904817,
+        0x4e9b4b7fb95a720b,
+        0x46a5cffc8c5e4207,
+        0xf6acb100116390f8,
+        0x8b0914c7ce22045e,
+        0xaf503d773ecb53be,
+        0xa3eefde24fd0fb,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 744663313386281181u64;
+    /// MODULUS = 6891450384315732539396789682275657542479668912536150109513790160209623422243491736087683183289411687640864567753786613451161759120554247759349511699125301598951605099378508850372543631423596795951899700429969112842764913119068299
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xf49d00000000008b,
+        0xe6913e6870000082,
+        0x160cf8aeeaf0a437,
+        0x98a116c25667a8f8,
+        0x71dcd3dc73ebff2e,
+        0x8689c8ed12f9fd90,
+        0x3cebaff25b42304,
+        0x707ba638e584e919,
+        0x528275ef8087be41,
+        0xb926186a81d14688,
+        0xd187c94004faff3e,
+        0x122e824fb83ce0a,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 761;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7a4e800000000045,
+        0xf3489f3438000041,
+        0xb067c577578521b,
+        0x4c508b612b33d47c,
+        0x38ee69ee39f5ff97,
+        0x4344e476897cfec8,
+        0x81e75d7f92da1182,
+        0xb83dd31c72c2748c,
+        0x29413af7c043df20,
+        0x5c930c3540e8a344,
+        0x68c3e4a0027d7f9f,
+        0x9174127dc1e705,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x51f77ef127e87d,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xc686392d2d1fa659,
+        0x7b14c9b2f79484ab,
+        0x7fa1e825c1d2b459,
+        0xd6ec28f848329d88,
+        0x4afb427b73a1ed40,
+        0x972c69400d5930ae,
+        0x2c7a26bf8c995976,
+        0xac52e458c6e57af9,
+        0xac731bfa0c536dfe,
+        0x121e5c630b103f50,
+        0x8f1b0953b886cda4,
+        0xad253c2da8d807,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 7;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) // 2^S =
+    /// 3445725192157866269698394841137828771239834456268075054756895080104811711121745868043841591644705843820432283876893306725580879560277123879674755849562650799475802549689254425186271815711798397975949850214984556421382456559534149
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x7a4e800000000045,
+        0xf3489f3438000041,
+        0xb067c577578521b,
+        0x4c508b612b33d47c,
+        0x38ee69ee39f5ff97,
+        0x4344e476897cfec8,
+        0x81e75d7f92da1182,
+        0xb83dd31c72c2748c,
+        0x29413af7c043df20,
+        0x5c930c3540e8a344,
+        0x68c3e4a0027d7f9f,
+        0x9174127dc1e705,
+    ]);
+    /// (T - 1) // 2 =
+    /// 1722862596078933134849197420568914385619917228134037527378447540052405855560872934021920795822352921910216141938446653362790439780138561939837377924781325399737901274844627212593135907855899198987974925107492278210691228279767074
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xbd27400000000022,
+        0xf9a44f9a1c000020,
+        0x5833e2bbabc290d,
+        0xa62845b09599ea3e,
+        0x1c7734f71cfaffcb,
+        0x21a2723b44be7f64,
+        0x40f3aebfc96d08c1,
+        0x5c1ee98e39613a46,
+        0x14a09d7be021ef90,
+        0xae49861aa07451a2,
+        0xb461f250013ebfcf,
+        0x48ba093ee0f382,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(4, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(5, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(6, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(7, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(8, 17, 8, 31, 0),
+    ];
+    const PARAMS_OPT_FOR_WEIGHTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(3, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(4, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(5, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(6, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(7, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(8, 257, 8, 13, 0),
+    ];
+}
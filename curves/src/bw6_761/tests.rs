@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bw6_761::{
+        g1::BW6_761G1Parameters,
+        g2::BW6_761G2Parameters,
+        Fq,
+        Fq3,
+        Fq3Parameters,
+        Fq6,
+        FqParameters,
+        Fr,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+        BW6_761,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingEngine,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{
+    FftField,
+    FftParameters,
+    Field,
+    Fp3Parameters,
+    LegendreSymbol::*,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::BigInteger768,
+    rand::{test_rng, UniformRand},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bw6_761_fr() {
+    for _ in 0..ITERATIONS {
+        let a: Fr = rand::random();
+        let b: Fr = rand::random();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        field_serialization_test::<Fr>();
+    }
+}
+
+#[test]
+fn test_bw6_761_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_bw6_761_fq3() {
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rand::random();
+        let b: Fq3 = rand::random();
+        field_test(a, b);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq3>();
+}
+
+#[test]
+fn test_bw6_761_fq6() {
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rand::random();
+        let h: Fq6 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq6>();
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(FqParameters::TWO_ADICITY, 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_legendre() {
+    assert_eq!(QuadraticResidue, Fq::one().legendre());
+    assert_eq!(Zero, Fq::zero().legendre());
+    assert_eq!(QuadraticResidue, Fq::from_repr(BigInteger768::from(4)).unwrap().legendre());
+    assert_eq!(QuadraticNonResidue, Fq::from_repr(BigInteger768::from(2)).unwrap().legendre());
+    // -1 is a non-residue, as q = 3 mod 4.
+    assert_eq!(QuadraticNonResidue, (-Fq::one()).legendre());
+}
+
+#[test]
+fn test_fq3_mul_nonresidue() {
+    let mut rng = test_rng();
+
+    let nonresidue = Fq3::new(Fq3Parameters::NONRESIDUE, Fq::zero(), Fq::zero());
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(
+            Fq3::new(Fq3Parameters::mul_fp_by_nonresidue(&a), Fq::zero(), Fq::zero()),
+            nonresidue * Fq3::new(a, Fq::zero(), Fq::zero())
+        );
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<BW6_761G1Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let a: G1Projective = rand::random();
+    let b: G1Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<BW6_761G2Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let a: G2Projective = rand::random();
+    let b: G2Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_affine_serialization() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let g1 = G1Projective::rand(&mut rng).to_affine();
+        let mut bytes = vec![];
+        g1.serialize(&mut bytes).unwrap();
+        assert_eq!(g1, G1Affine::deserialize(&mut &bytes[..]).unwrap());
+
+        let g2 = G2Projective::rand(&mut rng).to_affine();
+        let mut bytes = vec![];
+        g2.serialize(&mut bytes).unwrap();
+        assert_eq!(g2, G2Affine::deserialize(&mut &bytes[..]).unwrap());
+    }
+}
+
+#[test]
+fn test_bilinearity() {
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = BW6_761::pairing(sa, b);
+    let ans2 = BW6_761::pairing(a, sb);
+    let ans3 = BW6_761::pairing(a, b).pow(s.to_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq6::one());
+    assert_ne!(ans2, Fq6::one());
+    assert_ne!(ans3, Fq6::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
+}
+
+#[test]
+fn test_multi_pairing() {
+    // Ensure `e(s * a, b) * e(-a, s * b) = 1`.
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+    assert!(BW6_761::multi_pairing([a * s, -a], [b, b * s]).is_one());
+}
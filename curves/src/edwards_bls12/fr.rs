@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::{
+    AdditionChain,
     FftParameters,
     FieldParameters,
     Fp256,
@@ -56,6 +57,34 @@ impl FieldParameters for FrParameters {
     ]);
     #[rustfmt::skip]
     const INV: u64 = 9659935179256617473u64;
+    /// An addition chain for MODULUS - 2.
+    #[rustfmt::skip]
+    const INVERSION_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 4), (6, 10), (6, 13), (7, 10), (5, 7), (6, 9), (6, 8), (6, 12),
+            (5, 4), (6, 10), (6, 12), (1, 0), (9, 5), (6, 8), (6, 6), (7, 7),
+            (7, 11), (9, 13), (5, 13), (14, 2), (5, 2), (7, 9), (3, 2), (8, 14),
+            (6, 15), (5, 8), (8, 7), (7, 8), (6, 11), (4, 7), (6, 11), (7, 10),
+            (5, 10), (5, 13), (3, 2), (6, 6), (3, 1), (9, 12), (5, 15), (5, 13),
+            (7, 15), (4, 6),
+        ],
+        final_squarings: 0,
+    });
+    /// An addition chain for (MODULUS - 1) / 2.
+    #[rustfmt::skip]
+    const LEGENDRE_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 4), (6, 10), (6, 13), (7, 10), (5, 7), (6, 9), (6, 8), (6, 12),
+            (5, 4), (6, 10), (6, 12), (1, 0), (9, 5), (6, 8), (6, 6), (7, 7),
+            (7, 11), (9, 13), (5, 13), (14, 2), (5, 2), (7, 9), (3, 2), (8, 14),
+            (6, 15), (5, 8), (8, 7), (7, 8), (6, 11), (4, 7), (6, 11), (7, 10),
+            (5, 10), (5, 13), (3, 2), (6, 6), (3, 1), (9, 12), (5, 15), (5, 13),
+            (7, 15), (3, 3),
+        ],
+        final_squarings: 0,
+    });
     /// MODULUS = 2111115437357092606062206234695386632838870926408408195193685246394721360383
     #[rustfmt::skip]
     const MODULUS: BigInteger = BigInteger([
@@ -89,6 +118,20 @@ impl FieldParameters for FrParameters {
     ]);
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 5;
+    /// An addition chain for (t - 1) / 2.
+    #[rustfmt::skip]
+    const SQRT_CHAIN: Option<AdditionChain> = Some(AdditionChain {
+        num_odd_powers: 16,
+        steps: &[
+            (0, 4), (6, 10), (6, 13), (7, 10), (5, 7), (6, 9), (6, 8), (6, 12),
+            (5, 4), (6, 10), (6, 12), (1, 0), (9, 5), (6, 8), (6, 6), (7, 7),
+            (7, 11), (9, 13), (5, 13), (14, 2), (5, 2), (7, 9), (3, 2), (8, 14),
+            (6, 15), (5, 8), (8, 7), (7, 8), (6, 11), (4, 7), (6, 11), (7, 10),
+            (5, 10), (5, 13), (3, 2), (6, 6), (3, 1), (9, 12), (5, 15), (5, 13),
+            (7, 15), (2, 1),
+        ],
+        final_squarings: 0,
+    });
     #[rustfmt::skip]
     const T: BigInteger = BigInteger([
         6678124996694371583,
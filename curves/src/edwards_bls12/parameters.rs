@@ -18,7 +18,7 @@ use crate::{
     edwards_bls12::{Fq, Fr},
     errors::GroupError,
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, Elligator2Parameters, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::BigInteger256;
@@ -55,6 +55,10 @@ impl TwistedEdwardsParameters for EdwardsParameters {
         Fr,
         BigInteger256([10836190823041854989, 14880086764632731920, 5023208332782666747, 239524813690824359,])
     );
+    /// ELLIGATOR2 = (z), with the non-square z = 11
+    const ELLIGATOR2: Option<Elligator2Parameters<Self>> = Some(Elligator2Parameters {
+        z: field!(Fq, BigInteger256([0x19beffffffffff6a, 0x761e46b21fffff64, 0x565ad035f75edf35, 0xf929a91a9d71f63])),
+    });
 
     /// Multiplication by `a` is just negation.
     /// Is `a` 1 or -1?
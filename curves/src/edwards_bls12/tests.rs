@@ -22,6 +22,7 @@ use crate::{
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
+        HashToCurve,
         MontgomeryParameters,
         ProjectiveCurve,
         TwistedEdwardsParameters,
@@ -404,3 +405,34 @@ fn test_isomorphism() {
 
     assert_eq!(fr_element, fr_element_reconstructed);
 }
+
+#[test]
+fn test_hash_to_curve() {
+    const DST: &[u8] = b"SNARKVM-V01-CS01-with-EdwardsBLS12_XMD:SHA-256_ELL2_RO_";
+
+    // Ensure Elligator 2 maps onto the curve.
+    let elligator2 = EdwardsParameters::ELLIGATOR2.unwrap();
+    let mut rng = thread_rng();
+    for _ in 0..100 {
+        let (x, y) = elligator2.map_to_curve(&Fq::rand(&mut rng));
+        assert!(EdwardsAffine::new(x, y).is_on_curve());
+    }
+
+    // Check the output against fixed test vectors.
+    let point = EdwardsAffine::hash_to_curve(b"", DST).unwrap();
+    assert_eq!(point.x.to_string(), "3386835368182508910131191553758121338195766169744380997972728071071253666232");
+    assert_eq!(point.y.to_string(), "2550330257936687614683232736574147130930283171667113554230765111628123401314");
+
+    let point = EdwardsAffine::hash_to_curve(b"abc", DST).unwrap();
+    assert_eq!(point.x.to_string(), "4838535356046471390325557974201142765697774081353171506222322333019357362737");
+    assert_eq!(point.y.to_string(), "4894404922406061073020877162156806742878246862125449025609833570659817697197");
+
+    for message in [b"".as_ref(), b"abc", b"abcdef0123456789", &[b'a'; 512]] {
+        let point = EdwardsAffine::hash_to_curve(message, DST).unwrap();
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+
+        // Ensure the domain separation tag changes the output.
+        assert_ne!(point, EdwardsAffine::hash_to_curve(message, b"SNARKVM-V01-CS01-with-another-tag").unwrap());
+    }
+}
@@ -22,6 +22,15 @@ pub enum GroupError {
     #[error("{}", _0)]
     FieldError(snarkvm_fields::FieldError),
 
+    #[error("Hash-to-curve is not supported for this curve")]
+    HashToCurveUnsupported,
+
+    #[error("Invalid domain separation tag of {} bytes", _0)]
+    InvalidDomainSeparationTag(usize),
+
+    #[error("Invalid length of {} bytes for expand_message", _0)]
+    InvalidExpandLength(usize),
+
     #[error("Invalid group element")]
     InvalidGroupElement,
 
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+};
+use serde::{Deserialize, Serialize};
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use std::marker::PhantomData;
+
+pub trait BW6Parameters: 'static {
+    const X: &'static [u64];
+    const X_IS_NEGATIVE: bool;
+    /// The loop count of the first Miller loop, `x + 1`.
+    const ATE_LOOP_COUNT_1: &'static [u64];
+    /// The loop count of the second Miller loop, `x^3 - x^2 - x`, in non-adjacent form (least significant digit first).
+    const ATE_LOOP_COUNT_2: &'static [i8];
+    /// The coefficients of the polynomials `e_0(X)` and `e_1(X)` (lowest degree first), such that
+    /// `e_0(x) + q * e_1(x)` is a multiple of `(q^2 - q + 1) / r` that is coprime to `r`.
+    const FINAL_EXPONENT_HARD_PART_COEFFS: (&'static [i64], &'static [i64]);
+    const TWIST_TYPE: TwistType;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp3Params = Self::Fp3Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+        BaseField = Self::Fp,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BW6<P: BW6Parameters>(PhantomData<fn() -> P>);
+
+type CoeffTriplet<T> = (T, T, T);
+
+impl<P: BW6Parameters> BW6<P> {
+    // Evaluate the line function at point p.
+    fn ell(f: &mut Fp6<P::Fp6Params>, coeffs: &CoeffTriplet<P::Fp>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    fn exp_by_x(mut f: Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+        f = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+        f
+    }
+
+    // Raise an element of the cyclotomic subgroup to a small signed power.
+    fn exp_by_signed(f: &Fp6<P::Fp6Params>, exponent: i64) -> Fp6<P::Fp6Params> {
+        let mut result = f.cyclotomic_exp([exponent.unsigned_abs()]);
+        if exponent < 0 {
+            result.conjugate();
+        }
+        result
+    }
+}
+
+impl<P: BW6Parameters> PairingEngine for BW6<P>
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+{
+    type Fq = P::Fp;
+    type Fqe = P::Fp;
+    type Fqk = Fp6<P::Fp6Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        // Computes the optimal ate pairing of https://eprint.iacr.org/2020/351.pdf,
+        // as the product f_{x+1,Q}(P) * frobenius(f_{x^3-x^2-x,Q}(P)).
+        let mut pairs_1 = vec![];
+        let mut pairs_2 = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs_1.push((p, q.ell_coeffs_1.iter()));
+                pairs_2.push((p, q.ell_coeffs_2.iter()));
+            }
+        }
+
+        // f_1 = f_{x+1,Q}(P)
+        let mut f_1 = Self::Fqk::one();
+
+        for i in BitIteratorBE::new_without_leading_zeros(P::ATE_LOOP_COUNT_1).skip(1) {
+            f_1.square_in_place();
+
+            for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                    Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        // f_2 = f_{x^3-x^2-x,Q}(P)
+        let mut f_2 = Self::Fqk::one();
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            if i != P::ATE_LOOP_COUNT_2.len() - 1 {
+                f_2.square_in_place();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if P::ATE_LOOP_COUNT_2[i - 1] != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                    Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        f_2.frobenius_map(1);
+
+        f_1 * f_2
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // Easy part: r = f^((q^3 - 1)(q + 1)).
+        let mut f1 = *f;
+        f1.conjugate();
+
+        match f.inverse() {
+            Some(f2) => {
+                // r = f^(q^3 - 1)
+                let mut r = f1 * f2;
+                // r = f^((q^3 - 1)(q + 1))
+                let f2 = r;
+                r.frobenius_map(1);
+                r *= &f2;
+
+                // Hard part: r^(e_0(x) + q * e_1(x)), where each `r^(x^i)` is computed once,
+                // and the `q`-power is applied with a single Frobenius map at the end.
+                let (coeffs_0, coeffs_1) = P::FINAL_EXPONENT_HARD_PART_COEFFS;
+                let mut result_0 = Self::Fqk::one();
+                let mut result_1 = Self::Fqk::one();
+                let mut r_pow = r;
+                for i in 0..coeffs_0.len().max(coeffs_1.len()) {
+                    if i > 0 {
+                        r_pow = Self::exp_by_x(r_pow);
+                    }
+                    if let Some(coeff) = coeffs_0.get(i) {
+                        result_0 *= &Self::exp_by_signed(&r_pow, *coeff);
+                    }
+                    if let Some(coeff) = coeffs_1.get(i) {
+                        result_1 *= &Self::exp_by_signed(&r_pow, *coeff);
+                    }
+                }
+                result_1.frobenius_map(1);
+
+                Some(result_0 * result_1)
+            }
+            None => None,
+        }
+    }
+}
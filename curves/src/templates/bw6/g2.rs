@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::BW6Parameters,
+        short_weierstrass_jacobian::{Affine, Projective},
+    },
+    traits::{AffineCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::{bititerator::BitIteratorBE, serialize::*, ToBytes};
+
+use std::io::{Result as IoResult, Write};
+
+pub type G2Affine<P> = Affine<<P as BW6Parameters>::G2Parameters>;
+pub type G2Projective<P> = Projective<<P as BW6Parameters>::G2Parameters>;
+type CoeffTriplet<T> = (T, T, T);
+
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(
+    Clone(bound = "P: BW6Parameters"),
+    Debug(bound = "P: BW6Parameters"),
+    PartialEq(bound = "P: BW6Parameters"),
+    Eq(bound = "P: BW6Parameters")
+)]
+pub struct G2Prepared<P: BW6Parameters> {
+    // Stores the coefficients of the line evaluations as calculated in
+    // https://eprint.iacr.org/2013/722.pdf, for the two Miller loops.
+    pub ell_coeffs_1: Vec<CoeffTriplet<P::Fp>>,
+    pub ell_coeffs_2: Vec<CoeffTriplet<P::Fp>>,
+    pub infinity: bool,
+}
+
+#[derive(Derivative)]
+#[derivative(Clone(bound = "P: BW6Parameters"), Copy(bound = "P: BW6Parameters"), Debug(bound = "P: BW6Parameters"))]
+struct G2HomProjective<P: BW6Parameters> {
+    x: P::Fp,
+    y: P::Fp,
+    z: P::Fp,
+}
+
+impl<P: BW6Parameters> Default for G2Prepared<P> {
+    fn default() -> Self {
+        Self::from_affine(G2Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+fn write_ell_coeffs<W: Write, F: ToBytes>(ell_coeffs: &[CoeffTriplet<F>], mut writer: W) -> IoResult<()> {
+    (ell_coeffs.len() as u32).write_le(&mut writer)?;
+    for coeff in ell_coeffs {
+        coeff.0.write_le(&mut writer)?;
+        coeff.1.write_le(&mut writer)?;
+        coeff.2.write_le(&mut writer)?;
+    }
+    Ok(())
+}
+
+fn read_ell_coeffs<R: Read, F: FromBytes>(mut reader: R) -> IoResult<Vec<CoeffTriplet<F>>> {
+    let ell_coeffs_len: u32 = FromBytes::read_le(&mut reader)?;
+    let mut ell_coeffs = Vec::with_capacity(ell_coeffs_len as usize);
+    for _ in 0..ell_coeffs_len {
+        let coeff_1: F = FromBytes::read_le(&mut reader)?;
+        let coeff_2: F = FromBytes::read_le(&mut reader)?;
+        let coeff_3: F = FromBytes::read_le(&mut reader)?;
+        ell_coeffs.push((coeff_1, coeff_2, coeff_3));
+    }
+    Ok(ell_coeffs)
+}
+
+impl<P: BW6Parameters> ToBytes for G2Prepared<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        write_ell_coeffs(&self.ell_coeffs_1, &mut writer)?;
+        write_ell_coeffs(&self.ell_coeffs_2, &mut writer)?;
+        self.infinity.write_le(writer)
+    }
+}
+
+impl<P: BW6Parameters> FromBytes for G2Prepared<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let ell_coeffs_1 = read_ell_coeffs(&mut reader)?;
+        let ell_coeffs_2 = read_ell_coeffs(&mut reader)?;
+        let infinity: bool = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { ell_coeffs_1, ell_coeffs_2, infinity })
+    }
+}
+
+impl<P: BW6Parameters> G2Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine<P>) -> Self {
+        let two_inv = P::Fp::one().double().inverse().unwrap();
+        if q.is_zero() {
+            return Self { ell_coeffs_1: vec![], ell_coeffs_2: vec![], infinity: true };
+        }
+
+        // Coefficients for the first Miller loop, over the bits of `x + 1`.
+        let mut r = G2HomProjective::<P> { x: q.x, y: q.y, z: P::Fp::one() };
+
+        let mut ell_coeffs_1 = vec![];
+
+        for i in BitIteratorBE::new_without_leading_zeros(P::ATE_LOOP_COUNT_1).skip(1) {
+            ell_coeffs_1.push(doubling_step::<P>(&mut r, &two_inv));
+
+            if i {
+                ell_coeffs_1.push(addition_step::<P>(&mut r, &q));
+            }
+        }
+
+        // Coefficients for the second Miller loop, over the NAF digits of `x^3 - x^2 - x`.
+        let mut r = G2HomProjective::<P> { x: q.x, y: q.y, z: P::Fp::one() };
+        let negative_q = -q;
+
+        let mut ell_coeffs_2 = Vec::with_capacity(P::ATE_LOOP_COUNT_2.len());
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            ell_coeffs_2.push(doubling_step::<P>(&mut r, &two_inv));
+
+            match P::ATE_LOOP_COUNT_2[i - 1] {
+                1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &q)),
+                -1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &negative_q)),
+                _ => {}
+            }
+        }
+
+        Self { ell_coeffs_1, ell_coeffs_2, infinity: false }
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn doubling_step<B: BW6Parameters>(r: &mut G2HomProjective<B>, two_inv: &B::Fp) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+
+    let a = r.x * r.y * two_inv;
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = B::G2Parameters::COEFF_B * (c.double() + c);
+    let f = e.double() + e;
+    let g = (b + f) * two_inv;
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_square = e.square();
+
+    r.x = a * (b - f);
+    r.y = g.square() - (e_square.double() + e_square);
+    r.z = b * h;
+    match B::TWIST_TYPE {
+        TwistType::M => (i, j.double() + j, -h),
+        TwistType::D => (-h, j.double() + j, i),
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn addition_step<B: BW6Parameters>(r: &mut G2HomProjective<B>, q: &G2Affine<B>) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+    let theta = r.y - (q.y * r.z);
+    let lambda = r.x - (q.x * r.z);
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+    r.x = lambda * h;
+    r.y = theta * (g - h) - (e * r.y);
+    r.z *= &e;
+    let j = theta * q.x - (lambda * q.y);
+
+    match B::TWIST_TYPE {
+        TwistType::M => (j, -theta, lambda),
+        TwistType::D => (lambda, -theta, j),
+    }
+}
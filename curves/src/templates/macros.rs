@@ -66,6 +66,14 @@ macro_rules! impl_sw_curve_serializer {
                 let el: Affine<P> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
                 Ok(el.into())
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let el: Affine<P> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+                Ok(el.into())
+            }
         }
 
         impl<P: $params> ConstantSerializedSize for Projective<P> {
@@ -162,6 +170,44 @@ macro_rules! impl_sw_curve_serializer {
                 }
                 Ok(p)
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let (x, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                if flags.is_infinity() {
+                    Ok(Self::zero())
+                } else {
+                    // The point is on the curve by construction; only the subgroup check is skipped.
+                    Affine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
+                        .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)
+                }
+            }
+        }
+        impl<P: $params> serde::Serialize for Affine<P> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, P: $params> serde::Deserialize<'de> for Affine<P> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::deserialize(deserializer, "affine point")
+            }
+        }
+
+        impl<P: $params> serde::Serialize for Projective<P> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, P: $params> serde::Deserialize<'de> for Projective<P> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::deserialize(deserializer, "projective point")
+            }
         }
     };
 }
@@ -219,6 +265,14 @@ macro_rules! impl_edwards_curve_serializer {
                 let el: Affine<P> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
                 Ok(el.into())
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let el: Affine<P> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+                Ok(el.into())
+            }
         }
 
         impl<P: $params> CanonicalSerialize for Affine<P> {
@@ -297,6 +351,44 @@ macro_rules! impl_edwards_curve_serializer {
                 }
                 Ok(p)
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let (x, flags): (P::BaseField, snarkvm_utilities::serialize::EdwardsFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                if x == P::BaseField::zero() {
+                    Ok(Self::zero())
+                } else {
+                    // The point is on the curve by construction; only the subgroup check is skipped.
+                    Affine::<P>::from_x_coordinate(x, flags.is_positive())
+                        .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)
+                }
+            }
+        }
+        impl<P: $params> serde::Serialize for Affine<P> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, P: $params> serde::Deserialize<'de> for Affine<P> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::deserialize(deserializer, "affine point")
+            }
+        }
+
+        impl<P: $params> serde::Serialize for Projective<P> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, P: $params> serde::Deserialize<'de> for Projective<P> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                snarkvm_utilities::serialize::CanonicalSerde::deserialize(deserializer, "projective point")
+            }
         }
     };
 }
@@ -15,9 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    errors::GroupError,
     impl_sw_curve_serializer,
     templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{hash_to_field, AffineCurve, HashToCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
 use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
@@ -34,14 +35,13 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     io::{Error, ErrorKind, Read, Result as IoResult, Write},
     ops::{Mul, Neg},
 };
 
-#[derive(Derivative, Serialize, Deserialize)]
+#[derive(Derivative)]
 #[derivative(
     Copy(bound = "P: Parameters"),
     Clone(bound = "P: Parameters"),
@@ -64,6 +64,102 @@ impl<P: Parameters> Affine<P> {
     pub fn scale_by_cofactor(&self) -> Projective<P> {
         self.mul_bits(BitIteratorBE::new(P::COFACTOR))
     }
+
+    /// Serializes `self` into `writer` using the given point format.
+    pub fn serialize_with_format<W: Write>(
+        &self,
+        writer: &mut W,
+        format: PointFormat,
+    ) -> Result<(), SerializationError> {
+        let zero = P::BaseField::zero();
+        match format {
+            PointFormat::Compressed => self.serialize(writer),
+            PointFormat::Uncompressed => self.serialize_uncompressed(writer),
+            PointFormat::ZcashCompressed => {
+                let flags = ZcashFlags {
+                    is_compressed: true,
+                    is_infinity: self.is_zero(),
+                    is_lexicographically_largest: !self.is_zero() && self.y > -self.y,
+                };
+                let x = if self.is_zero() { zero } else { self.x };
+                serialize_big_endian(&x, flags, writer)
+            }
+            PointFormat::ZcashUncompressed => {
+                let flags = ZcashFlags { is_infinity: self.is_zero(), ..Default::default() };
+                let (x, y) = if self.is_zero() { (zero, zero) } else { (self.x, self.y) };
+                serialize_big_endian(&x, flags, writer)?;
+                serialize_big_endian(&y, ZcashFlags::default(), writer)
+            }
+        }
+    }
+
+    /// Deserializes a point from `reader` using the given point format,
+    /// and ensures it is in the prime-order subgroup.
+    pub fn deserialize_with_format<R: Read>(reader: &mut R, format: PointFormat) -> Result<Self, SerializationError> {
+        let (x, flags) = match format {
+            PointFormat::Compressed => return Self::deserialize(reader),
+            PointFormat::Uncompressed => return Self::deserialize_uncompressed(reader),
+            PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed => {
+                deserialize_big_endian::<P::BaseField, ZcashFlags, R>(reader)?
+            }
+        };
+
+        // Ensure the compression flag matches the expected format.
+        if flags.is_compressed != (format == PointFormat::ZcashCompressed) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let point = if flags.is_compressed {
+            match flags.is_infinity {
+                // The point at infinity is encoded with all other bits set to zero.
+                true if x.is_zero() && !flags.is_lexicographically_largest => Self::zero(),
+                true => return Err(SerializationError::InvalidData),
+                false => Self::from_x_coordinate(x, flags.is_lexicographically_largest)
+                    .ok_or(SerializationError::InvalidData)?,
+            }
+        } else {
+            let (y, _) = deserialize_big_endian::<P::BaseField, EmptyFlags, R>(reader)?;
+            match flags.is_infinity {
+                true if x.is_zero() && y.is_zero() && !flags.is_lexicographically_largest => Self::zero(),
+                true => return Err(SerializationError::InvalidData),
+                false if !flags.is_lexicographically_largest => Self::new(x, y, false),
+                false => return Err(SerializationError::InvalidData),
+            }
+        };
+
+        if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(point)
+    }
+}
+
+/// Writes the big-endian encoding of `element` into `writer`, with the given flags in the first byte.
+fn serialize_big_endian<F: CanonicalSerializeWithFlags, W: Write>(
+    element: &F,
+    flags: ZcashFlags,
+    writer: &mut W,
+) -> Result<(), SerializationError> {
+    // Reversing the little-endian encoding places the flags in the first byte,
+    // and orders the coefficients of an extension field from the highest degree to the lowest degree.
+    let mut bytes = Vec::new();
+    element.serialize_with_flags(&mut bytes, flags)?;
+    bytes.reverse();
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads the big-endian encoding of a field element from `reader`, with flags in the first byte.
+fn deserialize_big_endian<F, Fl, R>(reader: &mut R) -> Result<(F, Fl), SerializationError>
+where
+    F: CanonicalDeserializeWithFlags + ConstantSerializedSize,
+    Fl: Flags,
+    R: Read,
+{
+    let mut bytes = vec![0u8; F::SERIALIZED_SIZE];
+    reader.read_exact(&mut bytes)?;
+    bytes.reverse();
+    F::deserialize_with_flags(&mut &bytes[..])
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -124,7 +220,7 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         // Compute x^3 + ax + b
         let x3b = P::add_b(&((x.square() * x) + P::mul_by_a(&x)));
 
-        x3b.sqrt().map(|y| {
+        x3b.sqrt_if_square().map(|y| {
             let negy = -y;
 
             let y = if (y < negy) ^ greatest { y } else { negy };
@@ -152,12 +248,30 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         output
     }
 
+    fn glv_decompose(
+        &self,
+        scalar: &Self::ScalarField,
+    ) -> Option<((Self, Self::ScalarField), (Self, Self::ScalarField))> {
+        P::GLV.as_ref().map(|glv| {
+            let ((is_k1_negative, k1), (is_k2_negative, k2)) = glv.decompose(scalar);
+            // Compute `lambda * self` with the endomorphism `(x, y) -> (beta * x, y)`.
+            let endomorphism = match self.is_zero() {
+                true => *self,
+                false => Self::new(self.x * glv.beta, self.y, false),
+            };
+            let p1 = if is_k1_negative { -*self } else { *self };
+            let p2 = if is_k2_negative { -endomorphism } else { endomorphism };
+            ((p1, k1), (p2, k2))
+        })
+    }
+
     fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
         self.scale_by_cofactor()
     }
 
     fn mul_by_cofactor_inv(&self) -> Self {
-        (*self * P::COFACTOR_INV).into()
+        // Note: This does not use the GLV endomorphism, as `self` may not be in the prime-order subgroup.
+        self.mul_bits(BitIteratorBE::new(P::COFACTOR_INV.to_repr())).into()
     }
 
     #[inline]
@@ -242,6 +356,64 @@ impl<P: Parameters> AffineCurve for Affine<P> {
             a.y = lambda * (b.x - a.x) - b.y;
         }
     }
+
+    /// Performs the second half of batch addition in-place on each pair, in reverse order.
+    /// The inverse of each denominator is recovered in a serial pass, so that the remaining
+    /// multiplications may be batched over all of the pairs.
+    #[cfg(feature = "simd")]
+    fn batch_add_loop_2_many(a: &mut [Self], b: &[Option<Self>], inversion_tmp: &mut Self::BaseField) {
+        assert_eq!(a.len(), b.len());
+
+        let mut indices = Vec::with_capacity(a.len());
+        let mut lambdas = Vec::with_capacity(a.len());
+        let mut inverses = Vec::with_capacity(a.len());
+        for (i, (a, b)) in a.iter_mut().zip(b).enumerate().rev() {
+            if let Some(b) = b {
+                if a.is_zero() {
+                    *a = *b;
+                } else if !b.is_zero() {
+                    indices.push(i);
+                    lambdas.push(a.y);
+                    inverses.push(*inversion_tmp);
+                    *inversion_tmp *= &a.x; // Remove the top layer of the denominator
+                }
+            }
+        }
+        P::BaseField::batch_mul_in_place(&mut lambdas, &inverses);
+
+        // x3 = l^2 - x1 - x2, as in `batch_add_loop_2`.
+        let mut squares = lambdas.clone();
+        P::BaseField::batch_mul_in_place(&mut squares, &lambdas);
+        let mut differences = Vec::with_capacity(indices.len());
+        for (i, square) in indices.iter().zip(squares) {
+            let (a, b) = (&mut a[*i], b[*i].unwrap());
+            a.x += &b.x.double();
+            a.x = square - a.x;
+            differences.push(b.x - a.x);
+        }
+
+        // y3 = l*(x2 - x3) - y2, as in `batch_add_loop_2`.
+        P::BaseField::batch_mul_in_place(&mut differences, &lambdas);
+        for (i, difference) in indices.iter().zip(differences) {
+            a[*i].y = difference - b[*i].unwrap().y;
+        }
+    }
+}
+
+impl<P: Parameters> HashToCurve for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<Self, GroupError> {
+        let swu = P::SWU.ok_or(GroupError::HashToCurveUnsupported)?;
+        let map_to_curve =
+            |u: &P::BaseField| swu.map_to_curve(u).map_or_else(Self::zero, |(x, y)| Self::new(x, y, false));
+
+        // Map two field elements to the curve, and clear the cofactor of their sum.
+        let u = hash_to_field::<P::BaseField>(message, dst, 2)?;
+        let point = map_to_curve(&u[0]).to_projective() + map_to_curve(&u[1]).to_projective();
+        Ok(point.to_affine().mul_by_cofactor())
+    }
 }
 
 impl<P: Parameters> ToMinimalBits for Affine<P> {
@@ -266,7 +438,11 @@ impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Projective<P>;
 
     fn mul(self, other: P::ScalarField) -> Self::Output {
-        self.mul_bits(BitIteratorBE::new(other.to_repr()))
+        // If the curve has an efficient endomorphism, use the projective GLV scalar multiplication.
+        match P::GLV.is_some() {
+            true => self.to_projective() * other,
+            false => self.mul_bits(BitIteratorBE::new(other.to_repr())),
+        }
     }
 }
 
@@ -16,7 +16,7 @@
 
 use crate::{
     templates::short_weierstrass_jacobian::Affine,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{AffineCurve, GLVParameters, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
@@ -49,6 +49,36 @@ impl<P: Parameters> Projective<P> {
     pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
+
+    ///
+    /// Returns `scalar * self`, by decomposing the scalar into `k1 + k2 * lambda` with the GLV endomorphism,
+    /// and computing `k1 * self + k2 * (lambda * self)` with a joint double-and-add.
+    ///
+    fn mul_glv(self, glv: &GLVParameters<P>, scalar: &P::ScalarField) -> Self {
+        let ((is_k1_negative, k1), (is_k2_negative, k2)) = glv.decompose(scalar);
+
+        // Compute `lambda * self` with the endomorphism `(x, y) -> (beta * x, y)`.
+        // Note: In Jacobian coordinates, this is `(X, Y, Z) -> (beta * X, Y, Z)`.
+        let endomorphism = Self::new(self.x * glv.beta, self.y, self.z);
+        let p1 = if is_k1_negative { -self } else { self };
+        let p2 = if is_k2_negative { -endomorphism } else { endomorphism };
+        let p1_plus_p2 = p1 + p2;
+
+        let mut res = Self::zero();
+        for (bit_1, bit_2) in BitIteratorBE::new(k1.to_repr())
+            .zip(BitIteratorBE::new(k2.to_repr()))
+            .skip_while(|(bit_1, bit_2)| !bit_1 && !bit_2)
+        {
+            res.double_in_place();
+            match (bit_1, bit_2) {
+                (true, true) => res += p1_plus_p2,
+                (true, false) => res += p1,
+                (false, true) => res += p2,
+                (false, false) => (),
+            }
+        }
+        res
+    }
 }
 
 impl<P: Parameters> Display for Projective<P> {
@@ -511,6 +541,11 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
+        // If the curve has an efficient endomorphism, perform a GLV scalar multiplication.
+        if let Some(glv) = &P::GLV {
+            return self.mul_glv(glv, &other);
+        }
+
         let mut res = Self::zero();
         for i in BitIteratorBE::new_without_leading_zeros(other.to_repr()) {
             res.double_in_place();
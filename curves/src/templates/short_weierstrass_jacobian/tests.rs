@@ -15,19 +15,27 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{Affine, Projective};
-use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
+use crate::{
+    traits::tests_group::{curve_serde_test, curve_unchecked_deserialization_test},
+    AffineCurve,
+    ProjectiveCurve,
+    ShortWeierstrassParameters,
+};
 use snarkvm_fields::Zero;
 use snarkvm_utilities::{
     io::Cursor,
     rand::{test_rng, UniformRand},
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, PointFormat},
 };
 
 pub const ITERATIONS: usize = 10;
 
 pub fn sw_tests<P: ShortWeierstrassParameters>() {
     sw_curve_serialization_test::<P>();
+    curve_serde_test::<Projective<P>>();
+    curve_unchecked_deserialization_test::<Projective<P>>();
     sw_from_random_bytes::<P>();
+    sw_point_format_test::<P>();
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
@@ -133,3 +141,41 @@ pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>() {
         }
     }
 }
+
+pub fn sw_point_format_test<P: ShortWeierstrassParameters>() {
+    let formats = [
+        PointFormat::Compressed,
+        PointFormat::Uncompressed,
+        PointFormat::ZcashCompressed,
+        PointFormat::ZcashUncompressed,
+    ];
+
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng).to_affine();
+        for point in [a, -a, Affine::<P>::zero()] {
+            for format in formats {
+                let mut serialized = vec![];
+                point.serialize_with_format(&mut serialized, format).unwrap();
+                match format {
+                    PointFormat::Compressed | PointFormat::ZcashCompressed => {
+                        assert_eq!(serialized.len(), point.serialized_size())
+                    }
+                    PointFormat::Uncompressed | PointFormat::ZcashUncompressed => {
+                        assert_eq!(serialized.len(), point.uncompressed_size())
+                    }
+                }
+
+                let b = Affine::<P>::deserialize_with_format(&mut &serialized[..], format).unwrap();
+                assert_eq!(point, b);
+            }
+
+            // Ensure the ZCash encodings reject the opposite compression flag.
+            let mut serialized = vec![];
+            point.serialize_with_format(&mut serialized, PointFormat::ZcashCompressed).unwrap();
+            serialized[0] ^= 1 << 7;
+            assert!(Affine::<P>::deserialize_with_format(&mut &serialized[..], PointFormat::ZcashCompressed).is_err());
+        }
+    }
+}
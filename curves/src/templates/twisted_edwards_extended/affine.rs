@@ -15,15 +15,18 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    errors::GroupError,
     impl_edwards_curve_serializer,
     templates::twisted_edwards_extended::Projective,
-    traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+    traits::{hash_to_field, AffineCurve, HashToCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
 use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
     serialize::*,
+    Choice,
+    ConditionallySelectable,
     FromBytes,
     ToBits,
     ToBytes,
@@ -34,14 +37,13 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     io::{Read, Result as IoResult, Write},
     ops::{Mul, Neg},
 };
 
-#[derive(Derivative, Serialize, Deserialize)]
+#[derive(Derivative)]
 #[derivative(
     Copy(bound = "P: Parameters"),
     Clone(bound = "P: Parameters"),
@@ -66,6 +68,19 @@ impl<P: Parameters> Affine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
 impl<P: Parameters> PartialEq<Projective<P>> for Affine<P> {
     fn eq(&self, other: &Projective<P>) -> bool {
         other.eq(self)
@@ -119,7 +134,7 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         let numerator = P::mul_by_a(&x2) - one;
         let denominator = P::COEFF_D * x2 - one;
         let y2 = denominator.inverse().map(|denom| denom * numerator);
-        y2.and_then(|y2| y2.sqrt()).map(|y| {
+        y2.and_then(|y2| y2.sqrt_if_square()).map(|y| {
             let negy = -y;
             let y = if (y < negy) ^ greatest { y } else { negy };
             Self::new(x, y)
@@ -139,7 +154,7 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         let numerator = one - y2;
         let denominator = P::mul_by_a(&one) - (P::COEFF_D * y2);
         let x2 = denominator.inverse().map(|denom| denom * numerator);
-        x2.and_then(|x2| x2.sqrt()).map(|x| {
+        x2.and_then(|x2| x2.sqrt_if_square()).map(|x| {
             let negx = -x;
             let x = if (x < negx) ^ greatest { x } else { negx };
             Self::new(x, y)
@@ -229,6 +244,24 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> HashToCurve for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<Self, GroupError> {
+        let elligator2 = P::ELLIGATOR2.ok_or(GroupError::HashToCurveUnsupported)?;
+        let map_to_curve = |u: &P::BaseField| {
+            let (x, y) = elligator2.map_to_curve(u);
+            Self::new(x, y)
+        };
+
+        // Map two field elements to the curve, and clear the cofactor of their sum.
+        let u = hash_to_field::<P::BaseField>(message, dst, 2)?;
+        let point = map_to_curve(&u[0]).to_projective() + map_to_curve(&u[1]).to_projective();
+        Ok(point.to_affine().mul_by_cofactor())
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         self.x.to_bits_le()
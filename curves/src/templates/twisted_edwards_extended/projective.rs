@@ -19,7 +19,16 @@ use crate::{
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    rand::UniformRand,
+    serialize::*,
+    Choice,
+    ConditionallySelectable,
+    FromBytes,
+    IterBits,
+    ToBytes,
+};
 
 use rand::{
     distributions::{Distribution, Standard},
@@ -52,6 +61,45 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: Parameters> Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    /// Returns `self * scalar`, in time independent of the scalar.
+    ///
+    /// This is a Montgomery ladder over all `MODULUS_BITS` bits of the scalar, where each step
+    /// performs one addition and one doubling, and the bit only selects the operands in constant time.
+    /// As the addition formula is unified, the ladder does not branch on the identity either.
+    /// Use this in place of `Mul` for secret scalars, such as private keys and nonces.
+    pub fn ct_mul(&self, scalar: &P::ScalarField) -> Self {
+        let (mut r0, mut r1) = (Self::zero(), *self);
+        for bit in scalar.iter_bits_be() {
+            let choice = Choice::from(bit as u8);
+            // Maintain the invariant `r1 == r0 + self`, where `r0` is the product of the bits so far.
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+            r1 += &r0;
+            r0.double_in_place();
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+        }
+        r0
+    }
+}
+
 impl<P: Parameters> Display for Projective<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self.to_affine())
@@ -21,10 +21,18 @@ use snarkvm_utilities::{
     rand::{test_rng, UniformRand},
     serialize::{CanonicalDeserialize, CanonicalSerialize},
     to_bytes_le,
+    Choice,
+    ConditionallySelectable,
     ToBytes,
 };
 
-use crate::traits::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
+use crate::traits::{
+    tests_group::{curve_serde_test, curve_unchecked_deserialization_test},
+    AffineCurve,
+    MontgomeryParameters,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 
 pub const ITERATIONS: usize = 10;
@@ -47,8 +55,50 @@ where
     P::BaseField: PrimeField,
 {
     edwards_curve_serialization_test::<P>();
+    curve_serde_test::<Projective<P>>();
+    curve_unchecked_deserialization_test::<Projective<P>>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_conditional_select_test::<P>();
+    edwards_ct_mul_test::<P>();
+}
+
+pub fn edwards_conditional_select_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..10 {
+        let a = Projective::<P>::rand(&mut rng);
+        let b = Projective::<P>::rand(&mut rng);
+        assert_eq!(a, Projective::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, Projective::conditional_select(&a, &b, Choice::from(1)));
+
+        let (a, b) = (a.to_affine(), b.to_affine());
+        assert_eq!(a, Affine::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, Affine::conditional_select(&a, &b, Choice::from(1)));
+    }
+}
+
+pub fn edwards_ct_mul_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng);
+        let scalar = P::ScalarField::rand(&mut rng);
+        assert_eq!(a * scalar, a.ct_mul(&scalar));
+    }
+
+    // Check the edge cases of the scalar and the base.
+    let a = Projective::<P>::rand(&mut rng);
+    assert!(a.ct_mul(&P::ScalarField::zero()).is_zero());
+    assert_eq!(a, a.ct_mul(&P::ScalarField::one()));
+    assert_eq!(-a, a.ct_mul(&-P::ScalarField::one()));
+    assert!(Projective::<P>::zero().ct_mul(&P::ScalarField::rand(&mut rng)).is_zero());
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::ModelParameters;
+use snarkvm_fields::PrimeField;
+
+///
+/// The parameters of the GLV endomorphism `(x, y) -> (beta * x, y)` on a short Weierstrass curve with `a = 0`,
+/// which acts on the prime-order subgroup as multiplication by `lambda`.
+///
+/// The endomorphism decomposes a scalar multiplication `k * P` into `k1 * P + k2 * (lambda * P)`,
+/// where `k1` and `k2` are half the bit length of `k`, as in
+/// [GLV01](https://www.iacr.org/archive/crypto2001/21390189.pdf).
+///
+pub struct GLVParameters<P: ModelParameters + ?Sized> {
+    /// A primitive cube root of unity in the base field.
+    pub beta: P::BaseField,
+    /// A primitive cube root of unity in the scalar field, such that `(beta * x, y) = lambda * (x, y)`.
+    pub lambda: P::ScalarField,
+    /// A short basis `[(a1, b1), (a2, b2)]` of the lattice `{(u, v) : u + v * lambda = 0 mod r}`,
+    /// where `a1 * b2 - a2 * b1 = r`.
+    pub basis: [(P::ScalarField, P::ScalarField); 2],
+    /// The little-endian limbs of `round(2^256 * b2 / r)` and `round(-2^256 * b1 / r)`, which must be nonnegative.
+    pub rounded_quotients: [[u64; 4]; 2],
+}
+
+impl<P: ModelParameters + ?Sized> GLVParameters<P> {
+    ///
+    /// Returns the decomposition of the given scalar `k` into `k1 + k2 * lambda`,
+    /// as `((is_k1_negative, |k1|), (is_k2_negative, |k2|))`.
+    ///
+    pub fn decompose(&self, scalar: &P::ScalarField) -> ((bool, P::ScalarField), (bool, P::ScalarField)) {
+        let [(a1, b1), (a2, b2)] = self.basis;
+
+        // Compute the closest lattice vector `c1 * (a1, b1) + c2 * (a2, b2)` to `(k, 0)`,
+        // where `c1 := round(k * b2 / r)` and `c2 := round(-k * b1 / r)`.
+        let k = scalar.to_repr();
+        let c1 = P::ScalarField::from(mul_shift_round(k.as_ref(), &self.rounded_quotients[0]));
+        let c2 = P::ScalarField::from(mul_shift_round(k.as_ref(), &self.rounded_quotients[1]));
+
+        // Compute `(k1, k2) := (k, 0) - c1 * (a1, b1) - c2 * (a2, b2)`.
+        let k1 = *scalar - c1 * a1 - c2 * a2;
+        let k2 = -(c1 * b1 + c2 * b2);
+
+        // Return the absolute value and sign of each coefficient.
+        let abs = |k: P::ScalarField| match k.to_repr() > P::ScalarField::modulus_minus_one_div_two() {
+            true => (true, -k),
+            false => (false, k),
+        };
+        (abs(k1), abs(k2))
+    }
+}
+
+/// Returns `round(a * b / 2^256)` for the given little-endian limbs, where the result is less than `2^128`.
+fn mul_shift_round(a: &[u64], b: &[u64; 4]) -> u128 {
+    // Compute the product `a * b`.
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, a_i) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b_j) in b.iter().enumerate() {
+            let sum = (*a_i as u128) * (*b_j as u128) + product[i + j] as u128 + carry;
+            product[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+
+    // Add `2^255` to round to the nearest integer.
+    let mut carry = 1u128 << 63;
+    for limb in product[3..].iter_mut() {
+        let sum = *limb as u128 + carry;
+        *limb = sum as u64;
+        carry = sum >> 64;
+    }
+
+    // Shift right by 256 bits.
+    debug_assert!(product[6..].iter().all(|limb| *limb == 0), "The GLV coefficient exceeds 128 bits");
+    (product[4] as u128) | ((product[5] as u128) << 64)
+}
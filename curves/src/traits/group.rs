@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::PairingEngine;
+use crate::{Elligator2Parameters, GLVParameters, PairingEngine, SWUParameters};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{rand::UniformRand, serialize::*, FromBytes, ToBytes, ToMinimalBits};
 
@@ -55,6 +55,8 @@ pub trait ProjectiveCurve:
     + for<'a> SubAssign<&'a Self>
     + PartialEq<Self::Affine>
     + Sized
+    + Serialize
+    + DeserializeOwned
     + CanonicalSerialize
     + ConstantSerializedSize
     + CanonicalDeserialize
@@ -80,6 +82,32 @@ pub trait ProjectiveCurve:
         v.into_iter().map(|v| v.into()).collect()
     }
 
+    /// Normalizes a slice of projective elements in parallel, by splitting it
+    /// into one chunk per thread, where each chunk shares a single inversion.
+    fn batch_normalization_par(v: &mut [Self]) {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            let chunk_size = (v.len() + rayon::current_num_threads() - 1) / rayon::current_num_threads();
+            v.par_chunks_mut(core::cmp::max(chunk_size, 1)).for_each(Self::batch_normalization);
+        }
+        #[cfg(not(feature = "parallel"))]
+        Self::batch_normalization(v);
+    }
+
+    /// Normalizes a slice of projective elements in parallel and outputs a vector
+    /// containing the affine equivalents.
+    fn batch_normalization_into_affine_par(mut v: Vec<Self>) -> Vec<Self::Affine> {
+        Self::batch_normalization_par(&mut v);
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            v.into_par_iter().map(|v| v.into()).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        v.into_iter().map(|v| v.into()).collect()
+    }
+
     /// Checks if the point is already "normalized" so that
     /// cheap affine conversion is possible.
     #[must_use]
@@ -176,6 +204,16 @@ pub trait AffineCurve:
     /// an integer.
     fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> Self::Projective;
 
+    /// Returns the decomposition of `scalar * self` into `k1 * P1 + k2 * P2` as `((P1, k1), (P2, k2))`,
+    /// where `k1` and `k2` are half the bit length of `scalar`, if the curve has an efficient endomorphism.
+    #[allow(clippy::type_complexity)]
+    fn glv_decompose(
+        &self,
+        _scalar: &Self::ScalarField,
+    ) -> Option<((Self, Self::ScalarField), (Self, Self::ScalarField))> {
+        None
+    }
+
     /// Multiply this element by the cofactor.
     #[must_use]
     fn mul_by_cofactor(&self) -> Self {
@@ -212,6 +250,16 @@ pub trait AffineCurve:
 
     /// Performs the second half of batch addition in-place.
     fn batch_add_loop_2(a: &mut Self, b: Self, inversion_tmp: &mut Self::BaseField);
+
+    /// Performs the second half of batch addition in-place on each pair, in reverse order,
+    /// where a pair without a `b` is left unchanged.
+    fn batch_add_loop_2_many(a: &mut [Self], b: &[Option<Self>], inversion_tmp: &mut Self::BaseField) {
+        for (a, b) in a.iter_mut().rev().zip(b.iter().rev()) {
+            if let Some(b) = b {
+                Self::batch_add_loop_2(a, *b, inversion_tmp);
+            }
+        }
+    }
 }
 
 pub trait PairingCurve: AffineCurve {
@@ -251,6 +299,10 @@ pub trait ShortWeierstrassParameters: ModelParameters {
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// The GLV endomorphism of the curve, if the curve has one.
+    const GLV: Option<GLVParameters<Self>> = None;
+    /// The simplified SWU map used to hash to the curve, if the curve supports one.
+    const SWU: Option<SWUParameters<Self>> = None;
 
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
@@ -273,6 +325,8 @@ pub trait TwistedEdwardsParameters: Copy + Clone + Debug + Default + PartialEq +
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// The Elligator 2 map used to hash to the curve, if the curve supports one.
+    const ELLIGATOR2: Option<Elligator2Parameters<Self>> = None;
 
     type MontgomeryParameters: MontgomeryParameters<BaseField = Self::BaseField>;
 
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::GroupError,
+    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::BigInteger;
+
+use sha2::{Digest, Sha256};
+
+/// The output size of SHA-256 in bytes.
+const SHA256_OUTPUT_SIZE: usize = 32;
+/// The input block size of SHA-256 in bytes.
+const SHA256_BLOCK_SIZE: usize = 64;
+/// The target security level in bits, used to derive the length of each uniform byte string in `hash_to_field`.
+const SECURITY_PARAMETER: usize = 128;
+
+///
+/// Hashes arbitrary messages to points in the prime-order subgroup of a curve, following the random oracle
+/// construction `hash_to_curve` of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+///
+pub trait HashToCurve: AffineCurve {
+    ///
+    /// Returns a point in the prime-order subgroup, derived from the given `message`
+    /// under the given domain separation tag `dst`.
+    ///
+    /// Distinct protocols (and distinct uses within a protocol) must use distinct tags.
+    ///
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<Self, GroupError>;
+}
+
+///
+/// The parameters of the simplified Shallue-van de Woestijne-Ulas map (Section 6.6.2 of RFC 9380),
+/// applied to an isogenous curve `y^2 = x^3 + a * x + b` with `a * b != 0` (Section 6.6.3 of RFC 9380).
+///
+/// The isogeny from the isogenous curve to the target curve is given by the rational maps
+/// `x = x_numerator(x') / x_denominator(x')` and `y = y' * y_numerator(x') / y_denominator(x')`.
+///
+pub struct SWUParameters<P: ModelParameters + ?Sized> {
+    /// The coefficient `a` of the isogenous curve.
+    pub coeff_a: P::BaseField,
+    /// The coefficient `b` of the isogenous curve.
+    pub coeff_b: P::BaseField,
+    /// A non-square `z` such that `g(x) - z` is irreducible and `g(b / (z * a))` is square,
+    /// where `g(x) = x^3 + a * x + b`.
+    pub z: P::BaseField,
+    /// The coefficients of the isogeny map, from the lowest degree to the highest degree.
+    pub x_numerator: &'static [P::BaseField],
+    pub x_denominator: &'static [P::BaseField],
+    pub y_numerator: &'static [P::BaseField],
+    pub y_denominator: &'static [P::BaseField],
+}
+
+impl<P: ModelParameters + ?Sized> SWUParameters<P>
+where
+    P::BaseField: PrimeField,
+{
+    ///
+    /// Returns the affine coordinates of the image of `u` on the target curve,
+    /// or `None` if `u` maps to the point at infinity.
+    ///
+    pub fn map_to_curve(&self, u: &P::BaseField) -> Option<(P::BaseField, P::BaseField)> {
+        let (a, b, z) = (self.coeff_a, self.coeff_b, self.z);
+        let g = |x: P::BaseField| x.square() * x + a * x + b;
+
+        // Compute `x1 = (-b / a) * (1 + 1 / (z^2 * u^4 + z * u^2))`, or `b / (z * a)` if the denominator is zero.
+        let z_u2 = z * u.square();
+        let x1 = match (z_u2.square() + z_u2).inverse() {
+            Some(tv1) => -b * a.inverse()? * (P::BaseField::one() + tv1),
+            None => b * (z * a).inverse()?,
+        };
+
+        // Exactly one of `g(x1)` and `g(x2)` is square, where `x2 = z * u^2 * x1`.
+        let (x, mut y) = match g(x1).sqrt() {
+            Some(y1) => (x1, y1),
+            None => {
+                let x2 = z_u2 * x1;
+                (x2, g(x2).sqrt()?)
+            }
+        };
+
+        // Ensure the sign of `y` matches the sign of `u`.
+        if sgn0(u) != sgn0(&y) {
+            y = -y;
+        }
+
+        // Apply the isogeny to the target curve.
+        let x_denominator = evaluate(self.x_denominator, &x).inverse()?;
+        let y_denominator = evaluate(self.y_denominator, &x).inverse()?;
+        Some((evaluate(self.x_numerator, &x) * x_denominator, y * evaluate(self.y_numerator, &x) * y_denominator))
+    }
+}
+
+///
+/// The parameters of the Elligator 2 map to a twisted Edwards curve (Sections 6.7.1 and 6.8.2 of RFC 9380),
+/// which maps to the birationally equivalent Montgomery curve `K * t^2 = s^3 + J * s^2 + s`.
+///
+pub struct Elligator2Parameters<P: ModelParameters + ?Sized> {
+    /// A non-square `z` in the base field.
+    pub z: P::BaseField,
+}
+
+impl<P: TwistedEdwardsParameters> Elligator2Parameters<P>
+where
+    P::BaseField: PrimeField,
+{
+    ///
+    /// Returns the affine coordinates of the image of `u` on the twisted Edwards curve.
+    ///
+    pub fn map_to_curve(&self, u: &P::BaseField) -> (P::BaseField, P::BaseField) {
+        let j = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_A;
+        let k = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_B;
+
+        // Map to the curve `t^2 = s^3 + (J / K) * s^2 + s / K^2`.
+        let k_inv = k.inverse().expect("the Montgomery coefficient B must be nonzero");
+        let j_over_k = j * k_inv;
+        let g = |x: P::BaseField| (x.square() * x) + (j_over_k * x.square()) + (x * k_inv.square());
+
+        // Compute `x1 = -(J / K) / (1 + z * u^2)`, or `-(J / K)` if the result is zero.
+        let x1 = -j_over_k * (P::BaseField::one() + self.z * u.square()).inverse().unwrap_or_else(P::BaseField::zero);
+        let x1 = if x1.is_zero() { -j_over_k } else { x1 };
+
+        // Exactly one of `g(x1)` and `g(x2)` is square, where `x2 = -x1 - J / K`.
+        let (x, y) = match g(x1).sqrt() {
+            Some(y1) => (x1, if sgn0(&y1) { y1 } else { -y1 }),
+            None => {
+                let x2 = -x1 - j_over_k;
+                let y2 = g(x2).sqrt().expect("Elligator 2 requires a non-square z");
+                (x2, if sgn0(&y2) { -y2 } else { y2 })
+            }
+        };
+
+        // Scale to the Montgomery curve `K * t^2 = s^3 + J * s^2 + s`.
+        let (s, t) = (x * k, y * k);
+
+        // Apply the rational map to the twisted Edwards curve, sending the exceptional cases to the identity.
+        match (t.inverse(), (s + P::BaseField::one()).inverse()) {
+            (Some(t_inv), Some(s_plus_one_inv)) => (s * t_inv, (s - P::BaseField::one()) * s_plus_one_inv),
+            _ => (P::BaseField::zero(), P::BaseField::one()),
+        }
+    }
+}
+
+///
+/// Returns `len_in_bytes` uniformly random bytes derived from the given `message`
+/// and domain separation tag `dst`, using `expand_message_xmd` with SHA-256 (Section 5.3.1 of RFC 9380).
+///
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, GroupError> {
+    // Ensure the domain separation tag is at most 255 bytes.
+    if dst.len() > 255 {
+        return Err(GroupError::InvalidDomainSeparationTag(dst.len()));
+    }
+    // Ensure the requested length requires at most 255 blocks, and fits in two bytes.
+    let ell = (len_in_bytes + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(GroupError::InvalidExpandLength(len_in_bytes));
+    }
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_SIZE])
+        .chain_update(message)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = Sha256::new().chain_update(&b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_SIZE);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    for i in 2..=ell {
+        let xor: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new().chain_update(xor).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+///
+/// Returns `count` field elements derived from the given `message` and domain separation tag `dst`
+/// (Section 5.2 of RFC 9380).
+///
+pub fn hash_to_field<F: PrimeField>(message: &[u8], dst: &[u8], count: usize) -> Result<Vec<F>, GroupError> {
+    // Each element is derived from `ceil((ceil(log2(p)) + k) / 8)` bytes, to ensure a negligible bias.
+    let length = (F::size_in_bits() + SECURITY_PARAMETER + 7) / 8;
+    let uniform_bytes = expand_message_xmd(message, dst, count * length)?;
+    Ok(uniform_bytes.chunks(length).map(F::from_bytes_be_mod_order).collect())
+}
+
+/// Returns the sign of the given field element, as its parity (Section 4.1 of RFC 9380).
+fn sgn0<F: PrimeField>(element: &F) -> bool {
+    element.to_repr().is_odd()
+}
+
+/// Evaluates the polynomial with the given coefficients (lowest degree first) at `x`, using Horner's rule.
+fn evaluate<F: Field>(coefficients: &[F], x: &F) -> F {
+    coefficients.iter().rev().fold(F::zero(), |result, coefficient| result * x + coefficient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // Test vectors from Appendix K.1 of RFC 9380.
+        let expected = "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235";
+        assert_eq!(hex(&expand_message_xmd(b"", DST, 0x20).unwrap()), expected);
+
+        let expected = "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615";
+        assert_eq!(hex(&expand_message_xmd(b"abc", DST, 0x20).unwrap()), expected);
+
+        let expected = "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced";
+        assert_eq!(hex(&expand_message_xmd(b"", DST, 0x80).unwrap()), expected);
+    }
+
+    #[test]
+    fn test_expand_message_xmd_invalid_inputs() {
+        assert!(expand_message_xmd(b"abc", &[0u8; 256], 32).is_err());
+        assert!(expand_message_xmd(b"abc", DST, 255 * 32 + 1).is_err());
+        assert_eq!(expand_message_xmd(b"abc", DST, 255 * 32).unwrap().len(), 255 * 32);
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
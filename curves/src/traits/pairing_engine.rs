@@ -75,6 +75,20 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
         Self::final_exponentiation(&Self::miller_loop(i)).unwrap()
     }
 
+    /// Computes the product of the pairings of the given G1 and G2 elements,
+    /// with a single Miller loop and a single final exponentiation.
+    #[must_use]
+    fn multi_pairing<G1, G2>(g1s: impl IntoIterator<Item = G1>, g2s: impl IntoIterator<Item = G2>) -> Self::Fqk
+    where
+        G1: Into<Self::G1Affine>,
+        G2: Into<Self::G2Affine>,
+    {
+        let g1s = g1s.into_iter().map(|g1| g1.into().prepare()).collect::<Vec<_>>();
+        let g2s = g2s.into_iter().map(|g2| g2.into().prepare()).collect::<Vec<_>>();
+        assert_eq!(g1s.len(), g2s.len(), "Mismatched number of G1 and G2 elements in the multi-pairing");
+        Self::product_of_pairings(g1s.iter().zip(g2s.iter()))
+    }
+
     /// Performs multiple pairing operations
     #[must_use]
     fn pairing<G1, G2>(p: G1, q: G2) -> Self::Fqk
@@ -14,11 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use snarkvm_fields::{
+    traits::{FftParameters, FieldParameters},
+    FftField,
+    Field,
+    LegendreSymbol,
+    PrimeField,
+    SquareRootField,
+};
 use snarkvm_utilities::{
+    biginteger::BigInteger,
     io::Cursor,
     rand::test_rng,
     serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    Choice,
+    ToBits,
 };
 
 use rand::Rng;
@@ -92,6 +102,25 @@ fn random_multiplication_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_multiplication_tests<F: Field, R: Rng>(rng: &mut R) {
+    // Check lengths on either side of a multiple of the vector width.
+    for length in [0, 1, 3, 4, 5, 8, 13, 100] {
+        let a: Vec<F> = (0..length).map(|_| F::rand(rng)).collect();
+        let b: Vec<F> = (0..length).map(|_| F::rand(rng)).collect();
+
+        let mut batched = a.clone();
+        F::batch_mul_in_place(&mut batched, &b);
+
+        let expected: Vec<F> = a.iter().zip(&b).map(|(a, b)| *a * b).collect();
+        assert_eq!(batched, expected);
+    }
+
+    // Check the extremal elements.
+    let mut batched = vec![-F::one(), -F::one(), F::zero(), F::one()];
+    F::batch_mul_in_place(&mut batched, &[-F::one(), F::one(), -F::one(), -F::one()]);
+    assert_eq!(batched, vec![F::one(), -F::one(), F::zero(), -F::one()]);
+}
+
 fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     assert!(F::zero().inverse().is_none());
 
@@ -171,6 +200,65 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_serde_tests<F: PrimeField>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+
+        // Human-readable formats use the big-endian hex representation, and also accept the decimal string.
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, format!("\"0x{}\"", format!("{:?}", a.to_repr()).to_lowercase()));
+        assert_eq!(a, serde_json::from_str(&json).unwrap());
+        assert_eq!(a, serde_json::from_str(&format!("\"{}\"", a)).unwrap());
+    }
+
+    assert_eq!(F::one(), serde_json::from_str("\"0x01\"").unwrap());
+    assert!(serde_json::from_str::<F>("\"0x1\"").is_err());
+    assert!(serde_json::from_str::<F>(&format!("\"0x{:?}\"", F::Parameters::MODULUS)).is_err());
+}
+
+fn random_from_bits_tests<F: PrimeField>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        assert_eq!(a, F::from_bits_le(&a.to_bits_le()).unwrap());
+        assert_eq!(a, F::from_bits_be(&a.to_bits_be()).unwrap());
+
+        // The bit iterators yield the `MODULUS_BITS` least-significant bits of the canonical representation.
+        let mut expected = a.to_repr().to_bits_le();
+        expected.truncate(F::size_in_bits());
+        assert_eq!(expected, a.iter_bits_le().collect::<Vec<_>>());
+        expected.reverse();
+        assert_eq!(expected, a.iter_bits_be().collect::<Vec<_>>());
+    }
+
+    // Non-canonical values and oversized inputs are rejected.
+    let modulus = F::Parameters::MODULUS.to_bits_le();
+    assert!(F::from_bits_le(&modulus).is_err());
+    assert!(F::from_bits_le(&vec![false; modulus.len() + 1]).is_err());
+}
+
+fn random_constant_time_tests<F: PrimeField>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        let b = F::rand(&mut rng);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_ne(&a)));
+        assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+        assert_eq!(a, F::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, F::conditional_select(&a, &b, Choice::from(1)));
+
+        let mut c = a;
+        c.zeroize();
+        assert!(c.is_zero());
+    }
+}
+
 fn random_string_tests<F: PrimeField>() {
     let mut rng = test_rng();
 
@@ -211,6 +299,33 @@ fn random_string_tests<F: PrimeField>() {
     }
 }
 
+fn random_addition_chain_tests<F: PrimeField>() {
+    let mut rng = test_rng();
+
+    // MODULUS - 2
+    let mut modulus_minus_two = F::Parameters::MODULUS;
+    modulus_minus_two.sub_noborrow(&F::BigInteger::from(2));
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        if let Some(chain) = F::Parameters::INVERSION_CHAIN {
+            assert_eq!(chain.exponentiate(&a), a.pow(modulus_minus_two));
+        }
+        if let Some(chain) = F::Parameters::LEGENDRE_CHAIN {
+            assert_eq!(chain.exponentiate(&a), a.pow(F::Parameters::MODULUS_MINUS_ONE_DIV_TWO));
+        }
+        if let Some(chain) = F::Parameters::SQRT_CHAIN {
+            assert_eq!(chain.exponentiate(&a), a.pow(F::Parameters::T_MINUS_ONE_DIV_TWO));
+        }
+        assert_eq!(a.inverse_fast(), a.inverse());
+        if !a.is_zero() {
+            assert_eq!(a.pow(modulus_minus_two), a.inverse().unwrap());
+            assert_eq!(a * a.inverse_fast().unwrap(), F::one());
+        }
+    }
+    assert!(F::zero().inverse_fast().is_none());
+}
+
 fn random_sqrt_tests<F: SquareRootField>() {
     let mut rng = test_rng();
 
@@ -238,6 +353,17 @@ fn random_sqrt_tests<F: SquareRootField>() {
 
         c += &F::one();
     }
+
+    // Ensure `sqrt_if_square` agrees with the Legendre symbol, for residues and non-residues alike.
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        match a.legendre() {
+            LegendreSymbol::QuadraticNonResidue => assert!(a.sqrt_if_square().is_none()),
+            _ => assert_eq!(a.sqrt_if_square().unwrap().square(), a),
+        }
+        assert_eq!(a.sqrt_if_square(), a.sqrt());
+    }
+    assert_eq!(F::zero().sqrt_if_square(), Some(F::zero()));
 }
 
 #[allow(clippy::eq_op)]
@@ -308,6 +434,7 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_addition_tests::<F, _>(&mut rng);
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
+    random_batch_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
@@ -374,7 +501,22 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str("2").ok().unwrap(), two);
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
+    // Ensure the precomputed powers of the 2-adic root of unity are consistent,
+    // noting each power is in Montgomery form, i.e. scaled by `R`.
+    let powers = F::Parameters::TWO_ADIC_ROOT_OF_UNITY_POWERS;
+    assert!(powers.is_empty() || powers.len() == F::Parameters::TWO_ADICITY as usize);
+    let r_inverse = F::from_repr(F::Parameters::R).unwrap().inverse().unwrap();
+    let mut root_of_unity = F::two_adic_root_of_unity();
+    for power in powers {
+        assert_eq!(F::from_repr(*power).unwrap() * r_inverse, root_of_unity);
+        root_of_unity.square_in_place();
+    }
+
     random_string_tests::<F>();
+    random_serde_tests::<F>();
+    random_from_bits_tests::<F>();
+    random_constant_time_tests::<F>();
+    random_addition_chain_tests::<F>();
     fft_field_test::<F>();
 }
 
@@ -441,14 +583,22 @@ pub fn field_serialization_test<F: Field>() {
         }
 
         {
+            // Note: Fields without two spare bits in the last byte, such as BLS12-381 Fr, cannot hold `SWFlags`.
             let mut serialized = vec![0u8; buf_size];
             let mut cursor = Cursor::new(&mut serialized[..]);
-            a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true)).unwrap();
-            let mut cursor = Cursor::new(&serialized[..]);
-            let (b, flags) = F::deserialize_with_flags::<_, SWFlags>(&mut cursor).unwrap();
-            assert_eq!(flags.is_positive(), Some(true));
-            assert!(!flags.is_infinity());
-            assert_eq!(a, b);
+            match a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true)) {
+                Ok(()) => {
+                    let mut cursor = Cursor::new(&serialized[..]);
+                    let (b, flags) = F::deserialize_with_flags::<_, SWFlags>(&mut cursor).unwrap();
+                    assert_eq!(flags.is_positive(), Some(true));
+                    assert!(!flags.is_infinity());
+                    assert_eq!(a, b);
+                }
+                Err(error) => {
+                    assert!(matches!(error, SerializationError::NotEnoughSpace));
+                    assert!(F::deserialize_with_flags::<_, SWFlags>(&mut &serialized[..]).is_err());
+                }
+            }
         }
 
         #[derive(Default, Clone, Copy, Debug)]
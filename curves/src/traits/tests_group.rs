@@ -16,7 +16,10 @@
 
 use crate::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{test_rng, UniformRand};
+use snarkvm_utilities::{
+    rand::{test_rng, UniformRand},
+    serialize::{encode_hex, CanonicalDeserialize, CanonicalSerialize},
+};
 
 #[allow(clippy::eq_op)]
 pub fn affine_test<G: AffineCurve>(a: G) {
@@ -93,3 +96,57 @@ pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G) {
     assert_eq!(a_rand2.mul(fr_rand1), a.mul(fr_rand1 * fr_rand2), "(a * r2) * r1 != a * (r1 * r2)");
     assert_eq!(a_rand1.mul(fr_rand2), a.mul(fr_rand1 * fr_rand2), "(a * r1) * r2 != a * (r1 * r2)");
 }
+
+pub fn curve_serde_test<G: ProjectiveCurve>() {
+    let mut rng = test_rng();
+
+    for a in [G::zero(), G::rand(&mut rng)] {
+        let affine = a.to_affine();
+        let mut bytes = vec![];
+        CanonicalSerialize::serialize(&affine, &mut bytes).unwrap();
+
+        // Human-readable formats use the hex of the compressed encoding.
+        let json = serde_json::to_string(&affine).unwrap();
+        assert_eq!(json, format!("\"{}\"", encode_hex(&bytes)));
+        assert_eq!(affine, serde_json::from_str::<G::Affine>(&json).unwrap());
+        assert_eq!(json, serde_json::to_string(&a).unwrap());
+        assert_eq!(a, serde_json::from_str::<G>(&json).unwrap());
+
+        // Binary formats use the compressed encoding.
+        let serialized = bincode::serialize(&affine).unwrap();
+        assert_eq!(serialized, bytes);
+        assert_eq!(affine, bincode::deserialize::<G::Affine>(&serialized).unwrap());
+        assert_eq!(serialized, bincode::serialize(&a).unwrap());
+        assert_eq!(a, bincode::deserialize::<G>(&serialized).unwrap());
+
+        // Truncated encodings are rejected.
+        let truncated = format!("\"{}\"", encode_hex(&bytes[1..]));
+        assert!(serde_json::from_str::<G::Affine>(&truncated).is_err());
+        assert!(bincode::deserialize::<G::Affine>(&bytes[1..]).is_err());
+    }
+}
+
+pub fn curve_unchecked_deserialization_test<G: ProjectiveCurve>() {
+    let mut rng = test_rng();
+
+    // The unchecked path reads the same encoding as the checked path.
+    let points = (0..10).map(|_| G::rand(&mut rng).to_affine()).chain([G::Affine::zero()]).collect::<Vec<_>>();
+    let mut bytes = vec![];
+    points.serialize(&mut bytes).unwrap();
+    assert_eq!(points, Vec::<G::Affine>::deserialize_unchecked(&mut &bytes[..]).unwrap());
+    assert_eq!(points[0].to_projective(), G::deserialize_unchecked(&mut &bytes[8..]).unwrap());
+
+    // Points outside the prime-order subgroup are only accepted by the unchecked path.
+    for _ in 0..100 {
+        let x = <G::Affine as AffineCurve>::BaseField::rand(&mut rng);
+        if let Some(point) = G::Affine::from_x_coordinate(x, true) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                let mut bytes = vec![];
+                point.serialize(&mut bytes).unwrap();
+                assert!(G::Affine::deserialize(&mut &bytes[..]).is_err());
+                assert_eq!(point, G::Affine::deserialize_unchecked(&mut &bytes[..]).unwrap());
+                break;
+            }
+        }
+    }
+}
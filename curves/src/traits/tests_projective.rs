@@ -216,13 +216,18 @@ fn random_transformation_test<G: ProjectiveCurve>() {
         }
 
         let expected_v = v.iter().map(|v| v.to_affine().to_projective()).collect::<Vec<_>>();
+        let mut v_par = v.clone();
+        let v_affine = G::batch_normalization_into_affine_par(v.clone());
         G::batch_normalization(&mut v);
+        G::batch_normalization_par(&mut v_par);
 
-        for i in &v {
+        for i in v.iter().chain(&v_par) {
             assert!(i.is_normalized());
         }
 
         assert_eq!(v, expected_v);
+        assert_eq!(v_par, expected_v);
+        assert_eq!(v_affine, expected_v.iter().map(|v| v.to_affine()).collect::<Vec<_>>());
     }
 }
 
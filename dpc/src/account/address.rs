@@ -30,6 +30,7 @@ use snarkvm_utilities::{
 
 use bech32::{self, FromBase32, ToBase32};
 use core::hash::{Hash, Hasher};
+use rand::{CryptoRng, Rng};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 pub struct Address<N: Network>(<N::AccountEncryptionScheme as EncryptionScheme>::PublicKey);
@@ -57,6 +58,16 @@ impl<N: Network> Address<N> {
     pub fn verify_signature(&self, message: &[bool], signature: &N::AccountSignature) -> Result<bool, AccountError> {
         Ok(N::account_signature_scheme().verify(&self.0, message, signature)?)
     }
+
+    /// Encrypts the given message bytes to the account address, with a fresh ephemeral key.
+    /// The ciphertext can only be decrypted with the account view key.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<<N::AccountEncryptionScheme as EncryptionScheme>::Ciphertext, AccountError> {
+        Ok(N::account_encryption_scheme().encrypt_to_public_key(&self.0, message, rng)?)
+    }
 }
 
 impl<N: Network> From<PrivateKey<N>> for Address<N> {
@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account_format, AccountError, Address, Network, PrivateKey, ACCOUNT_HD_MASTER_KEY_DOMAIN};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+    Choice,
+    ConstantTimeEq,
+    FromBytes,
+    ToBytes,
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use base58::{FromBase58, ToBase58};
+use blake2::{
+    digest::{FixedOutput, KeyInit, Update, VariableOutput},
+    Blake2bMac512,
+    Blake2bVar,
+};
+
+/// The offset of hardened child indices.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+///
+/// An extended account private key, for BIP32-style hierarchical deterministic derivation.
+///
+/// Each child is derived from the account seed and chain code of its parent, as
+///     (seed_i, chain_code_i) := MAC(chain_code, seed || i),
+/// where the MAC is a keyed BLAKE2b. As the account private key is derived from the account seed
+/// with a PRF, there is no public derivation, and *only* hardened child indices are supported.
+///
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+#[zeroize(bound = "N: Network")]
+pub struct ExtendedPrivateKey<N: Network> {
+    /// The depth of the key in the derivation tree, where the master key has a depth of 0.
+    #[zeroize(skip)]
+    depth: u8,
+    /// The first 4 bytes of the hash of the parent address.
+    #[zeroize(skip)]
+    parent_fingerprint: [u8; 4],
+    /// The child index of the key, including the hardened offset.
+    #[zeroize(skip)]
+    child_index: u32,
+    /// The chain code of the key.
+    chain_code: [u8; 32],
+    /// The account seed of the key.
+    seed: N::AccountSeed,
+}
+
+impl<N: Network> ExtendedPrivateKey<N> {
+    /// Returns the master extended private key for the given wallet seed, which must be 16 to 64 bytes.
+    pub fn new_master(seed: &[u8]) -> Result<Self, AccountError> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(AccountError::InvalidByteLength(seed.len()));
+        }
+
+        let (seed, chain_code) = Self::derive(ACCOUNT_HD_MASTER_KEY_DOMAIN.as_bytes(), seed)?;
+        Ok(Self { depth: 0, parent_fingerprint: [0u8; 4], child_index: 0, chain_code, seed })
+    }
+
+    /// Returns the extended private key of the child at the given hardened index, including the hardened offset.
+    pub fn derive_child(&self, child_index: u32) -> Result<Self, AccountError> {
+        if child_index < HARDENED_OFFSET {
+            return Err(AccountError::Message(format!("Child index {} is not hardened", child_index)));
+        }
+        if self.depth == u8::MAX {
+            return Err(AccountError::Message("Maximum derivation depth exceeded".into()));
+        }
+
+        // Construct the input (seed || child_index).
+        let mut input = self.seed.to_bytes_le()?;
+        input.extend_from_slice(&child_index.to_le_bytes());
+
+        let (seed, chain_code) = Self::derive(&self.chain_code, &input)?;
+        Ok(Self { depth: self.depth + 1, parent_fingerprint: self.fingerprint()?, child_index, chain_code, seed })
+    }
+
+    ///
+    /// Returns the extended private key at the given path from this key, such as `m/44'/683'/0'/0'`,
+    /// where every child index must be hardened.
+    ///
+    pub fn derive_path(&self, path: &str) -> Result<Self, AccountError> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(AccountError::Message(format!("Invalid derivation path: {}", path)));
+        }
+
+        components.try_fold(self.clone(), |key, component| {
+            let index = component
+                .strip_suffix('\'')
+                .or_else(|| component.strip_suffix('h'))
+                .and_then(|index| index.parse::<u32>().ok())
+                .filter(|index| *index < HARDENED_OFFSET)
+                .ok_or_else(|| AccountError::Message(format!("Invalid hardened path component: {}", component)))?;
+            key.derive_child(index + HARDENED_OFFSET)
+        })
+    }
+
+    /// Returns the account private key.
+    pub fn to_private_key(&self) -> PrivateKey<N> {
+        PrivateKey::from(&self.seed)
+    }
+
+    /// Returns the account address.
+    pub fn to_address(&self) -> Address<N> {
+        self.to_private_key().to_address()
+    }
+
+    /// Returns the depth of the key in the derivation tree.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child index of the key, including the hardened offset.
+    pub fn child_index(&self) -> u32 {
+        self.child_index
+    }
+
+    /// Returns the fingerprint of the key, as the first 4 bytes of the hash of its address.
+    pub fn fingerprint(&self) -> Result<[u8; 4], AccountError> {
+        let mut hasher = Blake2bVar::new(32).unwrap();
+        hasher.update(&self.to_address().to_bytes_le()?);
+
+        let mut hash = [0u8; 32];
+        hasher.finalize_variable(&mut hash).unwrap();
+
+        let mut fingerprint = [0u8; 4];
+        fingerprint.copy_from_slice(&hash[..4]);
+        Ok(fingerprint)
+    }
+
+    /// Returns the account seed and chain code derived from the given key and input.
+    fn derive(key: &[u8], input: &[u8]) -> Result<(N::AccountSeed, [u8; 32]), AccountError> {
+        let mac = |tag: u8| {
+            let mut mac: Blake2bMac512 = KeyInit::new_from_slice(key).unwrap();
+            mac.update(&[tag]);
+            mac.update(input);
+            mac.finalize_fixed()
+        };
+
+        // Reduce the 512-bit output into the scalar field, to sample the account seed with negligible bias.
+        let seed = N::ProgramScalarField::from_bytes_le_mod_order(&mac(0));
+        let seed = N::AccountSeed::read_le(&seed.to_bytes_le()?[..])?;
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&mac(1)[..32]);
+        Ok((seed, chain_code))
+    }
+}
+
+impl<N: Network> ConstantTimeEq for ExtendedPrivateKey<N> {
+    /// Returns `1` if the extended private keys are equal, in time independent of the key material.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.depth.ct_eq(&other.depth)
+            & self.parent_fingerprint.ct_eq(&other.parent_fingerprint)
+            & self.child_index.ct_eq(&other.child_index)
+            & self.chain_code.ct_eq(&other.chain_code)
+            & self.seed.ct_eq(&other.seed)
+    }
+}
+
+impl<N: Network> PartialEq for ExtendedPrivateKey<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<N: Network> Eq for ExtendedPrivateKey<N> {}
+
+impl<N: Network> FromBytes for ExtendedPrivateKey<N> {
+    /// Reads in an extended private key buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let depth = u8::read_le(&mut reader)?;
+        let parent_fingerprint = <[u8; 4]>::read_le(&mut reader)?;
+        let child_index = u32::read_le(&mut reader)?;
+        let chain_code = <[u8; 32]>::read_le(&mut reader)?;
+        let seed = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { depth, parent_fingerprint, child_index, chain_code, seed })
+    }
+}
+
+impl<N: Network> ToBytes for ExtendedPrivateKey<N> {
+    /// Writes an extended private key buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.depth.write_le(&mut writer)?;
+        self.parent_fingerprint.write_le(&mut writer)?;
+        self.child_index.write_le(&mut writer)?;
+        self.chain_code.write_le(&mut writer)?;
+        self.seed.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for ExtendedPrivateKey<N> {
+    type Err = AccountError;
+
+    /// Reads in an extended private key string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 84 {
+            return Err(AccountError::InvalidByteLength(data.len()));
+        }
+
+        if data[0..11] != account_format::EXTENDED_PRIVATE_KEY_PREFIX {
+            return Err(AccountError::InvalidPrefixBytes(data[0..11].to_vec()));
+        }
+
+        Ok(FromBytes::read_le(&data[11..84])?)
+    }
+}
+
+impl<N: Network> fmt::Display for ExtendedPrivateKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut extended_private_key = [0u8; 84];
+        extended_private_key[0..11].copy_from_slice(&account_format::EXTENDED_PRIVATE_KEY_PREFIX);
+        self.write_le(&mut extended_private_key[11..84]).expect("extended private key formatting failed");
+
+        write!(f, "{}", extended_private_key.to_base58())
+    }
+}
+
+impl<N: Network> fmt::Debug for ExtendedPrivateKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ExtendedPrivateKey {{ depth: {}, child_index: {}, seed: {:?} }}",
+            self.depth, self.child_index, self.seed
+        )
+    }
+}
@@ -28,6 +28,9 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod extended_private_key;
+pub use extended_private_key::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -25,13 +25,14 @@ use crate::{
 };
 use snarkvm_algorithms::traits::{SignatureScheme, PRF};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+use snarkvm_utilities::{Choice, ConstantTimeEq, FromBytes, ToBytes, UniformRand, Zeroize, ZeroizeOnDrop};
 
 use base58::{FromBase58, ToBase58};
 use rand::{CryptoRng, Rng};
 use std::{fmt, str::FromStr};
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+#[zeroize(bound = "N: Network")]
 pub struct PrivateKey<N: Network> {
     seed: N::AccountSeed,
     pub(super) sk_sig: N::ProgramScalarField,
@@ -90,6 +91,21 @@ impl<N: Network> From<&N::AccountSeed> for PrivateKey<N> {
     }
 }
 
+impl<N: Network> ConstantTimeEq for PrivateKey<N> {
+    /// Returns `1` if the private keys are equal, in time independent of the key material.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.seed.ct_eq(&other.seed) & self.sk_sig.ct_eq(&other.sk_sig) & self.r_sig.ct_eq(&other.r_sig)
+    }
+}
+
+impl<N: Network> PartialEq for PrivateKey<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<N: Network> Eq for PrivateKey<N> {}
+
 impl<N: Network> FromStr for PrivateKey<N> {
     type Err = AccountError;
 
@@ -16,10 +16,20 @@
 
 #[cfg(test)]
 mod testnet1 {
-    use crate::{testnet1::Testnet1, Account, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet1::Testnet1,
+        Account,
+        Address,
+        ExtendedPrivateKey,
+        Network,
+        PrivateKey,
+        ViewKey,
+        HARDENED_OFFSET,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
-    use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::{ConstantTimeEq, FromBytes, ToBits, ToBytes, Zeroize};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
@@ -83,6 +93,44 @@ mod testnet1 {
         assert!(PrivateKey::<Testnet1>::from_str("").is_err());
     }
 
+    #[test]
+    fn test_private_key_ct_eq() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<Testnet1>::new(rng);
+            let other_private_key = PrivateKey::<Testnet1>::new(rng);
+            assert!(bool::from(private_key.ct_eq(&private_key.clone())));
+            assert!(bool::from(private_key.ct_ne(&other_private_key)));
+
+            let view_key = ViewKey::from_private_key(&private_key);
+            assert!(bool::from(view_key.ct_eq(&ViewKey::from_private_key(&private_key))));
+            assert!(bool::from(view_key.ct_ne(&ViewKey::from_private_key(&other_private_key))));
+        }
+    }
+
+    #[test]
+    fn test_private_key_zeroize() {
+        let rng = &mut thread_rng();
+
+        let private_key = PrivateKey::<Testnet1>::new(rng);
+        let mut candidate = private_key.clone();
+        candidate.zeroize();
+        assert_ne!(private_key, candidate);
+        assert_eq!(PrivateKey::<Testnet1>::from(&Default::default()).to_string(), candidate.to_string());
+
+        let mut view_key = ViewKey::from_private_key(&private_key);
+        view_key.zeroize();
+        assert!(view_key.is_zero());
+
+        let seed: [u8; 32] = rng.gen();
+        let extended_private_key = ExtendedPrivateKey::<Testnet1>::new_master(&seed).unwrap();
+        let mut candidate = extended_private_key.clone();
+        candidate.zeroize();
+        assert_ne!(extended_private_key, candidate);
+        assert_eq!(extended_private_key.depth(), candidate.depth());
+    }
+
     #[test]
     fn test_private_key_into_view_key() {
         let private_key = PrivateKey::<Testnet1>::from_str(ALEO_TESTNET1_PRIVATE_KEY).unwrap();
@@ -154,6 +202,61 @@ mod testnet1 {
         }
     }
 
+    #[test]
+    fn test_extended_private_key() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+        let seed: [u8; 32] = rng.gen();
+
+        let master = ExtendedPrivateKey::<Testnet1>::new_master(&seed).unwrap();
+        assert_eq!(master, ExtendedPrivateKey::new_master(&seed).unwrap());
+        assert_eq!(0, master.depth());
+
+        // Ensure the path derivation matches the child derivation.
+        let child = master.derive_path("m/44'/683'/0'").unwrap();
+        let expected = master
+            .derive_child(44 + HARDENED_OFFSET)
+            .and_then(|key| key.derive_child(683 + HARDENED_OFFSET))
+            .and_then(|key| key.derive_child(HARDENED_OFFSET))
+            .unwrap();
+        assert_eq!(expected, child);
+        assert_eq!(3, child.depth());
+        assert_eq!(HARDENED_OFFSET, child.child_index());
+        assert!(child.to_private_key().is_valid());
+
+        // Ensure siblings derive distinct accounts.
+        let sibling = master.derive_path("m/44'/683'/1'").unwrap();
+        assert_ne!(child.to_address(), sibling.to_address());
+
+        // Ensure the extended private key round-trips through strings.
+        let candidate = ExtendedPrivateKey::<Testnet1>::from_str(&child.to_string()).unwrap();
+        assert_eq!(child, candidate);
+        assert!(child.to_string().starts_with("AExtPrivateKey1"));
+
+        // Ensure invalid derivations fail.
+        assert!(master.derive_child(0).is_err());
+        assert!(master.derive_path("m/44'/683/0'").is_err());
+        assert!(master.derive_path("44'/683'/0'").is_err());
+        assert!(ExtendedPrivateKey::<Testnet1>::new_master(&seed[..8]).is_err());
+    }
+
+    #[test]
+    fn test_account_encryption() {
+        let private_key = PrivateKey::<Testnet1>::from_str(ALEO_TESTNET1_PRIVATE_KEY).unwrap();
+        let view_key = ViewKey::from_private_key(&private_key);
+        let address = Address::<Testnet1>::from_private_key(&private_key);
+
+        for i in 0..10 {
+            let message: Vec<u8> = (0..(32 * i)).map(|_| rand::random::<u8>()).collect();
+            let ciphertext = address.encrypt(&message, &mut thread_rng()).unwrap();
+            assert_eq!(message, view_key.decrypt(&ciphertext).unwrap());
+        }
+
+        // Ensure a different view key fails to decrypt the ciphertext.
+        let ciphertext = address.encrypt(b"Hi, I am an Aleo ciphertext!", &mut thread_rng()).unwrap();
+        let alternate_view_key = ViewKey::from_private_key(&PrivateKey::<Testnet1>::new(&mut thread_rng()));
+        assert!(alternate_view_key.decrypt(&ciphertext).is_err());
+    }
+
     #[test]
     fn test_invalid_account_signatures() {
         let private_key = PrivateKey::<Testnet1>::from_str(ALEO_TESTNET1_PRIVATE_KEY).unwrap();
@@ -275,10 +378,20 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        Address,
+        ExtendedPrivateKey,
+        Network,
+        PrivateKey,
+        ViewKey,
+        HARDENED_OFFSET,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
-    use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::{ConstantTimeEq, FromBytes, ToBits, ToBytes, Zeroize};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
@@ -342,6 +455,44 @@ mod testnet2 {
         assert!(PrivateKey::<Testnet2>::from_str("").is_err());
     }
 
+    #[test]
+    fn test_private_key_ct_eq() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<Testnet2>::new(rng);
+            let other_private_key = PrivateKey::<Testnet2>::new(rng);
+            assert!(bool::from(private_key.ct_eq(&private_key.clone())));
+            assert!(bool::from(private_key.ct_ne(&other_private_key)));
+
+            let view_key = ViewKey::from_private_key(&private_key);
+            assert!(bool::from(view_key.ct_eq(&ViewKey::from_private_key(&private_key))));
+            assert!(bool::from(view_key.ct_ne(&ViewKey::from_private_key(&other_private_key))));
+        }
+    }
+
+    #[test]
+    fn test_private_key_zeroize() {
+        let rng = &mut thread_rng();
+
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let mut candidate = private_key.clone();
+        candidate.zeroize();
+        assert_ne!(private_key, candidate);
+        assert_eq!(PrivateKey::<Testnet2>::from(&Default::default()).to_string(), candidate.to_string());
+
+        let mut view_key = ViewKey::from_private_key(&private_key);
+        view_key.zeroize();
+        assert!(view_key.is_zero());
+
+        let seed: [u8; 32] = rng.gen();
+        let extended_private_key = ExtendedPrivateKey::<Testnet2>::new_master(&seed).unwrap();
+        let mut candidate = extended_private_key.clone();
+        candidate.zeroize();
+        assert_ne!(extended_private_key, candidate);
+        assert_eq!(extended_private_key.depth(), candidate.depth());
+    }
+
     #[test]
     fn test_private_key_into_view_key() {
         let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
@@ -414,6 +565,61 @@ mod testnet2 {
         }
     }
 
+    #[test]
+    fn test_extended_private_key() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+        let seed: [u8; 32] = rng.gen();
+
+        let master = ExtendedPrivateKey::<Testnet2>::new_master(&seed).unwrap();
+        assert_eq!(master, ExtendedPrivateKey::new_master(&seed).unwrap());
+        assert_eq!(0, master.depth());
+
+        // Ensure the path derivation matches the child derivation.
+        let child = master.derive_path("m/44'/683'/0'").unwrap();
+        let expected = master
+            .derive_child(44 + HARDENED_OFFSET)
+            .and_then(|key| key.derive_child(683 + HARDENED_OFFSET))
+            .and_then(|key| key.derive_child(HARDENED_OFFSET))
+            .unwrap();
+        assert_eq!(expected, child);
+        assert_eq!(3, child.depth());
+        assert_eq!(HARDENED_OFFSET, child.child_index());
+        assert!(child.to_private_key().is_valid());
+
+        // Ensure siblings derive distinct accounts.
+        let sibling = master.derive_path("m/44'/683'/1'").unwrap();
+        assert_ne!(child.to_address(), sibling.to_address());
+
+        // Ensure the extended private key round-trips through strings.
+        let candidate = ExtendedPrivateKey::<Testnet2>::from_str(&child.to_string()).unwrap();
+        assert_eq!(child, candidate);
+        assert!(child.to_string().starts_with("AExtPrivateKey1"));
+
+        // Ensure invalid derivations fail.
+        assert!(master.derive_child(0).is_err());
+        assert!(master.derive_path("m/44'/683/0'").is_err());
+        assert!(master.derive_path("44'/683'/0'").is_err());
+        assert!(ExtendedPrivateKey::<Testnet2>::new_master(&seed[..8]).is_err());
+    }
+
+    #[test]
+    fn test_account_encryption() {
+        let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
+        let view_key = ViewKey::from_private_key(&private_key);
+        let address = Address::<Testnet2>::from_private_key(&private_key);
+
+        for i in 0..10 {
+            let message: Vec<u8> = (0..(32 * i)).map(|_| rand::random::<u8>()).collect();
+            let ciphertext = address.encrypt(&message, &mut thread_rng()).unwrap();
+            assert_eq!(message, view_key.decrypt(&ciphertext).unwrap());
+        }
+
+        // Ensure a different view key fails to decrypt the ciphertext.
+        let ciphertext = address.encrypt(b"Hi, I am an Aleo ciphertext!", &mut thread_rng()).unwrap();
+        let alternate_view_key = ViewKey::from_private_key(&PrivateKey::<Testnet2>::new(&mut thread_rng()));
+        assert!(alternate_view_key.decrypt(&ciphertext).is_err());
+    }
+
     #[test]
     fn test_invalid_account_signatures() {
         let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
@@ -16,7 +16,7 @@
 
 use crate::{account_format, AccountError, Network, PrivateKey};
 use snarkvm_algorithms::EncryptionScheme;
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_utilities::{Choice, ConstantTimeEq, FromBytes, ToBytes, Zeroize, ZeroizeOnDrop};
 
 use base58::{FromBase58, ToBase58};
 use std::{
@@ -26,7 +26,8 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+#[zeroize(bound = "N: Network")]
 pub struct ViewKey<N: Network>(<N::AccountEncryptionScheme as EncryptionScheme>::PrivateKey);
 
 impl<N: Network> ViewKey<N> {
@@ -34,6 +35,14 @@ impl<N: Network> ViewKey<N> {
     pub fn from_private_key(private_key: &PrivateKey<N>) -> Self {
         Self(private_key.to_decryption_key())
     }
+
+    /// Decrypts the given ciphertext, which was encrypted to the address of this view key.
+    pub fn decrypt(
+        &self,
+        ciphertext: &<N::AccountEncryptionScheme as EncryptionScheme>::Ciphertext,
+    ) -> Result<Vec<u8>, AccountError> {
+        Ok(N::account_encryption_scheme().decrypt_with_private_key(&self.0, ciphertext)?)
+    }
 }
 
 impl<N: Network> From<PrivateKey<N>> for ViewKey<N> {
@@ -50,6 +59,21 @@ impl<N: Network> From<&PrivateKey<N>> for ViewKey<N> {
     }
 }
 
+impl<N: Network> ConstantTimeEq for ViewKey<N> {
+    /// Returns `1` if the view keys are equal, in time independent of the key material.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<N: Network> PartialEq for ViewKey<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<N: Network> Eq for ViewKey<N> {}
+
 impl<N: Network> FromStr for ViewKey<N> {
     type Err = AccountError;
 
@@ -20,18 +20,23 @@ use crate::{
     BlockError,
     BlockHeader,
     BlockTemplate,
+    Ledger,
     LedgerProof,
     LedgerTree,
     LedgerTreeScheme,
     Network,
+    Storage,
     Transaction,
     Transactions,
+    Transition,
 };
 use snarkvm_algorithms::CRH;
 use snarkvm_utilities::{to_bytes_le, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
+use itertools::Itertools;
 use rand::{CryptoRng, Rng};
+use rayon::prelude::*;
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -109,8 +114,7 @@ impl<N: Network> Block<N> {
         assert!(!(*transactions).is_empty(), "Cannot create block with no transactions");
 
         // Compute the block hash.
-        let block_hash =
-            N::block_hash_crh().hash_bytes(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?.into();
+        let block_hash = Self::compute_block_hash(previous_block_hash, &header)?;
 
         // Construct the block.
         let block = Self { block_hash, previous_block_hash, header, transactions };
@@ -122,8 +126,26 @@ impl<N: Network> Block<N> {
         }
     }
 
-    /// Returns `true` if the block is well-formed.
+    /// Returns the block hash for the given previous block hash and block header.
+    pub(crate) fn compute_block_hash(
+        previous_block_hash: N::BlockHash,
+        header: &BlockHeader<N>,
+    ) -> Result<N::BlockHash, BlockError> {
+        Ok(N::block_hash_crh().hash_bytes(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?.into())
+    }
+
+    /// Returns `true` if the block is well-formed and valid.
     pub fn is_valid(&self) -> bool {
+        self.is_well_formed_with(Transactions::is_valid)
+    }
+
+    /// Returns `true` if the block is well-formed, without verifying its transitions.
+    pub fn is_well_formed(&self) -> bool {
+        self.is_well_formed_with(Transactions::is_well_formed)
+    }
+
+    /// Returns `true` if the block is well-formed, and its transactions pass the given check.
+    fn is_well_formed_with(&self, is_valid_transactions: fn(&Transactions<N>) -> bool) -> bool {
         // Ensure the previous block hash is well-formed.
         let genesis_previous_block_hash = LedgerProof::<N>::default().block_hash();
         if self.height() == 0u32 {
@@ -145,12 +167,6 @@ impl<N: Network> Block<N> {
             return false;
         }
 
-        // Ensure the transactions are valid.
-        if !self.transactions.is_valid() {
-            eprintln!("Invalid block transactions");
-            return false;
-        }
-
         // Ensure the transactions root matches the computed root from the transactions list.
         if self.header.transactions_root() != self.transactions.transactions_root() {
             eprintln!("Invalid block transactions does not match transactions root in header");
@@ -181,6 +197,101 @@ impl<N: Network> Block<N> {
             return false;
         }
 
+        // Ensure the transactions pass the given check, which may verify their transitions.
+        if !is_valid_transactions(&self.transactions) {
+            eprintln!("Invalid block transactions");
+            return false;
+        }
+
+        true
+    }
+
+    ///
+    /// Returns `true` if the block is valid and is the next block for the given ledger.
+    ///
+    /// Verification runs as a pipeline of stages, each parallelized over the block:
+    /// well-formedness, ledger state checks (including duplicate serial numbers),
+    /// value balance signatures as a single batch, and finally transition proofs,
+    /// where the input and output proofs of the block are each verified as a single batch.
+    /// Each stage only runs if all preceding stages succeed.
+    ///
+    pub fn verify_fast<S: Storage>(&self, ledger: &Ledger<N, S>) -> bool {
+        // Stage 1: Ensure the block is well-formed.
+        if !self.is_well_formed() {
+            return false;
+        }
+
+        // Stage 2: Ensure the block extends the ledger, and its state does not already exist in the ledger.
+        if self.height() != ledger.latest_block_height().saturating_add(1) {
+            eprintln!("Block height {} does not extend the ledger", self.height());
+            return false;
+        }
+        if self.previous_block_hash != ledger.latest_block_hash() {
+            eprintln!("Block previous block hash does not match the latest ledger block hash");
+            return false;
+        }
+        if self.transactions.par_iter().any(|transaction| ledger.contains_transaction(transaction)) {
+            eprintln!("Block contains a transaction that already exists in the ledger");
+            return false;
+        }
+        if !self.transactions.par_iter().all(|transaction| ledger.contains_ledger_root(&transaction.ledger_root())) {
+            eprintln!("Block contains a transaction that references a non-existent ledger root");
+            return false;
+        }
+        let serial_numbers = self.serial_numbers().collect::<Vec<_>>();
+        if serial_numbers.par_iter().any(|serial_number| ledger.contains_serial_number(serial_number)) {
+            eprintln!("Block contains a serial number that already exists in the ledger");
+            return false;
+        }
+        let commitments = self.commitments().collect::<Vec<_>>();
+        if commitments.par_iter().any(|commitment| ledger.contains_commitment(commitment)) {
+            eprintln!("Block contains a commitment that already exists in the ledger");
+            return false;
+        }
+
+        // Collect every transition in the block, along with its transaction and local transitions root.
+        let mut transitions = Vec::new();
+        for transaction in self.transactions.iter() {
+            match transaction.to_local_transitions_roots() {
+                Ok(local_transitions_roots) => transitions.extend(
+                    transaction
+                        .transitions()
+                        .iter()
+                        .zip_eq(local_transitions_roots)
+                        .map(|(transition, root)| (transaction, transition, root)),
+                ),
+                Err(error) => {
+                    eprintln!("Failed to compute the local transitions roots: {}", error);
+                    return false;
+                }
+            }
+        }
+
+        // Stage 3: Ensure the value balance commitments of all transitions are valid, as a single batch.
+        let block_transitions = transitions.iter().map(|(_, transition, _)| *transition).collect::<Vec<_>>();
+        if !Transition::verify_value_balance_commitments(&block_transitions) {
+            eprintln!("Block contains a transition with an invalid value balance commitment");
+            return false;
+        }
+
+        // Stage 4: Ensure the proofs of all transitions are valid, batching the input and output proofs.
+        let executions = transitions
+            .iter()
+            .map(|(transaction, transition, local_transitions_root)| {
+                (
+                    *transition,
+                    transaction.input_circuit_id(),
+                    transaction.output_circuit_id(),
+                    transaction.ledger_root(),
+                    *local_transitions_root,
+                )
+            })
+            .collect::<Vec<_>>();
+        if !Transition::verify_executions(&executions) {
+            eprintln!("Block contains a transition with an invalid proof");
+            return false;
+        }
+
         true
     }
 
@@ -289,20 +400,34 @@ impl<N: Network> Block<N> {
             }
         }
     }
+
+    ///
+    /// Reads a block without verifying it, such as its PoSW proof and transaction proofs.
+    /// The block hash is still recomputed from the header, and must match the given block hash.
+    /// Note: This is only for trusted bytes, such as a block read back from the ledger storage.
+    ///
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        let previous_block_hash = FromBytes::read_le(&mut reader)?;
+        let header = BlockHeader::read_le_unchecked(&mut reader)?;
+        let transactions = Transactions::read_le_unchecked(&mut reader)?;
+
+        match block_hash == Self::compute_block_hash(previous_block_hash, &header)? {
+            true => Ok(Self { block_hash, previous_block_hash, header, transactions }),
+            false => Err(BlockError::Message("Mismatching block hash, possible data corruption".to_string()).into()),
+        }
+    }
 }
 
 impl<N: Network> FromBytes for Block<N> {
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
-        let previous_block_hash = FromBytes::read_le(&mut reader)?;
-        let header = FromBytes::read_le(&mut reader)?;
-        let transactions = FromBytes::read_le(&mut reader)?;
-        let block = Self::from(previous_block_hash, header, transactions)?;
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let block = Self::read_le_unchecked(reader)?;
 
-        match block_hash == block.hash() {
+        // Ensure the block is valid.
+        match block.is_valid() {
             true => Ok(block),
-            false => Err(BlockError::Message("Mismatching block hash, possible data corruption".to_string()).into()),
+            false => Err(BlockError::Message("Failed to initialize a block from given inputs".to_string()).into()),
         }
     }
 }
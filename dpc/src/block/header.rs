@@ -267,11 +267,12 @@ impl<N: Network> BlockHeader<N> {
     pub fn to_header_root(&self) -> Result<N::BlockHeaderRoot> {
         Ok((*self.to_header_tree()?.root()).into())
     }
-}
 
-impl<N: Network> FromBytes for BlockHeader<N> {
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    ///
+    /// Reads a block header without verifying it, such as its PoSW proof.
+    /// Note: This is only for trusted bytes, such as a block header read back from the ledger storage.
+    ///
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the header core variables.
         let previous_ledger_root = FromBytes::read_le(&mut reader)?;
         let transactions_root = FromBytes::read_le(&mut reader)?;
@@ -294,7 +295,20 @@ impl<N: Network> FromBytes for BlockHeader<N> {
         let proof = FromBytes::read_le(&mut reader)?;
 
         // Construct the block header.
-        Ok(Self::from(previous_ledger_root, transactions_root, metadata, nonce, proof)?)
+        Ok(Self { previous_ledger_root, transactions_root, metadata, nonce, proof })
+    }
+}
+
+impl<N: Network> FromBytes for BlockHeader<N> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let block_header = Self::read_le_unchecked(reader)?;
+
+        // Ensure the block header is well-formed.
+        match block_header.is_valid() {
+            true => Ok(block_header),
+            false => Err(BlockError::Message("Invalid block header".to_string()).into()),
+        }
     }
 }
 
@@ -49,14 +49,8 @@ pub struct Transactions<N: Network> {
 impl<N: Network> Transactions<N> {
     /// Initializes from a given transactions list.
     pub fn from(transactions: &[Transaction<N>]) -> Result<Self, BlockError> {
-        // Compute the transactions tree.
-        let tree = MerkleTree::<N::TransactionsRootParameters>::new(
-            Arc::new(N::transactions_root_parameters().clone()),
-            &transactions.iter().map(Transaction::transaction_id).collect::<Vec<_>>(),
-        )?;
-
         // Construct the transactions struct.
-        let transactions = Self { transactions: transactions.to_vec(), tree: Arc::new(tree) };
+        let transactions = Self::from_unchecked(transactions.to_vec())?;
 
         // Ensure the list of transactions are valid.
         match transactions.is_valid() {
@@ -65,16 +59,26 @@ impl<N: Network> Transactions<N> {
         }
     }
 
-    /// Returns `true` if the transactions are well-formed.
+    /// Returns `true` if the transactions are well-formed and valid.
     pub fn is_valid(&self) -> bool {
+        self.is_well_formed_with(Transaction::is_valid)
+    }
+
+    /// Returns `true` if the transactions are well-formed, without verifying their transitions.
+    pub fn is_well_formed(&self) -> bool {
+        self.is_well_formed_with(Transaction::is_well_formed)
+    }
+
+    /// Returns `true` if the transactions list is well-formed, and each transaction passes the given check.
+    fn is_well_formed_with(&self, is_valid_transaction: fn(&Transaction<N>) -> bool) -> bool {
         // Ensure the transactions list is not empty.
         if self.transactions.is_empty() {
             eprintln!("Cannot process validity checks on an empty transactions list");
             return false;
         }
 
-        // Ensure each transaction is well-formed.
-        if !self.transactions.as_parallel_slice().par_iter().all(Transaction::is_valid) {
+        // Ensure each transaction passes the given check.
+        if !self.transactions.as_parallel_slice().par_iter().all(is_valid_transaction) {
             eprintln!("Invalid transaction found in the transactions list");
             return false;
         }
@@ -177,17 +181,42 @@ impl<N: Network> Transactions<N> {
     ) -> impl Iterator<Item = Record<N>> + 'a {
         self.transactions.iter().flat_map(move |transaction| transaction.to_decrypted_records(decryption_key))
     }
-}
 
-impl<N: Network> FromBytes for Transactions<N> {
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    ///
+    /// Reads a transactions list without verifying it, such as the proofs of its transactions.
+    /// Note: This is only for trusted bytes, such as a block read back from the ledger storage.
+    ///
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
         let mut transactions = Vec::with_capacity(num_transactions as usize);
         for _ in 0..num_transactions {
-            transactions.push(FromBytes::read_le(&mut reader)?);
+            transactions.push(Transaction::read_le_unchecked(&mut reader)?);
+        }
+        Ok(Self::from_unchecked(transactions)?)
+    }
+
+    /// Initializes from a given transactions list, without verifying it.
+    fn from_unchecked(transactions: Vec<Transaction<N>>) -> Result<Self, BlockError> {
+        // Compute the transactions tree.
+        let tree = MerkleTree::<N::TransactionsRootParameters>::new(
+            Arc::new(N::transactions_root_parameters().clone()),
+            &transactions.iter().map(Transaction::transaction_id).collect::<Vec<_>>(),
+        )?;
+
+        Ok(Self { transactions, tree: Arc::new(tree) })
+    }
+}
+
+impl<N: Network> FromBytes for Transactions<N> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let transactions = Self::read_le_unchecked(reader)?;
+
+        // Ensure the list of transactions are valid.
+        match transactions.is_valid() {
+            true => Ok(transactions),
+            false => Err(BlockError::Message("Failed to initialize the transactions list".to_string()).into()),
         }
-        Ok(Self::from(&transactions)?)
     }
 }
 
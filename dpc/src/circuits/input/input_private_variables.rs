@@ -16,8 +16,10 @@
 
 use crate::{LedgerProof, Network, Record};
 use snarkvm_algorithms::traits::SignatureScheme;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Clone)]
 pub struct InputPrivateVariables<N: Network> {
@@ -57,3 +59,25 @@ impl<N: Network> InputPrivateVariables<N> {
     //     })
     // }
 }
+
+impl<N: Network> FromBytes for InputPrivateVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let input_record = FromBytes::read_le(&mut reader)?;
+        let ledger_proof = FromBytes::read_le(&mut reader)?;
+        let signature = FromBytes::read_le(&mut reader)?;
+        let input_value_commitment_randomness = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { input_record, ledger_proof, signature, input_value_commitment_randomness })
+    }
+}
+
+impl<N: Network> ToBytes for InputPrivateVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.input_record.write_le(&mut writer)?;
+        self.ledger_proof.write_le(&mut writer)?;
+        self.signature.write_le(&mut writer)?;
+        self.input_value_commitment_randomness.write_le(&mut writer)
+    }
+}
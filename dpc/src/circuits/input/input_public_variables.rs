@@ -16,9 +16,10 @@
 
 use crate::Network;
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
-use snarkvm_utilities::ToBytes;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Clone, Debug)]
 pub struct InputPublicVariables<N: Network> {
@@ -93,3 +94,39 @@ impl<N: Network> ToConstraintField<N::InnerScalarField> for InputPublicVariables
         Ok(v)
     }
 }
+
+impl<N: Network> FromBytes for InputPublicVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let serial_number = FromBytes::read_le(&mut reader)?;
+        let input_value_commitment = FromBytes::read_le(&mut reader)?;
+        let ledger_root = FromBytes::read_le(&mut reader)?;
+        let local_transitions_root = FromBytes::read_le(&mut reader)?;
+
+        let program_id_exists: bool = FromBytes::read_le(&mut reader)?;
+        let program_id = match program_id_exists {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        Ok(Self::new(serial_number, input_value_commitment, ledger_root, local_transitions_root, program_id))
+    }
+}
+
+impl<N: Network> ToBytes for InputPublicVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.serial_number.write_le(&mut writer)?;
+        self.input_value_commitment.write_le(&mut writer)?;
+        self.ledger_root.write_le(&mut writer)?;
+        self.local_transitions_root.write_le(&mut writer)?;
+
+        match &self.program_id {
+            Some(program_id) => {
+                true.write_le(&mut writer)?;
+                program_id.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
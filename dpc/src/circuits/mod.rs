@@ -20,5 +20,11 @@ pub use input::*;
 pub mod output;
 pub use output::*;
 
+pub mod proving_request;
+pub use proving_request::*;
+
+pub mod proving_response;
+pub use proving_response::*;
+
 #[cfg(test)]
 mod tests;
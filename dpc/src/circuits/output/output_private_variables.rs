@@ -16,8 +16,10 @@
 
 use crate::{Network, Record};
 use snarkvm_algorithms::traits::EncryptionScheme;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Clone)]
 pub struct OutputPrivateVariables<N: Network> {
@@ -45,3 +47,23 @@ impl<N: Network> OutputPrivateVariables<N> {
         Ok(Self { output_record, encryption_randomness, output_value_commitment_randomness })
     }
 }
+
+impl<N: Network> FromBytes for OutputPrivateVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let output_record = FromBytes::read_le(&mut reader)?;
+        let encryption_randomness = FromBytes::read_le(&mut reader)?;
+        let output_value_commitment_randomness = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { output_record, encryption_randomness, output_value_commitment_randomness })
+    }
+}
+
+impl<N: Network> ToBytes for OutputPrivateVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.output_record.write_le(&mut writer)?;
+        self.encryption_randomness.write_le(&mut writer)?;
+        self.output_value_commitment_randomness.write_le(&mut writer)
+    }
+}
@@ -16,9 +16,10 @@
 
 use crate::Network;
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
-use snarkvm_utilities::ToBytes;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Clone, Debug)]
 pub struct OutputPublicVariables<N: Network> {
@@ -76,3 +77,35 @@ impl<N: Network> ToConstraintField<N::InnerScalarField> for OutputPublicVariable
         Ok(v)
     }
 }
+
+impl<N: Network> FromBytes for OutputPublicVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let commitment = FromBytes::read_le(&mut reader)?;
+        let output_value_commitment = FromBytes::read_le(&mut reader)?;
+
+        let program_id_exists: bool = FromBytes::read_le(&mut reader)?;
+        let program_id = match program_id_exists {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        Ok(Self::new(commitment, output_value_commitment, program_id))
+    }
+}
+
+impl<N: Network> ToBytes for OutputPublicVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.commitment.write_le(&mut writer)?;
+        self.output_value_commitment.write_le(&mut writer)?;
+
+        match &self.program_id {
+            Some(program_id) => {
+                true.write_le(&mut writer)?;
+                program_id.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
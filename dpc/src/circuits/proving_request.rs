@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    InputCircuit,
+    InputPrivateVariables,
+    InputPublicVariables,
+    Network,
+    OutputCircuit,
+    OutputPrivateVariables,
+    OutputPublicVariables,
+    ProvingResponse,
+};
+use snarkvm_algorithms::SNARK;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+};
+
+/// A request to a remote prover to prove a circuit assignment.
+///
+/// The request carries the full assignment of an input or output circuit,
+/// along with the circuit ID of the parameters it must be proven under.
+#[derive(Clone)]
+pub enum ProvingRequest<N: Network> {
+    /// A request to prove an assignment of the input circuit.
+    Input(N::InputCircuitID, InputPublicVariables<N>, InputPrivateVariables<N>),
+    /// A request to prove an assignment of the output circuit.
+    Output(N::OutputCircuitID, OutputPublicVariables<N>, OutputPrivateVariables<N>),
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Initializes a new request to prove the given input circuit assignment.
+    pub(crate) fn new_input(public: InputPublicVariables<N>, private: InputPrivateVariables<N>) -> Self {
+        Self::Input(*N::input_circuit_id(), public, private)
+    }
+
+    /// Initializes a new request to prove the given output circuit assignment.
+    pub(crate) fn new_output(public: OutputPublicVariables<N>, private: OutputPrivateVariables<N>) -> Self {
+        Self::Output(*N::output_circuit_id(), public, private)
+    }
+
+    /// Returns `true` if the request is for the circuit parameters of this network.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Input(circuit_id, ..) => circuit_id == N::input_circuit_id(),
+            Self::Output(circuit_id, ..) => circuit_id == N::output_circuit_id(),
+        }
+    }
+
+    /// Proves the circuit assignment, returning the response to the request.
+    pub fn prove<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<ProvingResponse<N>> {
+        // Ensure the request is for the parameters of this prover.
+        if !self.is_valid() {
+            return Err(anyhow!("Proving request is for a different set of circuit parameters"));
+        }
+
+        match self {
+            Self::Input(_, public, private) => {
+                let circuit = InputCircuit::<N>::new(public.clone(), private.clone());
+                let proof = N::InputSNARK::prove(&N::input_proving_key(), &circuit, rng)?;
+                Ok(ProvingResponse::Input(proof.into()))
+            }
+            Self::Output(_, public, private) => {
+                let circuit = OutputCircuit::<N>::new(public.clone(), private.clone());
+                let proof = N::OutputSNARK::prove(&N::output_proving_key(), &circuit, rng)?;
+                Ok(ProvingResponse::Output(proof.into()))
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for ProvingRequest<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant: u8 = FromBytes::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(Self::Input(
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+            )),
+            1 => Ok(Self::Output(
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+                FromBytes::read_le(&mut reader)?,
+            )),
+            _ => Err(error("Invalid proving request variant")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for ProvingRequest<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Input(circuit_id, public, private) => {
+                0u8.write_le(&mut writer)?;
+                circuit_id.write_le(&mut writer)?;
+                public.write_le(&mut writer)?;
+                private.write_le(&mut writer)
+            }
+            Self::Output(circuit_id, public, private) => {
+                1u8.write_le(&mut writer)?;
+                circuit_id.write_le(&mut writer)?;
+                public.write_le(&mut writer)?;
+                private.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> fmt::Debug for ProvingRequest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The private variables are intentionally omitted.
+        match self {
+            Self::Input(circuit_id, public, _) => f.debug_tuple("Input").field(circuit_id).field(public).finish(),
+            Self::Output(circuit_id, public, _) => f.debug_tuple("Output").field(circuit_id).field(public).finish(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, ProvingRequest};
+use snarkvm_algorithms::SNARK;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// The response of a remote prover to a proving request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProvingResponse<N: Network> {
+    /// A proof of an assignment of the input circuit.
+    Input(N::InputProof),
+    /// A proof of an assignment of the output circuit.
+    Output(N::OutputProof),
+}
+
+impl<N: Network> ProvingResponse<N> {
+    /// Returns `true` if the response contains a valid proof for the given request.
+    pub fn verify(&self, request: &ProvingRequest<N>) -> bool {
+        // Ensure the request is for the parameters of this network.
+        if !request.is_valid() {
+            eprintln!("Proving request is for a different set of circuit parameters");
+            return false;
+        }
+
+        let result = match (self, request) {
+            (Self::Input(proof), ProvingRequest::Input(_, public, _)) => {
+                N::InputSNARK::verify(N::input_verifying_key(), public, proof)
+            }
+            (Self::Output(proof), ProvingRequest::Output(_, public, _)) => {
+                N::OutputSNARK::verify(N::output_verifying_key(), public, proof)
+            }
+            _ => {
+                eprintln!("Proving response does not match the circuit of the request");
+                return false;
+            }
+        };
+
+        match result {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to validate the proving response: {:?}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for ProvingResponse<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant: u8 = FromBytes::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(Self::Input(FromBytes::read_le(&mut reader)?)),
+            1 => Ok(Self::Output(FromBytes::read_le(&mut reader)?)),
+            _ => Err(error("Invalid proving response variant")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for ProvingResponse<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Input(proof) => {
+                0u8.write_le(&mut writer)?;
+                proof.write_le(&mut writer)
+            }
+            Self::Output(proof) => {
+                1u8.write_le(&mut writer)?;
+                proof.write_le(&mut writer)
+            }
+        }
+    }
+}
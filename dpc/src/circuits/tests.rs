@@ -17,7 +17,7 @@
 use crate::{circuits::*, prelude::*};
 use snarkvm_algorithms::prelude::*;
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
-use snarkvm_utilities::ToBytes;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use itertools::Itertools;
 use rand::thread_rng;
@@ -248,6 +248,73 @@ fn dpc_execute_circuits_test<N: Network>(
     ));
 }
 
+fn dpc_proving_request_test<N: Network>() {
+    let rng = &mut thread_rng();
+
+    let sender = Account::new(rng);
+    let recipient = Account::new(rng);
+    let amount = AleoAmount::from_gate(0);
+
+    let record = Record::new_noop(sender.address(), rng).unwrap();
+    let request: Request<N> = Request::new_transfer(
+        sender.private_key(),
+        vec![record],
+        vec![LedgerProof::default()],
+        recipient.address(),
+        amount,
+        false,
+        rng,
+    )
+    .unwrap();
+    let response: Response<N> = ResponseBuilder::new()
+        .add_request(request.clone())
+        .add_output(Output::new(recipient.address(), amount, None, None).unwrap())
+        .add_output(Output::new(sender.address(), amount, None, None).unwrap())
+        .build(rng)
+        .unwrap();
+
+    let program_id = request.to_program_id().unwrap();
+
+    // Construct a proving request for each output circuit assignment.
+    let mut proving_requests = Vec::with_capacity(response.records().len());
+    for (
+        (((record, commitment), encryption_randomness), output_value_commitment),
+        output_value_commitment_randomness,
+    ) in response
+        .records()
+        .iter()
+        .zip_eq(response.commitments())
+        .zip_eq(response.encryption_randomness())
+        .zip_eq(response.output_value_commitments())
+        .zip_eq(response.output_value_commitment_randomness())
+    {
+        let output_public = OutputPublicVariables::<N>::new(commitment, output_value_commitment.clone(), program_id);
+        let output_private = OutputPrivateVariables::<N>::new(
+            record.clone(),
+            *encryption_randomness,
+            *output_value_commitment_randomness,
+        )
+        .unwrap();
+        proving_requests.push(ProvingRequest::new_output(output_public, output_private));
+    }
+
+    // Ensure the proving request round trips through its byte encoding, as it would to a remote prover.
+    let proving_request_bytes = proving_requests[0].to_bytes_le().unwrap();
+    let proving_request = ProvingRequest::<N>::read_le(&proving_request_bytes[..]).unwrap();
+    assert_eq!(proving_request_bytes, proving_request.to_bytes_le().unwrap());
+    assert!(proving_request.is_valid());
+
+    // Ensure the proving response round trips through its byte encoding, as it would from a remote prover.
+    let proving_response = proving_request.prove(rng).unwrap();
+    let proving_response_bytes = proving_response.to_bytes_le().unwrap();
+    let candidate_response = ProvingResponse::<N>::read_le(&proving_response_bytes[..]).unwrap();
+    assert_eq!(proving_response, candidate_response);
+
+    // Ensure the proving response is only valid for its own request.
+    assert!(candidate_response.verify(&proving_requests[0]));
+    assert!(!candidate_response.verify(&proving_requests[1]));
+}
+
 mod testnet1 {
     use super::*;
     use crate::testnet1::*;
@@ -272,6 +339,11 @@ mod testnet1 {
         dpc_execute_circuits_test::<Testnet1>(EXPECTED_INPUT_NUM_CONSTRAINTS, EXPECTED_OUTPUT_NUM_CONSTRAINTS, 1, 8);
         dpc_execute_circuits_test::<Testnet1>(EXPECTED_INPUT_NUM_CONSTRAINTS, EXPECTED_OUTPUT_NUM_CONSTRAINTS, 8, 1);
     }
+
+    #[test]
+    fn test_dpc_proving_request() {
+        dpc_proving_request_test::<Testnet1>();
+    }
 }
 
 mod testnet2 {
@@ -297,4 +369,9 @@ mod testnet2 {
         dpc_execute_circuits_test::<Testnet2>(EXPECTED_INPUT_NUM_CONSTRAINTS, EXPECTED_OUTPUT_NUM_CONSTRAINTS, 1, 8);
         dpc_execute_circuits_test::<Testnet2>(EXPECTED_INPUT_NUM_CONSTRAINTS, EXPECTED_OUTPUT_NUM_CONSTRAINTS, 8, 1);
     }
+
+    #[test]
+    fn test_dpc_proving_request() {
+        dpc_proving_request_test::<Testnet2>();
+    }
 }
@@ -68,6 +68,12 @@ pub enum VMError {
     #[error("{}", _0)]
     ParameterError(#[from] ParameterError),
 
+    #[error("Input record {} is not owned by the caller", _0)]
+    RecordOwnerMismatch(usize),
+
+    #[error("Input record {} does not match the program ID of the other input records", _0)]
+    RecordProgramMismatch(usize),
+
     #[error("{}", _0)]
     SignatureError(#[from] SignatureError),
 
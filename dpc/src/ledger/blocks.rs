@@ -19,7 +19,7 @@ use snarkvm_algorithms::merkle_tree::*;
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 use time::OffsetDateTime;
 
 #[derive(Clone, Debug)]
@@ -36,6 +36,14 @@ pub struct Blocks<N: Network> {
     headers: HashMap<u32, BlockHeader<N>>,
     /// The chain of block transactions.
     transactions: HashMap<u32, Transactions<N>>,
+    /// The block height of each transaction ID.
+    transaction_ids: HashMap<N::TransactionID, u32>,
+    /// The block height of each serial number.
+    serial_numbers: HashMap<N::SerialNumber, u32>,
+    /// The block height of each commitment.
+    commitments: HashMap<N::Commitment, u32>,
+    /// The state of the block transactions that were pruned by a ledger snapshot.
+    pruned_transactions: HashMap<u32, Vec<TransactionState<N>>>,
 }
 
 impl<N: Network> Blocks<N> {
@@ -51,16 +59,82 @@ impl<N: Network> Blocks<N> {
             previous_hashes: Default::default(),
             headers: Default::default(),
             transactions: Default::default(),
+            transaction_ids: Default::default(),
+            serial_numbers: Default::default(),
+            commitments: Default::default(),
+            pruned_transactions: Default::default(),
         };
 
-        blocks.ledger_tree.add(&genesis_block.hash())?;
-        blocks.previous_hashes.insert(height, genesis_block.previous_block_hash());
-        blocks.headers.insert(height, genesis_block.header().clone());
-        blocks.transactions.insert(height, genesis_block.transactions().clone());
+        blocks.apply_block(genesis_block)?;
 
         Ok(blocks)
     }
 
+    ///
+    /// Initializes an instance of `Blocks` from the given ledger snapshot, which must end at the given block hash.
+    /// The state of each pruned block is bound to its block hash through the transactions root of its header.
+    /// Only the genesis block and the latest block retain their transactions; the transactions of
+    /// the pruned blocks are not available.
+    ///
+    pub fn from_snapshot(snapshot: &LedgerSnapshot<N>, block_hash: &N::BlockHash) -> Result<Self> {
+        let mut blocks = Self::new()?;
+
+        for pruned_block in snapshot.pruned_blocks() {
+            blocks.apply_pruned_block(pruned_block)?;
+        }
+
+        // Ensure the latest block extends the pruned blocks.
+        let latest_block = snapshot.latest_block();
+        match latest_block.height() == 0 {
+            true => {
+                if latest_block.hash() != blocks.current_hash {
+                    return Err(anyhow!("The ledger snapshot has a different genesis block"));
+                }
+            }
+            false => {
+                if blocks.current_height + 1 != latest_block.height() {
+                    return Err(anyhow!("The ledger snapshot has an incorrect latest block height"));
+                }
+                if blocks.current_hash != latest_block.previous_block_hash() {
+                    return Err(anyhow!("The ledger snapshot has an incorrect previous block hash"));
+                }
+                if blocks.latest_ledger_root() != latest_block.previous_ledger_root() {
+                    return Err(anyhow!("The ledger snapshot has an incorrect previous ledger root"));
+                }
+                blocks.apply_block(latest_block)?;
+            }
+        }
+
+        // Ensure the snapshot ends at the given block hash.
+        if blocks.current_hash != *block_hash {
+            return Err(anyhow!("The ledger snapshot does not end at block {}", block_hash));
+        }
+
+        Ok(blocks)
+    }
+
+    /// Returns a snapshot of the ledger state, from which an instance of `Blocks` may be restored.
+    pub fn to_snapshot(&self) -> Result<LedgerSnapshot<N>> {
+        let pruned_blocks = (1..self.current_height)
+            .map(|height| {
+                let transactions = match self.pruned_transactions.get(&height) {
+                    Some(transactions) => transactions.clone(),
+                    None => {
+                        self.get_block_transactions(height)?.iter().map(TransactionState::from_transaction).collect()
+                    }
+                };
+                Ok(PrunedBlock::new(self.get_block_header(height)?.clone(), transactions))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(LedgerSnapshot::new(pruned_blocks, self.latest_block()?))
+    }
+
+    /// Returns `true` if the transactions of the given block height were pruned by a ledger snapshot.
+    pub fn is_pruned(&self, height: u32) -> bool {
+        self.pruned_transactions.contains_key(&height)
+    }
+
     /// Returns the latest block height.
     pub fn latest_block_height(&self) -> u32 {
         self.current_height
@@ -119,6 +193,10 @@ impl<N: Network> Blocks<N> {
 
     /// Returns the block transactions given the block height.
     pub fn get_block_transactions(&self, height: u32) -> Result<&Transactions<N>> {
+        if self.is_pruned(height) {
+            return Err(anyhow!("The block transactions for height {} were pruned by a ledger snapshot", height));
+        }
+
         match self.transactions.get(&height) {
             Some(transactions) => Ok(transactions),
             None => Err(anyhow!("Missing block transactions for height {}", height)),
@@ -161,38 +239,67 @@ impl<N: Network> Blocks<N> {
 
     /// Returns `true` if the given ledger root exists.
     pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
-        *ledger_root == self.latest_ledger_root()
-            || self.headers.values().map(BlockHeader::previous_ledger_root).any(|root| root == *ledger_root)
+        self.ledger_tree.contains_ledger_root(ledger_root)
     }
 
     /// Returns `true` if the given block hash exists.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
-        self.current_hash == *block_hash || self.previous_hashes.values().any(|hash| *hash == *block_hash)
+        self.ledger_tree.contains_block_hash(block_hash)
     }
 
     /// Returns `true` if the given transaction exists.
     pub fn contains_transaction(&self, transaction: &Transaction<N>) -> bool {
-        self.transactions.values().flat_map(|transactions| &**transactions).any(|tx| *tx == *transaction)
+        match self.transaction_ids.get(&transaction.transaction_id()) {
+            // The transaction ID of a pruned transaction is bound to its block by the transactions root.
+            Some(height) if self.is_pruned(*height) => true,
+            Some(height) => match self.transactions.get(height) {
+                Some(transactions) => transactions.iter().any(|tx| *tx == *transaction),
+                None => false,
+            },
+            None => false,
+        }
     }
 
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| (**transactions).iter().map(Transaction::serial_numbers))
-            .any(|mut serial_numbers| serial_numbers.contains(serial_number))
+        self.serial_numbers.contains_key(serial_number)
     }
 
     /// Returns `true` if the given commitment exists.
     pub fn contains_commitment(&self, commitment: &N::Commitment) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| (**transactions).iter().map(Transaction::commitments))
-            .any(|mut commitments| commitments.contains(commitment))
+        self.commitments.contains_key(commitment)
     }
 
     /// Adds the given block as the next block in the chain.
     pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is valid as the next block.
+        self.check_next(block)?;
+
+        // Add the block to the ledger.
+        self.apply_block(block)
+    }
+
+    ///
+    /// Adds the given trusted block as the next block in the chain, only ensuring it extends the chain.
+    /// Note: This is only for blocks that were verified before they were stored, such as in the ledger storage.
+    ///
+    pub(crate) fn add_next_trusted(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure the next block height is correct.
+        if self.current_height + 1 != block.height() {
+            return Err(anyhow!("The given block has an incorrect block height"));
+        }
+
+        // Ensure the previous block hash is correct.
+        if self.current_hash != block.previous_block_hash() {
+            return Err(anyhow!("The given block has an incorrect previous block hash"));
+        }
+
+        // Add the block to the ledger.
+        self.apply_block(block)
+    }
+
+    /// Returns `Ok(())` if the given block is valid as the next block in the chain.
+    pub fn check_next(&self, block: &Block<N>) -> Result<()> {
         // Ensure the block itself is valid.
         if !block.is_valid() {
             return Err(anyhow!("The given block is invalid"));
@@ -282,20 +389,6 @@ impl<N: Network> Blocks<N> {
             }
         }
 
-        // Add the block to the ledger. This code section executes atomically.
-        {
-            let mut blocks = self.clone();
-
-            blocks.current_height = height;
-            blocks.current_hash = block_hash;
-            blocks.ledger_tree.add(&block.hash())?;
-            blocks.previous_hashes.insert(height, block.previous_block_hash());
-            blocks.headers.insert(height, block.header().clone());
-            blocks.transactions.insert(height, block.transactions().clone());
-
-            *self = blocks;
-        }
-
         Ok(())
     }
 
@@ -316,19 +409,29 @@ impl<N: Network> Blocks<N> {
     /// Returns a ledger proof for the given commitment.
     ///
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
-        // TODO (howardwu): Optimize this operation.
-        let transaction = self
-            .transactions
-            .values()
-            .flat_map(|transactions| &**transactions)
-            .filter(|transaction| transaction.commitments().contains(&commitment))
-            .collect::<Vec<_>>();
+        self.state_path(commitment, &self.latest_ledger_root())
+    }
 
-        if transaction.len() != 1 {
-            return Err(anyhow!("Multiple transactions associated with commitment {}", commitment.to_string()));
-        }
+    ///
+    /// Returns a ledger proof for the given commitment, with respect to the given ledger root.
+    /// The ledger root may be any past or current ledger root that includes the commitment.
+    ///
+    pub fn state_path(&self, commitment: N::Commitment, ledger_root: &N::LedgerRoot) -> Result<LedgerProof<N>> {
+        let block_height = match self.commitments.get(&commitment) {
+            Some(block_height) => *block_height,
+            None => return Err(anyhow!("Commitment {} does not exist in the ledger", commitment)),
+        };
+        let transactions = self.get_block_transactions(block_height)?;
+
+        let (index, transaction) = match transactions
+            .iter()
+            .enumerate()
+            .find(|(_, transaction)| transaction.commitments().contains(&commitment))
+        {
+            Some((index, transaction)) => (index, transaction),
+            None => return Err(anyhow!("Missing transaction for commitment {}", commitment)),
+        };
 
-        let transaction = transaction[0];
         let local_proof = {
             // Initialize a transitions tree.
             let mut transitions_tree = Transitions::<N>::new()?;
@@ -339,52 +442,19 @@ impl<N: Network> Blocks<N> {
         };
         let transaction_id = local_proof.transaction_id();
 
-        // TODO (howardwu): Optimize this operation.
-        let block_height = self
-            .transactions
-            .iter()
-            .filter_map(|(block_height, transactions)| match transactions.transaction_ids().contains(&transaction_id) {
-                true => Some(block_height),
-                false => None,
-            })
-            .collect::<Vec<_>>();
-
-        if block_height.len() != 1 {
-            return Err(anyhow!("Multiple blocks associated with transaction {}", transaction_id.to_string()));
-        }
-
-        let block_height = *block_height[0];
-        let transactions = self.get_block_transactions(block_height)?;
-        let block_header = self.get_block_header(block_height)?;
-
         // Compute the transactions inclusion proof.
-        let transactions_inclusion_proof = {
-            // TODO (howardwu): Optimize this operation.
-            let index = transactions
-                .transaction_ids()
-                .enumerate()
-                .filter_map(|(index, id)| match id == transaction_id {
-                    true => Some(index),
-                    false => None,
-                })
-                .collect::<Vec<_>>();
-
-            if index.len() != 1 {
-                return Err(anyhow!("Block contains multiple transactions with the id {}", transaction_id.to_string()));
-            }
-
-            transactions.to_transactions_inclusion_proof(index[0], transaction_id)?
-        };
+        let transactions_inclusion_proof = transactions.to_transactions_inclusion_proof(index, transaction_id)?;
 
         // Compute the block header inclusion proof.
+        let block_header = self.get_block_header(block_height)?;
         let transactions_root = transactions.transactions_root();
         let block_header_inclusion_proof = block_header.to_header_inclusion_proof(1, transactions_root)?;
         let block_header_root = block_header.to_header_root()?;
-        let previous_block_hash = self.get_previous_block_hash(self.current_height)?;
-        let current_block_hash = self.current_hash;
+        let block_hash = self.get_block_hash(block_height)?;
+        let previous_block_hash = self.get_previous_block_hash(block_height)?;
 
         let record_proof = RecordProof::new(
-            current_block_hash,
+            block_hash,
             previous_block_hash,
             block_header_root,
             block_header_inclusion_proof,
@@ -393,10 +463,75 @@ impl<N: Network> Blocks<N> {
             local_proof,
         )?;
 
-        let ledger_root = self.latest_ledger_root();
-        let ledger_root_inclusion_proof = self.to_ledger_root_inclusion_proof(&current_block_hash)?;
+        let ledger_root_inclusion_proof = self.ledger_tree.to_ledger_inclusion_proof_at(&block_hash, ledger_root)?;
 
-        LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
+        LedgerProof::new(*ledger_root, ledger_root_inclusion_proof, record_proof)
+    }
+
+    ///
+    /// Applies the given block to the ledger state, updating the ledger tree and indices.
+    /// The ledger tree is updated first, as it is the only fallible step.
+    /// Note: The block must be checked with `check_next` beforehand.
+    ///
+    pub(crate) fn apply_block(&mut self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        let block_hash = block.hash();
+
+        self.ledger_tree.add(&block_hash)?;
+
+        self.current_height = height;
+        self.current_hash = block_hash;
+        self.previous_hashes.insert(height, block.previous_block_hash());
+        self.headers.insert(height, block.header().clone());
+        self.transaction_ids.extend(block.transactions().transaction_ids().map(|id| (id, height)));
+        self.serial_numbers.extend(block.serial_numbers().map(|serial_number| (*serial_number, height)));
+        self.commitments.extend(block.commitments().map(|commitment| (*commitment, height)));
+        self.transactions.insert(height, block.transactions().clone());
+
+        Ok(())
+    }
+
+    /// Adds the given pruned block from a ledger snapshot to the ledger.
+    /// The block hash is computed from its header, whose transactions root must match the transaction state.
+    fn apply_pruned_block(&mut self, pruned_block: &PrunedBlock<N>) -> Result<()> {
+        let header = pruned_block.header();
+        let height = header.height();
+
+        // Ensure the pruned block is the next block, and extends the current ledger root.
+        if self.current_height + 1 != height {
+            return Err(anyhow!("The ledger snapshot has an incorrect block height {}", height));
+        }
+        if self.latest_ledger_root() != header.previous_ledger_root() {
+            return Err(anyhow!("The ledger snapshot has an incorrect previous ledger root for block {}", height));
+        }
+
+        // Ensure the transactions root matches the transaction state.
+        let transaction_ids =
+            pruned_block.transactions().iter().map(TransactionState::to_transaction_id).collect::<Result<Vec<_>>>()?;
+        let transactions_tree = MerkleTree::<N::TransactionsRootParameters>::new(
+            Arc::new(N::transactions_root_parameters().clone()),
+            &transaction_ids,
+        )?;
+        let transactions_root: N::TransactionsRoot = (*transactions_tree.root()).into();
+        if header.transactions_root() != transactions_root {
+            return Err(anyhow!("The ledger snapshot has an incorrect transactions root for block {}", height));
+        }
+
+        let block_hash = Block::compute_block_hash(self.current_hash, header)?;
+        self.ledger_tree.add(&block_hash)?;
+
+        self.previous_hashes.insert(height, self.current_hash);
+        self.current_height = height;
+        self.current_hash = block_hash;
+        self.headers.insert(height, header.clone());
+        self.transaction_ids.extend(transaction_ids.into_iter().map(|id| (id, height)));
+        for transaction in pruned_block.transactions() {
+            self.serial_numbers.extend(transaction.serial_numbers().map(|serial_number| (*serial_number, height)));
+            self.commitments.extend(transaction.commitments().map(|commitment| (*commitment, height)));
+        }
+        self.pruned_transactions.insert(height, pruned_block.transactions().to_vec());
+
+        Ok(())
     }
 
     /// Returns the expected difficulty target given the previous block and expected next block details.
@@ -15,26 +15,101 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::prelude::*;
+use snarkvm_utilities::ToBytes;
 
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, Rng};
-use std::{collections::HashMap, sync::atomic::AtomicBool};
+use rayon::prelude::*;
+use std::{collections::HashMap, ops::Range, path::Path, sync::atomic::AtomicBool};
 use time::OffsetDateTime;
 
 #[derive(Clone, Debug)]
-pub struct Ledger<N: Network> {
+pub struct Ledger<N: Network, S: Storage = MemoryStorage> {
     /// The canonical chain of blocks.
     canon_blocks: Blocks<N>,
     /// The set of unknown orphan blocks.
     orphan_blocks: HashMap<u32, Block<N>>,
     /// The pool of unconfirmed transactions.
     memory_pool: MemoryPool<N>,
+    /// The storage of the canonical chain.
+    storage: S,
 }
 
 impl<N: Network> Ledger<N> {
-    /// Initializes a new instance of the ledger.
+    /// Initializes a new instance of the ledger, in memory.
     pub fn new() -> Result<Self> {
-        Ok(Self { canon_blocks: Blocks::new()?, orphan_blocks: Default::default(), memory_pool: MemoryPool::new() })
+        Self::from_storage(MemoryStorage::new(), false)
+    }
+
+    /// Restores the ledger, in memory, from the snapshot at the given path, which must end at the given block hash.
+    /// The restored ledger does not contain the transactions of the blocks preceding the latest block of the snapshot,
+    /// and returns an error for any block, ledger proof, or record scan that requires them.
+    pub fn restore<P: AsRef<Path>>(path: P, block_hash: &N::BlockHash) -> Result<Self> {
+        let snapshot = LedgerSnapshot::<N>::read_from(path)?;
+
+        let ledger = Self {
+            canon_blocks: Blocks::from_snapshot(&snapshot, block_hash)?,
+            orphan_blocks: Default::default(),
+            memory_pool: MemoryPool::new(),
+            storage: MemoryStorage::new(),
+        };
+        ledger.store_block(N::genesis_block())?;
+        ledger.store_block(snapshot.latest_block())?;
+
+        Ok(ledger)
+    }
+}
+
+impl<N: Network, S: Storage> Ledger<N, S> {
+    /// Opens the ledger from the storage at the given path, trusting its stored blocks.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_storage(S::open(path)?, false)
+    }
+
+    ///
+    /// Initializes the ledger from the given storage. If the storage is empty,
+    /// it is initialized with the genesis block. Otherwise, the stored blocks
+    /// are added to the canon chain in order of block height.
+    ///
+    /// If `verify_blocks` is `true`, every stored block is fully verified, including its proofs.
+    /// Otherwise, the stored blocks are trusted, as they were verified before they were stored:
+    /// only their block hashes and their links to the previous block are checked,
+    /// and the latest block is fully verified.
+    ///
+    pub fn from_storage(storage: S, verify_blocks: bool) -> Result<Self> {
+        let mut ledger = Self {
+            canon_blocks: Blocks::new()?,
+            orphan_blocks: Default::default(),
+            memory_pool: MemoryPool::new(),
+            storage,
+        };
+
+        // Ensure the storage is for the same genesis block, or initialize it.
+        let genesis_block = N::genesis_block();
+        match ledger.load_block(genesis_block.height())? {
+            Some(block) if block.hash() != genesis_block.hash() => {
+                return Err(anyhow!("Storage contains a different genesis block"));
+            }
+            Some(_) => (),
+            None => ledger.store_block(genesis_block)?,
+        }
+
+        // Add the stored blocks to the canon chain.
+        let mut next_block = ledger.load_block(ledger.latest_block_height() + 1)?;
+        while let Some(block) = next_block {
+            next_block = ledger.load_block(block.height() + 1)?;
+            match verify_blocks || next_block.is_none() {
+                true => ledger.canon_blocks.add_next(&block)?,
+                false => ledger.canon_blocks.add_next_trusted(&block)?,
+            }
+        }
+
+        Ok(ledger)
+    }
+
+    /// Writes a snapshot of the ledger state to the given path, from which the ledger may be restored.
+    pub fn snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.canon_blocks.to_snapshot()?.write_to(path)
     }
 
     /// Returns the latest block height.
@@ -92,11 +167,56 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Returns `true` if the given serial number exists on the canon chain.
+    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
+        self.canon_blocks.contains_serial_number(serial_number)
+    }
+
+    /// Returns `true` if the given commitment exists on the canon chain.
+    pub fn contains_commitment(&self, commitment: &N::Commitment) -> bool {
+        self.canon_blocks.contains_commitment(commitment)
+    }
+
+    ///
+    /// Returns the records owned by the given account view key in the given range of canon block heights.
+    /// The range is clamped to the latest block height, and the returned cursor is the block height
+    /// from which to resume scanning once new blocks are added.
+    ///
+    pub fn scan(&self, view_key: &ViewKey<N>, block_range: Range<u32>) -> Result<RecordScan<N>> {
+        let start = block_range.start;
+        let end = block_range.end.min(self.latest_block_height().saturating_add(1)).max(start);
+
+        // Trial-decrypt the record ciphertexts of each block in parallel.
+        let decryption_key = DecryptionKey::from(view_key);
+        let records = (start..end)
+            .into_par_iter()
+            .map(|height| {
+                let transactions = self.canon_blocks.get_block_transactions(height)?;
+                Ok(transactions
+                    .par_iter()
+                    .flat_map_iter(|transaction| transaction.to_decrypted_records(&decryption_key))
+                    .map(|record| (height, record))
+                    .collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RecordScan::new(records.into_iter().flatten().collect(), end))
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
-        // Attempt to insert the block into canon.
-        self.canon_blocks.add_next(block)?;
+        // Ensure the block is valid as the next canon block.
+        self.canon_blocks.check_next(block)?;
+
+        // Persist the block in storage, before inserting it into canon.
+        self.store_block(block)?;
+
+        // Insert the block into canon, and remove it from storage if this fails.
+        if let Err(error) = self.canon_blocks.apply_block(block) {
+            self.remove_block(block)?;
+            return Err(error);
+        }
 
         Ok(())
     }
@@ -208,6 +328,67 @@ impl<N: Network> Ledger<N> {
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
         self.canon_blocks.to_ledger_proof(commitment)
     }
+
+    ///
+    /// Returns the ledger proof for the given commitment with the given past or current ledger root.
+    ///
+    pub fn state_path(&self, commitment: N::Commitment, ledger_root: &N::LedgerRoot) -> Result<LedgerProof<N>> {
+        self.canon_blocks.state_path(commitment, ledger_root)
+    }
+
+    ///
+    /// Returns the block at the given height from storage, if it exists.
+    /// The block is not verified, but its hash must match the stored block hash for the height.
+    ///
+    fn load_block(&self, height: u32) -> Result<Option<Block<N>>> {
+        let block_hash = match self.storage.get(Column::BlockHashes, &height.to_le_bytes())? {
+            Some(block_hash) => block_hash,
+            None => return Ok(None),
+        };
+        let block = match self.storage.get(Column::Blocks, &block_hash)? {
+            Some(block) => Block::<N>::read_le_unchecked(&block[..])?,
+            None => return Err(anyhow!("Missing block for height {} in storage", height)),
+        };
+        match block.height() == height && block.hash().to_bytes_le()? == block_hash {
+            true => Ok(Some(block)),
+            false => Err(anyhow!("Mismatching block for height {} in storage", height)),
+        }
+    }
+
+    /// Persists the given block and its indices in storage.
+    fn store_block(&self, block: &Block<N>) -> Result<()> {
+        self.storage.put_all(&Self::to_storage_entries(block)?)
+    }
+
+    /// Removes the given block and its indices from storage.
+    fn remove_block(&self, block: &Block<N>) -> Result<()> {
+        for (column, key, _) in Self::to_storage_entries(block)? {
+            self.storage.remove(column, &key)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the storage entries for the given block and its indices.
+    fn to_storage_entries(block: &Block<N>) -> Result<Vec<(Column, Vec<u8>, Vec<u8>)>> {
+        let block_hash = block.hash().to_bytes_le()?;
+
+        let mut entries = vec![
+            (Column::BlockHashes, block.height().to_le_bytes().to_vec(), block_hash.clone()),
+            (Column::Blocks, block_hash.clone(), block.to_bytes_le()?),
+        ];
+        for transaction in block.transactions().iter() {
+            let transaction_id = transaction.transaction_id().to_bytes_le()?;
+            for serial_number in transaction.serial_numbers() {
+                entries.push((Column::SerialNumbers, serial_number.to_bytes_le()?, transaction_id.clone()));
+            }
+            for commitment in transaction.commitments() {
+                entries.push((Column::Commitments, commitment.to_bytes_le()?, transaction_id.clone()));
+            }
+            entries.push((Column::Transactions, transaction_id, block_hash.clone()));
+        }
+
+        Ok(entries)
+    }
 }
 
 #[cfg(test)]
@@ -226,6 +407,185 @@ mod tests {
         assert_eq!(0, ledger.latest_block_height());
     }
 
+    #[test]
+    fn test_from_storage() {
+        let rng = &mut thread_rng();
+
+        let storage = MemoryStorage::new();
+        let mut ledger = Ledger::<Testnet2>::from_storage(storage.clone(), false).unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        for _ in 0..2 {
+            ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+        }
+
+        // Ensure the ledger is restored from its storage, whether the stored blocks are trusted or verified.
+        for verify_blocks in [false, true] {
+            let restored_ledger = Ledger::<Testnet2>::from_storage(storage.clone(), verify_blocks).unwrap();
+            assert_eq!(2, restored_ledger.latest_block_height());
+            assert_eq!(ledger.latest_block_hash(), restored_ledger.latest_block_hash());
+            assert_eq!(ledger.latest_ledger_root(), restored_ledger.latest_ledger_root());
+            assert!(restored_ledger.contains_commitment(ledger.latest_block().unwrap().commitments().next().unwrap()));
+        }
+
+        // Ensure a stored block that does not match its stored block hash is rejected.
+        let block_hash = storage.get(Column::BlockHashes, &1u32.to_le_bytes()).unwrap().unwrap();
+        storage.put(Column::BlockHashes, &2u32.to_le_bytes(), &block_hash).unwrap();
+        assert!(Ledger::<Testnet2>::from_storage(storage, false).is_err());
+    }
+
+    #[test]
+    fn test_state_path() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let genesis_ledger_root = ledger.latest_ledger_root();
+        let commitment = *ledger.latest_block().unwrap().commitments().next().unwrap();
+
+        let mut ledger_roots = vec![genesis_ledger_root];
+        let mut block_hashes = vec![ledger.latest_block_hash()];
+        for _ in 0..3 {
+            ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+            ledger_roots.push(ledger.latest_ledger_root());
+            block_hashes.push(ledger.latest_block_hash());
+        }
+
+        // Ensure the incrementally maintained ledger root matches a ledger tree built from scratch.
+        let mut expected_ledger_tree = LedgerTree::<Testnet2>::new().unwrap();
+        expected_ledger_tree.add_all(&block_hashes).unwrap();
+        assert_eq!(expected_ledger_tree.root(), ledger.latest_ledger_root());
+
+        // Ensure a state path is available against every historical ledger root.
+        for ledger_root in &ledger_roots {
+            assert!(ledger.contains_ledger_root(ledger_root));
+            let ledger_proof = ledger.state_path(commitment, ledger_root).unwrap();
+            assert_eq!(*ledger_root, ledger_proof.ledger_root());
+            assert_eq!(block_hashes[0], ledger_proof.block_hash());
+        }
+
+        // Ensure a state path for a commitment in a later block is rejected against an earlier ledger root.
+        let commitment = *ledger.latest_block().unwrap().commitments().next().unwrap();
+        assert!(ledger.state_path(commitment, &genesis_ledger_root).is_err());
+        assert!(ledger.state_path(commitment, &ledger.latest_ledger_root()).is_ok());
+    }
+
+    #[test]
+    fn test_scan() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let coinbase_record = ledger.mine_next_block(account.address(), false, &AtomicBool::new(false), rng).unwrap();
+
+        // Ensure the coinbase record is found by scanning the full chain.
+        let record_scan = ledger.scan(account.view_key(), 0..u32::MAX).unwrap();
+        assert_eq!(1, record_scan.len());
+        assert_eq!(2, record_scan.cursor());
+        assert_eq!(1, record_scan.records()[0].0);
+        assert_eq!(coinbase_record.commitment(), record_scan.records()[0].1.commitment());
+        assert_eq!(account.address(), record_scan.records()[0].1.owner());
+
+        // Ensure resuming from the cursor only scans new blocks.
+        let record_scan = ledger.scan(account.view_key(), record_scan.cursor()..u32::MAX).unwrap();
+        assert!(record_scan.is_empty());
+        assert_eq!(2, record_scan.cursor());
+
+        // Ensure records are not found for another account.
+        let other_account = Account::<Testnet2>::new(rng);
+        assert!(ledger.scan(other_account.view_key(), 0..u32::MAX).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_verify_fast() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let other_ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+
+        // Ensure the mined block is valid as the next block of a ledger without it.
+        let block = ledger.latest_block().unwrap();
+        assert!(block.verify_fast(&other_ledger));
+
+        // Ensure the mined block is rejected by the ledger that already contains it.
+        assert!(!block.verify_fast(&ledger));
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+        let pruned_block = ledger.latest_block().unwrap();
+        let previous_ledger_root = ledger.latest_ledger_root();
+        ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+        let latest_block = ledger.latest_block().unwrap();
+        let ledger_root = ledger.latest_ledger_root();
+
+        let directory = std::env::temp_dir().join(format!("snarkvm-ledger-snapshot-{}", rng.gen::<u64>()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("ledger.snapshot");
+        ledger.snapshot(&path).unwrap();
+
+        // Ensure the snapshot must end at the given block hash.
+        assert!(Ledger::<Testnet2>::restore(&path, &pruned_block.hash()).is_err());
+
+        // Ensure the restored ledger matches the original ledger state.
+        let mut restored_ledger = Ledger::<Testnet2>::restore(&path, &latest_block.hash()).unwrap();
+        assert_eq!(ledger.latest_block_height(), restored_ledger.latest_block_height());
+        assert_eq!(ledger.latest_block_hash(), restored_ledger.latest_block_hash());
+        assert_eq!(ledger_root, restored_ledger.latest_ledger_root());
+        assert!(restored_ledger.contains_ledger_root(&previous_ledger_root));
+        for block in [&pruned_block, &latest_block] {
+            for transaction in block.transactions().iter() {
+                assert!(restored_ledger.contains_transaction(transaction));
+            }
+            for serial_number in block.serial_numbers() {
+                assert!(restored_ledger.contains_serial_number(serial_number));
+            }
+            for commitment in block.commitments() {
+                assert!(restored_ledger.contains_commitment(commitment));
+            }
+        }
+
+        // Ensure the ledger proofs and records of the latest block are available.
+        let commitment = *latest_block.commitments().next().unwrap();
+        let ledger_proof = restored_ledger.to_ledger_proof(commitment).unwrap();
+        assert_eq!(
+            ledger_proof.to_bytes_le().unwrap(),
+            ledger.to_ledger_proof(commitment).unwrap().to_bytes_le().unwrap()
+        );
+        assert_eq!(
+            ledger.scan(recipient.view_key(), 2..3).unwrap().records(),
+            restored_ledger.scan(recipient.view_key(), 2..3).unwrap().records()
+        );
+
+        // Ensure the ledger proofs and records of the pruned block are not available.
+        let commitment = *pruned_block.commitments().next().unwrap();
+        assert!(restored_ledger.to_ledger_proof(commitment).is_err());
+        assert!(restored_ledger.scan(recipient.view_key(), 0..3).is_err());
+
+        // Ensure the restored ledger can extend the chain, and be restored from its own snapshot.
+        restored_ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+        assert_eq!(3, restored_ledger.latest_block_height());
+        restored_ledger.snapshot(&path).unwrap();
+        let restored_again = Ledger::<Testnet2>::restore(&path, &restored_ledger.latest_block_hash()).unwrap();
+        assert_eq!(restored_ledger.latest_ledger_root(), restored_again.latest_ledger_root());
+
+        // Ensure a corrupted snapshot is rejected.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let index = bytes.len() / 2;
+        bytes[index] ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+        assert!(Ledger::<Testnet2>::restore(&path, &restored_ledger.latest_block_hash()).is_err());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
     #[test]
     fn test_mine_next_block() {
         let rng = &mut thread_rng();
@@ -31,6 +31,8 @@ pub struct LedgerTree<N: Network> {
     #[derivative(Debug = "ignore")]
     tree: Arc<MerkleTree<N::LedgerRootParameters>>,
     block_hashes: HashMap<N::BlockHash, u32>,
+    /// The historical ledger roots, mapped to the number of block hashes they commit to.
+    ledger_roots: HashMap<N::LedgerRoot, u32>,
     current_index: u32,
 }
 
@@ -43,6 +45,7 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
                 &[],
             )?),
             block_hashes: Default::default(),
+            ledger_roots: Default::default(),
             current_index: 0,
         })
     }
@@ -54,14 +57,21 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
             return Err(MerkleError::Message(format!("{} already exists in the ledger tree", block_hash)).into());
         }
 
-        self.tree = Arc::new(self.tree.rebuild(self.current_index as usize, &[block_hash])?);
-        self.block_hashes.insert(*block_hash, self.current_index);
-
         let current_index = self.current_index;
-        self.current_index = current_index
+        let next_index = current_index
             .checked_add(1)
             .ok_or_else(|| anyhow!("The index exceeds the maximum number of allowed block hashes."))?;
 
+        // If the tree has spare capacity, only the path from the new leaf to the root is recomputed.
+        // Otherwise, the tree is rebuilt with double the capacity, reusing the existing leaf hashes.
+        match (current_index as usize) < self.tree.hashed_leaves().len() {
+            true => Arc::make_mut(&mut self.tree).update(current_index as usize, block_hash)?,
+            false => self.tree = Arc::new(self.tree.rebuild(current_index as usize, &[block_hash])?),
+        }
+        self.block_hashes.insert(*block_hash, current_index);
+        self.current_index = next_index;
+        self.ledger_roots.insert(self.root(), next_index);
+
         Ok(current_index)
     }
 
@@ -107,6 +117,7 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
             .current_index
             .checked_add(num_block_hashes as u32)
             .ok_or_else(|| anyhow!("The index exceeds the maximum number of allowed block hashes."))?;
+        self.ledger_roots.insert(self.root(), self.current_index);
         let end_index = self.current_index.checked_sub(1).ok_or_else(|| anyhow!("Integer underflow."))?;
 
         Ok((start_index, end_index))
@@ -122,6 +133,11 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
         self.block_hashes.get(block_hash)
     }
 
+    /// Returns `true` if the given ledger root was the root of the tree at any point.
+    fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        self.ledger_roots.contains_key(ledger_root)
+    }
+
     /// Returns the ledger root.
     fn root(&self) -> N::LedgerRoot {
         (*self.tree.root()).into()
@@ -134,6 +150,37 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
             _ => Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
         }
     }
+
+    /// Returns the Merkle path for a given block hash, with respect to the given historical ledger root.
+    fn to_ledger_inclusion_proof_at(
+        &self,
+        block_hash: &N::BlockHash,
+        ledger_root: &N::LedgerRoot,
+    ) -> Result<MerklePath<N::LedgerRootParameters>> {
+        let num_block_hashes = match self.ledger_roots.get(ledger_root) {
+            Some(num_block_hashes) => *num_block_hashes,
+            None => return Err(anyhow!("Ledger root {} does not exist in the ledger tree", ledger_root)),
+        };
+
+        let index = match self.get_block_hash_index(block_hash) {
+            Some(index) if *index < num_block_hashes => *index,
+            Some(_) => return Err(anyhow!("Block hash {} is not in ledger root {}", block_hash, ledger_root)),
+            None => return Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
+        };
+
+        // The latest ledger root is served directly from the current tree.
+        if num_block_hashes == self.current_index {
+            return Ok(self.tree.generate_proof(index as usize, block_hash)?);
+        }
+
+        // Reconstruct the tree as of the given ledger root from the cached leaf hashes.
+        let tree = self.tree.rebuild::<N::BlockHash>(num_block_hashes as usize, &[])?;
+        if N::LedgerRoot::from(*tree.root()) != *ledger_root {
+            return Err(anyhow!("Failed to reconstruct the ledger tree for ledger root {}", ledger_root));
+        }
+
+        Ok(tree.generate_proof(index as usize, block_hash)?)
+    }
 }
 
 impl<N: Network> Default for LedgerTree<N> {
@@ -35,5 +35,11 @@ pub use memory_pool::*;
 pub(crate) mod record_proof;
 pub(crate) use record_proof::*;
 
+pub mod record_scan;
+pub use record_scan::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
 pub(crate) mod transitions;
 pub(crate) use transitions::*;
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Network, Record};
+
+/// The records found by scanning a range of canon blocks with an account view key.
+#[derive(Clone, Debug)]
+pub struct RecordScan<N: Network> {
+    /// The decrypted records, paired with the height of the block they were found in.
+    records: Vec<(u32, Record<N>)>,
+    /// The block height from which to resume scanning.
+    cursor: u32,
+}
+
+impl<N: Network> RecordScan<N> {
+    /// Initializes a new instance of a record scan.
+    pub(crate) fn new(records: Vec<(u32, Record<N>)>, cursor: u32) -> Self {
+        Self { records, cursor }
+    }
+
+    /// Returns the decrypted records, paired with the height of the block they were found in.
+    pub fn records(&self) -> &[(u32, Record<N>)] {
+        &self.records
+    }
+
+    /// Returns the block height from which to resume scanning.
+    pub fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    /// Returns the number of records found.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if no records were found.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the decrypted records, consuming the record scan.
+    pub fn into_records(self) -> Vec<(u32, Record<N>)> {
+        self.records
+    }
+}
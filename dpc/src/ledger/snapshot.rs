@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, BlockHeader, Network, Transaction, Transition};
+use snarkvm_algorithms::merkle_tree::MerkleTree;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use blake2::{Blake2s256, Digest};
+use std::{
+    fs,
+    io::{Read, Result as IoResult, Write},
+    path::Path,
+    sync::Arc,
+};
+
+/// The magic bytes identifying a ledger snapshot.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"ALEOSNAP";
+/// The version of the ledger snapshot format.
+const SNAPSHOT_VERSION: u16 = 2;
+/// The size of the snapshot checksum in bytes.
+const CHECKSUM_SIZE: usize = 32;
+
+/// The serial numbers and commitments of each transition in a transaction.
+/// The transaction ID is recomputed from this state, which binds it to the transactions root of its block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionState<N: Network> {
+    transitions: Vec<(Vec<N::SerialNumber>, Vec<N::Commitment>)>,
+}
+
+impl<N: Network> TransactionState<N> {
+    /// Initializes the transaction state of the given transaction.
+    pub fn from_transaction(transaction: &Transaction<N>) -> Self {
+        let transitions = transaction
+            .transitions()
+            .iter()
+            .map(|transition| {
+                (transition.serial_numbers().copied().collect(), transition.commitments().copied().collect())
+            })
+            .collect();
+        Self { transitions }
+    }
+
+    /// Returns the transaction ID, computed from the transition IDs.
+    pub fn to_transaction_id(&self) -> Result<N::TransactionID> {
+        let transition_ids = self
+            .transitions
+            .iter()
+            .map(|(serial_numbers, commitments)| Transition::<N>::compute_transition_id(serial_numbers, commitments))
+            .collect::<Result<Vec<_>>>()?;
+        let tree = MerkleTree::<N::TransactionIDParameters>::new(
+            Arc::new(N::transaction_id_parameters().clone()),
+            &transition_ids,
+        )?;
+        Ok((*tree.root()).into())
+    }
+
+    /// Returns the serial numbers of the transaction.
+    pub fn serial_numbers(&self) -> impl Iterator<Item = &N::SerialNumber> {
+        self.transitions.iter().flat_map(|(serial_numbers, _)| serial_numbers)
+    }
+
+    /// Returns the commitments of the transaction.
+    pub fn commitments(&self) -> impl Iterator<Item = &N::Commitment> {
+        self.transitions.iter().flat_map(|(_, commitments)| commitments)
+    }
+}
+
+/// A block in a ledger snapshot, with its transactions pruned to their serial numbers and commitments.
+#[derive(Clone, Debug)]
+pub struct PrunedBlock<N: Network> {
+    /// The block header.
+    header: BlockHeader<N>,
+    /// The state of each transaction in the block.
+    transactions: Vec<TransactionState<N>>,
+}
+
+impl<N: Network> PrunedBlock<N> {
+    /// Initializes a new pruned block.
+    pub fn new(header: BlockHeader<N>, transactions: Vec<TransactionState<N>>) -> Self {
+        Self { header, transactions }
+    }
+
+    /// Returns the block header.
+    pub fn header(&self) -> &BlockHeader<N> {
+        &self.header
+    }
+
+    /// Returns the state of each transaction in the block.
+    pub fn transactions(&self) -> &[TransactionState<N>] {
+        &self.transactions
+    }
+}
+
+///
+/// A checkpoint of the ledger state, from which a ledger may be restored without replaying the chain.
+///
+/// The snapshot contains every block header, with the blocks preceding the latest block pruned
+/// to the serial numbers and commitments of their transactions. The ledger state is bound to
+/// the block hashes of the snapshot, and a restored ledger must end at a trusted block hash.
+/// The checksum only detects data corruption.
+///
+#[derive(Clone, Debug)]
+pub struct LedgerSnapshot<N: Network> {
+    /// The pruned blocks from height 1 up to, and excluding, the latest block.
+    pruned_blocks: Vec<PrunedBlock<N>>,
+    /// The latest block.
+    latest_block: Block<N>,
+}
+
+impl<N: Network> LedgerSnapshot<N> {
+    /// Initializes a new ledger snapshot.
+    pub(crate) fn new(pruned_blocks: Vec<PrunedBlock<N>>, latest_block: Block<N>) -> Self {
+        Self { pruned_blocks, latest_block }
+    }
+
+    /// Reads a ledger snapshot from the given path, ensuring its checksum is valid.
+    pub fn read_from<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let bytes = fs::read(path)?;
+        if bytes.len() < CHECKSUM_SIZE {
+            return Err(anyhow!("The ledger snapshot is truncated"));
+        }
+
+        // Ensure the checksum matches the snapshot contents.
+        let (snapshot, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if Blake2s256::digest(snapshot).as_slice() != checksum {
+            return Err(anyhow!("The ledger snapshot checksum is invalid, possible data corruption"));
+        }
+
+        let mut reader = snapshot;
+        let snapshot = Self::read_le(&mut reader)?;
+        match reader.is_empty() {
+            true => Ok(snapshot),
+            false => Err(anyhow!("The ledger snapshot contains trailing bytes")),
+        }
+    }
+
+    /// Writes the ledger snapshot to the given path, followed by its checksum.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let snapshot = self.to_bytes_le()?;
+        let checksum = Blake2s256::digest(&snapshot);
+        fs::write(path, [&snapshot[..], checksum.as_slice()].concat())?;
+        Ok(())
+    }
+
+    /// Returns the pruned blocks from height 1 up to, and excluding, the latest block.
+    pub fn pruned_blocks(&self) -> &[PrunedBlock<N>] {
+        &self.pruned_blocks
+    }
+
+    /// Returns the latest block.
+    pub fn latest_block(&self) -> &Block<N> {
+        &self.latest_block
+    }
+}
+
+impl<N: Network> FromBytes for TransactionState<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Note: The elements are read one by one, as the given lengths are not trusted.
+        let num_transitions: u16 = FromBytes::read_le(&mut reader)?;
+        let transitions = (0..num_transitions)
+            .map(|_| {
+                let num_serial_numbers: u16 = FromBytes::read_le(&mut reader)?;
+                let serial_numbers =
+                    (0..num_serial_numbers).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+                let num_commitments: u16 = FromBytes::read_le(&mut reader)?;
+                let commitments =
+                    (0..num_commitments).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+                Ok((serial_numbers, commitments))
+            })
+            .collect::<IoResult<_>>()?;
+
+        Ok(Self { transitions })
+    }
+}
+
+impl<N: Network> ToBytes for TransactionState<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.transitions.len() as u16).write_le(&mut writer)?;
+        for (serial_numbers, commitments) in &self.transitions {
+            (serial_numbers.len() as u16).write_le(&mut writer)?;
+            serial_numbers.write_le(&mut writer)?;
+            (commitments.len() as u16).write_le(&mut writer)?;
+            commitments.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for PrunedBlock<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let header = FromBytes::read_le(&mut reader)?;
+
+        // Note: The elements are read one by one, as the given length is not trusted.
+        let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
+        let transactions = (0..num_transactions).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        Ok(Self::new(header, transactions))
+    }
+}
+
+impl<N: Network> ToBytes for PrunedBlock<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.header.write_le(&mut writer)?;
+        (self.transactions.len() as u16).write_le(&mut writer)?;
+        self.transactions.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for LedgerSnapshot<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(error("Invalid ledger snapshot magic bytes"));
+        }
+
+        let version: u16 = FromBytes::read_le(&mut reader)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(error("Unsupported ledger snapshot version"));
+        }
+
+        let network_id: u16 = FromBytes::read_le(&mut reader)?;
+        if network_id != N::NETWORK_ID {
+            return Err(error("The ledger snapshot is for a different network"));
+        }
+
+        // Note: The elements are read one by one, as the given length is not trusted.
+        let num_pruned_blocks: u32 = FromBytes::read_le(&mut reader)?;
+        let pruned_blocks = (0..num_pruned_blocks).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        let latest_block = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self::new(pruned_blocks, latest_block))
+    }
+}
+
+impl<N: Network> ToBytes for LedgerSnapshot<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        writer.write_all(&SNAPSHOT_MAGIC)?;
+        SNAPSHOT_VERSION.write_le(&mut writer)?;
+        N::NETWORK_ID.write_le(&mut writer)?;
+
+        (self.pruned_blocks.len() as u32).write_le(&mut writer)?;
+        self.pruned_blocks.write_le(&mut writer)?;
+
+        self.latest_block.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_untrusted_lengths() {
+        // Ensure a snapshot declaring more pruned blocks than it contains is rejected, without preallocating them.
+        let mut snapshot = SNAPSHOT_MAGIC.to_vec();
+        snapshot.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        snapshot.extend_from_slice(&Testnet2::NETWORK_ID.to_le_bytes());
+        snapshot.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(LedgerSnapshot::<Testnet2>::read_le(&snapshot[..]).is_err());
+
+        // Ensure a transaction state declaring more elements than it contains is rejected.
+        assert!(TransactionState::<Testnet2>::read_le(&u16::MAX.to_le_bytes()[..]).is_err());
+        assert!(TransactionState::<Testnet2>::read_le(&[1u8, 0, 255, 255][..]).is_err());
+    }
+}
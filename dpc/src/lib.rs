@@ -49,6 +49,9 @@ pub use posw::*;
 pub mod record;
 pub use record::*;
 
+pub mod storage;
+pub use storage::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -68,6 +71,7 @@ pub mod prelude {
         errors::*,
         ledger::*,
         record::*,
+        storage::*,
         traits::*,
         transaction::*,
         value_balance::*,
@@ -31,14 +31,28 @@ macro_rules! dpc_snark_setup {
         fn $fn_name() -> &'static <Self::$snark_type as SNARK>::$key_type {
             static PARAMETER: OnceCell<<<$network as Network>::$snark_type as SNARK>::$key_type> = OnceCell::new();
             PARAMETER.get_or_init(|| {
-                <Self::$snark_type as SNARK>::$key_type::read_le(
-                    $parameter::load_bytes().expect(&format!("Failed to load parameter bytes for {}", $message)).as_slice()
-                ).expect(&format!("Failed to read {} from bytes", $message))
+                $parameter::load::<<Self::$snark_type as SNARK>::$key_type>()
+                    .expect(&format!("Failed to load {}", $message))
             })
         }
     };
 }
 
+#[rustfmt::skip]
+macro_rules! dpc_snark_cache {
+    ($network: ident, $fn_name: ident, $snark_type: ident, $key_type: ident, $parameter: ident, $message: expr) => {
+        #[inline]
+        fn $fn_name() -> Arc<<Self::$snark_type as SNARK>::$key_type> {
+            ParameterCache::global().get_or_load(concat!(stringify!($network), "::", stringify!($fn_name)), || {
+                let key = $parameter::load::<<Self::$snark_type as SNARK>::$key_type>()?;
+                // The key is budgeted by its in-memory size, which is far larger than its serialized size.
+                let size = key.memory_footprint();
+                Ok((key, size))
+            }).expect(&format!("Failed to load {}", $message))
+        }
+    };
+}
+
 /// Converts a string of 2 characters into a `u16` for a human-readable prefix in Bech32.
 #[macro_export]
 macro_rules! hrp2 {
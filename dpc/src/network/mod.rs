@@ -23,6 +23,9 @@ pub mod macros;
 pub mod object;
 pub use object::*;
 
+pub mod parameter_cache;
+pub use parameter_cache::*;
+
 #[cfg(feature = "testnet1")]
 pub mod testnet1;
 
@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::OnceCell;
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// A cache of deserialized parameters, which are loaded on first use and shared across threads.
+///
+/// Each parameter is loaded at most once at a time, without blocking callers of other parameters.
+/// Once the total in-memory size of the loaded parameters exceeds the memory budget, the least recently used
+/// parameters are evicted. An evicted parameter remains alive for as long as a caller holds onto it,
+/// and is loaded again on its next use.
+pub struct ParameterCache {
+    state: Mutex<CacheState>,
+}
+
+struct CacheState {
+    /// The maximum total in-memory size of the loaded parameters, in bytes.
+    memory_budget: usize,
+    /// The total in-memory size of the loaded parameters, in bytes.
+    size: usize,
+    /// A logical clock, incremented on every access.
+    clock: u64,
+    entries: HashMap<&'static str, CacheEntry>,
+}
+
+struct CacheEntry {
+    /// The slot holding the parameter and its in-memory size, filled by the first caller to load it.
+    slot: Arc<OnceCell<(Arc<dyn Any + Send + Sync>, usize)>>,
+    /// The in-memory size of the parameter, in bytes, once it has been loaded and accounted for.
+    size: Option<usize>,
+    last_used: u64,
+}
+
+impl ParameterCache {
+    /// The default memory budget of 2 GiB.
+    pub const DEFAULT_MEMORY_BUDGET: usize = 2 * 1024 * 1024 * 1024;
+
+    /// Initializes an empty parameter cache with the given memory budget, in bytes.
+    pub fn new(memory_budget: usize) -> Self {
+        Self { state: Mutex::new(CacheState { memory_budget, size: 0, clock: 0, entries: Default::default() }) }
+    }
+
+    /// Returns the parameter cache shared by the process.
+    pub fn global() -> &'static Self {
+        static CACHE: OnceCell<ParameterCache> = OnceCell::new();
+        CACHE.get_or_init(|| Self::new(Self::DEFAULT_MEMORY_BUDGET))
+    }
+
+    /// Returns the memory budget, in bytes.
+    pub fn memory_budget(&self) -> usize {
+        self.state().memory_budget
+    }
+
+    /// Sets the memory budget, in bytes, evicting parameters until the cache fits within it.
+    pub fn set_memory_budget(&self, memory_budget: usize) {
+        let mut state = self.state();
+        state.memory_budget = memory_budget;
+        state.evict(None);
+    }
+
+    /// Returns the total in-memory size of the loaded parameters, in bytes.
+    pub fn size(&self) -> usize {
+        self.state().size
+    }
+
+    /// Returns `true` if the parameter with the given key is loaded in the cache.
+    pub fn contains(&self, key: &str) -> bool {
+        self.state().entries.get(key).map_or(false, |entry| entry.size.is_some())
+    }
+
+    /// Returns the parameter with the given key, loading it on first use.
+    ///
+    /// The loader returns the parameter along with its in-memory size in bytes. It is called without holding
+    /// the cache lock, so other parameters remain available while it runs, and concurrent callers of the same
+    /// parameter wait for it to finish rather than loading the parameter twice.
+    pub fn get_or_load<T: Any + Send + Sync, F: FnOnce() -> Result<(T, usize)>>(
+        &self,
+        key: &'static str,
+        load: F,
+    ) -> Result<Arc<T>> {
+        // Fetch the slot for the parameter, creating an empty one on first use.
+        let slot = {
+            let mut state = self.state();
+            state.clock += 1;
+            let clock = state.clock;
+
+            let entry = state.entries.entry(key).or_insert_with(|| CacheEntry {
+                slot: Default::default(),
+                size: None,
+                last_used: clock,
+            });
+            entry.last_used = clock;
+            entry.slot.clone()
+        };
+
+        // Load the parameter into its slot, unless another caller already has.
+        let result = slot.get_or_try_init(|| {
+            let (value, size) = load()?;
+            Ok::<_, anyhow::Error>((Arc::new(value) as Arc<dyn Any + Send + Sync>, size))
+        });
+
+        let mut state = self.state();
+        match result {
+            Ok((value, size)) => {
+                // Account for the parameter once, provided its slot was not evicted while it was loading.
+                let is_new = match state.entries.get_mut(key) {
+                    Some(entry) if Arc::ptr_eq(&entry.slot, &slot) && entry.size.is_none() => {
+                        entry.size = Some(*size);
+                        true
+                    }
+                    _ => false,
+                };
+                if is_new {
+                    state.size += size;
+                    state.evict(Some(key));
+                }
+
+                value.clone().downcast::<T>().map_err(|_| anyhow!("Parameter {} is cached with a different type", key))
+            }
+            Err(error) => {
+                // Remove the empty slot, so that the next caller retries the load.
+                if matches!(state.entries.get(key), Some(entry) if Arc::ptr_eq(&entry.slot, &slot) && entry.size.is_none())
+                {
+                    state.entries.remove(key);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Removes the parameter with the given key from the cache, returning `true` if it was cached.
+    pub fn evict(&self, key: &str) -> bool {
+        let mut state = self.state();
+        match state.entries.remove(key) {
+            Some(entry) => {
+                state.size -= entry.size.unwrap_or(0);
+                entry.size.is_some()
+            }
+            None => false,
+        }
+    }
+
+    /// Removes every parameter from the cache.
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.size = 0;
+    }
+
+    fn state(&self) -> MutexGuard<CacheState> {
+        // A panicking loader leaves the state consistent, as entries are only accounted for once loaded.
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl CacheState {
+    /// Evicts the least recently used parameters, other than the given one, until the cache fits within its budget.
+    /// Parameters that are still loading hold no memory in the cache, and are left in place.
+    fn evict(&mut self, keep: Option<&str>) {
+        while self.size > self.memory_budget {
+            let lru = self
+                .entries
+                .iter()
+                .filter(|(key, entry)| Some(**key) != keep && entry.size.is_some())
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key);
+
+            match lru {
+                Some(key) => {
+                    let entry = self.entries.remove(key).expect("Cached parameter must exist");
+                    self.size -= entry.size.unwrap_or(0);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, sync::mpsc, thread};
+
+    #[test]
+    fn test_get_or_load() {
+        let cache = ParameterCache::new(100);
+        let num_loads = Cell::new(0);
+        let load = |value: u64, size: usize| {
+            num_loads.set(num_loads.get() + 1);
+            Ok((value, size))
+        };
+
+        // The parameter is loaded once, on first use.
+        assert_eq!(1, *cache.get_or_load("a", || load(1, 40)).unwrap());
+        assert_eq!(1, *cache.get_or_load("a", || load(2, 40)).unwrap());
+        assert_eq!(1, num_loads.get());
+        assert_eq!(40, cache.size());
+
+        // A parameter of another type cannot be read from the same key.
+        assert!(cache.get_or_load::<u32, _>("a", || Ok((1, 4))).is_err());
+
+        // A failed load is not cached.
+        assert!(cache.get_or_load::<u64, _>("b", || Err(anyhow!("Failed to load"))).is_err());
+        assert!(!cache.contains("b"));
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = ParameterCache::new(100);
+
+        let a = cache.get_or_load("a", || Ok((1u64, 40))).unwrap();
+        cache.get_or_load("b", || Ok((2u64, 40))).unwrap();
+
+        // Using "a" makes "b" the least recently used parameter.
+        cache.get_or_load("a", || Ok((1u64, 40))).unwrap();
+        cache.get_or_load("c", || Ok((3u64, 40))).unwrap();
+        assert!(cache.contains("a"));
+        assert!(!cache.contains("b"));
+        assert!(cache.contains("c"));
+        assert_eq!(80, cache.size());
+
+        // A parameter larger than the budget is still cached, evicting every other parameter.
+        cache.get_or_load("d", || Ok((4u64, 200))).unwrap();
+        assert!(!cache.contains("a") && !cache.contains("c"));
+        assert_eq!(200, cache.size());
+
+        // Evicted parameters remain alive for their holders.
+        assert_eq!(1, *a);
+
+        // Lowering the budget evicts the remaining parameters.
+        cache.set_memory_budget(0);
+        assert!(!cache.contains("d"));
+        assert_eq!(0, cache.size());
+    }
+
+    #[test]
+    fn test_load_does_not_block_other_parameters() {
+        let cache = Arc::new(ParameterCache::new(100));
+        let (started_sender, started) = mpsc::channel();
+        let (finish, finish_receiver) = mpsc::channel();
+
+        // Start loading "a", which blocks until it is told to finish.
+        let handle = {
+            let cache = cache.clone();
+            thread::spawn(move || {
+                let a = cache.get_or_load("a", move || {
+                    started_sender.send(()).unwrap();
+                    finish_receiver.recv().unwrap();
+                    Ok((1u64, 40))
+                });
+                a.map(|a| *a)
+            })
+        };
+        started.recv().unwrap();
+
+        // While "a" is loading, "b" is loaded and accounted for.
+        assert_eq!(2, *cache.get_or_load("b", || Ok((2u64, 40))).unwrap());
+        assert!(!cache.contains("a"));
+        assert_eq!(40, cache.size());
+
+        finish.send(()).unwrap();
+        assert_eq!(1, handle.join().unwrap().unwrap());
+        assert!(cache.contains("a"));
+        assert_eq!(80, cache.size());
+    }
+}
@@ -24,6 +24,7 @@ use crate::{
     InputPublicVariables,
     Network,
     OutputPublicVariables,
+    ParameterCache,
     PoSWScheme,
     ProgramPublicVariables,
     ValueBalanceCommitment,
@@ -64,6 +65,7 @@ use snarkvm_utilities::{FromBytes, ToMinimalBits};
 
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Testnet1;
@@ -257,13 +259,13 @@ impl Network for Testnet1 {
     dpc_setup!{Testnet1, input_circuit_id_crh, InputCircuitIDCRH, "AleoInputCircuitIDCRH0"}
     dpc_setup!{Testnet1, output_circuit_id_crh, OutputCircuitIDCRH, "AleoOutputCircuitIDCRH0"}
 
-    dpc_snark_setup!{Testnet1, input_proving_key, InputSNARK, ProvingKey, InputProvingKeyBytes, "input circuit proving key"}
+    dpc_snark_cache!{Testnet1, input_proving_key, InputSNARK, ProvingKey, InputProvingKeyBytes, "input circuit proving key"}
     dpc_snark_setup!{Testnet1, input_verifying_key, InputSNARK, VerifyingKey, InputVerifyingKeyBytes, "input circuit verifying key"}
     
-    dpc_snark_setup!{Testnet1, output_proving_key, OutputSNARK, ProvingKey, OutputProvingKeyBytes, "output circuit proving key"}
+    dpc_snark_cache!{Testnet1, output_proving_key, OutputSNARK, ProvingKey, OutputProvingKeyBytes, "output circuit proving key"}
     dpc_snark_setup!{Testnet1, output_verifying_key, OutputSNARK, VerifyingKey, OutputVerifyingKeyBytes, "output circuit verifying key"}
 
-    dpc_snark_setup!{Testnet1, posw_proving_key, PoSWSNARK, ProvingKey, PoSWProvingKeyBytes, "posw proving key"}
+    dpc_snark_cache!{Testnet1, posw_proving_key, PoSWSNARK, ProvingKey, PoSWProvingKeyBytes, "posw proving key"}
     dpc_snark_setup!{Testnet1, posw_verifying_key, PoSWSNARK, VerifyingKey, PoSWVerifyingKeyBytes, "posw verifying key"}
 
     fn input_circuit_id() -> &'static Self::InputCircuitID {
@@ -19,7 +19,7 @@
 //! inputs a mask computed as Blake2s(nonce || root), which the verifier also checks.
 
 use crate::{BlockTemplate, Network};
-use snarkvm_algorithms::prelude::*;
+use snarkvm_algorithms::{merkle_tree::MerkleTree, prelude::*};
 use snarkvm_gadgets::{
     algorithms::merkle_tree::compute_masked_root,
     traits::{AllocGadget, CRHGadget, MaskedCRHGadget, PRFGadget},
@@ -40,9 +40,12 @@ pub struct PoSWCircuit<N: Network> {
 impl<N: Network> PoSWCircuit<N> {
     /// Creates a PoSW circuit from the provided transaction ids and nonce.
     pub fn new(block_template: &BlockTemplate<N>, nonce: N::PoSWNonce) -> Result<Self> {
-        let tree = block_template.to_header_tree()?;
+        Ok(Self::from_tree(&block_template.to_header_tree()?, nonce))
+    }
 
-        Ok(Self { block_header_root: (*tree.root()).into(), nonce, hashed_leaves: tree.hashed_leaves().to_vec() })
+    /// Creates a PoSW circuit from the provided block header tree and nonce.
+    pub fn from_tree(tree: &MerkleTree<N::BlockHeaderRootParameters>, nonce: N::PoSWNonce) -> Self {
+        Self { block_header_root: (*tree.root()).into(), nonce, hashed_leaves: tree.hashed_leaves().to_vec() }
     }
 
     /// Creates a blank PoSW circuit for setup.
@@ -22,3 +22,6 @@ pub(crate) use posw::PoSW;
 
 pub mod proof;
 pub use proof::*;
+
+pub mod puzzle;
+pub use puzzle::*;
@@ -31,14 +31,15 @@ use snarkvm_utilities::UniformRand;
 
 use core::sync::atomic::AtomicBool;
 use rand::{CryptoRng, Rng};
+use std::sync::Arc;
 use time::OffsetDateTime;
 
 /// A Proof of Succinct Work miner and verifier.
 #[derive(Clone)]
 pub struct PoSW<N: Network> {
     /// The proving key. If not provided, PoSW will work in verify-only mode
-    /// and the `mine` function will panic.
-    proving_key: Option<<<N as Network>::PoSWSNARK as SNARK>::ProvingKey>,
+    /// and the `mine` function will panic. It is shared with the network's parameter cache.
+    proving_key: Option<Arc<<<N as Network>::PoSWSNARK as SNARK>::ProvingKey>>,
     /// The verifying key.
     verifying_key: <<N as Network>::PoSWSNARK as SNARK>::VerifyingKey,
 }
@@ -53,7 +54,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         let (proving_key, verifying_key) =
             <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(&PoSWCircuit::<N>::blank()?, srs)?;
 
-        Ok(Self { proving_key: Some(proving_key), verifying_key })
+        Ok(Self { proving_key: Some(Arc::new(proving_key)), verifying_key })
     }
 
     ///
@@ -62,7 +63,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
     fn load(is_prover: bool) -> Result<Self, PoSWError> {
         Ok(Self {
             proving_key: match is_prover {
-                true => Some(N::posw_proving_key().clone()),
+                true => Some(N::posw_proving_key()),
                 false => None,
             },
             verifying_key: N::posw_verifying_key().clone(),
@@ -72,8 +73,8 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
     ///
     /// Returns a reference to the PoSW circuit proving key.
     ///
-    fn proving_key(&self) -> &Option<<N::PoSWSNARK as SNARK>::ProvingKey> {
-        &self.proving_key
+    fn proving_key(&self) -> Option<&<N::PoSWSNARK as SNARK>::ProvingKey> {
+        self.proving_key.as_deref()
     }
 
     ///
@@ -137,7 +138,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<PoSWProof<N>, PoSWError> {
-        let pk = self.proving_key.as_ref().expect("tried to mine without a PK set up");
+        let pk = self.proving_key.as_deref().expect("tried to mine without a PK set up");
 
         // Sample a random nonce.
         circuit.set_nonce(UniformRand::rand(rng));
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implements a coinbase puzzle on top of the PoSW circuit. A puzzle instance is derived
+//! from an epoch hash, and a solution is a prover address, nonce, and PoSW proof which meet a difficulty target.
+
+use crate::{posw::PoSWCircuit, Address, Network, PoSWError, PoSWProof, PoSWScheme};
+use snarkvm_algorithms::{merkle_tree::MerkleTree, SNARK};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+    UniformRand,
+};
+
+use anyhow::Result;
+use blake2::{Blake2s256, Digest};
+use core::sync::atomic::{AtomicBool, Ordering};
+use rand::{CryptoRng, Rng};
+use rayon::prelude::*;
+use std::{collections::HashSet, sync::Arc};
+
+/// An instance of the coinbase puzzle for an epoch.
+#[derive(Clone, Debug)]
+pub struct PuzzleInstance<N: Network> {
+    /// The epoch hash the puzzle is derived from.
+    epoch_hash: N::BlockHash,
+}
+
+impl<N: Network> PuzzleInstance<N> {
+    /// Initializes the puzzle instance for the given epoch hash.
+    pub fn new(epoch_hash: N::BlockHash) -> Self {
+        Self { epoch_hash }
+    }
+
+    /// Returns the epoch hash of the puzzle.
+    pub fn epoch_hash(&self) -> N::BlockHash {
+        self.epoch_hash
+    }
+
+    ///
+    /// Returns the root of the puzzle for the given prover address, which is the public input
+    /// of each solution alongside its nonce. The root commits to the epoch hash and the prover address,
+    /// so a solution proves work for its prover, and cannot be claimed by another address.
+    ///
+    pub fn to_root(&self, address: &Address<N>) -> Result<N::BlockHeaderRoot> {
+        Ok((*self.to_tree(address)?.root()).into())
+    }
+
+    /// Returns the PoSW circuit for the given prover address and nonce.
+    pub fn to_circuit(&self, address: &Address<N>, nonce: N::PoSWNonce) -> Result<PoSWCircuit<N>> {
+        Ok(PoSWCircuit::from_tree(&self.to_tree(address)?, nonce))
+    }
+
+    /// Returns a solution to the puzzle for the given prover address that meets the given difficulty target,
+    /// or an error if the terminator is set before one is found.
+    pub fn prove<R: Rng + CryptoRng>(
+        &self,
+        address: Address<N>,
+        difficulty_target: u64,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<PuzzleSolution<N>, PoSWError> {
+        let mut circuit = self.to_circuit(&address, UniformRand::rand(rng))?;
+
+        while !terminator.load(Ordering::SeqCst) {
+            // Run one iteration of PoSW.
+            let proof = N::posw().prove_once_unchecked(&mut circuit, terminator, rng)?;
+
+            if N::posw().verify(difficulty_target, &circuit.to_public_inputs(), &proof) {
+                return Ok(PuzzleSolution::new(address, circuit.nonce(), proof));
+            }
+        }
+
+        Err(PoSWError::Message("Terminated before a puzzle solution was found".to_string()))
+    }
+
+    /// Returns `true` if the given solution is valid for its prover address and meets the given difficulty target.
+    pub fn verify(&self, difficulty_target: u64, solution: &PuzzleSolution<N>) -> bool {
+        match self.to_public_inputs(solution) {
+            Ok(inputs) => N::posw().verify(difficulty_target, &inputs, solution.proof()),
+            Err(_) => false,
+        }
+    }
+
+    ///
+    /// Returns `true` if every solution is valid for its prover address and meets the given difficulty target.
+    /// The proofs of the solutions are verified as a single batch.
+    ///
+    /// Returns an error if a prover address uses the same nonce more than once,
+    /// so the same work cannot be submitted twice.
+    ///
+    pub fn batch_verify(&self, difficulty_target: u64, solutions: &[PuzzleSolution<N>]) -> Result<bool, PoSWError> {
+        // Ensure each prover address uses each nonce once.
+        let statements =
+            solutions.iter().map(|solution| (solution.address(), solution.nonce())).collect::<HashSet<_>>();
+        if statements.len() != solutions.len() {
+            return Err(PoSWError::Message("Puzzle solutions contain a duplicate nonce".to_string()));
+        }
+
+        // Ensure each solution meets the difficulty target.
+        for solution in solutions {
+            if solution.to_difficulty()? > difficulty_target {
+                return Ok(false);
+            }
+        }
+
+        // Verify the proofs of the solutions as a single batch.
+        let inputs =
+            solutions.par_iter().map(|solution| self.to_public_inputs(solution)).collect::<Result<Vec<_>>>()?;
+        let proofs = solutions
+            .iter()
+            .map(|solution| match solution.proof() {
+                PoSWProof::NonHiding(proof) => (**proof).clone(),
+            })
+            .collect::<Vec<_>>();
+        Ok(N::PoSWSNARK::verify_batch(N::posw().verifying_key(), &inputs.iter().collect::<Vec<_>>(), &proofs)?)
+    }
+
+    /// Returns the public inputs of the given solution, which are the puzzle root for its prover address and its nonce.
+    fn to_public_inputs(&self, solution: &PuzzleSolution<N>) -> Result<Vec<N::InnerScalarField>> {
+        Ok(vec![*self.to_root(&solution.address())?, *solution.nonce()])
+    }
+
+    /// Returns the puzzle tree for the given prover address, with one leaf for each leaf of the block header tree,
+    /// so the instance fits the PoSW circuit.
+    fn to_tree(&self, address: &Address<N>) -> Result<MerkleTree<N::BlockHeaderRootParameters>> {
+        let preimage = [self.epoch_hash.to_bytes_le()?, address.to_bytes_le()?].concat();
+        let num_leaves = usize::pow(2, N::HEADER_TREE_DEPTH as u32);
+        let leaves = (0..num_leaves as u32)
+            .map(|index| Blake2s256::digest([&preimage[..], &index.to_le_bytes()].concat()).to_vec())
+            .collect::<Vec<_>>();
+
+        Ok(MerkleTree::<N::BlockHeaderRootParameters>::new(
+            Arc::new(N::block_header_root_parameters().clone()),
+            &leaves,
+        )?)
+    }
+}
+
+/// A solution to a puzzle instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleSolution<N: Network> {
+    address: Address<N>,
+    nonce: N::PoSWNonce,
+    proof: PoSWProof<N>,
+}
+
+impl<N: Network> PuzzleSolution<N> {
+    /// Initializes a new puzzle solution.
+    pub fn new(address: Address<N>, nonce: N::PoSWNonce, proof: PoSWProof<N>) -> Self {
+        Self { address, nonce, proof }
+    }
+
+    /// Returns the address of the prover of the solution.
+    pub fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the nonce of the solution.
+    pub fn nonce(&self) -> N::PoSWNonce {
+        self.nonce
+    }
+
+    /// Returns the PoSW proof of the solution.
+    pub fn proof(&self) -> &PoSWProof<N> {
+        &self.proof
+    }
+
+    /// Returns the difficulty of the solution, which must be at most the difficulty target.
+    pub fn to_difficulty(&self) -> Result<u64> {
+        self.proof.to_proof_difficulty()
+    }
+}
+
+impl<N: Network> FromBytes for PuzzleSolution<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address = FromBytes::read_le(&mut reader)?;
+        let nonce = FromBytes::read_le(&mut reader)?;
+        let proof = FromBytes::read_le(&mut reader)?;
+        Ok(Self::new(address, nonce, proof))
+    }
+}
+
+impl<N: Network> ToBytes for PuzzleSolution<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.address.write_le(&mut writer)?;
+        self.nonce.write_le(&mut writer)?;
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_puzzle_instance() {
+        let rng = &mut thread_rng();
+        let address = Account::<Testnet2>::new(rng).address();
+        let epoch_hash = Testnet2::genesis_block().hash();
+
+        // The puzzle root is deterministic in the epoch hash and prover address.
+        let instance = PuzzleInstance::<Testnet2>::new(epoch_hash);
+        let root = instance.to_root(&address).unwrap();
+        assert_eq!(root, PuzzleInstance::<Testnet2>::new(epoch_hash).to_root(&address).unwrap());
+
+        let other_hash = Testnet2::genesis_block().previous_block_hash();
+        assert_ne!(root, PuzzleInstance::<Testnet2>::new(other_hash).to_root(&address).unwrap());
+
+        let other_address = Account::<Testnet2>::new(rng).address();
+        assert_ne!(root, instance.to_root(&other_address).unwrap());
+    }
+
+    #[test]
+    fn test_puzzle_prove_and_verify() {
+        let rng = &mut thread_rng();
+        let address = Account::<Testnet2>::new(rng).address();
+
+        let instance = PuzzleInstance::<Testnet2>::new(Testnet2::genesis_block().hash());
+        let solution = instance.prove(address, u64::MAX, &AtomicBool::new(false), rng).unwrap();
+        assert!(instance.verify(u64::MAX, &solution));
+
+        // The solution is bound to its puzzle instance.
+        let other = PuzzleInstance::<Testnet2>::new(Testnet2::genesis_block().previous_block_hash());
+        assert!(!other.verify(u64::MAX, &solution));
+
+        // The solution is bound to its prover address.
+        let other_address = Account::<Testnet2>::new(rng).address();
+        let claimed = PuzzleSolution::new(other_address, solution.nonce(), solution.proof().clone());
+        assert!(!instance.verify(u64::MAX, &claimed));
+
+        // The solution is rejected for a difficulty target it does not meet.
+        let difficulty = solution.to_difficulty().unwrap();
+        assert!(instance.verify(difficulty, &solution));
+        if difficulty > 0 {
+            assert!(!instance.verify(difficulty - 1, &solution));
+        }
+
+        // The solution serializes to bytes.
+        let bytes = solution.to_bytes_le().unwrap();
+        assert_eq!(solution, PuzzleSolution::read_le(&bytes[..]).unwrap());
+
+        // A batch is valid only if each solution is valid, and a prover address cannot reuse a nonce.
+        let second = instance.prove(address, u64::MAX, &AtomicBool::new(false), rng).unwrap();
+        assert!(instance.batch_verify(u64::MAX, &[solution.clone(), second.clone()]).unwrap());
+        assert!(!instance.batch_verify(u64::MAX, &[second, claimed]).unwrap());
+        assert!(instance.batch_verify(u64::MAX, &[solution.clone(), solution]).is_err());
+
+        // Proving stops once terminated.
+        assert!(instance.prove(address, u64::MAX, &AtomicBool::new(true), rng).is_err());
+    }
+}
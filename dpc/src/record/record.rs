@@ -175,7 +175,7 @@ impl<N: Network> Record<N> {
         // and interpret these bytes as a program base field element
         // For our choice of scalar field and base field (i.e. a TE curve), the scalar field
         // is always smaller than base field, so the bytes always fit without wraparound.
-        let seed = N::InnerScalarField::from_repr(FromBits::from_bits_le(&compute_key.sk_prf().to_bits_le())).unwrap();
+        let seed = N::InnerScalarField::from_repr(FromBits::from_bits_le(&compute_key.sk_prf().to_bits_le())?).unwrap();
         let input = self.commitment();
         let serial_number = N::SerialNumberPRF::evaluate(&seed, &input.into()).into();
 
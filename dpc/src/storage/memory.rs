@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Column, Storage};
+
+use anyhow::{anyhow, Result};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+type Columns = HashMap<Column, BTreeMap<Vec<u8>, Vec<u8>>>;
+
+/// An in-memory storage, for tests and ephemeral nodes.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    columns: Arc<RwLock<Columns>>,
+}
+
+impl MemoryStorage {
+    /// Initializes a new instance of an empty in-memory storage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    /// Returns a new, empty in-memory storage. The path is ignored.
+    fn open<P: AsRef<Path>>(_path: P) -> Result<Self> {
+        Ok(Self::new())
+    }
+
+    fn get(&self, column: Column, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let columns = self.columns.read().map_err(|_| anyhow!("Memory storage lock is poisoned"))?;
+        Ok(columns.get(&column).and_then(|entries| entries.get(key)).cloned())
+    }
+
+    fn put(&self, column: Column, key: &[u8], value: &[u8]) -> Result<()> {
+        let mut columns = self.columns.write().map_err(|_| anyhow!("Memory storage lock is poisoned"))?;
+        columns.entry(column).or_default().insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn put_all(&self, entries: &[(Column, Vec<u8>, Vec<u8>)]) -> Result<()> {
+        let mut columns = self.columns.write().map_err(|_| anyhow!("Memory storage lock is poisoned"))?;
+        for (column, key, value) in entries {
+            columns.entry(*column).or_default().insert(key.clone(), value.clone());
+        }
+        Ok(())
+    }
+
+    fn remove(&self, column: Column, key: &[u8]) -> Result<()> {
+        let mut columns = self.columns.write().map_err(|_| anyhow!("Memory storage lock is poisoned"))?;
+        if let Some(entries) = columns.get_mut(&column) {
+            entries.remove(key);
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over a snapshot of the given column, so the storage is not locked during iteration.
+    fn iter(&self, column: Column) -> Result<Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + '_>> {
+        let columns = self.columns.read().map_err(|_| anyhow!("Memory storage lock is poisoned"))?;
+        Ok(Box::new(columns.get(&column).cloned().unwrap_or_default().into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_storage() {
+        let storage = MemoryStorage::new();
+        assert_eq!(None, storage.get(Column::Blocks, b"a").unwrap());
+
+        storage.put(Column::Blocks, b"b", b"2").unwrap();
+        storage.put_all(&[(Column::Blocks, b"a".to_vec(), b"1".to_vec())]).unwrap();
+        assert_eq!(Some(b"1".to_vec()), storage.get(Column::Blocks, b"a").unwrap());
+        assert!(!storage.contains_key(Column::Transactions, b"a").unwrap());
+
+        // Ensure entries are iterated in order of their keys.
+        let expected = vec![(b"a".to_vec(), b"1".to_vec()), (b"b".to_vec(), b"2".to_vec())];
+        assert_eq!(expected, storage.iter(Column::Blocks).unwrap().collect::<Vec<_>>());
+
+        // Ensure clones share the same underlying storage.
+        storage.clone().remove(Column::Blocks, b"a").unwrap();
+        assert!(!storage.contains_key(Column::Blocks, b"a").unwrap());
+    }
+}
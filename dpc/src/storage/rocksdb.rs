@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Column, Storage};
+
+use ::rocksdb::{ColumnFamily, IteratorMode, Options, WriteBatch, DB};
+use anyhow::{anyhow, Result};
+use std::{fmt, path::Path, sync::Arc};
+
+/// A persistent storage backed by RocksDB, with one column family per column.
+#[derive(Clone)]
+pub struct RocksDB {
+    db: Arc<DB>,
+}
+
+impl RocksDB {
+    /// Returns the handle of the given column family.
+    fn cf_handle(&self, column: Column) -> Result<&ColumnFamily> {
+        self.db.cf_handle(column.name()).ok_or_else(|| anyhow!("Missing column family {}", column.name()))
+    }
+}
+
+impl Storage for RocksDB {
+    fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        let db = DB::open_cf(&options, path, Column::ALL.iter().map(Column::name))?;
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    fn get(&self, column: Column, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.db.get_cf(self.cf_handle(column)?, key)?)
+    }
+
+    fn put(&self, column: Column, key: &[u8], value: &[u8]) -> Result<()> {
+        Ok(self.db.put_cf(self.cf_handle(column)?, key, value)?)
+    }
+
+    fn put_all(&self, entries: &[(Column, Vec<u8>, Vec<u8>)]) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        for (column, key, value) in entries {
+            batch.put_cf(self.cf_handle(*column)?, key, value);
+        }
+        Ok(self.db.write(batch)?)
+    }
+
+    fn remove(&self, column: Column, key: &[u8]) -> Result<()> {
+        Ok(self.db.delete_cf(self.cf_handle(column)?, key)?)
+    }
+
+    fn iter(&self, column: Column) -> Result<Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + '_>> {
+        Ok(Box::new(
+            self.db
+                .iterator_cf(self.cf_handle(column)?, IteratorMode::Start)
+                .map(|(key, value)| (key.to_vec(), value.to_vec())),
+        ))
+    }
+}
+
+impl fmt::Debug for RocksDB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RocksDB").field("path", &self.db.path()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_rocksdb_storage() {
+        let path = std::env::temp_dir().join(format!("snarkvm-rocksdb-{}", thread_rng().gen::<u64>()));
+        {
+            let storage = RocksDB::open(&path).unwrap();
+            storage.put(Column::Blocks, b"b", b"2").unwrap();
+            storage.put_all(&[(Column::Blocks, b"a".to_vec(), b"1".to_vec())]).unwrap();
+            storage.remove(Column::Blocks, b"b").unwrap();
+        }
+        {
+            // Ensure the entries persist after the storage is reopened.
+            let storage = RocksDB::open(&path).unwrap();
+            assert_eq!(Some(b"1".to_vec()), storage.get(Column::Blocks, b"a").unwrap());
+            assert_eq!(vec![(b"a".to_vec(), b"1".to_vec())], storage.iter(Column::Blocks).unwrap().collect::<Vec<_>>());
+            assert!(!storage.contains_key(Column::Blocks, b"b").unwrap());
+        }
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -36,9 +36,19 @@ pub trait LedgerTreeScheme<N: Network>: Sized {
     /// Returns the index for the given block hash, if it exists.
     fn get_block_hash_index(&self, block_hash: &N::BlockHash) -> Option<&u32>;
 
+    /// Returns `true` if the given ledger root was the root of the tree at any point.
+    fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool;
+
     /// Returns the ledger root.
     fn root(&self) -> N::LedgerRoot;
 
     /// Returns the Merkle path for a given block hash.
     fn to_ledger_inclusion_proof(&self, block_hash: &N::BlockHash) -> Result<MerklePath<N::LedgerRootParameters>>;
+
+    /// Returns the Merkle path for a given block hash, with respect to the given historical ledger root.
+    fn to_ledger_inclusion_proof_at(
+        &self,
+        block_hash: &N::BlockHash,
+        ledger_root: &N::LedgerRoot,
+    ) -> Result<MerklePath<N::LedgerRootParameters>>;
 }
@@ -25,3 +25,6 @@ pub use network::*;
 
 pub mod posw;
 pub use posw::*;
+
+pub mod storage;
+pub use storage::*;
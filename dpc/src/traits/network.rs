@@ -21,6 +21,7 @@ use crate::{
     OutputPublicVariables,
     PoSWScheme,
     ProgramPublicVariables,
+    ToCircuitSize,
     ValueBalanceCommitment,
 };
 use snarkvm_algorithms::prelude::*;
@@ -35,15 +36,17 @@ use snarkvm_gadgets::{
 use snarkvm_utilities::{
     fmt::{Debug, Display},
     hash::Hash,
+    ConstantTimeEq,
     FromBytes,
     ToBytes,
     ToMinimalBits,
     UniformRand,
+    Zeroize,
 };
 
 use anyhow::Result;
 use serde::{de::DeserializeOwned, Serialize};
-use std::{borrow::Borrow, ops::Deref, str::FromStr};
+use std::{borrow::Borrow, ops::Deref, str::FromStr, sync::Arc};
 
 pub trait Bech32Locator<F: Field>:
     From<F>
@@ -191,7 +194,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     /// SNARK for Aleo program functions.
     type ProgramSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::InnerBaseField, VerifierInput = ProgramPublicVariables<Self>, ProvingKey = Self::ProgramProvingKey, VerifyingKey = Self::ProgramVerifyingKey, UniversalSetupConfig = usize>;
     type ProgramProvingKey: Clone + ToBytes + FromBytes + Send + Sync;
-    type ProgramVerifyingKey: ToConstraintField<Self::InnerBaseField> + Clone + PartialEq + Eq + ToBytes + FromBytes + Serialize + DeserializeOwned + ToMinimalBits + Send + Sync;
+    type ProgramVerifyingKey: ToConstraintField<Self::InnerBaseField> + Clone + PartialEq + Eq + ToBytes + FromBytes + Serialize + DeserializeOwned + ToMinimalBits + ToCircuitSize + Send + Sync;
     type ProgramProof: Bech32Object<<Self::ProgramSNARK as SNARK>::Proof>;
 
     /// SNARK for PoSW.
@@ -205,7 +208,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     /// PRF for deriving the account private key from a seed.
     type AccountSeedPRF: PRF<Input = Vec<Self::ProgramScalarField>, Seed = Self::AccountSeed, Output = Self::ProgramScalarField>;
-    type AccountSeed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug + UniformRand;
+    type AccountSeed: FromBytes + ToBytes + PartialEq + Eq + ConstantTimeEq + Clone + Default + Debug + UniformRand + Zeroize;
 
     /// Signature scheme for transaction authorizations. Invoked only over `Self::InnerScalarField`.
     type AccountSignatureScheme: SignatureScheme<PrivateKey = (Self::ProgramScalarField, Self::ProgramScalarField), PublicKey = Self::ProgramAffineCurve>
@@ -321,14 +324,14 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     fn output_circuit_id_crh() -> &'static Self::OutputCircuitIDCRH;
 
     fn input_circuit_id() -> &'static Self::InputCircuitID;
-    fn input_proving_key() -> &'static <Self::InputSNARK as SNARK>::ProvingKey;
+    fn input_proving_key() -> Arc<<Self::InputSNARK as SNARK>::ProvingKey>;
     fn input_verifying_key() -> &'static <Self::InputSNARK as SNARK>::VerifyingKey;
 
     fn output_circuit_id() -> &'static Self::OutputCircuitID;
-    fn output_proving_key() -> &'static <Self::OutputSNARK as SNARK>::ProvingKey;
+    fn output_proving_key() -> Arc<<Self::OutputSNARK as SNARK>::ProvingKey>;
     fn output_verifying_key() -> &'static <Self::OutputSNARK as SNARK>::VerifyingKey;
     
-    fn posw_proving_key() -> &'static <Self::PoSWSNARK as SNARK>::ProvingKey;
+    fn posw_proving_key() -> Arc<<Self::PoSWSNARK as SNARK>::ProvingKey>;
     fn posw_verifying_key() -> &'static <Self::PoSWSNARK as SNARK>::VerifyingKey;
     fn posw() -> &'static Self::PoSW;
 
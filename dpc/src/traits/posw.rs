@@ -32,7 +32,7 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
     fn load(is_prover: bool) -> Result<Self, PoSWError>;
 
     /// Returns a reference to the PoSW circuit proving key.
-    fn proving_key(&self) -> &Option<<N::PoSWSNARK as SNARK>::ProvingKey>;
+    fn proving_key(&self) -> Option<&<N::PoSWSNARK as SNARK>::ProvingKey>;
 
     /// Returns a reference to the PoSW circuit verifying key.
     fn verifying_key(&self) -> &<N::PoSWSNARK as SNARK>::VerifyingKey;
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use std::{fmt::Debug, path::Path};
+
+/// The column families of the ledger storage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Column {
+    /// A map from block height to block hash.
+    BlockHashes,
+    /// A map from block hash to block.
+    Blocks,
+    /// A map from transaction ID to the hash of its block.
+    Transactions,
+    /// A map from serial number to the ID of its transaction.
+    SerialNumbers,
+    /// A map from commitment to the ID of its transaction.
+    Commitments,
+}
+
+impl Column {
+    /// The list of all column families.
+    pub const ALL: [Column; 5] =
+        [Column::BlockHashes, Column::Blocks, Column::Transactions, Column::SerialNumbers, Column::Commitments];
+
+    /// Returns the name of the column family.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Column::BlockHashes => "block_hashes",
+            Column::Blocks => "blocks",
+            Column::Transactions => "transactions",
+            Column::SerialNumbers => "serial_numbers",
+            Column::Commitments => "commitments",
+        }
+    }
+}
+
+/// A key-value store with column families, used to persist the ledger.
+pub trait Storage: Clone + Debug + Send + Sync + Sized {
+    /// Opens the storage at the given path, creating it if it does not exist.
+    fn open<P: AsRef<Path>>(path: P) -> Result<Self>;
+
+    /// Returns the value for the given key in the given column, if it exists.
+    fn get(&self, column: Column, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Returns `true` if the given key exists in the given column.
+    fn contains_key(&self, column: Column, key: &[u8]) -> Result<bool> {
+        Ok(self.get(column, key)?.is_some())
+    }
+
+    /// Inserts the given key-value pair into the given column.
+    fn put(&self, column: Column, key: &[u8], value: &[u8]) -> Result<()>;
+
+    /// Inserts all given key-value pairs atomically.
+    fn put_all(&self, entries: &[(Column, Vec<u8>, Vec<u8>)]) -> Result<()>;
+
+    /// Removes the given key from the given column.
+    fn remove(&self, column: Column, key: &[u8]) -> Result<()>;
+
+    /// Returns an iterator over the key-value pairs in the given column, in order of their keys.
+    fn iter(&self, column: Column) -> Result<Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + '_>>;
+}
@@ -23,5 +23,8 @@ pub use response::*;
 pub mod transaction;
 pub use transaction::*;
 
+pub mod transaction_builder;
+pub use transaction_builder::*;
+
 pub mod transition;
 pub use transition::*;
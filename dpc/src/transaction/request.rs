@@ -171,10 +171,11 @@ impl<N: Network> Request<N> {
                 return false;
             }
 
-            // Ensure that the total value is equivalent to the recipient amount and fee
+            // Ensure that the total value covers the recipient amount and fee.
+            // Any remaining value is returned to the caller as change.
             if let Operation::Transfer(_, _, amount) = &self.operation {
-                if balance != self.fee.add(*amount) {
-                    eprintln!("Request records do not contain the correct value");
+                if balance < self.fee.add(*amount) {
+                    eprintln!("Request records do not contain sufficient value for the transfer");
                     return false;
                 }
             }
@@ -196,7 +197,8 @@ impl<N: Network> Request<N> {
         };
 
         // If there is no program id, ensure there is no function ID.
-        if program_id == None && self.function_id() != None {
+        // A call may consume noop records, as its program is resolved from the program registry.
+        if program_id.is_none() && self.function_id().is_some() && !self.operation.is_call() {
             eprintln!("Request contains mismatching program ID and function ID");
             return false;
         }
@@ -207,28 +209,37 @@ impl<N: Network> Request<N> {
         // Ensure the given record commitments are in the specified ledger proofs.
         {}
 
-        // Verify the request signatures.
-        for (i, (record, signature)) in self.records.iter().zip_eq(&self.signatures).enumerate() {
-            // Prepare for signature verification.
-            let message = match to_bytes_le![
+        // Prepare the request signatures for verification.
+        let mut public_keys = Vec::with_capacity(self.records.len());
+        let mut messages = Vec::with_capacity(self.records.len());
+        for (i, record) in self.records.iter().enumerate() {
+            match to_bytes_le![
                 record.commitment(),
                 record.program_id().unwrap_or_default() /*operation_id, self.fee*/
             ] {
-                Ok(signature_message) => signature_message.to_bits_le(),
+                Ok(signature_message) => messages.push(signature_message.to_bits_le()),
                 Err(error) => {
                     eprintln!("Failed to construct record {} request signature message: {}", i, error);
                     return false;
                 }
             };
-
-            // Ensure the signature is valid.
-            if let Err(error) = N::account_signature_scheme().verify(&record.owner(), &message, signature) {
-                eprintln!("Failed to verify request signature {}: {}", i, error);
-                return false;
+            public_keys.push(*record.owner());
+        }
+        let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let signatures = self.signatures.iter().map(|signature| (**signature).clone()).collect::<Vec<_>>();
+
+        // Ensure the request signatures are valid, as a single batch.
+        match N::account_signature_scheme().verify_batch(&public_keys, &messages, &signatures) {
+            Ok(true) => true,
+            Ok(false) => {
+                eprintln!("Request contains an invalid signature");
+                false
+            }
+            Err(error) => {
+                eprintln!("Failed to verify request signatures: {}", error);
+                false
             }
         }
-
-        true
     }
 
     /// Returns a reference to the records.
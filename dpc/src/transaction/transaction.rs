@@ -23,6 +23,7 @@ use crate::{
     LedgerTreeScheme,
     LocalProof,
     Network,
+    ProgramRegistry,
     Request,
     Transition,
     Transitions,
@@ -106,61 +107,95 @@ impl<N: Network> Transaction<N> {
     /// correct ciphertext IDs, and a valid proof.
     #[inline]
     pub fn is_valid(&self) -> bool {
+        // Ensure the transaction is well-formed, and retrieve the local transitions root of each transition.
+        let local_transitions_roots = match self.to_well_formed_local_transitions_roots() {
+            Some(local_transitions_roots) => local_transitions_roots,
+            None => return false,
+        };
+
+        // Returns `false` if any transition is invalid.
+        for (transition, local_transitions_root) in self.transitions.iter().zip_eq(local_transitions_roots) {
+            if !transition.verify(self.input_circuit_id, self.output_circuit_id, self.ledger_root, local_transitions_root)
+            {
+                eprintln!("Transaction contains an invalid transition");
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns `true` if the transaction is valid, and each of its program executions and calls
+    /// is valid for the programs in the given registry.
+    #[inline]
+    pub fn is_valid_with_registry(&self, program_registry: &ProgramRegistry<N>) -> bool {
+        self.is_valid() && program_registry.verify_program_calls(self)
+    }
+
+    /// Returns `true` if the transaction is well-formed, without verifying its transitions.
+    #[inline]
+    pub fn is_well_formed(&self) -> bool {
+        self.to_well_formed_local_transitions_roots().is_some()
+    }
+
+    /// Returns the local transitions root of each transition if the transaction is well-formed,
+    /// building the local transitions tree once to check the transaction ID.
+    fn to_well_formed_local_transitions_roots(&self) -> Option<Vec<N::TransactionID>> {
         // Ensure the number of transitions is between 1 and N::NUM_TRANSITIONS.
         let num_transitions = self.transitions.len();
         if num_transitions < 1 || num_transitions > N::NUM_TRANSITIONS as usize {
             eprintln!("Transaction contains invalid number of transitions");
-            return false;
+            return None;
         }
 
         // Ensure the number of events is less than `N::NUM_EVENTS`.
         if self.events().count() > num_transitions * N::NUM_EVENTS as usize {
             eprintln!("Transaction contains an invalid number of events");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of serial numbers in the transaction is incorrect.
+        // Returns `None` if the number of serial numbers in the transaction is incorrect.
         if self.serial_numbers().count() > num_transitions * N::NUM_INPUTS as usize {
             eprintln!("Transaction contains incorrect number of serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate serial numbers in the transaction.
+        // Returns `None` if there are duplicate serial numbers in the transaction.
         if has_duplicates(self.serial_numbers()) {
             eprintln!("Transaction contains duplicate serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of commitments in the transaction is incorrect.
+        // Returns `None` if the number of commitments in the transaction is incorrect.
         if self.commitments().count() > num_transitions * N::NUM_OUTPUTS as usize {
             eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate commitments numbers in the transaction.
+        // Returns `None` if there are duplicate commitments numbers in the transaction.
         if has_duplicates(self.commitments()) {
             eprintln!("Transaction contains duplicate commitments");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the number of record ciphertexts in the transaction is incorrect.
+        // Returns `None` if the number of record ciphertexts in the transaction is incorrect.
         if self.ciphertexts().count() > num_transitions * N::NUM_OUTPUTS as usize {
             eprintln!("Transaction contains incorrect number of record ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if there are duplicate ciphertexts in the transition.
+        // Returns `None` if there are duplicate ciphertexts in the transition.
         if has_duplicates(self.ciphertexts()) {
             eprintln!("Transaction contains duplicate ciphertexts");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the transaction is not a coinbase, and has a transition with a negative value balance.
+        // Returns `None` if the transaction is not a coinbase, and has a transition with a negative value balance.
         if self.transitions.len() > 1
             && self.transitions.iter().any(|transition| transition.value_balance().is_negative())
         {
             eprintln!("Transaction contains a transition with a negative value balance");
-            return false;
+            return None;
         }
 
         // Initialize a local transitions tree.
@@ -168,38 +203,45 @@ impl<N: Network> Transaction<N> {
             Ok(transitions) => transitions,
             Err(error) => {
                 eprintln!("Transaction failed to initialize a local transitions tree: {}", error);
-                return false;
+                return None;
             }
         };
 
-        // Returns `false` if any transition is invalid.
+        // Update the local transitions tree, recording the root that each transition is bound to.
+        let mut local_transitions_roots = Vec::with_capacity(num_transitions);
         for transition in &self.transitions {
-            // Returns `false` if the transition is invalid.
-            if !transition.verify(self.input_circuit_id, self.output_circuit_id, self.ledger_root, transitions.root()) {
-                eprintln!("Transaction contains an invalid transition");
-                return false;
-            }
-
-            // Update the local transitions tree.
+            local_transitions_roots.push(transitions.root());
             if let Err(error) = transitions.add(transition) {
                 eprintln!("Transaction failed to update local transitions tree: {}", error);
-                return false;
+                return None;
             }
         }
 
-        // Returns `false` if the size of the local transitions tree does not match the number of transitions.
+        // Returns `None` if the size of the local transitions tree does not match the number of transitions.
         if transitions.len() != num_transitions {
             eprintln!("Transaction contains invalid local transitions tree state");
-            return false;
+            return None;
         }
 
-        // Returns `false` if the final transitions root does not match the transaction ID.
+        // Returns `None` if the final transitions root does not match the transaction ID.
         if transitions.root() != self.transaction_id {
             eprintln!("Transaction contains an invalid transaction ID");
-            return false;
+            return None;
         }
 
-        true
+        Some(local_transitions_roots)
+    }
+
+    /// Returns the local transitions root that each transition in the transaction is bound to,
+    /// which is the root of the local transitions tree over all preceding transitions.
+    pub fn to_local_transitions_roots(&self) -> Result<Vec<N::TransactionID>> {
+        let mut transitions = Transitions::<N>::new()?;
+        let mut local_transitions_roots = Vec::with_capacity(self.transitions.len());
+        for transition in &self.transitions {
+            local_transitions_roots.push(transitions.root());
+            transitions.add(transition)?;
+        }
+        Ok(local_transitions_roots)
     }
 
     /// Returns `true` if the given transition ID exists.
@@ -319,6 +361,26 @@ impl<N: Network> Transaction<N> {
         // Return the root of the transitions tree.
         Ok(transitions_tree.root())
     }
+
+    ///
+    /// Reads a transaction without verifying it, such as its transition proofs.
+    /// Note: This is only for trusted bytes, such as a transaction read back from the ledger storage.
+    ///
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let input_circuit_id = FromBytes::read_le(&mut reader)?;
+        let output_circuit_id = FromBytes::read_le(&mut reader)?;
+        let ledger_root = FromBytes::read_le(&mut reader)?;
+
+        let num_transitions: u16 = FromBytes::read_le(&mut reader)?;
+        let mut transitions = Vec::with_capacity(num_transitions as usize);
+        for _ in 0..num_transitions {
+            transitions.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let transaction_id = Self::compute_transaction_id(&transitions)
+            .map_err(|e| error(format!("Failed to deserialize a transaction: {e}")))?;
+        Ok(Self { transaction_id, input_circuit_id, output_circuit_id, ledger_root, transitions })
+    }
 }
 
 impl<N: Network> PartialEq for Transaction<N> {
@@ -338,19 +400,12 @@ impl<N: Network> Hash for Transaction<N> {
 
 impl<N: Network> FromBytes for Transaction<N> {
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let input_circuit_id = FromBytes::read_le(&mut reader)?;
-        let output_circuit_id = FromBytes::read_le(&mut reader)?;
-        let ledger_root = FromBytes::read_le(&mut reader)?;
-
-        let num_transitions: u16 = FromBytes::read_le(&mut reader)?;
-        let mut transitions = Vec::with_capacity(num_transitions as usize);
-        for _ in 0..num_transitions {
-            transitions.push(FromBytes::read_le(&mut reader)?);
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let transaction = Self::read_le_unchecked(reader)?;
+        match transaction.is_valid() {
+            true => Ok(transaction),
+            false => Err(error("Failed to deserialize a transaction: Failed to initialize a transaction")),
         }
-
-        Self::from(input_circuit_id, output_circuit_id, ledger_root, transitions)
-            .map_err(|e| error(format!("Failed to deserialize a transaction: {e}")))
     }
 }
 
@@ -0,0 +1,294 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Address,
+    AleoAmount,
+    LedgerProof,
+    Network,
+    Operation,
+    PrivateKey,
+    ProvingRequest,
+    ProvingResponse,
+    Record,
+    Request,
+    Transaction,
+    VMError,
+    VirtualMachine,
+};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder<N: Network> {
+    /// The records being consumed, along with their ledger proofs.
+    inputs: Vec<(Record<N>, LedgerProof<N>)>,
+    /// The records being consumed to pay the fee, along with their ledger proofs.
+    fee_inputs: Vec<(Record<N>, LedgerProof<N>)>,
+    /// The recipients and amounts being transferred.
+    outputs: Vec<(Address<N>, AleoAmount)>,
+    /// The network fee being paid.
+    fee: AleoAmount,
+    /// The visibility of the transaction.
+    is_public: bool,
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    ///
+    /// Initializes a new instance of `TransactionBuilder`.
+    ///
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::with_capacity(N::NUM_INPUTS as usize),
+            fee_inputs: Vec::new(),
+            outputs: Vec::with_capacity(N::NUM_TRANSITIONS as usize),
+            fee: AleoAmount::ZERO,
+            is_public: false,
+        }
+    }
+
+    ///
+    /// Adds the given record and its ledger proof as an input into the builder.
+    ///
+    pub fn add_input(mut self, record: Record<N>, ledger_proof: LedgerProof<N>) -> Self {
+        self.inputs.push((record, ledger_proof));
+        self
+    }
+
+    ///
+    /// Adds the given record and its ledger proof as a fee input into the builder.
+    /// Fee inputs are consumed before any other inputs.
+    ///
+    pub fn add_fee_input(mut self, record: Record<N>, ledger_proof: LedgerProof<N>) -> Self {
+        self.fee_inputs.push((record, ledger_proof));
+        self
+    }
+
+    ///
+    /// Adds a transfer of the given amount to the given recipient into the builder.
+    ///
+    pub fn add_output(mut self, recipient: Address<N>, amount: AleoAmount) -> Self {
+        self.outputs.push((recipient, amount));
+        self
+    }
+
+    ///
+    /// Sets the network fee of the transaction.
+    ///
+    pub fn fee(mut self, fee: AleoAmount) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    ///
+    /// Sets the visibility of the transaction.
+    ///
+    pub fn is_public(mut self, is_public: bool) -> Self {
+        self.is_public = is_public;
+        self
+    }
+
+    ///
+    /// Finalizes the builder, proving every transition locally, and returns a new instance of `Transaction`.
+    ///
+    pub fn build<R: Rng + CryptoRng>(
+        &self,
+        caller: &PrivateKey<N>,
+        ledger_root: N::LedgerRoot,
+        rng: &mut R,
+    ) -> Result<Transaction<N>, VMError> {
+        self.build_with_prover(caller, ledger_root, |proving_request, rng| proving_request.prove(rng), rng)
+    }
+
+    ///
+    /// Finalizes the builder, delegating the circuit proofs to the given prover,
+    /// and returns a new instance of `Transaction`.
+    ///
+    pub fn build_with_prover<R, P>(
+        &self,
+        caller: &PrivateKey<N>,
+        ledger_root: N::LedgerRoot,
+        mut prover: P,
+        rng: &mut R,
+    ) -> Result<Transaction<N>, VMError>
+    where
+        R: Rng + CryptoRng,
+        P: FnMut(&ProvingRequest<N>, &mut R) -> Result<ProvingResponse<N>>,
+    {
+        let requests = self.to_requests(caller, rng)?;
+
+        let mut vm = VirtualMachine::<N>::new(ledger_root)?;
+        for request in &requests {
+            vm = vm.execute_with_prover(request, &mut prover, rng)?.0;
+        }
+        Ok(vm.finalize()?)
+    }
+
+    ///
+    /// Returns the signed requests for each transition of the transaction.
+    ///
+    /// Each output is transferred in its own transition, and the fee is paid in the first transition.
+    /// The inputs of each transition are drawn in order from the fee inputs, followed by the inputs,
+    /// until the transition is funded. Any excess value in a transition is returned to the caller as change,
+    /// and any inputs left over after funding every transition are not consumed.
+    ///
+    pub fn to_requests<R: Rng + CryptoRng>(
+        &self,
+        caller: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Vec<Request<N>>, VMError> {
+        // Ensure there is at least one output, and at most one output per transition.
+        if self.outputs.is_empty() {
+            return Err(anyhow!("Transaction builder requires at least one output").into());
+        }
+        if self.outputs.len() > N::NUM_TRANSITIONS as usize {
+            return Err(anyhow!("Transaction builder exceeded maximum of {} outputs", N::NUM_TRANSITIONS).into());
+        }
+
+        // Ensure the fee and output amounts are not negative.
+        if self.fee.is_negative() || self.outputs.iter().any(|(_, amount)| amount.is_negative()) {
+            return Err(anyhow!("Transaction builder received a negative amount").into());
+        }
+
+        // Ensure every input record is owned by the caller and belongs to the same program.
+        let caller_address = caller.to_address();
+        let program_id = self.fee_inputs.iter().chain(&self.inputs).find_map(|(record, _)| record.program_id());
+        for (i, (record, _)) in self.fee_inputs.iter().chain(&self.inputs).enumerate() {
+            if record.owner() != caller_address {
+                return Err(VMError::RecordOwnerMismatch(i));
+            }
+            if record.program_id().is_some() && record.program_id() != program_id {
+                return Err(VMError::RecordProgramMismatch(i));
+            }
+        }
+
+        // Ensure the inputs contain sufficient value for the outputs and fee.
+        let balance: AleoAmount = self.fee_inputs.iter().chain(&self.inputs).map(|(record, _)| record.value()).sum();
+        let total: AleoAmount = self.outputs.iter().map(|(_, amount)| *amount).sum();
+        if balance < total.add(self.fee) {
+            return Err(VMError::BalanceInsufficient);
+        }
+
+        // Construct a request for each output.
+        let mut inputs: VecDeque<_> = self.fee_inputs.iter().chain(&self.inputs).cloned().collect();
+        let mut requests = Vec::with_capacity(self.outputs.len());
+        for (i, (recipient, amount)) in self.outputs.iter().enumerate() {
+            let fee = if i == 0 { self.fee } else { AleoAmount::ZERO };
+            let required = amount.add(fee);
+
+            // Draw inputs until the transition is funded. Every transition consumes at least one record.
+            let mut records = Vec::with_capacity(N::NUM_INPUTS as usize);
+            let mut ledger_proofs = Vec::with_capacity(N::NUM_INPUTS as usize);
+            let mut value = AleoAmount::ZERO;
+            while records.is_empty() || value < required {
+                if records.len() == N::NUM_INPUTS as usize {
+                    return Err(anyhow!(
+                        "Transaction builder requires more than {} inputs for output {}",
+                        N::NUM_INPUTS,
+                        i
+                    )
+                    .into());
+                }
+                match inputs.pop_front() {
+                    Some((record, ledger_proof)) => {
+                        value = value.add(record.value());
+                        records.push(record);
+                        ledger_proofs.push(ledger_proof);
+                    }
+                    None => return Err(VMError::BalanceInsufficient),
+                }
+            }
+
+            let operation = Operation::Transfer(caller_address, *recipient, *amount);
+            requests.push(Request::new(caller, records, ledger_proofs, operation, fee, self.is_public, rng)?);
+        }
+
+        Ok(requests)
+    }
+}
+
+impl<N: Network> Default for TransactionBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, LedgerTree, LedgerTreeScheme};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_transaction_builder() {
+        let rng = &mut thread_rng();
+
+        let sender = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let record = Record::new_noop(sender.address(), rng).unwrap();
+        let transaction = TransactionBuilder::new()
+            .add_input(record.clone(), LedgerProof::default())
+            .add_output(recipient.address(), AleoAmount::ZERO)
+            .build(sender.private_key(), LedgerTree::<Testnet2>::new().unwrap().root(), rng)
+            .unwrap();
+
+        assert!(transaction.is_valid());
+        assert_eq!(1, transaction.transitions().len());
+
+        let expected_serial_number = record.to_serial_number(&sender.private_key().to_compute_key()).unwrap();
+        assert_eq!(vec![&expected_serial_number], transaction.serial_numbers().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_transaction_builder_insufficient_balance() {
+        let rng = &mut thread_rng();
+
+        let sender = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let record = Record::new_noop(sender.address(), rng).unwrap();
+        let builder = TransactionBuilder::new()
+            .add_input(record.clone(), LedgerProof::default())
+            .add_output(recipient.address(), AleoAmount::ZERO);
+
+        // The fee is not covered by the input.
+        let result = builder.clone().fee(AleoAmount::ONE_GATE).to_requests(sender.private_key(), rng);
+        assert!(matches!(result, Err(VMError::BalanceInsufficient)));
+
+        // The input is not sufficient to fund every transition.
+        let result = builder.add_output(recipient.address(), AleoAmount::ZERO).to_requests(sender.private_key(), rng);
+        assert!(matches!(result, Err(VMError::BalanceInsufficient)));
+    }
+
+    #[test]
+    fn test_transaction_builder_record_mismatch() {
+        let rng = &mut thread_rng();
+
+        let sender = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let result = TransactionBuilder::new()
+            .add_input(Record::new_noop(sender.address(), rng).unwrap(), LedgerProof::default())
+            .add_input(Record::new_noop(recipient.address(), rng).unwrap(), LedgerProof::default())
+            .add_output(recipient.address(), AleoAmount::ZERO)
+            .to_requests(sender.private_key(), rng);
+        assert!(matches!(result, Err(VMError::RecordOwnerMismatch(1))));
+    }
+}
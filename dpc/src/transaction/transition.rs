@@ -18,11 +18,15 @@ use crate::{
     circuits::{InputPublicVariables, OutputPublicVariables},
     prelude::*,
 };
-use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_algorithms::{
+    merkle_tree::{MerklePath, MerkleTree},
+    SNARK,
+};
 use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use rayon::prelude::*;
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -134,6 +138,168 @@ impl<N: Network> Transition<N> {
         ledger_root: N::LedgerRoot,
         local_transitions_root: N::TransactionID,
     ) -> bool {
+        self.verify_value_balance_commitment()
+            && self.verify_execution(input_circuit_id, output_circuit_id, ledger_root, local_transitions_root)
+    }
+
+    /// Returns `true` if the value balance commitment is valid for the transition ID.
+    #[inline]
+    pub fn verify_value_balance_commitment(&self) -> bool {
+        match self.transition_id.to_bytes_le() {
+            // Verify that the value balance commitment is valid.
+            Ok(message) => match self.value_balance_commitment.verify(
+                &self.input_value_commitments,
+                &self.output_value_commitments,
+                self.value_balance,
+                &message,
+            ) {
+                Ok(true) => true,
+                Ok(false) => {
+                    eprintln!("Transition contains an invalid value balance commitment");
+                    false
+                }
+                Err(err) => {
+                    eprintln!("Invalid value balance commitment verification {:?}", err);
+                    false
+                }
+            },
+            Err(err) => {
+                eprintln!("Invalid transition id {}", err);
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the value balance commitments of every given transition are valid, as a single batch.
+    pub fn verify_value_balance_commitments(transitions: &[&Self]) -> bool {
+        let transition_ids = transitions.iter().map(|transition| transition.transition_id.to_bytes_le());
+        let messages = match transition_ids.collect::<Result<Vec<_>, _>>() {
+            Ok(messages) => messages,
+            Err(err) => {
+                eprintln!("Invalid transition id {}", err);
+                return false;
+            }
+        };
+
+        let batch = transitions
+            .iter()
+            .zip_eq(&messages)
+            .map(|(transition, message)| {
+                (
+                    &*transition.value_balance_commitment,
+                    &transition.input_value_commitments[..],
+                    &transition.output_value_commitments[..],
+                    transition.value_balance,
+                    &message[..],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Verify that the value balance commitments are valid.
+        match ValueBalanceCommitment::<N>::verify_batch(&batch) {
+            Ok(true) => true,
+            Ok(false) => {
+                eprintln!("Transitions contain an invalid value balance commitment");
+                false
+            }
+            Err(err) => {
+                eprintln!("Invalid value balance commitment batch verification {:?}", err);
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the transition proofs are valid, without checking the value balance commitment.
+    #[inline]
+    pub fn verify_execution(
+        &self,
+        input_circuit_id: N::InputCircuitID,
+        output_circuit_id: N::OutputCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> bool {
+        // Returns `false` if the transition cannot be executed under the given circuit IDs.
+        if !self.is_valid_execution(input_circuit_id, output_circuit_id) {
+            return false;
+        }
+
+        let (input_public_variables, output_public_variables) =
+            self.to_public_variables(ledger_root, local_transitions_root);
+
+        // Returns `false` if the execution is invalid.
+        self.execution.verify(
+            N::input_verifying_key(),
+            N::output_verifying_key(),
+            &input_public_variables,
+            &output_public_variables,
+            self.transition_id,
+        )
+    }
+
+    ///
+    /// Returns `true` if the proofs of every given transition are valid, where each transition is given as
+    /// (transition, input circuit ID, output circuit ID, ledger root, local transitions root).
+    ///
+    /// The input and output proofs of all transitions are verified as two batches,
+    /// while each program proof is verified under its own verifying key.
+    ///
+    pub fn verify_executions(
+        transitions: &[(&Self, N::InputCircuitID, N::OutputCircuitID, N::LedgerRoot, N::TransactionID)],
+    ) -> bool {
+        // Returns `false` if any transition cannot be executed, or contains an invalid program proof.
+        if !transitions.par_iter().all(|(transition, input_circuit_id, output_circuit_id, ..)| {
+            transition.is_valid_execution(*input_circuit_id, *output_circuit_id)
+                && transition.execution.verify_program(transition.transition_id)
+        }) {
+            return false;
+        }
+
+        let (input_public_variables, output_public_variables): (Vec<_>, Vec<_>) = transitions
+            .par_iter()
+            .map(|(transition, _, _, ledger_root, local_transitions_root)| {
+                transition.to_public_variables(*ledger_root, *local_transitions_root)
+            })
+            .unzip();
+
+        // Returns `false` if any input proof is invalid.
+        let input_public_variables = input_public_variables.iter().flatten().collect::<Vec<_>>();
+        let input_proofs = transitions
+            .iter()
+            .flat_map(|(transition, ..)| transition.execution.input_proofs.iter().map(|proof| (**proof).clone()))
+            .collect::<Vec<_>>();
+        match N::InputSNARK::verify_batch(N::input_verifying_key(), &input_public_variables, &input_proofs) {
+            Ok(true) => (),
+            Ok(false) => {
+                eprintln!("Input proofs failed to verify");
+                return false;
+            }
+            Err(error) => {
+                eprintln!("Failed to validate the input proofs: {:?}", error);
+                return false;
+            }
+        }
+
+        // Returns `false` if any output proof is invalid.
+        let output_public_variables = output_public_variables.iter().flatten().collect::<Vec<_>>();
+        let output_proofs = transitions
+            .iter()
+            .flat_map(|(transition, ..)| transition.execution.output_proofs.iter().map(|proof| (**proof).clone()))
+            .collect::<Vec<_>>();
+        match N::OutputSNARK::verify_batch(N::output_verifying_key(), &output_public_variables, &output_proofs) {
+            Ok(true) => true,
+            Ok(false) => {
+                eprintln!("Output proofs failed to verify");
+                false
+            }
+            Err(error) => {
+                eprintln!("Failed to validate the output proofs: {:?}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the transition has a valid number of events and proofs, for the given circuit IDs.
+    fn is_valid_execution(&self, input_circuit_id: N::InputCircuitID, output_circuit_id: N::OutputCircuitID) -> bool {
         // Ensure the number of events is less than `N::NUM_EVENTS`.
         if self.events.len() > N::NUM_EVENTS as usize {
             eprintln!("Transition contains an invalid number of events");
@@ -152,34 +318,28 @@ impl<N: Network> Transition<N> {
             return false;
         }
 
-        match self.transition_id.to_bytes_le() {
-            Ok(message) => {
-                // Verify that the value balance commitment is valid.
-                match self.value_balance_commitment.verify(
-                    &self.input_value_commitments,
-                    &self.output_value_commitments,
-                    self.value_balance,
-                    &message,
-                ) {
-                    Ok(result) => {
-                        if !result {
-                            eprintln!("Transition contains an invalid value balance commitment");
-                            return false;
-                        }
-                    }
-                    Err(err) => {
-                        eprintln!("Invalid value balance commitment verification {:?}", err);
-                        return false;
-                    }
-                }
-            }
-            Err(err) => {
-                eprintln!("Invalid transition id {}", err);
-                return false;
-            }
+        // Returns `false` if the number of proofs does not match the number of records.
+        if self.execution.input_proofs.len() != self.serial_numbers.len()
+            || self.input_value_commitments.len() != self.serial_numbers.len()
+            || self.execution.output_proofs.len() != self.commitments.len()
+            || self.output_value_commitments.len() != self.commitments.len()
+        {
+            eprintln!("Transition contains an invalid number of proofs");
+            return false;
         }
 
+        true
+    }
+
+    /// Returns the public variables of the input and output proofs of this transition.
+    fn to_public_variables(
+        &self,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> (Vec<InputPublicVariables<N>>, Vec<OutputPublicVariables<N>>) {
         let program_id = self.execution.program_execution.as_ref().map(|x| x.program_id);
+        // The output records of a call belong to the callee program.
+        let output_program_id = self.execution.program_execution.as_ref().map(|x| x.output_program_id());
 
         let mut input_public_variables = Vec::with_capacity(N::NUM_INPUTS as usize);
         for (serial_number, input_value_commitment) in self.serial_numbers().zip_eq(self.input_value_commitments()) {
@@ -197,19 +357,12 @@ impl<N: Network> Transition<N> {
         let mut output_public_variables = Vec::with_capacity(N::NUM_OUTPUTS as usize);
         for (commitment, output_value_commitment) in self.commitments().zip_eq(self.output_value_commitments()) {
             let output_public =
-                OutputPublicVariables::<N>::new(*commitment, output_value_commitment.clone(), program_id);
+                OutputPublicVariables::<N>::new(*commitment, output_value_commitment.clone(), output_program_id);
 
             output_public_variables.push(output_public);
         }
 
-        // Returns `false` if the execution is invalid.
-        self.execution.verify(
-            N::input_verifying_key(),
-            N::output_verifying_key(),
-            &input_public_variables,
-            &output_public_variables,
-            self.transition_id,
-        )
+        (input_public_variables, output_public_variables)
     }
 
     /// Returns `true` if the given serial number exists.
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{error::ValueBalanceCommitmentError, AleoAmount, Network};
-use snarkvm_algorithms::CommitmentScheme;
+use snarkvm_algorithms::{msm::VariableBase, CommitmentScheme};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{FromBytes, ToBytes};
@@ -24,7 +24,8 @@ use blake2::{
     digest::{Update, VariableOutput},
     Blake2bVar,
 };
-use rand::Rng;
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
     io::{Read, Result as IoResult, Write},
@@ -139,6 +140,72 @@ impl<N: Network> ValueBalanceCommitment<N> {
         input: &[u8],
     ) -> Result<bool, ValueBalanceCommitmentError> {
         // Craft the combined value commitments (verifying key).
+        let combined_commitments =
+            Self::combined_commitments(input_value_commitments, output_value_commitments, value_balance)?;
+
+        let c = hash_into_field::<N>(&self.commitment.to_x_coordinate().to_bytes_le()?, input);
+        let recommit = N::value_commitment_scheme().commit_bytes(&0i64.to_le_bytes(), &self.blinding_factor)?;
+
+        Ok((combined_commitments.mul(c) + self.commitment.to_projective() - recommit.to_projective()).is_zero())
+    }
+
+    ///
+    /// Returns `true` if every value balance commitment is valid, where each entry is given as
+    /// (value balance commitment, input value commitments, output value commitments, value balance, message).
+    ///
+    /// The verification equations are checked at once, as a random linear combination of
+    ///     Σ z_i * (c_i * combined_commitments_i + commitment_i) == Commit(0, Σ z_i * blinding_factor_i)
+    /// which holds as the value commitment scheme is homomorphic.
+    ///
+    pub fn verify_batch(
+        batch: &[(&Self, &[N::ValueCommitment], &[N::ValueCommitment], AleoAmount, &[u8])],
+    ) -> Result<bool, ValueBalanceCommitmentError> {
+        // Craft the combined value commitments and the challenge for each value balance commitment.
+        let (combined_commitments, challenges): (Vec<_>, Vec<_>) = batch
+            .par_iter()
+            .map(|(vbc, input_vcs, output_vcs, value_balance, input)| -> Result<_, ValueBalanceCommitmentError> {
+                let combined_commitments = Self::combined_commitments(input_vcs, output_vcs, *value_balance)?;
+                let c = hash_into_field::<N>(&vbc.commitment.to_x_coordinate().to_bytes_le()?, input);
+                Ok((combined_commitments, c))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        // Sample a 128-bit randomizer z_i for each verification equation.
+        let rng = &mut thread_rng();
+        let randomizers = (0..batch.len()).map(|_| N::ProgramScalarField::from(rng.gen::<u128>())).collect::<Vec<_>>();
+
+        // Accumulate Σ z_i * blinding_factor_i, so that the recommitment is computed once.
+        let blinding_factor_sum = batch
+            .iter()
+            .zip(&randomizers)
+            .fold(N::ProgramScalarField::zero(), |sum, ((value_balance_commitment, ..), z)| {
+                sum + (value_balance_commitment.blinding_factor * z)
+            });
+        let recommit = N::value_commitment_scheme().commit_bytes(&0i64.to_le_bytes(), &blinding_factor_sum)?;
+
+        // Construct the bases and scalars for Σ z_i * (c_i * combined_commitments_i + commitment_i).
+        let mut bases =
+            <N::ProgramAffineCurve as AffineCurve>::Projective::batch_normalization_into_affine(combined_commitments);
+        bases.extend(batch.iter().map(|(value_balance_commitment, ..)| *value_balance_commitment.commitment));
+        let scalars = challenges
+            .iter()
+            .zip(&randomizers)
+            .map(|(c, z)| (*c * z).to_repr())
+            .chain(randomizers.iter().map(|z| z.to_repr()))
+            .collect::<Vec<_>>();
+
+        Ok((VariableBase::msm(&bases, &scalars) - recommit.to_projective()).is_zero())
+    }
+
+    /// Returns the combined value commitments (verifying key), given as
+    /// `Σ input_value_commitments - Σ output_value_commitments - Commit(value_balance, 0)`.
+    fn combined_commitments(
+        input_value_commitments: &[N::ValueCommitment],
+        output_value_commitments: &[N::ValueCommitment],
+        value_balance: AleoAmount,
+    ) -> Result<<N::ProgramAffineCurve as AffineCurve>::Projective, ValueBalanceCommitmentError> {
         let mut combined_commitments = N::ProgramAffineCurve::zero().to_projective();
 
         for vc_input in input_value_commitments {
@@ -151,10 +218,7 @@ impl<N: Network> ValueBalanceCommitment<N> {
 
         combined_commitments.sub_assign_mixed(&Self::commit_without_randomness(value_balance)?);
 
-        let c = hash_into_field::<N>(&self.commitment.to_x_coordinate().to_bytes_le()?, input);
-        let recommit = N::value_commitment_scheme().commit_bytes(&0i64.to_le_bytes(), &self.blinding_factor)?;
-
-        Ok((combined_commitments.mul(c) + self.commitment.to_projective() - recommit.to_projective()).is_zero())
+        Ok(combined_commitments)
     }
 
     /// Returns a commitment on the value balance with a randomness of zero.
@@ -27,11 +27,13 @@ use std::{
     str::FromStr,
 };
 
-/// Program ID, program path, verifying key, and proof.
+/// Program ID, callee program ID, program path, verifying key, and proof.
 #[derive(Clone, Derivative)]
 #[derivative(Debug(bound = "N: Network"), PartialEq(bound = "N: Network"), Eq(bound = "N: Network"))]
 pub struct ProgramExecution<N: Network> {
     pub program_id: N::ProgramID,
+    /// The program ID of the callee, if the execution is a call to another program.
+    pub callee_program_id: Option<N::ProgramID>,
     pub program_path: MerklePath<N::ProgramIDParameters>,
     #[derivative(Debug = "ignore")]
     pub verifying_key: N::ProgramVerifyingKey,
@@ -41,11 +43,17 @@ pub struct ProgramExecution<N: Network> {
 impl<N: Network> ProgramExecution<N> {
     pub fn from(
         program_id: N::ProgramID,
+        callee_program_id: Option<N::ProgramID>,
         program_path: MerklePath<N::ProgramIDParameters>,
         verifying_key: N::ProgramVerifyingKey,
         program_proof: N::ProgramProof,
     ) -> Result<Self> {
-        Ok(Self { program_id, program_path, verifying_key, program_proof })
+        Ok(Self { program_id, callee_program_id, program_path, verifying_key, program_proof })
+    }
+
+    /// Returns the program ID of the output records, which is the callee program ID for a call.
+    pub fn output_program_id(&self) -> N::ProgramID {
+        self.callee_program_id.unwrap_or(self.program_id)
     }
 }
 
@@ -111,6 +119,13 @@ impl<N: Network> Execution<N> {
             };
         }
 
+        // Returns `false` if the program proof is invalid.
+        self.verify_program(transition_id)
+    }
+
+    /// Returns `true` if the program proof is valid, or if there is no program execution.
+    #[inline]
+    pub fn verify_program(&self, transition_id: N::TransitionID) -> bool {
         if let Some(program_execution) = &self.program_execution {
             // Returns `false` if the program proof is invalid.
             match N::ProgramSNARK::verify(
@@ -169,10 +184,7 @@ impl<N: Network> ToBytes for Execution<N> {
         match &self.program_execution {
             Some(program_execution) => {
                 true.write_le(&mut writer)?;
-                program_execution.program_id.write_le(&mut writer)?;
-                program_execution.program_path.write_le(&mut writer)?;
-                program_execution.verifying_key.write_le(&mut writer)?;
-                program_execution.program_proof.write_le(&mut writer)?;
+                program_execution.write_le(&mut writer)?;
             }
             None => false.write_le(&mut writer)?,
         }
@@ -248,11 +260,16 @@ impl<N: Network> FromBytes for ProgramExecution<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let program_id = FromBytes::read_le(&mut reader)?;
+        let callee_program_id_exists: bool = FromBytes::read_le(&mut reader)?;
+        let callee_program_id = match callee_program_id_exists {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
         let program_path = FromBytes::read_le(&mut reader)?;
         let verifying_key = FromBytes::read_le(&mut reader)?;
         let program_proof = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self { program_id, program_path, verifying_key, program_proof })
+        Ok(Self { program_id, callee_program_id, program_path, verifying_key, program_proof })
     }
 }
 
@@ -262,6 +279,13 @@ impl<N: Network> ToBytes for ProgramExecution<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.program_id.write_le(&mut writer)?;
+        match &self.callee_program_id {
+            Some(callee_program_id) => {
+                true.write_le(&mut writer)?;
+                callee_program_id.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
         self.program_path.write_le(&mut writer)?;
         self.verifying_key.write_le(&mut writer)?;
         self.program_proof.write_le(&mut writer)
@@ -288,8 +312,9 @@ impl<N: Network> Serialize for ProgramExecution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("ProgramExecution", 4)?;
+                let mut execution = serializer.serialize_struct("ProgramExecution", 5)?;
                 execution.serialize_field("program_id", &self.program_id)?;
+                execution.serialize_field("callee_program_id", &self.callee_program_id)?;
                 execution.serialize_field("program_path", &self.program_path)?;
                 execution.serialize_field("verifying_key", &self.verifying_key)?;
                 execution.serialize_field("program_proof", &self.program_proof)?;
@@ -308,6 +333,8 @@ impl<'de, N: Network> Deserialize<'de> for ProgramExecution<N> {
                 // Recover the program execution.
                 Self::from(
                     serde_json::from_value(program_execution["program_id"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(program_execution["callee_program_id"].clone())
+                        .map_err(de::Error::custom)?,
                     serde_json::from_value(program_execution["program_path"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(program_execution["verifying_key"].clone()).map_err(de::Error::custom)?,
                     serde_json::from_value(program_execution["program_proof"].clone()).map_err(de::Error::custom)?,
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoAmount, Function, Network, ProgramPublicVariables};
+use snarkvm_algorithms::snark::marlin::{CircuitVerifyingKey, MarlinMode};
+use snarkvm_curves::PairingEngine;
+use snarkvm_r1cs::CountingConstraintSystem;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// The size of a function circuit, as indexed by the program SNARK.
+///
+/// The public input is padded to a power of two and the constraint matrices are made square,
+/// so that the size of a counted circuit matches the size recorded in its verifying key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CircuitSize {
+    /// The number of variables, including the padded public input.
+    pub num_variables: u64,
+    /// The number of constraints, including the padding constraints.
+    pub num_constraints: u64,
+    /// The number of non-zero entries across the A, B, and C matrices.
+    pub num_non_zero: u64,
+}
+
+impl CircuitSize {
+    /// Returns the indexed circuit size for the given counts.
+    pub fn from_counts(counts: &CountingConstraintSystem) -> Self {
+        let num_variables = counts.num_public_variables.next_power_of_two() + counts.num_private_variables;
+        // The constraint matrices are padded with dummy constraints or variables until they are square.
+        let num_padded = num_variables.max(counts.num_constraints) as u64;
+
+        Self {
+            num_variables: num_padded,
+            num_constraints: num_padded,
+            num_non_zero: (counts.num_non_zero_a + counts.num_non_zero_b + counts.num_non_zero_c) as u64,
+        }
+    }
+}
+
+/// Returns the size of the circuit that a verifying key was derived from.
+pub trait ToCircuitSize {
+    fn to_circuit_size(&self) -> CircuitSize;
+}
+
+impl<E: PairingEngine, MM: MarlinMode> ToCircuitSize for CircuitVerifyingKey<E, MM> {
+    fn to_circuit_size(&self) -> CircuitSize {
+        let info = &self.circuit_info;
+        CircuitSize {
+            num_variables: info.num_variables as u64,
+            num_constraints: info.num_constraints as u64,
+            num_non_zero: (info.num_non_zero_a + info.num_non_zero_b + info.num_non_zero_c) as u64,
+        }
+    }
+}
+
+/// A deterministic fee schedule, which maps the size of a function circuit to a fee.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeeSchedule {
+    /// The fee charged for any function execution, in gates.
+    pub base_fee: u64,
+    /// The fee charged per constraint, in gates.
+    pub fee_per_constraint: u64,
+    /// The fee charged per variable, in gates.
+    pub fee_per_variable: u64,
+    /// The fee charged per non-zero matrix entry, in gates.
+    pub fee_per_non_zero: u64,
+}
+
+impl FeeSchedule {
+    /// Returns the fee for a circuit of the given size.
+    pub fn fee(&self, size: &CircuitSize) -> Result<AleoAmount> {
+        let variable_fees = [
+            (size.num_constraints, self.fee_per_constraint),
+            (size.num_variables, self.fee_per_variable),
+            (size.num_non_zero, self.fee_per_non_zero),
+        ];
+
+        let fee = variable_fees.iter().try_fold(self.base_fee, |fee, (count, rate)| {
+            count.checked_mul(*rate).and_then(|variable_fee| fee.checked_add(variable_fee))
+        });
+
+        match fee.and_then(|fee| i64::try_from(fee).ok()) {
+            Some(fee) => Ok(AleoAmount::from_gate(fee)),
+            None => Err(anyhow!("Fee estimate overflowed for circuit of size {:?}", size)),
+        }
+    }
+
+    /// Returns the fee for the given function, by synthesizing its circuit in counting mode.
+    pub fn fee_estimate<N: Network, F: Function<N>>(&self, function: &F) -> Result<AleoAmount> {
+        let mut counts = CountingConstraintSystem::new();
+        function.synthesize(&mut counts, &ProgramPublicVariables::blank())?;
+        self.fee(&CircuitSize::from_counts(&counts))
+    }
+
+    /// Returns the fee for the function with the given verifying key.
+    pub fn fee_for_verifying_key<N: Network>(&self, verifying_key: &N::ProgramVerifyingKey) -> Result<AleoAmount> {
+        self.fee(&verifying_key.to_circuit_size())
+    }
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self { base_fee: 1_000, fee_per_constraint: 1, fee_per_variable: 1, fee_per_non_zero: 1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, ProgramPrivateVariables};
+    use snarkvm_algorithms::{
+        snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode},
+        SNARK,
+        SRS,
+    };
+    use snarkvm_fields::One;
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+
+    use rand::{rngs::ThreadRng, thread_rng};
+
+    /// A function that proves knowledge of the factors of a number.
+    struct FactorFunction {
+        num_factors: usize,
+    }
+
+    impl<N: Network> Function<N> for FactorFunction {
+        fn function_id(&self) -> N::FunctionID {
+            Default::default()
+        }
+
+        fn execute(&self, _: ProgramPublicVariables<N>, _: &dyn ProgramPrivateVariables<N>) -> Result<N::ProgramProof> {
+            unimplemented!()
+        }
+
+        fn verify(&self, _: &ProgramPublicVariables<N>, _: &N::ProgramProof) -> bool {
+            unimplemented!()
+        }
+
+        fn synthesize<CS: ConstraintSystem<N::InnerScalarField>>(
+            &self,
+            cs: &mut CS,
+            _: &ProgramPublicVariables<N>,
+        ) -> Result<(), SynthesisError> {
+            let one = N::InnerScalarField::one();
+            let mut product = cs.alloc_input(|| "product", || Ok(one))?;
+            for i in 0..self.num_factors {
+                let factor = cs.alloc(|| format!("factor_{}", i), || Ok(one))?;
+                let next = cs.alloc(|| format!("partial_{}", i), || Ok(one))?;
+                cs.enforce(|| format!("multiply_{}", i), |lc| lc + product, |lc| lc + factor, |lc| lc + next);
+                product = next;
+            }
+            Ok(())
+        }
+    }
+
+    /// The circuit of a function, as synthesized for the program SNARK setup.
+    struct FunctionCircuit<'a, N: Network>(&'a FactorFunction, ProgramPublicVariables<N>);
+
+    impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for FunctionCircuit<'_, N> {
+        fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
+            &self,
+            cs: &mut CS,
+        ) -> Result<(), SynthesisError> {
+            Function::<N>::synthesize(self.0, cs, &self.1)
+        }
+    }
+
+    #[test]
+    fn test_fee_estimate() {
+        let schedule = FeeSchedule::default();
+
+        // 2 public variables are padded to 2, with 2 private variables per factor and 1 constraint per factor.
+        let function = FactorFunction { num_factors: 10 };
+        let expected_size = CircuitSize { num_variables: 22, num_constraints: 22, num_non_zero: 30 };
+        let expected_fee = AleoAmount::from_gate(1_000 + 22 + 22 + 30);
+        assert_eq!(expected_fee, schedule.fee_estimate::<Testnet2, _>(&function).unwrap());
+        assert_eq!(expected_fee, schedule.fee(&expected_size).unwrap());
+
+        // The estimate grows with the size of the circuit.
+        let larger = FactorFunction { num_factors: 100 };
+        assert!(schedule.fee_estimate::<Testnet2, _>(&larger).unwrap() > expected_fee);
+
+        // The estimate is rejected if it overflows.
+        let schedule = FeeSchedule { fee_per_constraint: u64::MAX, ..Default::default() };
+        assert!(schedule.fee_estimate::<Testnet2, _>(&function).is_err());
+    }
+
+    #[test]
+    fn test_fee_estimate_matches_verifying_key() {
+        let rng = &mut thread_rng();
+        let schedule = FeeSchedule::default();
+
+        let max_degree =
+            AHPForR1CS::<<Testnet2 as Network>::InnerScalarField, MarlinHidingMode>::max_degree(256, 256, 512).unwrap();
+        let universal_srs = <<Testnet2 as Network>::ProgramSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
+
+        for num_factors in [1, 10, 100] {
+            let function = FactorFunction { num_factors };
+            let circuit = FunctionCircuit(&function, ProgramPublicVariables::<Testnet2>::blank());
+
+            let srs = &mut SRS::<ThreadRng, _>::Universal(&universal_srs);
+            let (_, verifying_key) = <Testnet2 as Network>::ProgramSNARK::setup(&circuit, srs).unwrap();
+
+            assert_eq!(
+                schedule.fee_estimate::<Testnet2, _>(&function).unwrap(),
+                schedule.fee_for_verifying_key::<Testnet2>(&verifying_key).unwrap()
+            );
+        }
+    }
+}
@@ -23,6 +23,9 @@ pub use event::*;
 pub mod execution;
 pub use execution::*;
 
+pub mod fee_schedule;
+pub use fee_schedule::*;
+
 pub mod function_inputs;
 pub use function_inputs::*;
 
@@ -38,5 +41,8 @@ pub use program::*;
 pub mod program_public_variables;
 pub use program_public_variables::*;
 
+pub mod program_registry;
+pub use program_registry::*;
+
 pub mod virtual_machine;
 pub use virtual_machine::*;
@@ -40,6 +40,8 @@ pub enum Operation<N: Network> {
     Transfer(Caller<N>, Recipient<N>, AleoAmount),
     /// Invokes the given records on the function and inputs.
     Evaluate(N::FunctionID, FunctionInputs<N>),
+    /// Invokes the given records on the function and inputs, passing the output record to the callee program.
+    Call(N::FunctionID, N::ProgramID, FunctionInputs<N>),
 }
 
 impl<N: Network> Operation<N> {
@@ -50,23 +52,32 @@ impl<N: Network> Operation<N> {
             Self::Coinbase(..) => 1,
             Self::Transfer(..) => 2,
             Self::Evaluate(..) => 3,
+            Self::Call(..) => 4,
         }
     }
 
     pub fn function_id(&self) -> Option<N::FunctionID> {
         match self {
             Self::Noop | Self::Coinbase(..) | Self::Transfer(..) => None,
-            Self::Evaluate(function_id, _) => Some(*function_id),
+            Self::Evaluate(function_id, _) | Self::Call(function_id, ..) => Some(*function_id),
         }
     }
 
     pub fn function_inputs(&self) -> Result<FunctionInputs<N>> {
         match self {
-            Self::Evaluate(_, function_inputs) => Ok(function_inputs.clone()),
+            Self::Evaluate(_, function_inputs) | Self::Call(_, _, function_inputs) => Ok(function_inputs.clone()),
             _ => Err(anyhow!("operation does not have function inputs")),
         }
     }
 
+    /// Returns the program ID of the callee, if the operation is a call.
+    pub fn callee_program_id(&self) -> Option<N::ProgramID> {
+        match self {
+            Self::Call(_, callee_program_id, _) => Some(*callee_program_id),
+            _ => None,
+        }
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Self::Noop)
     }
@@ -82,6 +93,10 @@ impl<N: Network> Operation<N> {
     pub fn is_evaluate(&self) -> bool {
         matches!(self, Self::Evaluate(..))
     }
+
+    pub fn is_call(&self) -> bool {
+        matches!(self, Self::Call(..))
+    }
 }
 
 impl<N: Network> FromBytes for Operation<N> {
@@ -106,7 +121,13 @@ impl<N: Network> FromBytes for Operation<N> {
                 let function_inputs = FromBytes::read_le(&mut reader)?;
                 Ok(Self::Evaluate(function_id, function_inputs))
             }
-            4.. => Err(error("Invalid operation ID during deserialization")),
+            4 => {
+                let function_id = FromBytes::read_le(&mut reader)?;
+                let callee_program_id = FromBytes::read_le(&mut reader)?;
+                let function_inputs = FromBytes::read_le(&mut reader)?;
+                Ok(Self::Call(function_id, callee_program_id, function_inputs))
+            }
+            5.. => Err(error("Invalid operation ID during deserialization")),
         }
     }
 }
@@ -130,6 +151,11 @@ impl<N: Network> ToBytes for Operation<N> {
                 function_id.write_le(&mut writer)?;
                 function_inputs.write_le(&mut writer)
             }
+            Self::Call(function_id, callee_program_id, function_inputs) => {
+                function_id.write_le(&mut writer)?;
+                callee_program_id.write_le(&mut writer)?;
+                function_inputs.write_le(&mut writer)
+            }
         }
     }
 }
@@ -159,7 +185,13 @@ impl<N: Network> FromStr for Operation<N> {
                 let function_inputs = serde_json::from_value(operation["function_inputs"].clone())?;
                 Ok(Self::Evaluate(function_id, function_inputs))
             }
-            4.. => Err(error("Invalid operation ID during deserialization").into()),
+            4 => {
+                let function_id = serde_json::from_value(operation["function_id"].clone())?;
+                let callee_program_id = serde_json::from_value(operation["callee_program_id"].clone())?;
+                let function_inputs = serde_json::from_value(operation["function_inputs"].clone())?;
+                Ok(Self::Call(function_id, callee_program_id, function_inputs))
+            }
+            5.. => Err(error("Invalid operation ID during deserialization").into()),
         }
     }
 }
@@ -194,6 +226,14 @@ impl<N: Network> fmt::Display for Operation<N> {
                     "function_inputs": function_inputs
                 })
             }
+            Self::Call(function_id, callee_program_id, function_inputs) => {
+                serde_json::json!({
+                    "id": self.operation_id(),
+                    "function_id": function_id,
+                    "callee_program_id": callee_program_id,
+                    "function_inputs": function_inputs
+                })
+            }
         };
 
         write!(f, "{}", operation)
@@ -224,3 +264,35 @@ impl<N: Network> ToConstraintField<N::InnerScalarField> for Operation<N> {
         Ok(v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, Payload};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_call_operation_serialization() {
+        let rng = &mut thread_rng();
+
+        let caller = Account::<Testnet2>::new(rng).address();
+        let recipient = Account::<Testnet2>::new(rng).address();
+        let function_inputs = FunctionInputs::new(&caller, &recipient, AleoAmount(100), Payload::default());
+        let function_id = <Testnet2 as Network>::FunctionID::rand(rng);
+        let callee_program_id = <Testnet2 as Network>::ProgramID::rand(rng);
+
+        let operation = Operation::<Testnet2>::Call(function_id, callee_program_id, function_inputs);
+        assert_eq!(4, operation.operation_id());
+        assert_eq!(Some(function_id), operation.function_id());
+        assert_eq!(Some(callee_program_id), operation.callee_program_id());
+
+        // Ensure the operation round trips through bytes.
+        let bytes = operation.to_bytes_le().unwrap();
+        assert_eq!(operation, Operation::read_le(&bytes[..]).unwrap());
+
+        // Ensure the operation round trips through a string.
+        assert_eq!(operation, Operation::from_str(&operation.to_string()).unwrap());
+    }
+}
@@ -0,0 +1,386 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::merkle_tree::MerklePath;
+
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, sync::Arc};
+
+/// A program registry resolves program IDs to programs and the verifying keys of their functions,
+/// allowing a function to reference the function of another program.
+#[derive(Clone, Derivative)]
+#[derivative(Debug(bound = "N: Network"))]
+pub struct ProgramRegistry<N: Network> {
+    #[derivative(Debug = "ignore")]
+    programs: HashMap<N::ProgramID, Arc<Program<N>>>,
+    #[derivative(Debug = "ignore")]
+    verifying_keys: HashMap<(N::ProgramID, N::FunctionID), N::ProgramVerifyingKey>,
+}
+
+impl<N: Network> ProgramRegistry<N> {
+    /// Initializes an empty program registry.
+    pub fn new() -> Self {
+        Self { programs: Default::default(), verifying_keys: Default::default() }
+    }
+
+    /// Adds the given program and the verifying key of each of its functions, returning the program ID.
+    pub fn add_program(
+        &mut self,
+        program: Program<N>,
+        verifying_keys: Vec<(N::FunctionID, N::ProgramVerifyingKey)>,
+    ) -> Result<N::ProgramID> {
+        let program_id = program.program_id();
+
+        // Ensure the program does not already exist in the registry.
+        if self.contains_program(&program_id) {
+            return Err(anyhow!("Program {} already exists in the registry", program_id));
+        }
+
+        // Ensure there is exactly one verifying key for each function in the program.
+        let verifying_keys = verifying_keys.into_iter().collect::<HashMap<_, _>>();
+        if verifying_keys.len() != program.num_functions()
+            || verifying_keys.keys().any(|function_id| !program.contains_function(function_id))
+        {
+            return Err(anyhow!("Program {} requires one verifying key for each function", program_id));
+        }
+
+        self.verifying_keys.extend(
+            verifying_keys.into_iter().map(|(function_id, verifying_key)| ((program_id, function_id), verifying_key)),
+        );
+        self.programs.insert(program_id, Arc::new(program));
+
+        Ok(program_id)
+    }
+
+    /// Returns `true` if the given program ID exists in the registry.
+    pub fn contains_program(&self, program_id: &N::ProgramID) -> bool {
+        self.programs.contains_key(program_id)
+    }
+
+    /// Returns the program given the program ID, if it exists.
+    pub fn get_program(&self, program_id: &N::ProgramID) -> Result<Arc<Program<N>>> {
+        match self.programs.get(program_id) {
+            Some(program) => Ok(program.clone()),
+            None => Err(anyhow!("Program {} does not exist in the registry", program_id)),
+        }
+    }
+
+    /// Returns the function, program path, and verifying key for the given function of the given program.
+    pub fn to_function(
+        &self,
+        program_id: &N::ProgramID,
+        function_id: &N::FunctionID,
+    ) -> Result<(Arc<dyn Function<N>>, MerklePath<N::ProgramIDParameters>, N::ProgramVerifyingKey)> {
+        let program = self.get_program(program_id)?;
+        let verifying_key = match self.verifying_keys.get(&(*program_id, *function_id)) {
+            Some(verifying_key) => verifying_key.clone(),
+            None => return Err(anyhow!("Function {} does not exist in program {}", function_id, program_id)),
+        };
+
+        Ok((program.to_function(function_id)?, program.to_program_path(function_id)?, verifying_key))
+    }
+
+    /// Returns the fee for the given function of the given program, from the size of its verifying key.
+    pub fn fee_estimate(
+        &self,
+        program_id: &N::ProgramID,
+        function_id: &N::FunctionID,
+        fee_schedule: &FeeSchedule,
+    ) -> Result<AleoAmount> {
+        match self.verifying_keys.get(&(*program_id, *function_id)) {
+            Some(verifying_key) => fee_schedule.fee_for_verifying_key::<N>(verifying_key),
+            None => Err(anyhow!("Function {} does not exist in program {}", function_id, program_id)),
+        }
+    }
+
+    /// Returns `true` if the given program execution is for a function in the registry,
+    /// and uses the registered verifying key of that function.
+    pub fn contains_program_execution(&self, program_execution: &ProgramExecution<N>) -> bool {
+        self.verifying_keys.iter().any(|((program_id, function_id), verifying_key)| {
+            *program_id == program_execution.program_id
+                && *verifying_key == program_execution.verifying_key
+                && program_execution.program_path.verify(program_id, function_id).unwrap_or(false)
+        })
+    }
+
+    /// Returns `true` if each program execution in the given transaction is for a function in the registry.
+    /// For each call, the callee program must be in the registry, and a subsequent transition of the
+    /// transaction must execute the callee program, which is the only program that may consume
+    /// the output records of the call.
+    pub fn verify_program_calls(&self, transaction: &Transaction<N>) -> bool {
+        let program_executions = transaction
+            .transitions()
+            .iter()
+            .map(|transition| transition.execution().program_execution.as_ref())
+            .collect::<Vec<_>>();
+
+        for (index, program_execution) in program_executions.iter().enumerate() {
+            let program_execution = match program_execution {
+                Some(program_execution) => program_execution,
+                None => continue,
+            };
+
+            // Ensure the program execution is for a function in the registry.
+            if !self.contains_program_execution(program_execution) {
+                eprintln!(
+                    "Transaction contains an execution of program {} that is not registered",
+                    program_execution.program_id
+                );
+                return false;
+            }
+
+            if let Some(callee_program_id) = program_execution.callee_program_id {
+                // Ensure the callee program is in the registry.
+                if !self.contains_program(&callee_program_id) {
+                    eprintln!("Transaction contains a call to program {} that is not registered", callee_program_id);
+                    return false;
+                }
+
+                // Ensure a subsequent transition executes the callee program.
+                if !program_executions[index + 1..]
+                    .iter()
+                    .flatten()
+                    .any(|callee_execution| callee_execution.program_id == callee_program_id)
+                {
+                    eprintln!("Transaction contains a call to program {} that is never executed", callee_program_id);
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<N: Network> Default for ProgramRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_algorithms::{
+        snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode},
+        SNARK,
+        SRS,
+    };
+    use snarkvm_fields::{ConstraintFieldError, One, ToConstraintField};
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use rand::{rngs::ThreadRng, thread_rng};
+    use std::io::{Read, Result as IoResult, Write};
+
+    type CurrentNetwork = Testnet2;
+    type InnerScalarField = <CurrentNetwork as Network>::InnerScalarField;
+    type ProgramSNARK = <CurrentNetwork as Network>::ProgramSNARK;
+
+    /// A circuit that enforces its public variables and `num_constraints` additional constraints.
+    struct TestCircuit {
+        num_constraints: usize,
+        public: ProgramPublicVariables<CurrentNetwork>,
+    }
+
+    impl ConstraintSynthesizer<InnerScalarField> for TestCircuit {
+        fn generate_constraints<CS: ConstraintSystem<InnerScalarField>>(
+            &self,
+            cs: &mut CS,
+        ) -> Result<(), SynthesisError> {
+            for (i, value) in self.public.to_field_elements()?.into_iter().enumerate() {
+                let public = cs.alloc_input(|| format!("public_{}", i), || Ok(value))?;
+                cs.enforce(|| format!("enforce_public_{}", i), |lc| lc + public, |lc| lc + CS::one(), |lc| lc + public);
+            }
+            for i in 0..self.num_constraints {
+                let one = cs.alloc(|| format!("one_{}", i), || Ok(InnerScalarField::one()))?;
+                cs.enforce(|| format!("square_{}", i), |lc| lc + one, |lc| lc + one, |lc| lc + one);
+            }
+            Ok(())
+        }
+    }
+
+    /// A function that proves the test circuit with its own proving key.
+    struct TestFunction {
+        num_constraints: usize,
+        function_id: <CurrentNetwork as Network>::FunctionID,
+        proving_key: <ProgramSNARK as SNARK>::ProvingKey,
+        verifying_key: <CurrentNetwork as Network>::ProgramVerifyingKey,
+    }
+
+    impl TestFunction {
+        fn new(num_constraints: usize, universal_srs: &<ProgramSNARK as SNARK>::UniversalSetupParameters) -> Self {
+            let circuit = TestCircuit { num_constraints, public: ProgramPublicVariables::blank() };
+            let (proving_key, verifying_key) =
+                ProgramSNARK::setup(&circuit, &mut SRS::<ThreadRng, _>::Universal(universal_srs)).unwrap();
+            let function_id = CurrentNetwork::function_id(&verifying_key).unwrap();
+
+            Self { num_constraints, function_id, proving_key, verifying_key }
+        }
+    }
+
+    impl Function<CurrentNetwork> for TestFunction {
+        fn function_id(&self) -> <CurrentNetwork as Network>::FunctionID {
+            self.function_id
+        }
+
+        fn execute(
+            &self,
+            public: ProgramPublicVariables<CurrentNetwork>,
+            _: &dyn ProgramPrivateVariables<CurrentNetwork>,
+        ) -> Result<<CurrentNetwork as Network>::ProgramProof> {
+            let circuit = TestCircuit { num_constraints: self.num_constraints, public };
+            Ok(<ProgramSNARK as SNARK>::prove(&self.proving_key, &circuit, &mut thread_rng())?.into())
+        }
+
+        fn verify(
+            &self,
+            public: &ProgramPublicVariables<CurrentNetwork>,
+            proof: &<CurrentNetwork as Network>::ProgramProof,
+        ) -> bool {
+            <ProgramSNARK as SNARK>::verify(&self.verifying_key, public, proof).unwrap_or(false)
+        }
+
+        fn synthesize<CS: ConstraintSystem<InnerScalarField>>(
+            &self,
+            cs: &mut CS,
+            public: &ProgramPublicVariables<CurrentNetwork>,
+        ) -> Result<(), SynthesisError> {
+            TestCircuit { num_constraints: self.num_constraints, public: *public }.generate_constraints(cs)
+        }
+    }
+
+    #[derive(Debug)]
+    struct EmptyPrivateVariables;
+
+    impl ToConstraintField<InnerScalarField> for EmptyPrivateVariables {
+        fn to_field_elements(&self) -> Result<Vec<InnerScalarField>, ConstraintFieldError> {
+            Ok(vec![])
+        }
+    }
+
+    impl FromBytes for EmptyPrivateVariables {
+        fn read_le<R: Read>(_: R) -> IoResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    impl ToBytes for EmptyPrivateVariables {
+        fn write_le<W: Write>(&self, _: W) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    impl ProgramPrivateVariables<CurrentNetwork> for EmptyPrivateVariables {
+        fn new_blank() -> Result<Self> {
+            Ok(Self)
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Returns a program with the given function, along with the verifying key of the function.
+    fn to_program(
+        function: &Arc<TestFunction>,
+    ) -> (
+        Program<CurrentNetwork>,
+        Vec<(<CurrentNetwork as Network>::FunctionID, <CurrentNetwork as Network>::ProgramVerifyingKey)>,
+    ) {
+        let program = Program::new(vec![function.clone() as Arc<dyn Function<CurrentNetwork>>]).unwrap();
+        (program, vec![(function.function_id, function.verifying_key.clone())])
+    }
+
+    #[test]
+    fn test_execute_call() {
+        let rng = &mut thread_rng();
+
+        let max_degree = AHPForR1CS::<InnerScalarField, MarlinHidingMode>::max_degree(256, 256, 512).unwrap();
+        let universal_srs = <ProgramSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
+
+        // Register a caller program and a callee program.
+        let caller_function = Arc::new(TestFunction::new(1, &universal_srs));
+        let callee_function = Arc::new(TestFunction::new(2, &universal_srs));
+
+        let mut program_registry = ProgramRegistry::<CurrentNetwork>::new();
+        let (program, verifying_keys) = to_program(&caller_function);
+        let caller_program_id = program_registry.add_program(program, verifying_keys).unwrap();
+        let (program, verifying_keys) = to_program(&callee_function);
+        let callee_program_id = program_registry.add_program(program, verifying_keys).unwrap();
+
+        // The caller passes a record to the recipient in the callee program.
+        let caller = Account::<CurrentNetwork>::new(rng);
+        let recipient = Account::<CurrentNetwork>::new(rng);
+        let payload = Payload::default();
+
+        let function_inputs =
+            FunctionInputs::new(&caller.address(), &recipient.address(), AleoAmount::ZERO, payload.clone());
+        let request = Request::new(
+            caller.private_key(),
+            vec![Record::new_noop(caller.address(), rng).unwrap()],
+            vec![LedgerProof::default()],
+            Operation::Call(caller_function.function_id, callee_program_id, function_inputs),
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+
+        let vm = VirtualMachine::<CurrentNetwork>::new(LedgerTree::<CurrentNetwork>::new().unwrap().root()).unwrap();
+        let (vm, response) = vm
+            .execute_call(&request, &program_registry, caller_program_id, &EmptyPrivateVariables, vec![], rng)
+            .unwrap();
+
+        // The call is invalid until the callee program consumes the record.
+        let transaction = vm.finalize().unwrap();
+        assert!(transaction.is_valid());
+        assert!(!transaction.is_valid_with_registry(&program_registry));
+
+        // The recipient consumes the record in the callee program.
+        let (records, ledger_proofs) = vm.to_callee_inputs(&response, callee_program_id, recipient.address()).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(Some(callee_program_id), records[0].program_id());
+
+        let function_inputs =
+            FunctionInputs::new(&recipient.address(), &recipient.address(), AleoAmount::ZERO, payload);
+        let request = Request::new(
+            recipient.private_key(),
+            records,
+            ledger_proofs,
+            Operation::Evaluate(callee_function.function_id, function_inputs),
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+
+        let (vm, _) = vm
+            .execute_call(&request, &program_registry, callee_program_id, &EmptyPrivateVariables, vec![], rng)
+            .unwrap();
+
+        let transaction = vm.finalize().unwrap();
+        assert!(transaction.is_valid_with_registry(&program_registry));
+
+        // The transaction is invalid if the callee program is not in the registry.
+        let mut caller_registry = ProgramRegistry::<CurrentNetwork>::new();
+        let (program, verifying_keys) = to_program(&caller_function);
+        caller_registry.add_program(program, verifying_keys).unwrap();
+        assert!(!transaction.is_valid_with_registry(&caller_registry));
+    }
+}
@@ -49,7 +49,24 @@ impl<N: Network> VirtualMachine<N> {
     }
 
     /// Executes the request, returning a transaction.
-    pub fn execute<R: Rng + CryptoRng>(mut self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
+    pub fn execute<R: Rng + CryptoRng>(self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
+        self.execute_with_prover(request, |proving_request, rng| proving_request.prove(rng), rng)
+    }
+
+    /// Executes the request, delegating the input and output circuit proofs to the given prover.
+    ///
+    /// The prover may forward each proving request to a remote service. Every proving response
+    /// is verified against its request before it is included in the transition.
+    pub fn execute_with_prover<R, P>(
+        mut self,
+        request: &Request<N>,
+        mut prover: P,
+        rng: &mut R,
+    ) -> Result<(Self, Response<N>)>
+    where
+        R: Rng + CryptoRng,
+        P: FnMut(&ProvingRequest<N>, &mut R) -> Result<ProvingResponse<N>>,
+    {
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
@@ -61,14 +78,17 @@ impl<N: Network> VirtualMachine<N> {
             Operation::Noop => Self::noop(request, rng)?,
             Operation::Coinbase(recipient, amount) => Self::coinbase(request, recipient, amount, rng)?,
             Operation::Transfer(caller, recipient, amount) => Self::transfer(request, caller, recipient, amount, rng)?,
-            Operation::Evaluate(function_id, function_inputs) => self.evaluate(
-                request,
-                request.to_program_id()?,
-                &function_id,
-                &function_inputs,
-                vec![], // custom_events
-                rng,
-            )?,
+            Operation::Evaluate(function_id, function_inputs) => {
+                self.evaluate(
+                    request,
+                    request.to_program_id()?,
+                    &function_id,
+                    &function_inputs,
+                    vec![], // custom_events
+                    rng,
+                )?
+            }
+            Operation::Call(..) => return Err(anyhow!("Virtual machine must execute a call with `execute_call`")),
         };
 
         let program_id = request.to_program_id()?;
@@ -102,12 +122,16 @@ impl<N: Network> VirtualMachine<N> {
                 *input_value_commitment_randomness,
             )?;
 
-            let input_circuit = InputCircuit::<N>::new(input_public.clone(), input_private);
-            let input_proof = N::InputSNARK::prove(N::input_proving_key(), &input_circuit, rng)?;
+            let proving_request = ProvingRequest::new_input(input_public, input_private);
+            let proving_response = prover(&proving_request, rng)?;
 
-            assert!(N::InputSNARK::verify(N::input_verifying_key(), &input_public, &input_proof)?);
-
-            input_proofs.push(input_proof.into());
+            // Ensure the proof is valid for the request.
+            match proving_response {
+                ProvingResponse::Input(input_proof) if proving_response.verify(&proving_request) => {
+                    input_proofs.push(input_proof)
+                }
+                _ => return Err(anyhow!("Virtual machine received an invalid input proof")),
+            }
         }
 
         // TODO (raychu86): Clean this up.
@@ -132,12 +156,16 @@ impl<N: Network> VirtualMachine<N> {
                 *output_value_commitment_randomness,
             )?;
 
-            let output_circuit = OutputCircuit::<N>::new(output_public.clone(), output_private);
-            let output_proof = N::OutputSNARK::prove(N::output_proving_key(), &output_circuit, rng)?;
+            let proving_request = ProvingRequest::new_output(output_public, output_private);
+            let proving_response = prover(&proving_request, rng)?;
 
-            assert!(N::OutputSNARK::verify(N::output_verifying_key(), &output_public, &output_proof)?);
-
-            output_proofs.push(output_proof.into());
+            // Ensure the proof is valid for the request.
+            match proving_response {
+                ProvingResponse::Output(output_proof) if proving_response.verify(&proving_request) => {
+                    output_proofs.push(output_proof)
+                }
+                _ => return Err(anyhow!("Virtual machine received an invalid output proof")),
+            }
         }
 
         // Compute the noop execution, for now.
@@ -245,17 +273,24 @@ impl<N: Network> VirtualMachine<N> {
             return Err(VMError::BalanceInsufficient.into());
         }
 
+        // If the operation is a call, the output record is passed to the callee program.
+        let output_program_id = request.operation().callee_program_id().or(program_id);
+
         let mut response_builder = ResponseBuilder::new().add_request(request.clone()).add_output(Output::new(
             function_inputs.recipient,
             function_inputs.amount,
             Some(function_inputs.record_payload.clone()),
-            program_id,
+            output_program_id,
         )?);
 
         // Add the change address if the balance is not zero.
         if !caller_balance.is_zero() {
-            response_builder =
-                response_builder.add_output(Output::new(function_inputs.caller, caller_balance, None, None)?)
+            response_builder = response_builder.add_output(Output::new(
+                function_inputs.caller,
+                caller_balance,
+                None,
+                output_program_id,
+            )?)
         }
 
         // Add custom events to the response.
@@ -271,6 +306,62 @@ impl<N: Network> VirtualMachine<N> {
         response_builder.build(rng)
     }
 
+    /// Executes the request on the given program in the registry, returning a transaction.
+    /// The function and its verifying key are resolved from the registry by program ID.
+    ///
+    /// To call the function of another program, the caller executes an `Operation::Call`, which passes
+    /// its output record to the callee program. The callee request then consumes this record, using the
+    /// records and ledger proofs from `to_callee_inputs`, and is executed as the next transition.
+    pub fn execute_call<R: Rng + CryptoRng>(
+        self,
+        request: &Request<N>,
+        program_registry: &ProgramRegistry<N>,
+        program_id: N::ProgramID,
+        private_variables: &dyn ProgramPrivateVariables<N>,
+        custom_events: Vec<Vec<u8>>,
+        rng: &mut R,
+    ) -> Result<(Self, Response<N>)> {
+        let function_id = match request.function_id() {
+            Some(function_id) => function_id,
+            None => return Err(anyhow!("Virtual machine received a request without a function ID")),
+        };
+        let (function, function_path, verifying_key) = program_registry.to_function(&program_id, &function_id)?;
+
+        self.execute_program(
+            request,
+            program_id,
+            &function,
+            &function_path,
+            verifying_key,
+            private_variables,
+            custom_events,
+            rng,
+        )
+    }
+
+    /// Returns the output records of the given response that are passed to the callee program and owned
+    /// by the given address, along with the ledger proofs for consuming them in a subsequent transition
+    /// of this transaction.
+    pub fn to_callee_inputs(
+        &self,
+        response: &Response<N>,
+        callee_program_id: N::ProgramID,
+        owner: Address<N>,
+    ) -> Result<(Vec<Record<N>>, Vec<LedgerProof<N>>)> {
+        let records = response
+            .records()
+            .iter()
+            .filter(|record| record.program_id() == Some(callee_program_id) && record.owner() == owner)
+            .cloned()
+            .collect::<Vec<_>>();
+        let ledger_proofs = records
+            .iter()
+            .map(|record| LedgerProof::new_dummy(self.to_local_proof(record.commitment())?))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((records, ledger_proofs))
+    }
+
     // TODO (raychu86): Temporary solution. Handle execution elsewhere.
     /// Executes the request of a particular program execution and returns a transaction.
     pub fn execute_program<R: Rng + CryptoRng>(
@@ -289,10 +380,17 @@ impl<N: Network> VirtualMachine<N> {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
 
+        // Ensure the request records belong to the given program.
+        if let Some(record_program_id) = request.to_program_id()? {
+            if record_program_id != program_id {
+                return Err(anyhow!("Request records do not belong to program {}", program_id));
+            }
+        }
+
         // Compute the operation.
         let operation = request.operation().clone();
         let response = match operation {
-            Operation::Evaluate(function_id, function_inputs) => {
+            Operation::Evaluate(function_id, function_inputs) | Operation::Call(function_id, _, function_inputs) => {
                 self.evaluate(request, Some(program_id), &function_id, &function_inputs, custom_events, rng)?
             }
             _ => return Err(anyhow!("Invalid Operation")),
@@ -300,6 +398,10 @@ impl<N: Network> VirtualMachine<N> {
 
         let transition_id = response.transition_id();
 
+        // The output records of a call belong to the callee program.
+        let callee_program_id = request.operation().callee_program_id();
+        let output_program_id = callee_program_id.unwrap_or(program_id);
+
         // Compute the execution.
         let program_proof = function.execute(ProgramPublicVariables::new(transition_id), private_variables)?;
         let public_variables = ProgramPublicVariables::new(transition_id);
@@ -337,7 +439,7 @@ impl<N: Network> VirtualMachine<N> {
             )?;
 
             let input_circuit = InputCircuit::<N>::new(input_public.clone(), input_private);
-            let input_proof = N::InputSNARK::prove(N::input_proving_key(), &input_circuit, rng)?;
+            let input_proof = N::InputSNARK::prove(&N::input_proving_key(), &input_circuit, rng)?;
 
             assert!(N::InputSNARK::verify(N::input_verifying_key(), &input_public, &input_proof)?);
 
@@ -359,7 +461,7 @@ impl<N: Network> VirtualMachine<N> {
             .zip_eq(response.output_value_commitment_randomness())
         {
             let output_public =
-                OutputPublicVariables::<N>::new(commitment, output_value_commitment.clone(), Some(program_id));
+                OutputPublicVariables::<N>::new(commitment, output_value_commitment.clone(), Some(output_program_id));
             let output_private = OutputPrivateVariables::<N>::new(
                 record.clone(),
                 *encryption_randomness,
@@ -367,7 +469,7 @@ impl<N: Network> VirtualMachine<N> {
             )?;
 
             let output_circuit = OutputCircuit::<N>::new(output_public.clone(), output_private);
-            let output_proof = N::OutputSNARK::prove(N::output_proving_key(), &output_circuit, rng)?;
+            let output_proof = N::OutputSNARK::prove(&N::output_proving_key(), &output_circuit, rng)?;
 
             assert!(N::OutputSNARK::verify(N::output_verifying_key(), &output_public, &output_proof)?);
 
@@ -375,7 +477,13 @@ impl<N: Network> VirtualMachine<N> {
         }
 
         let execution = Execution::from(
-            Some(ProgramExecution::from(program_id, function_path.clone(), function_verifying_key, program_proof)?),
+            Some(ProgramExecution::from(
+                program_id,
+                callee_program_id,
+                function_path.clone(),
+                function_verifying_key,
+                program_proof,
+            )?),
             input_proofs,
             output_proofs,
         )?;
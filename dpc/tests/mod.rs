@@ -103,9 +103,9 @@ fn test_posw_setup_vs_load_weak_sanity_check() {
     let loaded_posw = Testnet2::posw().clone();
 
     let generated_proving_key: &CircuitProvingKey<Bls12_377, MarlinNonHidingMode> =
-        generated_posw.proving_key().as_ref().unwrap();
+        generated_posw.proving_key().unwrap();
     let loaded_proving_key: &CircuitProvingKey<Bls12_377, MarlinNonHidingMode> =
-        loaded_posw.proving_key().as_ref().unwrap();
+        loaded_posw.proving_key().unwrap();
 
     let a = generated_proving_key.committer_key.max_degree;
     let b = loaded_proving_key.committer_key.max_degree;
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Field;
+
+/// The maximum number of odd powers that an addition chain may precompute.
+const MAX_ODD_POWERS: usize = 16;
+
+/// An addition chain for a fixed exponent, as a sliding window over precomputed odd powers.
+///
+/// The chain starts from one, and each step squares the accumulator `squarings` times
+/// before multiplying it by the odd power `x^(2 * index + 1)`, for the step `(squarings, index)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdditionChain {
+    /// The number of odd powers `x, x^3, ..., x^(2 * num_odd_powers - 1)` used by the chain.
+    pub num_odd_powers: usize,
+    /// The steps of the chain, as `(squarings, index)` pairs.
+    pub steps: &'static [(u32, usize)],
+    /// The number of squarings after the last step.
+    pub final_squarings: u32,
+}
+
+impl AdditionChain {
+    /// Returns `base` raised to the exponent of the chain.
+    pub fn exponentiate<F: Field>(&self, base: &F) -> F {
+        assert!(self.num_odd_powers > 0 && self.num_odd_powers <= MAX_ODD_POWERS);
+
+        // Compute the odd powers `x, x^3, ..., x^(2 * num_odd_powers - 1)`.
+        let mut odd_powers = [F::zero(); MAX_ODD_POWERS];
+        odd_powers[0] = *base;
+        let square = base.square();
+        for i in 1..self.num_odd_powers {
+            odd_powers[i] = odd_powers[i - 1] * square;
+        }
+
+        let mut result = F::one();
+        for (squarings, index) in self.steps {
+            for _ in 0..*squarings {
+                result.square_in_place();
+            }
+            result *= &odd_powers[*index];
+        }
+        for _ in 0..self.final_squarings {
+            result.square_in_place();
+        }
+        result
+    }
+}
@@ -29,18 +29,29 @@ use crate::{
     SquareRootField,
     Zero,
 };
+#[cfg(feature = "simd")]
+use crate::simd;
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger256 as BigInteger},
     serialize::CanonicalDeserialize,
+    BitIteratorBE,
+    BitIteratorLE,
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    FromBits,
     FromBytes,
+    IterBits,
     ToBits,
     ToBytes,
+    Zeroize,
 };
 
 use std::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     io::{Read, Result as IoResult, Write},
+    iter::{Skip, Take},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
@@ -77,9 +88,11 @@ impl<P: Fp256Parameters> Fp256<P> {
 
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
-        }
+        // Subtract the modulus, and keep the difference if it did not borrow.
+        // Note: The difference is selected in constant time, so that secret values do not branch.
+        let mut reduced = self.0;
+        let borrow = reduced.sub_noborrow(&P::MODULUS);
+        self.0 = BigInteger::conditional_select(&reduced, &self.0, Choice::from(borrow as u8));
     }
 
     #[inline(always)]
@@ -295,6 +308,16 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         }
     }
 
+    #[inline]
+    fn inverse_fast(&self) -> Option<Self> {
+        match P::INVERSION_CHAIN {
+            // By Fermat's little theorem, self^(MODULUS - 2) = self^(-1).
+            Some(chain) if !self.is_zero() => Some(chain.exponentiate(self)),
+            Some(_) => None,
+            None => self.inverse(),
+        }
+    }
+
     fn inverse_in_place(&mut self) -> Option<&mut Self> {
         if let Some(inverse) = self.inverse() {
             *self = inverse;
@@ -308,6 +331,20 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
     fn frobenius_map(&mut self, _: usize) {
         // No-op: No effect in a prime field.
     }
+
+    #[cfg(feature = "simd")]
+    fn batch_mul_in_place(a: &mut [Self], b: &[Self]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.chunks_mut(simd::LANES).zip(b.chunks(simd::LANES)) {
+            // Any trailing lanes are left as zero, which multiply to zero.
+            let mut x = [[0u64; 4]; simd::LANES];
+            let mut y = [[0u64; 4]; simd::LANES];
+            x.iter_mut().zip(a.iter()).for_each(|(x, a)| *x = (a.0).0);
+            y.iter_mut().zip(b).for_each(|(y, b)| *y = (b.0).0);
+            simd::mul_assign::<simd::NativeLanes, 4>(&mut x, &y, &P::MODULUS.0, P::INV);
+            a.iter_mut().zip(x).for_each(|(a, x)| (a.0).0 = x);
+        }
+    }
 }
 
 impl<P: Fp256Parameters> PrimeField for Fp256<P> {
@@ -400,7 +437,10 @@ impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
         use crate::LegendreSymbol::*;
 
         // s = self^((MODULUS - 1) // 2)
-        let mut s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        let mut s = match P::LEGENDRE_CHAIN {
+            Some(chain) => chain.exponentiate(self),
+            None => self.pow(P::MODULUS_MINUS_ONE_DIV_TWO),
+        };
         s.reduce();
 
         if s.is_zero() {
@@ -443,17 +483,67 @@ impl_mul_div_from_field_ref!(Fp256, Fp256Parameters);
 
 impl<P: Fp256Parameters> ToBits for Fp256<P> {
     fn to_bits_le(&self) -> Vec<bool> {
-        let mut bits_vec = self.to_repr().to_bits_le();
-        bits_vec.truncate(P::MODULUS_BITS as usize);
-
-        bits_vec
+        self.iter_bits_le().collect()
     }
 
     fn to_bits_be(&self) -> Vec<bool> {
-        let mut bits_vec = self.to_bits_le();
-        bits_vec.reverse();
+        self.iter_bits_be().collect()
+    }
+}
+
+impl<P: Fp256Parameters> ConstantTimeEq for Fp256<P> {
+    /// Returns `1` if the field elements are equal, in time independent of their values.
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Field elements are always fully reduced, so their representations are unique.
+        self.0.ct_eq(&other.0)
+    }
+}
 
-        bits_vec
+impl<P: Fp256Parameters> ConditionallySelectable for Fp256<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(BigInteger::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<P: Fp256Parameters> Zeroize for Fp256<P> {
+    /// Overwrites the field element with zero.
+    #[inline]
+    fn zeroize(&mut self) {
+        // Note: Zero is represented by all-zero limbs in Montgomery form.
+        self.0.zeroize();
+    }
+}
+
+impl<P: Fp256Parameters> IterBits for Fp256<P> {
+    type IterBE = Skip<BitIteratorBE<BigInteger>>;
+    type IterLE = Take<BitIteratorLE<BigInteger>>;
+
+    /// Returns an iterator over the `MODULUS_BITS` bits of the canonical representation in little-endian order.
+    fn iter_bits_le(&self) -> Self::IterLE {
+        BitIteratorLE::new(self.to_repr()).take(P::MODULUS_BITS as usize)
+    }
+
+    /// Returns an iterator over the `MODULUS_BITS` bits of the canonical representation in big-endian order.
+    fn iter_bits_be(&self) -> Self::IterBE {
+        BitIteratorBE::new(self.to_repr()).skip(BigInteger::NUM_LIMBS * 64 - P::MODULUS_BITS as usize)
+    }
+}
+
+impl<P: Fp256Parameters> FromBits for Fp256<P> {
+    /// Returns the field element with the given little-endian bits,
+    /// or an error if the bits do not encode a canonical field element.
+    fn from_bits_le(bits: &[bool]) -> anyhow::Result<Self> {
+        Self::from_repr(BigInteger::from_bits_le(bits)?)
+            .ok_or_else(|| anyhow::anyhow!("The given bits do not encode a canonical field element"))
+    }
+
+    /// Returns the field element with the given big-endian bits,
+    /// or an error if the bits do not encode a canonical field element.
+    fn from_bits_be(bits: &[bool]) -> anyhow::Result<Self> {
+        Self::from_repr(BigInteger::from_bits_be(bits)?)
+            .ok_or_else(|| anyhow::anyhow!("The given bits do not encode a canonical field element"))
     }
 }
 
@@ -624,12 +714,12 @@ impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
-
-        self.0.sub_noborrow(&other.0);
+        // Subtract `other`, and add the modulus back if the subtraction borrowed.
+        // Note: The sum is selected in constant time, so that secret values do not branch.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = self.0;
+        corrected.add_nocarry(&P::MODULUS);
+        self.0 = BigInteger::conditional_select(&self.0, &corrected, Choice::from(borrow as u8));
     }
 }
 
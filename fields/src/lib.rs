@@ -15,7 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "simd"), forbid(unsafe_code))]
+#![cfg_attr(feature = "simd", deny(unsafe_code))]
 
 #[macro_use]
 extern crate derivative;
@@ -26,6 +27,9 @@ extern crate thiserror;
 #[macro_use]
 mod macros;
 
+mod addition_chain;
+pub use addition_chain::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -35,9 +39,17 @@ pub use fp_256::*;
 mod fp_384;
 pub use fp_384::*;
 
+mod fp_768;
+pub use fp_768::*;
+
 mod fp2;
 pub use fp2::*;
 
+mod fp3;
+pub use fp3::*;
+
+pub mod fp6_2over3;
+
 pub mod fp6_3over2;
 
 mod fp12_2over3over2;
@@ -46,6 +58,9 @@ pub use fp12_2over3over2::*;
 mod legendre;
 pub use legendre::*;
 
+#[cfg(feature = "simd")]
+mod simd;
+
 mod to_field_vec;
 pub use to_field_vec::*;
 
@@ -67,9 +82,11 @@ use snarkvm_utilities::{
 
 impl_field_into_biginteger!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_into_biginteger!(Fp384, BigInteger384, Fp384Parameters);
+impl_field_into_biginteger!(Fp768, BigInteger768, Fp768Parameters);
 
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
+impl_primefield_serializer!(Fp768, Fp768Parameters, 96);
 
 // Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
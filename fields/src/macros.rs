@@ -85,58 +85,68 @@ macro_rules! impl_primefield_from_int {
 
 macro_rules! sqrt_impl {
     ($Self:ident, $P:tt, $self:expr) => {{
-        use crate::LegendreSymbol::*;
         // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
         // Actually this is just normal Tonelli-Shanks; since `P::Generator`
         // is a quadratic non-residue, `P::ROOT_OF_UNITY = P::GENERATOR ^ t`
         // is also a quadratic non-residue (since `t` is odd).
-        match $self.legendre() {
-            Zero => Some(*$self),
-            QuadraticNonResidue => None,
-            QuadraticResidue => {
-                let mut z = $Self::two_adic_root_of_unity();
-                let mut w = $self.pow($P::T_MINUS_ONE_DIV_TWO);
-                let mut x = w * $self;
-                let mut b = x * w;
-
-                let mut v = $P::TWO_ADICITY as usize;
-                // t = self^t
-                #[cfg(debug_assertions)]
-                {
-                    let mut check = b;
-                    for _ in 0..(v - 1) {
-                        check.square_in_place();
-                    }
-                    if !check.is_one() {
-                        panic!("Input is not a square root, but it passed the QR test")
-                    }
+        //
+        // Non-residues are detected while computing the root, so no separate
+        // Legendre check is needed.
+        if $self.is_zero() {
+            return Some($Self::zero());
+        }
+
+        // w = self^((t - 1) / 2)
+        let w = match $P::SQRT_CHAIN {
+            Some(chain) => chain.exponentiate($self),
+            None => $self.pow($P::T_MINUS_ONE_DIV_TWO),
+        };
+        // x = self^((t + 1) / 2)
+        let mut x = w * $self;
+        // b = self^t
+        let mut b = x * w;
+
+        // If MODULUS = 3 mod 4, then x = self^((MODULUS + 1) / 4) is the square root,
+        // and b = self^((MODULUS - 1) / 2) is the Legendre symbol.
+        if $P::TWO_ADICITY == 1 {
+            return if b.is_one() { Some(x) } else { None };
+        }
+
+        // Returns z^(2^i) for the 2-adic root of unity z, from the precomputed table if available.
+        let root_of_unity_power = |i: usize| match $P::TWO_ADIC_ROOT_OF_UNITY_POWERS.get(i) {
+            Some(power) => $Self::new(*power),
+            None => {
+                let mut power = $Self::two_adic_root_of_unity();
+                for _ in 0..i {
+                    power.square_in_place();
                 }
-
-                while !b.is_one() {
-                    let mut k = 0usize;
-
-                    let mut b2k = b;
-                    while !b2k.is_one() {
-                        // invariant: b2k = b^(2^k) after entering this loop
-                        b2k.square_in_place();
-                        k += 1;
-                    }
-
-                    let j = v - k - 1;
-                    w = z;
-                    for _ in 0..j {
-                        w.square_in_place();
-                    }
-
-                    z = w.square();
-                    b *= &z;
-                    x *= &w;
-                    v = k;
+                power
+            }
+        };
+
+        let mut v = $P::TWO_ADICITY as usize;
+        while !b.is_one() {
+            // Find the least k such that b^(2^k) = 1.
+            let mut k = 0usize;
+            let mut b2k = b;
+            while !b2k.is_one() {
+                b2k.square_in_place();
+                k += 1;
+                // As b has order 2^v, self is a quadratic non-residue.
+                if k == v {
+                    return None;
                 }
-
-                Some(x)
             }
+
+            // In each iteration, the root of unity used by Tonelli-Shanks
+            // is w = z^(2^(TWO_ADICITY - k - 1)), for the 2-adic root of unity z.
+            let j = $P::TWO_ADICITY as usize - k - 1;
+            b *= &root_of_unity_power(j + 1);
+            x *= &root_of_unity_power(j);
+            v = k;
         }
+
+        Some(x)
     }};
 }
 
@@ -228,24 +238,55 @@ macro_rules! impl_primefield_serializer {
             }
         }
 
+        /// Human-readable formats encode the element as the `0x`-prefixed big-endian hex of its canonical
+        /// representation, and other formats as its compressed bytes.
+        ///
+        /// Note: human-readable formats previously encoded the element as a decimal string. This changes
+        /// the wire format of existing JSON, although decimal strings are still accepted when deserializing.
         impl<P: $params> serde::Serialize for $field<P> {
             fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
-                CanonicalSerialize::serialize(self, &mut bytes).map_err(serde::ser::Error::custom)?;
-
                 match serializer.is_human_readable() {
-                    true => serializer.collect_str(self),
-                    false => snarkvm_utilities::ToBytesSerializer::serialize(&bytes, serializer),
+                    true => {
+                        let repr = <$field<P> as crate::PrimeField>::to_repr(self);
+                        let mut bytes =
+                            snarkvm_utilities::ToBytes::to_bytes_le(&repr).map_err(serde::ser::Error::custom)?;
+                        bytes.reverse();
+                        serializer.serialize_str(&format!("0x{}", snarkvm_utilities::serialize::encode_hex(&bytes)))
+                    }
+                    false => {
+                        let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
+                        CanonicalSerialize::serialize(self, &mut bytes).map_err(serde::ser::Error::custom)?;
+                        snarkvm_utilities::ToBytesSerializer::serialize(&bytes, serializer)
+                    }
                 }
             }
         }
 
+        /// Human-readable formats accept the `0x`-prefixed big-endian hex of the canonical representation,
+        /// as well as the decimal string emitted by earlier releases.
         impl<'de, P: $params> serde::Deserialize<'de> for $field<P> {
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 match deserializer.is_human_readable() {
                     true => {
                         let s: String = serde::Deserialize::deserialize(deserializer)?;
-                        core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom)
+                        match s.strip_prefix("0x") {
+                            // Hex strings encode the big-endian canonical representation.
+                            Some(hex) => {
+                                let mut bytes =
+                                    snarkvm_utilities::serialize::decode_hex(hex).map_err(serde::de::Error::custom)?;
+                                if bytes.len() > $byte_size {
+                                    let expected = concat!("at most ", stringify!($byte_size), " bytes");
+                                    return Err(serde::de::Error::invalid_length(bytes.len(), &expected));
+                                }
+                                bytes.reverse();
+                                bytes.resize($byte_size, 0u8);
+                                let repr = snarkvm_utilities::FromBytes::read_le(&bytes[..])
+                                    .map_err(serde::de::Error::custom)?;
+                                <$field<P> as crate::PrimeField>::from_repr(repr)
+                                    .ok_or_else(|| serde::de::Error::custom("field element is not in canonical form"))
+                            }
+                            None => core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom),
+                        }
                     }
                     false => {
                         struct SerVisitor<P>(std::marker::PhantomData<P>);
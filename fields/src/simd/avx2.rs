@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(unsafe_code)]
+
+use super::{Lanes, LANES};
+
+use core::arch::x86_64::*;
+
+/// A vector of four 64-bit lanes in an AVX2 register.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Avx2Lanes(__m256i);
+
+// Safety: this module is only compiled when the `avx2` target feature is enabled,
+// so the intrinsics below are always available.
+impl Lanes for Avx2Lanes {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        Self(unsafe { _mm256_set1_epi64x(value as i64) })
+    }
+
+    #[inline(always)]
+    fn load(values: [u64; LANES]) -> Self {
+        Self(unsafe { _mm256_loadu_si256(values.as_ptr() as *const __m256i) })
+    }
+
+    #[inline(always)]
+    fn store(self) -> [u64; LANES] {
+        let mut values = [0u64; LANES];
+        unsafe { _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, self.0) };
+        values
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self(unsafe { _mm256_add_epi64(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn mul_lo(self, other: Self) -> Self {
+        Self(unsafe { _mm256_mul_epu32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn hi32(self) -> Self {
+        Self(unsafe { _mm256_srli_epi64::<32>(self.0) })
+    }
+
+    #[inline(always)]
+    fn lo32(self) -> Self {
+        Self(unsafe { _mm256_and_si256(self.0, _mm256_set1_epi64x(0xFFFF_FFFF)) })
+    }
+}
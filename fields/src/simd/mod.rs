@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A vectorized backend for Montgomery multiplication in prime fields.
+//!
+//! Each call multiplies four independent pairs of field elements at once. The elements are
+//! split into 32-bit digits, so every product fits in a 64-bit lane, and the digits are
+//! reduced with the CIOS method. The result is in the same Montgomery form as the scalar code,
+//! as `R = 2^(64 * N)` for `N` 64-bit limbs.
+//!
+//! The lane backend is selected at compile time: AVX2 on `x86_64` when built with
+//! `target_feature = "avx2"`, NEON on `aarch64`, and a portable implementation otherwise.
+
+mod portable;
+pub(crate) use portable::PortableLanes;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod avx2;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon;
+
+#[cfg(test)]
+mod tests;
+
+/// The lane backend used for field multiplication on this target.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub(crate) type NativeLanes = avx2::Avx2Lanes;
+
+/// The lane backend used for field multiplication on this target.
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub(crate) type NativeLanes = neon::NeonLanes;
+
+/// The lane backend used for field multiplication on this target.
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+pub(crate) type NativeLanes = PortableLanes;
+
+/// The number of field elements multiplied at once.
+pub(crate) const LANES: usize = 4;
+
+/// The maximum number of 32-bit digits in a field element, i.e. 384 bits.
+const MAX_DIGITS: usize = 12;
+
+/// A vector of four 64-bit lanes.
+pub(crate) trait Lanes: Copy {
+    /// Returns a vector with `value` in every lane.
+    fn splat(value: u64) -> Self;
+
+    /// Loads the given lanes into a vector.
+    fn load(values: [u64; LANES]) -> Self;
+
+    /// Returns the lanes of the vector.
+    fn store(self) -> [u64; LANES];
+
+    /// Returns the lane-wise wrapping sum of `self` and `other`.
+    fn add(self, other: Self) -> Self;
+
+    /// Returns the lane-wise 64-bit product of the low 32 bits of `self` and `other`.
+    fn mul_lo(self, other: Self) -> Self;
+
+    /// Returns the high 32 bits of each lane.
+    fn hi32(self) -> Self;
+
+    /// Returns the low 32 bits of each lane.
+    fn lo32(self) -> Self;
+}
+
+/// Sets `a[i] *= b[i]` for each lane, where `a` and `b` are in Montgomery form with `N` limbs.
+/// `inv` is the scalar `-modulus^{-1} mod 2^64`, i.e. the `INV` of the field parameters.
+#[inline]
+pub(crate) fn mul_assign<L: Lanes, const N: usize>(
+    a: &mut [[u64; N]; LANES],
+    b: &[[u64; N]; LANES],
+    modulus: &[u64; N],
+    inv: u64,
+) {
+    let num_digits = 2 * N;
+    assert!(num_digits <= MAX_DIGITS);
+
+    let a_digits = to_digits::<L, N>(a);
+    let b_digits = to_digits::<L, N>(b);
+    let p_digits: [L; MAX_DIGITS] = {
+        let mut digits = [L::splat(0); MAX_DIGITS];
+        for (j, digit) in digits.iter_mut().take(num_digits).enumerate() {
+            *digit = L::splat((modulus[j / 2] >> (32 * (j % 2))) & 0xFFFF_FFFF);
+        }
+        digits
+    };
+    let k = L::splat(inv & 0xFFFF_FFFF);
+
+    // Every intermediate value is bounded by `2^32 - 1 + (2^32 - 1)^2 + 2^32 - 1 = 2^64 - 1`,
+    // so the accumulation never overflows a lane.
+    let mut t = [L::splat(0); MAX_DIGITS + 2];
+    for b_digit in b_digits.iter().take(num_digits) {
+        let mut carry = L::splat(0);
+        for j in 0..num_digits {
+            let sum = t[j].add(a_digits[j].mul_lo(*b_digit)).add(carry);
+            t[j] = sum.lo32();
+            carry = sum.hi32();
+        }
+        let sum = t[num_digits].add(carry);
+        t[num_digits] = sum.lo32();
+        t[num_digits + 1] = sum.hi32();
+
+        let m = t[0].mul_lo(k).lo32();
+        let mut carry = t[0].add(m.mul_lo(p_digits[0])).hi32();
+        for j in 1..num_digits {
+            let sum = t[j].add(m.mul_lo(p_digits[j])).add(carry);
+            t[j - 1] = sum.lo32();
+            carry = sum.hi32();
+        }
+        let sum = t[num_digits].add(carry);
+        t[num_digits - 1] = sum.lo32();
+        t[num_digits] = t[num_digits + 1].add(sum.hi32());
+    }
+
+    let t = t.map(L::store);
+    for (lane, a) in a.iter_mut().enumerate() {
+        for (l, limb) in a.iter_mut().enumerate() {
+            *limb = t[2 * l][lane] | (t[2 * l + 1][lane] << 32);
+        }
+        // The result is less than twice the modulus, so one subtraction suffices.
+        if t[num_digits][lane] != 0 || !is_less_than(a, modulus) {
+            subtract(a, modulus);
+        }
+    }
+}
+
+/// Splits the lanes of `values` into 32-bit digits, least significant first.
+#[inline]
+fn to_digits<L: Lanes, const N: usize>(values: &[[u64; N]; LANES]) -> [L; MAX_DIGITS] {
+    let mut digits = [L::splat(0); MAX_DIGITS];
+    for (j, digit) in digits.iter_mut().take(2 * N).enumerate() {
+        *digit = L::load(values.map(|value| (value[j / 2] >> (32 * (j % 2))) & 0xFFFF_FFFF));
+    }
+    digits
+}
+
+/// Returns `true` if `a < b`.
+#[inline]
+fn is_less_than<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_lt()
+}
+
+/// Sets `a -= b`, wrapping around `2^(64 * N)`.
+#[inline]
+fn subtract<const N: usize>(a: &mut [u64; N], b: &[u64; N]) {
+    let mut borrow = false;
+    for (a, b) in a.iter_mut().zip(b) {
+        let (difference, borrow_1) = a.overflowing_sub(*b);
+        let (difference, borrow_2) = difference.overflowing_sub(borrow as u64);
+        *a = difference;
+        borrow = borrow_1 | borrow_2;
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(unsafe_code)]
+
+use super::{Lanes, LANES};
+
+use core::arch::aarch64::*;
+
+/// A vector of four 64-bit lanes in a pair of NEON registers.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct NeonLanes(uint64x2_t, uint64x2_t);
+
+// Safety: this module is only compiled when the `neon` target feature is enabled,
+// so the intrinsics below are always available.
+impl Lanes for NeonLanes {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        unsafe { Self(vdupq_n_u64(value), vdupq_n_u64(value)) }
+    }
+
+    #[inline(always)]
+    fn load(values: [u64; LANES]) -> Self {
+        unsafe { Self(vld1q_u64(values.as_ptr()), vld1q_u64(values[2..].as_ptr())) }
+    }
+
+    #[inline(always)]
+    fn store(self) -> [u64; LANES] {
+        let mut values = [0u64; LANES];
+        unsafe {
+            vst1q_u64(values.as_mut_ptr(), self.0);
+            vst1q_u64(values[2..].as_mut_ptr(), self.1);
+        }
+        values
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(vaddq_u64(self.0, other.0), vaddq_u64(self.1, other.1)) }
+    }
+
+    #[inline(always)]
+    fn mul_lo(self, other: Self) -> Self {
+        unsafe {
+            Self(vmull_u32(vmovn_u64(self.0), vmovn_u64(other.0)), vmull_u32(vmovn_u64(self.1), vmovn_u64(other.1)))
+        }
+    }
+
+    #[inline(always)]
+    fn hi32(self) -> Self {
+        unsafe { Self(vshrq_n_u64::<32>(self.0), vshrq_n_u64::<32>(self.1)) }
+    }
+
+    #[inline(always)]
+    fn lo32(self) -> Self {
+        unsafe {
+            let mask = vdupq_n_u64(0xFFFF_FFFF);
+            Self(vandq_u64(self.0, mask), vandq_u64(self.1, mask))
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Lanes, LANES};
+
+/// A portable vector of four 64-bit lanes, for targets without a vectorized backend.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PortableLanes([u64; LANES]);
+
+impl Lanes for PortableLanes {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        Self([value; LANES])
+    }
+
+    #[inline(always)]
+    fn load(values: [u64; LANES]) -> Self {
+        Self(values)
+    }
+
+    #[inline(always)]
+    fn store(self) -> [u64; LANES] {
+        self.0
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self([
+            self.0[0].wrapping_add(other.0[0]),
+            self.0[1].wrapping_add(other.0[1]),
+            self.0[2].wrapping_add(other.0[2]),
+            self.0[3].wrapping_add(other.0[3]),
+        ])
+    }
+
+    #[inline(always)]
+    fn mul_lo(self, other: Self) -> Self {
+        Self([
+            (self.0[0] & 0xFFFF_FFFF) * (other.0[0] & 0xFFFF_FFFF),
+            (self.0[1] & 0xFFFF_FFFF) * (other.0[1] & 0xFFFF_FFFF),
+            (self.0[2] & 0xFFFF_FFFF) * (other.0[2] & 0xFFFF_FFFF),
+            (self.0[3] & 0xFFFF_FFFF) * (other.0[3] & 0xFFFF_FFFF),
+        ])
+    }
+
+    #[inline(always)]
+    fn hi32(self) -> Self {
+        Self(self.0.map(|lane| lane >> 32))
+    }
+
+    #[inline(always)]
+    fn lo32(self) -> Self {
+        Self(self.0.map(|lane| lane & 0xFFFF_FFFF))
+    }
+}
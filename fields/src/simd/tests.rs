@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::rand::test_rng;
+
+use rand::Rng;
+
+const ITERATIONS: usize = 1000;
+
+/// The BLS12-377 scalar field modulus and its `INV`.
+const MODULUS_256: ([u64; 4], u64) =
+    ([725501752471715841, 6461107452199829505, 6968279316240510977, 1345280370688173398], 725501752471715839);
+
+/// The secp256k1 base field modulus and its `INV`, which uses every bit of the top limb.
+const FULL_MODULUS_256: ([u64; 4], u64) =
+    ([0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff], 15580212934572586289);
+
+/// The BLS12-377 base field modulus and its `INV`.
+const MODULUS_384: ([u64; 6], u64) = (
+    [
+        0x8508c00000000001,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x1ae3a4617c510ea,
+    ],
+    9586122913090633727,
+);
+
+/// Returns `a * b * 2^(-64 * N) mod modulus`, computed with 64-bit limbs one element at a time.
+fn scalar_mul<const N: usize>(a: &[u64; N], b: &[u64; N], modulus: &[u64; N], inv: u64) -> [u64; N] {
+    let mut t = vec![0u64; N + 2];
+    for b_limb in b {
+        let mut carry = 0u128;
+        for j in 0..N {
+            let sum = t[j] as u128 + a[j] as u128 * *b_limb as u128 + carry;
+            t[j] = sum as u64;
+            carry = sum >> 64;
+        }
+        let sum = t[N] as u128 + carry;
+        t[N] = sum as u64;
+        t[N + 1] = (sum >> 64) as u64;
+
+        let m = t[0].wrapping_mul(inv);
+        let mut carry = (t[0] as u128 + m as u128 * modulus[0] as u128) >> 64;
+        for j in 1..N {
+            let sum = t[j] as u128 + m as u128 * modulus[j] as u128 + carry;
+            t[j - 1] = sum as u64;
+            carry = sum >> 64;
+        }
+        let sum = t[N] as u128 + carry;
+        t[N - 1] = sum as u64;
+        t[N] = t[N + 1] + (sum >> 64) as u64;
+    }
+
+    let mut result = [0u64; N];
+    result.copy_from_slice(&t[..N]);
+    if t[N] != 0 || !is_less_than(&result, modulus) {
+        subtract(&mut result, modulus);
+    }
+    result
+}
+
+/// Samples an element below the modulus.
+fn sample<R: Rng, const N: usize>(rng: &mut R, modulus: &[u64; N]) -> [u64; N] {
+    let mut value = [0u64; N];
+    value.iter_mut().for_each(|limb| *limb = rng.gen());
+    value[N - 1] %= modulus[N - 1];
+    value
+}
+
+/// Checks the given lane backend against the scalar multiplication.
+fn lanes_test<L: Lanes, const N: usize>((modulus, inv): ([u64; N], u64)) {
+    let mut rng = test_rng();
+
+    // The largest element is checked first, as it maximizes every intermediate value.
+    let mut largest = modulus;
+    largest[0] -= 1;
+
+    for i in 0..ITERATIONS {
+        let mut a = [(); LANES].map(|_| sample(&mut rng, &modulus));
+        let b = [(); LANES].map(|_| sample(&mut rng, &modulus));
+        if i == 0 {
+            a[0] = largest;
+        }
+
+        let expected: Vec<_> = a.iter().zip(&b).map(|(a, b)| scalar_mul(a, b, &modulus, inv)).collect();
+        mul_assign::<L, N>(&mut a, &b, &modulus, inv);
+        assert_eq!(a.to_vec(), expected);
+    }
+}
+
+#[test]
+fn test_portable_lanes() {
+    lanes_test::<PortableLanes, 4>(MODULUS_256);
+    lanes_test::<PortableLanes, 4>(FULL_MODULUS_256);
+    lanes_test::<PortableLanes, 6>(MODULUS_384);
+}
+
+#[test]
+fn test_native_lanes() {
+    lanes_test::<NativeLanes, 4>(MODULUS_256);
+    lanes_test::<NativeLanes, 4>(FULL_MODULUS_256);
+    lanes_test::<NativeLanes, 6>(MODULUS_384);
+}
+
+#[test]
+fn test_native_lanes_match_portable_lanes() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let values: [u64; LANES] = rng.gen();
+        let others: [u64; LANES] = rng.gen();
+        let (native, portable) = (NativeLanes::load(values), PortableLanes::load(values));
+        let (native_other, portable_other) = (NativeLanes::load(others), PortableLanes::load(others));
+
+        assert_eq!(native.store(), portable.store());
+        assert_eq!(native.add(native_other).store(), portable.add(portable_other).store());
+        assert_eq!(native.mul_lo(native_other).store(), portable.mul_lo(portable_other).store());
+        assert_eq!(native.hi32().store(), portable.hi32().store());
+        assert_eq!(native.lo32().store(), portable.lo32().store());
+        assert_eq!(NativeLanes::splat(values[0]).store(), PortableLanes::splat(values[0]).store());
+    }
+}
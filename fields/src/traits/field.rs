@@ -129,6 +129,23 @@ pub trait Field:
     /// Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// using an addition chain for the modulus where one is available.
+    /// The chain performs the same operations for every nonzero input, but is slower than the
+    /// binary extended Euclidean algorithm of `inverse` (see the `curves` benchmarks),
+    /// so callers opt in explicitly.
+    #[must_use]
+    fn inverse_fast(&self) -> Option<Self> {
+        self.inverse()
+    }
+
+    /// Sets `a[i] *= b[i]` for each `i`. Prime fields override this to vectorize
+    /// the multiplications when the `simd` feature is enabled.
+    fn batch_mul_in_place(a: &mut [Self], b: &[Self]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut().zip(b).for_each(|(a, b)| *a *= b);
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
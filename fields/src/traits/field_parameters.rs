@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::{FftParameters, PoseidonDefaultParameters};
+use crate::{
+    traits::{FftParameters, PoseidonDefaultParameters},
+    AdditionChain,
+};
 
 /// A trait that defines parameters for a prime field.
 pub trait FieldParameters: 'static + FftParameters + PoseidonDefaultParameters {
@@ -56,4 +59,17 @@ pub trait FieldParameters: 'static + FftParameters + PoseidonDefaultParameters {
 
     /// (Self::MODULUS - 1) / 2
     const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInteger;
+
+    /// An addition chain for the exponent `Self::MODULUS - 2`, used for inversion.
+    const INVERSION_CHAIN: Option<AdditionChain> = None;
+
+    /// An addition chain for the exponent `Self::MODULUS_MINUS_ONE_DIV_TWO`, used for the Legendre symbol.
+    const LEGENDRE_CHAIN: Option<AdditionChain> = None;
+
+    /// An addition chain for the exponent `Self::T_MINUS_ONE_DIV_TWO`, used for square roots.
+    const SQRT_CHAIN: Option<AdditionChain> = None;
+
+    /// The powers `TWO_ADIC_ROOT_OF_UNITY^(2^i)` for `i` in `0..TWO_ADICITY`, in Montgomery form,
+    /// used for square roots. If empty, the powers are computed as needed.
+    const TWO_ADIC_ROOT_OF_UNITY_POWERS: &'static [Self::BigInteger] = &[];
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{PoseidonGrainLFSR, PrimeField};
+use crate::{FieldParameters, PoseidonGrainLFSR, PrimeField};
 
 /// Parameters and RNG used
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -42,60 +42,24 @@ pub trait PoseidonDefaultField {
     where
         Self: PrimeField,
     {
-        /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
-            full_rounds: u64,
-            partial_rounds: u64,
-            skip_matrices: u64,
-        ) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
-
-            let mut ark = Vec::<Vec<F>>::new();
-            for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1));
-            }
-
-            let mut mds = vec![vec![F::zero(); RATE + 1]; RATE + 1];
-            for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1));
-            }
-
-            // a qualifying matrix must satisfy the following requirements
-            // - there is no duplication among the elements in x or y
-            // - there is no i and j such that x[i] + y[j] = p
-            // - the resultant MDS passes all the three tests
-
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
-
-            for (i, x) in xs.iter().enumerate().take(RATE + 1) {
-                for (j, y) in ys.iter().enumerate().take(RATE + 1) {
-                    mds[i][j] = (*x + y).inverse().unwrap();
-                }
-            }
-
-            (ark, mds)
-        }
-
         let default_entries = match optimized_for_weights {
             true => Self::Parameters::PARAMS_OPT_FOR_WEIGHTS,
             false => Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS,
         };
 
-        default_entries.iter().find(|entry| entry.rate == RATE).map(|entry| {
+        default_entries.iter().find(|entry| entry.rate == RATE).and_then(|entry| {
             let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
                 entry.full_rounds as u64,
                 entry.partial_rounds as u64,
                 entry.skip_matrices as u64,
-            );
-            PoseidonParameters {
+            )?;
+            Some(PoseidonParameters {
                 full_rounds: entry.full_rounds,
                 partial_rounds: entry.partial_rounds,
                 alpha: entry.alpha as u64,
                 ark,
                 mds,
-            }
+            })
         })
     }
 }
@@ -145,3 +109,157 @@ impl PoseidonDefaultParametersEntry {
         Self { rate, alpha, full_rounds, partial_rounds, skip_matrices }
     }
 }
+
+/// The security level, in bits, targeted by `PoseidonParameters::generate`.
+pub const POSEIDON_SECURITY_LEVEL: u64 = 128;
+
+impl<F: PrimeField, const RATE: usize> PoseidonParameters<F, RATE, 1> {
+    ///
+    /// Returns the Poseidon parameters for this rate and the given S-box exponent, targeting
+    /// `POSEIDON_SECURITY_LEVEL` bits of security, or `None` if `x^alpha` is not a permutation of the field.
+    ///
+    /// The round numbers are found with `find_poseidon_round_numbers`. The round constants are sampled
+    /// from the Grain LFSR, and the MDS matrix is the first Cauchy matrix sampled from the LFSR that is well-formed,
+    /// as in the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash).
+    ///
+    pub fn generate(alpha: u64) -> Option<Self> {
+        // Ensure the rate is nonzero, and `x^alpha` is a permutation of the field.
+        if RATE == 0 || !is_valid_poseidon_alpha::<F>(alpha) {
+            return None;
+        }
+
+        let state_len = (RATE + 1) as u64;
+        let (full_rounds, partial_rounds) =
+            find_poseidon_round_numbers(F::size_in_bits() as u64, state_len, alpha, POSEIDON_SECURITY_LEVEL)?;
+
+        // Skip any matrices that are not well-formed.
+        let (ark, mds) = (0..).find_map(|skip_matrices| {
+            find_poseidon_ark_and_mds::<F, RATE>(full_rounds as u64, partial_rounds as u64, skip_matrices)
+        })?;
+
+        Some(Self { full_rounds, partial_rounds, alpha, ark, mds })
+    }
+}
+
+///
+/// Returns the number of full and partial rounds for a Poseidon permutation over a field of the given size,
+/// with the given state length and S-box exponent `x^alpha`, for the given security level in bits.
+///
+/// This is the secure choice with the fewest S-boxes, following the round number calculation in the
+/// [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash) of
+/// [GKRRS19](https://eprint.iacr.org/2019/458), including its security margin
+/// of 2 full rounds and 7.5% more partial rounds.
+///
+pub fn find_poseidon_round_numbers(
+    field_size_in_bits: u64,
+    state_len: u64,
+    alpha: u64,
+    security_level: u64,
+) -> Option<(usize, usize)> {
+    let (n, t, a, m) = (field_size_in_bits as f64, state_len as f64, alpha as f64, security_level as f64);
+    let log_alpha = |x: f64| x.ln() / a.ln();
+
+    // Returns `true` if the given round numbers resist the known attacks.
+    let is_secure = |full_rounds: u64, partial_rounds: u64| {
+        let r_p = partial_rounds as f64;
+        // Statistical attacks.
+        let r_f_1 = if m <= (n - (a - 1.0) / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+        // Interpolation attacks.
+        let r_f_2 = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - r_p;
+        // Gröbner basis attacks.
+        let r_f_3 = log_alpha(2.0) * m.min(n) - r_p;
+        let r_f_4 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(n / 2.0) - r_p;
+        let r_f_5 = (t - 2.0 + m / (2.0 * (a.ln() / 2f64.ln())) - r_p) / (t - 1.0);
+
+        let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5].iter().fold(f64::MIN, |max, r_f| max.max(r_f.ceil()));
+        full_rounds as f64 >= r_f_max
+    };
+
+    let mut best: Option<(u64, u64, u64)> = None;
+    for partial_rounds in 1..500 {
+        // Find the fewest secure full rounds for this number of partial rounds.
+        if let Some(full_rounds) = (4..100).step_by(2).find(|full_rounds| is_secure(*full_rounds, partial_rounds)) {
+            // Add the security margin.
+            let full_rounds = full_rounds + 2;
+            let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as u64;
+
+            // Select the round numbers with the fewest S-boxes, preferring fewer full rounds.
+            let cost = state_len * full_rounds + partial_rounds;
+            if best.map_or(true, |(best_cost, best_full_rounds, _)| {
+                cost < best_cost || (cost == best_cost && full_rounds < best_full_rounds)
+            }) {
+                best = Some((cost, full_rounds, partial_rounds));
+            }
+        }
+    }
+    best.map(|(_, full_rounds, partial_rounds)| (full_rounds as usize, partial_rounds as usize))
+}
+
+/// Returns `true` if `x^alpha` is a permutation of the field, i.e. `alpha > 1` and `gcd(alpha, p - 1) = 1`.
+pub fn is_valid_poseidon_alpha<F: PrimeField>(alpha: u64) -> bool {
+    if alpha < 2 {
+        return false;
+    }
+
+    // Compute `(p - 1) mod alpha`.
+    let alpha = alpha as u128;
+    let modulus = F::Parameters::MODULUS;
+    let remainder =
+        modulus.as_ref().iter().rev().fold(0u128, |remainder, limb| ((remainder << 64) + *limb as u128) % alpha);
+    let remainder = (remainder + alpha - 1) % alpha;
+
+    // Compute `gcd(alpha, (p - 1) mod alpha)`, which equals `gcd(alpha, p - 1)`.
+    let (mut a, mut b) = (alpha, remainder);
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a == 1
+}
+
+/// Returns the round constants and the MDS matrix sampled from the Poseidon Grain LFSR,
+/// or `None` if the sampled Cauchy matrix is not well-formed.
+#[allow(clippy::type_complexity)]
+fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
+    full_rounds: u64,
+    partial_rounds: u64,
+    skip_matrices: u64,
+) -> Option<(Vec<Vec<F>>, Vec<Vec<F>>)> {
+    let mut lfsr =
+        PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
+
+    let mut ark = Vec::<Vec<F>>::new();
+    for _ in 0..(full_rounds + partial_rounds) {
+        ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1));
+    }
+
+    let mut mds = vec![vec![F::zero(); RATE + 1]; RATE + 1];
+    for _ in 0..skip_matrices {
+        let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1));
+    }
+
+    // a qualifying matrix must satisfy the following requirements
+    // - there is no duplication among the elements in x or y
+    // - there is no i and j such that x[i] + y[j] = p
+    // - the resultant MDS passes all the three tests
+    //
+    // Note: Only the first two requirements are checked here.
+
+    let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
+    let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
+
+    let has_duplicates = |elements: &[F]| elements.iter().enumerate().any(|(i, a)| elements[..i].contains(a));
+    if has_duplicates(&xs) || has_duplicates(&ys) {
+        return None;
+    }
+
+    for (i, x) in xs.iter().enumerate().take(RATE + 1) {
+        for (j, y) in ys.iter().enumerate().take(RATE + 1) {
+            mds[i][j] = (*x + y).inverse()?;
+        }
+    }
+
+    Some((ark, mds))
+}
+
@@ -107,7 +107,7 @@ impl PoseidonGrainLFSR {
                 let bits = self.get_bits(self.prime_num_bits as usize);
 
                 // Construct the number
-                let bigint = F::BigInteger::from_bits_be(&bits);
+                let bigint = F::BigInteger::from_bits_be(&bits).unwrap();
 
                 if bigint.cmp(&F::Parameters::MODULUS) == Ordering::Less {
                     res.push(F::from_repr(bigint).unwrap());
@@ -15,11 +15,27 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{FftField, FieldError, FieldParameters, PoseidonDefaultField};
-use snarkvm_utilities::{biginteger::BigInteger, cmp::min, str::FromStr};
+use snarkvm_utilities::{
+    biginteger::BigInteger,
+    cmp::min,
+    str::FromStr,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    FromBits,
+    IterBits,
+    Zeroize,
+};
 
 /// The interface for a prime field.
 pub trait PrimeField:
-    FftField<FftParameters = <Self as PrimeField>::Parameters> + PoseidonDefaultField + FromStr<Err = FieldError>
+    FftField<FftParameters = <Self as PrimeField>::Parameters>
+    + PoseidonDefaultField
+    + FromBits
+    + IterBits
+    + ConstantTimeEq
+    + ConditionallySelectable
+    + Zeroize
+    + FromStr<Err = FieldError>
 {
     type Parameters: FieldParameters<BigInteger = Self::BigInteger>;
     type BigInteger: BigInteger;
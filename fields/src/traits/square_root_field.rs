@@ -25,6 +25,13 @@ pub trait SquareRootField: Field {
     #[must_use]
     fn sqrt(&self) -> Option<Self>;
 
+    /// Returns the square root of self, if it exists. Prime fields detect non-residues
+    /// while computing the root, instead of with a separate Legendre check.
+    #[must_use]
+    fn sqrt_if_square(&self) -> Option<Self> {
+        self.sqrt()
+    }
+
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
 }
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, collections::BTreeMap};
+
+use snarkvm_algorithms::{merkle_tree::MerkleBatchPath, traits::MerkleParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::merkle_tree::merkle_path::hash_inner_node_gadget,
+    bits::ToBytesGadget,
+    traits::{algorithms::CRHGadget, alloc::AllocGadget},
+    EqGadget,
+};
+
+pub struct MerkleBatchPathGadget<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> {
+    /// `leaf_indices[i]` is the index of the ith leaf, which is fixed in the circuit.
+    leaf_indices: Vec<u64>,
+    /// `siblings` are the entries of the siblings not computed from the leaves, from bottom to top.
+    siblings: Vec<HG::OutputGadget>,
+}
+
+impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> MerkleBatchPathGadget<P, HG, F> {
+    pub fn calculate_root<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        leaves: &[impl ToBytesGadget<F>],
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        if leaves.is_empty() || leaves.len() != self.leaf_indices.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut known = BTreeMap::new();
+        for (i, (index, leaf)) in self.leaf_indices.iter().zip(leaves).enumerate() {
+            let leaf_bytes = leaf.to_bytes(&mut cs.ns(|| format!("leaf_to_bytes_{}", i)))?;
+            let leaf_hash = crh.check_evaluation_gadget(cs.ns(|| format!("leaf_hash_{}", i)), leaf_bytes)?;
+            if known.insert(*index, leaf_hash).is_some() {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+
+        // The traversal is determined by the leaf indices, which are constants,
+        // so each node is placed on the left or right without a conditional select.
+        let mut siblings = self.siblings.iter();
+        for level in 0..P::DEPTH {
+            let mut parents = BTreeMap::new();
+            for (&position, hash) in &known {
+                // A right child is hashed together with its left sibling, if the left sibling is known.
+                if position & 1 == 1 && known.contains_key(&(position - 1)) {
+                    continue;
+                }
+
+                let sibling =
+                    known.get(&(position ^ 1)).or_else(|| siblings.next()).ok_or(SynthesisError::Unsatisfiable)?;
+                let (left, right) = if position & 1 == 0 { (hash, sibling) } else { (sibling, hash) };
+
+                let parent = hash_inner_node_gadget::<P::H, HG, F, _>(
+                    &mut cs.ns(|| format!("hash_inner_node_{}_{}", level, position)),
+                    crh,
+                    left,
+                    right,
+                )?;
+                parents.insert(position >> 1, parent);
+            }
+            known = parents;
+        }
+
+        match (siblings.next(), known.remove(&0)) {
+            (None, Some(root)) => Ok(root),
+            _ => Err(SynthesisError::Unsatisfiable),
+        }
+    }
+
+    pub fn check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        leaves: &[impl ToBytesGadget<F>],
+    ) -> Result<(), SynthesisError> {
+        let expected_root = self.calculate_root(cs.ns(|| "calculate_root"), crh, leaves)?;
+
+        root.enforce_equal(&mut cs.ns(|| "root_is_eq"), &expected_root)
+    }
+}
+
+impl<P, HGadget, F> AllocGadget<MerkleBatchPath<P>, F> for MerkleBatchPathGadget<P, HGadget, F>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: PrimeField,
+{
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<MerkleBatchPath<P>>,
+    {
+        let batch_path = value_gen()?.borrow().clone();
+
+        let mut siblings = Vec::with_capacity(batch_path.siblings.len());
+        for (i, node) in batch_path.siblings.iter().enumerate() {
+            siblings.push(HGadget::OutputGadget::alloc(&mut cs.ns(|| format!("alloc_node_{}", i)), || Ok(*node))?);
+        }
+
+        Ok(MerkleBatchPathGadget { leaf_indices: batch_path.leaf_indices, siblings })
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<MerkleBatchPath<P>>,
+    {
+        let batch_path = value_gen()?.borrow().clone();
+
+        let mut siblings = Vec::with_capacity(batch_path.siblings.len());
+        for (i, node) in batch_path.siblings.iter().enumerate() {
+            siblings
+                .push(HGadget::OutputGadget::alloc_input(&mut cs.ns(|| format!("alloc_input_node_{}", i)), || {
+                    Ok(*node)
+                })?);
+        }
+
+        Ok(MerkleBatchPathGadget { leaf_indices: batch_path.leaf_indices, siblings })
+    }
+}
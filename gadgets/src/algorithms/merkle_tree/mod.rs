@@ -17,6 +17,9 @@
 pub mod masked_tree;
 pub use masked_tree::*;
 
+pub mod merkle_batch_path;
+pub use merkle_batch_path::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
@@ -21,7 +21,7 @@ use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
     crh::{PedersenCRH, PedersenCompressedCRH, BHPCRH},
-    merkle_tree::{MaskedMerkleTreeParameters, MerkleTree},
+    merkle_tree::{MaskedMerkleTreeParameters, MerkleTree, MerkleVectorCommitment},
     traits::{MaskedMerkleParameters, MerkleParameters, CRH},
 };
 use snarkvm_curves::{
@@ -216,6 +216,37 @@ fn update_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>
     assert!(satisfied);
 }
 
+fn batch_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>>(leaves: &[[u8; 30]]) {
+    let merkle_parameters = Arc::new(P::setup("merkle_tree_test"));
+    let commitment = MerkleVectorCommitment::<P, _>::commit(merkle_parameters.clone(), leaves.to_vec()).unwrap();
+
+    let indices = [5, 0, 1, leaves.len() - 1];
+    let batch_path = commitment.batch_open(&indices).unwrap();
+    let opened = indices.iter().map(|i| leaves[*i]).collect::<Vec<_>>();
+    assert!(batch_path.verify(commitment.root(), &opened).unwrap());
+
+    for (use_bad_leaf, expected) in [(false, true), (true, false)] {
+        let mut cs = TestConstraintSystem::<F>::new();
+
+        let crh = HG::alloc_constant(&mut cs.ns(|| "crh"), || Ok(merkle_parameters.crh())).unwrap();
+        let root =
+            <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(commitment.root())).unwrap();
+        let path = MerkleBatchPathGadget::<_, HG, _>::alloc(&mut cs.ns(|| "path"), || Ok(&batch_path)).unwrap();
+
+        let leaf_gadgets = opened
+            .iter()
+            .enumerate()
+            .map(|(i, leaf)| {
+                let leaf = if use_bad_leaf && i == 0 { [u8::MAX; 30] } else { *leaf };
+                UInt8::alloc_vec(cs.ns(|| format!("alloc_leaf_{}", i)), &leaf).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        path.check_membership(cs.ns(|| "check_membership"), &crh, &root, &leaf_gadgets).unwrap();
+        assert_eq!(expected, cs.is_satisfied());
+    }
+}
+
 mod merkle_tree_pedersen_crh {
     use super::*;
 
@@ -263,6 +294,19 @@ mod merkle_tree_pedersen_crh {
         }
         update_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves);
     }
+
+    #[test]
+    fn batch_merkle_tree_test() {
+        let mut rng = thread_rng();
+        let mut leaves = Vec::new();
+
+        for _ in 0..1 << EdwardsMerkleParameters::DEPTH {
+            let mut input = [0u8; 30];
+            rng.fill(&mut input);
+            leaves.push(input);
+        }
+        batch_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves);
+    }
 }
 
 mod merkle_tree_compressed_pedersen_crh {
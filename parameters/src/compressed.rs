@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ParameterError;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    io::{BufReader, Read, Write},
+    path::Path,
+};
+
+/// The magic bytes at the start of a compressed parameter file.
+pub const COMPRESSED_MAGIC: [u8; 8] = *b"ALEOZSTD";
+/// The version of the compressed parameter file format.
+pub const COMPRESSED_VERSION: u8 = 1;
+/// The default zstd compression level for parameter files.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 19;
+
+/// A zstd dictionary, trained on parameter files to improve their compression ratio.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressionDictionary {
+    bytes: Vec<u8>,
+}
+
+impl CompressionDictionary {
+    /// Initializes a dictionary from its bytes.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Trains a dictionary of at most `max_size` bytes on the given samples.
+    pub fn train(samples: &[Vec<u8>], max_size: usize) -> Result<Self, ParameterError> {
+        Ok(Self::new(zstd::dict::from_samples(samples, max_size)?))
+    }
+
+    /// Returns the ID of the dictionary, which is recorded in the files it compresses.
+    /// The ID `0` is reserved for files compressed without a dictionary.
+    pub fn id(&self) -> u32 {
+        let digest = snarkvm_algorithms::crypto_hash::sha256::sha256(&self.bytes);
+        u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]).max(1)
+    }
+
+    /// Returns the bytes of the dictionary.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the filename of the dictionary with the given ID.
+    pub fn filename(id: u32) -> String {
+        format!("{:08x}.zdict", id)
+    }
+
+    /// Loads the dictionary with the given ID from the given directory.
+    /// Returns `None` for the ID `0`, which denotes files compressed without a dictionary.
+    pub fn load(directory: &Path, id: u32) -> Result<Option<Self>, ParameterError> {
+        if id == 0 {
+            return Ok(None);
+        }
+
+        let dictionary = Self::new(std::fs::read(directory.join(Self::filename(id)))?);
+        match dictionary.id() == id {
+            true => Ok(Some(dictionary)),
+            false => Err(ParameterError::Message(format!("Dictionary {} does not match its ID", Self::filename(id)))),
+        }
+    }
+}
+
+/// Returns `true` if the given bytes begin with the header of a compressed parameter file.
+pub fn is_compressed(bytes: &[u8]) -> bool {
+    bytes.starts_with(&COMPRESSED_MAGIC)
+}
+
+/// Writes the given value as a compressed parameter file, streaming its serialization through the encoder.
+pub fn write_compressed<T: ToBytes, W: Write>(
+    value: &T,
+    mut writer: W,
+    level: i32,
+    dictionary: Option<&CompressionDictionary>,
+) -> Result<(), ParameterError> {
+    // Write the header.
+    writer.write_all(&COMPRESSED_MAGIC)?;
+    writer.write_all(&[COMPRESSED_VERSION])?;
+    writer.write_all(&dictionary.map_or(0, |dictionary| dictionary.id()).to_le_bytes())?;
+
+    // Write the zstd frame, including a checksum of its contents.
+    let dictionary = dictionary.map_or(&[][..], |dictionary| dictionary.as_bytes());
+    let mut encoder = zstd::stream::write::Encoder::with_dictionary(writer, level, dictionary)?;
+    encoder.include_checksum(true)?;
+    value.write_le(&mut encoder)?;
+    encoder.finish()?.flush()?;
+    Ok(())
+}
+
+/// Reads a value from a compressed parameter file, streaming the decompressed bytes into its deserializer.
+pub fn read_compressed<T: FromBytes, R: Read>(
+    reader: R,
+    dictionary: Option<&CompressionDictionary>,
+) -> Result<T, ParameterError> {
+    Ok(T::read_le(decoder(reader, dictionary)?)?)
+}
+
+/// Reads the header of a compressed parameter file, and returns a reader over its decompressed bytes.
+pub fn decoder<R: Read>(
+    mut reader: R,
+    dictionary: Option<&CompressionDictionary>,
+) -> Result<zstd::stream::read::Decoder<'static, BufReader<R>>, ParameterError> {
+    // Read the header.
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != COMPRESSED_MAGIC {
+        return Err(ParameterError::Message("Invalid compressed parameter file header".to_string()));
+    }
+
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    if version[0] != COMPRESSED_VERSION {
+        return Err(ParameterError::Message(format!("Unsupported compressed parameter file version {}", version[0])));
+    }
+
+    let mut dictionary_id = [0u8; 4];
+    reader.read_exact(&mut dictionary_id)?;
+    let dictionary_id = u32::from_le_bytes(dictionary_id);
+    let expected_id = dictionary.map_or(0, |dictionary| dictionary.id());
+    if dictionary_id != expected_id {
+        return Err(ParameterError::Message(format!(
+            "Compressed parameter file requires dictionary {}, found dictionary {}",
+            dictionary_id, expected_id
+        )));
+    }
+
+    // Read the zstd frame.
+    let dictionary = dictionary.map_or(&[][..], |dictionary| dictionary.as_bytes());
+    Ok(zstd::stream::read::Decoder::with_dictionary(BufReader::new(reader), dictionary)?)
+}
+
+/// Returns the decompressed bytes of a compressed parameter file.
+pub fn decompress<R: Read>(reader: R, dictionary: Option<&CompressionDictionary>) -> Result<Vec<u8>, ParameterError> {
+    read_compressed::<Decompressed, _>(reader, dictionary).map(|decompressed| decompressed.0)
+}
+
+/// The raw bytes of a decompressed parameter file.
+struct Decompressed(Vec<u8>);
+
+impl FromBytes for Decompressed {
+    fn read_le<R: Read>(mut reader: R) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode, UniversalSRS};
+    use snarkvm_dpc::{testnet2::Testnet2, Network};
+
+    use rand::thread_rng;
+
+    type ProgramSNARK = <Testnet2 as Network>::ProgramSNARK;
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let max_degree =
+            AHPForR1CS::<<Testnet2 as Network>::InnerScalarField, MarlinHidingMode>::max_degree(100, 100, 100).unwrap();
+        let srs = ProgramSNARK::universal_setup(max_degree, &mut thread_rng()).unwrap();
+        let expected = srs.to_bytes_le().unwrap();
+
+        let mut compressed = Vec::new();
+        write_compressed(&srs, &mut compressed, DEFAULT_COMPRESSION_LEVEL, None).unwrap();
+        assert!(is_compressed(&compressed));
+
+        let candidate: UniversalSRS<<Testnet2 as Network>::InnerCurve> =
+            read_compressed(&compressed[..], None).unwrap();
+        assert_eq!(expected, candidate.to_bytes_le().unwrap());
+        assert_eq!(expected, decompress(&compressed[..], None).unwrap());
+
+        // A corrupted frame is rejected.
+        let last = compressed.len() - 1;
+        compressed[last] ^= 1;
+        assert!(decompress(&compressed[..], None).is_err());
+    }
+
+    #[test]
+    fn test_compressed_with_dictionary() {
+        let samples = (0..64u32)
+            .map(|i| (0..256u32).flat_map(|j| (i * j % 17).to_le_bytes()).collect::<Vec<u8>>())
+            .collect::<Vec<_>>();
+        let dictionary = CompressionDictionary::train(&samples, 4 * 1024).unwrap();
+
+        let mut compressed = Vec::new();
+        write_compressed(&samples[0], &mut compressed, DEFAULT_COMPRESSION_LEVEL, Some(&dictionary)).unwrap();
+        assert_eq!(samples[0], decompress(&compressed[..], Some(&dictionary)).unwrap());
+
+        // The file cannot be read without its dictionary.
+        assert!(decompress(&compressed[..], None).is_err());
+
+        // The dictionary is loaded by its ID.
+        let directory = std::env::temp_dir();
+        let path = directory.join(CompressionDictionary::filename(dictionary.id()));
+        std::fs::write(&path, dictionary.as_bytes()).unwrap();
+        assert_eq!(Some(&dictionary), CompressionDictionary::load(&directory, dictionary.id()).unwrap().as_ref());
+        assert_eq!(None, CompressionDictionary::load(&directory, 0).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
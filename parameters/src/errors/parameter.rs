@@ -21,6 +21,9 @@ pub enum ParameterError {
     #[error("expected checksum of {}, found checksum of {}", _0, _1)]
     ChecksumMismatch(String, String),
 
+    #[error("corrupt parameter file {}: {}", _0, _1)]
+    CorruptFile(String, Box<ParameterError>),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
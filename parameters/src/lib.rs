@@ -27,9 +27,18 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+#[cfg(feature = "compressed")]
+pub mod compressed;
+
 pub mod errors;
 pub use errors::*;
 
+pub mod manifest;
+pub use manifest::*;
+
+#[cfg(not(feature = "wasm"))]
+pub mod remote;
+
 pub mod testnet1;
 
 pub mod testnet2;
@@ -35,31 +35,25 @@ macro_rules! impl_local {
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, crate::errors::ParameterError> {
+            /// Returns the pinned size and checksum of the parameter file.
+            pub fn manifest() -> Result<crate::manifest::Manifest, crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+                crate::manifest::Manifest::from_metadata(METADATA, $ftype)
+            }
 
-                let metadata: serde_json::Value =
-                    serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String =
-                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize =
-                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
-
+            pub fn load_bytes() -> Result<Vec<u8>, crate::errors::ParameterError> {
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
 
-                // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err(crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
-                }
-
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer);
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum);
-                }
+                // Ensure the size and checksum match.
+                Self::manifest()?.verify(buffer)?;
 
                 return Ok(buffer.to_vec());
             }
+
+            /// Loads the parameter from its bytes.
+            pub fn load<T: snarkvm_utilities::FromBytes>() -> Result<T, crate::errors::ParameterError> {
+                Ok(T::read_le(&Self::load_bytes()?[..])?)
+            }
         }
 
         paste::item! {
@@ -74,160 +68,144 @@ macro_rules! impl_local {
 
 #[macro_export]
 macro_rules! impl_remote {
-    ($name: ident, $remote_url: tt, $local_dir: expr, $fname: tt, $ftype: tt) => {
+    ($name: ident, $remote_urls: tt, $local_dir: expr, $fname: tt, $ftype: tt) => {
 
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, crate::errors::ParameterError> {
+            /// Returns the pinned size and checksum of the parameter file.
+            pub fn manifest() -> Result<crate::manifest::Manifest, crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+                crate::manifest::Manifest::from_metadata(METADATA, $ftype)
+            }
 
-                let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String = metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize = metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
-
+            /// Returns the versioned filename and the local path of the parameter file.
+            pub fn file_path() -> Result<(String, std::path::PathBuf), crate::errors::ParameterError> {
                 // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
-                    Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
-                    _ => concat!($fname, $ftype).to_string()
-                };
+                let filename = Self::manifest()?.versioned_filename($fname, $ftype);
 
                 // Compose the correct file path for the parameter file.
                 let mut file_path = aleo_std::aleo_dir();
                 file_path.push($local_dir);
                 file_path.push(&filename);
 
-                let buffer = if file_path.exists() {
-                    // Attempts to load the parameter file locally with an absolute path.
-                    std::fs::read(file_path)?
-                } else {
-                    // Downloads the missing parameters and stores it in the local directory for use.
-                    eprintln!(
-                        "\nATTENTION - \"{}\" does not exist, downloading this file remotely and storing it locally. Please ensure \"{}\" is stored in {:?}.\n",
-                        filename, filename, file_path
-                    );
-
-                    // Load remote file
-                    cfg_if::cfg_if! {
-                        if #[cfg(not(feature = "wasm"))] {
-                            #[cfg(not(feature = "no_std_out"))]
-                            println!("{} - Downloading parameters...", module_path!());
-
-
-                            let mut buffer = vec![];
-                            Self::remote_fetch(&mut buffer, &format!("{}/{}", $remote_url, filename))?;
-
-                            #[cfg(not(feature = "no_std_out"))]
-                            println!("\n{} - Download complete", module_path!());
-
-                            // Ensure the checksum matches.
-                            let candidate_checksum = checksum!(&buffer);
-                            if expected_checksum != candidate_checksum {
-                                return checksum_error!(expected_checksum, candidate_checksum)
-                            }
+                Ok((filename, file_path))
+            }
 
-                            match Self::store_bytes(&buffer, &file_path) {
-                                Ok(()) => buffer,
-                                Err(_) => {
-                                    eprintln!(
-                                        "\nATTENTION - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                        filename, file_path
-                                    );
-                                    buffer
-                                }
-                            }
-                        } else if #[cfg(feature = "wasm")] {
-                            let buffer = alloc::sync::Arc::new(parking_lot::RwLock::new(vec![]));
-                            let url = String::from($remote_url);
-
-                            // NOTE(julesdesmit): I'm leaking memory here so that I can get a
-                            // static reference to the url, which is needed to pass it into
-                            // the local thread which downloads the file.
-                            let url = Box::leak(url.into_boxed_str());
-
-                            let buffer_clone = alloc::sync::Arc::downgrade(&buffer);
-                            Self::remote_fetch(buffer_clone, url)?;
-
-                            // Recover the bytes.
-                            let buffer = alloc::sync::Arc::try_unwrap(buffer).unwrap();
-                            let buffer = buffer.write().clone();
-
-                            // Ensure the checksum matches.
-                            let candidate_checksum = checksum!(&buffer);
-                            if expected_checksum != candidate_checksum {
-                                return checksum_error!(expected_checksum, candidate_checksum)
-                            }
+            /// Returns `Ok` if the parameter file is not stored locally, or matches its pinned size and checksum.
+            pub fn verify_cached() -> Result<(), crate::errors::ParameterError> {
+                Self::manifest()?.verify_cached(&Self::file_path()?.1)
+            }
 
-                            buffer
-                        } else {
-                            return Err(crate::errors::ParameterError::RemoteFetchDisabled);
+            /// Loads the parameter, streaming it from a compressed copy of the file if only that copy is stored locally.
+            pub fn load<T: snarkvm_utilities::FromBytes>() -> Result<T, crate::errors::ParameterError> {
+                #[cfg(feature = "compressed")]
+                {
+                    let manifest = Self::manifest()?;
+                    let file_path = Self::file_path()?.1;
+                    if !file_path.exists() {
+                        if let Some(decoder) = Self::compressed_decoder(&manifest, &file_path)? {
+                            return manifest.read_verified(decoder);
                         }
                     }
-                };
-
-                 // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err(crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
                 }
 
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer.as_slice());
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum)
-                }
-
-                return Ok(buffer)
+                Ok(T::read_le(&Self::load_bytes()?[..])?)
             }
 
-            #[cfg(not(feature = "wasm"))]
-            fn store_bytes(
-                buffer: &[u8],
+            /// Returns a reader over the decompressed bytes of the compressed copy of the parameter file, if it exists.
+            /// The copy is decompressed with the dictionary pinned in the manifest, which is stored alongside it.
+            #[cfg(feature = "compressed")]
+            fn compressed_decoder(
+                manifest: &crate::manifest::Manifest,
                 file_path: &std::path::Path,
-            ) -> Result<(), crate::errors::ParameterError> {
-                use snarkvm_utilities::Write;
-
-                #[cfg(not(feature = "no_std_out"))]
-                println!("{} - Storing parameters ({:?})", module_path!(), file_path);
+            ) -> Result<Option<impl std::io::Read>, crate::errors::ParameterError> {
+                let mut compressed_path = file_path.to_path_buf().into_os_string();
+                compressed_path.push(".zst");
+                let compressed_path = std::path::PathBuf::from(compressed_path);
+                if !compressed_path.exists() {
+                    return Ok(None);
+                }
 
-                // Ensure the folders up to the file path all exist.
-                let mut directory_path = file_path.to_path_buf();
-                directory_path.pop();
-                let _ = std::fs::create_dir_all(directory_path)?;
+                let directory = file_path.parent().unwrap_or_else(|| std::path::Path::new("."));
+                let dictionary = crate::compressed::CompressionDictionary::load(directory, manifest.dictionary_id)?;
+                let file = std::fs::File::open(&compressed_path)?;
+                Ok(Some(crate::compressed::decoder(file, dictionary.as_ref())?))
+            }
 
-                // Attempt to write the parameter buffer to a file.
-                match std::fs::File::create(file_path) {
-                    Ok(mut file) => file.write_all(&buffer)?,
-                    Err(error) => eprintln!("{}", error)
+            pub fn load_bytes() -> Result<Vec<u8>, crate::errors::ParameterError> {
+                let manifest = Self::manifest()?;
+                let (filename, file_path) = Self::file_path()?;
+
+                // Attempts to load the parameter file locally with an absolute path.
+                if file_path.exists() {
+                    let buffer = std::fs::read(&file_path)?;
+                    match manifest.verify(&buffer) {
+                        Ok(()) => return Ok(buffer),
+                        Err(error) => {
+                            // Discard the corrupt file, so that it is downloaded again below.
+                            eprintln!(
+                                "\nATTENTION - \"{}\" is corrupt ({}), removing it and downloading it again.\n",
+                                filename, error
+                            );
+                            std::fs::remove_file(&file_path)?;
+                        }
+                    }
                 }
-                Ok(())
-            }
 
-            #[cfg(not(feature = "wasm"))]
-            fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), crate::errors::ParameterError> {
-                let mut easy = curl::easy::Easy::new();
-                easy.url(url)?;
-                #[cfg(not(feature = "no_std_out"))]
-                {
-                    easy.progress(true)?;
-                    easy.progress_function(|total_download, current_download, _, _| {
-                        let percent = (current_download / total_download) * 100.0;
-                        let size_in_megabytes = total_download as u64 / 1_048_576;
-                        print!(
-                            "\r{} - {:.2}% complete ({:#} MB total)",
-                            module_path!(),
-                            percent,
-                            size_in_megabytes
-                        );
-                        true
-                    })?;
+                // Attempts to load a compressed copy of the parameter file, stored alongside it.
+                #[cfg(feature = "compressed")]
+                if let Some(mut decoder) = Self::compressed_decoder(&manifest, &file_path)? {
+                    let mut buffer = Vec::with_capacity(manifest.size);
+                    std::io::Read::read_to_end(&mut decoder, &mut buffer)?;
+                    manifest.verify(&buffer)?;
+                    return Ok(buffer);
                 }
 
-                let mut transfer = easy.transfer();
-                transfer.write_function(|data| {
-                    buffer.extend_from_slice(data);
-                    Ok(data.len())
-                })?;
-                Ok(transfer.perform()?)
+                // Downloads the missing parameters and stores it in the local directory for use.
+                eprintln!(
+                    "\nATTENTION - \"{}\" does not exist, downloading this file remotely and storing it locally. Please ensure \"{}\" is stored in {:?}.\n",
+                    filename, filename, file_path
+                );
+
+                // Load remote file
+                cfg_if::cfg_if! {
+                    if #[cfg(not(feature = "wasm"))] {
+                        let mirrors = crate::remote::mirrors($remote_urls);
+                        match crate::remote::fetch(&mirrors, &filename, &file_path, &manifest) {
+                            Ok(buffer) => Ok(buffer),
+                            Err(error) => {
+                                eprintln!(
+                                    "\nATTENTION - Failed to download \"{}\". Please download this file manually and ensure it is stored in {:?}.\n",
+                                    filename, file_path
+                                );
+                                Err(error)
+                            }
+                        }
+                    } else if #[cfg(feature = "wasm")] {
+                        let buffer = alloc::sync::Arc::new(parking_lot::RwLock::new(vec![]));
+                        let url = format!("{}/{}", $remote_urls[0], filename);
+
+                        // NOTE(julesdesmit): I'm leaking memory here so that I can get a
+                        // static reference to the url, which is needed to pass it into
+                        // the local thread which downloads the file.
+                        let url = Box::leak(url.into_boxed_str());
+
+                        let buffer_clone = alloc::sync::Arc::downgrade(&buffer);
+                        Self::remote_fetch(buffer_clone, url)?;
+
+                        // Recover the bytes.
+                        let buffer = alloc::sync::Arc::try_unwrap(buffer).unwrap();
+                        let buffer = buffer.write().clone();
+
+                        // Ensure the size and checksum match.
+                        manifest.verify(&buffer)?;
+
+                        Ok(buffer)
+                    } else {
+                        return Err(crate::errors::ParameterError::RemoteFetchDisabled);
+                    }
+                }
             }
 
             #[cfg(feature = "wasm")]
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ParameterError;
+use snarkvm_utilities::FromBytes;
+
+use sha2::{Digest, Sha256};
+use std::io::Read;
+
+/// The pinned size and SHA-256 digest of a parameter file, as recorded in its `.metadata` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifest {
+    /// The hex-encoded SHA-256 checksum of the file.
+    pub checksum: String,
+    /// The size of the file in bytes.
+    pub size: usize,
+    /// The ID of the dictionary that compressed copies of the file are compressed with, or `0` for none.
+    pub dictionary_id: u32,
+}
+
+impl Manifest {
+    /// Returns the manifest for the given file type from the given metadata.
+    pub fn from_metadata(metadata: &str, ftype: &str) -> Result<Self, ParameterError> {
+        let metadata: serde_json::Value = serde_json::from_str(metadata)
+            .map_err(|error| ParameterError::Crate("serde_json", format!("{:?}", error)))?;
+
+        let checksum = match metadata[format!("{}_checksum", ftype)].as_str() {
+            Some(checksum) => checksum.to_string(),
+            None => return Err(ParameterError::Message(format!("Missing {} checksum in metadata", ftype))),
+        };
+        let size = match metadata[format!("{}_size", ftype)].as_u64() {
+            Some(size) => size as usize,
+            None => return Err(ParameterError::Message(format!("Missing {} size in metadata", ftype))),
+        };
+
+        // The dictionary ID is optional, as most files are compressed without a dictionary.
+        let dictionary_id = match &metadata[format!("{}_dictionary_id", ftype)] {
+            serde_json::Value::Null => 0,
+            dictionary_id => match dictionary_id.as_u64().and_then(|id| u32::try_from(id).ok()) {
+                Some(dictionary_id) => dictionary_id,
+                None => return Err(ParameterError::Message(format!("Invalid {} dictionary ID in metadata", ftype))),
+            },
+        };
+
+        Ok(Self { checksum, size, dictionary_id })
+    }
+
+    /// Returns the versioned filename of the file, which includes a prefix of its checksum.
+    pub fn versioned_filename(&self, fname: &str, ftype: &str) -> String {
+        match self.checksum.get(0..7) {
+            Some(sum) => format!("{}.{}.{}", fname, ftype, sum),
+            None => format!("{}.{}", fname, ftype),
+        }
+    }
+
+    /// Returns `Ok` if the given bytes match the pinned size and checksum.
+    pub fn verify(&self, buffer: &[u8]) -> Result<(), ParameterError> {
+        // Ensure the size matches.
+        if self.size != buffer.len() {
+            return Err(ParameterError::SizeMismatch(self.size, buffer.len()));
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(buffer);
+        if self.checksum != candidate_checksum {
+            return checksum_error!(self.checksum.clone(), candidate_checksum);
+        }
+
+        Ok(())
+    }
+
+    /// Reads a value from the given reader, and returns it if the bytes read match the pinned size and checksum.
+    /// The bytes are hashed as they are streamed into the deserializer, and any trailing bytes are also read.
+    pub fn read_verified<T: FromBytes, R: Read>(&self, reader: R) -> Result<T, ParameterError> {
+        let mut reader = DigestReader { reader, hasher: Sha256::new(), size: 0 };
+        let value = T::read_le(&mut reader)?;
+        std::io::copy(&mut reader, &mut std::io::sink())?;
+
+        // Ensure the size matches.
+        if self.size != reader.size {
+            return Err(ParameterError::SizeMismatch(self.size, reader.size));
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = hex::encode(reader.hasher.finalize());
+        if self.checksum != candidate_checksum {
+            return checksum_error!(self.checksum.clone(), candidate_checksum);
+        }
+
+        Ok(value)
+    }
+
+    /// Returns `Ok` if the file at the given path does not exist, or matches the pinned size and checksum.
+    pub fn verify_cached(&self, file_path: &std::path::Path) -> Result<(), ParameterError> {
+        if !file_path.exists() {
+            return Ok(());
+        }
+
+        // Check the size first, to avoid reading a truncated or oversized file into memory.
+        let size = std::fs::metadata(file_path)?.len() as usize;
+        let result = match size == self.size {
+            true => self.verify(&std::fs::read(file_path)?),
+            false => Err(ParameterError::SizeMismatch(self.size, size)),
+        };
+
+        result.map_err(|error| ParameterError::CorruptFile(file_path.display().to_string(), Box::new(error)))
+    }
+}
+
+/// A reader that hashes and counts the bytes read through it.
+struct DigestReader<R: Read> {
+    reader: R,
+    hasher: Sha256,
+    size: usize,
+}
+
+impl<R: Read> Read for DigestReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        self.size += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"{
+        "proving_checksum": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+        "proving_size": 5
+    }"#;
+
+    #[test]
+    fn test_manifest_verify() {
+        let manifest = Manifest::from_metadata(METADATA, "proving").unwrap();
+        assert_eq!("proving.bin.2cf24db", manifest.versioned_filename("proving", "bin"));
+
+        assert!(manifest.verify(b"hello").is_ok());
+        assert!(matches!(manifest.verify(b"hello!"), Err(ParameterError::SizeMismatch(5, 6))));
+        assert!(matches!(manifest.verify(b"world"), Err(ParameterError::ChecksumMismatch(..))));
+
+        // Missing fields are reported as errors.
+        assert!(Manifest::from_metadata(METADATA, "verifying").is_err());
+        assert!(Manifest::from_metadata("{", "proving").is_err());
+    }
+
+    #[test]
+    fn test_manifest_dictionary_id() {
+        assert_eq!(0, Manifest::from_metadata(METADATA, "proving").unwrap().dictionary_id);
+
+        let metadata = METADATA.replace("\"proving_size\": 5", "\"proving_size\": 5, \"proving_dictionary_id\": 42");
+        assert_eq!(42, Manifest::from_metadata(&metadata, "proving").unwrap().dictionary_id);
+
+        let metadata = METADATA.replace("\"proving_size\": 5", "\"proving_size\": 5, \"proving_dictionary_id\": -1");
+        assert!(Manifest::from_metadata(&metadata, "proving").is_err());
+    }
+
+    #[test]
+    fn test_manifest_read_verified() {
+        let manifest = Manifest::from_metadata(METADATA, "proving").unwrap();
+
+        // The checksum covers the bytes left after the value is read.
+        assert_eq!(0x6c6c6568u32, manifest.read_verified::<u32, _>(&b"hello"[..]).unwrap());
+        assert!(matches!(manifest.read_verified::<u32, _>(&b"hello!"[..]), Err(ParameterError::SizeMismatch(5, 6))));
+        assert!(matches!(manifest.read_verified::<u32, _>(&b"world"[..]), Err(ParameterError::ChecksumMismatch(..))));
+    }
+
+    #[test]
+    fn test_manifest_verify_cached() {
+        let manifest = Manifest::from_metadata(METADATA, "proving").unwrap();
+
+        let mut file_path = std::env::temp_dir();
+        file_path.push(format!("snarkvm_parameters_manifest_{}", std::process::id()));
+
+        // A missing file is not checked.
+        assert!(manifest.verify_cached(&file_path).is_ok());
+
+        std::fs::write(&file_path, b"hello").unwrap();
+        assert!(manifest.verify_cached(&file_path).is_ok());
+
+        std::fs::write(&file_path, b"world").unwrap();
+        assert!(matches!(manifest.verify_cached(&file_path), Err(ParameterError::CorruptFile(..))));
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::ParameterError, manifest::Manifest};
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable listing additional mirrors to download parameters from, separated by commas.
+pub const MIRRORS_ENV_VAR: &str = "ALEO_PARAMETERS_MIRRORS";
+
+/// Returns the mirrors to download parameters from, with any mirrors from the environment tried first.
+pub fn mirrors(default_mirrors: &[&str]) -> Vec<String> {
+    let mut mirrors = match std::env::var(MIRRORS_ENV_VAR) {
+        Ok(mirrors) => mirrors.split(',').map(str::trim).filter(|m| !m.is_empty()).map(String::from).collect(),
+        Err(_) => Vec::new(),
+    };
+    mirrors.extend(default_mirrors.iter().map(|mirror| mirror.to_string()));
+    mirrors
+}
+
+/// Downloads the given file to the given path from the first mirror that serves it intact.
+///
+/// Bytes are written to a `.partial` file next to the destination as they arrive,
+/// so an interrupted download resumes from where it stopped, on the same or another mirror.
+/// The partial file is only moved into place once it matches the manifest.
+pub fn fetch(
+    mirrors: &[String],
+    filename: &str,
+    file_path: &Path,
+    manifest: &Manifest,
+) -> Result<Vec<u8>, ParameterError> {
+    // Ensure the folders up to the file path all exist.
+    if let Some(directory_path) = file_path.parent() {
+        std::fs::create_dir_all(directory_path)?;
+    }
+
+    let partial_path = partial_path(file_path);
+
+    for mirror in mirrors {
+        let url = format!("{}/{}", mirror.trim_end_matches('/'), filename);
+
+        // Resume any partial download, unless it is already larger than the file.
+        let mut offset = partial_path.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        if offset > manifest.size as u64 {
+            File::create(&partial_path)?;
+            offset = 0;
+        }
+
+        // Skip the download if a previous attempt completed without being moved into place.
+        if offset < manifest.size as u64 {
+            #[cfg(not(feature = "no_std_out"))]
+            println!("{} - Downloading {} from {} (resuming at byte {})", module_path!(), filename, mirror, offset);
+
+            let mut file = OpenOptions::new().create(true).append(true).open(&partial_path)?;
+            if let Err(error) = fetch_range(&url, &mut file, offset) {
+                eprintln!("\nATTENTION - Failed to download \"{}\" from {}: {}\n", filename, mirror, error);
+                // Restart from the beginning if the mirror does not support ranged requests.
+                if error.is_range_error() {
+                    File::create(&partial_path)?;
+                }
+                continue;
+            }
+
+            #[cfg(not(feature = "no_std_out"))]
+            println!("\n{} - Download complete", module_path!());
+        }
+
+        let buffer = std::fs::read(&partial_path)?;
+        match manifest.verify(&buffer) {
+            Ok(()) => {
+                std::fs::rename(&partial_path, file_path)?;
+                return Ok(buffer);
+            }
+            Err(error) => {
+                // Discard the corrupt download, so the next mirror starts from the beginning.
+                eprintln!("\nATTENTION - Discarding \"{}\" downloaded from {}: {}\n", filename, mirror, error);
+                std::fs::remove_file(&partial_path)?;
+            }
+        }
+    }
+
+    Err(ParameterError::Message(format!("Failed to download \"{}\" from {} mirror(s)", filename, mirrors.len())))
+}
+
+/// Returns the path of the partial download for the given file.
+fn partial_path(file_path: &Path) -> PathBuf {
+    let mut partial_path = file_path.as_os_str().to_owned();
+    partial_path.push(".partial");
+    PathBuf::from(partial_path)
+}
+
+/// Appends the bytes of the given URL, starting at the given offset, to the given file.
+fn fetch_range(url: &str, file: &mut File, offset: u64) -> Result<(), curl::Error> {
+    let mut easy = curl::easy::Easy::new();
+    easy.url(url)?;
+    easy.fail_on_error(true)?;
+    if offset > 0 {
+        easy.resume_from(offset)?;
+    }
+    #[cfg(not(feature = "no_std_out"))]
+    {
+        easy.progress(true)?;
+        easy.progress_function(move |total_download, current_download, _, _| {
+            let total_download = total_download + offset as f64;
+            let current_download = current_download + offset as f64;
+            let percent = (current_download / total_download) * 100.0;
+            let size_in_megabytes = total_download as u64 / 1_048_576;
+            print!("\r{} - {:.2}% complete ({:#} MB total)", module_path!(), percent, size_in_megabytes);
+            true
+        })?;
+    }
+
+    let mut transfer = easy.transfer();
+    transfer.write_function(|data| match file.write_all(data) {
+        Ok(()) => Ok(data.len()),
+        // Returning a short write aborts the transfer.
+        Err(_) => Ok(0),
+    })?;
+    transfer.perform()
+}
@@ -17,16 +17,24 @@
 pub mod genesis;
 pub use genesis::*;
 
-const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
+const REMOTE_URLS: &[&str] = &["https://s3-us-west-1.amazonaws.com/aleo.parameters"];
 
 // Input Circuit
-impl_remote!(InputProvingKeyBytes, REMOTE_URL, "./resources/", "input", "proving");
+impl_remote!(InputProvingKeyBytes, REMOTE_URLS, "./resources/", "input", "proving");
 impl_local!(InputVerifyingKeyBytes, "./resources/", "input", "verifying");
 
 // Output Circuit
-impl_remote!(OutputProvingKeyBytes, REMOTE_URL, "./resources/", "output", "proving");
+impl_remote!(OutputProvingKeyBytes, REMOTE_URLS, "./resources/", "output", "proving");
 impl_local!(OutputVerifyingKeyBytes, "./resources/", "output", "verifying");
 
 // PoSW Circuit
-impl_remote!(PoSWProvingKeyBytes, REMOTE_URL, "./resources/", "posw", "proving");
+impl_remote!(PoSWProvingKeyBytes, REMOTE_URLS, "./resources/", "posw", "proving");
 impl_local!(PoSWVerifyingKeyBytes, "./resources/", "posw", "verifying");
+
+/// Returns `Ok` if every parameter file stored locally matches its pinned size and checksum.
+pub fn verify_all() -> Result<(), crate::errors::ParameterError> {
+    InputProvingKeyBytes::verify_cached()?;
+    OutputProvingKeyBytes::verify_cached()?;
+    PoSWProvingKeyBytes::verify_cached()?;
+    Ok(())
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Index;
+use snarkvm_fields::Field;
+
+use std::collections::BTreeMap;
+
+/// A constraint system which records its constraints, and can export them as sparse matrices.
+pub trait ToConstraintMatrices<F: Field> {
+    /// Returns the `A`, `B`, and `C` matrices of the constraints recorded so far.
+    fn to_constraint_matrices(&self) -> ConstraintMatrices<F>;
+}
+
+/// The `A`, `B`, and `C` matrices of a rank-1 constraint system, where the `i`-th constraint
+/// enforces `<A_i, z> * <B_i, z> = <C_i, z>` for the full assignment `z` of the variables.
+///
+/// The columns are ordered with the public variables first, starting with the constant `1`,
+/// followed by the private variables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMatrices<F: Field> {
+    /// The number of public variables, including the constant `1`.
+    pub num_public_variables: usize,
+    /// The number of private variables.
+    pub num_private_variables: usize,
+    /// The `A` matrix.
+    pub a: SparseMatrix<F>,
+    /// The `B` matrix.
+    pub b: SparseMatrix<F>,
+    /// The `C` matrix.
+    pub c: SparseMatrix<F>,
+}
+
+impl<F: Field> ConstraintMatrices<F> {
+    /// Returns the number of constraints, i.e. the number of rows of each matrix.
+    pub fn num_constraints(&self) -> usize {
+        self.a.num_rows
+    }
+
+    /// Returns the number of variables, i.e. the number of columns of each matrix.
+    pub fn num_variables(&self) -> usize {
+        self.num_public_variables + self.num_private_variables
+    }
+
+    /// Returns the column of the given variable.
+    pub fn column(&self, index: Index) -> usize {
+        column(self.num_public_variables, index)
+    }
+
+    /// Returns the density statistics of the `A`, `B`, and `C` matrices.
+    pub fn density(&self) -> (MatrixDensity, MatrixDensity, MatrixDensity) {
+        (self.a.density(), self.b.density(), self.c.density())
+    }
+
+    /// Returns the number of entries of each variable across the `A`, `B`, and `C` matrices, indexed by column.
+    pub fn variable_usage(&self) -> Vec<usize> {
+        let mut usage = vec![0; self.num_variables()];
+        for &(_, column, _) in self.a.entries.iter().chain(&self.b.entries).chain(&self.c.entries) {
+            usage[column] += 1;
+        }
+        usage
+    }
+
+    /// Returns a histogram of the variable usage, mapping each number of entries
+    /// to the number of variables with that many entries across the `A`, `B`, and `C` matrices.
+    pub fn variable_usage_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for usage in self.variable_usage() {
+            *histogram.entry(usage).or_insert(0) += 1;
+        }
+        histogram
+    }
+}
+
+/// A sparse matrix in triplet format, where each entry is a `(row, column, coefficient)` triplet.
+///
+/// The entries are sorted by row and then by column, and each coordinate appears at most once.
+/// Coefficients which cancel out within a row are kept as zero entries, as the Marlin indexer expects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMatrix<F: Field> {
+    /// The number of rows of the matrix.
+    pub num_rows: usize,
+    /// The number of columns of the matrix.
+    pub num_columns: usize,
+    /// The `(row, column, coefficient)` entries of the matrix.
+    pub entries: Vec<(usize, usize, F)>,
+}
+
+impl<F: Field> SparseMatrix<F> {
+    /// Returns the matrix whose rows are given as `(variable index, coefficient)` terms,
+    /// summing the coefficients of any variable which appears more than once in a row.
+    pub fn from_rows<R, T>(num_public_variables: usize, num_variables: usize, rows: R) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: IntoIterator<Item = (Index, F)>,
+    {
+        let mut num_rows = 0;
+        let mut entries = Vec::new();
+        for terms in rows {
+            let mut row = BTreeMap::new();
+            for (index, coeff) in terms {
+                *row.entry(column(num_public_variables, index)).or_insert_with(F::zero) += coeff;
+            }
+            entries.extend(row.into_iter().map(|(column, coeff)| (num_rows, column, coeff)));
+            num_rows += 1;
+        }
+
+        Self { num_rows, num_columns: num_variables, entries }
+    }
+
+    /// Returns the number of entries of the matrix.
+    pub fn num_non_zero(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the rows of the matrix, as lists of `(coefficient, column)` pairs.
+    pub fn to_rows(&self) -> Vec<Vec<(F, usize)>> {
+        let mut rows = vec![Vec::new(); self.num_rows];
+        for &(row, column, coeff) in &self.entries {
+            rows[row].push((coeff, column));
+        }
+        rows
+    }
+
+    /// Returns the number of entries in each row of the matrix.
+    pub fn row_weights(&self) -> Vec<usize> {
+        let mut weights = vec![0; self.num_rows];
+        self.entries.iter().for_each(|&(row, _, _)| weights[row] += 1);
+        weights
+    }
+
+    /// Returns the number of entries in each column of the matrix.
+    pub fn column_weights(&self) -> Vec<usize> {
+        let mut weights = vec![0; self.num_columns];
+        self.entries.iter().for_each(|&(_, column, _)| weights[column] += 1);
+        weights
+    }
+
+    /// Returns the density statistics of the matrix.
+    pub fn density(&self) -> MatrixDensity {
+        let row_weights = self.row_weights();
+        let column_weights = self.column_weights();
+
+        let num_cells = self.num_rows as f64 * self.num_columns as f64;
+        MatrixDensity {
+            num_non_zero: self.num_non_zero(),
+            density: if num_cells > 0.0 { self.num_non_zero() as f64 / num_cells } else { 0.0 },
+            max_row_weight: row_weights.iter().copied().max().unwrap_or(0),
+            max_column_weight: column_weights.iter().copied().max().unwrap_or(0),
+            num_empty_rows: row_weights.iter().filter(|weight| **weight == 0).count(),
+            num_empty_columns: column_weights.iter().filter(|weight| **weight == 0).count(),
+        }
+    }
+}
+
+/// The density statistics of a sparse matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixDensity {
+    /// The number of entries.
+    pub num_non_zero: usize,
+    /// The fraction of the cells of the matrix which hold an entry.
+    pub density: f64,
+    /// The largest number of entries in a row.
+    pub max_row_weight: usize,
+    /// The largest number of entries in a column.
+    pub max_column_weight: usize,
+    /// The number of rows without entries.
+    pub num_empty_rows: usize,
+    /// The number of columns without entries.
+    pub num_empty_columns: usize,
+}
+
+/// Returns the column of the given variable, where the public variables precede the private variables.
+fn column(num_public_variables: usize, index: Index) -> usize {
+    match index {
+        Index::Public(i) => i,
+        Index::Private(i) => num_public_variables + i,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConstraintSystem, Fr, TestConstraintSystem};
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_constraint_matrices() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+        let z = cs.alloc(|| "z", || Ok(Fr::from(12u64))).unwrap();
+
+        // x * x = y
+        cs.enforce(|| "square", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+        // (x + y) * 1 = z, with `y` split over two terms.
+        cs.enforce(
+            || "sum",
+            |lc| lc + x + (Fr::from(2u64), y) - y,
+            |lc| lc + TestConstraintSystem::<Fr>::one(),
+            |lc| lc + z,
+        );
+        assert!(cs.is_satisfied());
+
+        let matrices = cs.to_constraint_matrices();
+        assert_eq!(2, matrices.num_public_variables);
+        assert_eq!(2, matrices.num_private_variables);
+        assert_eq!(2, matrices.num_constraints());
+        assert_eq!(2, matrices.column(y.get_unchecked()));
+
+        assert_eq!(vec![(0, 1, Fr::one()), (1, 1, Fr::one()), (1, 2, Fr::one())], matrices.a.entries);
+        assert_eq!(vec![(0, 1, Fr::one()), (1, 0, Fr::one())], matrices.b.entries);
+        assert_eq!(vec![(0, 2, Fr::one()), (1, 3, Fr::one())], matrices.c.entries);
+        assert_eq!(vec![vec![(Fr::one(), 1)], vec![(Fr::one(), 1), (Fr::one(), 2)]], matrices.a.to_rows());
+
+        let (a, b, _) = matrices.density();
+        assert_eq!(3, a.num_non_zero);
+        assert_eq!(3.0 / 8.0, a.density);
+        assert_eq!(2, a.max_row_weight);
+        assert_eq!(2, a.max_column_weight);
+        assert_eq!(2, a.num_empty_columns);
+        assert_eq!(1, b.max_row_weight);
+        assert_eq!(0, b.num_empty_rows);
+
+        // The constant `1` appears once, `x` three times, `y` twice, and `z` once.
+        assert_eq!(vec![1, 3, 2, 1], matrices.variable_usage());
+        assert_eq!(vec![(1, 2), (2, 1), (3, 1)], matrices.variable_usage_histogram().into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cancelled_coefficients() {
+        let row = vec![(Index::Private(0), Fr::one()), (Index::Private(0), -Fr::one())];
+        let matrix = SparseMatrix::<Fr>::from_rows(1, 2, vec![row]);
+        assert_eq!(vec![(0, 1, Fr::zero())], matrix.entries);
+        assert_eq!(1, matrix.density().num_empty_columns);
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::{errors::SynthesisError, Index, InputLayout, LinearCombination, Namespace, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -105,6 +105,12 @@ pub trait ConstraintSystem<F: Field>: Sized {
 
     /// Output whether the constraint system is in the setup mode.
     fn is_in_setup_mode(&self) -> bool;
+
+    /// Output the paths of the public inputs in allocation order, if the constraint system tracks them.
+    /// Use `InputLayout::of` to obtain the layout of a circuit regardless of the constraint system.
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        None
+    }
 }
 
 /// Convenience implementation of ConstraintSystem<F> for mutable references to
@@ -187,4 +193,8 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
     fn is_in_setup_mode(&self) -> bool {
         (**self).is_in_setup_mode()
     }
+    #[inline]
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        (**self).input_assignment_layout()
+    }
 }
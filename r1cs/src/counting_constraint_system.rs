@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+/// A constraint system which only counts the variables and constraints of a circuit,
+/// along with the non-zero entries of its constraint matrices.
+///
+/// Assignments are never computed, and linear combinations are discarded once counted,
+/// which makes it possible to size a circuit without synthesizing it in full.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountingConstraintSystem {
+    /// The number of public variables, including the constant `1`.
+    pub num_public_variables: usize,
+    /// The number of private variables.
+    pub num_private_variables: usize,
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The number of non-zero entries in the A matrix.
+    pub num_non_zero_a: usize,
+    /// The number of non-zero entries in the B matrix.
+    pub num_non_zero_b: usize,
+    /// The number of non-zero entries in the C matrix.
+    pub num_non_zero_c: usize,
+}
+
+impl CountingConstraintSystem {
+    /// Returns a new, empty counting constraint system.
+    pub fn new() -> Self {
+        Self {
+            num_public_variables: 1,
+            num_private_variables: 0,
+            num_constraints: 0,
+            num_non_zero_a: 0,
+            num_non_zero_b: 0,
+            num_non_zero_c: 0,
+        }
+    }
+
+    /// Returns the counts for the given circuit.
+    pub fn count<F: Field, C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut cs = Self::new();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs)
+    }
+
+    /// Returns the total number of variables, including the constant `1`.
+    pub fn num_variables(&self) -> usize {
+        self.num_public_variables + self.num_private_variables
+    }
+}
+
+impl Default for CountingConstraintSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for CountingConstraintSystem {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let var = Variable::new_unchecked(Index::Private(self.num_private_variables));
+        self.num_private_variables += 1;
+        Ok(var)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let var = Variable::new_unchecked(Index::Public(self.num_public_variables));
+        self.num_public_variables += 1;
+        Ok(var)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        // Linear combinations hold at most one term per variable, so each term is an entry of the matrix.
+        self.num_non_zero_a += a(LinearCombination::zero()).0.len();
+        self.num_non_zero_b += b(LinearCombination::zero()).0.len();
+        self.num_non_zero_c += c(LinearCombination::zero()).0.len();
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FnSynthesizer, Fr, TestConstraintSystem, ToConstraintMatrices};
+
+    #[test]
+    fn test_counting_constraint_system() {
+        let circuit = FnSynthesizer::new(|cs| {
+            let x = cs.alloc(|| "x", || Ok(Fr::from(3u64)))?;
+            let y = cs.alloc(|| "y", || Ok(Fr::from(4u64)))?;
+            let z = cs.alloc_input(|| "z", || Ok(Fr::from(7u64)))?;
+
+            cs.enforce(|| "x + y = z", |lc| lc + x + y, |lc| lc + TestConstraintSystem::<Fr>::one(), |lc| lc + z);
+            cs.enforce(
+                || "x * y = 12",
+                |lc| lc + x + x - x,
+                |lc| lc + y,
+                |lc| lc + (Fr::from(12u64), TestConstraintSystem::<Fr>::one()),
+            );
+            Ok(())
+        });
+
+        let counts = CountingConstraintSystem::count(&circuit).unwrap();
+        assert_eq!(2, counts.num_public_variables);
+        assert_eq!(2, counts.num_private_variables);
+        assert_eq!(2, counts.num_constraints);
+        assert_eq!((3, 2, 2), (counts.num_non_zero_a, counts.num_non_zero_b, counts.num_non_zero_c));
+
+        // The counts match those of a full synthesis.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        let matrices = cs.to_constraint_matrices();
+        assert_eq!(cs.num_public_variables(), counts.num_public_variables);
+        assert_eq!(cs.num_private_variables(), counts.num_private_variables);
+        assert_eq!(cs.num_constraints(), counts.num_constraints);
+        assert_eq!(matrices.a.num_non_zero(), counts.num_non_zero_a);
+        assert_eq!(matrices.b.num_non_zero(), counts.num_non_zero_b);
+        assert_eq!(matrices.c.num_non_zero(), counts.num_non_zero_c);
+    }
+}
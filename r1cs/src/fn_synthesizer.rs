@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    InputLayout,
+    LinearCombination,
+    Variable,
+};
+use snarkvm_fields::Field;
+
+use std::marker::PhantomData;
+
+/// A `ConstraintSynthesizer` defined by a closure, for small circuits which do not warrant a struct.
+///
+/// As `generate_constraints` is generic over the constraint system, the closure is given
+/// an `ErasedConstraintSystem`, which forwards every call to the underlying constraint system.
+///
+/// ```ignore
+/// let circuit = FnSynthesizer::new(|cs| {
+///     let a = cs.alloc(|| "a", || Ok(F::one()))?;
+///     cs.enforce(|| "a * a = a", |lc| lc + a, |lc| lc + a, |lc| lc + a);
+///     Ok(())
+/// });
+/// ```
+pub struct FnSynthesizer<F: Field, C> {
+    synthesize: C,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, C> FnSynthesizer<F, C>
+where
+    C: Fn(&mut ErasedConstraintSystem<F>) -> Result<(), SynthesisError>,
+{
+    /// Returns a constraint synthesizer which generates its constraints using the given closure.
+    pub fn new(synthesize: C) -> Self {
+        Self { synthesize, _field: PhantomData }
+    }
+}
+
+impl<F: Field, C> ConstraintSynthesizer<F> for FnSynthesizer<F, C>
+where
+    C: Fn(&mut ErasedConstraintSystem<F>) -> Result<(), SynthesisError>,
+{
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        (self.synthesize)(&mut ErasedConstraintSystem(cs))
+    }
+}
+
+/// A constraint system which forwards every call to a constraint system of an erased type.
+pub struct ErasedConstraintSystem<'a, F: Field>(&'a mut dyn ObjectSafeConstraintSystem<F>);
+
+impl<F: Field> ConstraintSystem<F> for ErasedConstraintSystem<'_, F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let (mut annotation, mut f) = (Some(annotation), Some(f));
+        self.0.dyn_alloc(&mut || annotation.take().unwrap()().as_ref().to_owned(), &mut || f.take().unwrap()())
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let (mut annotation, mut f) = (Some(annotation), Some(f));
+        self.0.dyn_alloc_input(&mut || annotation.take().unwrap()().as_ref().to_owned(), &mut || f.take().unwrap()())
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let mut annotation = Some(annotation);
+        let (mut a, mut b, mut c) = (Some(a), Some(b), Some(c));
+        self.0.dyn_enforce(
+            &mut || annotation.take().unwrap()().as_ref().to_owned(),
+            &mut |lc| a.take().unwrap()(lc),
+            &mut |lc| b.take().unwrap()(lc),
+            &mut |lc| c.take().unwrap()(lc),
+        )
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let mut name_fn = Some(name_fn);
+        self.0.dyn_push_namespace(&mut || name_fn.take().unwrap()().as_ref().to_owned())
+    }
+
+    fn pop_namespace(&mut self) {
+        self.0.dyn_pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.0.dyn_num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.0.dyn_num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.0.dyn_num_private_variables()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.0.dyn_is_in_setup_mode()
+    }
+
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        self.0.dyn_input_assignment_layout()
+    }
+}
+
+type DynAnnotation<'a> = &'a mut dyn FnMut() -> String;
+type DynAssignment<'a, F> = &'a mut dyn FnMut() -> Result<F, SynthesisError>;
+type DynLinearCombination<'a, F> = &'a mut dyn FnMut(LinearCombination<F>) -> LinearCombination<F>;
+
+/// An object-safe counterpart of `ConstraintSystem`, whose closures are passed as trait objects;
+/// each closure is invoked at most once, as `ConstraintSystem` expects.
+trait ObjectSafeConstraintSystem<F: Field> {
+    fn dyn_alloc(&mut self, annotation: DynAnnotation<'_>, f: DynAssignment<'_, F>)
+    -> Result<Variable, SynthesisError>;
+
+    fn dyn_alloc_input(
+        &mut self,
+        annotation: DynAnnotation<'_>,
+        f: DynAssignment<'_, F>,
+    ) -> Result<Variable, SynthesisError>;
+
+    fn dyn_enforce(
+        &mut self,
+        annotation: DynAnnotation<'_>,
+        a: DynLinearCombination<'_, F>,
+        b: DynLinearCombination<'_, F>,
+        c: DynLinearCombination<'_, F>,
+    );
+
+    fn dyn_push_namespace(&mut self, name_fn: DynAnnotation<'_>);
+
+    fn dyn_pop_namespace(&mut self);
+
+    fn dyn_num_constraints(&self) -> usize;
+
+    fn dyn_num_public_variables(&self) -> usize;
+
+    fn dyn_num_private_variables(&self) -> usize;
+
+    fn dyn_is_in_setup_mode(&self) -> bool;
+
+    fn dyn_input_assignment_layout(&self) -> Option<InputLayout>;
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ObjectSafeConstraintSystem<F> for CS {
+    fn dyn_alloc(
+        &mut self,
+        annotation: DynAnnotation<'_>,
+        f: DynAssignment<'_, F>,
+    ) -> Result<Variable, SynthesisError> {
+        self.alloc(annotation, f)
+    }
+
+    fn dyn_alloc_input(
+        &mut self,
+        annotation: DynAnnotation<'_>,
+        f: DynAssignment<'_, F>,
+    ) -> Result<Variable, SynthesisError> {
+        self.alloc_input(annotation, f)
+    }
+
+    fn dyn_enforce(
+        &mut self,
+        annotation: DynAnnotation<'_>,
+        a: DynLinearCombination<'_, F>,
+        b: DynLinearCombination<'_, F>,
+        c: DynLinearCombination<'_, F>,
+    ) {
+        self.enforce(annotation, a, b, c)
+    }
+
+    fn dyn_push_namespace(&mut self, name_fn: DynAnnotation<'_>) {
+        self.push_namespace(name_fn)
+    }
+
+    fn dyn_pop_namespace(&mut self) {
+        self.pop_namespace()
+    }
+
+    fn dyn_num_constraints(&self) -> usize {
+        self.num_constraints()
+    }
+
+    fn dyn_num_public_variables(&self) -> usize {
+        self.num_public_variables()
+    }
+
+    fn dyn_num_private_variables(&self) -> usize {
+        self.num_private_variables()
+    }
+
+    fn dyn_is_in_setup_mode(&self) -> bool {
+        self.is_in_setup_mode()
+    }
+
+    fn dyn_input_assignment_layout(&self) -> Option<InputLayout> {
+        self.input_assignment_layout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, TestConstraintSystem};
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_fn_synthesizer() {
+        let circuit = FnSynthesizer::new(|cs| {
+            let a = cs.alloc(|| "a", || Ok(Fr::from(3u64)))?;
+            let b = cs.alloc_input(|| "b", || Ok(Fr::from(9u64)))?;
+            let mut cs = cs.ns(|| "square");
+            cs.enforce(|| "a * a = b", |lc| lc + a, |lc| lc + a, |lc| lc + b);
+            Ok(())
+        });
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(1, cs.num_constraints());
+        assert_eq!(2, cs.num_public_variables());
+        assert_eq!(1, cs.num_private_variables());
+        assert_eq!("square/a * a = b", cs.get_constraint_path(0));
+        assert_eq!(Fr::from(3u64), cs.get("a"));
+
+        cs.set("b", Fr::one());
+        assert!(!cs.is_satisfied());
+    }
+}
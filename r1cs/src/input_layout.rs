@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+use anyhow::anyhow;
+
+/// The namespace paths of the public inputs of a circuit, in the order in which they are allocated.
+///
+/// The position of an input in the layout is its position in the input assignment given to
+/// the verifier, which excludes the constant `1`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputLayout {
+    paths: Vec<String>,
+}
+
+impl InputLayout {
+    /// Returns the input layout of the given circuit, which is synthesized without computing any assignment.
+    pub fn of<F: Field, C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut cs = InputLayoutRecorder::default();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs.layout)
+    }
+
+    /// Appends an input with the given path to the layout.
+    pub(crate) fn push(&mut self, path: String) {
+        self.paths.push(path);
+    }
+
+    /// Returns the number of inputs.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns `true` if there are no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns the paths of the inputs, in allocation order.
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    /// Returns the position of the input with the given path.
+    pub fn position(&self, path: &str) -> Option<usize> {
+        self.paths.iter().position(|candidate| candidate == path)
+    }
+
+    /// Returns the input assignment for the verifier, given the value of each input by path.
+    pub fn assemble<F: Field, S: AsRef<str>>(
+        &self,
+        assignments: impl IntoIterator<Item = (S, F)>,
+    ) -> Result<Vec<F>, SynthesisError> {
+        let mut values = vec![None; self.len()];
+        for (path, value) in assignments {
+            let path = path.as_ref();
+            let position = self.position(path).ok_or_else(|| anyhow!("Unknown public input '{}'", path))?;
+            if values[position].replace(value).is_some() {
+                return Err(anyhow!("Public input '{}' is assigned more than once", path).into());
+            }
+        }
+        values.into_iter().map(|value| value.ok_or(SynthesisError::AssignmentMissing)).collect()
+    }
+}
+
+/// Records the paths of the public inputs, ignoring everything else.
+#[derive(Default)]
+struct InputLayoutRecorder {
+    namespace: Vec<String>,
+    layout: InputLayout,
+    num_private_variables: usize,
+    num_constraints: usize,
+}
+
+impl<F: Field> ConstraintSystem<F> for InputLayoutRecorder {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.num_private_variables += 1;
+        Ok(Variable::new_unchecked(Index::Private(self.num_private_variables - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let name = annotation();
+        let path = self.namespace.iter().map(String::as_str).chain([name.as_ref()]).collect::<Vec<_>>().join("/");
+        self.layout.push(path);
+        Ok(Variable::new_unchecked(Index::Public(self.layout.len())))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.namespace.push(name_fn().as_ref().to_owned());
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.namespace.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.layout.len() + 1
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        true
+    }
+
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        Some(self.layout.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FnSynthesizer, Fr, TestConstraintSystem};
+
+    #[test]
+    fn test_input_layout() {
+        let circuit = FnSynthesizer::new(|cs| {
+            let a = cs.alloc_input(|| "a", || Ok(Fr::from(2u64)))?;
+            let b = cs.alloc(|| "b", || Ok(Fr::from(3u64)))?;
+            let mut cs = cs.ns(|| "product");
+            let c = cs.alloc_input(|| "c", || Ok(Fr::from(6u64)))?;
+            cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            Ok(())
+        });
+
+        let layout = InputLayout::of(&circuit).unwrap();
+        assert_eq!(["a", "product/c"], layout.paths());
+        assert_eq!(Some(1), layout.position("product/c"));
+        assert_eq!(None, layout.position("c"));
+
+        // Release builds purge the variables of a namespace once it is popped.
+        if cfg!(debug_assertions) {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            circuit.generate_constraints(&mut cs).unwrap();
+            assert_eq!(Some(&layout), cs.input_assignment_layout().as_ref());
+        }
+
+        let inputs = layout.assemble([("product/c", Fr::from(6u64)), ("a", Fr::from(2u64))]).unwrap();
+        assert_eq!(vec![Fr::from(2u64), Fr::from(6u64)], inputs);
+
+        assert!(matches!(layout.assemble([("a", Fr::from(2u64))]), Err(SynthesisError::AssignmentMissing)));
+        assert!(layout.assemble([("a", Fr::from(2u64)), ("a", Fr::from(2u64))]).is_err());
+        assert!(layout.assemble([("b", Fr::from(3u64))]).is_err());
+    }
+}
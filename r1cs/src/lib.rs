@@ -23,26 +23,41 @@ pub use assignment::*;
 mod constraint_counter;
 pub use constraint_counter::*;
 
+mod constraint_matrices;
+pub use constraint_matrices::*;
+
 mod constraint_system;
 pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem};
 
 mod constraint_variable;
 pub use constraint_variable::*;
 
+mod counting_constraint_system;
+pub use counting_constraint_system::*;
+
 pub mod errors;
 pub use errors::*;
 
+mod fn_synthesizer;
+pub use fn_synthesizer::*;
+
+mod input_layout;
+pub use input_layout::*;
+
 mod linear_combination;
 pub use linear_combination::*;
 
 mod namespace;
 pub use namespace::*;
 
+mod optimizer;
+pub use optimizer::*;
+
 mod optional_vec;
 pub use optional_vec::*;
 
 mod test_constraint_system;
-pub use test_constraint_system::{Fr, TestConstraintSystem};
+pub use test_constraint_system::{EvaluatedConstraint, Fr, TestConstraintSystem, UnsatisfiedConstraint};
 
 mod test_constraint_checker;
 pub use test_constraint_checker::TestConstraintChecker;
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, Variable};
+use crate::{errors::SynthesisError, ConstraintSystem, InputLayout, LinearCombination, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -105,6 +105,11 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
     fn is_in_setup_mode(&self) -> bool {
         self.0.is_in_setup_mode()
     }
+
+    #[inline]
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        self.0.input_assignment_layout()
+    }
 }
 
 impl<F: Field, CS: ConstraintSystem<F>> Drop for Namespace<'_, F, CS> {
@@ -0,0 +1,455 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+use fxhash::{FxHashMap, FxHashSet};
+use std::collections::{BTreeMap, VecDeque};
+
+/// A rank-1 constraint, which enforces `a * b = c`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct R1CSConstraint<F: Field> {
+    pub a: LinearCombination<F>,
+    pub b: LinearCombination<F>,
+    pub c: LinearCombination<F>,
+}
+
+impl<F: Field> R1CSConstraint<F> {
+    /// Returns the constraint with its terms merged, sorted, and stripped of zero coefficients.
+    fn normalize(self) -> Self {
+        let normalize = |lc: LinearCombination<F>| {
+            let mut terms = BTreeMap::new();
+            for (var, coeff) in lc.0 {
+                *terms.entry(var).or_insert_with(F::zero) += coeff;
+            }
+            LinearCombination(terms.into_iter().filter(|(_, coeff)| !coeff.is_zero()).collect())
+        };
+        Self { a: normalize(self.a), b: normalize(self.b), c: normalize(self.c) }
+    }
+
+    /// Returns the variables of the constraint, other than the constant `1`.
+    fn variables(&self) -> impl Iterator<Item = Variable> + '_ {
+        self.a.0.iter().chain(&self.b.0).chain(&self.c.0).map(|(var, _)| *var).filter(|var| *var != one())
+    }
+
+    /// Returns the variable fixed by the constraint and its value, if the constraint is linear
+    /// in a single variable, i.e. if `a` or `b` is a constant and the constraint reduces to `coeff * var + k = 0`.
+    fn fixed_variable(&self) -> Option<(Variable, F)> {
+        let (k, other) = match (constant(&self.a), constant(&self.b)) {
+            (Some(k), _) => (k, &self.b),
+            (_, Some(k)) => (k, &self.a),
+            _ => return None,
+        };
+
+        let mut terms = BTreeMap::new();
+        for (var, coeff) in &other.0 {
+            *terms.entry(*var).or_insert_with(F::zero) += k * coeff;
+        }
+        for (var, coeff) in &self.c.0 {
+            *terms.entry(*var).or_insert_with(F::zero) -= coeff;
+        }
+        terms.retain(|_, coeff| !coeff.is_zero());
+
+        let k = terms.remove(&one()).unwrap_or_else(F::zero);
+        match terms.len() == 1 {
+            true => terms.into_iter().next().and_then(|(var, coeff)| Some((var, -k * coeff.inverse()?))),
+            false => None,
+        }
+    }
+
+    /// Replaces the given variable with the given value, returning `true` if the variable was present.
+    fn substitute(&mut self, var: Variable, value: F) -> bool {
+        let mut substituted = false;
+        for lc in [&mut self.a, &mut self.b, &mut self.c] {
+            if let Ok(position) = lc.0.binary_search_by_key(&var, |(var, _)| *var) {
+                let (_, coeff) = lc.0.remove(position);
+                // The constant `1` is the smallest variable, so its term is always the first one.
+                match lc.0.first_mut() {
+                    Some((first, constant)) if *first == one() => *constant += coeff * value,
+                    _ => lc.0.insert(0, (one(), coeff * value)),
+                }
+                lc.0.retain(|(_, coeff)| !coeff.is_zero());
+                substituted = true;
+            }
+        }
+        substituted
+    }
+
+    /// Returns `true` if the constraint holds for any assignment.
+    fn is_trivially_satisfied(&self) -> bool {
+        // Both `0 * b = 0` and `a * 0 = 0` hold for any `a` and `b`.
+        if (self.a.0.is_empty() || self.b.0.is_empty()) && self.c.0.is_empty() {
+            return true;
+        }
+        match (constant(&self.a), constant(&self.b), constant(&self.c)) {
+            (Some(a), Some(b), Some(c)) => a * b == c,
+            _ => false,
+        }
+    }
+}
+
+/// The effect of `optimize_constraints` on a list of constraints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptimizationStats {
+    /// The number of constraints before the optimization.
+    pub num_constraints_before: usize,
+    /// The number of variables found to be constant.
+    pub num_constant_variables: usize,
+    /// The number of trivially satisfied constraints which were removed.
+    pub num_trivial_constraints: usize,
+    /// The number of duplicate constraints which were removed.
+    pub num_duplicate_constraints: usize,
+    /// The number of constraints after the optimization.
+    pub num_constraints_after: usize,
+}
+
+/// Optimizes the given constraints, without changing the assignments which satisfy them.
+///
+/// 1. Constant propagation: a constraint which fixes a variable to a constant, such as `x * 1 = 5`,
+///    is kept, and the variable is replaced by the constant in every other constraint.
+/// 2. Trivial constraints, which hold for any assignment, such as `0 * x = 0` or `2 * 3 = 6`, are removed.
+/// 3. Constraints equal to an earlier constraint, up to swapping `a` and `b`, are removed.
+///
+/// The variables are left unchanged, and the remaining constraints keep their relative order.
+/// The result only depends on the structure of the constraints, and not on the assignment.
+pub fn optimize_constraints<F: Field>(
+    constraints: Vec<R1CSConstraint<F>>,
+) -> (Vec<R1CSConstraint<F>>, OptimizationStats) {
+    let num_constraints_before = constraints.len();
+    let mut constraints: Vec<_> = constraints.into_iter().map(R1CSConstraint::normalize).collect();
+
+    // Index the constraints in which each variable appears.
+    let mut occurrences = FxHashMap::<Variable, Vec<usize>>::default();
+    for (i, constraint) in constraints.iter().enumerate() {
+        for var in constraint.variables() {
+            let indices = occurrences.entry(var).or_default();
+            if indices.last() != Some(&i) {
+                indices.push(i);
+            }
+        }
+    }
+
+    // Propagate the constants until no constraint fixes a new variable. The constraint fixing a variable
+    // is the only one which still refers to it, and is kept, so that the variable remains constrained.
+    let mut constants = FxHashMap::<Variable, F>::default();
+    let mut definitions = FxHashSet::<usize>::default();
+    let mut queue: VecDeque<usize> = (0..constraints.len()).collect();
+    while let Some(i) = queue.pop_front() {
+        let (var, value) = match constraints[i].fixed_variable() {
+            Some((var, value)) if !constants.contains_key(&var) => (var, value),
+            _ => continue,
+        };
+        constants.insert(var, value);
+        definitions.insert(i);
+
+        for &j in occurrences.get(&var).into_iter().flatten() {
+            if j != i && constraints[j].substitute(var, value) {
+                queue.push_back(j);
+            }
+        }
+    }
+
+    // Remove the trivially satisfied constraints, and the duplicate constraints.
+    let mut num_trivial_constraints = 0;
+    let mut num_duplicate_constraints = 0;
+    let mut seen = FxHashSet::default();
+    let mut optimized = Vec::with_capacity(constraints.len());
+    for (i, constraint) in constraints.iter().enumerate() {
+        if !definitions.contains(&i) && constraint.is_trivially_satisfied() {
+            num_trivial_constraints += 1;
+            continue;
+        }
+
+        let (a, b) = match constraint.a.0 <= constraint.b.0 {
+            true => (&constraint.a, &constraint.b),
+            false => (&constraint.b, &constraint.a),
+        };
+        if !seen.insert((a, b, &constraint.c)) {
+            num_duplicate_constraints += 1;
+            continue;
+        }
+
+        optimized.push(constraint.clone());
+    }
+
+    let stats = OptimizationStats {
+        num_constraints_before,
+        num_constant_variables: constants.len(),
+        num_trivial_constraints,
+        num_duplicate_constraints,
+        num_constraints_after: optimized.len(),
+    };
+    (optimized, stats)
+}
+
+/// A `ConstraintSynthesizer` which optimizes the constraints of a circuit with `optimize_constraints`,
+/// before passing them on to the constraint system, e.g. the Marlin indexer or prover.
+///
+/// As the optimization does not depend on the assignment, the indexer and the prover see the same
+/// constraints, provided that the circuit is wrapped for both. The variables keep their indices.
+pub struct OptimizedSynthesizer<C> {
+    circuit: C,
+}
+
+impl<C> OptimizedSynthesizer<C> {
+    /// Returns a constraint synthesizer which optimizes the constraints of the given circuit.
+    pub fn new(circuit: C) -> Self {
+        Self { circuit }
+    }
+
+    /// Returns the inner circuit.
+    pub fn into_inner(self) -> C {
+        self.circuit
+    }
+
+    /// Returns the effect of the optimization on the constraints of the circuit.
+    pub fn optimization_stats<F: Field>(&self) -> Result<OptimizationStats, SynthesisError>
+    where
+        C: ConstraintSynthesizer<F>,
+    {
+        let mut recorder = RecordingConstraintSystem::new(true);
+        self.circuit.generate_constraints(&mut recorder)?;
+        Ok(optimize_constraints(recorder.constraints).1)
+    }
+}
+
+impl<F: Field, C: ConstraintSynthesizer<F>> ConstraintSynthesizer<F> for OptimizedSynthesizer<C> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let mut recorder = RecordingConstraintSystem::new(cs.is_in_setup_mode());
+        self.circuit.generate_constraints(&mut recorder)?;
+        let (constraints, _) = optimize_constraints(recorder.constraints);
+
+        // Allocate the variables in order, so that they keep their relative indices.
+        let mut public_variables = vec![CS::one()];
+        for (i, value) in recorder.public_variables.into_iter().enumerate().skip(1) {
+            let value = || value.ok_or(SynthesisError::AssignmentMissing);
+            public_variables.push(cs.alloc_input(|| format!("public {}", i), value)?);
+        }
+        let mut private_variables = Vec::with_capacity(recorder.private_variables.len());
+        for (i, value) in recorder.private_variables.into_iter().enumerate() {
+            let value = || value.ok_or(SynthesisError::AssignmentMissing);
+            private_variables.push(cs.alloc(|| format!("private {}", i), value)?);
+        }
+
+        let map = |lc: LinearCombination<F>| {
+            LinearCombination(
+                lc.0.into_iter()
+                    .map(|(var, coeff)| match var.get_unchecked() {
+                        Index::Public(i) => (public_variables[i], coeff),
+                        Index::Private(i) => (private_variables[i], coeff),
+                    })
+                    .collect(),
+            )
+        };
+        for (i, R1CSConstraint { a, b, c }) in constraints.into_iter().enumerate() {
+            cs.enforce(|| format!("constraint {}", i), |_| map(a), |_| map(b), |_| map(c));
+        }
+
+        Ok(())
+    }
+}
+
+/// Records the variables and constraints of a circuit, ignoring its namespaces.
+struct RecordingConstraintSystem<F: Field> {
+    public_variables: Vec<Option<F>>,
+    private_variables: Vec<Option<F>>,
+    constraints: Vec<R1CSConstraint<F>>,
+    is_in_setup_mode: bool,
+}
+
+impl<F: Field> RecordingConstraintSystem<F> {
+    fn new(is_in_setup_mode: bool) -> Self {
+        Self {
+            public_variables: vec![Some(F::one())],
+            private_variables: Vec::new(),
+            constraints: Vec::new(),
+            is_in_setup_mode,
+        }
+    }
+
+    /// Returns the assignment of a variable, which is only computed outside of the setup mode.
+    fn assignment<FN>(&self, f: FN) -> Result<Option<F>, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+    {
+        match self.is_in_setup_mode {
+            true => Ok(None),
+            false => f().map(Some),
+        }
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for RecordingConstraintSystem<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let value = self.assignment(f)?;
+        self.private_variables.push(value);
+        Ok(Variable::new_unchecked(Index::Private(self.private_variables.len() - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let value = self.assignment(f)?;
+        self.public_variables.push(value);
+        Ok(Variable::new_unchecked(Index::Public(self.public_variables.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.constraints.push(R1CSConstraint {
+            a: a(LinearCombination::zero()),
+            b: b(LinearCombination::zero()),
+            c: c(LinearCombination::zero()),
+        });
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.is_in_setup_mode
+    }
+}
+
+/// Returns the constant `1` variable.
+fn one() -> Variable {
+    Variable::new_unchecked(Index::Public(0))
+}
+
+/// Returns the value of the linear combination, if it only consists of the constant `1` term.
+fn constant<F: Field>(lc: &LinearCombination<F>) -> Option<F> {
+    match lc.0.as_slice() {
+        [] => Some(F::zero()),
+        [(var, coeff)] if *var == one() => Some(*coeff),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FnSynthesizer, Fr, TestConstraintSystem};
+
+    #[test]
+    fn test_optimize_constraints() {
+        let var = |index| Variable::new_unchecked(Index::Private(index));
+        let lc = |terms: &[(Variable, u64)]| LinearCombination(terms.iter().map(|(v, c)| (*v, Fr::from(*c))).collect());
+        let (x, y, z) = (var(0), var(1), var(2));
+
+        let constraints = vec![
+            // x * 1 = 5
+            R1CSConstraint { a: lc(&[(x, 1)]), b: lc(&[(one(), 1)]), c: lc(&[(one(), 5)]) },
+            // x * y = z, which becomes 5 * y = z
+            R1CSConstraint { a: lc(&[(x, 1)]), b: lc(&[(y, 1)]), c: lc(&[(z, 1)]) },
+            // y * x = z, which becomes a duplicate of the constraint above
+            R1CSConstraint { a: lc(&[(y, 1)]), b: lc(&[(x, 1)]), c: lc(&[(z, 1)]) },
+            // x * x = 25, which becomes trivial
+            R1CSConstraint { a: lc(&[(x, 1)]), b: lc(&[(x, 1)]), c: lc(&[(one(), 25)]) },
+            // 0 * z = 0
+            R1CSConstraint { a: lc(&[(y, 0)]), b: lc(&[(z, 1)]), c: lc(&[]) },
+        ];
+        let (optimized, stats) = optimize_constraints(constraints.clone());
+
+        assert_eq!(
+            vec![constraints[0].clone(), R1CSConstraint { a: lc(&[(one(), 5)]), ..constraints[1].clone() }],
+            optimized
+        );
+        assert_eq!(
+            OptimizationStats {
+                num_constraints_before: 5,
+                num_constant_variables: 1,
+                num_trivial_constraints: 2,
+                num_duplicate_constraints: 1,
+                num_constraints_after: 2,
+            },
+            stats
+        );
+    }
+
+    #[test]
+    fn test_optimized_synthesizer() {
+        let circuit = FnSynthesizer::new(|cs| {
+            let x = cs.alloc(|| "x", || Ok(Fr::from(5u64)))?;
+            let y = cs.alloc(|| "y", || Ok(Fr::from(2u64)))?;
+            let z = cs.alloc_input(|| "z", || Ok(Fr::from(10u64)))?;
+            let one = TestConstraintSystem::<Fr>::one();
+
+            cs.enforce(|| "x = 5", |lc| lc + x, |lc| lc + one, |lc| lc + (Fr::from(5u64), one));
+            for i in 0..3 {
+                cs.enforce(|| format!("x * y = z {}", i), |lc| lc + x, |lc| lc + y, |lc| lc + z);
+            }
+            cs.enforce(|| "x * x = 25", |lc| lc + x, |lc| lc + x, |lc| lc + (Fr::from(25u64), one));
+            Ok(())
+        });
+        let optimized = OptimizedSynthesizer::new(circuit);
+
+        let stats = optimized.optimization_stats::<Fr>().unwrap();
+        assert_eq!((5, 2), (stats.num_constraints_before, stats.num_constraints_after));
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        optimized.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(2, cs.num_constraints());
+        assert_eq!(2, cs.num_public_variables());
+        assert_eq!(2, cs.num_private_variables());
+
+        // The constant is still enforced, and so is the remaining constraint.
+        cs.set("private 0", Fr::from(4u64));
+        assert_eq!("constraint 0", cs.which_is_unsatisfied().unwrap().path);
+        cs.set("private 0", Fr::from(5u64));
+        cs.set("public 1", Fr::from(11u64));
+        assert_eq!("constraint 1", cs.which_is_unsatisfied().unwrap().path);
+    }
+}
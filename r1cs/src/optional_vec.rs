@@ -77,6 +77,12 @@ impl<T> OptionalVec<T> {
         self.values.len() - self.holes.len()
     }
 
+    /// Returns the number of `Some(T)` values and holes, i.e. one past the largest index in use.
+    #[inline]
+    pub fn len_with_holes(&self) -> usize {
+        self.values.len()
+    }
+
     #[inline]
     /// Returns `true` if there are no `Some(T)` values
     pub fn is_empty(&self) -> bool {
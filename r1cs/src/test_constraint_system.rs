@@ -14,13 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, OptionalVec, Variable};
+use crate::{
+    errors::SynthesisError,
+    ConstraintMatrices,
+    ConstraintSystem,
+    Index,
+    InputLayout,
+    LinearCombination,
+    OptionalVec,
+    SparseMatrix,
+    ToConstraintMatrices,
+    Variable,
+};
 use snarkvm_fields::Field;
 
 use cfg_if::cfg_if;
 use fxhash::{FxBuildHasher, FxHashMap};
 use indexmap::{map::Entry, IndexMap, IndexSet};
 use itertools::Itertools;
+use std::fmt;
 
 /// This field is the scalar field (Fr) of BLS12-377.
 pub type Fr = snarkvm_curves::bls12_377::Fr;
@@ -80,6 +92,38 @@ impl CurrentNamespace {
     }
 }
 
+/// A constraint of a `TestConstraintSystem`, evaluated at the current assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvaluatedConstraint<F: Field> {
+    /// The namespace path of the constraint.
+    pub path: String,
+    /// The index of the constraint, which is also its row in `ToConstraintMatrices::to_constraint_matrices`.
+    pub index: usize,
+    /// The evaluation of the `A` linear combination.
+    pub a_eval: F,
+    /// The evaluation of the `B` linear combination.
+    pub b_eval: F,
+    /// The evaluation of the `C` linear combination.
+    pub c_eval: F,
+}
+
+impl<F: Field> EvaluatedConstraint<F> {
+    /// Returns `true` if `a_eval * b_eval == c_eval`.
+    pub fn is_satisfied(&self) -> bool {
+        self.a_eval * self.b_eval == self.c_eval
+    }
+}
+
+impl<F: Field> fmt::Display for EvaluatedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let relation = if self.is_satisfied() { "==" } else { "!=" };
+        write!(f, "#{} {}: {} * {} {} {}", self.index, self.path, self.a_eval, self.b_eval, relation, self.c_eval)
+    }
+}
+
+/// A constraint which is not satisfied by the current assignment of a `TestConstraintSystem`.
+pub type UnsatisfiedConstraint<F> = EvaluatedConstraint<F>;
+
 /// Constraint system for testing purposes.
 pub struct TestConstraintSystem<F: Field> {
     // used to intern full paths in test scenarios, for get and set purposes
@@ -226,20 +270,52 @@ impl<F: Field> TestConstraintSystem<F> {
         acc
     }
 
-    pub fn which_is_unsatisfied(&self) -> Option<String> {
-        for TestConstraint { interned_path, a, b, c } in self.constraints.iter() {
-            let mut a = self.eval_lc(a.as_ref());
-            let b = self.eval_lc(b.as_ref());
-            let c = self.eval_lc(c.as_ref());
-
-            a.mul_assign(&b);
-
-            if a != c {
-                return Some(self.unintern_path(*interned_path));
+    /// Returns the first constraint which is not satisfied by the current assignment, if any.
+    pub fn which_is_unsatisfied(&self) -> Option<UnsatisfiedConstraint<F>> {
+        self.constraints.iter().enumerate().find_map(|(index, TestConstraint { interned_path, a, b, c })| {
+            let a_eval = self.eval_lc(a.as_ref());
+            let b_eval = self.eval_lc(b.as_ref());
+            let c_eval = self.eval_lc(c.as_ref());
+
+            match a_eval * b_eval == c_eval {
+                true => None,
+                false => Some(EvaluatedConstraint {
+                    path: self.unintern_path(*interned_path),
+                    index,
+                    a_eval,
+                    b_eval,
+                    c_eval,
+                }),
             }
+        })
+    }
+
+    /// Returns the (at most) `n` constraints nearest to the constraint at the given index,
+    /// evaluated at the current assignment, in the order in which they were enforced.
+    pub fn nearest_constraints(&self, index: usize, n: usize) -> Vec<EvaluatedConstraint<F>> {
+        let start = core::cmp::min(index.saturating_sub(n / 2), self.constraints.len().saturating_sub(n));
+
+        self.constraints
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(n)
+            .map(|(index, TestConstraint { interned_path, a, b, c })| EvaluatedConstraint {
+                path: self.unintern_path(*interned_path),
+                index,
+                a_eval: self.eval_lc(a.as_ref()),
+                b_eval: self.eval_lc(b.as_ref()),
+                c_eval: self.eval_lc(c.as_ref()),
+            })
+            .collect()
+    }
+
+    /// Prints the (at most) `n` constraints nearest to the constraint at the given index,
+    /// evaluated at the current assignment.
+    pub fn print_nearest_constraints(&self, index: usize, n: usize) {
+        for constraint in self.nearest_constraints(index, n) {
+            println!("{}", constraint);
         }
-
-        None
     }
 
     #[inline]
@@ -372,6 +448,32 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 }
 
+impl<F: Field> ToConstraintMatrices<F> for TestConstraintSystem<F> {
+    fn to_constraint_matrices(&self) -> ConstraintMatrices<F> {
+        // Variables purged along with their namespace leave holes, which become empty columns.
+        let num_public_variables = self.public_variables.len_with_holes();
+        let num_private_variables = self.private_variables.len_with_holes();
+        let num_variables = num_public_variables + num_private_variables;
+
+        let matrix = |select: fn(&TestConstraint) -> &[(Variable, InternedField)]| {
+            let rows = self.constraints.iter().map(|constraint| {
+                select(constraint).iter().map(|(var, interned_coeff)| {
+                    (var.get_unchecked(), *self.interned_fields.get_index(*interned_coeff).unwrap())
+                })
+            });
+            SparseMatrix::from_rows(num_public_variables, num_variables, rows)
+        };
+
+        ConstraintMatrices {
+            num_public_variables,
+            num_private_variables,
+            a: matrix(|constraint| &constraint.a),
+            b: matrix(|constraint| &constraint.b),
+            c: matrix(|constraint| &constraint.c),
+        }
+    }
+}
+
 impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
     type Root = Self;
 
@@ -504,4 +606,64 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
     fn is_in_setup_mode(&self) -> bool {
         false
     }
+
+    fn input_assignment_layout(&self) -> Option<InputLayout> {
+        let mut inputs = self
+            .named_objects
+            .iter()
+            .filter_map(|(interned_path, named_obj)| match named_obj {
+                NamedObject::Var(var) => match var.get_unchecked() {
+                    Index::Public(index) if index != 0 => Some((index, self.unintern_path(*interned_path))),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        inputs.sort_unstable_by_key(|(index, _)| *index);
+
+        let mut layout = InputLayout::default();
+        inputs.into_iter().for_each(|(_, path)| layout.push(path));
+        Some(layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_which_is_unsatisfied() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(2u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(4u64))).unwrap();
+
+        for i in 0..4 {
+            cs.enforce(|| format!("x * x = y {}", i), |lc| lc + x, |lc| lc + x, |lc| lc + y);
+        }
+        assert!(cs.which_is_unsatisfied().is_none());
+
+        cs.enforce(|| "x * y = y", |lc| lc + x, |lc| lc + y, |lc| lc + y);
+        cs.enforce(|| "x * 1 = x", |lc| lc + x, |lc| lc + TestConstraintSystem::<Fr>::one(), |lc| lc + x);
+
+        let unsatisfied = cs.which_is_unsatisfied().unwrap();
+        assert_eq!("x * y = y", unsatisfied.path);
+        assert_eq!(4, unsatisfied.index);
+        assert_eq!(
+            (Fr::from(2u64), Fr::from(4u64), Fr::from(4u64)),
+            (unsatisfied.a_eval, unsatisfied.b_eval, unsatisfied.c_eval)
+        );
+        assert!(!unsatisfied.is_satisfied());
+
+        // The window is centered on the given index, and shifted to stay within the constraints.
+        let nearest = cs.nearest_constraints(unsatisfied.index, 3);
+        assert_eq!(vec![3, 4, 5], nearest.iter().map(|constraint| constraint.index).collect::<Vec<_>>());
+        assert_eq!("x * x = y 3", nearest[0].path);
+        assert!(nearest[0].is_satisfied() && nearest[2].is_satisfied());
+        assert_eq!(Fr::one(), nearest[2].b_eval);
+
+        let nearest = cs.nearest_constraints(5, 4);
+        assert_eq!(vec![2, 3, 4, 5], nearest.iter().map(|constraint| constraint.index).collect::<Vec<_>>());
+        assert_eq!(6, cs.nearest_constraints(0, 10).len());
+    }
 }
@@ -14,24 +14,50 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Data, Type};
+use syn::{Data, DataStruct, Type};
 
-/// Returns two TokenStreams, one for the compressed deserialize, one for the
-/// uncompressed.
-fn impl_deserialize_field(ty: &Type) -> (TokenStream, TokenStream) {
+/// Returns the TokenStream that deserializes a field of type `ty` with the given `CanonicalDeserialize` method.
+fn impl_deserialize_field(ty: &Type, method: &Ident) -> TokenStream {
     // Check if type is a tuple.
     match ty {
         Type::Tuple(tuple) => {
-            let (compressed_fields, uncompressed_fields): (Vec<_>, Vec<_>) =
-                tuple.elems.iter().map(impl_deserialize_field).unzip();
-            (quote! { (#(#compressed_fields)*), }, quote! { (#(#uncompressed_fields)*), })
+            let fields = tuple.elems.iter().map(|elem| impl_deserialize_field(elem, method));
+            quote! { (#(#fields)*), }
         }
-        _ => (
-            quote! { CanonicalDeserialize::deserialize(reader)?, },
-            quote! { CanonicalDeserialize::deserialize_uncompressed(reader)?, },
-        ),
+        _ => quote! { CanonicalDeserialize::#method(reader)?, },
+    }
+}
+
+/// Returns the body of the given `CanonicalDeserialize` method for the struct `name`.
+fn impl_deserialize_body(name: &Ident, data_struct: &DataStruct, method: &Ident) -> TokenStream {
+    let mut tuple = false;
+    let mut field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+    for field in data_struct.fields.iter() {
+        let field_case = impl_deserialize_field(&field.ty, method);
+        match &field.ident {
+            None => {
+                tuple = true;
+                field_cases.push(field_case);
+            }
+            // struct field without len_type
+            Some(ident) => field_cases.push(quote! { #ident: #field_case }),
+        }
+    }
+
+    if tuple {
+        quote!({
+            Ok(#name (
+                #(#field_cases)*
+            ))
+        })
+    } else {
+        quote!({
+            Ok(#name {
+                #(#field_cases)*
+            })
+        })
     }
 }
 
@@ -40,68 +66,23 @@ pub(crate) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let deserialize_body;
-    let deserialize_uncompressed_body;
-
-    match ast.data {
-        Data::Struct(ref data_struct) => {
-            let mut tuple = false;
-            let mut compressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
-            let mut uncompressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
-            for field in data_struct.fields.iter() {
-                match &field.ident {
-                    None => {
-                        tuple = true;
-                        let (compressed, uncompressed) = impl_deserialize_field(&field.ty);
-                        compressed_field_cases.push(compressed);
-                        uncompressed_field_cases.push(uncompressed);
-                    }
-                    // struct field without len_type
-                    Some(ident) => {
-                        let (compressed_field, uncompressed_field) = impl_deserialize_field(&field.ty);
-                        compressed_field_cases.push(quote! { #ident: #compressed_field });
-                        uncompressed_field_cases.push(quote! { #ident: #uncompressed_field });
-                    }
-                }
-            }
-
-            if tuple {
-                deserialize_body = quote!({
-                    Ok(#name (
-                        #(#compressed_field_cases)*
-                    ))
-                });
-                deserialize_uncompressed_body = quote!({
-                    Ok(#name (
-                        #(#uncompressed_field_cases)*
-                    ))
-                });
-            } else {
-                deserialize_body = quote!({
-                    Ok(#name {
-                        #(#compressed_field_cases)*
-                    })
-                });
-                deserialize_uncompressed_body = quote!({
-                    Ok(#name {
-                        #(#uncompressed_field_cases)*
-                    })
-                });
-            }
-        }
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
         _ => panic!("Deserialize can only be derived for structs, {} is not a Struct", name),
     };
 
+    let methods = ["deserialize", "deserialize_uncompressed", "deserialize_unchecked", "deserialize_compact"]
+        .map(|method| Ident::new(method, Span::call_site()));
+    let bodies = methods.iter().map(|method| impl_deserialize_body(name, data_struct, method));
+
     let gen = quote! {
         impl #impl_generics CanonicalDeserialize for #name #ty_generics #where_clause {
-            #[allow(unused_mut,unused_variables)]
-            fn deserialize<R: snarkvm_utilities::Read>(reader: &mut R) -> Result<Self, snarkvm_utilities::SerializationError> {
-                #deserialize_body
-            }
-            #[allow(unused_mut,unused_variables)]
-            fn deserialize_uncompressed<R: snarkvm_utilities::Read>(reader: &mut R) -> Result<Self, snarkvm_utilities::SerializationError> {
-                #deserialize_uncompressed_body
-            }
+            #(
+                #[allow(unused_mut,unused_variables)]
+                fn #methods<R: snarkvm_utilities::Read>(reader: &mut R) -> Result<Self, snarkvm_utilities::SerializationError> {
+                    #bodies
+                }
+            )*
         }
     };
     gen
@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Data, Index, Type};
+use syn::{Data, DataStruct, Index, Type};
 
 enum IdentOrIndex {
-    Ident(proc_macro2::Ident),
+    Ident(Ident),
     Index(Index),
 }
 
@@ -32,13 +32,15 @@ impl ToTokens for IdentOrIndex {
     }
 }
 
+/// Appends the statements that serialize a field of type `ty`, and add its size, with the given
+/// `CanonicalSerialize` methods to `serialize_body` and `size_body` respectively.
 fn impl_serialize_field(
     serialize_body: &mut Vec<TokenStream>,
-    serialized_size_body: &mut Vec<TokenStream>,
-    serialize_uncompressed_body: &mut Vec<TokenStream>,
-    uncompressed_size_body: &mut Vec<TokenStream>,
+    size_body: &mut Vec<TokenStream>,
     idents: &mut Vec<IdentOrIndex>,
     ty: &Type,
+    serialize_method: &Ident,
+    size_method: &Ident,
 ) {
     // Check if type is a tuple.
     match ty {
@@ -46,97 +48,89 @@ fn impl_serialize_field(
             for (i, elem_ty) in tuple.elems.iter().enumerate() {
                 let index = Index::from(i);
                 idents.push(IdentOrIndex::Index(index));
-                impl_serialize_field(
-                    serialize_body,
-                    serialized_size_body,
-                    serialize_uncompressed_body,
-                    uncompressed_size_body,
-                    idents,
-                    elem_ty,
-                );
+                impl_serialize_field(serialize_body, size_body, idents, elem_ty, serialize_method, size_method);
                 idents.pop();
             }
         }
         _ => {
-            serialize_body.push(quote! { CanonicalSerialize::serialize(&self.#(#idents).*, writer)?; });
-            serialized_size_body.push(quote! { size += CanonicalSerialize::serialized_size(&self.#(#idents).*); });
-            serialize_uncompressed_body
-                .push(quote! { CanonicalSerialize::serialize_uncompressed(&self.#(#idents).*, writer)?; });
-            uncompressed_size_body.push(quote! { size += CanonicalSerialize::uncompressed_size(&self.#(#idents).*); });
+            serialize_body.push(quote! { CanonicalSerialize::#serialize_method(&self.#(#idents).*, writer)?; });
+            size_body.push(quote! { size += CanonicalSerialize::#size_method(&self.#(#idents).*); });
         }
     }
 }
 
+/// Returns the bodies of the given `CanonicalSerialize` methods for a struct.
+fn impl_serialize_bodies(
+    data_struct: &DataStruct,
+    serialize_method: &Ident,
+    size_method: &Ident,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    let mut serialize_body = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+    let mut size_body = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+    let mut idents = Vec::<IdentOrIndex>::new();
+
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        match field.ident {
+            None => {
+                let index = Index::from(i);
+                idents.push(IdentOrIndex::Index(index));
+            }
+            Some(ref ident) => {
+                idents.push(IdentOrIndex::Ident(ident.clone()));
+            }
+        }
+
+        impl_serialize_field(
+            &mut serialize_body,
+            &mut size_body,
+            &mut idents,
+            &field.ty,
+            serialize_method,
+            size_method,
+        );
+
+        idents.clear();
+    }
+
+    (serialize_body, size_body)
+}
+
 pub(crate) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let len = if let Data::Struct(ref data_struct) = ast.data {
-        data_struct.fields.len()
-    } else {
-        panic!("Serialize can only be derived for structs, {} is not a struct", name);
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
+        _ => panic!("Serialize can only be derived for structs, {} is not a struct", name),
     };
 
-    let mut serialize_body = Vec::<TokenStream>::with_capacity(len);
-    let mut serialized_size_body = Vec::<TokenStream>::with_capacity(len);
-    let mut serialize_uncompressed_body = Vec::<TokenStream>::with_capacity(len);
-    let mut uncompressed_size_body = Vec::<TokenStream>::with_capacity(len);
-
-    match ast.data {
-        Data::Struct(ref data_struct) => {
-            let mut idents = Vec::<IdentOrIndex>::new();
-
-            for (i, field) in data_struct.fields.iter().enumerate() {
-                match field.ident {
-                    None => {
-                        let index = Index::from(i);
-                        idents.push(IdentOrIndex::Index(index));
-                    }
-                    Some(ref ident) => {
-                        idents.push(IdentOrIndex::Ident(ident.clone()));
-                    }
-                }
-
-                impl_serialize_field(
-                    &mut serialize_body,
-                    &mut serialized_size_body,
-                    &mut serialize_uncompressed_body,
-                    &mut uncompressed_size_body,
-                    &mut idents,
-                    &field.ty,
-                );
+    let serialize_methods = ["serialize", "serialize_uncompressed", "serialize_compact"]
+        .map(|method| Ident::new(method, Span::call_site()));
+    let size_methods =
+        ["serialized_size", "uncompressed_size", "compact_size"].map(|method| Ident::new(method, Span::call_site()));
 
-                idents.clear();
-            }
-        }
-        _ => panic!("Serialize can only be derived for structs, {} is not a struct", name),
-    };
+    let (serialize_bodies, size_bodies): (Vec<_>, Vec<_>) = serialize_methods
+        .iter()
+        .zip(&size_methods)
+        .map(|(serialize_method, size_method)| impl_serialize_bodies(data_struct, serialize_method, size_method))
+        .unzip();
 
     let gen = quote! {
         impl #impl_generics CanonicalSerialize for #name #ty_generics #where_clause {
-            #[allow(unused_mut, unused_variables)]
-            fn serialize<W: snarkvm_utilities::Write>(&self, writer: &mut W) -> Result<(), snarkvm_utilities::SerializationError> {
-                #(#serialize_body)*
-                Ok(())
-            }
-            #[allow(unused_mut, unused_variables)]
-            fn serialized_size(&self) -> usize {
-                let mut size = 0;
-                #(#serialized_size_body)*
-                size
-            }
-            #[allow(unused_mut, unused_variables)]
-            fn serialize_uncompressed<W: snarkvm_utilities::Write>(&self, writer: &mut W) -> Result<(), snarkvm_utilities::SerializationError> {
-                #(#serialize_uncompressed_body)*
-                Ok(())
-            }
-            #[allow(unused_mut, unused_variables)]
-            fn uncompressed_size(&self) -> usize {
-                let mut size = 0;
-                #(#uncompressed_size_body)*
-                size
-            }
+            #(
+                #[allow(unused_mut, unused_variables)]
+                fn #serialize_methods<W: snarkvm_utilities::Write>(&self, writer: &mut W) -> Result<(), snarkvm_utilities::SerializationError> {
+                    #(#serialize_bodies)*
+                    Ok(())
+                }
+                #[allow(unused_mut, unused_variables)]
+                fn #size_methods(&self) -> usize {
+                    let mut size = 0;
+                    #(#size_bodies)*
+                    size
+                }
+            )*
         }
     };
     gen
@@ -16,11 +16,17 @@
 
 use crate::{
     bititerator::{BitIteratorBE, BitIteratorLE},
+    constant_time::{Choice, ConditionallySelectable, ConstantTimeEq},
     io::{Read, Result as IoResult, Write},
+    serialize::{decode_hex, encode_hex},
     FromBits,
     FromBytes,
+    FromBytesDeserializer,
+    IterBits,
     ToBits,
     ToBytes,
+    ToBytesSerializer,
+    Zeroize,
 };
 
 use crate::biginteger::BigInteger;
@@ -29,6 +35,7 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Debug, Display};
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
@@ -243,10 +250,47 @@ impl ToBits for BigInteger384 {
         BitIteratorBE::new(self).collect::<Vec<_>>()
     }
 }
+impl IterBits for BigInteger384 {
+    type IterBE = BitIteratorBE<Self>;
+    type IterLE = BitIteratorLE<Self>;
+
+    #[doc = " Returns an iterator over the bits of `self` in little-endian order, with trailing zeros."]
+    fn iter_bits_le(&self) -> Self::IterLE {
+        BitIteratorLE::new(*self)
+    }
+
+    #[doc = " Returns an iterator over the bits of `self` in big-endian order, with leading zeros."]
+    fn iter_bits_be(&self) -> Self::IterBE {
+        BitIteratorBE::new(*self)
+    }
+}
+impl ConstantTimeEq for BigInteger384 {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+impl ConditionallySelectable for BigInteger384 {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(<[u64; 6]>::conditional_select(&a.0, &b.0, choice))
+    }
+}
+impl Zeroize for BigInteger384 {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
 impl FromBits for BigInteger384 {
     #[doc = " Returns a `BigInteger` by parsing a slice of bits in little-endian format"]
     #[doc = " and transforms it into a slice of little-endian u64 elements."]
-    fn from_bits_le(bits: &[bool]) -> Self {
+    fn from_bits_le(bits: &[bool]) -> anyhow::Result<Self> {
+        // Ensure the bits fit in the integer.
+        if bits.len() > 384 {
+            anyhow::bail!("BigInteger384 expects at most 384 bits, found {}", bits.len());
+        }
+
         let mut res = Self::default();
         for (i, bits64) in bits.chunks(64).enumerate() {
             let mut acc: u64 = 0;
@@ -256,12 +300,12 @@ impl FromBits for BigInteger384 {
             }
             res.0[i] = acc;
         }
-        res
+        Ok(res)
     }
 
     #[doc = " Returns a `BigInteger` by parsing a slice of bits in big-endian format"]
     #[doc = " and transforms it into a slice of little-endian u64 elements."]
-    fn from_bits_be(bits: &[bool]) -> Self {
+    fn from_bits_be(bits: &[bool]) -> anyhow::Result<Self> {
         let mut bits_reversed = bits.to_vec();
         bits_reversed.reverse();
         Self::from_bits_le(&bits_reversed)
@@ -292,6 +336,33 @@ impl Display for BigInteger384 {
         write!(f, "{}", self.to_biguint())
     }
 }
+impl Serialize for BigInteger384 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut bytes = self.to_bytes_le().map_err(serde::ser::Error::custom)?;
+                bytes.reverse();
+                serializer.serialize_str(&encode_hex(&bytes))
+            }
+            false => ToBytesSerializer::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for BigInteger384 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut bytes = decode_hex(&String::deserialize(deserializer)?).map_err(de::Error::custom)?;
+                if bytes.len() != 48 {
+                    return Err(de::Error::invalid_length(bytes.len(), &"48 bytes"));
+                }
+                bytes.reverse();
+                Self::read_le(&bytes[..]).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize(deserializer, "BigInteger384", 48),
+        }
+    }
+}
 impl Ord for BigInteger384 {
     #[inline]
     #[allow(clippy::comparison_chain)]
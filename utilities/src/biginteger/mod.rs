@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{rand::UniformRand, FromBits, FromBytes, ToBits, ToBytes};
+use crate::{
+    rand::UniformRand,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    FromBits,
+    FromBytes,
+    IterBits,
+    ToBits,
+    ToBytes,
+    Zeroize,
+};
 
 use num_bigint::BigUint;
 use std::fmt::{Debug, Display};
@@ -25,6 +35,9 @@ pub use bigint_256::*;
 mod bigint_384;
 pub use bigint_384::*;
 
+mod bigint_768;
+pub use bigint_768::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -34,6 +47,10 @@ mod tests;
 pub trait BigInteger:
     ToBits
     + FromBits
+    + IterBits
+    + ConstantTimeEq
+    + ConditionallySelectable
+    + Zeroize
     + ToBytes
     + FromBytes
     + Copy
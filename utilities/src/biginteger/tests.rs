@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{biginteger::*, rand::UniformRand};
+use crate::{biginteger::*, rand::UniformRand, Choice};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
+use serde::{de::DeserializeOwned, Serialize};
 
 #[allow(clippy::eq_op)]
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
@@ -66,6 +67,23 @@ fn biginteger_bits_test<B: BigInteger>() {
     assert!(!thirty_two.get_bit(3));
     assert!(!thirty_two.get_bit(4));
     assert!(thirty_two.get_bit(5), "{:?}", thirty_two);
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let x: B = UniformRand::rand(&mut rng);
+    assert_eq!(x, B::from_bits_le(&x.to_bits_le()).unwrap());
+    assert_eq!(x, B::from_bits_be(&x.to_bits_be()).unwrap());
+    assert_eq!(thirty_two, B::from_bits_le(&[false, false, false, false, false, true]).unwrap());
+    assert_eq!(thirty_two, B::from_bits_be(&[true, false, false, false, false, false]).unwrap());
+
+    // The bit iterators yield the same bits as the bit vectors.
+    assert_eq!(x.to_bits_le(), x.iter_bits_le().collect::<Vec<_>>());
+    assert_eq!(x.to_bits_be(), x.iter_bits_be().collect::<Vec<_>>());
+
+    // Bits that do not fit in the integer are rejected.
+    let mut bits = x.to_bits_le();
+    bits.push(false);
+    assert!(B::from_bits_le(&bits).is_err());
+    assert!(B::from_bits_be(&bits).is_err());
 }
 
 fn biginteger_bytes_test<B: BigInteger>() {
@@ -77,6 +95,25 @@ fn biginteger_bytes_test<B: BigInteger>() {
     assert_eq!(x, y);
 }
 
+fn biginteger_serde_test<B: BigInteger + Serialize + DeserializeOwned>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let x: B = UniformRand::rand(&mut rng);
+
+    // Human-readable formats use a fixed-width, big-endian hex string.
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, format!("\"{}\"", format!("{:?}", x).to_lowercase()));
+    assert_eq!(x, serde_json::from_str(&json).unwrap());
+
+    let one = serde_json::to_string(&B::from(1u64)).unwrap();
+    assert_eq!(one, format!("\"{:0>width$}\"", 1, width = 16 * B::NUM_LIMBS));
+    assert!(serde_json::from_str::<B>("\"0x01\"").is_err());
+
+    // Binary formats use the little-endian bytes.
+    let bytes = bincode::serialize(&x).unwrap();
+    assert_eq!(bytes, x.to_bytes_le().unwrap());
+    assert_eq!(x, bincode::deserialize(&bytes).unwrap());
+}
+
 fn biginteger_to_string_test<B: BigInteger>() {
     const ITERATIONS: u64 = 1_000_000;
 
@@ -109,14 +146,41 @@ fn biginteger_to_string_test<B: BigInteger>() {
     }
 }
 
-fn test_biginteger<B: BigInteger>(zero: B) {
+fn biginteger_constant_time_test<B: BigInteger>() {
+    const ITERATIONS: usize = 100;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a: B = UniformRand::rand(&mut rng);
+        let b: B = UniformRand::rand(&mut rng);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+        assert_eq!(a, B::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, B::conditional_select(&a, &b, Choice::from(1)));
+    }
+}
+
+fn biginteger_zeroize_test<B: BigInteger>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut a: B = UniformRand::rand(&mut rng);
+    a.zeroize();
+    assert!(a.is_zero());
+}
+
+fn test_biginteger<B: BigInteger + Serialize + DeserializeOwned>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
     let b: B = UniformRand::rand(&mut rng);
     biginteger_arithmetic_test(a, b, zero);
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
+    biginteger_serde_test::<B>();
     biginteger_to_string_test::<B>();
+    biginteger_constant_time_test::<B>();
+    biginteger_zeroize_test::<B>();
 }
 
 #[test]
@@ -128,3 +192,8 @@ fn test_biginteger256() {
 fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
+
+#[test]
+fn test_biginteger768() {
+    test_biginteger(BigInteger768::new([0u64; 12]));
+}
@@ -102,6 +102,57 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
     }
 }
 
+/// Iterates over the bits of a byte slice in *little-endian* order.
+/// Reversing the iterator yields the bits in *big-endian* order.
+#[derive(Debug)]
+pub struct BitIteratorBytes<Bytes: AsRef<[u8]>> {
+    s: Bytes,
+    front: usize,
+    back: usize,
+}
+
+impl<Bytes: AsRef<[u8]>> BitIteratorBytes<Bytes> {
+    pub fn new(s: Bytes) -> Self {
+        let back = s.as_ref().len() * 8;
+        BitIteratorBytes { s, front: 0, back }
+    }
+
+    fn bit(&self, n: usize) -> bool {
+        (self.s.as_ref()[n / 8] >> (n % 8)) & 1 == 1
+    }
+}
+
+impl<Bytes: AsRef<[u8]>> Iterator for BitIteratorBytes<Bytes> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.bit(self.front - 1))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<Bytes: AsRef<[u8]>> DoubleEndedIterator for BitIteratorBytes<Bytes> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.bit(self.back))
+        }
+    }
+}
+
+impl<Bytes: AsRef<[u8]>> ExactSizeIterator for BitIteratorBytes<Bytes> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +202,23 @@ mod tests {
         assert_eq!(Some(true), five.next());
         assert_eq!(None, five.next());
     }
+
+    #[test]
+    fn test_bititerator_bytes() {
+        let bytes = [5u8, 0x80];
+
+        let le = BitIteratorBytes::new(&bytes).collect::<Vec<_>>();
+        assert_eq!(crate::bits_from_bytes_le(&bytes).collect::<Vec<_>>(), le);
+        assert_eq!(16, BitIteratorBytes::new(&bytes).len());
+
+        let mut be = BitIteratorBytes::new(&bytes).rev();
+        assert_eq!(Some(true), be.next());
+        for _ in 0..12 {
+            assert_eq!(Some(false), be.next());
+        }
+        assert_eq!(Some(true), be.next());
+        assert_eq!(Some(false), be.next());
+        assert_eq!(Some(true), be.next());
+        assert_eq!(None, be.next());
+    }
 }
@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Vec;
+use crate::{BitIteratorBytes, Vec};
+
+use core::iter::Rev;
 
 /// Takes as input a sequence of structs, and converts them to a series of little-endian bits.
 /// All traits that implement `ToBits` can be automatically converted to bits in this manner.
@@ -45,12 +47,29 @@ pub trait ToBits: Sized {
     fn to_bits_be(&self) -> Vec<bool>;
 }
 
+pub trait IterBits {
+    /// The iterator over the bits of `self` in little-endian order.
+    type IterLE: Iterator<Item = bool>;
+    /// The iterator over the bits of `self` in big-endian order.
+    type IterBE: Iterator<Item = bool>;
+
+    /// Returns an iterator over the bits of `self` in little-endian order,
+    /// yielding the same bits as `ToBits::to_bits_le` without allocating.
+    fn iter_bits_le(&self) -> Self::IterLE;
+
+    /// Returns an iterator over the bits of `self` in big-endian order,
+    /// yielding the same bits as `ToBits::to_bits_be` without allocating.
+    fn iter_bits_be(&self) -> Self::IterBE;
+}
+
 pub trait FromBits: Sized {
-    /// Reads `Self` from a boolean array in little-endian order.
-    fn from_bits_le(bits: &[bool]) -> Self;
+    /// Reads `Self` from a boolean array in little-endian order,
+    /// returning an error if the bits do not encode a valid `Self`.
+    fn from_bits_le(bits: &[bool]) -> anyhow::Result<Self>;
 
-    /// Reads `Self` from a boolean array in big-endian order.
-    fn from_bits_be(bits: &[bool]) -> Self;
+    /// Reads `Self` from a boolean array in big-endian order,
+    /// returning an error if the bits do not encode a valid `Self`.
+    fn from_bits_be(bits: &[bool]) -> anyhow::Result<Self>;
 }
 
 pub trait ToMinimalBits: Sized {
@@ -103,3 +122,87 @@ impl ToBits for Vec<u8> {
         crate::bits_from_bytes_le(self).rev().collect()
     }
 }
+
+impl<const N: usize> IterBits for [u8; N] {
+    type IterBE = Rev<BitIteratorBytes<[u8; N]>>;
+    type IterLE = BitIteratorBytes<[u8; N]>;
+
+    #[doc = " Returns an iterator over the bits of `self` in little-endian order, with trailing zeros."]
+    fn iter_bits_le(&self) -> Self::IterLE {
+        BitIteratorBytes::new(*self)
+    }
+
+    #[doc = " Returns an iterator over the bits of `self` in big-endian order, with leading zeros."]
+    fn iter_bits_be(&self) -> Self::IterBE {
+        BitIteratorBytes::new(*self).rev()
+    }
+}
+
+impl<'a> IterBits for &'a [u8] {
+    type IterBE = Rev<BitIteratorBytes<&'a [u8]>>;
+    type IterLE = BitIteratorBytes<&'a [u8]>;
+
+    #[doc = " Returns an iterator over the bits of `self` in little-endian order, with trailing zeros."]
+    fn iter_bits_le(&self) -> Self::IterLE {
+        BitIteratorBytes::new(*self)
+    }
+
+    #[doc = " Returns an iterator over the bits of `self` in big-endian order, with leading zeros."]
+    fn iter_bits_be(&self) -> Self::IterBE {
+        BitIteratorBytes::new(*self).rev()
+    }
+}
+
+macro_rules! impl_iter_bits_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl IterBits for $integer {
+                type IterBE = Rev<BitIteratorBytes<[u8; core::mem::size_of::<$integer>()]>>;
+                type IterLE = BitIteratorBytes<[u8; core::mem::size_of::<$integer>()]>;
+
+                #[doc = " Returns an iterator over the bits of `self` in little-endian order, with trailing zeros."]
+                fn iter_bits_le(&self) -> Self::IterLE {
+                    BitIteratorBytes::new(self.to_le_bytes())
+                }
+
+                #[doc = " Returns an iterator over the bits of `self` in big-endian order, with leading zeros."]
+                fn iter_bits_be(&self) -> Self::IterBE {
+                    BitIteratorBytes::new(self.to_le_bytes()).rev()
+                }
+            }
+        )*
+    };
+}
+
+impl_iter_bits_for_integer!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_bits_bytes() {
+        let bytes = [0x12u8, 0x34, 0x56, 0x78, 0x9a];
+        assert_eq!(bytes.to_bits_le(), bytes.iter_bits_le().collect::<Vec<_>>());
+        assert_eq!(bytes.to_bits_be(), bytes.iter_bits_be().collect::<Vec<_>>());
+
+        let slice = &bytes[1..];
+        assert_eq!(slice.to_bits_le(), slice.iter_bits_le().collect::<Vec<_>>());
+        assert_eq!(slice.to_bits_be(), slice.iter_bits_be().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_iter_bits_integers() {
+        let value = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(value.to_le_bytes().to_bits_le(), value.iter_bits_le().collect::<Vec<_>>());
+        assert_eq!(value.to_le_bytes().to_bits_be(), value.iter_bits_be().collect::<Vec<_>>());
+
+        let mut bits = 5u8.iter_bits_le();
+        assert_eq!(Some(true), bits.next());
+        assert_eq!(Some(false), bits.next());
+        assert_eq!(Some(true), bits.next());
+        assert_eq!(5, bits.filter(|bit| !bit).count());
+
+        assert_eq!(128, u128::MAX.iter_bits_be().filter(|bit| *bit).count());
+    }
+}
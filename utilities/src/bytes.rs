@@ -375,6 +375,53 @@ pub fn bytes_from_bits_le(bits: &[bool]) -> Vec<u8> {
     bytes
 }
 
+/// Packs the given bytes into chunks of `num_data_bytes` bytes, each zero-extended to `num_bytes` bytes.
+///
+/// The bytes are canonically padded with a `1u8` terminator, followed by zeros up to a multiple of
+/// `num_data_bytes`. This ensures byte strings that differ only in trailing zeros are packed differently.
+#[inline]
+pub fn pack_bytes_le(bytes: &[u8], num_data_bytes: usize, num_bytes: usize) -> Vec<Vec<u8>> {
+    assert!(num_data_bytes > 0 && num_data_bytes <= num_bytes, "Invalid chunk size for packing bytes");
+
+    // Append the canonical padding.
+    let mut padded = Vec::with_capacity(bytes.len() + num_data_bytes);
+    padded.extend_from_slice(bytes);
+    padded.push(1u8);
+    let num_chunks = (padded.len() + num_data_bytes - 1) / num_data_bytes;
+    padded.resize(num_chunks * num_data_bytes, 0u8);
+
+    padded
+        .chunks(num_data_bytes)
+        .map(|chunk| {
+            let mut chunk = chunk.to_vec();
+            chunk.resize(num_bytes, 0u8);
+            chunk
+        })
+        .collect()
+}
+
+/// Unpacks the given chunks of `num_data_bytes` bytes, as packed by `pack_bytes_le`, into the original bytes.
+#[inline]
+pub fn unpack_bytes_le(chunks: &[Vec<u8>], num_data_bytes: usize) -> IoResult<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(chunks.len() * num_data_bytes);
+    for chunk in chunks {
+        // Ensure the chunk is zero beyond its data bytes.
+        if chunk.len() < num_data_bytes || chunk[num_data_bytes..].iter().any(|byte| *byte != 0) {
+            return Err(error("Malformed chunk in packed bytes"));
+        }
+        bytes.extend_from_slice(&chunk[..num_data_bytes]);
+    }
+
+    // Strip the canonical padding, which must lie in the last chunk.
+    match bytes.iter().rposition(|byte| *byte != 0) {
+        Some(index) if bytes[index] == 1 && bytes.len() - index <= num_data_bytes => {
+            bytes.truncate(index);
+            Ok(bytes)
+        }
+        _ => Err(error("Malformed padding in packed bytes")),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -441,4 +488,38 @@ mod test {
             assert_eq!(given_bytes.to_vec(), recovered_bytes);
         }
     }
+
+    #[test]
+    fn test_pack_bytes_le() {
+        // The empty string is packed into a single chunk containing the terminator.
+        assert_eq!(pack_bytes_le(&[], 3, 4), vec![vec![1, 0, 0, 0]]);
+        assert_eq!(pack_bytes_le(&[7, 8], 3, 4), vec![vec![7, 8, 1, 0]]);
+        assert_eq!(pack_bytes_le(&[7, 8, 9], 3, 4), vec![vec![7, 8, 9, 0], vec![1, 0, 0, 0]]);
+        // Trailing zeros are preserved.
+        assert_eq!(pack_bytes_le(&[7, 0], 3, 4), vec![vec![7, 0, 1, 0]]);
+    }
+
+    #[test]
+    fn test_pack_unpack_bytes_le() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for i in 0..ITERATIONS {
+            let bytes = (0..(i % 100)).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+            let chunks = pack_bytes_le(&bytes, 31, 32);
+            assert_eq!(chunks.len(), bytes.len() / 31 + 1);
+            assert_eq!(unpack_bytes_le(&chunks, 31).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_unpack_bytes_le_malformed() {
+        // Missing terminator.
+        assert!(unpack_bytes_le(&[vec![7, 8, 0, 0]], 3).is_err());
+        // Invalid terminator.
+        assert!(unpack_bytes_le(&[vec![7, 8, 2, 0]], 3).is_err());
+        // Nonzero byte beyond the data bytes.
+        assert!(unpack_bytes_le(&[vec![7, 1, 0, 5]], 3).is_err());
+        // Non-canonical trailing chunk of zeros.
+        assert!(unpack_bytes_le(&[vec![7, 1, 0, 0], vec![0, 0, 0, 0]], 3).is_err());
+    }
 }
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Constant-time equality and selection, provided by the `subtle` crate.
+//!
+//! A [`Choice`] is a boolean that is not meant to be branched on; it should only be combined
+//! with other choices and passed to [`ConditionallySelectable`], and converted to a `bool`
+//! once the secret-dependent part of a computation is complete.
+
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choice() {
+        assert!(bool::from(Choice::from(1)));
+        assert!(!bool::from(Choice::from(0)));
+        assert!(!bool::from(!Choice::from(1)));
+        assert!(bool::from(Choice::from(1) & Choice::from(1)));
+        assert!(!bool::from(Choice::from(1) & Choice::from(0)));
+        assert!(bool::from(Choice::from(1) | Choice::from(0)));
+        assert!(bool::from(Choice::from(1) ^ Choice::from(0)));
+        assert!(!bool::from(Choice::from(1) ^ Choice::from(1)));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        for (a, b) in [(0u64, 0u64), (0, 1), (1, 0), (u64::MAX, u64::MAX), (u64::MAX, 1 << 63), (1 << 63, 0)] {
+            assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+            assert_eq!(a != b, bool::from(a.ct_ne(&b)));
+            assert_eq!(a as i8 == b as i8, bool::from((a as i8).ct_eq(&(b as i8))));
+            assert_eq!(a as u128 == b as u128, bool::from((a as u128).ct_eq(&(b as u128))));
+        }
+
+        assert!(bool::from([1u8, 2, 3].ct_eq(&[1, 2, 3])));
+        assert!(!bool::from([1u8, 2, 3].ct_eq(&[1, 2, 4])));
+        assert!(!bool::from([1u8, 2, 3][..].ct_eq(&[1, 2][..])));
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        assert_eq!(5u32, u32::conditional_select(&5, &7, Choice::from(0)));
+        assert_eq!(7u32, u32::conditional_select(&5, &7, Choice::from(1)));
+        assert_eq!(-5i64, i64::conditional_select(&3, &-5, Choice::from(1)));
+        assert_eq!([1u64, 2], <[u64; 2]>::conditional_select(&[1, 2], &[3, 4], Choice::from(0)));
+        assert_eq!([3u64, 4], <[u64; 2]>::conditional_select(&[1, 2], &[3, 4], Choice::from(1)));
+
+        let (mut a, mut b) = (1u8, 2u8);
+        u8::conditional_swap(&mut a, &mut b, Choice::from(0));
+        assert_eq!((1, 2), (a, b));
+        u8::conditional_swap(&mut a, &mut b, Choice::from(1));
+        assert_eq!((2, 1), (a, b));
+
+        let mut c = 9u16;
+        c.conditional_assign(&4, Choice::from(0));
+        assert_eq!(9, c);
+        c.conditional_assign(&4, Choice::from(1));
+        assert_eq!(4, c);
+    }
+}
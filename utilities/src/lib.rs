@@ -59,6 +59,9 @@ pub use bits::*;
 pub mod bytes;
 pub use bytes::*;
 
+pub mod constant_time;
+pub use constant_time::*;
+
 pub mod error;
 pub use error::*;
 
@@ -75,6 +78,9 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+pub mod zeroize;
+pub use zeroize::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
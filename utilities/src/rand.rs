@@ -17,10 +17,13 @@
 use rand::{
     distributions::{Distribution, Standard},
     rngs::StdRng,
+    CryptoRng,
     Rng,
+    RngCore,
     SeedableRng,
 };
 use rand_xorshift::XorShiftRng;
+use std::cell::Cell;
 
 pub trait UniformRand: Sized {
     fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self;
@@ -36,16 +39,131 @@ where
     }
 }
 
+/// The environment variable which, when set to a `u64`, fixes the seed of the test Rngs.
+pub const TEST_SEED_ENV: &str = "SNARKVM_TEST_SEED";
+
+thread_local! {
+    // The base seed of the test Rngs on this thread, and the number of test Rngs seeded from it so far.
+    static TEST_SEED: Cell<Option<(u64, u64)>> = Cell::new(None);
+}
+
+/// Returns the seed for the next test Rng on this thread.
+///
+/// The seeds on each thread are derived from a base seed, which is read from `SNARKVM_TEST_SEED` if it is set,
+/// and is obtained using entropy provided by the OS otherwise. The base seed is printed on its first use;
+/// as the test harness runs each test on its own thread and only displays the output of failing tests,
+/// a failing test can be reproduced by rerunning it with `SNARKVM_TEST_SEED` set to the printed seed.
+fn next_test_seed() -> u64 {
+    TEST_SEED.with(|state| {
+        let (base_seed, count) = state.get().unwrap_or_else(|| {
+            let base_seed = match std::env::var(TEST_SEED_ENV) {
+                Ok(seed) => {
+                    seed.parse().unwrap_or_else(|_| panic!("{} must be a u64, found \"{}\"", TEST_SEED_ENV, seed))
+                }
+                Err(_) => StdRng::from_entropy().gen(),
+            };
+            eprintln!("Seeding the test Rngs with {} (set {}={} to reproduce)", base_seed, TEST_SEED_ENV, base_seed);
+            (base_seed, 0)
+        });
+        state.set(Some((base_seed, count + 1)));
+
+        // Each test Rng on the thread receives a distinct seed, so that separately sampled values differ.
+        base_seed.wrapping_add(count)
+    })
+}
+
 /// A fast Rng which should be used only in tests or benchmarks, but not for any real world purposes.
+/// The Rng is reproducible by setting `SNARKVM_TEST_SEED`, as described in `TestRng`.
 pub fn test_rng() -> XorShiftRng {
-    // Obtain the initial seed using entropy provided by the OS.
-    let seed = StdRng::from_entropy().gen();
-
     // Use the seed to initialize a fast, non-cryptographic Rng.
-    XorShiftRng::seed_from_u64(seed)
+    XorShiftRng::seed_from_u64(next_test_seed())
 }
 
 /// An Rng which can be used in tests or benchmarks requiring a CryptoRng.
-pub fn test_crypto_rng() -> StdRng {
-    StdRng::from_entropy()
+pub fn test_crypto_rng() -> TestRng {
+    TestRng::default()
+}
+
+/// A deterministic Rng which can be used in tests or benchmarks requiring a `CryptoRng`,
+/// such as prover tests, but not for any real world purposes.
+///
+/// By default, the Rng is seeded from a per-thread base seed, which is printed on its first use
+/// and can be fixed by setting the `SNARKVM_TEST_SEED` environment variable, e.g.
+/// `SNARKVM_TEST_SEED=1234 cargo test <test name>`, to reproduce a failing randomized test.
+#[derive(Clone, Debug)]
+pub struct TestRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl TestRng {
+    /// Returns a new test Rng, seeded from the base seed of this thread.
+    pub fn new() -> Self {
+        Self::fixed(next_test_seed())
+    }
+
+    /// Returns a new test Rng with the given seed.
+    pub fn fixed(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    /// Returns the seed of the test Rng.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Default for TestRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for TestRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_rng_fixed() {
+        let mut first = TestRng::fixed(1234);
+        let mut second = TestRng::fixed(1234);
+        assert_eq!(1234, first.seed());
+        assert_eq!(first.gen::<[u64; 4]>(), second.gen::<[u64; 4]>());
+
+        let mut other = TestRng::fixed(1235);
+        assert_ne!(first.gen::<[u64; 4]>(), other.gen::<[u64; 4]>());
+    }
+
+    #[test]
+    fn test_test_rng_distinct_seeds() {
+        // Test Rngs on the same thread are seeded consecutively from the base seed.
+        let first = TestRng::default();
+        let second = TestRng::default();
+        assert_eq!(first.seed().wrapping_add(1), second.seed());
+        assert_ne!(test_rng().gen::<u64>(), test_rng().gen::<u64>());
+    }
 }
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    io::{Read, Write},
+    serialize::{traits::*, SerializationError, SerializationMode, VarInt},
+    Vec,
+};
+
+use std::mem;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of elements serialized into a single buffer by each parallel task.
+const SERIALIZATION_CHUNK_SIZE: usize = 1 << 12;
+
+/// Serializes `values` in the same `length || elements` format as the `Vec<T>` method for the given mode,
+/// encoding chunks of elements in parallel when the `parallel` feature is enabled.
+pub fn serialize_vec_par<T: CanonicalSerialize + Sync, W: Write>(
+    values: &[T],
+    mode: SerializationMode,
+    writer: &mut W,
+) -> Result<(), SerializationError> {
+    match mode {
+        SerializationMode::Compact => VarInt::from(values.len()).serialize(writer)?,
+        _ => (values.len() as u64).serialize(writer)?,
+    }
+
+    let buffers = cfg_chunks!(values, SERIALIZATION_CHUNK_SIZE)
+        .map(|chunk| {
+            let mut buffer = Vec::new();
+            for value in chunk {
+                match mode {
+                    SerializationMode::Compressed => value.serialize(&mut buffer)?,
+                    SerializationMode::Uncompressed => value.serialize_uncompressed(&mut buffer)?,
+                    SerializationMode::Compact => value.serialize_compact(&mut buffer)?,
+                }
+            }
+            Ok(buffer)
+        })
+        .collect::<Result<Vec<_>, SerializationError>>()?;
+
+    for buffer in buffers {
+        writer.write_all(&buffer)?;
+    }
+    Ok(())
+}
+
+/// Deserializes a vector written by the `Vec<T>` method for the given mode (or `serialize_vec_par`),
+/// decoding the elements in parallel when the `parallel` feature is enabled.
+pub fn deserialize_vec_par<T, R>(reader: &mut R, mode: SerializationMode) -> Result<Vec<T>, SerializationError>
+where
+    T: CanonicalDeserialize + ConstantSerializedSize + Send,
+    R: Read,
+{
+    let len = match mode {
+        SerializationMode::Compact => VarInt::deserialize(reader)?.0,
+        _ => u64::deserialize(reader)?,
+    };
+    let bytes = read_elements::<T, R>(reader, len, element_size::<T>(mode))?;
+    deserialize_slice_par(&bytes, mode)
+}
+
+/// Deserializes a compressed vector written by `Vec::<T>::serialize` without validating its elements,
+/// decoding them in parallel when the `parallel` feature is enabled.
+///
+/// This must only be used on inputs from a trusted source.
+pub fn deserialize_vec_unchecked_par<T, R>(reader: &mut R) -> Result<Vec<T>, SerializationError>
+where
+    T: CanonicalDeserialize + ConstantSerializedSize + Send,
+    R: Read,
+{
+    let len = u64::deserialize(reader)?;
+    let bytes = read_elements::<T, R>(reader, len, T::SERIALIZED_SIZE)?;
+    cfg_chunks!(bytes, T::SERIALIZED_SIZE).map(|mut element| T::deserialize_unchecked(&mut element)).collect()
+}
+
+/// Deserializes a sequence of elements that were serialized back to back with the given mode,
+/// without a length prefix, decoding them in parallel when the `parallel` feature is enabled.
+pub fn deserialize_slice_par<T>(bytes: &[u8], mode: SerializationMode) -> Result<Vec<T>, SerializationError>
+where
+    T: CanonicalDeserialize + ConstantSerializedSize + Send,
+{
+    let size = element_size::<T>(mode);
+    if bytes.len() % size != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+
+    cfg_chunks!(bytes, size)
+        .map(|mut element| match mode {
+            SerializationMode::Compressed => T::deserialize(&mut element),
+            SerializationMode::Uncompressed => T::deserialize_uncompressed(&mut element),
+            SerializationMode::Compact => T::deserialize_compact(&mut element),
+        })
+        .collect()
+}
+
+/// Returns the number of bytes used to encode a single `T` in the given mode.
+pub(crate) fn element_size<T: ConstantSerializedSize>(mode: SerializationMode) -> usize {
+    match mode {
+        SerializationMode::Compressed | SerializationMode::Compact => T::SERIALIZED_SIZE,
+        SerializationMode::Uncompressed => T::UNCOMPRESSED_SIZE,
+    }
+}
+
+/// Reads the encoding of `len` elements of the given size,
+/// applying the same size limit as `Vec::<T>::deserialize`.
+fn read_elements<T, R: Read>(reader: &mut R, len: u64, size: usize) -> Result<Vec<u8>, SerializationError> {
+    let len = len as usize;
+
+    if mem::size_of::<T>().saturating_mul(len) > 1024 * 1024 * 1024 {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let mut bytes = vec![0u8; len.checked_mul(size).ok_or(SerializationError::InvalidData)?];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_par_matches_sequential_format() {
+        let values = (0..10_000u64).collect::<Vec<_>>();
+
+        for mode in [SerializationMode::Compressed, SerializationMode::Uncompressed, SerializationMode::Compact] {
+            let mut expected = Vec::new();
+            match mode {
+                SerializationMode::Compressed => values.serialize(&mut expected).unwrap(),
+                SerializationMode::Uncompressed => values.serialize_uncompressed(&mut expected).unwrap(),
+                SerializationMode::Compact => values.serialize_compact(&mut expected).unwrap(),
+            }
+
+            let mut bytes = Vec::new();
+            serialize_vec_par(&values, mode, &mut bytes).unwrap();
+            assert_eq!(expected, bytes);
+
+            let candidate: Vec<u64> = deserialize_vec_par(&mut &bytes[..], mode).unwrap();
+            assert_eq!(values, candidate);
+        }
+
+        let mut bytes = Vec::new();
+        values.serialize(&mut bytes).unwrap();
+        let candidate: Vec<u64> = deserialize_vec_unchecked_par(&mut &bytes[..]).unwrap();
+        assert_eq!(values, candidate);
+
+        let candidate: Vec<u64> = deserialize_slice_par(&bytes[8..], SerializationMode::Compressed).unwrap();
+        assert_eq!(values, candidate);
+    }
+
+    #[test]
+    fn test_vec_par_rejects_truncated_input() {
+        let values = (0..100u64).collect::<Vec<_>>();
+        let mut bytes = Vec::new();
+        serialize_vec_par(&values, SerializationMode::Compressed, &mut bytes).unwrap();
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(deserialize_vec_par::<u64, _>(&mut &truncated[..], SerializationMode::Compressed).is_err());
+        assert!(deserialize_slice_par::<u64>(&truncated[8..], SerializationMode::Compressed).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, SerializationError},
+    FromBytesVisitor,
+    Vec,
+};
+
+use serde::{
+    de::{self, Deserialize},
+    ser::{self, SerializeTuple},
+    Deserializer,
+    Serializer,
+};
+
+/// Returns the given bytes as a lowercase hex string.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Returns the bytes of the given hex string, which may be prefixed with `0x`.
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, SerializationError> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return Err(SerializationError::InvalidData);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| SerializationError::InvalidData))
+        .collect()
+}
+
+/// Serializes `CanonicalSerialize` objects with serde, as a hex string of the compressed encoding
+/// in human-readable formats, and as the compressed bytes otherwise.
+///
+/// Note: curve points previously derived their serde encoding as a struct of their coordinates.
+/// Points serialized in that format by earlier releases must be re-encoded, as it is not accepted here.
+pub struct CanonicalSerde;
+
+impl CanonicalSerde {
+    /// Serializes the compressed encoding of `object`.
+    pub fn serialize<T: CanonicalSerialize, S: Serializer>(object: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::with_capacity(object.serialized_size());
+        CanonicalSerialize::serialize(object, &mut bytes).map_err(ser::Error::custom)?;
+
+        match serializer.is_human_readable() {
+            true => serializer.serialize_str(&encode_hex(&bytes)),
+            false => {
+                let mut tuple = serializer.serialize_tuple(bytes.len())?;
+                for byte in &bytes {
+                    tuple.serialize_element(byte)?;
+                }
+                tuple.end()
+            }
+        }
+    }
+
+    /// Deserializes an object from its compressed encoding.
+    pub fn deserialize<'de, T, D>(deserializer: D, name: &str) -> Result<T, D::Error>
+    where
+        T: CanonicalDeserialize + ConstantSerializedSize,
+        D: Deserializer<'de>,
+    {
+        let bytes = match deserializer.is_human_readable() {
+            true => decode_hex(&<String as Deserialize>::deserialize(deserializer)?).map_err(de::Error::custom)?,
+            false => {
+                let mut buffer = Vec::with_capacity(T::SERIALIZED_SIZE);
+                deserializer.deserialize_tuple(T::SERIALIZED_SIZE, FromBytesVisitor::new(&mut buffer, name))?;
+                buffer
+            }
+        };
+
+        if bytes.len() != T::SERIALIZED_SIZE {
+            return Err(de::Error::invalid_length(bytes.len(), &format!("{} bytes", T::SERIALIZED_SIZE).as_str()));
+        }
+        CanonicalDeserialize::deserialize(&mut &bytes[..]).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let bytes = [0x00, 0x01, 0x7f, 0x80, 0xfe, 0xff];
+        assert_eq!(encode_hex(&bytes), "00017f80feff");
+        assert_eq!(decode_hex("00017f80feff").unwrap(), bytes);
+        assert_eq!(decode_hex("0x00017F80FEFF").unwrap(), bytes);
+        assert!(decode_hex("0").is_err());
+        assert!(decode_hex("zz").is_err());
+        assert!(decode_hex("é0").is_err());
+        assert!(decode_hex("").unwrap().is_empty());
+    }
+}
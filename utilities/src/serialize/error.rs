@@ -25,6 +25,9 @@ pub enum SerializationError {
     /// During serialization, we countered an I/O error.
     #[error("IoError: {0}")]
     IoError(#[from] crate::io::Error),
+    /// During deserialization, the input did not begin with a versioned header.
+    #[error("the input does not begin with a versioned header")]
+    MissingVersionHeader,
     /// During serialization, we didn't have enough space to write extra info.
     #[error("the last byte does not have enough space to encode the extra info bits")]
     NotEnoughSpace,
@@ -32,6 +35,12 @@ pub enum SerializationError {
     /// expected.
     #[error("the call expects empty flags")]
     UnexpectedFlags,
+    /// During deserialization, the versioned header contained unknown mode flags.
+    #[error("the versioned header contains unknown mode flags {0:#010b}")]
+    UnknownModeFlags(u8),
+    /// During deserialization, the versioned header contained an unsupported version.
+    #[error("found serialization version {found}, but only versions {min} to {max} are supported")]
+    UnsupportedVersion { found: u16, min: u16, max: u16 },
 }
 
 impl From<SerializationError> for crate::io::Error {
@@ -183,3 +183,67 @@ impl Flags for EdwardsFlags {
         1
     }
 }
+
+/// Flags for the big-endian encoding of BLS12 curve points used by ZCash,
+/// stored in the three most significant bits of the encoding.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZcashFlags {
+    /// Whether the encoding omits the y-coordinate.
+    pub is_compressed: bool,
+    /// Whether the encoded point is the point at infinity.
+    pub is_infinity: bool,
+    /// Whether the y-coordinate is the lexicographically largest of `y` and `-y`.
+    pub is_lexicographically_largest: bool,
+}
+
+impl Flags for ZcashFlags {
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        let mut mask = 0;
+        if self.is_compressed {
+            mask |= 1 << 7;
+        }
+        if self.is_infinity {
+            mask |= 1 << 6;
+        }
+        if self.is_lexicographically_largest {
+            mask |= 1 << 5;
+        }
+        mask
+    }
+
+    #[inline]
+    fn from_u8(value: u8) -> Self {
+        Self {
+            is_compressed: (value >> 7) & 1 == 1,
+            is_infinity: (value >> 6) & 1 == 1,
+            is_lexicographically_largest: (value >> 5) & 1 == 1,
+        }
+    }
+
+    #[inline]
+    fn from_u8_remove_flags(value: &mut u8) -> Self {
+        let flags = Self::from_u8(*value);
+        *value &= 0x1F;
+        flags
+    }
+
+    /// Number of bits required for these flags.
+    #[inline]
+    fn num_bits() -> usize {
+        3
+    }
+}
+
+/// The encoding of a short Weierstrass curve point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointFormat {
+    /// The little-endian encoding of `CanonicalSerialize::serialize`.
+    Compressed,
+    /// The little-endian encoding of `CanonicalSerialize::serialize_uncompressed`.
+    Uncompressed,
+    /// The big-endian encoding of the x-coordinate used by ZCash, with `ZcashFlags` in the first byte.
+    ZcashCompressed,
+    /// The big-endian encoding of both coordinates used by ZCash, with `ZcashFlags` in the first byte.
+    ZcashUncompressed,
+}
@@ -24,7 +24,10 @@ pub use crate::{
     ToBytes,
     Vec,
 };
-use crate::{serialize::traits::*, SerializationError};
+use crate::{
+    serialize::{traits::*, VarInt},
+    SerializationError,
+};
 
 use std::{borrow::Cow, collections::BTreeMap, mem, rc::Rc, sync::Arc};
 
@@ -57,6 +60,17 @@ impl CanonicalSerialize for String {
     fn serialized_size(&self) -> usize {
         self.len() + 8
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        VarInt::from(self.len()).serialize(writer)?;
+        Ok(writer.write_all(self.as_bytes())?)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        VarInt::from(self.len()).serialized_size() + self.len()
+    }
 }
 
 impl CanonicalDeserialize for String {
@@ -64,6 +78,19 @@ impl CanonicalDeserialize for String {
     fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(bincode::deserialize_from(reader)?)
     }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = VarInt::deserialize(reader)?.0;
+
+        if len > 1024 * 1024 * 1024 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut bytes = vec![0u8; len as usize];
+        reader.read_exact(&mut bytes)?;
+        String::from_utf8(bytes).map_err(|_| SerializationError::InvalidData)
+    }
 }
 
 macro_rules! impl_canonical_serialization_uint {
@@ -127,6 +154,21 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
         Ok(())
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.is_some().serialize_compact(writer)?;
+        if let Some(item) = self {
+            item.serialize_compact(writer)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        1 + if let Some(item) = self { item.compact_size() } else { 0 }
+    }
 }
 
 impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
@@ -145,6 +187,22 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 
         Ok(data)
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let is_some = bool::deserialize(reader)?;
+        let data = if is_some { Some(T::deserialize_unchecked(reader)?) } else { None };
+
+        Ok(data)
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let is_some = bool::deserialize(reader)?;
+        let data = if is_some { Some(T::deserialize_compact(reader)?) } else { None };
+
+        Ok(data)
+    }
 }
 
 // No-op
@@ -192,6 +250,16 @@ impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Rc<T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.as_ref().serialize_compact(writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.as_ref().compact_size()
+    }
 }
 
 impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Rc<T> {
@@ -204,6 +272,16 @@ impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Rc<T> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Rc::new(T::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Rc::new(T::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Rc::new(T::deserialize_compact(reader)?))
+    }
 }
 
 impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Arc<T> {
@@ -221,6 +299,16 @@ impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Arc<T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.as_ref().serialize_compact(writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.as_ref().compact_size()
+    }
 }
 
 impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Arc<T> {
@@ -233,6 +321,16 @@ impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Arc<T> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Arc::new(T::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Arc::new(T::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Arc::new(T::deserialize_compact(reader)?))
+    }
 }
 
 impl<'a, T: CanonicalSerialize + ToOwned> CanonicalSerialize for Cow<'a, T> {
@@ -250,6 +348,16 @@ impl<'a, T: CanonicalSerialize + ToOwned> CanonicalSerialize for Cow<'a, T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.as_ref().serialize_compact(writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.as_ref().compact_size()
+    }
 }
 
 impl<'a, T> CanonicalDeserialize for Cow<'a, T>
@@ -266,6 +374,16 @@ where
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_compact(reader)?))
+    }
 }
 
 impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
@@ -288,6 +406,16 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
     fn uncompressed_size(&self) -> usize {
         self.as_slice().uncompressed_size()
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.as_slice().serialize_compact(writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.as_slice().compact_size()
+    }
 }
 
 impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
@@ -320,6 +448,36 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
         }
         Ok(values)
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+
+        if mem::size_of::<T>().saturating_mul(len as usize) > 1024 * 1024 * 1024 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut values = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            values.push(T::deserialize_unchecked(reader)?);
+        }
+        Ok(values)
+    }
+
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = VarInt::deserialize(reader)?.0;
+
+        if mem::size_of::<T>().saturating_mul(len as usize) > 1024 * 1024 * 1024 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut values = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            values.push(T::deserialize_compact(reader)?);
+        }
+        Ok(values)
+    }
 }
 
 impl<T: CanonicalSerialize> CanonicalSerialize for [T] {
@@ -352,6 +510,20 @@ impl<T: CanonicalSerialize> CanonicalSerialize for [T] {
     fn uncompressed_size(&self) -> usize {
         8 + self.iter().map(|item| item.uncompressed_size()).sum::<usize>()
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        VarInt::from(self.len()).serialize(writer)?;
+        for item in self.iter() {
+            item.serialize_compact(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        VarInt::from(self.len()).serialized_size() + self.iter().map(|item| item.compact_size()).sum::<usize>()
+    }
 }
 
 impl<'a, T: CanonicalSerialize> CanonicalSerialize for &'a [T] {
@@ -384,6 +556,20 @@ impl<'a, T: CanonicalSerialize> CanonicalSerialize for &'a [T] {
     fn uncompressed_size(&self) -> usize {
         8 + self.iter().map(|item| item.uncompressed_size()).sum::<usize>()
     }
+
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        VarInt::from(self.len()).serialize(writer)?;
+        for item in self.iter() {
+            item.serialize_compact(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        VarInt::from(self.len()).serialized_size() + self.iter().map(|item| item.compact_size()).sum::<usize>()
+    }
 }
 
 // Implement Serialization for tuples
@@ -417,6 +603,19 @@ macro_rules! impl_tuple {
                     self.$no.uncompressed_size(),
                 )*].iter().sum()
             }
+
+            #[inline]
+            fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+                $(self.$no.serialize_compact(writer)?;)*
+                Ok(())
+            }
+
+            #[inline]
+            fn compact_size(&self) -> usize {
+                [$(
+                    self.$no.compact_size(),
+                )*].iter().sum()
+            }
         }
 
         impl<$($ty, )+> CanonicalDeserialize for ($($ty,)+) where
@@ -435,6 +634,20 @@ macro_rules! impl_tuple {
                     $ty::deserialize_uncompressed(reader)?,
                 )+))
             }
+
+            #[inline]
+            fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                Ok(($(
+                    $ty::deserialize_unchecked(reader)?,
+                )+))
+            }
+
+            #[inline]
+            fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                Ok(($(
+                    $ty::deserialize_compact(reader)?,
+                )+))
+            }
         }
     }
 }
@@ -462,6 +675,22 @@ where
     fn serialized_size(&self) -> usize {
         8 + self.iter().map(|(k, v)| k.serialized_size() + v.serialized_size()).sum::<usize>()
     }
+
+    /// Serializes a `BTreeMap` as `len(map) || key 1 || value 1 || ... || key n || value n`,
+    /// where the length is a `VarInt`.
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        VarInt::from(self.len()).serialize(writer)?;
+        for (k, v) in self.iter() {
+            k.serialize_compact(writer)?;
+            v.serialize_compact(writer)?;
+        }
+        Ok(())
+    }
+
+    fn compact_size(&self) -> usize {
+        VarInt::from(self.len()).serialized_size()
+            + self.iter().map(|(k, v)| k.compact_size() + v.compact_size()).sum::<usize>()
+    }
 }
 
 impl<K, V> CanonicalDeserialize for BTreeMap<K, V>
@@ -478,6 +707,26 @@ where
         }
         Ok(map)
     }
+
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            map.insert(K::deserialize_unchecked(reader)?, V::deserialize_unchecked(reader)?);
+        }
+        Ok(map)
+    }
+
+    /// Deserializes a `BTreeMap` from `len(map) || key 1 || value 1 || ... || key n || value n`,
+    /// where the length is a `VarInt`.
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = VarInt::deserialize(reader)?.0;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            map.insert(K::deserialize_compact(reader)?, V::deserialize_compact(reader)?);
+        }
+        Ok(map)
+    }
 }
 
 #[cfg(test)]
@@ -489,6 +738,11 @@ mod test {
         data.serialize(&mut &mut serialized[..]).unwrap();
         let de = T::deserialize(&mut &serialized[..]).unwrap();
         assert_eq!(data, de);
+
+        let mut serialized = vec![0; data.compact_size()];
+        data.serialize_compact(&mut &mut serialized[..]).unwrap();
+        let de = T::deserialize_compact(&mut &serialized[..]).unwrap();
+        assert_eq!(data, de);
     }
 
     #[test]
@@ -537,4 +791,24 @@ mod test {
     fn test_phantomdata() {
         test_serialize(std::marker::PhantomData::<u64>);
     }
+
+    #[test]
+    fn test_btreemap() {
+        test_serialize((0..200u64).map(|i| (i, vec![i as u8; i as usize])).collect::<BTreeMap<_, _>>());
+        test_serialize(BTreeMap::<u64, u64>::new());
+    }
+
+    #[test]
+    fn test_compact_lengths() {
+        let data = (vec![1u8, 2, 3], "asdf".to_owned(), Some(vec![vec![7u16; 200]]));
+
+        let mut serialized = vec![];
+        data.serialize_compact(&mut serialized).unwrap();
+        assert_eq!(data.compact_size(), serialized.len());
+
+        // Each length is encoded in a single byte, except for the length 200, which takes two bytes.
+        assert_eq!((1 + 3) + (1 + 4) + (1 + 1 + 2 + 400), serialized.len());
+        assert_eq!(vec![3u8, 1, 2, 3], serialized[..4]);
+        assert_eq!(data, CanonicalDeserialize::deserialize_compact(&mut &serialized[..]).unwrap());
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    serialize::{batch::element_size, deserialize_slice_par, traits::*, SerializationError, SerializationMode},
+    Vec,
+};
+
+use memmap2::Mmap;
+use std::{fs::File, marker::PhantomData, ops::Range, path::Path, sync::Arc};
+
+/// A read-only memory map of a file containing canonically serialized data.
+///
+/// The contents are paged in by the operating system as they are accessed,
+/// so a mapped file does not hold its data resident until it is deserialized.
+#[derive(Clone, Debug)]
+pub struct MappedFile {
+    mmap: Arc<Mmap>,
+}
+
+impl MappedFile {
+    /// Maps the file at the given path into memory.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SerializationError> {
+        let file = File::open(path)?;
+        // SAFETY: The map is read-only, and the files mapped by this library (keys and parameters)
+        // are not expected to be truncated or modified by other processes while they are in use.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(Self { mmap: Arc::new(mmap) })
+    }
+
+    /// Returns the number of bytes in the mapped file.
+    pub fn len(&self) -> usize {
+        self.mmap.len()
+    }
+
+    /// Returns `true` if the mapped file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.mmap.is_empty()
+    }
+
+    /// Returns the contents of the mapped file, without copying them.
+    pub fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Returns a view of `len` elements, serialized back to back with the given mode,
+    /// starting at the given byte offset.
+    pub fn elements<T: ConstantSerializedSize>(
+        &self,
+        offset: usize,
+        len: usize,
+        mode: SerializationMode,
+    ) -> Result<MappedElements<T>, SerializationError> {
+        let size = element_size::<T>(mode);
+        let end = len.checked_mul(size).and_then(|length| length.checked_add(offset));
+        match end {
+            Some(end) if end <= self.len() => {
+                Ok(MappedElements { file: self.clone(), offset, len, mode, _phantom: PhantomData })
+            }
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+/// A sequence of fixed-size elements inside a `MappedFile`, which are deserialized on demand.
+#[derive(Clone, Debug)]
+pub struct MappedElements<T> {
+    file: MappedFile,
+    offset: usize,
+    len: usize,
+    mode: SerializationMode,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: CanonicalDeserialize + ConstantSerializedSize + Send> MappedElements<T> {
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the encoding of all elements, without copying it.
+    pub fn bytes(&self) -> &[u8] {
+        &self.file.bytes()[self.byte_range(0, self.len)]
+    }
+
+    /// Deserializes the element at the given index.
+    pub fn get(&self, index: usize) -> Result<T, SerializationError> {
+        if index >= self.len {
+            return Err(SerializationError::InvalidData);
+        }
+        let mut bytes = &self.file.bytes()[self.byte_range(index, index + 1)];
+        match self.mode {
+            SerializationMode::Compressed => T::deserialize(&mut bytes),
+            SerializationMode::Uncompressed => T::deserialize_uncompressed(&mut bytes),
+            SerializationMode::Compact => T::deserialize_compact(&mut bytes),
+        }
+    }
+
+    /// Deserializes the elements from `lower` (inclusive) to `upper` (exclusive).
+    pub fn range(&self, lower: usize, upper: usize) -> Result<Vec<T>, SerializationError> {
+        if lower > upper || upper > self.len {
+            return Err(SerializationError::InvalidData);
+        }
+        deserialize_slice_par(&self.file.bytes()[self.byte_range(lower, upper)], self.mode)
+    }
+
+    /// Deserializes all elements.
+    pub fn to_vec(&self) -> Result<Vec<T>, SerializationError> {
+        self.range(0, self.len)
+    }
+
+    /// Returns the byte range of the elements from `lower` (inclusive) to `upper` (exclusive).
+    fn byte_range(&self, lower: usize, upper: usize) -> Range<usize> {
+        let size = element_size::<T>(self.mode);
+        (self.offset + lower * size)..(self.offset + upper * size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    #[test]
+    fn test_mapped_elements() {
+        let values = (0..1000u64).collect::<Vec<_>>();
+
+        let path = std::env::temp_dir().join(format!("mapped_elements_{}", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        values.serialize(&mut file).unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        let mapped = MappedFile::open(&path).unwrap();
+        assert_eq!(values.serialized_size(), mapped.len());
+
+        let elements = mapped.elements::<u64>(8, values.len(), SerializationMode::Compressed).unwrap();
+        assert_eq!(values.len(), elements.len());
+        assert_eq!(values, elements.to_vec().unwrap());
+        assert_eq!(values[10..20].to_vec(), elements.range(10, 20).unwrap());
+        assert_eq!(values[999], elements.get(999).unwrap());
+        assert_eq!(&mapped.bytes()[8..], elements.bytes());
+
+        assert!(elements.get(1000).is_err());
+        assert!(elements.range(20, 10).is_err());
+        assert!(mapped.elements::<u64>(16, values.len(), SerializationMode::Compressed).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -14,18 +14,35 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod batch;
+pub use batch::*;
+
+mod canonical_serde;
+pub use canonical_serde::*;
+
 pub mod error;
 pub use error::*;
 
 mod impls;
 pub use impls::*;
 
+#[cfg(feature = "mmap")]
+mod mapped;
+#[cfg(feature = "mmap")]
+pub use mapped::*;
+
 mod flags;
 pub use flags::*;
 
 mod traits;
 pub use traits::*;
 
+mod varint;
+pub use varint::*;
+
+mod versioned;
+pub use versioned::*;
+
 #[cfg(feature = "derive")]
 pub use snarkvm_utilities_derives::*;
 
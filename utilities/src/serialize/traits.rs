@@ -82,6 +82,18 @@ pub trait CanonicalSerialize {
     fn uncompressed_size(&self) -> usize {
         self.serialized_size()
     }
+
+    /// Serializes `self` into `writer` in the same format as `serialize`, except that the lengths
+    /// of variable-length collections are encoded as a `VarInt` instead of a `u64`.
+    #[inline]
+    fn serialize_compact<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize(writer)
+    }
+
+    #[inline]
+    fn compact_size(&self) -> usize {
+        self.serialized_size()
+    }
 }
 
 /// Serializer in little endian format allowing to encode flags.
@@ -122,6 +134,20 @@ pub trait CanonicalDeserialize: Sized {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Self::deserialize(reader)
     }
+
+    /// Reads `Self` from `reader` in the same format as `deserialize`, but skips the validity checks
+    /// (such as subgroup membership of group elements) that dominate the cost of loading large keys.
+    /// This must only be used on trusted inputs, such as local files written by this library.
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    /// Reads `Self` from `reader` in the format written by `CanonicalSerialize::serialize_compact`.
+    #[inline]
+    fn deserialize_compact<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
 }
 
 /// Deserializer in little endian format allowing flags to be encoded.
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
+};
+
+/// The maximum number of bytes in the encoding of a `VarInt`.
+const MAX_VARINT_SIZE: usize = 10;
+
+/// An unsigned integer encoded in LEB128, i.e. in groups of 7 bits, least-significant group first,
+/// where the most-significant bit of each byte is set if more bytes follow.
+///
+/// This is used to encode the lengths of collections in compact mode, where a length below 128 takes a single byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarInt(pub u64);
+
+impl CanonicalSerialize for VarInt {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        let mut value = self.0;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            match value {
+                0 => return Ok(writer.write_all(&[byte])?),
+                _ => writer.write_all(&[byte | 0x80])?,
+            }
+        }
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        let num_bits = 64 - self.0.leading_zeros() as usize;
+        core::cmp::max(1, (num_bits + 6) / 7)
+    }
+}
+
+impl CanonicalDeserialize for VarInt {
+    /// Reads a `VarInt`, rejecting encodings that overflow a `u64` or are not minimal,
+    /// so that every value has exactly one encoding.
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let mut value = 0u64;
+        for i in 0..MAX_VARINT_SIZE {
+            let byte = u8::deserialize(reader)?;
+            let bits = (byte & 0x7f) as u64;
+
+            // Ensure the bits fit in a `u64`.
+            if i == MAX_VARINT_SIZE - 1 && bits > 1 {
+                return Err(SerializationError::InvalidData);
+            }
+            value |= bits << (7 * i);
+
+            if byte & 0x80 == 0 {
+                // Ensure the encoding is minimal, i.e. does not end in a zero group.
+                if i > 0 && byte == 0 {
+                    return Err(SerializationError::InvalidData);
+                }
+                return Ok(Self(value));
+            }
+        }
+        Err(SerializationError::InvalidData)
+    }
+}
+
+impl From<usize> for VarInt {
+    fn from(value: usize) -> Self {
+        Self(value as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_round_trip() {
+        for (value, size) in [
+            (0u64, 1),
+            (1, 1),
+            (127, 1),
+            (128, 2),
+            (300, 2),
+            (16383, 2),
+            (16384, 3),
+            (u32::MAX as u64, 5),
+            (u64::MAX, MAX_VARINT_SIZE),
+        ] {
+            let mut bytes = vec![];
+            VarInt(value).serialize(&mut bytes).unwrap();
+            assert_eq!(size, bytes.len());
+            assert_eq!(size, VarInt(value).serialized_size());
+            assert_eq!(VarInt(value), VarInt::deserialize(&mut &bytes[..]).unwrap());
+        }
+
+        let mut bytes = vec![];
+        VarInt(300).serialize(&mut bytes).unwrap();
+        assert_eq!(vec![0b1010_1100, 0b0000_0010], bytes);
+    }
+
+    #[test]
+    fn test_varint_rejects_invalid_encodings() {
+        // Non-minimal encodings are rejected.
+        assert!(VarInt::deserialize(&mut &[0x80u8, 0x00][..]).is_err());
+        assert!(VarInt::deserialize(&mut &[0x81u8, 0x80, 0x00][..]).is_err());
+
+        // Encodings that overflow a `u64` are rejected.
+        let mut overflow = vec![0xffu8; MAX_VARINT_SIZE - 1];
+        overflow.push(0x02);
+        assert!(VarInt::deserialize(&mut &overflow[..]).is_err());
+        assert!(VarInt::deserialize(&mut &[0xffu8; MAX_VARINT_SIZE + 1][..]).is_err());
+
+        // Truncated encodings are rejected.
+        assert!(VarInt::deserialize(&mut &[0x80u8][..]).is_err());
+    }
+}
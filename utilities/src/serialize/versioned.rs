@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    io::{Read, Write},
+    ops::RangeInclusive,
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
+};
+
+/// The magic bytes at the start of every versioned envelope.
+pub const VERSIONED_MAGIC: [u8; 4] = *b"SVMv";
+
+/// The mode flag set when the payload uses the uncompressed encoding.
+const UNCOMPRESSED_FLAG: u8 = 0b0000_0001;
+
+/// The mode flag set when the payload encodes the lengths of collections as a `VarInt`.
+const COMPACT_FLAG: u8 = 0b0000_0010;
+
+/// The encoding used for the payload of a versioned envelope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SerializationMode {
+    /// The payload is written with `CanonicalSerialize::serialize`.
+    Compressed,
+    /// The payload is written with `CanonicalSerialize::serialize_uncompressed`.
+    Uncompressed,
+    /// The payload is written with `CanonicalSerialize::serialize_compact`.
+    Compact,
+}
+
+/// The header of a versioned envelope, encoded as `magic || version || mode flags`,
+/// where the version is a little-endian `u16` and the mode flags are a single byte.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VersionedHeader {
+    pub version: u16,
+    pub mode: SerializationMode,
+}
+
+impl VersionedHeader {
+    /// The number of bytes in an encoded header.
+    pub const SIZE: usize = VERSIONED_MAGIC.len() + 2 + 1;
+
+    /// Initializes a new header for the given version and mode.
+    pub const fn new(version: u16, mode: SerializationMode) -> Self {
+        Self { version, mode }
+    }
+
+    /// Writes the header into `writer`.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        let flags = match self.mode {
+            SerializationMode::Compressed => 0u8,
+            SerializationMode::Uncompressed => UNCOMPRESSED_FLAG,
+            SerializationMode::Compact => COMPACT_FLAG,
+        };
+        writer.write_all(&VERSIONED_MAGIC)?;
+        writer.write_all(&self.version.to_le_bytes())?;
+        writer.write_all(&[flags])?;
+        Ok(())
+    }
+
+    /// Reads a header from `reader`, returning `SerializationError::MissingVersionHeader`
+    /// if the input does not begin with the magic bytes.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; Self::SIZE];
+        reader.read_exact(&mut bytes)?;
+        Self::peek(&bytes)?.ok_or(SerializationError::MissingVersionHeader)
+    }
+
+    /// Returns the header at the start of `bytes`, or `None` if `bytes` does not begin with the magic bytes,
+    /// as is the case for data written without an envelope by older releases.
+    pub fn peek(bytes: &[u8]) -> Result<Option<Self>, SerializationError> {
+        if bytes.len() < Self::SIZE || bytes[..VERSIONED_MAGIC.len()] != VERSIONED_MAGIC {
+            return Ok(None);
+        }
+
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        let mode = match bytes[6] {
+            0 => SerializationMode::Compressed,
+            UNCOMPRESSED_FLAG => SerializationMode::Uncompressed,
+            COMPACT_FLAG => SerializationMode::Compact,
+            flags => return Err(SerializationError::UnknownModeFlags(flags)),
+        };
+        Ok(Some(Self::new(version, mode)))
+    }
+}
+
+/// Writes `object` into `writer` as a versioned envelope with the given version and mode.
+pub fn serialize_versioned<T: CanonicalSerialize, W: Write>(
+    object: &T,
+    version: u16,
+    mode: SerializationMode,
+    writer: &mut W,
+) -> Result<(), SerializationError> {
+    VersionedHeader::new(version, mode).write(writer)?;
+    match mode {
+        SerializationMode::Compressed => object.serialize(writer),
+        SerializationMode::Uncompressed => object.serialize_uncompressed(writer),
+        SerializationMode::Compact => object.serialize_compact(writer),
+    }
+}
+
+/// Reads a versioned envelope from `reader`, returning its header and payload.
+///
+/// This method fails with `SerializationError::UnsupportedVersion` if the version is not in `supported_versions`,
+/// in which case the caller may migrate the payload using the version in `VersionedHeader::peek`.
+pub fn deserialize_versioned<T: CanonicalDeserialize, R: Read>(
+    reader: &mut R,
+    supported_versions: RangeInclusive<u16>,
+) -> Result<(VersionedHeader, T), SerializationError> {
+    let header = VersionedHeader::read(reader)?;
+    if !supported_versions.contains(&header.version) {
+        return Err(SerializationError::UnsupportedVersion {
+            found: header.version,
+            min: *supported_versions.start(),
+            max: *supported_versions.end(),
+        });
+    }
+
+    let object = match header.mode {
+        SerializationMode::Compressed => T::deserialize(reader)?,
+        SerializationMode::Uncompressed => T::deserialize_uncompressed(reader)?,
+        SerializationMode::Compact => T::deserialize_compact(reader)?,
+    };
+    Ok((header, object))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versioned_round_trip() {
+        let data = vec![1u64, 2, 3, 4, 5];
+
+        for (mode, size) in [
+            (SerializationMode::Compressed, data.serialized_size()),
+            (SerializationMode::Uncompressed, data.uncompressed_size()),
+            (SerializationMode::Compact, data.compact_size()),
+        ] {
+            let mut bytes = vec![];
+            serialize_versioned(&data, 3, mode, &mut bytes).unwrap();
+            assert_eq!(bytes.len(), VersionedHeader::SIZE + size);
+            assert_eq!(bytes[..4], VERSIONED_MAGIC);
+            assert_eq!(bytes[4..6], [3, 0]);
+
+            assert_eq!(VersionedHeader::peek(&bytes).unwrap(), Some(VersionedHeader::new(3, mode)));
+            let (header, candidate) = deserialize_versioned::<Vec<u64>, _>(&mut &bytes[..], 1..=3).unwrap();
+            assert_eq!(header, VersionedHeader::new(3, mode));
+            assert_eq!(candidate, data);
+        }
+    }
+
+    #[test]
+    fn test_versioned_errors() {
+        let data = vec![1u64, 2, 3];
+        let mut bytes = vec![];
+        serialize_versioned(&data, 2, SerializationMode::Compressed, &mut bytes).unwrap();
+
+        // Unsupported versions are reported with the version that was found.
+        assert!(matches!(
+            deserialize_versioned::<Vec<u64>, _>(&mut &bytes[..], 3..=4).unwrap_err(),
+            SerializationError::UnsupportedVersion { found: 2, min: 3, max: 4 }
+        ));
+
+        // Unknown mode flags are rejected.
+        bytes[6] = 0b1000_0000;
+        assert!(matches!(VersionedHeader::peek(&bytes), Err(SerializationError::UnknownModeFlags(0b1000_0000))));
+
+        // Data written without an envelope is detected.
+        let mut legacy = vec![];
+        data.serialize(&mut legacy).unwrap();
+        assert_eq!(VersionedHeader::peek(&legacy).unwrap(), None);
+        assert!(matches!(
+            deserialize_versioned::<Vec<u64>, _>(&mut &legacy[..], 0..=u16::MAX).unwrap_err(),
+            SerializationError::MissingVersionHeader
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Zeroization of secret values, provided by the `zeroize` crate.
+//!
+//! This is used to wipe secret values, such as private keys and nonces, once they are no longer needed.
+//! Types holding secrets should derive [`ZeroizeOnDrop`], which wipes their contents when they are dropped.
+//! Note: Only the value itself is wiped; copies of the value (e.g. moved-from stack slots) are not.
+
+pub use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zeroize() {
+        let mut a = u64::MAX;
+        a.zeroize();
+        assert_eq!(0, a);
+
+        let mut b = [1u8, 2, 3];
+        b.zeroize();
+        assert_eq!([0u8; 3], b);
+
+        let mut c = (true, -5i32);
+        c.zeroize();
+        assert_eq!((false, 0), c);
+
+        let mut d = vec![[7u16; 2]; 3];
+        d.zeroize();
+        assert!(d.is_empty());
+
+        let mut e = Some(9u128);
+        e.zeroize();
+        assert_eq!(None, e);
+    }
+}